@@ -4,7 +4,8 @@
 //! Uses `~/Library/Caches/{APP_BUNDLE_ID}/` on macOS for persistence across reboots,
 //! with a fallback to `/tmp/{APP_BUNDLE_ID}/` if the cache directory is unavailable.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 
 use crate::constants::APP_BUNDLE_ID;
 
@@ -94,8 +95,125 @@ pub fn clear_cache() -> std::io::Result<u64> {
     Ok(bytes_freed)
 }
 
+/// Disk usage of one entry at the top of the cache directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheUsage {
+    /// Subdirectory name (e.g., `wallpapers`), or the file name for loose files.
+    pub name: String,
+    /// Total size in bytes.
+    pub bytes: u64,
+}
+
+/// Result of pruning the cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PruneResult {
+    /// Number of files removed.
+    pub files_removed: u64,
+    /// Total size of the removed files in bytes.
+    pub bytes_freed: u64,
+}
+
+/// Returns the disk usage of each entry in the cache directory, largest first.
+///
+/// # Errors
+///
+/// Returns an error if the cache directory exists but cannot be read.
+pub fn cache_usage() -> std::io::Result<Vec<CacheUsage>> { dir_usage(&get_cache_dir()) }
+
+/// Returns the disk usage of each entry in `root`, largest first.
+fn dir_usage(root: &Path) -> std::io::Result<Vec<CacheUsage>> {
+    if !root.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut usage = Vec::new();
+    for entry in std::fs::read_dir(root)? {
+        let entry = entry?;
+        let path = entry.path();
+        let bytes = if path.is_dir() {
+            calculate_dir_size(&path)?
+        } else {
+            entry.metadata().map(|m| m.len()).unwrap_or(0)
+        };
+
+        usage.push(CacheUsage {
+            name: entry.file_name().to_string_lossy().into_owned(),
+            bytes,
+        });
+    }
+
+    usage.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.name.cmp(&b.name)));
+    Ok(usage)
+}
+
+/// Removes cache files that were last modified more than `max_age` ago.
+///
+/// Directories left empty are removed as well, but the cache directory itself
+/// is kept. Symbolic links are never followed, so nothing outside
+/// [`get_cache_dir`] is touched.
+///
+/// # Errors
+///
+/// Returns an error if the cache directory cannot be read or a file cannot
+/// be removed.
+pub fn prune_cache(max_age: Duration) -> std::io::Result<PruneResult> {
+    let cutoff = SystemTime::now().checked_sub(max_age).unwrap_or(SystemTime::UNIX_EPOCH);
+    prune_dir(&get_cache_dir(), cutoff)
+}
+
+/// Removes files under `root` last modified before `cutoff`.
+fn prune_dir(root: &Path, cutoff: SystemTime) -> std::io::Result<PruneResult> {
+    let mut result = PruneResult::default();
+
+    if root.is_dir() {
+        prune_entries(root, root, cutoff, &mut result)?;
+    }
+
+    Ok(result)
+}
+
+/// Prunes the entries of `dir`, returning whether it is now empty.
+fn prune_entries(
+    root: &Path,
+    dir: &Path,
+    cutoff: SystemTime,
+    result: &mut PruneResult,
+) -> std::io::Result<bool> {
+    let mut is_empty = true;
+
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+
+        // `read_dir` yields children of `dir`, but guard against ever leaving the cache root
+        if !path.starts_with(root) {
+            is_empty = false;
+            continue;
+        }
+
+        let metadata = std::fs::symlink_metadata(&path)?;
+        let file_type = metadata.file_type();
+
+        if file_type.is_dir() {
+            if prune_entries(root, &path, cutoff, result)? {
+                std::fs::remove_dir(&path)?;
+            } else {
+                is_empty = false;
+            }
+        } else if file_type.is_file() && metadata.modified().is_ok_and(|time| time < cutoff) {
+            std::fs::remove_file(&path)?;
+            result.files_removed += 1;
+            result.bytes_freed += metadata.len();
+        } else {
+            is_empty = false;
+        }
+    }
+
+    Ok(is_empty)
+}
+
 /// Calculates the total size of a directory in bytes.
-fn calculate_dir_size(path: &std::path::Path) -> std::io::Result<u64> {
+fn calculate_dir_size(path: &Path) -> std::io::Result<u64> {
     let mut total = 0u64;
 
     if path.is_dir() {
@@ -203,6 +321,86 @@ mod tests {
         assert_ne!(path1, path2);
     }
 
+    fn write_file(path: &Path, bytes: usize, age: Duration) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, vec![0u8; bytes]).unwrap();
+        let file = std::fs::File::options().write(true).open(path).unwrap();
+        file.set_modified(SystemTime::now() - age).unwrap();
+    }
+
+    const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+    #[test]
+    fn test_dir_usage_reports_each_entry_largest_first() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(&dir.path().join("wallpapers/a.jpg"), 300, Duration::ZERO);
+        write_file(&dir.path().join("wallpapers/nested/b.jpg"), 200, Duration::ZERO);
+        write_file(&dir.path().join("weather/forecast.json"), 100, Duration::ZERO);
+
+        let usage = dir_usage(dir.path()).unwrap();
+
+        assert_eq!(usage, vec![
+            CacheUsage {
+                name: "wallpapers".to_string(),
+                bytes: 500
+            },
+            CacheUsage {
+                name: "weather".to_string(),
+                bytes: 100
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_dir_usage_missing_dir_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(dir_usage(&dir.path().join("missing")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_prune_removes_only_old_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let old = dir.path().join("wallpapers/old.jpg");
+        let recent = dir.path().join("wallpapers/recent.jpg");
+        write_file(&old, 100, DAY * 10);
+        write_file(&recent, 50, DAY);
+
+        let result = prune_dir(dir.path(), SystemTime::now() - DAY * 7).unwrap();
+
+        assert_eq!(result, PruneResult {
+            files_removed: 1,
+            bytes_freed: 100
+        });
+        assert!(!old.exists());
+        assert!(recent.exists());
+    }
+
+    #[test]
+    fn test_prune_removes_emptied_dirs_but_keeps_root() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(&dir.path().join("media_artwork/a/cover.png"), 10, DAY * 30);
+
+        prune_dir(dir.path(), SystemTime::now() - DAY).unwrap();
+
+        assert!(!dir.path().join("media_artwork").exists());
+        assert!(dir.path().exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_prune_does_not_follow_symlinks() {
+        let cache = tempfile::tempdir().unwrap();
+        let outside = tempfile::tempdir().unwrap();
+        let target = outside.path().join("keep.txt");
+        write_file(&target, 10, DAY * 30);
+        std::os::unix::fs::symlink(outside.path(), cache.path().join("link")).unwrap();
+
+        let result = prune_dir(cache.path(), SystemTime::now() - DAY).unwrap();
+
+        assert_eq!(result.files_removed, 0);
+        assert!(target.exists());
+    }
+
     #[test]
     fn test_cache_subdir_is_absolute_or_tmp() {
         let path = get_cache_subdir("test");
@@ -0,0 +1,63 @@
+//! App CLI commands.
+//!
+//! This module contains the app subcommands for switching between the
+//! applications in use.
+
+use clap::Subcommand;
+
+use crate::error::StacheError;
+use crate::platform::ipc_socket::{self, IpcError, IpcQuery, IpcResponse};
+
+/// App subcommands.
+#[derive(Subcommand, Debug)]
+#[command(next_display_order = None)]
+pub enum AppCommands {
+    /// Switch to the app used before the current one.
+    ///
+    /// Like Cmd+Tab, but following the order apps were last activated in,
+    /// including activations from the Dock, Spotlight or clicking a window.
+    /// Apps that quit are forgotten.
+    #[command(after_long_help = r#"Examples:
+  stache app switch   # Bind to a hotkey to flip between the last two apps"#)]
+    Switch,
+}
+
+/// Execute app subcommands.
+pub fn execute(cmd: &AppCommands) -> Result<(), StacheError> {
+    match cmd {
+        AppCommands::Switch => execute_switch(),
+    }
+}
+
+/// Activate the second most recently activated app.
+fn execute_switch() -> Result<(), StacheError> {
+    let response = match ipc_socket::send_query(IpcQuery::SwitchApp) {
+        Ok(r) => r,
+        Err(IpcError::AppNotRunning) => return Err(StacheError::AppNotRunning),
+        Err(e) => return Err(StacheError::IpcError(e.to_string())),
+    };
+
+    match response {
+        IpcResponse::Success { .. } => Ok(()),
+        IpcResponse::Error { error } => Err(StacheError::CommandError(error)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct TestCli {
+        #[command(subcommand)]
+        command: AppCommands,
+    }
+
+    #[test]
+    fn test_app_switch_parse() {
+        let cli = TestCli::try_parse_from(["test", "switch"]).unwrap();
+        assert!(matches!(cli.command, AppCommands::Switch));
+    }
+}
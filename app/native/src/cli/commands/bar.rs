@@ -0,0 +1,67 @@
+//! Bar CLI commands.
+//!
+//! This module contains the bar subcommands for showing and hiding the status bar
+//! and changing its background material.
+
+use clap::Subcommand;
+
+use super::types::CliBarMaterial;
+use crate::error::StacheError;
+use crate::platform::ipc::{self, StacheNotification};
+
+/// Bar subcommands for controlling the status bar window.
+#[derive(Subcommand, Debug)]
+#[command(next_display_order = None)]
+pub enum BarCommands {
+    /// Show the bar.
+    ///
+    /// Tiled windows on the main screen shrink back to leave room for it.
+    #[command(after_long_help = r#"Examples:
+  stache bar show   # Show the bar again"#)]
+    Show,
+
+    /// Hide the bar.
+    ///
+    /// Tiled windows on the main screen grow into the space the bar takes.
+    #[command(after_long_help = r#"Examples:
+  stache bar hide   # Hide the bar for full-screen focus"#)]
+    Hide,
+
+    /// Show the bar if it is hidden, hide it otherwise.
+    #[command(after_long_help = r#"Examples:
+  stache bar toggle   # Bind to a hotkey to flip the bar on and off"#)]
+    Toggle,
+
+    /// Change the background material of the bar.
+    ///
+    /// Blurs what is behind the bar with a macOS material, following the
+    /// system light or dark appearance. Lasts until the app restarts; set
+    /// `bar.material` to keep it.
+    #[command(after_long_help = r#"Examples:
+  stache bar material menu   # Match the translucent macOS menu bar
+  stache bar material none   # Back to a fully transparent bar"#)]
+    Material {
+        /// Material name.
+        #[arg(value_enum)]
+        material: CliBarMaterial,
+    },
+}
+
+/// Execute bar subcommands.
+pub fn execute(cmd: &BarCommands) -> Result<(), StacheError> {
+    let notification = match cmd {
+        BarCommands::Show => StacheNotification::BarShow,
+        BarCommands::Hide => StacheNotification::BarHide,
+        BarCommands::Toggle => StacheNotification::BarToggle,
+        BarCommands::Material { material } => {
+            StacheNotification::BarMaterial(material.as_str().to_string())
+        }
+    };
+
+    if !ipc::send_notification(&notification) {
+        return Err(StacheError::IpcError(
+            "Failed to send bar notification to Stache app".to_string(),
+        ));
+    }
+    Ok(())
+}
@@ -2,6 +2,8 @@
 //!
 //! This module contains the cache subcommands for managing the application's cache.
 
+use std::time::Duration;
+
 use clap::Subcommand;
 
 use crate::cache;
@@ -25,6 +27,26 @@ pub enum CacheCommands {
     #[command(after_long_help = r#"Examples:
   stache cache path    # Print the cache directory path"#)]
     Path,
+
+    /// Show how much space the cache uses.
+    ///
+    /// Prints the size of each cache subdirectory (wallpapers, media artwork,
+    /// weather, ...) and the total.
+    #[command(after_long_help = r#"Examples:
+  stache cache info    # Print the size of each cache subdirectory"#)]
+    Info,
+
+    /// Remove cache entries older than a number of days.
+    ///
+    /// Deletes cached files last modified before the given age, keeping
+    /// recent entries. Only files inside the cache directory are removed.
+    #[command(after_long_help = r#"Examples:
+  stache cache prune --older-than 30   # Remove entries older than 30 days"#)]
+    Prune {
+        /// Minimum age in days of the entries to remove.
+        #[arg(long, value_name = "DAYS")]
+        older_than: u64,
+    },
 }
 
 /// Execute cache subcommands.
@@ -52,6 +74,50 @@ pub fn execute(cmd: &CacheCommands) -> Result<(), StacheError> {
             let cache_dir_display = cache_dir.display();
             println!("{cache_dir_display}");
         }
+        CacheCommands::Info => print_cache_info()?,
+        CacheCommands::Prune { older_than } => prune_cache(*older_than)?,
+    }
+    Ok(())
+}
+
+/// Prints the size of each cache entry and the total.
+fn print_cache_info() -> Result<(), StacheError> {
+    let cache_dir = cache::get_cache_dir();
+    println!("{}", cache_dir.display());
+
+    let usage = cache::cache_usage()
+        .map_err(|err| StacheError::CacheError(format!("Failed to read cache: {err}")))?;
+
+    if usage.is_empty() {
+        println!("Cache is empty.");
+        return Ok(());
+    }
+
+    let width = usage.iter().map(|entry| entry.name.len()).max().unwrap_or(0).max("Total".len());
+    for entry in &usage {
+        println!("  {:<width$}  {}", entry.name, cache::format_bytes(entry.bytes));
+    }
+
+    let total = usage.iter().map(|entry| entry.bytes).sum();
+    println!("  {:<width$}  {}", "Total", cache::format_bytes(total));
+    Ok(())
+}
+
+/// Removes cache entries older than `days`.
+fn prune_cache(days: u64) -> Result<(), StacheError> {
+    let max_age = Duration::from_secs(days.saturating_mul(24 * 60 * 60));
+
+    let result = cache::prune_cache(max_age)
+        .map_err(|err| StacheError::CacheError(format!("Failed to prune cache: {err}")))?;
+
+    if result.files_removed == 0 {
+        println!("No cache entries older than {days} day(s).");
+    } else {
+        let formatted = cache::format_bytes(result.bytes_freed);
+        println!(
+            "Removed {} cache file(s) older than {days} day(s). Freed {formatted}.",
+            result.files_removed
+        );
     }
     Ok(())
 }
@@ -79,4 +145,21 @@ mod tests {
         let cli = TestCli::try_parse_from(["test", "path"]).unwrap();
         assert!(matches!(cli.command, CacheCommands::Path));
     }
+
+    #[test]
+    fn test_cache_info_parse() {
+        let cli = TestCli::try_parse_from(["test", "info"]).unwrap();
+        assert!(matches!(cli.command, CacheCommands::Info));
+    }
+
+    #[test]
+    fn test_cache_prune_parse() {
+        let cli = TestCli::try_parse_from(["test", "prune", "--older-than", "30"]).unwrap();
+        assert!(matches!(cli.command, CacheCommands::Prune { older_than: 30 }));
+    }
+
+    #[test]
+    fn test_cache_prune_requires_age() {
+        assert!(TestCli::try_parse_from(["test", "prune"]).is_err());
+    }
 }
@@ -5,9 +5,13 @@
 use std::path::PathBuf;
 
 use clap::Subcommand;
+use colored::Colorize;
 
-use crate::config::config_paths;
 use crate::config::template::{create_config_file, generate_config_template};
+use crate::config::validate::{Severity, validate_file};
+use crate::config::{
+    StacheConfig, config_paths, get_config, load_config_from_path, resolve_config_path,
+};
 use crate::error::StacheError;
 
 /// Config management commands.
@@ -47,6 +51,35 @@ pub enum ConfigCommands {
     /// Displays the path where Stache looks for configuration files,
     /// and indicates which one is currently in use (if any).
     Path,
+
+    /// Print the effective configuration as JSON.
+    ///
+    /// Outputs the fully resolved configuration, with defaults filled in for
+    /// every option the file does not set. This is the same configuration the
+    /// app uses at runtime.
+    #[command(after_long_help = r#"Examples:
+  stache config dump                          # Dump the active configuration
+  stache --config ~/my-config.jsonc config dump  # Dump a specific file
+  stache config dump | jq .tiling             # Inspect a single section"#)]
+    Dump,
+
+    /// Validate a configuration file without applying it.
+    ///
+    /// Reports syntax errors, unknown keys, invalid values and conflicting
+    /// definitions with their field path and position. Warnings flag values
+    /// that are valid but may not match this machine, such as a workspace
+    /// assigned to a screen that is not connected.
+    ///
+    /// Exits with a non-zero status if any error is found.
+    #[command(after_long_help = r#"Examples:
+  stache config validate                      # Validate the active configuration
+  stache config validate ~/my-config.jsonc    # Validate a specific file"#)]
+    Validate {
+        /// Path of the file to validate.
+        /// If not specified, validates the active configuration file.
+        #[arg(value_name = "PATH")]
+        path: Option<PathBuf>,
+    },
 }
 
 /// Execute config subcommands.
@@ -64,6 +97,8 @@ pub fn execute(cmd: &ConfigCommands) -> Result<(), StacheError> {
             }
         }
         ConfigCommands::Path => show_config_path(),
+        ConfigCommands::Dump => dump_config(),
+        ConfigCommands::Validate { path } => validate_config(path.clone()),
     }
 }
 
@@ -110,17 +145,23 @@ fn init_config(force: bool, custom_path: Option<PathBuf>) -> Result<(), StacheEr
 /// Show the configuration file path.
 #[allow(clippy::unnecessary_wraps)] // Consistent return type with other CLI functions
 fn show_config_path() -> Result<(), StacheError> {
-    println!("Configuration file search paths (in priority order):\n");
-
+    let active = resolve_config_path();
     let paths = config_paths();
-    let mut found_config = false;
+
+    if let Some(ref custom) = active
+        && !paths.contains(custom)
+    {
+        println!("Configuration file (from --config):\n");
+        println!("  {} (active)", custom.display());
+        return Ok(());
+    }
+
+    println!("Configuration file search paths (in priority order):\n");
 
     for (i, path) in paths.iter().enumerate() {
-        let exists = path.exists();
-        let marker = if exists && !found_config {
-            found_config = true;
+        let marker = if active.as_ref() == Some(path) {
             " (active)"
-        } else if exists {
+        } else if path.exists() {
             " (exists)"
         } else {
             ""
@@ -129,7 +170,7 @@ fn show_config_path() -> Result<(), StacheError> {
         println!("  {}. {}{}", i + 1, path.display(), marker);
     }
 
-    if !found_config {
+    if active.is_none() {
         println!("\nNo configuration file found.");
         println!("Run 'stache config init' to create one.");
     }
@@ -137,6 +178,82 @@ fn show_config_path() -> Result<(), StacheError> {
     Ok(())
 }
 
+/// Print the effective configuration as pretty JSON.
+///
+/// The active file is loaded first so that parse or validation errors are
+/// reported instead of silently dumping the defaults.
+fn dump_config() -> Result<(), StacheError> {
+    let json = match resolve_config_path() {
+        Some(path) => {
+            load_config_from_path(&path)
+                .map_err(|e| StacheError::ConfigError(format!("{}: {e}", path.display())))?;
+            serde_json::to_string_pretty(get_config())
+        }
+        // Without a file, `get_config()` would create a template; dump the defaults instead
+        None => serde_json::to_string_pretty(&StacheConfig::default()),
+    }
+    .map_err(|e| StacheError::ConfigError(format!("JSON serialization error: {e}")))?;
+
+    println!("{json}");
+
+    Ok(())
+}
+
+/// Validate a configuration file and print its diagnostics.
+fn validate_config(path: Option<PathBuf>) -> Result<(), StacheError> {
+    let Some(path) = path.or_else(resolve_config_path) else {
+        return Err(StacheError::ConfigError(
+            "No configuration file found.\nRun 'stache config init' to create one.".to_string(),
+        ));
+    };
+
+    // Screen checks are skipped when no display information is available
+    let screens: Vec<String> = crate::tiling::actor::handlers::screen::get_screens_from_macos()
+        .into_iter()
+        .map(|screen| screen.name)
+        .collect();
+    let screens = (!screens.is_empty()).then_some(screens);
+
+    let diagnostics = validate_file(&path, screens.as_deref())
+        .map_err(|e| StacheError::ConfigError(format!("{}: {e}", path.display())))?;
+
+    let errors = diagnostics.iter().filter(|d| d.severity == Severity::Error).count();
+    let warnings = diagnostics.len() - errors;
+
+    for diagnostic in &diagnostics {
+        let label = match diagnostic.severity {
+            Severity::Error => "error".red().bold(),
+            Severity::Warning => "warning".yellow().bold(),
+        };
+        let location = match (diagnostic.line, diagnostic.column) {
+            (Some(line), Some(column)) => format!("{}:{line}:{column}", path.display()),
+            _ => path.display().to_string(),
+        };
+        let field = if diagnostic.path.is_empty() {
+            String::new()
+        } else {
+            format!(" {}", diagnostic.path.cyan())
+        };
+
+        println!("{label}: {location}{field}: {}", diagnostic.message);
+    }
+
+    if errors > 0 {
+        return Err(StacheError::ConfigError(format!(
+            "{} is invalid: {errors} error(s), {warnings} warning(s)",
+            path.display()
+        )));
+    }
+
+    if warnings > 0 {
+        println!("\n{} is valid with {warnings} warning(s)", path.display());
+    } else {
+        println!("{} is valid", path.display());
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,160 @@
+//! Logs CLI commands.
+//!
+//! This module contains the logs subcommands for finding and viewing the log
+//! file written by the desktop app.
+
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use clap::Subcommand;
+
+use crate::error::StacheError;
+use crate::logging;
+
+/// How often `tail --follow` checks the log file for new lines.
+const FOLLOW_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Logs subcommands for finding and viewing the log file.
+#[derive(Subcommand, Debug)]
+#[command(next_display_order = None)]
+pub enum LogsCommands {
+    /// Show the log file location.
+    ///
+    /// Rotated files are kept next to it as `stache.1.log`, `stache.2.log`,
+    /// and so on, from newest to oldest.
+    #[command(after_long_help = r#"Examples:
+  stache logs path                 # Print the log file path
+  open "$(dirname "$(stache logs path)")"   # Show the log files in Finder"#)]
+    Path,
+
+    /// Print the last lines of the log file.
+    #[command(after_long_help = r#"Examples:
+  stache logs tail                 # Print the last 50 lines
+  stache logs tail -n 200          # Print the last 200 lines
+  stache logs tail --follow        # Keep printing new lines as they are logged"#)]
+    Tail {
+        /// Number of lines to print.
+        #[arg(long, short = 'n', default_value_t = 50, value_name = "COUNT")]
+        lines: usize,
+
+        /// Keep printing new lines as they are logged, until interrupted.
+        #[arg(long, short)]
+        follow: bool,
+    },
+}
+
+/// Execute logs subcommands.
+pub fn execute(cmd: &LogsCommands) -> Result<(), StacheError> {
+    match cmd {
+        LogsCommands::Path => println!("{}", logging::log_file_path().display()),
+        LogsCommands::Tail { lines, follow } => tail(&logging::log_file_path(), *lines, *follow)?,
+    }
+    Ok(())
+}
+
+/// Prints the last `lines` lines of the log file, then new ones with `follow`.
+fn tail(path: &Path, lines: usize, follow: bool) -> Result<(), StacheError> {
+    if !path.exists() {
+        return Err(StacheError::IoError(format!(
+            "No log file at {}. Is `logs.enabled` turned off?",
+            path.display()
+        )));
+    }
+
+    let contents = std::fs::read(path)?;
+    print!("{}", last_lines(&String::from_utf8_lossy(&contents), lines));
+    io::stdout().flush()?;
+
+    if follow {
+        follow_file(path, contents.len() as u64)?;
+    }
+    Ok(())
+}
+
+/// Returns the last `count` lines of `text`.
+fn last_lines(text: &str, count: usize) -> &str {
+    if count == 0 {
+        return "";
+    }
+
+    // Skip the line break ending the text so it doesn't count as a line
+    let body = text.strip_suffix('\n').unwrap_or(text);
+    body.rmatch_indices('\n')
+        .nth(count - 1)
+        .map_or(text, |(index, _)| &text[index + 1..])
+}
+
+/// Prints what is appended to the log file from `position` on, forever.
+///
+/// Starts over from the beginning when the file shrinks, as it does when rotated.
+fn follow_file(path: &Path, mut position: u64) -> Result<(), StacheError> {
+    let mut buffer = Vec::new();
+    loop {
+        std::thread::sleep(FOLLOW_INTERVAL);
+
+        let Ok(mut file) = File::open(path) else {
+            continue;
+        };
+        let len = file.metadata()?.len();
+        if len < position {
+            position = 0;
+        }
+        if len == position {
+            continue;
+        }
+
+        file.seek(SeekFrom::Start(position))?;
+        buffer.clear();
+        position += file.read_to_end(&mut buffer)? as u64;
+
+        let mut stdout = io::stdout().lock();
+        stdout.write_all(&buffer)?;
+        stdout.flush()?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct TestCli {
+        #[command(subcommand)]
+        command: LogsCommands,
+    }
+
+    #[test]
+    fn test_logs_path_parse() {
+        let cli = TestCli::try_parse_from(["test", "path"]).unwrap();
+        assert!(matches!(cli.command, LogsCommands::Path));
+    }
+
+    #[test]
+    fn test_logs_tail_parse() {
+        let cli = TestCli::try_parse_from(["test", "tail"]).unwrap();
+        assert!(matches!(cli.command, LogsCommands::Tail {
+            lines: 50,
+            follow: false
+        }));
+
+        let cli = TestCli::try_parse_from(["test", "tail", "-n", "5", "--follow"]).unwrap();
+        assert!(matches!(cli.command, LogsCommands::Tail {
+            lines: 5,
+            follow: true
+        }));
+    }
+
+    #[test]
+    fn test_last_lines() {
+        let text = "one\ntwo\nthree\n";
+        assert_eq!(last_lines(text, 2), "two\nthree\n");
+        assert_eq!(last_lines(text, 3), text);
+        assert_eq!(last_lines(text, 10), text);
+        assert_eq!(last_lines(text, 0), "");
+        assert_eq!(last_lines("one\ntwo", 1), "two");
+    }
+}
@@ -3,32 +3,46 @@
 //! This module defines all CLI commands and their arguments, organized into
 //! domain-specific submodules:
 //!
+//! - `app` - Application switching commands
 //! - `audio` - Audio device management commands
+//! - `bar` - Status bar commands
 //! - `cache` - Cache management commands
+//! - `logs` - Log file commands
+//! - `screenshot` - Window and workspace screenshots
 //! - `tiling` - Tiling window manager commands
 //! - `types` - Shared types used across commands
 //! - `wallpaper` - Wallpaper management commands
 
-use std::io;
+use std::path::{Path, PathBuf};
+use std::{fs, io};
 
 use clap::{CommandFactory, Parser, Subcommand};
 use clap_complete::{Generator, Shell, generate};
 
+use crate::cli::launch;
 use crate::error::StacheError;
 use crate::platform::ipc::{self, StacheNotification};
 use crate::{config, schema};
 
+pub mod app;
 pub mod audio;
+pub mod bar;
 pub mod cache;
 pub mod config_cmd;
+pub mod logs;
+pub mod screenshot;
 pub mod tiling;
 pub mod types;
 pub mod wallpaper;
 
 // Re-export commonly used types for convenience
+pub use app::AppCommands;
 pub use audio::AudioCommands;
+pub use bar::BarCommands;
 pub use cache::CacheCommands;
 pub use config_cmd::ConfigCommands;
+pub use logs::LogsCommands;
+pub use screenshot::ScreenshotArgs;
 pub use tiling::TilingCommands;
 pub use wallpaper::WallpaperCommands;
 
@@ -48,6 +62,13 @@ pub struct Cli {
     #[arg(long, short, global = true, value_name = "PATH")]
     pub config: Option<String>,
 
+    /// Don't launch the desktop app if it isn't running.
+    ///
+    /// Commands that need the app fail with exit code 69 instead, which is
+    /// useful for scripting.
+    #[arg(long, global = true)]
+    pub no_launch: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -66,18 +87,41 @@ pub enum Commands {
     #[command(subcommand)]
     Cache(CacheCommands),
 
+    /// Log file commands.
+    ///
+    /// Find or follow the log file written by the desktop app.
+    #[command(subcommand)]
+    Logs(LogsCommands),
+
     /// Audio device management commands.
     ///
     /// List and inspect audio devices on the system.
     #[command(subcommand)]
     Audio(AudioCommands),
 
+    /// Status bar commands.
+    ///
+    /// Show or hide the bar window, or change its material.
+    #[command(subcommand)]
+    Bar(BarCommands),
+
+    /// Application commands.
+    ///
+    /// Switch between the applications in use.
+    #[command(subcommand)]
+    App(AppCommands),
+
     /// Tiling window manager commands.
     ///
     /// Manage windows, workspaces, and query tiling state.
     #[command(subcommand)]
     Tiling(TilingCommands),
 
+    /// Take a screenshot of the focused window or workspace.
+    ///
+    /// Saves a PNG to a file, or copies it to the clipboard.
+    Screenshot(ScreenshotArgs),
+
     /// Configuration file management commands.
     ///
     /// Initialize, view, and manage the configuration file.
@@ -92,10 +136,23 @@ pub enum Commands {
 
     /// Output Stache configuration JSON Schema.
     ///
-    /// Outputs a JSON Schema to stdout that describes the structure of the
-    /// Stache configuration file. Can be redirected to a file for use with
-    /// editors that support JSON Schema validation.
-    Schema,
+    /// Outputs a JSON Schema that describes the structure of the Stache
+    /// configuration file, to stdout or to a file with `--output`. Reference it
+    /// from the config's `$schema` key for editor validation and completion.
+    ///
+    /// Usage:
+    ///   stache schema --output ~/.config/stache/stache.schema.json
+    ///   stache schema --stdout > stache.schema.json
+    ///   stache schema --output stache.schema.json --stdout | jq .title
+    Schema {
+        /// Write the schema to this file instead of stdout.
+        #[arg(long, short, value_name = "PATH")]
+        output: Option<PathBuf>,
+
+        /// Print the schema to stdout, also when writing it with `--output`.
+        #[arg(long)]
+        stdout: bool,
+    },
 
     /// Generate shell completions.
     ///
@@ -120,6 +177,21 @@ pub enum Commands {
     Desktop,
 }
 
+impl Commands {
+    /// Returns whether the command talks to the running desktop app.
+    ///
+    /// `reload` is left out: a freshly launched app already reads the latest
+    /// config, so there is nothing to launch it for.
+    #[must_use]
+    pub const fn requires_app(&self) -> bool {
+        match self {
+            Self::Tiling(cmd) => cmd.requires_app(),
+            Self::Bar(_) | Self::App(_) | Self::Screenshot(_) => true,
+            _ => false,
+        }
+    }
+}
+
 impl Cli {
     /// Returns the custom config path if specified via --config flag.
     #[must_use]
@@ -144,11 +216,19 @@ impl Cli {
             config::set_custom_config_path(path_buf);
         }
 
+        if self.command.requires_app() {
+            launch::ensure_app_running(self.no_launch, self.config.as_deref())?;
+        }
+
         match &self.command {
             Commands::Wallpaper(cmd) => wallpaper::execute(cmd),
             Commands::Cache(cmd) => cache::execute(cmd),
+            Commands::Logs(cmd) => logs::execute(cmd),
             Commands::Audio(cmd) => audio::execute(cmd),
+            Commands::Bar(cmd) => bar::execute(cmd),
+            Commands::App(cmd) => app::execute(cmd),
             Commands::Tiling(cmd) => tiling::execute(cmd),
+            Commands::Screenshot(args) => screenshot::execute(args),
             Commands::Config(cmd) => config_cmd::execute(cmd),
 
             Commands::Reload => {
@@ -160,11 +240,7 @@ impl Cli {
                 Ok(())
             }
 
-            Commands::Schema => {
-                let schema_output = schema::print_schema();
-                println!("{schema_output}");
-                Ok(())
-            }
+            Commands::Schema { output, stdout } => Self::export_schema(output.as_deref(), *stdout),
 
             Commands::Completions { shell } => {
                 Self::print_completions(*shell);
@@ -178,6 +254,44 @@ impl Cli {
         }
     }
 
+    /// Prints the JSON Schema, or writes it to `output` and suggests a `$schema` reference.
+    ///
+    /// With `stdout`, the schema is printed as well and the suggestion goes to
+    /// stderr, so the output can still be piped.
+    fn export_schema(output: Option<&Path>, stdout: bool) -> Result<(), StacheError> {
+        let schema_output = schema::print_schema();
+
+        let Some(path) = output else {
+            println!("{schema_output}");
+            return Ok(());
+        };
+
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, format!("{schema_output}\n"))?;
+
+        let reference = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+        let suggestion = format!(
+            "Schema written to {}\n\n\
+             Add this to your config file to enable editor validation:\n  \
+             \"$schema\": \"{}\"\n\n\
+             Or reference the published schema:\n  \
+             \"$schema\": \"{}\"",
+            path.display(),
+            reference.display(),
+            schema::SCHEMA_ID
+        );
+
+        if stdout {
+            println!("{schema_output}");
+            eprintln!("{suggestion}");
+        } else {
+            println!("{suggestion}");
+        }
+        Ok(())
+    }
+
     /// Print shell completions to stdout.
     fn print_completions<G: Generator>(generator: G) {
         let mut cmd = Self::command();
@@ -188,7 +302,7 @@ impl Cli {
 #[cfg(test)]
 mod tests {
     use super::tiling::TilingQueryCommands;
-    use super::types::{CliLayoutType, ScreenIndex, ScreenTarget};
+    use super::types::{CliBarMaterial, CliLayoutType, ScreenIndex, ScreenTarget};
     use super::*;
 
     // ========================================================================
@@ -201,10 +315,37 @@ mod tests {
         assert!(matches!(cli.command, Commands::Reload));
     }
 
+    #[test]
+    fn test_cli_parses_no_launch() {
+        let cli =
+            Cli::try_parse_from(["stache", "tiling", "query", "screens", "--no-launch"]).unwrap();
+        assert!(cli.no_launch);
+
+        let cli = Cli::try_parse_from(["stache", "reload"]).unwrap();
+        assert!(!cli.no_launch);
+    }
+
+    #[test]
+    fn test_commands_requiring_app() {
+        let requires_app =
+            |args: &[&str]| Cli::try_parse_from(args).unwrap().command.requires_app();
+
+        assert!(requires_app(&["stache", "bar", "toggle"]));
+        assert!(requires_app(&["stache", "app", "switch"]));
+        assert!(requires_app(&["stache", "screenshot", "--window"]));
+        assert!(requires_app(&["stache", "tiling", "query", "screens"]));
+        assert!(requires_app(&["stache", "tiling", "workspace", "--balance"]));
+        assert!(!requires_app(&["stache", "tiling", "query"]));
+        assert!(!requires_app(&["stache", "reload"]));
+        assert!(!requires_app(&["stache", "schema"]));
+        assert!(!requires_app(&["stache", "cache", "path"]));
+        assert!(!requires_app(&["stache", "logs", "tail"]));
+    }
+
     #[test]
     fn test_cli_parses_schema() {
         let cli = Cli::try_parse_from(["stache", "schema"]).unwrap();
-        assert!(matches!(cli.command, Commands::Schema));
+        assert!(matches!(cli.command, Commands::Schema { .. }));
     }
 
     #[test]
@@ -252,6 +393,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cli_parses_bar_commands() {
+        let cli = Cli::try_parse_from(["stache", "bar", "show"]).unwrap();
+        assert!(matches!(cli.command, Commands::Bar(BarCommands::Show)));
+
+        let cli = Cli::try_parse_from(["stache", "bar", "hide"]).unwrap();
+        assert!(matches!(cli.command, Commands::Bar(BarCommands::Hide)));
+
+        let cli = Cli::try_parse_from(["stache", "bar", "toggle"]).unwrap();
+        assert!(matches!(cli.command, Commands::Bar(BarCommands::Toggle)));
+
+        let cli = Cli::try_parse_from(["stache", "bar", "material", "hud-window"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Commands::Bar(BarCommands::Material {
+                material: CliBarMaterial::HudWindow
+            })
+        ));
+        assert!(Cli::try_parse_from(["stache", "bar", "material", "glass"]).is_err());
+    }
+
+    #[test]
+    fn test_cli_parses_config_dump() {
+        let cli = Cli::try_parse_from(["stache", "config", "dump"]).unwrap();
+        match cli.command {
+            Commands::Config(ConfigCommands::Dump) => {}
+            _ => panic!("Expected Config Dump command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_config_validate_with_path() {
+        let cli =
+            Cli::try_parse_from(["stache", "config", "validate", "/tmp/config.jsonc"]).unwrap();
+        match cli.command {
+            Commands::Config(ConfigCommands::Validate { path }) => {
+                assert_eq!(path, Some(std::path::PathBuf::from("/tmp/config.jsonc")));
+            }
+            _ => panic!("Expected Config Validate command"),
+        }
+    }
+
     #[test]
     fn test_cli_parses_audio_list() {
         let cli = Cli::try_parse_from(["stache", "audio", "list"]).unwrap();
@@ -378,6 +561,38 @@ mod tests {
         assert!(!APP_VERSION.is_empty());
     }
 
+    #[test]
+    fn test_cli_parses_schema_output() {
+        let cli =
+            Cli::try_parse_from(["stache", "schema", "--output", "/tmp/schema.json"]).unwrap();
+        match cli.command {
+            Commands::Schema { output, stdout } => {
+                assert_eq!(output, Some(PathBuf::from("/tmp/schema.json")));
+                assert!(!stdout);
+            }
+            _ => panic!("Expected Schema command"),
+        }
+    }
+
+    #[test]
+    fn test_cli_parses_schema_stdout() {
+        let cli = Cli::try_parse_from(["stache", "schema", "--stdout"]).unwrap();
+        assert!(matches!(cli.command, Commands::Schema {
+            output: None,
+            stdout: true
+        }));
+    }
+
+    #[test]
+    fn test_cli_parses_schema_output_with_stdout() {
+        let cli =
+            Cli::try_parse_from(["stache", "schema", "--stdout", "-o", "schema.json"]).unwrap();
+        assert!(matches!(cli.command, Commands::Schema {
+            output: Some(_),
+            stdout: true
+        }));
+    }
+
     #[test]
     fn test_app_version_format() {
         // Version should be in semver format (X.Y.Z)
@@ -396,7 +611,7 @@ mod tests {
         let cli =
             Cli::try_parse_from(["stache", "--config", "/path/to/config.json", "schema"]).unwrap();
         assert_eq!(cli.config, Some("/path/to/config.json".to_string()));
-        assert!(matches!(cli.command, Commands::Schema));
+        assert!(matches!(cli.command, Commands::Schema { .. }));
     }
 
     #[test]
@@ -0,0 +1,99 @@
+//! Screenshot CLI command.
+//!
+//! This module contains the command for capturing the focused window or
+//! workspace to a PNG file or the clipboard.
+
+use std::path::PathBuf;
+
+use crate::error::StacheError;
+use crate::platform::ipc_socket::{self, IpcError, IpcQuery, IpcResponse};
+
+/// Screenshot command arguments.
+#[derive(Debug, clap::Args)]
+#[command(after_long_help = r#"Examples:
+  stache screenshot --window                      # Copy the focused window
+  stache screenshot --workspace -o layout.png     # Save the focused workspace
+  stache screenshot --workspace --only-windows    # Leave out the bar and borders
+
+Stache needs the Screen Recording permission. The first screenshot asks for it;
+allow Stache in System Settings > Privacy & Security > Screen Recording."#)]
+#[group(id = "target", required = true, multiple = false)]
+pub struct ScreenshotArgs {
+    /// Capture the focused window.
+    #[arg(long, group = "target")]
+    pub window: bool,
+
+    /// Capture the visible windows of the focused workspace.
+    ///
+    /// The screenshot covers the smallest area containing all of them.
+    #[arg(long, group = "target")]
+    pub workspace: bool,
+
+    /// Capture only the windows, leaving out the bar, borders and anything
+    /// else in front of or behind them.
+    #[arg(long)]
+    pub only_windows: bool,
+
+    /// Write the PNG to this file instead of copying it to the clipboard.
+    #[arg(long, short, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+}
+
+/// Execute the screenshot command.
+pub fn execute(args: &ScreenshotArgs) -> Result<(), StacheError> {
+    // The app writes the file, so resolve relative paths against our directory.
+    let output = args.output.as_deref().map(std::path::absolute).transpose()?;
+
+    let query = IpcQuery::Screenshot {
+        workspace: args.workspace,
+        only_windows: args.only_windows,
+        output: output.as_ref().map(|path| path.to_string_lossy().into_owned()),
+    };
+
+    let response = match ipc_socket::send_query(query) {
+        Ok(r) => r,
+        Err(IpcError::AppNotRunning) => return Err(StacheError::AppNotRunning),
+        Err(e) => return Err(StacheError::IpcError(e.to_string())),
+    };
+
+    let data = match response {
+        IpcResponse::Success { data } => data,
+        IpcResponse::Error { error } => return Err(StacheError::CommandError(error)),
+    };
+
+    let size = format!("{}x{}", data["width"], data["height"]);
+    match output {
+        Some(path) => println!("Saved {size} screenshot to {}", path.display()),
+        None => println!("Copied {size} screenshot to the clipboard"),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct TestCli {
+        #[command(flatten)]
+        args: ScreenshotArgs,
+    }
+
+    #[test]
+    fn test_screenshot_parse() {
+        let cli = TestCli::try_parse_from(["test", "--workspace", "--only-windows", "-o", "a.png"])
+            .unwrap();
+        assert!(cli.args.workspace && !cli.args.window);
+        assert!(cli.args.only_windows);
+        assert_eq!(cli.args.output, Some(PathBuf::from("a.png")));
+    }
+
+    #[test]
+    fn test_screenshot_requires_one_target() {
+        assert!(TestCli::try_parse_from(["test"]).is_err());
+        assert!(TestCli::try_parse_from(["test", "--window", "--workspace"]).is_err());
+        assert!(TestCli::try_parse_from(["test", "--window"]).is_ok());
+    }
+}
@@ -9,8 +9,9 @@ use tabled::settings::object::Columns;
 use tabled::settings::{Alignment, Modify, Style};
 use tabled::{Table, Tabled};
 
-use super::types::{CliLayoutType, Direction};
-use crate::cli::output;
+use super::types::{CliLayoutType, CliMasterPosition, Direction};
+use crate::cli::{output, tree};
+use crate::config::DimensionValue;
 use crate::error::StacheError;
 use crate::platform::ipc::{self, StacheNotification};
 use crate::platform::ipc_socket::{self, IpcError, IpcQuery, IpcResponse};
@@ -48,6 +49,201 @@ pub enum TilingCommands {
     ///
     /// Use flags to specify the workspace operation to perform.
     Workspace(TilingWorkspaceArgs),
+
+    /// App commands.
+    ///
+    /// Use flags to specify the app operation to perform.
+    App(TilingAppArgs),
+
+    /// Screen commands.
+    ///
+    /// `--center-cursor` moves the mouse cursor to the center of the focused
+    /// screen, leaving out the area taken by the bar. Useful in scripts, e.g.
+    /// after focusing a screen whose workspace has no windows.
+    #[command(after_long_help = r#"Examples:
+  stache tiling screen --center-cursor
+  stache tiling workspace --focus-screen right && stache tiling screen --center-cursor"#)]
+    Screen {
+        /// Move the mouse cursor to the center of the focused screen.
+        #[arg(long)]
+        center_cursor: bool,
+    },
+
+    /// Gap commands.
+    ///
+    /// `--inner` and `--outer` grow or shrink the gaps of the focused screen
+    /// by a number of pixels and re-apply the layout. Adjustments are kept in
+    /// memory on top of the config until `stache tiling gaps reset` or a
+    /// restart, and never shrink a gap below zero.
+    #[command(
+        args_conflicts_with_subcommands = true,
+        after_long_help = r#"Examples:
+  stache tiling gaps --inner +4
+  stache tiling gaps --inner -4 --outer -4
+  stache tiling gaps reset"#
+    )]
+    Gaps {
+        /// Pixels to add to the inner gaps. Negative values shrink them.
+        #[arg(long, value_name = "PIXELS", allow_negative_numbers = true)]
+        inner: Option<i32>,
+
+        /// Pixels to add to the outer gaps. Negative values shrink them.
+        #[arg(long, value_name = "PIXELS", allow_negative_numbers = true)]
+        outer: Option<i32>,
+
+        /// Gaps subcommand.
+        #[command(subcommand)]
+        command: Option<TilingGapsCommands>,
+    },
+
+    /// Master layout commands.
+    ///
+    /// Without flags, prints the master position of the focused workspace.
+    /// `--position` moves the master window of the focused workspace and
+    /// re-applies the layout. The position is kept in memory across layout
+    /// changes until a restart and overrides `tiling.master.position`.
+    #[command(after_long_help = r#"Examples:
+  stache tiling master
+  stache tiling master --position top
+  stache tiling master --position auto"#)]
+    Master {
+        /// Position of the master window.
+        #[arg(long, short = 'p', value_enum)]
+        position: Option<CliMasterPosition>,
+    },
+
+    /// Pause window management.
+    ///
+    /// Windows stay where they are and new windows are tracked but not
+    /// positioned until `stache tiling resume`. Useful before screen sharing.
+    Pause,
+
+    /// Resume window management and re-apply the layout of visible workspaces.
+    Resume,
+
+    /// Resync tracked windows with the windows on screen.
+    ///
+    /// Untracks windows that no longer exist, tracks new ones and re-applies
+    /// the layout of affected workspaces. Set `tiling.reconcileInterval` to
+    /// run this periodically.
+    Reconcile,
+
+    /// Tear down and re-initialize window management.
+    ///
+    /// Stops the window, app, screen and mouse monitors, drops all tracked
+    /// state and starts tiling again as on launch, without restarting the app.
+    /// Layouts are kept. Useful when tiling gets into a bad state.
+    Restart,
+
+    /// Print the windows of a workspace as an ASCII diagram.
+    ///
+    /// Draws each window as a box scaled from its computed frame, labelled
+    /// with its title and size. The focused window has `#` borders. Defaults
+    /// to the focused workspace.
+    #[command(after_long_help = r#"Examples:
+  stache tiling tree
+  stache tiling tree --workspace coding
+  stache tiling tree --json"#)]
+    Tree {
+        /// Workspace to draw instead of the focused one.
+        #[arg(long, short = 'w', value_name = "NAME")]
+        workspace: Option<String>,
+
+        /// Output the window frames in JSON format instead of the diagram.
+        #[arg(long, short = 'j')]
+        json: bool,
+    },
+
+    /// Explain which rules put a window where it is.
+    ///
+    /// Lists the ignore rules in effect for the window's workspace, the
+    /// display and workspace rules matching it, and the fallback used when no
+    /// rule put it on its workspace. Window IDs are listed by
+    /// `stache tiling query windows`.
+    #[command(after_long_help = r#"Examples:
+  stache tiling explain 12345
+  stache tiling explain 12345 --json"#)]
+    Explain {
+        /// ID of the tracked window.
+        window_id: u32,
+
+        /// Output in JSON format instead of human-readable text.
+        #[arg(long, short = 'j')]
+        json: bool,
+    },
+
+    /// Debugging helpers for the tracked tiling state.
+    Debug {
+        /// Debug subcommand.
+        #[command(subcommand)]
+        command: TilingDebugCommands,
+    },
+}
+
+/// Tiling gaps subcommands.
+#[derive(Subcommand, Debug)]
+#[command(next_display_order = None)]
+pub enum TilingGapsCommands {
+    /// Toggle gaps off or back on and re-apply the layout.
+    ///
+    /// Lays windows out edge to edge, leaving room for the bar, until toggled
+    /// again. Applies to the focused workspace unless `--all` is given. The
+    /// config is left untouched.
+    #[command(after_long_help = r#"Examples:
+  stache tiling gaps toggle
+  stache tiling gaps toggle --all"#)]
+    Toggle {
+        /// Toggle gaps for every workspace instead of the focused one.
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// Drop gap adjustments made with `--inner` and `--outer` on all screens.
+    Reset,
+}
+
+/// Tiling debug subcommands.
+#[derive(Subcommand, Debug)]
+#[command(next_display_order = None)]
+pub enum TilingDebugCommands {
+    /// List phantom windows.
+    ///
+    /// Phantom windows are still tracked, but macOS no longer has an
+    /// accessibility element or a window server entry for them, usually
+    /// because a destroy event was missed. They keep a slot in the layout
+    /// without anything on screen.
+    #[command(after_long_help = r#"Examples:
+  stache tiling debug windows
+  stache tiling debug windows --prune-phantoms
+  stache tiling debug windows --json"#)]
+    Windows {
+        /// Untrack the phantom windows and re-apply the layout.
+        #[arg(long)]
+        prune_phantoms: bool,
+
+        /// Output in JSON format instead of table format.
+        #[arg(long, short = 'j')]
+        json: bool,
+    },
+
+    /// Show internal performance counters.
+    ///
+    /// Counts accessibility calls, computed and skipped layouts, animations
+    /// and processed events since launch or the last `--reset`, and lists how
+    /// long the most recent layout computations took.
+    #[command(after_long_help = r#"Examples:
+  stache tiling debug metrics
+  stache tiling debug metrics --reset
+  stache tiling debug metrics --json"#)]
+    Metrics {
+        /// Reset the counters to zero after reading them.
+        #[arg(long)]
+        reset: bool,
+
+        /// Output in JSON format instead of table format.
+        #[arg(long, short = 'j')]
+        json: bool,
+    },
 }
 
 /// Tiling query subcommands.
@@ -122,21 +318,47 @@ pub enum TilingQueryCommands {
 /// Tiling window command arguments.
 ///
 /// Multiple operations can be combined in a single command.
-/// Operations are executed in order: focus -> swap -> preset -> resize -> send.
+/// Operations are executed in order: focus -> swap -> promote/demote -> preset ->
+/// place -> resize -> send.
 #[derive(Debug, clap::Args)]
 #[command(after_long_help = r#"Examples:
   stache tiling window --focus left                            # Focus window to the left
+  stache tiling window --focus mru                             # Focus the previous window
+  stache tiling window --focus global-mru                      # Back to the last window anywhere
+  stache tiling window --focus app-next                        # Next window of the same app
   stache tiling window --swap down                             # Swap with window below
+  stache tiling window --promote                               # Move one place up the stack
+  stache tiling window --place 0,0,50%,100%                    # Float at an exact frame
+  stache tiling window --place 10%,10%,800,600 --place-gaps    # Same, inside the outer gaps
   stache tiling window --resize width 100                      # Increase width by 100px
   stache tiling window --resize width 100 --resize height 50   # Resize both dimensions
   stache tiling window --swap right --resize width 150         # Swap then resize
-  stache tiling window --send-to-screen main                   # Send to main screen"#)]
+  stache tiling window --send-to-screen main                   # Send to main screen
+  stache tiling window --send-to-screen left                   # Throw to the screen on the left
+  stache tiling window --send-to-workspace web --no-follow     # Send away, stay put
+  stache tiling window --send-to-workspace-index 2             # Send to workspace 2 of this screen
+  stache tiling window --also-workspace comms                  # Show in 'comms' too
+  stache tiling window --always-on-top toggle                  # Keep above other windows
+  stache tiling window --opacity 70                            # Make semi-transparent
+  stache tiling window --minimize                              # Minimize to the Dock
+  stache tiling window --restore                               # Bring the last one back
+  stache tiling window --move-mode                             # Move with the arrow keys"#)]
 pub struct TilingWindowArgs {
     /// Focus a window in a direction or by ID.
     ///
     /// Direction: up, down, left, right, previous, next.
     /// Or specify a window ID directly.
-    #[arg(long, value_name = "DIRECTION|WINDOW_ID")]
+    ///
+    /// `mru` focuses the most recently used other window in the workspace.
+    /// Repeating it within a second walks further back, like alt-tab.
+    /// `global-mru` focuses the last used window in any workspace, switching
+    /// to its workspace if it is hidden.
+    /// `app-next` and `app-previous` cycle through the focused app's windows
+    /// in the workspace.
+    #[arg(
+        long,
+        value_name = "DIRECTION|WINDOW_ID|mru|global-mru|app-next|app-previous"
+    )]
     pub focus: Option<String>,
 
     /// Swap focused window with another in a direction.
@@ -145,12 +367,46 @@ pub struct TilingWindowArgs {
     #[arg(long, value_name = "DIRECTION", value_enum)]
     pub swap: Option<Direction>,
 
+    /// Move the focused window one position earlier in the stack.
+    ///
+    /// The window keeps its size in split layouts. At the start of the stack
+    /// it stays put, or wraps to the end with `tiling.wrapStackMoves`.
+    #[arg(long)]
+    pub promote: bool,
+
+    /// Move the focused window one position later in the stack.
+    ///
+    /// The window keeps its size in split layouts. At the end of the stack
+    /// it stays put, or wraps to the start with `tiling.wrapStackMoves`.
+    #[arg(long, conflicts_with = "promote")]
+    pub demote: bool,
+
     /// Apply a floating preset to the focused window.
     ///
-    /// Uses a preset defined in the configuration file.
+    /// Uses a preset defined in the configuration file, or one of the built-in
+    /// presets: `center`, `center-large`, `under-cursor`, `maximize`,
+    /// `left-half`, `right-half`, `top-half`, `bottom-half`, `top-left`,
+    /// `top-right`, `bottom-left`, `bottom-right`, `left-third`,
+    /// `left-two-thirds`, `right-third` or `right-two-thirds`.
+    ///
+    /// A tiled window is made floating first. Repeating `left-half` or
+    /// `right-half` cycles through that side's thirds.
     #[arg(long, value_name = "PRESET_NAME")]
     pub preset: Option<String>,
 
+    /// Set the focused window's frame exactly, as `x,y,width,height`.
+    ///
+    /// Each value is pixels or a percentage of the screen's visible frame,
+    /// with positions measured from its top-left corner. The frame is clamped
+    /// to the screen, and a tiled window is made floating first. Prints the
+    /// frame applied as JSON.
+    #[arg(long, value_name = "X,Y,W,H", value_parser = parse_placement)]
+    pub place: Option<[DimensionValue; 4]>,
+
+    /// Place the window inside the outer gaps instead of the whole screen.
+    #[arg(long = "place-gaps", requires = "place")]
+    pub place_gaps: bool,
+
     /// Resize the focused window.
     ///
     /// Specify dimension (width/height) and amount in pixels.
@@ -161,29 +417,137 @@ pub struct TilingWindowArgs {
 
     /// Send focused window to another screen.
     ///
-    /// Target: main, secondary, or screen name.
+    /// Target: main, secondary, a role from `tiling.screenRoles`, screen name,
+    /// or a direction from the focused screen (left, right, up, down, next,
+    /// previous).
     #[arg(long = "send-to-screen", value_name = "SCREEN")]
     pub send_to_screen: Option<String>,
 
     /// Send focused window to another workspace.
     ///
-    /// The window will be hidden if the target workspace is not visible.
-    #[arg(long = "send-to-workspace", value_name = "WORKSPACE")]
+    /// Switches to the target workspace and focuses the window, unless
+    /// `--no-follow` is given.
+    #[arg(
+        long = "send-to-workspace",
+        value_name = "WORKSPACE",
+        group = "send_workspace"
+    )]
     pub send_to_workspace: Option<String>,
+
+    /// Send focused window to the Nth workspace of the focused screen.
+    ///
+    /// Workspaces are numbered from 1 in config order. Does nothing if the
+    /// screen has fewer workspaces. Follows the window like
+    /// `--send-to-workspace`.
+    #[arg(
+        long = "send-to-workspace-index",
+        value_name = "N",
+        group = "send_workspace",
+        value_parser = clap::value_parser!(u8).range(1..)
+    )]
+    pub send_to_workspace_index: Option<u8>,
+
+    /// Also show the focused window in another workspace, or stop showing it there.
+    ///
+    /// The window keeps its own workspace and appears in both, laid out in
+    /// whichever is visible. Run again with the same workspace to undo.
+    #[arg(long = "also-workspace", value_name = "WORKSPACE")]
+    pub also_workspace: Option<String>,
+
+    /// Stay on the current workspace when sending a window to another one.
+    ///
+    /// The window will be hidden if the target workspace is not visible.
+    #[arg(long = "no-follow", requires = "send_workspace")]
+    pub no_follow: bool,
+
+    /// Keep the focused window above normal windows.
+    ///
+    /// `toggle` flips the current state. The window still hides with its
+    /// workspace, and goes back to the normal level when tiling is disabled.
+    ///
+    /// macOS only lets an app change the level of its own windows. Other apps'
+    /// windows need a scripting addition loaded into the Dock, which requires
+    /// System Integrity Protection to be partially disabled; without it the
+    /// command fails.
+    #[arg(long = "always-on-top", value_name = "ACTION", value_parser = ["toggle"])]
+    pub always_on_top: Option<String>,
+
+    /// Set the opacity of the focused window, in percent.
+    ///
+    /// The opacity is kept across layout and focus changes. 100 restores
+    /// full opacity, as does disabling tiling.
+    ///
+    /// Like `--always-on-top`, changing other apps' windows requires a
+    /// scripting addition loaded into the Dock, with System Integrity
+    /// Protection partially disabled; without it the command fails.
+    #[arg(long, value_name = "0-100", value_parser = clap::value_parser!(u8).range(0..=100))]
+    pub opacity: Option<u8>,
+
+    /// Minimize the focused window to the Dock.
+    #[arg(long)]
+    pub minimize: bool,
+
+    /// Restore the most recently minimized window of the focused workspace.
+    ///
+    /// Repeat to restore windows in the reverse order they were minimized.
+    /// The window goes back to its prior position in the layout.
+    #[arg(long, conflicts_with = "minimize")]
+    pub restore: bool,
+
+    /// Move the focused window with the arrow keys.
+    ///
+    /// Arrows nudge a floating window by `tiling.moveMode.step` pixels, or
+    /// swap a tiled window with its neighbor. Enter keeps the new position,
+    /// Escape puts the window back. The mode ends by itself after
+    /// `tiling.moveMode.timeout` seconds without a key press.
+    #[arg(long = "move-mode")]
+    pub move_mode: bool,
+}
+
+/// Tiling app command arguments.
+#[derive(Debug, clap::Args)]
+#[command(after_long_help = r#"Examples:
+  stache tiling app --gather com.apple.Safari   # Make Safari windows adjacent
+  stache tiling app --gather 4242               # Same, by process ID"#)]
+pub struct TilingAppArgs {
+    /// Make the windows of an app adjacent in the focused workspace.
+    ///
+    /// Accepts a bundle ID, app name or process ID. Windows stay in their
+    /// workspace; only their order changes, so directional focus steps
+    /// through them one after another.
+    #[arg(long, value_name = "APP")]
+    pub gather: Option<String>,
 }
 
 /// Tiling workspace command arguments.
 ///
 /// Multiple operations can be combined in a single command.
-/// Operations are executed in order: focus -> layout -> balance -> send.
+/// Operations are executed in order: focus screen -> focus -> gather -> layout -> balance -> send -> swap screens.
 #[derive(Debug, clap::Args)]
 #[command(after_long_help = r#"Examples:
   stache tiling workspace --balance                    # Balance windows in focused workspace
   stache tiling workspace --focus coding               # Switch to 'coding' workspace
+  stache tiling workspace --focus-index 2              # Switch to workspace 2 of this screen
+  stache tiling workspace --focus-screen right         # Focus the screen on the right
+  stache tiling workspace --gather                     # Bring every window here
+  stache tiling workspace --gather Safari              # Bring every Safari window here
   stache tiling workspace --layout dwindle                 # Use DWINDLE layout
   stache tiling workspace --layout dwindle --balance       # Change layout then balance
-  stache tiling workspace --send-to-screen main        # Move workspace to main screen"#)]
+  stache tiling workspace --layout-toggle              # Toggle monocle and back
+  stache tiling workspace --layout-auto                # Follow tiling.autoLayout again
+  stache tiling workspace --send-to-screen main        # Move workspace to main screen
+  stache tiling workspace --swap-screens               # Swap main and secondary workspaces
+  stache tiling workspace --swap-screens main DELL     # Swap workspaces of two screens"#)]
 pub struct TilingWorkspaceArgs {
+    /// Focus the visible workspace on another screen.
+    ///
+    /// Target: main, secondary, a role from `tiling.screenRoles`, screen name,
+    /// or a direction from the focused screen (left, right, up, down, next,
+    /// previous). Does nothing if there
+    /// is no screen in that direction.
+    #[arg(long = "focus-screen", value_name = "SCREEN")]
+    pub focus_screen: Option<String>,
+
     /// Focus a workspace by name.
     ///
     /// Switches to the specified workspace, hiding windows from
@@ -191,12 +555,45 @@ pub struct TilingWorkspaceArgs {
     #[arg(long, value_name = "WORKSPACE")]
     pub focus: Option<String>,
 
+    /// Focus the Nth workspace of the focused screen.
+    ///
+    /// Workspaces are numbered from 1 in config order. Does nothing if the
+    /// screen has fewer workspaces.
+    #[arg(
+        long = "focus-index",
+        value_name = "N",
+        conflicts_with = "focus",
+        value_parser = clap::value_parser!(u8).range(1..)
+    )]
+    pub focus_index: Option<u8>,
+
+    /// Move every window into the focused workspace.
+    ///
+    /// With an app name or bundle ID, only that app's windows are moved.
+    /// Split ratios are reset so the windows share the workspace evenly.
+    #[arg(long, value_name = "APP", num_args = 0..=1)]
+    pub gather: Option<Option<String>>,
+
     /// Change the layout of the focused workspace.
     ///
     /// Layout: dwindle, split, split-vertical, split-horizontal, monocle, master, grid, floating.
     #[arg(long, value_name = "LAYOUT", value_enum)]
     pub layout: Option<CliLayoutType>,
 
+    /// Toggle the focused workspace to the alternate layout and back.
+    ///
+    /// Switches to `tiling.layoutToggleAlternate` (monocle by default),
+    /// and toggling again restores the previous layout and window sizes.
+    #[arg(long = "layout-toggle", conflicts_with = "layout")]
+    pub layout_toggle: bool,
+
+    /// Let `tiling.autoLayout` pick the focused workspace's layout again.
+    ///
+    /// Setting a layout by hand pins it, and this hands it back to the
+    /// layouts configured by window count.
+    #[arg(long = "layout-auto", conflicts_with_all = ["layout", "layout_toggle"])]
+    pub layout_auto: bool,
+
     /// Balance windows in the focused workspace.
     ///
     /// Resets all window size ratios to their default values,
@@ -206,9 +603,102 @@ pub struct TilingWorkspaceArgs {
 
     /// Send focused workspace to another screen.
     ///
-    /// Target: main, secondary, or screen name.
+    /// Target: main, secondary, a role from `tiling.screenRoles`, screen name,
+    /// or a direction from the focused screen (left, right, up, down, next,
+    /// previous).
     #[arg(long = "send-to-screen", value_name = "SCREEN")]
     pub send_to_screen: Option<String>,
+
+    /// Swap the visible workspaces of two screens.
+    ///
+    /// Screens: main, secondary, or screen name. Defaults to main and secondary.
+    #[arg(long = "swap-screens", value_names = ["SCREEN_A", "SCREEN_B"], num_args = 0..=2)]
+    pub swap_screens: Option<Vec<String>>,
+}
+
+impl TilingCommands {
+    /// Returns whether the command talks to the running app.
+    ///
+    /// `tiling query` without a subcommand only prints help.
+    #[must_use]
+    pub const fn requires_app(&self) -> bool { !matches!(self, Self::Query { command: None, .. }) }
+}
+
+/// Execute screen commands.
+fn execute_screen(center_cursor: bool) -> Result<(), StacheError> {
+    if !center_cursor {
+        return Err(StacheError::InvalidArguments(
+            "No screen operation specified. Use --help for available options.".to_string(),
+        ));
+    }
+
+    ipc::send_notification(&StacheNotification::TilingCenterCursor);
+    Ok(())
+}
+
+/// Execute gap commands.
+fn execute_gaps(
+    inner: Option<i32>,
+    outer: Option<i32>,
+    command: Option<&TilingGapsCommands>,
+) -> Result<(), StacheError> {
+    let notification = match command {
+        Some(TilingGapsCommands::Toggle { all }) => {
+            StacheNotification::TilingToggleGaps { all: *all }
+        }
+        Some(TilingGapsCommands::Reset) => StacheNotification::TilingResetGaps,
+        None if inner.is_some() || outer.is_some() => StacheNotification::TilingAdjustGaps {
+            inner: inner.unwrap_or(0),
+            outer: outer.unwrap_or(0),
+        },
+        None => {
+            return Err(StacheError::InvalidArguments(
+                "No gaps operation specified. Use --help for available options.".to_string(),
+            ));
+        }
+    };
+
+    ipc::send_notification(&notification);
+    Ok(())
+}
+
+/// Execute master commands.
+///
+/// Sets the master position of the focused workspace, or prints it when no
+/// position is given.
+fn execute_master(position: Option<CliMasterPosition>) -> Result<(), StacheError> {
+    if let Some(position) = position {
+        ipc::send_notification(&StacheNotification::TilingSetMasterPosition(
+            position.as_str().to_string(),
+        ));
+        return Ok(());
+    }
+
+    let query = IpcQuery::Workspaces {
+        screen: None,
+        focused_screen: true,
+    };
+    let response = match ipc_socket::send_query(query) {
+        Ok(r) => r,
+        Err(IpcError::AppNotRunning) => return Err(StacheError::AppNotRunning),
+        Err(e) => return Err(StacheError::IpcError(e.to_string())),
+    };
+
+    let data = match response {
+        IpcResponse::Success { data } => data,
+        IpcResponse::Error { error } => return Err(StacheError::TilingError(error)),
+    };
+
+    let position = data
+        .as_array()
+        .and_then(|workspaces| {
+            workspaces.iter().find(|ws| ws["isFocused"].as_bool().unwrap_or(false))
+        })
+        .and_then(|ws| ws["masterPosition"].as_str())
+        .ok_or_else(|| StacheError::TilingError("No focused workspace".to_string()))?;
+
+    println!("{position}");
+    Ok(())
 }
 
 /// Execute tiling subcommands.
@@ -219,6 +709,46 @@ pub fn execute(cmd: &TilingCommands) -> Result<(), StacheError> {
         }
         TilingCommands::Window(args) => execute_window(args),
         TilingCommands::Workspace(args) => execute_workspace(args),
+        TilingCommands::App(args) => execute_app(args),
+        TilingCommands::Screen { center_cursor } => execute_screen(*center_cursor),
+        TilingCommands::Gaps { inner, outer, command } => {
+            execute_gaps(*inner, *outer, command.as_ref())
+        }
+        TilingCommands::Master { position } => execute_master(*position),
+        TilingCommands::Pause => {
+            ipc::send_notification(&StacheNotification::TilingPause);
+            Ok(())
+        }
+        TilingCommands::Resume => {
+            ipc::send_notification(&StacheNotification::TilingResume);
+            Ok(())
+        }
+        TilingCommands::Reconcile => {
+            ipc::send_notification(&StacheNotification::TilingReconcile);
+            Ok(())
+        }
+        TilingCommands::Restart => {
+            ipc::send_notification(&StacheNotification::TilingRestart);
+            Ok(())
+        }
+        TilingCommands::Tree { workspace, json } => {
+            execute_tree(workspace.as_deref(), *json);
+            Ok(())
+        }
+        TilingCommands::Explain { window_id, json } => {
+            execute_explain(*window_id, *json);
+            Ok(())
+        }
+        TilingCommands::Debug { command } => match command {
+            TilingDebugCommands::Windows { prune_phantoms, json } => {
+                execute_debug_windows(*json, *prune_phantoms);
+                Ok(())
+            }
+            TilingDebugCommands::Metrics { reset, json } => {
+                execute_debug_metrics(*json, *reset);
+                Ok(())
+            }
+        },
     }
 }
 
@@ -736,116 +1266,631 @@ fn execute_query_apps(json: bool, _detailed: bool) {
     }
 }
 
-/// Execute tiling window commands.
-///
-/// Operations are executed in order: focus -> swap -> preset -> resize -> send.
-/// Multiple operations can be combined in a single command.
-#[allow(clippy::useless_let_if_seq)] // Clearer to track operation state this way
-fn execute_window(args: &TilingWindowArgs) -> Result<(), StacheError> {
-    let mut has_operation = false;
-
-    // 1. Focus (changes which window we're operating on)
-    if let Some(target) = &args.focus {
-        ipc::send_notification(&StacheNotification::TilingWindowFocus(target.clone()));
-        has_operation = true;
+/// Execute tiling debug windows command.
+#[allow(clippy::cast_possible_truncation)]
+fn execute_debug_windows(json: bool, prune: bool) {
+    #[derive(Tabled)]
+    struct PhantomRow {
+        #[tabled(rename = "ID")]
+        id: u32,
+        #[tabled(rename = "App")]
+        app: String,
+        #[tabled(rename = "Title")]
+        title: String,
+        #[tabled(rename = "Workspace")]
+        workspace: String,
+        #[tabled(rename = "Pruned")]
+        pruned: String,
     }
 
-    // 2. Swap position with another window
-    if let Some(direction) = &args.swap {
-        ipc::send_notification(&StacheNotification::TilingWindowSwap(
-            format!("{direction:?}").to_lowercase(),
-        ));
-        has_operation = true;
-    }
+    let response = match ipc_socket::send_query(IpcQuery::PhantomWindows { prune }) {
+        Ok(r) => r,
+        Err(IpcError::AppNotRunning) => {
+            if json {
+                println!(r#"{{"error":"Stache app is not running"}}"#);
+            } else {
+                println!("{}", "Stache app is not running.".red());
+            }
+            return;
+        }
+        Err(e) => {
+            if json {
+                println!(r#"{{"error":"{e}"}}"#);
+            } else {
+                println!("{} {e}", "Error:".red());
+            }
+            return;
+        }
+    };
 
-    // 3. Apply floating preset
-    if let Some(name) = &args.preset {
-        ipc::send_notification(&StacheNotification::TilingWindowPreset(name.clone()));
-        has_operation = true;
-    }
+    match response {
+        IpcResponse::Success { data } => {
+            if json {
+                output::print_highlighted_json(&data);
+            } else {
+                let windows: Vec<serde_json::Value> =
+                    serde_json::from_value(data).unwrap_or_default();
 
-    // 4. Resize (can be multiple, collected as pairs in a flat Vec)
-    if !args.resize.is_empty() {
-        // Process resize args in pairs: [dim1, amt1, dim2, amt2, ...]
-        for pair in args.resize.chunks(2) {
-            if pair.len() == 2 {
-                let dimension = &pair[0];
-                let amount = &pair[1];
-                // Validate dimension and amount
-                if !["width", "height"].contains(&dimension.to_lowercase().as_str()) {
-                    return Err(StacheError::InvalidArguments(format!(
-                        "Invalid resize dimension '{dimension}'. Must be 'width' or 'height'."
-                    )));
+                if windows.is_empty() {
+                    println!("{}", "No phantom windows found.".dimmed());
+                    return;
                 }
-                let amount_i32: i32 = amount.parse().map_err(|_| {
-                    StacheError::InvalidArguments(format!(
-                        "Invalid resize amount '{amount}'. Must be an integer."
-                    ))
-                })?;
-                ipc::send_notification(&StacheNotification::TilingWindowResize {
-                    dimension: dimension.to_lowercase(),
-                    amount: amount_i32,
-                });
-            }
-        }
-        has_operation = true;
-    }
 
-    // 5. Send to screen
-    if let Some(screen) = &args.send_to_screen {
-        ipc::send_notification(&StacheNotification::TilingWindowSendToScreen(screen.clone()));
-        has_operation = true;
-    }
+                let count = windows.len();
+                println!("{}", format!("Phantom Windows ({count})").bold());
 
-    // 6. Send to workspace
-    if let Some(workspace) = &args.send_to_workspace {
-        ipc::send_notification(&StacheNotification::TilingWindowSendToWorkspace(
-            workspace.clone(),
-        ));
-        has_operation = true;
-    }
+                let rows: Vec<PhantomRow> = windows
+                    .iter()
+                    .map(|w| PhantomRow {
+                        id: w["id"].as_u64().unwrap_or(0) as u32,
+                        app: output::truncate(w["appName"].as_str().unwrap_or("?"), 20),
+                        title: output::truncate(
+                            w["title"].as_str().filter(|s| !s.is_empty()).unwrap_or("(no title)"),
+                            35,
+                        ),
+                        workspace: w["workspace"].as_str().unwrap_or("?").to_string(),
+                        pruned: output::format_bool(w["pruned"].as_bool().unwrap_or(false)),
+                    })
+                    .collect();
 
-    if has_operation {
-        Ok(())
-    } else {
+                let table = Table::new(rows)
+                    .with(Style::rounded())
+                    .with(Modify::new(Columns::one(0)).with(Alignment::right()))
+                    .with(Modify::new(Columns::last()).with(Alignment::center()))
+                    .to_string();
+
+                println!("{table}");
+
+                if !prune {
+                    println!("{}", "Run with --prune-phantoms to untrack them.".dimmed());
+                }
+            }
+        }
+        IpcResponse::Error { error } => {
+            if json {
+                println!(r#"{{"error":"{error}"}}"#);
+            } else {
+                println!("{} {error}", "Error:".red());
+            }
+        }
+    }
+}
+
+/// Execute tiling debug metrics command.
+fn execute_debug_metrics(json: bool, reset: bool) {
+    #[derive(Tabled)]
+    struct MetricRow {
+        #[tabled(rename = "Counter")]
+        counter: &'static str,
+        #[tabled(rename = "Value")]
+        value: u64,
+    }
+
+    #[derive(Tabled)]
+    struct LayoutTimingRow {
+        #[tabled(rename = "Workspace")]
+        workspace: String,
+        #[tabled(rename = "Layout")]
+        layout: String,
+        #[tabled(rename = "Windows")]
+        windows: u64,
+        #[tabled(rename = "Time (ms)")]
+        time: String,
+    }
+
+    let response = match ipc_socket::send_query(IpcQuery::Metrics { reset }) {
+        Ok(r) => r,
+        Err(IpcError::AppNotRunning) => {
+            if json {
+                println!(r#"{{"error":"Stache app is not running"}}"#);
+            } else {
+                println!("{}", "Stache app is not running.".red());
+            }
+            return;
+        }
+        Err(e) => {
+            if json {
+                println!(r#"{{"error":"{e}"}}"#);
+            } else {
+                println!("{} {e}", "Error:".red());
+            }
+            return;
+        }
+    };
+
+    match response {
+        IpcResponse::Success { data } => {
+            if json {
+                output::print_highlighted_json(&data);
+                return;
+            }
+
+            let rows: Vec<MetricRow> = [
+                ("AX calls", "axCalls"),
+                ("Layouts computed", "layoutsComputed"),
+                ("Layout cache hits", "layoutCacheHits"),
+                ("Layout cache misses", "layoutCacheMisses"),
+                ("Animations started", "animationsStarted"),
+                ("Events processed", "eventsProcessed"),
+            ]
+            .into_iter()
+            .map(|(counter, key)| MetricRow {
+                counter,
+                value: data[key].as_u64().unwrap_or(0),
+            })
+            .collect();
+
+            let table = Table::new(rows)
+                .with(Style::rounded())
+                .with(Modify::new(Columns::last()).with(Alignment::right()))
+                .to_string();
+
+            println!("{table}");
+
+            let timings: Vec<LayoutTimingRow> = data["recentLayouts"]
+                .as_array()
+                .map(|timings| {
+                    timings
+                        .iter()
+                        .map(|t| LayoutTimingRow {
+                            workspace: t["workspace"].as_str().unwrap_or("?").to_string(),
+                            layout: t["layout"].as_str().unwrap_or("?").to_string(),
+                            windows: t["windows"].as_u64().unwrap_or(0),
+                            time: format!(
+                                "{:.2}",
+                                t["durationUs"].as_f64().unwrap_or(0.0) / 1000.0
+                            ),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            if !timings.is_empty() {
+                println!("{}", format!("Recent Layouts ({})", timings.len()).bold());
+                let table = Table::new(timings)
+                    .with(Style::rounded())
+                    .with(Modify::new(Columns::new(2..)).with(Alignment::right()))
+                    .to_string();
+                println!("{table}");
+            }
+
+            if reset {
+                println!("{}", "Counters reset.".dimmed());
+            }
+        }
+        IpcResponse::Error { error } => {
+            if json {
+                println!(r#"{{"error":"{error}"}}"#);
+            } else {
+                println!("{} {error}", "Error:".red());
+            }
+        }
+    }
+}
+
+/// Execute tiling tree command.
+fn execute_tree(workspace: Option<&str>, json: bool) {
+    let response = match ipc_socket::send_query(IpcQuery::Tree {
+        workspace: workspace.map(str::to_string),
+    }) {
+        Ok(r) => r,
+        Err(IpcError::AppNotRunning) => {
+            if json {
+                println!(r#"{{"error":"Stache app is not running"}}"#);
+            } else {
+                println!("{}", "Stache app is not running.".red());
+            }
+            return;
+        }
+        Err(e) => {
+            if json {
+                println!(r#"{{"error":"{e}"}}"#);
+            } else {
+                println!("{} {e}", "Error:".red());
+            }
+            return;
+        }
+    };
+
+    match response {
+        IpcResponse::Success { data } => {
+            if json {
+                output::print_highlighted_json(&data);
+                return;
+            }
+
+            let windows: Vec<tree::TreeWindow> = data["windows"]
+                .as_array()
+                .map(|windows| {
+                    windows
+                        .iter()
+                        .map(|w| tree::TreeWindow {
+                            title: w["title"]
+                                .as_str()
+                                .filter(|s| !s.is_empty())
+                                .or_else(|| w["appName"].as_str())
+                                .unwrap_or("?")
+                                .to_string(),
+                            frame: serde_json::from_value(w["frame"].clone()).unwrap_or_default(),
+                            focused: w["isFocused"].as_bool().unwrap_or(false),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let name = data["workspace"].as_str().unwrap_or("?");
+            let layout = data["layout"].as_str().unwrap_or("?");
+            println!(
+                "{} {}",
+                format!("Workspace {name}").bold(),
+                format!("({layout}, {} windows)", windows.len()).dimmed()
+            );
+
+            let Some(screen) = serde_json::from_value(data["screenFrame"].clone()).ok() else {
+                println!("{}", "Screen of the workspace not found.".dimmed());
+                return;
+            };
+
+            for line in tree::render(screen, &windows) {
+                println!("{line}");
+            }
+        }
+        IpcResponse::Error { error } => {
+            if json {
+                println!(r#"{{"error":"{error}"}}"#);
+            } else {
+                println!("{} {error}", "Error:".red());
+            }
+        }
+    }
+}
+
+/// Execute tiling explain command.
+fn execute_explain(window_id: u32, json: bool) {
+    let response = match ipc_socket::send_query(IpcQuery::Explain { window_id }) {
+        Ok(r) => r,
+        Err(IpcError::AppNotRunning) => {
+            if json {
+                println!(r#"{{"error":"Stache app is not running"}}"#);
+            } else {
+                println!("{}", "Stache app is not running.".red());
+            }
+            return;
+        }
+        Err(e) => {
+            if json {
+                println!(r#"{{"error":"{e}"}}"#);
+            } else {
+                println!("{} {e}", "Error:".red());
+            }
+            return;
+        }
+    };
+
+    match response {
+        IpcResponse::Success { data } => {
+            if json {
+                output::print_highlighted_json(&data);
+                return;
+            }
+
+            let window = &data["window"];
+            let title = window["title"].as_str().filter(|s| !s.is_empty()).unwrap_or("(no title)");
+            println!(
+                "{} {}",
+                format!("Window {window_id}").bold(),
+                format!("({}, {title})", window["appId"].as_str().unwrap_or("?")).dimmed()
+            );
+            println!(
+                "Workspace: {} on {}",
+                data["workspace"].as_str().unwrap_or("?"),
+                data["screen"].as_str().unwrap_or("?")
+            );
+
+            let rule = &data["workspaceRule"];
+            if rule.is_null() {
+                println!("Workspace rule: {}", "none".dimmed());
+            } else {
+                println!(
+                    "Workspace rule: {} #{} {}",
+                    rule["workspace"].as_str().unwrap_or("?"),
+                    rule["ruleIndex"],
+                    rule["rule"]
+                );
+            }
+
+            let display = &data["displayRule"];
+            if display.is_null() {
+                println!("Display rule: {}", "none".dimmed());
+            } else {
+                println!(
+                    "Display rule: #{} pins to {}",
+                    display["index"],
+                    display["screen"].as_str().unwrap_or("?")
+                );
+            }
+
+            let ignore = data["ignoreRules"].as_array().cloned().unwrap_or_default();
+            println!(
+                "Ignore rules: {} of {} match",
+                data["ignoreMatches"],
+                ignore.len()
+            );
+            for rule in &ignore {
+                let matched = if rule["matched"].as_bool().unwrap_or(false) {
+                    "matches".yellow()
+                } else {
+                    "no match".dimmed()
+                };
+                println!("  #{} {} {matched}", rule["index"], rule["rule"]);
+            }
+
+            if let Some(fallback) = data["fallback"].as_str() {
+                println!("Fallback: {fallback}");
+            }
+        }
+        IpcResponse::Error { error } => {
+            if json {
+                println!(r#"{{"error":"{error}"}}"#);
+            } else {
+                println!("{} {error}", "Error:".red());
+            }
+        }
+    }
+}
+
+/// Execute tiling window commands.
+///
+/// Operations are executed in order: focus -> swap -> preset -> resize -> send.
+/// Multiple operations can be combined in a single command.
+#[allow(clippy::useless_let_if_seq)] // Clearer to track operation state this way
+fn execute_window(args: &TilingWindowArgs) -> Result<(), StacheError> {
+    let mut has_operation = false;
+
+    // 1. Focus (changes which window we're operating on)
+    if let Some(target) = &args.focus {
+        ipc::send_notification(&StacheNotification::TilingWindowFocus(target.clone()));
+        has_operation = true;
+    }
+
+    // 2. Swap position with another window
+    if let Some(direction) = &args.swap {
+        ipc::send_notification(&StacheNotification::TilingWindowSwap(
+            format!("{direction:?}").to_lowercase(),
+        ));
+        has_operation = true;
+    }
+
+    // 3. Move in the stack
+    if args.promote {
+        ipc::send_notification(&StacheNotification::TilingWindowPromote);
+        has_operation = true;
+    } else if args.demote {
+        ipc::send_notification(&StacheNotification::TilingWindowDemote);
+        has_operation = true;
+    }
+
+    // 4. Apply floating preset
+    if let Some(name) = &args.preset {
+        ipc::send_notification(&StacheNotification::TilingWindowPreset(name.clone()));
+        has_operation = true;
+    }
+
+    // 5. Place at an exact frame, waiting for the frame applied
+    if let Some([x, y, width, height]) = &args.place {
+        send_window_query(IpcQuery::PlaceWindow {
+            x: x.clone(),
+            y: y.clone(),
+            width: width.clone(),
+            height: height.clone(),
+            gaps: args.place_gaps,
+        })?;
+        has_operation = true;
+    }
+
+    // 6. Resize (can be multiple, collected as pairs in a flat Vec)
+    if !args.resize.is_empty() {
+        // Process resize args in pairs: [dim1, amt1, dim2, amt2, ...]
+        for pair in args.resize.chunks(2) {
+            if pair.len() == 2 {
+                let dimension = &pair[0];
+                let amount = &pair[1];
+                // Validate dimension and amount
+                if !["width", "height"].contains(&dimension.to_lowercase().as_str()) {
+                    return Err(StacheError::InvalidArguments(format!(
+                        "Invalid resize dimension '{dimension}'. Must be 'width' or 'height'."
+                    )));
+                }
+                let amount_i32: i32 = amount.parse().map_err(|_| {
+                    StacheError::InvalidArguments(format!(
+                        "Invalid resize amount '{amount}'. Must be an integer."
+                    ))
+                })?;
+                ipc::send_notification(&StacheNotification::TilingWindowResize {
+                    dimension: dimension.to_lowercase(),
+                    amount: amount_i32,
+                });
+            }
+        }
+        has_operation = true;
+    }
+
+    // 7. Send to screen
+    if let Some(screen) = &args.send_to_screen {
+        ipc::send_notification(&StacheNotification::TilingWindowSendToScreen(screen.clone()));
+        has_operation = true;
+    }
+
+    // 8. Send to workspace
+    if let Some(workspace) = &args.send_to_workspace {
+        ipc::send_notification(&StacheNotification::TilingWindowSendToWorkspace {
+            workspace: workspace.clone(),
+            follow: !args.no_follow,
+        });
+        has_operation = true;
+    } else if let Some(index) = args.send_to_workspace_index {
+        ipc::send_notification(&StacheNotification::TilingWindowSendToWorkspaceIndex {
+            index: usize::from(index),
+            follow: !args.no_follow,
+        });
+        has_operation = true;
+    }
+
+    // 9. Also show in another workspace
+    if let Some(workspace) = &args.also_workspace {
+        ipc::send_notification(&StacheNotification::TilingWindowAlsoWorkspace(workspace.clone()));
+        has_operation = true;
+    }
+
+    // 10. Always on top
+    if args.always_on_top.is_some() {
+        send_window_query(IpcQuery::ToggleAlwaysOnTop)?;
+        has_operation = true;
+    }
+
+    // 11. Opacity
+    if let Some(opacity) = args.opacity {
+        send_window_query(IpcQuery::SetWindowOpacity { opacity })?;
+        has_operation = true;
+    }
+
+    // 12. Minimize or restore
+    if args.minimize {
+        ipc::send_notification(&StacheNotification::TilingWindowMinimize);
+        has_operation = true;
+    } else if args.restore {
+        ipc::send_notification(&StacheNotification::TilingWindowRestore);
+        has_operation = true;
+    }
+
+    // 13. Move with the arrow keys
+    if args.move_mode {
+        ipc::send_notification(&StacheNotification::TilingWindowMoveMode);
+        has_operation = true;
+    }
+
+    if has_operation {
+        Ok(())
+    } else {
         Err(StacheError::InvalidArguments(
             "No window operation specified. Use --help for available options.".to_string(),
         ))
     }
 }
 
+/// Parses `x,y,width,height` for `--place`, each in pixels or a percentage.
+fn parse_placement(value: &str) -> Result<[DimensionValue; 4], String> {
+    let parts = value
+        .split(',')
+        .map(str::parse::<DimensionValue>)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    <[DimensionValue; 4]>::try_from(parts)
+        .map_err(|_| format!("expected x,y,width,height, got '{value}'"))
+}
+
+/// Sends a window query that waits for the result, and prints it.
+fn send_window_query(query: IpcQuery) -> Result<(), StacheError> {
+    let response = match ipc_socket::send_query(query) {
+        Ok(r) => r,
+        Err(IpcError::AppNotRunning) => return Err(StacheError::AppNotRunning),
+        Err(e) => return Err(StacheError::IpcError(e.to_string())),
+    };
+
+    match response {
+        IpcResponse::Success { data } => {
+            output::print_highlighted_json(&data);
+            Ok(())
+        }
+        IpcResponse::Error { error } => Err(StacheError::TilingError(error)),
+    }
+}
+
+/// Execute tiling app commands.
+fn execute_app(args: &TilingAppArgs) -> Result<(), StacheError> {
+    let Some(app) = &args.gather else {
+        return Err(StacheError::InvalidArguments(
+            "No app operation specified. Use --help for available options.".to_string(),
+        ));
+    };
+
+    ipc::send_notification(&StacheNotification::TilingAppGather(app.clone()));
+    Ok(())
+}
+
 /// Execute tiling workspace commands.
 ///
-/// Operations are executed in order: focus -> layout -> balance -> send.
+/// Operations are executed in order: focus screen -> focus -> gather -> layout -> balance -> send -> swap screens.
 /// Multiple operations can be combined in a single command.
 #[allow(clippy::useless_let_if_seq)] // Clearer to track operation state this way
 fn execute_workspace(args: &TilingWorkspaceArgs) -> Result<(), StacheError> {
     let mut has_operation = false;
 
-    // 1. Focus workspace (switch to it first)
+    // 1. Focus screen
+    if let Some(screen) = &args.focus_screen {
+        ipc::send_notification(&StacheNotification::TilingFocusScreen(screen.clone()));
+        has_operation = true;
+    }
+
+    // 2. Focus workspace (switch to it before changing it)
     if let Some(workspace) = &args.focus {
         ipc::send_notification(&StacheNotification::TilingFocusWorkspace(workspace.clone()));
         has_operation = true;
+    } else if let Some(index) = args.focus_index {
+        ipc::send_notification(&StacheNotification::TilingFocusWorkspaceIndex(usize::from(
+            index,
+        )));
+        has_operation = true;
     }
 
-    // 2. Change layout
+    // 3. Gather windows into the focused workspace
+    if let Some(app) = &args.gather {
+        ipc::send_notification(&StacheNotification::TilingWorkspaceGather(app.clone()));
+        has_operation = true;
+    }
+
+    // 4. Change layout
     if let Some(layout) = &args.layout {
         ipc::send_notification(&StacheNotification::TilingSetLayout(layout.as_str().to_string()));
         has_operation = true;
+    } else if args.layout_toggle {
+        ipc::send_notification(&StacheNotification::TilingWorkspaceToggleLayout);
+        has_operation = true;
+    } else if args.layout_auto {
+        ipc::send_notification(&StacheNotification::TilingWorkspaceAutoLayout);
+        has_operation = true;
     }
 
-    // 3. Balance windows
+    // 5. Balance windows
     if args.balance {
         ipc::send_notification(&StacheNotification::TilingWorkspaceBalance);
         has_operation = true;
     }
 
-    // 4. Send to screen
+    // 6. Send to screen
     if let Some(screen) = &args.send_to_screen {
         ipc::send_notification(&StacheNotification::TilingWorkspaceSendToScreen(screen.clone()));
         has_operation = true;
     }
 
+    // 7. Swap the visible workspaces of two screens
+    if let Some(screens) = &args.swap_screens {
+        let (screen_a, screen_b) = match screens.as_slice() {
+            [] => ("main".to_string(), "secondary".to_string()),
+            [screen_a, screen_b] => (screen_a.clone(), screen_b.clone()),
+            _ => {
+                return Err(StacheError::InvalidArguments(
+                    "--swap-screens takes either no screens or two screens.".to_string(),
+                ));
+            }
+        };
+        ipc::send_notification(&StacheNotification::TilingWorkspaceSwapScreens {
+            screen_a,
+            screen_b,
+        });
+        has_operation = true;
+    }
+
     if has_operation {
         Ok(())
     } else {
@@ -1077,6 +2122,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_tiling_window_place_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--place", "0,10%,800,50%"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => {
+                let [x, y, width, height] = args.place.unwrap();
+                assert!(matches!(x, DimensionValue::Pixels(0)));
+                assert!(matches!(y, DimensionValue::Percentage(ref pct) if pct == "10%"));
+                assert!(matches!(width, DimensionValue::Pixels(800)));
+                assert!(matches!(height, DimensionValue::Percentage(ref pct) if pct == "50%"));
+                assert!(!args.place_gaps);
+            }
+            _ => panic!("Expected Window command"),
+        }
+
+        assert!(TestCli::try_parse_from(["test", "window", "--place", "0,0,800"]).is_err());
+        assert!(TestCli::try_parse_from(["test", "window", "--place", "0,0,wide,600"]).is_err());
+        assert!(TestCli::try_parse_from(["test", "window", "--place-gaps"]).is_err());
+    }
+
     #[test]
     fn test_tiling_window_resize_parse() {
         let cli = TestCli::try_parse_from(["test", "window", "--resize", "width", "100"]).unwrap();
@@ -1131,11 +2196,148 @@ mod tests {
         match cli.command {
             TilingCommands::Window(args) => {
                 assert_eq!(args.send_to_workspace, Some("coding".to_string()));
+                assert!(!args.no_follow);
             }
             _ => panic!("Expected Window command"),
         }
     }
 
+    #[test]
+    fn test_tiling_workspace_index_parse() {
+        let cli = TestCli::try_parse_from([
+            "test",
+            "window",
+            "--send-to-workspace-index",
+            "2",
+            "--no-follow",
+        ])
+        .unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => {
+                assert_eq!(args.send_to_workspace_index, Some(2));
+                assert!(args.no_follow);
+            }
+            _ => panic!("Expected Window command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "workspace", "--focus-index", "3"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => assert_eq!(args.focus_index, Some(3)),
+            _ => panic!("Expected Workspace command"),
+        }
+
+        // Indices start at 1, and a name and an index cannot be combined
+        assert!(TestCli::try_parse_from(["test", "workspace", "--focus-index", "0"]).is_err());
+        assert!(
+            TestCli::try_parse_from([
+                "test",
+                "window",
+                "--send-to-workspace",
+                "web",
+                "--send-to-workspace-index",
+                "1"
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_tiling_window_also_workspace_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--also-workspace", "comms"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => {
+                assert_eq!(args.also_workspace, Some("comms".to_string()));
+            }
+            _ => panic!("Expected Window command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_window_send_to_workspace_no_follow_parse() {
+        let cli = TestCli::try_parse_from([
+            "test",
+            "window",
+            "--send-to-workspace",
+            "coding",
+            "--no-follow",
+        ])
+        .unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => assert!(args.no_follow),
+            _ => panic!("Expected Window command"),
+        }
+
+        // --no-follow only applies when sending to a workspace
+        assert!(TestCli::try_parse_from(["test", "window", "--no-follow"]).is_err());
+    }
+
+    #[test]
+    fn test_tiling_window_always_on_top_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--always-on-top", "toggle"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => {
+                assert_eq!(args.always_on_top, Some("toggle".to_string()));
+            }
+            _ => panic!("Expected Window command"),
+        }
+
+        assert!(TestCli::try_parse_from(["test", "window", "--always-on-top", "maybe"]).is_err());
+    }
+
+    #[test]
+    fn test_tiling_window_opacity_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--opacity", "70"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => assert_eq!(args.opacity, Some(70)),
+            _ => panic!("Expected Window command"),
+        }
+
+        assert!(TestCli::try_parse_from(["test", "window", "--opacity", "101"]).is_err());
+    }
+
+    #[test]
+    fn test_tiling_window_minimize_restore_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--minimize"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => assert!(args.minimize && !args.restore),
+            _ => panic!("Expected Window command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "window", "--restore"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => assert!(args.restore && !args.minimize),
+            _ => panic!("Expected Window command"),
+        }
+
+        assert!(TestCli::try_parse_from(["test", "window", "--minimize", "--restore"]).is_err());
+    }
+
+    #[test]
+    fn test_tiling_window_promote_demote_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--promote"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => assert!(args.promote && !args.demote),
+            _ => panic!("Expected Window command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "window", "--demote"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => assert!(args.demote && !args.promote),
+            _ => panic!("Expected Window command"),
+        }
+
+        assert!(TestCli::try_parse_from(["test", "window", "--promote", "--demote"]).is_err());
+    }
+
+    #[test]
+    fn test_tiling_window_move_mode_parse() {
+        let cli = TestCli::try_parse_from(["test", "window", "--move-mode"]).unwrap();
+        match cli.command {
+            TilingCommands::Window(args) => assert!(args.move_mode),
+            _ => panic!("Expected Window command"),
+        }
+    }
+
     #[test]
     fn test_tiling_window_combined_operations_parse() {
         let cli = TestCli::try_parse_from([
@@ -1188,6 +2390,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_tiling_workspace_layout_toggle_parse() {
+        let cli = TestCli::try_parse_from(["test", "workspace", "--layout-toggle"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => {
+                assert!(args.layout_toggle);
+                assert!(args.layout.is_none());
+            }
+            _ => panic!("Expected Workspace command"),
+        }
+
+        assert!(
+            TestCli::try_parse_from(["test", "workspace", "--layout", "grid", "--layout-toggle"])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_tiling_workspace_layout_auto_parse() {
+        let cli = TestCli::try_parse_from(["test", "workspace", "--layout-auto"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => assert!(args.layout_auto),
+            _ => panic!("Expected Workspace command"),
+        }
+
+        assert!(
+            TestCli::try_parse_from(["test", "workspace", "--layout", "grid", "--layout-auto"])
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_tiling_workspace_balance_parse() {
         let cli = TestCli::try_parse_from(["test", "workspace", "--balance"]).unwrap();
@@ -1211,6 +2444,220 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_tiling_workspace_swap_screens_parse() {
+        let cli = TestCli::try_parse_from(["test", "workspace", "--swap-screens"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => assert_eq!(args.swap_screens, Some(vec![])),
+            _ => panic!("Expected Workspace command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "workspace", "--swap-screens", "main", "DELL"])
+            .unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => {
+                assert_eq!(
+                    args.swap_screens,
+                    Some(vec!["main".to_string(), "DELL".to_string()])
+                );
+            }
+            _ => panic!("Expected Workspace command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_workspace_focus_screen_parse() {
+        let cli = TestCli::try_parse_from(["test", "workspace", "--focus-screen", "left"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => {
+                assert_eq!(args.focus_screen, Some("left".to_string()));
+            }
+            _ => panic!("Expected Workspace command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_app_gather_parse() {
+        let cli = TestCli::try_parse_from(["test", "app", "--gather", "com.apple.Safari"]).unwrap();
+        match cli.command {
+            TilingCommands::App(args) => {
+                assert_eq!(args.gather, Some("com.apple.Safari".to_string()));
+            }
+            _ => panic!("Expected App command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "app"]).unwrap();
+        match cli.command {
+            TilingCommands::App(args) => assert!(execute_app(&args).is_err()),
+            _ => panic!("Expected App command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_master_parse() {
+        let cli = TestCli::try_parse_from(["test", "master"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Master { position: None }));
+
+        let cli = TestCli::try_parse_from(["test", "master", "--position", "top"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Master {
+            position: Some(CliMasterPosition::Top)
+        }));
+
+        assert!(TestCli::try_parse_from(["test", "master", "--position", "middle"]).is_err());
+    }
+
+    #[test]
+    fn test_tiling_screen_center_cursor_parse() {
+        let cli = TestCli::try_parse_from(["test", "screen", "--center-cursor"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Screen {
+            center_cursor: true
+        }));
+        assert!(cli.command.requires_app());
+
+        let cli = TestCli::try_parse_from(["test", "screen"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Screen {
+            center_cursor: false
+        }));
+    }
+
+    #[test]
+    fn test_tiling_pause_resume_parse() {
+        let cli = TestCli::try_parse_from(["test", "pause"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Pause));
+        assert!(cli.command.requires_app());
+
+        let cli = TestCli::try_parse_from(["test", "resume"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Resume));
+    }
+
+    #[test]
+    fn test_tiling_reconcile_parse() {
+        let cli = TestCli::try_parse_from(["test", "reconcile"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Reconcile));
+        assert!(cli.command.requires_app());
+    }
+
+    #[test]
+    fn test_tiling_restart_parse() {
+        let cli = TestCli::try_parse_from(["test", "restart"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Restart));
+        assert!(cli.command.requires_app());
+    }
+
+    #[test]
+    fn test_tiling_tree_parse() {
+        let cli = TestCli::try_parse_from(["test", "tree"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Tree {
+            workspace: None,
+            json: false
+        }));
+
+        let cli =
+            TestCli::try_parse_from(["test", "tree", "--workspace", "coding", "--json"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            TilingCommands::Tree { workspace: Some(ref w), json: true } if w == "coding"
+        ));
+    }
+
+    #[test]
+    fn test_tiling_explain_parse() {
+        let cli = TestCli::try_parse_from(["test", "explain", "12345"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Explain {
+            window_id: 12345,
+            json: false
+        }));
+        assert!(cli.command.requires_app());
+
+        assert!(TestCli::try_parse_from(["test", "explain"]).is_err());
+    }
+
+    #[test]
+    fn test_tiling_gaps_toggle_parse() {
+        let cli = TestCli::try_parse_from(["test", "gaps", "toggle"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Gaps {
+            command: Some(TilingGapsCommands::Toggle { all: false }),
+            ..
+        }));
+
+        let cli = TestCli::try_parse_from(["test", "gaps", "toggle", "--all"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Gaps {
+            command: Some(TilingGapsCommands::Toggle { all: true }),
+            ..
+        }));
+    }
+
+    #[test]
+    fn test_tiling_gaps_adjust_parse() {
+        let cli =
+            TestCli::try_parse_from(["test", "gaps", "--inner", "+4", "--outer", "-4"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Gaps {
+            inner: Some(4),
+            outer: Some(-4),
+            command: None,
+        }));
+
+        let cli = TestCli::try_parse_from(["test", "gaps", "reset"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Gaps {
+            inner: None,
+            outer: None,
+            command: Some(TilingGapsCommands::Reset),
+        }));
+
+        assert!(TestCli::try_parse_from(["test", "gaps", "--inner", "4", "reset"]).is_err());
+    }
+
+    #[test]
+    fn test_tiling_debug_windows_parse() {
+        let cli = TestCli::try_parse_from(["test", "debug", "windows"]).unwrap();
+        match cli.command {
+            TilingCommands::Debug {
+                command: TilingDebugCommands::Windows { prune_phantoms, json },
+            } => {
+                assert!(!prune_phantoms);
+                assert!(!json);
+            }
+            _ => panic!("Expected Debug Windows command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "debug", "windows", "--prune-phantoms", "-j"])
+            .unwrap();
+        match cli.command {
+            TilingCommands::Debug {
+                command: TilingDebugCommands::Windows { prune_phantoms, json },
+            } => {
+                assert!(prune_phantoms);
+                assert!(json);
+            }
+            _ => panic!("Expected Debug Windows command"),
+        }
+    }
+
+    #[test]
+    fn test_tiling_debug_metrics_parse() {
+        let cli = TestCli::try_parse_from(["test", "debug", "metrics", "--reset"]).unwrap();
+        assert!(matches!(cli.command, TilingCommands::Debug {
+            command: TilingDebugCommands::Metrics { reset: true, json: false },
+        }));
+    }
+
+    #[test]
+    fn test_tiling_workspace_gather_parse() {
+        let cli = TestCli::try_parse_from(["test", "workspace", "--gather"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => assert_eq!(args.gather, Some(None)),
+            _ => panic!("Expected Workspace command"),
+        }
+
+        let cli = TestCli::try_parse_from(["test", "workspace", "--gather", "Safari"]).unwrap();
+        match cli.command {
+            TilingCommands::Workspace(args) => {
+                assert_eq!(args.gather, Some(Some("Safari".to_string())));
+            }
+            _ => panic!("Expected Workspace command"),
+        }
+    }
+
     #[test]
     fn test_tiling_workspace_combined_operations_parse() {
         let cli =
@@ -140,6 +140,91 @@ impl CliLayoutType {
     }
 }
 
+/// Master window position for the master layout (CLI representation).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum CliMasterPosition {
+    /// Master window on the left.
+    Left,
+    /// Master window on the right.
+    Right,
+    /// Master window on top.
+    Top,
+    /// Master window on bottom.
+    Bottom,
+    /// Left on landscape screens, top on portrait screens.
+    Auto,
+}
+
+impl CliMasterPosition {
+    /// Converts to kebab-case string for IPC communication.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Left => "left",
+            Self::Right => "right",
+            Self::Top => "top",
+            Self::Bottom => "bottom",
+            Self::Auto => "auto",
+        }
+    }
+}
+
+/// Background material of the bar (CLI representation).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum CliBarMaterial {
+    /// No material, fully transparent.
+    None,
+    /// Window title bar material.
+    Titlebar,
+    /// Menu material, closest to the macOS menu bar.
+    Menu,
+    /// Popover material.
+    Popover,
+    /// Window sidebar material.
+    Sidebar,
+    /// Inline header and footer material.
+    HeaderView,
+    /// Sheet material.
+    Sheet,
+    /// Window background material.
+    WindowBackground,
+    /// Dark heads-up display material.
+    HudWindow,
+    /// Full-screen modal interface material.
+    FullScreenUi,
+    /// Tooltip material.
+    Tooltip,
+    /// Opaque content background material.
+    ContentBackground,
+    /// Material under window backgrounds.
+    UnderWindowBackground,
+    /// Material under a page background.
+    UnderPageBackground,
+}
+
+impl CliBarMaterial {
+    /// Converts to kebab-case string for IPC communication.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Titlebar => "titlebar",
+            Self::Menu => "menu",
+            Self::Popover => "popover",
+            Self::Sidebar => "sidebar",
+            Self::HeaderView => "header-view",
+            Self::Sheet => "sheet",
+            Self::WindowBackground => "window-background",
+            Self::HudWindow => "hud-window",
+            Self::FullScreenUi => "full-screen-ui",
+            Self::Tooltip => "tooltip",
+            Self::ContentBackground => "content-background",
+            Self::UnderWindowBackground => "under-window-background",
+            Self::UnderPageBackground => "under-page-background",
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -367,4 +452,21 @@ mod tests {
         assert_eq!(CliLayoutType::Grid.as_str(), "grid");
         assert_eq!(CliLayoutType::Floating.as_str(), "floating");
     }
+
+    // ========================================================================
+    // CliBarMaterial tests
+    // ========================================================================
+
+    #[test]
+    fn test_cli_bar_material_matches_config_materials() {
+        use clap::ValueEnum;
+
+        use crate::config::BarMaterial;
+
+        for material in CliBarMaterial::value_variants() {
+            let parsed: Result<BarMaterial, _> =
+                serde_json::from_value(serde_json::json!(material.as_str()));
+            assert!(parsed.is_ok(), "{}", material.as_str());
+        }
+    }
 }
@@ -58,6 +58,18 @@ pub enum WallpaperCommands {
     /// Returns a JSON array of wallpaper paths from the configured wallpaper
     /// directory or list.
     List,
+
+    /// Advance to the next wallpaper.
+    ///
+    /// Follows the slideshow order when a slideshow is configured, otherwise
+    /// the order of the wallpaper list.
+    Next,
+
+    /// Go back to the previous wallpaper.
+    ///
+    /// Follows the slideshow order when a slideshow is configured, otherwise
+    /// the order of the wallpaper list.
+    Previous,
 }
 
 /// Execute wallpaper subcommands.
@@ -71,6 +83,8 @@ pub fn execute(cmd: &WallpaperCommands) -> Result<(), StacheError> {
         }
         WallpaperCommands::GenerateAll => execute_generate_all(),
         WallpaperCommands::List => execute_list(),
+        WallpaperCommands::Next => execute_step(&WallpaperAction::Next),
+        WallpaperCommands::Previous => execute_step(&WallpaperAction::Previous),
     }
 }
 
@@ -132,6 +146,14 @@ fn execute_set(path: Option<&str>, random: bool, screen: &ScreenTarget) -> Resul
     Ok(())
 }
 
+/// Execute the wallpaper next/previous commands.
+fn execute_step(action: &WallpaperAction) -> Result<(), StacheError> {
+    wallpaper::perform_action(action).map_err(wallpaper_error_to_stache_error)?;
+
+    println!("Wallpaper set successfully.");
+    Ok(())
+}
+
 /// Execute the wallpaper list command.
 fn execute_list() -> Result<(), StacheError> {
     let wallpapers = wallpaper::list_wallpapers().map_err(wallpaper_error_to_stache_error)?;
@@ -184,6 +206,15 @@ mod tests {
         assert!(matches!(cli.command, WallpaperCommands::GenerateAll));
     }
 
+    #[test]
+    fn test_wallpaper_next_previous_parse() {
+        let cli = TestCli::try_parse_from(["test", "next"]).unwrap();
+        assert!(matches!(cli.command, WallpaperCommands::Next));
+
+        let cli = TestCli::try_parse_from(["test", "previous"]).unwrap();
+        assert!(matches!(cli.command, WallpaperCommands::Previous));
+    }
+
     #[test]
     fn test_wallpaper_set_path_parse() {
         let cli = TestCli::try_parse_from(["test", "set", "/path/to/image.jpg"]).unwrap();
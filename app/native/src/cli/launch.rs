@@ -0,0 +1,72 @@
+//! Launching the desktop app for CLI commands that need it.
+//!
+//! Commands that talk to the running app (tiling, reload) first check that it
+//! answers on the IPC socket. If it doesn't, the app is started in the
+//! background from the current executable and the command waits for it to
+//! come up. With `--no-launch` the command fails instead with
+//! [`StacheError::AppNotRunning`], which exits with a distinct code for scripts.
+
+use std::os::unix::process::CommandExt;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::error::StacheError;
+use crate::platform::ipc_socket;
+
+/// How long to wait for a launched app to answer on the IPC socket.
+const LAUNCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Delay between checks while waiting for a launched app.
+const LAUNCH_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Makes sure the desktop app is running, launching it unless `no_launch` is set.
+///
+/// `config` is forwarded to the launched app so it uses the same config file.
+///
+/// # Errors
+///
+/// Returns `StacheError::AppNotRunning` if the app is not running and either
+/// `no_launch` is set or it did not come up in time, or an IO error if it
+/// could not be started.
+pub fn ensure_app_running(no_launch: bool, config: Option<&str>) -> Result<(), StacheError> {
+    if ipc_socket::is_app_running() {
+        return Ok(());
+    }
+
+    if no_launch {
+        return Err(StacheError::AppNotRunning);
+    }
+
+    launch_app(config)?;
+
+    let deadline = Instant::now() + LAUNCH_TIMEOUT;
+    while Instant::now() < deadline {
+        if ipc_socket::is_app_running() {
+            return Ok(());
+        }
+        std::thread::sleep(LAUNCH_POLL_INTERVAL);
+    }
+
+    Err(StacheError::AppNotRunning)
+}
+
+/// Starts the desktop app in the background, detached from the terminal.
+fn launch_app(config: Option<&str>) -> Result<(), StacheError> {
+    let exe = std::env::current_exe()?;
+    let mut command = Command::new(exe);
+    command.arg("--desktop");
+    if let Some(path) = config {
+        command.args(["--config", path]);
+    }
+
+    // Own process group, so Ctrl-C on the CLI doesn't take the app down with it
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .process_group(0)
+        .spawn()?;
+
+    eprintln!("stache: Stache is not running, launching it in the background...");
+    Ok(())
+}
@@ -2,10 +2,13 @@
 //!
 //! This module provides command-line interface functionality for interacting
 //! with Stache. When the desktop app is running, CLI commands communicate with
-//! it directly. When not running, some commands may launch the app.
+//! it directly. When not running, commands that need the app launch it in the
+//! background unless `--no-launch` is passed.
 
 mod commands;
+mod launch;
 mod output;
+mod tree;
 
 use clap::Parser;
 pub use commands::Cli;
@@ -0,0 +1,193 @@
+//! ASCII rendering of a workspace's windows.
+//!
+//! Used by `stache tiling tree`. Window frames are scaled from the screen's
+//! visible frame down to a fixed character grid and drawn as boxes labelled
+//! with the window title and size. The focused window is drawn last with `#`
+//! borders, so it stays whole where floating windows overlap it.
+
+use crate::cli::output;
+use crate::tiling::Rect;
+
+/// Width of the grid in characters.
+const GRID_WIDTH: usize = 80;
+
+/// Height of a terminal cell relative to its width.
+const CELL_ASPECT: f64 = 2.0;
+
+/// Fewest rows drawn, so very wide screens still fit a label.
+const MIN_ROWS: usize = 6;
+
+/// A window to draw.
+#[derive(Debug, Clone)]
+pub struct TreeWindow {
+    /// Label shown on the first line of the box.
+    pub title: String,
+    /// Frame in screen coordinates.
+    pub frame: Rect,
+    /// Whether the window is focused.
+    pub focused: bool,
+}
+
+/// Renders windows inside `screen` as an ASCII box diagram, one string per row.
+///
+/// Windows are drawn in order, so later windows cover earlier ones where they
+/// overlap. The focused window is always drawn last.
+#[must_use]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+pub fn render(screen: Rect, windows: &[TreeWindow]) -> Vec<String> {
+    let cols = GRID_WIDTH;
+    let rows = if screen.width > 0.0 {
+        ((cols as f64 * screen.height / screen.width / CELL_ASPECT).round() as usize).max(MIN_ROWS)
+    } else {
+        MIN_ROWS
+    };
+
+    let mut grid = vec![vec![' '; cols]; rows];
+    let (non_focused, focused): (Vec<_>, Vec<_>) = windows.iter().partition(|w| !w.focused);
+
+    for window in non_focused.into_iter().chain(focused) {
+        let Some((left, top, right, bottom)) = to_cells(screen, window.frame, cols, rows) else {
+            continue;
+        };
+        draw_box(&mut grid, (left, top, right, bottom), window);
+    }
+
+    grid.into_iter().map(|row| row.into_iter().collect()).collect()
+}
+
+/// Scales a frame to inclusive grid cells, or `None` if it is off the grid.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn to_cells(
+    screen: Rect,
+    frame: Rect,
+    cols: usize,
+    rows: usize,
+) -> Option<(usize, usize, usize, usize)> {
+    if screen.width <= 0.0 || screen.height <= 0.0 {
+        return None;
+    }
+
+    let scale_x = cols as f64 / screen.width;
+    let scale_y = rows as f64 / screen.height;
+    let to_col = |x: f64| ((x - screen.x) * scale_x).round().clamp(0.0, cols as f64) as usize;
+    let to_row = |y: f64| ((y - screen.y) * scale_y).round().clamp(0.0, rows as f64) as usize;
+
+    let left = to_col(frame.x);
+    let top = to_row(frame.y);
+    let right = to_col(frame.x + frame.width).saturating_sub(1);
+    let bottom = to_row(frame.y + frame.height).saturating_sub(1);
+
+    // A box needs two columns and two rows for its borders
+    let right = right.max(left + 1).min(cols - 1);
+    let bottom = bottom.max(top + 1).min(rows - 1);
+    (left < right && top < bottom).then_some((left, top, right, bottom))
+}
+
+/// Draws a window's box and labels, clearing what was drawn below it.
+fn draw_box(grid: &mut [Vec<char>], cells: (usize, usize, usize, usize), window: &TreeWindow) {
+    let (left, top, right, bottom) = cells;
+    let (corner, horizontal, vertical) = if window.focused {
+        ('#', '#', '#')
+    } else {
+        ('+', '-', '|')
+    };
+
+    for (y, row) in grid.iter_mut().enumerate().take(bottom + 1).skip(top) {
+        for (x, cell) in row.iter_mut().enumerate().take(right + 1).skip(left) {
+            let edge_x = x == left || x == right;
+            let edge_y = y == top || y == bottom;
+            *cell = match (edge_x, edge_y) {
+                (true, true) => corner,
+                (false, true) => horizontal,
+                (true, false) => vertical,
+                (false, false) => ' ',
+            };
+        }
+    }
+
+    let inner_width = right - left - 1;
+    if inner_width == 0 {
+        return;
+    }
+
+    let size = format!("{:.0}x{:.0}", window.frame.width, window.frame.height);
+    let labels = [
+        output::truncate(&window.title, inner_width),
+        output::truncate(&size, inner_width),
+    ];
+
+    for (row, label) in (top + 1..bottom).zip(labels) {
+        for (x, ch) in (left + 1..).zip(label.chars()) {
+            grid[row][x] = ch;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCREEN: Rect = Rect::new(0.0, 0.0, 1600.0, 1000.0);
+
+    fn window(title: &str, frame: Rect, focused: bool) -> TreeWindow {
+        TreeWindow {
+            title: title.to_string(),
+            frame,
+            focused,
+        }
+    }
+
+    #[test]
+    fn test_render_scales_to_grid() {
+        let lines = render(SCREEN, &[window("Terminal", SCREEN, false)]);
+
+        assert_eq!(lines.len(), 25);
+        assert!(lines.iter().all(|line| line.chars().count() == GRID_WIDTH));
+        assert_eq!(lines[0], format!("+{}+", "-".repeat(GRID_WIDTH - 2)));
+        assert!(lines[1].starts_with("|Terminal "));
+        assert!(lines[2].starts_with("|1600x1000 "));
+    }
+
+    #[test]
+    fn test_render_side_by_side_windows() {
+        let lines = render(SCREEN, &[
+            window("Left", Rect::new(0.0, 0.0, 800.0, 1000.0), false),
+            window("Right", Rect::new(800.0, 0.0, 800.0, 1000.0), true),
+        ]);
+
+        assert_eq!(&lines[0][..40], format!("+{}+", "-".repeat(38)));
+        assert_eq!(&lines[0][40..], "#".repeat(40));
+        assert!(lines[1][41..].starts_with("Right"));
+    }
+
+    #[test]
+    fn test_render_draws_focused_window_last() {
+        let lines = render(SCREEN, &[
+            window("Focused", SCREEN, true),
+            window("Floating", Rect::new(400.0, 400.0, 800.0, 400.0), false),
+        ]);
+
+        // The focused window covers the floating one drawn after it in the list
+        assert!(lines.iter().all(|line| !line.contains("Floating")));
+        assert!(lines[1].starts_with("#Focused"));
+    }
+
+    #[test]
+    fn test_render_truncates_long_titles() {
+        let lines = render(SCREEN, &[window(
+            "A very long window title that does not fit",
+            Rect::new(0.0, 0.0, 200.0, 1000.0),
+            false,
+        )]);
+
+        assert!(lines[1].starts_with("|A very …|"));
+    }
+}
@@ -5,30 +5,46 @@
 //!
 //! The configuration file supports JSONC format (JSON with comments).
 //! Both single-line (`//`) and multi-line (`/* */`) comments are allowed.
+//! A `.toml` file is read into the same configuration, with the same keys.
+//! When several files exist in one location, JSONC wins over JSON and TOML.
+//!
+//! A few values can also be overridden through `STACHE_*` environment
+//! variables, which take precedence over the file.
 
 pub mod env;
+mod overrides;
 pub mod template;
 pub mod types;
+pub mod validate;
 mod watcher;
 
 use std::path::PathBuf;
 use std::sync::OnceLock;
+use std::sync::atomic::{AtomicPtr, Ordering};
 
+pub use overrides::apply_env_overrides;
 pub use types::{
-    AnimationConfig, AudioDeviceDependency, AudioDevicePriority, BarConfig, BorderColor,
-    BorderStateConfig, BordersConfig, CommandQuitConfig, ConfigError, DimensionValue, EasingType,
-    FloatingConfig, FloatingPreset, GapValue, GapsConfig, GapsConfigValue, GradientConfig,
-    LayoutType, MasterConfig, MasterPosition, MatchStrategy, MenuAnywhereConfig,
-    MenuAnywhereModifier, MenuAnywhereMouseButton, NoTunesConfig, ProxyAudioConfig, Rgba,
-    ShortcutCommands, StacheConfig, TargetMusicApp, TilingConfig, WallpaperConfig, WallpaperMode,
-    WeatherConfig, WindowRule, WorkspaceConfig, config_paths, load_config as load_config_default,
-    load_config_from_path, parse_color, parse_hex_color, parse_rgba_color,
+    AnimationConfig, AppInsets, AudioConfig, AudioDeviceDependency, AudioDevicePriority,
+    AutoLayoutThreshold, BarConfig, BarMaterial, BarPosition, BorderColor, BorderStateConfig,
+    BordersConfig, CONFIG_VERSION, CommandQuitConfig, ConfigError, ConfigVersion, DimensionValue,
+    DisplayRule, DwindleConfig, DwindleSplit, EasingType, FloatingConfig, FloatingPreset,
+    FocusAfterClose, GapValue, GapsConfig, GapsConfigValue, GradientConfig, IdleAction, IdleConfig,
+    LayoutType, LogsConfig, MasterConfig, MasterPosition, MatchStrategy, MediaConfig,
+    MenuAnywhereConfig, MenuAnywhereModifier, MenuAnywhereMouseButton, MoveModeConfig,
+    NoTunesConfig, ProxyAudioConfig, Rgba, ShortcutCommands, StacheConfig, TargetMusicApp,
+    TilingConfig, WallpaperConfig, WallpaperMode, WallpaperScaling, WeatherConfig,
+    WindowOpenAnimation, WindowRule, WorkspaceConfig, config_paths,
+    load_config as load_config_default, load_config_from_path, parse_color, parse_hex_color,
+    parse_rgba_color,
 };
-pub use watcher::watch_config_file;
+pub use watcher::{register_live_reload, watch_config_file};
 
 /// Global configuration instance, loaded once at startup.
 static CONFIG: OnceLock<StacheConfig> = OnceLock::new();
 
+/// Configuration applied by a live reload, superseding `CONFIG` when set.
+static RELOADED_CONFIG: AtomicPtr<StacheConfig> = AtomicPtr::new(std::ptr::null_mut());
+
 /// Path to the currently loaded configuration file.
 static CONFIG_PATH: OnceLock<PathBuf> = OnceLock::new();
 
@@ -52,7 +68,15 @@ pub fn set_custom_config_path(path: PathBuf) -> bool { CUSTOM_CONFIG_PATH.set(pa
 ///
 /// Returns the loaded configuration, or a default configuration if loading fails.
 /// If no configuration file exists, creates a template configuration file.
+/// Environment overrides are applied on top either way.
 fn load_or_default() -> StacheConfig {
+    let mut config = load_file_or_default();
+    apply_env_overrides(&mut config);
+    config
+}
+
+/// Loads the configuration file, falling back to the defaults.
+fn load_file_or_default() -> StacheConfig {
     // Check for custom config path first
     let result = CUSTOM_CONFIG_PATH.get().map_or_else(load_config_default, load_config_from_path);
 
@@ -112,7 +136,7 @@ fn create_default_config_file() {
 /// the same configuration instance.
 ///
 /// If no configuration file is found, returns a default empty configuration.
-pub fn init() -> &'static StacheConfig { CONFIG.get_or_init(load_or_default) }
+pub fn init() -> &'static StacheConfig { get_config() }
 
 /// Returns the global configuration instance, initializing it if necessary.
 ///
@@ -120,11 +144,41 @@ pub fn init() -> &'static StacheConfig { CONFIG.get_or_init(load_or_default) }
 /// the configuration if it hasn't been loaded yet.
 ///
 /// If no configuration file is found, returns a default empty configuration.
-pub fn get_config() -> &'static StacheConfig { CONFIG.get_or_init(load_or_default) }
+/// After a live reload, returns the reloaded configuration.
+pub fn get_config() -> &'static StacheConfig {
+    let reloaded = RELOADED_CONFIG.load(Ordering::Acquire);
+    if reloaded.is_null() {
+        return CONFIG.get_or_init(load_or_default);
+    }
+
+    // SAFETY: the pointer comes from `Box::into_raw` in `replace_config` and is never freed
+    unsafe { &*reloaded }
+}
+
+/// Replaces the global configuration after a live reload.
+///
+/// The previous configuration is intentionally leaked: callers may still hold
+/// `&'static` references to it, and reloads are rare enough for this to be
+/// negligible.
+fn replace_config(config: StacheConfig) {
+    RELOADED_CONFIG.store(Box::into_raw(Box::new(config)), Ordering::Release);
+}
 
 /// Returns the path to the loaded configuration file, if any.
 pub fn get_config_path() -> Option<&'static PathBuf> { CONFIG_PATH.get() }
 
+/// Returns the configuration file that [`get_config`] loads, if any.
+///
+/// This is the `--config` override when set, otherwise the first existing file
+/// from [`config_paths`]. Unlike [`get_config_path`], it does not load the file.
+#[must_use]
+pub fn resolve_config_path() -> Option<PathBuf> {
+    CUSTOM_CONFIG_PATH
+        .get()
+        .cloned()
+        .or_else(|| config_paths().into_iter().find(|path| path.exists()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,203 @@
+//! Environment variable overrides for select configuration values.
+//!
+//! A few values can be set through `STACHE_*` environment variables, which is
+//! handy to flip one setting for a quick experiment or in CI without editing
+//! the config file. Overrides are applied on top of the loaded file, so the
+//! precedence is: environment, then config file, then defaults.
+//!
+//! Only the variables in [`OVERRIDES`] are read. A variable holding a value of
+//! the wrong type is reported and ignored, keeping the value from the file.
+
+use serde::de::DeserializeOwned;
+
+use super::types::{GapValue, GapsConfigValue, LayoutType, StacheConfig};
+
+/// An overridable config value.
+struct Override {
+    /// Environment variable to read.
+    var: &'static str,
+    /// Applies the variable's value, or describes why it is invalid.
+    apply: fn(&mut StacheConfig, &str) -> Result<(), String>,
+}
+
+/// Every config value that can be overridden from the environment.
+const OVERRIDES: &[Override] = &[
+    Override {
+        var: "STACHE_TILING_ENABLED",
+        apply: |config, value| {
+            config.tiling.enabled = parse_bool(value)?;
+            Ok(())
+        },
+    },
+    Override {
+        var: "STACHE_TILING_DEFAULT_LAYOUT",
+        apply: |config, value| {
+            config.tiling.default_layout = parse_enum::<LayoutType>(value)?;
+            Ok(())
+        },
+    },
+    Override {
+        var: "STACHE_ANIMATIONS_ENABLED",
+        apply: |config, value| {
+            config.tiling.animations.enabled = parse_bool(value)?;
+            Ok(())
+        },
+    },
+    Override {
+        var: "STACHE_BORDERS_ENABLED",
+        apply: |config, value| {
+            config.tiling.borders.enabled = parse_bool(value)?;
+            Ok(())
+        },
+    },
+    Override {
+        var: "STACHE_GAPS_INNER",
+        apply: |config, value| {
+            let gap = parse_pixels(value)?;
+            set_gaps(&mut config.tiling.gaps, |inner, _| {
+                *inner = GapValue::Uniform(gap);
+            });
+            Ok(())
+        },
+    },
+    Override {
+        var: "STACHE_GAPS_OUTER",
+        apply: |config, value| {
+            let gap = parse_pixels(value)?;
+            set_gaps(&mut config.tiling.gaps, |_, outer| {
+                *outer = GapValue::Uniform(gap);
+            });
+            Ok(())
+        },
+    },
+    Override {
+        var: "STACHE_BAR_ENABLED",
+        apply: |config, value| {
+            config.bar.enabled = parse_bool(value)?;
+            Ok(())
+        },
+    },
+];
+
+/// Applies the overrides set in the process environment, logging invalid ones.
+pub fn apply_env_overrides(config: &mut StacheConfig) {
+    for error in apply_overrides(config, |var| std::env::var(var).ok()) {
+        tracing::warn!("config: ignoring environment override: {error}");
+    }
+}
+
+/// Applies the overrides `lookup` returns a value for.
+///
+/// Returns a message for each variable whose value could not be used.
+fn apply_overrides(
+    config: &mut StacheConfig,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    for entry in OVERRIDES {
+        let Some(value) = lookup(entry.var) else {
+            continue;
+        };
+
+        match (entry.apply)(config, value.trim()) {
+            Ok(()) => tracing::info!("config: {} overrides the config file", entry.var),
+            Err(reason) => errors.push(format!("{}={value:?}: {reason}", entry.var)),
+        }
+    }
+
+    errors
+}
+
+/// Sets the gaps of every screen, whether gaps are global or per screen.
+fn set_gaps(gaps: &mut GapsConfigValue, set: impl Fn(&mut GapValue, &mut GapValue)) {
+    match gaps {
+        GapsConfigValue::Global(gaps) => set(&mut gaps.inner, &mut gaps.outer),
+        GapsConfigValue::PerScreen(screens) => {
+            for screen in screens {
+                set(&mut screen.inner, &mut screen.outer);
+            }
+        }
+    }
+}
+
+/// Parses `true`/`false`, also accepting `1`/`0`, `yes`/`no` and `on`/`off`.
+fn parse_bool(value: &str) -> Result<bool, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "true" | "1" | "yes" | "on" => Ok(true),
+        "false" | "0" | "no" | "off" => Ok(false),
+        _ => Err("expected a boolean (true or false)".to_string()),
+    }
+}
+
+/// Parses a non-negative number of pixels.
+fn parse_pixels(value: &str) -> Result<u32, String> {
+    value
+        .parse()
+        .map_err(|_| "expected a non-negative whole number of pixels".to_string())
+}
+
+/// Parses a value by its name in the config file, like `"monocle"`.
+fn parse_enum<T: DeserializeOwned>(value: &str) -> Result<T, String> {
+    serde_json::from_value(serde_json::Value::String(value.to_string()))
+        .map_err(|err| err.to_string())
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn apply(config: &mut StacheConfig, vars: &[(&str, &str)]) -> Vec<String> {
+        let vars: HashMap<&str, &str> = vars.iter().copied().collect();
+        apply_overrides(config, |var| vars.get(var).map(ToString::to_string))
+    }
+
+    #[test]
+    fn test_overrides_replace_file_values() {
+        let mut config = StacheConfig::default();
+        config.tiling.enabled = true;
+
+        let errors = apply(&mut config, &[
+            ("STACHE_TILING_ENABLED", "false"),
+            ("STACHE_TILING_DEFAULT_LAYOUT", "monocle"),
+            ("STACHE_GAPS_INNER", "12"),
+        ]);
+
+        assert!(errors.is_empty(), "{errors:?}");
+        assert!(!config.tiling.enabled);
+        assert_eq!(config.tiling.default_layout, LayoutType::Monocle);
+        let GapsConfigValue::Global(gaps) = &config.tiling.gaps else {
+            panic!("expected global gaps");
+        };
+        assert!(matches!(gaps.inner, GapValue::Uniform(12)));
+    }
+
+    #[test]
+    fn test_invalid_override_keeps_file_value() {
+        let mut config = StacheConfig::default();
+        config.tiling.enabled = true;
+
+        let errors = apply(&mut config, &[
+            ("STACHE_TILING_ENABLED", "maybe"),
+            ("STACHE_GAPS_OUTER", "-4"),
+        ]);
+
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].starts_with("STACHE_TILING_ENABLED=\"maybe\""));
+        assert!(config.tiling.enabled);
+    }
+
+    #[test]
+    fn test_unset_variables_change_nothing() {
+        let mut config = StacheConfig::default();
+
+        assert!(apply(&mut config, &[]).is_empty());
+        assert!(!config.tiling.enabled);
+    }
+}
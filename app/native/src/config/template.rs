@@ -20,6 +20,12 @@ pub fn generate_config_template() -> String {
 // Documentation: https://github.com/marcosmoura/stache
 
 {
+  // JSON Schema for editor validation (or a local copy from `stache schema --output`)
+  // "$schema": "https://raw.githubusercontent.com/marcosmoura/stache/main/stache.schema.json",
+  //
+  // Config schema version. Files without one are read as the current format.
+  // "version": 1,
+
   // ============================================================================
   // Status Bar Configuration
   // ============================================================================
@@ -33,13 +39,34 @@ pub fn generate_config_template() -> String {
   //   // Padding around the status bar in pixels
   //   "padding": 12,
   //
+  //   // Screen edge the status bar is shown on: "top" or "bottom"
+  //   "position": "top",
+  //
+  //   // Show a bar on every connected screen, each with its own workspaces
+  //   "perScreen": false,
+  //
+  //   // Background material of the bar: "none", "menu", "hud-window", ...
+  //   "material": "none",
+  //
   //   // Weather widget configuration
   //   "weather": {
   //     // Path to .env file containing VISUAL_CROSSING_API_KEY
   //     "apiKeys": "",
   //
   //     // Default location when geolocation fails (city name or coordinates)
-  //     "defaultLocation": ""
+  //     "defaultLocation": "",
+  //
+  //     // Additional locations to show weather for
+  //     "locations": [],
+  //
+  //     // Minimum seconds between fetches for the same location (default: 600)
+  //     "minRefreshInterval": 600
+  //   },
+  //
+  //   // Media (now playing) widget configuration
+  //   "media": {
+  //     // Maximum album artwork size in pixels (default: 128)
+  //     "artworkSize": 128
   //   }
   // },
 
@@ -52,7 +79,13 @@ pub fn generate_config_template() -> String {
   //   "enabled": false,
   //
   //   // Duration in milliseconds to hold ⌘Q before quitting (default: 1500)
-  //   "holdDuration": 1500
+  //   "holdDuration": 1500,
+  //
+  //   // Only require holding ⌘Q in these apps (empty = all apps)
+  //   "applyTo": [],
+  //
+  //   // Apps where ⌘Q quits instantly
+  //   "bypass": ["com.apple.Preview"]
   // },
 
   // ============================================================================
@@ -68,12 +101,16 @@ pub fn generate_config_template() -> String {
   //   // Or specify a list of wallpaper file paths
   //   "list": [],
   //
-  //   // Rotation mode: "random" or "sequential"
+  //   // Rotation mode: "random", "sequential" or a slideshow
+  //   // e.g. { "slideshow": { "folder": "~/Pictures", "interval": 1800, "shuffle": true } }
   //   "mode": "random",
   //
   //   // Interval in seconds between wallpaper changes (0 = no rotation)
   //   "interval": 0,
   //
+  //   // Scaling: "fill", "fit", "stretch", "center" or "tile"
+  //   "scaling": "fill",
+  //
   //   // Blur radius in pixels (0 = no blur)
   //   "blur": 0,
   //
@@ -95,6 +132,29 @@ pub fn generate_config_template() -> String {
   //
   //   // Example: Multiple commands (executed sequentially)
   //   // "Command+Control+T": ["stache reload", "open -a Terminal"]
+  //
+  //   // Example: Prefix chord (press the leader, then W, then 1)
+  //   // "Command+Space then W then 1": "stache tiling workspace --focus 1"
+  // },
+
+  // ============================================================================
+  // Tiling Hotkeys
+  // ============================================================================
+  // Bind tiling actions to key combinations. Actions include "focus-<dir>",
+  // "swap-<dir>", "resize-width-grow", "workspace-<name>",
+  // "send-to-workspace-<name>", "move-to-workspace-<name>" (without following),
+  // "send-to-screen-<target>", "focus-screen-<target>" (target can also be a
+  // direction like "left"), "layout-<layout>", "cycle-layout",
+  // "toggle-layout", "balance", "toggle-floating" and "preset-<name>".
+  // Changes apply without a restart.
+  // "custom" binds key combinations to shell commands instead.
+  // "hotkeys": {
+  //   "focus-left": "Option+H",
+  //   "focus-right": "Option+L",
+  //   "workspace-code": "Option+1",
+  //   "custom": {
+  //     "Option+Return": "open -na Ghostty"
+  //   }
   // },
 
   // ============================================================================
@@ -107,6 +167,42 @@ pub fn generate_config_template() -> String {
   //   "stache wallpaper set --random"
   // ],
 
+  // ============================================================================
+  // Idle Detection
+  // ============================================================================
+  // React to a period without keyboard or mouse input
+  // "idle": {
+  //   // Seconds without input before idle (0 disables)
+  //   "timeout": 0,
+  //
+  //   // Action: "none", "releaseKeepAwake", or { "switchWorkspace": "<name>" }
+  //   "action": "none",
+  //
+  //   // Undo the action on the next input
+  //   "restoreOnActivity": true
+  // },
+
+  // ============================================================================
+  // Log File
+  // ============================================================================
+  // Rotating log file in the cache directory (see `stache logs path`)
+  // "logs": {
+  //   // Write logs to a file besides stderr
+  //   "enabled": true,
+  //
+  //   // Megabytes the file may reach before it is rotated (0 never rotates)
+  //   "maxSize": 10,
+  //
+  //   // Also rotate the file when the day changes (UTC)
+  //   "rotateDaily": true,
+  //
+  //   // Rotated files kept besides the current one
+  //   "retention": 5,
+  //
+  //   // Replace window titles with a placeholder, for sharing logs publicly
+  //   "redactTitles": false
+  // },
+
   // ============================================================================
   // Menu Anywhere
   // ============================================================================
@@ -119,7 +215,21 @@ pub fn generate_config_template() -> String {
   //   "modifiers": ["control", "command"],
   //
   //   // Mouse button trigger: "rightClick" or "middleClick"
-  //   "mouseButton": "rightClick"
+  //   "mouseButton": "rightClick",
+  //
+  //   // Bundle identifiers of apps where the trigger is passed through
+  //   "excludedApps": []
+  // },
+
+  // ============================================================================
+  // Audio (Switch Output When Preferred Devices Connect)
+  // ============================================================================
+  // "audio": {
+  //   // Output devices in priority order (first available device is used)
+  //   // Ignored while proxyAudio is enabled
+  //   "autoSwitch": [
+  //     { "name": "AirPods", "strategy": "contains" }
+  //   ]
   // },
 
   // ============================================================================
@@ -155,12 +265,19 @@ pub fn generate_config_template() -> String {
   //   "enabled": false,
   //
   //   // App to launch instead: "spotify", "tidal", or "none"
-  //   "targetApp": "spotify"
+  //   "targetApp": "spotify",
+  //
+  //   // Bundle identifiers to block (defaults to Apple Music and iTunes)
+  //   "intercept": ["com.apple.Music", "com.apple.iTunes"],
+  //
+  //   // Or open any app instead, by bundle identifier or .app path
+  //   // "launchInstead": "com.spotify.client"
   // },
 
   // ============================================================================
   // Tiling Window Manager
   // ============================================================================
+  // Gaps, master settings, new workspaces and layouts apply without a restart.
   // "tiling": {
   //   // Enable the tiling window manager
   //   "enabled": false,
@@ -169,6 +286,27 @@ pub fn generate_config_template() -> String {
   //   // "master", "grid", or "floating"
   //   "defaultLayout": "dwindle",
   //
+  //   // Default layout of auto-created workspaces per screen ("auto" picks
+  //   // split-vertical on portrait screens)
+  //   "screenLayouts": [{ "screen": "secondary", "layout": "auto" }],
+  //
+  //   // Layouts visited when cycling layouts, in order (empty: all layouts)
+  //   "layoutCycle": ["split", "monocle", "grid"],
+  //
+  //   // Layout "--layout-toggle" switches to and back from
+  //   "layoutToggleAlternate": "monocle",
+  //
+  //   // Outline the new layout briefly before cycling moves the windows
+  //   "previewOnCycle": false,
+  //
+  //   // Layouts picked by window count, each from its count up (pinned once
+  //   // a layout is set by hand, until "--layout-auto")
+  //   "autoLayout": [
+  //     { "windows": 1, "layout": "monocle" },
+  //     { "windows": 2, "layout": "split" },
+  //     { "windows": 3, "layout": "dwindle" }
+  //   ],
+  //
   //   // Gap configuration
   //   "gaps": {
   //     // Gap between windows (pixels)
@@ -188,11 +326,51 @@ pub fn generate_config_template() -> String {
   //     "position": "auto"
   //   },
   //
+  //   // Dwindle layout configuration
+  //   "dwindle": {
+  //     // First split: "horizontal", "vertical", or "auto" (by orientation)
+  //     "firstSplit": "auto"
+  //   },
+  //
+  //   // Keyboard move mode (`stache tiling window --move-mode`)
+  //   "moveMode": {
+  //     // Pixels a floating window moves per arrow key press
+  //     "step": 20,
+  //     // Seconds without a key press before the mode ends
+  //     "timeout": 5
+  //   },
+  //
+  //   // Seconds between passes that resync tracked windows with the windows on
+  //   // screen (0 = only on demand with `stache tiling reconcile`)
+  //   "reconcileInterval": 0,
+  //
+  //   // Include floating windows when focusing the next or previous window
+  //   "cycleIncludesFloating": false,
+  //
+  //   // Window focused when the focused window closes: "next", "previous", or "master"
+  //   "focusAfterClose": "next",
+  //
+  //   // Extra tries when focusing a window fails because its app is busy
+  //   "focusRetries": 3,
+  //
+  //   // Wrap promoted/demoted windows around the ends of the stack
+  //   "wrapStackMoves": false,
+  //
+  //   // Keep windows open at startup on the screen they are on
+  //   "respectExistingScreen": false,
+  //
+  //   // Warn when computing a layout takes longer than this many milliseconds
+  //   "layoutBudget": 8,
+  //
+  //   // Log every tiling event and decision, for troubleshooting
+  //   "verbose": false,
+  //
   //   // Animation configuration
   //   "animations": {
   //     "enabled": false,
   //     "duration": 200,
-  //     "easing": "ease-out"
+  //     "easing": "ease-out",
+  //     "windowOpen": "none" // "none", "fade" (needs a scripting addition), or "scale"
   //   },
   //
   //   // Window borders
@@ -206,14 +384,26 @@ pub fn generate_config_template() -> String {
   //   },
   //
   //   // Floating window presets
+  //   // Built in: "center", "center-large", "under-cursor" (centered on the mouse),
+  //   // "maximize", "left-half", "right-half", "top-half", "bottom-half", "top-left",
+  //   // "top-right", "bottom-left", "bottom-right" and the "left-"/"right-" thirds
+  //   // and two-thirds. Repeating "left-half" or "right-half" cycles through the thirds.
+  //   // Presets here replace a built-in one with the same name.
   //   "floating": {
   //     "defaultPosition": "center",
   //     "presets": [
   //       // { "name": "small", "width": 800, "height": 600, "center": true },
-  //       // { "name": "large", "width": "80%", "height": "80%", "center": true }
+  //       // { "name": "large", "width": "80%", "height": "80%", "center": true },
+  //       // { "name": "pointer", "width": 1200, "height": 800, "underCursor": true },
+  //       // { "name": "left-third", "width": "33.3%", "height": "100%", "x": 0, "y": 0 }
   //     ]
   //   },
   //
+  //   // Pin windows to a display, whatever their workspace rules say
+  //   "displayRules": [
+  //     // { "appId": "us.zoom.xos", "screen": "Built-in" }
+  //   ],
+  //
   //   // Windows to ignore (never tiled)
   //   "ignore": [
   //     // { "appName": "System Preferences" },
@@ -221,6 +411,39 @@ pub fn generate_config_template() -> String {
   //     // { "title": "Picture in Picture" }
   //   ],
   //
+  //   // Never manage windows without a title when they appear, such as
+  //   // splash screens and loaders
+  //   "ignoreUntitled": false,
+  //
+  //   // Bundle IDs of apps that are always tiled (even small windows or dialogs)
+  //   // or always floating. "forceTile" wins when an app is in both lists.
+  //   "forceTile": [],
+  //   "forceFloat": [
+  //     // "com.apple.calculator"
+  //   ],
+  //
+  //   // Windows that always float, matched like "ignore" rules. Rules can match
+  //   // the accessibility subrole, e.g. to float every dialog
+  //   "floatRules": [
+  //     // { "subrole": "AXDialog" }
+  //   ],
+  //
+  //   // Space kept free around windows of an app inside their tile, by bundle ID
+  //   "appInsets": {
+  //     // "com.apple.Terminal": { "top": 8, "left": 8, "bottom": 8, "right": 8 }
+  //   },
+  //
+  //   // Display roles, by display name or UUID, usable wherever a screen is
+  //   // expected. "primary" and "secondary" decide what "main" and "secondary" mean
+  //   "screenRoles": {
+  //     // "secondary": "DELL U2720Q"
+  //   },
+  //
+  //   // Maximum tiled windows per workspace (0 = no limit). New windows go to
+  //   // "overflowWorkspace", or the next workspace on the screen with room
+  //   "maxWindows": 0,
+  //   // "overflowWorkspace": "misc",
+  //
   //   // Workspace definitions
   //   "workspaces": [
   //     // {
@@ -236,7 +459,12 @@ pub fn generate_config_template() -> String {
   //     //   "layout": "monocle",
   //     //   "rules": [
   //     //     { "appId": "com.microsoft.VSCode" }
-  //     //   ]
+  //     //   ],
+  //     //   // Replaces the global ignore rules in this workspace
+  //     //   "ignore": [],
+  //     //   // Turns on Do Not Disturb while focused, through the "Turn On Do
+  //     //   // Not Disturb" and "Turn Off Do Not Disturb" shortcuts
+  //     //   "dnd": true
   //     // }
   //   ]
   // }
@@ -284,6 +512,8 @@ mod tests {
         assert!(template.contains("wallpapers"));
         assert!(template.contains("keybindings"));
         assert!(template.contains("execOnStartup"));
+        assert!(template.contains("idle"));
+        assert!(template.contains("logs"));
         assert!(template.contains("menuAnywhere"));
         assert!(template.contains("proxyAudio"));
         assert!(template.contains("notunes"));
@@ -108,6 +108,24 @@ impl ProxyAudioConfig {
     pub const fn is_enabled(&self) -> bool { self.enabled }
 }
 
+/// General audio configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AudioConfig {
+    /// Output devices to switch to automatically when they connect.
+    /// Devices are checked in order; the first available device becomes the
+    /// default output whenever a device is connected or disconnected.
+    /// An empty list disables automatic switching.
+    #[serde(default)]
+    pub auto_switch: Vec<AudioDevicePriority>,
+}
+
+impl AudioConfig {
+    /// Returns whether automatic output switching is enabled.
+    #[must_use]
+    pub const fn is_auto_switch_enabled(&self) -> bool { !self.auto_switch.is_empty() }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -142,4 +160,22 @@ mod tests {
         let config = ProxyAudioConfig::default();
         assert!(!config.is_enabled());
     }
+
+    #[test]
+    fn test_audio_config_default_disables_auto_switch() {
+        let config = AudioConfig::default();
+        assert!(!config.is_auto_switch_enabled());
+    }
+
+    #[test]
+    fn test_audio_config_parses_auto_switch() {
+        let config: AudioConfig = serde_json::from_str(
+            r#"{ "autoSwitch": [{ "name": "AirPods", "strategy": "contains" }] }"#,
+        )
+        .unwrap();
+
+        assert!(config.is_auto_switch_enabled());
+        assert_eq!(config.auto_switch[0].name, "AirPods");
+        assert_eq!(config.auto_switch[0].strategy, MatchStrategy::Contains);
+    }
 }
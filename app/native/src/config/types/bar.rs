@@ -1,12 +1,12 @@
 //! Status bar configuration types.
 //!
-//! Configuration for the status bar UI components including weather.
+//! Configuration for the status bar UI components including weather and media.
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// Weather provider options.
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 pub enum WeatherProvider {
     /// Automatically select provider based on available API keys.
@@ -35,8 +35,11 @@ impl WeatherProvider {
     }
 }
 
+/// Default minimum interval between weather fetches, in seconds (10 minutes).
+const DEFAULT_MIN_REFRESH_INTERVAL: u64 = 600;
+
 /// Weather configuration for the status bar.
-#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default, rename_all = "camelCase")]
 pub struct WeatherConfig {
     /// Weather data provider.
@@ -62,6 +65,29 @@ pub struct WeatherConfig {
     /// Default location for weather data when geolocation fails.
     /// Can be a city name, address, or coordinates.
     pub default_location: String,
+
+    /// Additional locations to fetch weather for.
+    /// Each entry can be a city name, address, or coordinates (e.g., "40.7128,-74.0060").
+    /// The default location is always fetched first.
+    /// Default: []
+    pub locations: Vec<String>,
+
+    /// Minimum time in seconds between fetches for the same location.
+    /// Refresh requests within this interval return cached data to avoid rate limits.
+    /// Default: 600 (10 minutes)
+    pub min_refresh_interval: u64,
+}
+
+impl Default for WeatherConfig {
+    fn default() -> Self {
+        Self {
+            provider: WeatherProvider::default(),
+            api_keys: String::new(),
+            default_location: String::new(),
+            locations: Vec::new(),
+            min_refresh_interval: DEFAULT_MIN_REFRESH_INTERVAL,
+        }
+    }
 }
 
 impl WeatherConfig {
@@ -78,6 +104,93 @@ impl WeatherConfig {
             WeatherProvider::OpenMeteo => true,
         }
     }
+
+    /// Returns all configured locations, starting with the default location.
+    ///
+    /// Empty entries and duplicates are skipped.
+    #[must_use]
+    pub fn all_locations(&self) -> Vec<&str> {
+        let mut result: Vec<&str> = Vec::with_capacity(self.locations.len() + 1);
+
+        for location in std::iter::once(&self.default_location).chain(&self.locations) {
+            let location = location.trim();
+            if !location.is_empty() && !result.contains(&location) {
+                result.push(location);
+            }
+        }
+
+        result
+    }
+}
+
+/// Default maximum artwork size in pixels.
+const DEFAULT_ARTWORK_SIZE: u32 = 128;
+
+/// Media (now playing) configuration for the status bar.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct MediaConfig {
+    /// Maximum width and height of the album artwork in pixels.
+    /// Artwork is center-cropped to a square and downscaled to this size.
+    /// Default: 128
+    pub artwork_size: u32,
+}
+
+impl Default for MediaConfig {
+    fn default() -> Self {
+        Self {
+            artwork_size: DEFAULT_ARTWORK_SIZE,
+        }
+    }
+}
+
+/// Screen edge the status bar is shown on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum BarPosition {
+    /// Along the top edge of the main screen.
+    #[default]
+    Top,
+    /// Along the bottom edge of the main screen.
+    Bottom,
+}
+
+/// Background material of the bar windows.
+///
+/// Maps to the `NSVisualEffectView` materials macOS uses for its own chrome.
+/// The blur follows the system light or dark appearance.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum BarMaterial {
+    /// No material; the bar is fully transparent behind its content.
+    #[default]
+    None,
+    /// The material of window title bars.
+    Titlebar,
+    /// The material of menus, closest to the translucent menu bar.
+    Menu,
+    /// The material of popovers.
+    Popover,
+    /// The material of window sidebars.
+    Sidebar,
+    /// The material of inline header and footer views.
+    HeaderView,
+    /// The material of sheets.
+    Sheet,
+    /// The material of window backgrounds.
+    WindowBackground,
+    /// The dark material of heads-up display windows.
+    HudWindow,
+    /// The material of full-screen modal interfaces.
+    FullScreenUi,
+    /// The material of tooltips.
+    Tooltip,
+    /// The material of opaque content backgrounds.
+    ContentBackground,
+    /// The material under window backgrounds.
+    UnderWindowBackground,
+    /// The material under a page background.
+    UnderPageBackground,
 }
 
 /// Bar configuration for the status bar UI components.
@@ -98,12 +211,30 @@ pub struct BarConfig {
     pub height: u16,
 
     /// Padding around the status bar in pixels.
-    /// This is added to the height when calculating the top gap for tiling.
+    /// Tiling keeps the bar and its padding free on the bar's edge.
     /// Default: 12
     pub padding: u16,
 
+    /// Screen edge the status bar is shown on: "top" or "bottom".
+    /// Default: "top"
+    pub position: BarPosition,
+
+    /// Whether to show a bar on every connected screen instead of only the
+    /// main one. Each bar shows the workspaces of its own screen.
+    /// Default: false
+    pub per_screen: bool,
+
+    /// Background material of the bar, blurring what is behind it like the
+    /// macOS menu bar. Use "menu" for the closest match, or "none" to keep
+    /// the bar fully transparent.
+    /// Default: "none"
+    pub material: BarMaterial,
+
     /// Weather status bar configuration.
     pub weather: WeatherConfig,
+
+    /// Media (now playing) status bar configuration.
+    pub media: MediaConfig,
 }
 
 impl BarConfig {
@@ -111,3 +242,52 @@ impl BarConfig {
     #[must_use]
     pub const fn is_enabled(&self) -> bool { self.enabled }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_material_parses_kebab_case() {
+        let config: BarConfig = serde_json::from_str(r#"{ "material": "hud-window" }"#).unwrap();
+        assert_eq!(config.material, BarMaterial::HudWindow);
+        assert_eq!(BarConfig::default().material, BarMaterial::None);
+    }
+
+    #[test]
+    fn test_weather_config_default_refresh_interval() {
+        let config = WeatherConfig::default();
+        assert_eq!(config.min_refresh_interval, 600);
+        assert!(config.locations.is_empty());
+    }
+
+    #[test]
+    fn test_all_locations_starts_with_default() {
+        let config = WeatherConfig {
+            default_location: "Lisbon".to_string(),
+            locations: vec!["Tokyo".to_string(), "New York".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(config.all_locations(), vec!["Lisbon", "Tokyo", "New York"]);
+    }
+
+    #[test]
+    fn test_all_locations_skips_empty_and_duplicates() {
+        let config = WeatherConfig {
+            default_location: String::new(),
+            locations: vec!["Tokyo".to_string(), "  ".to_string(), " Tokyo ".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(config.all_locations(), vec!["Tokyo"]);
+    }
+
+    #[test]
+    fn test_all_locations_empty() {
+        assert!(WeatherConfig::default().all_locations().is_empty());
+    }
+
+    #[test]
+    fn test_media_config_default() {
+        assert_eq!(MediaConfig::default().artwork_size, 128);
+    }
+}
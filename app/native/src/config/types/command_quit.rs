@@ -22,7 +22,18 @@ pub struct CommandQuitConfig {
 
     /// Duration in milliseconds to hold ⌘Q before quitting.
     /// Default: 1500 (1.5 seconds)
+    #[serde(alias = "durationMs")]
     pub hold_duration: u64,
+
+    /// Bundle identifiers of the apps where hold-to-quit applies.
+    /// When empty, it applies to every app not listed in `bypass`.
+    /// Default: []
+    pub apply_to: Vec<String>,
+
+    /// Bundle identifiers of the apps where ⌘Q quits instantly.
+    /// Takes precedence over `applyTo`.
+    /// Default: []
+    pub bypass: Vec<String>,
 }
 
 impl Default for CommandQuitConfig {
@@ -30,6 +41,8 @@ impl Default for CommandQuitConfig {
         Self {
             enabled: false,
             hold_duration: DEFAULT_HOLD_DURATION_MS,
+            apply_to: Vec::new(),
+            bypass: Vec::new(),
         }
     }
 }
@@ -43,6 +56,22 @@ impl CommandQuitConfig {
     #[must_use]
     #[allow(clippy::cast_precision_loss)] // Precision loss is negligible for millisecond values
     pub fn hold_duration_secs(&self) -> f64 { self.hold_duration as f64 / 1000.0 }
+
+    /// Returns whether hold-to-quit applies to the app with the given bundle identifier.
+    ///
+    /// Apps without a bundle identifier always require holding ⌘Q.
+    #[must_use]
+    pub fn applies_to(&self, bundle_id: Option<&str>) -> bool {
+        let Some(bundle_id) = bundle_id else {
+            return true;
+        };
+
+        if self.bypass.iter().any(|id| id == bundle_id) {
+            return false;
+        }
+
+        self.apply_to.is_empty() || self.apply_to.iter().any(|id| id == bundle_id)
+    }
 }
 
 #[cfg(test)]
@@ -64,6 +93,7 @@ mod tests {
         let config_custom = CommandQuitConfig {
             enabled: true,
             hold_duration: 2000,
+            ..Default::default()
         };
         assert!((config_custom.hold_duration_secs() - 2.0).abs() < f64::EPSILON);
     }
@@ -91,11 +121,53 @@ mod tests {
         assert_eq!(config.hold_duration, 2000);
     }
 
+    #[test]
+    fn test_deserialize_duration_ms_alias() {
+        let json = r#"{"enabled": true, "durationMs": 800}"#;
+        let config: CommandQuitConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.hold_duration, 800);
+    }
+
+    #[test]
+    fn test_applies_to_all_apps_by_default() {
+        let config = CommandQuitConfig::default();
+        assert!(config.applies_to(Some("com.apple.Safari")));
+        assert!(config.applies_to(None));
+    }
+
+    #[test]
+    fn test_applies_to_respects_bypass() {
+        let config = CommandQuitConfig {
+            bypass: vec!["com.apple.Preview".to_string()],
+            ..Default::default()
+        };
+        assert!(!config.applies_to(Some("com.apple.Preview")));
+        assert!(config.applies_to(Some("com.apple.Safari")));
+    }
+
+    #[test]
+    fn test_applies_to_restricted_to_apply_to_list() {
+        let config = CommandQuitConfig {
+            apply_to: vec![
+                "com.apple.Safari".to_string(),
+                "com.apple.Preview".to_string(),
+            ],
+            bypass: vec!["com.apple.Preview".to_string()],
+            ..Default::default()
+        };
+        assert!(config.applies_to(Some("com.apple.Safari")));
+        assert!(!config.applies_to(Some("com.apple.Preview")));
+        assert!(!config.applies_to(Some("com.apple.Terminal")));
+        assert!(config.applies_to(None));
+    }
+
     #[test]
     fn test_deserialize_partial_json_uses_defaults() {
         let json = r#"{"enabled": false}"#;
         let config: CommandQuitConfig = serde_json::from_str(json).unwrap();
         assert!(!config.enabled);
         assert_eq!(config.hold_duration, 1500);
+        assert!(config.apply_to.is_empty());
+        assert!(config.bypass.is_empty());
     }
 }
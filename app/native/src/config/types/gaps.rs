@@ -2,6 +2,8 @@
 //!
 //! Configuration for inner and outer gaps in tiling layouts.
 
+use std::str::FromStr;
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -26,11 +28,47 @@ impl DimensionValue {
         match self {
             Self::Pixels(px) => f64::from(*px),
             Self::Percentage(s) => {
-                let trimmed = s.trim().trim_end_matches('%');
-                trimmed.parse::<f64>().map(|pct| (pct / 100.0) * reference_size).unwrap_or(0.0)
+                parse_percentage(s).map_or(0.0, |pct| (pct / 100.0) * reference_size)
             }
         }
     }
+
+    /// Returns whether the value is a positive size: non-zero pixels, or a
+    /// percentage above 0% and at most 100%.
+    ///
+    /// Percentages are read like [`Self::resolve`] does, so the `%` sign is
+    /// optional.
+    #[must_use]
+    pub fn is_positive_size(&self) -> bool {
+        match self {
+            Self::Pixels(px) => *px > 0,
+            Self::Percentage(s) => parse_percentage(s).is_some_and(|pct| pct > 0.0 && pct <= 100.0),
+        }
+    }
+}
+
+/// Parses a percentage string, with or without the `%` sign.
+fn parse_percentage(s: &str) -> Option<f64> { s.trim().trim_end_matches('%').trim().parse().ok() }
+
+impl FromStr for DimensionValue {
+    type Err = String;
+
+    /// Parses pixels (`"120"`) or a percentage (`"50%"`).
+    ///
+    /// Percentages are normalized, so `"50 %"` is stored as `"50%"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some(pct) = s.strip_suffix('%') {
+            return match pct.trim().parse::<f64>() {
+                Ok(value) if value >= 0.0 => Ok(Self::Percentage(format!("{value}%"))),
+                _ => Err(format!("invalid percentage '{s}'")),
+            };
+        }
+
+        s.parse::<u32>()
+            .map(Self::Pixels)
+            .map_err(|_| format!("invalid dimension '{s}', expected pixels or a percentage"))
+    }
 }
 
 /// A gap value that can be uniform, per-axis, or per-side.
@@ -141,6 +179,40 @@ mod tests {
         assert!((dim.resolve(1000.0) - 500.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_dimension_value_is_positive_size() {
+        assert!(DimensionValue::Pixels(120).is_positive_size());
+        assert!(!DimensionValue::Pixels(0).is_positive_size());
+        for pct in ["50%", "50", " 50 % ", "100%"] {
+            assert!(
+                DimensionValue::Percentage(pct.to_string()).is_positive_size(),
+                "{pct}"
+            );
+        }
+        for pct in ["0%", "0", "150%", "abc"] {
+            assert!(
+                !DimensionValue::Percentage(pct.to_string()).is_positive_size(),
+                "{pct}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_dimension_value_from_str() {
+        assert!(matches!("120".parse(), Ok(DimensionValue::Pixels(120))));
+        assert!(matches!(
+            " 50% ".parse::<DimensionValue>(),
+            Ok(DimensionValue::Percentage(pct)) if pct == "50%"
+        ));
+        assert!(matches!(
+            "12.5 %".parse::<DimensionValue>(),
+            Ok(DimensionValue::Percentage(pct)) if pct == "12.5%"
+        ));
+        assert!("-5".parse::<DimensionValue>().is_err());
+        assert!("abc%".parse::<DimensionValue>().is_err());
+        assert!("".parse::<DimensionValue>().is_err());
+    }
+
     #[test]
     fn test_gap_value_as_inner() {
         let uniform = GapValue::Uniform(10);
@@ -0,0 +1,255 @@
+//! Tiling hotkey configuration helpers.
+//!
+//! The `hotkeys` config section maps tiling action names to key combinations,
+//! e.g. `"focus-left": "Option+H"`, and under `custom` key combinations to
+//! shell commands. This module validates that no combination is bound twice,
+//! either within `hotkeys` or against `keybindings`.
+
+use std::collections::{BTreeSet, HashMap};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::root::{ConfigError, ShortcutCommands};
+
+/// Hotkeys configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct HotkeysConfig {
+    /// Shell commands run by key combinations.
+    ///
+    /// Maps a key combination to a command run with `sh -c`, e.g.
+    /// `"Option+Return": "open -na Ghostty"`. Commands run in the background;
+    /// a non-zero exit is logged.
+    /// Default: {}
+    pub custom: HashMap<String, String>,
+
+    /// Tiling actions mapped to key combinations.
+    #[serde(flatten)]
+    pub actions: HashMap<String, String>,
+}
+
+/// Separates the leader from the following keys in a chord keybinding,
+/// e.g. `"Command+Space then W then 1"`.
+pub const CHORD_SEPARATOR: &str = " then ";
+
+/// Modifier names in canonical order, with the aliases accepted for each.
+const MODIFIERS: &[(&str, &[&str])] = &[
+    ("command", &[
+        "command",
+        "cmd",
+        "super",
+        "meta",
+        "commandorcontrol",
+        "cmdorctrl",
+    ]),
+    ("control", &["control", "ctrl"]),
+    ("option", &["option", "opt", "alt"]),
+    ("shift", &["shift"]),
+    ("capslock", &["capslock"]),
+];
+
+/// Returns a canonical form of a key combination for comparison.
+///
+/// Modifiers are case-insensitive, aliases are resolved (`Cmd` → `command`,
+/// `Alt` → `option`, ...) and sorted, so `Shift+Cmd+K` and `command+shift+k`
+/// produce the same value.
+#[must_use]
+pub fn canonical_combo(combo: &str) -> String {
+    let mut modifiers = BTreeSet::new();
+    let mut keys = Vec::new();
+
+    for part in combo.split('+').map(str::trim).filter(|part| !part.is_empty()) {
+        let lower = part.to_lowercase();
+        let modifier = MODIFIERS.iter().position(|(_, aliases)| aliases.contains(&lower.as_str()));
+
+        match modifier {
+            Some(index) => {
+                modifiers.insert(index);
+            }
+            None => keys.push(if lower == "`" {
+                "backquote".to_string()
+            } else {
+                lower
+            }),
+        }
+    }
+
+    modifiers
+        .into_iter()
+        .map(|index| MODIFIERS[index].0.to_string())
+        .chain(keys)
+        .collect::<Vec<_>>()
+        .join("+")
+}
+
+/// Validates the `hotkeys` section against itself and the `keybindings` map.
+///
+/// # Errors
+///
+/// Returns `ConfigError::InvalidConfig` listing every key combination bound by
+/// a hotkey that is also bound by another hotkey or keybinding.
+pub fn validate_hotkeys(
+    hotkeys: &HotkeysConfig,
+    keybindings: &HashMap<String, ShortcutCommands>,
+) -> Result<(), ConfigError> {
+    let mut owners: HashMap<String, Vec<String>> = HashMap::new();
+
+    // A chord occupies its leader, so only that part can clash with a hotkey
+    for combo in keybindings.keys() {
+        let leader = combo.split(CHORD_SEPARATOR).next().unwrap_or(combo);
+        owners
+            .entry(canonical_combo(leader))
+            .or_default()
+            .push(format!("keybindings.{combo}"));
+    }
+
+    for (action, combo) in &hotkeys.actions {
+        owners
+            .entry(canonical_combo(combo))
+            .or_default()
+            .push(format!("hotkeys.{action}"));
+    }
+
+    for combo in hotkeys.custom.keys() {
+        owners
+            .entry(canonical_combo(combo))
+            .or_default()
+            .push(format!("hotkeys.custom.{combo}"));
+    }
+
+    let mut conflicts: Vec<String> = owners
+        .into_iter()
+        .filter(|(_, bound)| {
+            // Duplicates within `keybindings` alone are only warned about at registration
+            bound.len() > 1 && bound.iter().any(|owner| owner.starts_with("hotkeys."))
+        })
+        .map(|(combo, mut bound)| {
+            bound.sort();
+            format!("'{combo}' is bound by {}", bound.join(", "))
+        })
+        .collect();
+
+    if conflicts.is_empty() {
+        return Ok(());
+    }
+
+    conflicts.sort();
+    Err(ConfigError::InvalidConfig(format!(
+        "conflicting hotkeys: {}",
+        conflicts.join("; ")
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hotkeys(entries: &[(&str, &str)]) -> HotkeysConfig {
+        HotkeysConfig {
+            actions: entries
+                .iter()
+                .map(|(action, combo)| ((*action).to_string(), (*combo).to_string()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_canonical_combo_resolves_aliases() {
+        assert_eq!(canonical_combo("Cmd+Shift+K"), "command+shift+k");
+        assert_eq!(canonical_combo("Shift+Command+k"), "command+shift+k");
+        assert_eq!(canonical_combo("Ctrl+Alt+Left"), "control+option+left");
+        assert_eq!(canonical_combo("Super+`"), "command+backquote");
+    }
+
+    #[test]
+    fn test_canonical_combo_ignores_whitespace() {
+        assert_eq!(canonical_combo(" Option + H "), "option+h");
+    }
+
+    #[test]
+    fn test_validate_hotkeys_accepts_unique_combos() {
+        let hotkeys = hotkeys(&[("focus-left", "Option+H"), ("focus-right", "Option+L")]);
+        assert!(validate_hotkeys(&hotkeys, &HashMap::new()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_hotkeys_rejects_duplicate_hotkeys() {
+        let hotkeys = hotkeys(&[("focus-left", "Option+H"), ("swap-left", "Alt+h")]);
+        let err = validate_hotkeys(&hotkeys, &HashMap::new()).unwrap_err();
+        let message = err.to_string();
+
+        assert!(message.contains("option+h"));
+        assert!(message.contains("hotkeys.focus-left"));
+        assert!(message.contains("hotkeys.swap-left"));
+    }
+
+    #[test]
+    fn test_validate_hotkeys_rejects_conflict_with_keybindings() {
+        let hotkeys = hotkeys(&[("balance", "Command+Control+B")]);
+        let keybindings = HashMap::from([(
+            "Ctrl+Cmd+B".to_string(),
+            ShortcutCommands::Single("stache reload".to_string()),
+        )]);
+
+        let err = validate_hotkeys(&hotkeys, &keybindings).unwrap_err();
+        assert!(matches!(err, ConfigError::InvalidConfig(_)));
+        assert!(err.to_string().contains("keybindings.Ctrl+Cmd+B"));
+    }
+
+    #[test]
+    fn test_validate_hotkeys_rejects_conflict_with_chord_leader() {
+        let hotkeys = hotkeys(&[("balance", "Command+Space")]);
+        let keybindings = HashMap::from([(
+            "Cmd+Space then W then 1".to_string(),
+            ShortcutCommands::Single("stache tiling workspace --focus 1".to_string()),
+        )]);
+
+        let err = validate_hotkeys(&hotkeys, &keybindings).unwrap_err();
+        assert!(err.to_string().contains("'command+space'"));
+    }
+
+    #[test]
+    fn test_validate_hotkeys_ignores_keybinding_only_duplicates() {
+        let keybindings = HashMap::from([
+            (
+                "Cmd+K".to_string(),
+                ShortcutCommands::Single("first".to_string()),
+            ),
+            (
+                "Command+K".to_string(),
+                ShortcutCommands::Single("second".to_string()),
+            ),
+        ]);
+
+        assert!(validate_hotkeys(&HotkeysConfig::default(), &keybindings).is_ok());
+    }
+
+    #[test]
+    fn test_hotkeys_config_parses_custom_commands_next_to_actions() {
+        let config: HotkeysConfig = serde_json::from_str(
+            r#"{ "focus-left": "Option+H", "custom": { "Option+Return": "open -na Ghostty" } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.actions.get("focus-left").map(String::as_str),
+            Some("Option+H")
+        );
+        assert_eq!(
+            config.custom.get("Option+Return").map(String::as_str),
+            Some("open -na Ghostty")
+        );
+        assert!(!config.actions.contains_key("custom"));
+    }
+
+    #[test]
+    fn test_validate_hotkeys_rejects_custom_conflict_with_action() {
+        let mut hotkeys = hotkeys(&[("focus-left", "Option+H")]);
+        hotkeys.custom.insert("Alt+H".to_string(), "open -a Terminal".to_string());
+
+        let err = validate_hotkeys(&hotkeys, &HashMap::new()).unwrap_err();
+        assert!(err.to_string().contains("hotkeys.custom.Alt+H"));
+    }
+}
@@ -0,0 +1,101 @@
+//! Idle detection configuration types.
+//!
+//! Configuration for reacting to the user stepping away from the keyboard and mouse.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Action dispatched once the user has been idle for the configured timeout.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum IdleAction {
+    /// Do nothing.
+    #[default]
+    None,
+    /// Release the keep-awake wake lock so the system can sleep.
+    ReleaseKeepAwake,
+    /// Switch to the tiling workspace with the given name.
+    SwitchWorkspace(String),
+}
+
+/// Configuration for idle detection.
+///
+/// When no keyboard, mouse or trackpad input is received for `timeout`
+/// seconds, `action` is dispatched. With `restoreOnActivity`, the action is
+/// undone on the next input.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct IdleConfig {
+    /// Seconds without input before the user is considered idle.
+    /// Set to 0 to disable idle detection.
+    /// Default: 0
+    pub timeout: u64,
+
+    /// Action to dispatch when the user becomes idle.
+    /// Options: "none", "releaseKeepAwake", { "switchWorkspace": "<name>" }
+    /// Default: "none"
+    pub action: IdleAction,
+
+    /// Whether to undo the action on the next input: re-acquire the wake lock
+    /// or switch back to the previously focused workspace.
+    /// Default: true
+    pub restore_on_activity: bool,
+}
+
+impl Default for IdleConfig {
+    fn default() -> Self {
+        Self {
+            timeout: 0,
+            action: IdleAction::None,
+            restore_on_activity: true,
+        }
+    }
+}
+
+impl IdleConfig {
+    /// Returns whether idle detection is enabled.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool { self.timeout > 0 && self.action != IdleAction::None }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_idle_config_default_is_disabled() {
+        let config = IdleConfig::default();
+        assert!(!config.is_enabled());
+        assert!(config.restore_on_activity);
+        assert_eq!(config.action, IdleAction::None);
+    }
+
+    #[test]
+    fn test_idle_config_requires_timeout_and_action() {
+        let config = IdleConfig {
+            timeout: 300,
+            ..Default::default()
+        };
+        assert!(!config.is_enabled());
+
+        let config = IdleConfig {
+            timeout: 300,
+            action: IdleAction::ReleaseKeepAwake,
+            ..Default::default()
+        };
+        assert!(config.is_enabled());
+    }
+
+    #[test]
+    fn test_deserialize_actions() {
+        let json = r#"{"timeout": 600, "action": "releaseKeepAwake"}"#;
+        let config: IdleConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.timeout, 600);
+        assert_eq!(config.action, IdleAction::ReleaseKeepAwake);
+
+        let json = r#"{"timeout": 60, "action": {"switchWorkspace": "music"}, "restoreOnActivity": false}"#;
+        let config: IdleConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.action, IdleAction::SwitchWorkspace("music".to_string()));
+        assert!(!config.restore_on_activity);
+    }
+}
@@ -0,0 +1,103 @@
+//! Log file configuration types.
+//!
+//! Configuration for the rotating log file kept in the cache directory.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for the log file.
+///
+/// Logs are written to `stache.log` in the `logs` cache directory. Once the
+/// file reaches `maxSize`, or with `rotateDaily` on the first write of a new
+/// day, it is renamed to `stache.1.log`, older files move up by one, and files
+/// past `retention` are deleted.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LogsConfig {
+    /// Whether logs are written to a file besides stderr.
+    /// Default: true
+    pub enabled: bool,
+
+    /// Size in megabytes the log file may reach before it is rotated.
+    /// Set to 0 to never rotate.
+    /// Default: 10
+    pub max_size: u64,
+
+    /// Whether the log file is also rotated when the day changes, so each file
+    /// holds at most one day of logs. Days follow UTC, like the log timestamps.
+    /// Default: true
+    pub rotate_daily: bool,
+
+    /// Number of rotated log files kept besides the current one.
+    /// Default: 5
+    pub retention: u32,
+
+    /// Whether window titles are replaced with a placeholder in the logs, for
+    /// sharing them publicly.
+    /// Default: false
+    pub redact_titles: bool,
+}
+
+impl Default for LogsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_size: 10,
+            rotate_daily: true,
+            retention: 5,
+            redact_titles: false,
+        }
+    }
+}
+
+impl LogsConfig {
+    /// Returns the size in bytes the log file may reach before it is rotated,
+    /// or `None` to never rotate.
+    #[must_use]
+    pub const fn max_size_bytes(&self) -> Option<u64> {
+        if self.max_size == 0 {
+            None
+        } else {
+            Some(self.max_size.saturating_mul(1024 * 1024))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_logs_config_default() {
+        let config = LogsConfig::default();
+        assert!(config.enabled);
+        assert_eq!(config.max_size, 10);
+        assert!(config.rotate_daily);
+        assert_eq!(config.retention, 5);
+        assert!(!config.redact_titles);
+    }
+
+    #[test]
+    fn test_max_size_bytes() {
+        let config = LogsConfig {
+            max_size: 2,
+            ..Default::default()
+        };
+        assert_eq!(config.max_size_bytes(), Some(2 * 1024 * 1024));
+
+        let config = LogsConfig {
+            max_size: 0,
+            ..Default::default()
+        };
+        assert_eq!(config.max_size_bytes(), None);
+    }
+
+    #[test]
+    fn test_deserialize_partial_json_uses_defaults() {
+        let json = r#"{"redactTitles": true}"#;
+        let config: LogsConfig = serde_json::from_str(json).unwrap();
+        assert!(config.enabled);
+        assert!(config.redact_titles);
+        assert_eq!(config.retention, 5);
+    }
+}
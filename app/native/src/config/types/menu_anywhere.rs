@@ -48,6 +48,11 @@ pub struct MenuAnywhereConfig {
     /// Mouse button that triggers the menu.
     /// Default: `"rightClick"`
     pub mouse_button: MenuAnywhereMouseButton,
+
+    /// Bundle identifiers of the apps where the trigger is ignored and the
+    /// click is passed through (e.g., apps with their own click gestures).
+    /// Default: `[]`
+    pub excluded_apps: Vec<String>,
 }
 
 impl Default for MenuAnywhereConfig {
@@ -56,6 +61,7 @@ impl Default for MenuAnywhereConfig {
             enabled: false,
             modifiers: vec![MenuAnywhereModifier::Control, MenuAnywhereModifier::Command],
             mouse_button: MenuAnywhereMouseButton::RightClick,
+            excluded_apps: Vec::new(),
         }
     }
 }
@@ -65,6 +71,12 @@ impl MenuAnywhereConfig {
     #[must_use]
     pub const fn is_enabled(&self) -> bool { self.enabled }
 
+    /// Returns whether the trigger is disabled for the given bundle identifier.
+    #[must_use]
+    pub fn is_excluded(&self, bundle_id: &str) -> bool {
+        self.excluded_apps.iter().any(|id| id == bundle_id)
+    }
+
     /// Returns the required modifier flags as a bitmask for Core Graphics events.
     ///
     /// The returned value uses the macOS `CGEventFlags` constants:
@@ -100,6 +112,25 @@ mod tests {
             MenuAnywhereModifier::Command
         ]);
         assert_eq!(config.mouse_button, MenuAnywhereMouseButton::RightClick);
+        assert!(config.excluded_apps.is_empty());
+    }
+
+    #[test]
+    fn test_is_excluded() {
+        let config = MenuAnywhereConfig {
+            excluded_apps: vec!["com.figma.Desktop".to_string()],
+            ..Default::default()
+        };
+        assert!(config.is_excluded("com.figma.Desktop"));
+        assert!(!config.is_excluded("com.apple.Safari"));
+    }
+
+    #[test]
+    fn test_deserialize_excluded_apps() {
+        let json = r#"{"enabled": true, "excludedApps": ["com.figma.Desktop"]}"#;
+        let config: MenuAnywhereConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.excluded_apps, vec!["com.figma.Desktop"]);
+        assert_eq!(config.mouse_button, MenuAnywhereMouseButton::RightClick);
     }
 
     #[test]
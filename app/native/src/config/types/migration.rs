@@ -0,0 +1,130 @@
+//! Configuration schema versioning and migration.
+//!
+//! Every config file records the schema `version` it was written for; files
+//! without one are treated as version 0. When loading, known older shapes
+//! (renamed fields, moved sections, ...) are upgraded one version at a time
+//! until the file matches [`CONFIG_VERSION`], before it is deserialized.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use super::root::ConfigError;
+
+/// The config schema version this build reads and writes.
+pub const CONFIG_VERSION: u32 = 1;
+
+/// Schema version of a configuration file.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(transparent)]
+pub struct ConfigVersion(pub u32);
+
+impl Default for ConfigVersion {
+    fn default() -> Self { Self(CONFIG_VERSION) }
+}
+
+/// Upgrades a config object by one version, recording what changed.
+type MigrationStep = fn(&mut Map<String, Value>, &mut Vec<String>);
+
+/// Migration steps; the step at index `n` upgrades version `n` to `n + 1`.
+const MIGRATIONS: &[MigrationStep] = &[migrate_v0_to_v1];
+
+/// Version 1 introduced the `version` field; the config shape is unchanged.
+const fn migrate_v0_to_v1(_root: &mut Map<String, Value>, _notes: &mut Vec<String>) {}
+
+/// Returns the `version` recorded in a config value (0 when missing).
+///
+/// # Errors
+///
+/// Returns `ConfigError::InvalidConfig` if `version` is not a non-negative integer.
+pub fn config_version(value: &Value) -> Result<u32, ConfigError> {
+    match value.get("version") {
+        None => Ok(0),
+        Some(version) => {
+            version.as_u64().and_then(|version| u32::try_from(version).ok()).ok_or_else(|| {
+                ConfigError::InvalidConfig(format!(
+                    "`version` must be a non-negative integer, found {version}"
+                ))
+            })
+        }
+    }
+}
+
+/// Upgrades a raw config value to [`CONFIG_VERSION`].
+///
+/// Returns a description of every change made, for logging. Values that are
+/// not objects are left alone, so deserialization reports the problem.
+///
+/// # Errors
+///
+/// Returns `ConfigError::UnsupportedVersion` if the file was written for a
+/// newer schema than this build supports, or `ConfigError::InvalidConfig` if
+/// `version` is malformed.
+pub fn migrate(value: &mut Value) -> Result<Vec<String>, ConfigError> {
+    let version = config_version(value)?;
+    if version > CONFIG_VERSION {
+        return Err(ConfigError::UnsupportedVersion(version));
+    }
+
+    let Some(root) = value.as_object_mut() else {
+        return Ok(Vec::new());
+    };
+
+    let mut notes = Vec::new();
+    for step in &MIGRATIONS[version as usize..] {
+        step(root, &mut notes);
+    }
+
+    root.insert("version".to_string(), Value::from(CONFIG_VERSION));
+    Ok(notes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migrations_cover_every_version() {
+        assert_eq!(MIGRATIONS.len(), CONFIG_VERSION as usize);
+    }
+
+    #[test]
+    fn test_current_version_is_unchanged() {
+        let mut value = serde_json::json!({ "version": CONFIG_VERSION, "notunes": {} });
+        let original = value.clone();
+
+        assert!(migrate(&mut value).unwrap().is_empty());
+        assert_eq!(value, original);
+    }
+
+    #[test]
+    fn test_missing_version_is_stamped() {
+        let mut value = serde_json::json!({ "notunes": { "enabled": true } });
+
+        assert!(migrate(&mut value).unwrap().is_empty());
+        assert_eq!(value["version"], CONFIG_VERSION);
+        assert_eq!(value["notunes"]["enabled"], true);
+    }
+
+    #[test]
+    fn test_newer_version_is_rejected() {
+        let mut value = serde_json::json!({ "version": CONFIG_VERSION + 1 });
+        let err = migrate(&mut value).unwrap_err();
+
+        assert!(matches!(err, ConfigError::UnsupportedVersion(v) if v == CONFIG_VERSION + 1));
+        assert!(err.to_string().contains("update Stache"));
+    }
+
+    #[test]
+    fn test_malformed_version_is_rejected() {
+        let mut value = serde_json::json!({ "version": "one" });
+        assert!(matches!(migrate(&mut value), Err(ConfigError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_default_version_is_current() {
+        assert_eq!(ConfigVersion::default(), ConfigVersion(CONFIG_VERSION));
+    }
+}
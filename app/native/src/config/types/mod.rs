@@ -11,7 +11,11 @@ pub mod borders;
 pub mod color;
 pub mod command_quit;
 pub mod gaps;
+pub mod hotkeys;
+pub mod idle;
+pub mod logs;
 pub mod menu_anywhere;
+pub mod migration;
 pub mod notunes;
 pub mod root;
 pub mod tiling;
@@ -21,9 +25,11 @@ pub mod workspaces;
 // Re-export all types for backward compatibility and convenience
 
 // Audio types
-pub use audio::{AudioDeviceDependency, AudioDevicePriority, MatchStrategy, ProxyAudioConfig};
+pub use audio::{
+    AudioConfig, AudioDeviceDependency, AudioDevicePriority, MatchStrategy, ProxyAudioConfig,
+};
 // Bar types
-pub use bar::{BarConfig, WeatherConfig, WeatherProvider};
+pub use bar::{BarConfig, BarMaterial, BarPosition, MediaConfig, WeatherConfig, WeatherProvider};
 // Border types
 pub use borders::{BorderColor, BorderStateConfig, BordersConfig, GradientConfig};
 // Color types
@@ -32,8 +38,14 @@ pub use color::{Rgba, parse_color, parse_hex_color, parse_rgba_color};
 pub use command_quit::CommandQuitConfig;
 // Gap types
 pub use gaps::{DimensionValue, GapValue, GapsConfig, GapsConfigValue};
+// Idle types
+pub use idle::{IdleAction, IdleConfig};
+// Log file types
+pub use logs::LogsConfig;
 // Menu Anywhere types
 pub use menu_anywhere::{MenuAnywhereConfig, MenuAnywhereModifier, MenuAnywhereMouseButton};
+// Migration types
+pub use migration::{CONFIG_VERSION, ConfigVersion};
 // NoTunes types
 pub use notunes::{NoTunesConfig, TargetMusicApp};
 // Root config types
@@ -42,10 +54,12 @@ pub use root::{
 };
 // Tiling types
 pub use tiling::{
-    AnimationConfig, EasingType, FloatingConfig, FloatingPreset, LayoutType, MasterConfig,
-    MasterPosition, TilingConfig,
+    AnimationConfig, AppInsets, AutoLayout, AutoLayoutThreshold, DwindleConfig, DwindleSplit,
+    EasingType, FloatingConfig, FloatingPreset, FocusAfterClose, LayoutType, MasterConfig,
+    MasterPosition, MoveModeConfig, ScreenLayout, ScreenLayoutConfig, TilingConfig,
+    WindowOpenAnimation,
 };
 // Wallpaper types
-pub use wallpaper::{WallpaperConfig, WallpaperMode};
+pub use wallpaper::{WallpaperConfig, WallpaperMode, WallpaperScaling};
 // Workspace types
-pub use workspaces::{WindowRule, WorkspaceConfig};
+pub use workspaces::{DisplayRule, WindowRule, WorkspaceConfig};
@@ -57,6 +57,9 @@ impl TargetMusicApp {
     }
 }
 
+/// Bundle identifiers intercepted by default (Apple Music and legacy iTunes).
+const DEFAULT_INTERCEPT: [&str; 2] = ["com.apple.Music", "com.apple.iTunes"];
+
 /// Configuration for the noTunes feature.
 ///
 /// noTunes prevents Apple Music or iTunes from launching automatically
@@ -69,17 +72,31 @@ pub struct NoTunesConfig {
     /// Default: false
     pub enabled: bool,
 
+    /// Bundle identifiers of the apps to block from launching.
+    /// Default: ["com.apple.Music", "com.apple.iTunes"]
+    pub intercept: Vec<String>,
+
     /// The music app to launch when Apple Music/iTunes is blocked.
     /// Options: "tidal", "spotify", "feishin", "none"
+    /// Ignored when `launchInstead` is set.
     /// Default: "spotify"
     pub target_app: TargetMusicApp,
+
+    /// App to open when an intercepted app is blocked, as a bundle identifier
+    /// (e.g., "com.spotify.client") or a path to an `.app` bundle.
+    /// Takes precedence over `targetApp`.
+    /// Default: null
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub launch_instead: Option<String>,
 }
 
 impl Default for NoTunesConfig {
     fn default() -> Self {
         Self {
             enabled: false,
+            intercept: DEFAULT_INTERCEPT.iter().map(ToString::to_string).collect(),
             target_app: TargetMusicApp::Spotify,
+            launch_instead: None,
         }
     }
 }
@@ -88,6 +105,12 @@ impl NoTunesConfig {
     /// Returns whether noTunes functionality is enabled.
     #[must_use]
     pub const fn is_enabled(&self) -> bool { self.enabled }
+
+    /// Returns whether launches of the given bundle identifier are blocked.
+    #[must_use]
+    pub fn intercepts(&self, bundle_id: &str) -> bool {
+        self.intercept.iter().any(|id| id == bundle_id)
+    }
 }
 
 #[cfg(test)]
@@ -134,5 +157,29 @@ mod tests {
         let config = NoTunesConfig::default();
         assert!(!config.enabled);
         assert_eq!(config.target_app, TargetMusicApp::Spotify);
+        assert_eq!(config.intercept, vec!["com.apple.Music", "com.apple.iTunes"]);
+        assert!(config.launch_instead.is_none());
+    }
+
+    #[test]
+    fn test_notunes_config_intercepts() {
+        let config = NoTunesConfig::default();
+        assert!(config.intercepts("com.apple.Music"));
+        assert!(config.intercepts("com.apple.iTunes"));
+        assert!(!config.intercepts("com.spotify.client"));
+        assert!(!config.intercepts("com.apple.music"));
+    }
+
+    #[test]
+    fn test_notunes_config_custom_intercept() {
+        let config: NoTunesConfig = serde_json::from_str(
+            r#"{ "enabled": true, "intercept": ["com.apple.TV"], "launchInstead": "com.spotify.client" }"#,
+        )
+        .unwrap();
+
+        assert!(config.intercepts("com.apple.TV"));
+        assert!(!config.intercepts("com.apple.Music"));
+        assert_eq!(config.launch_instead.as_deref(), Some("com.spotify.client"));
+        assert_eq!(config.target_app, TargetMusicApp::Spotify);
     }
 }
@@ -4,15 +4,19 @@
 
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use super::audio::ProxyAudioConfig;
+use super::audio::{AudioConfig, ProxyAudioConfig};
 use super::bar::BarConfig;
 use super::command_quit::CommandQuitConfig;
+use super::hotkeys::{HotkeysConfig, validate_hotkeys};
+use super::idle::IdleConfig;
+use super::logs::LogsConfig;
 use super::menu_anywhere::MenuAnywhereConfig;
+use super::migration::{CONFIG_VERSION, ConfigVersion, migrate};
 use super::notunes::NoTunesConfig;
 use super::tiling::TilingConfig;
 use super::wallpaper::WallpaperConfig;
@@ -75,6 +79,23 @@ impl ShortcutCommands {
 #[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct StacheConfig {
+    /// JSON Schema reference for editor validation and completion.
+    ///
+    /// Generate a local copy with `stache schema --output <path>`. Ignored by the app.
+    #[serde(rename = "$schema", skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+
+    /// Config schema version the file was written for.
+    ///
+    /// Files without a version are read as version 0, which has the same shape
+    /// as version 1. Files with a newer version than the app supports are rejected.
+    pub version: ConfigVersion,
+
+    /// Audio configuration.
+    ///
+    /// Controls automatic output switching when preferred devices connect.
+    pub audio: AudioConfig,
+
     /// Bar configuration for status bar UI components.
     ///
     /// Contains settings for weather.
@@ -103,6 +124,15 @@ pub struct StacheConfig {
     /// The value is either a single command string or an array of commands.
     pub keybindings: HashMap<String, ShortcutCommands>,
 
+    /// Tiling action hotkeys.
+    ///
+    /// Maps a tiling action name to a key combination, e.g.
+    /// `"focus-left": "Option+H"` or `"workspace-code": "Option+1"`.
+    /// `custom` maps key combinations to shell commands instead.
+    /// A combination may only be bound once across `hotkeys` and `keybindings`.
+    /// Changes are applied without restarting the app.
+    pub hotkeys: HotkeysConfig,
+
     /// Commands to execute once when Stache starts.
     ///
     /// The value is either a single command string or an array of commands.
@@ -110,6 +140,18 @@ pub struct StacheConfig {
     #[serde(rename = "execOnStartup")]
     pub exec_on_startup: ShortcutCommands,
 
+    /// Idle detection configuration.
+    ///
+    /// Dispatches an action (switch workspace, release keep-awake) after a
+    /// period without input. Disabled by default.
+    pub idle: IdleConfig,
+
+    /// Log file configuration.
+    ///
+    /// Writes logs to a rotating file in the cache directory, to attach to
+    /// bug reports. Enabled by default.
+    pub logs: LogsConfig,
+
     /// `MenuAnywhere` configuration.
     ///
     /// Allows summoning the current application's menu bar at the cursor position.
@@ -155,6 +197,19 @@ impl StacheConfig {
             for rule in &mut workspace.rules {
                 rule.prepare();
             }
+            for rule in workspace.ignore.iter_mut().flatten() {
+                rule.prepare();
+            }
+        }
+
+        // Prepare float rules
+        for rule in &mut self.tiling.float_rules {
+            rule.prepare();
+        }
+
+        // Prepare display rules
+        for display_rule in &mut self.tiling.display_rules {
+            display_rule.rule.prepare();
         }
 
         // Prepare border ignore rules
@@ -162,6 +217,18 @@ impl StacheConfig {
             rule.prepare();
         }
     }
+
+    /// Validates cross-field constraints that cannot be expressed in the schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidConfig` if a hotkey combination is bound twice,
+    /// a floating preset has an invalid size or an app inset is negative.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        validate_hotkeys(&self.hotkeys, &self.keybindings)?;
+        self.tiling.floating.validate_presets()?;
+        self.tiling.validate_app_insets()
+    }
 }
 
 /// Errors that can occur when loading the configuration.
@@ -173,6 +240,23 @@ pub enum ConfigError {
     IoError(std::io::Error),
     /// The configuration file contains invalid JSON.
     ParseError(serde_json::Error),
+    /// The configuration file contains invalid TOML.
+    TomlParseError(toml::de::Error),
+    /// The configuration file is valid JSON but violates a constraint.
+    InvalidConfig(String),
+    /// The configuration file was written for a newer schema version.
+    UnsupportedVersion(u32),
+}
+
+impl ConfigError {
+    /// Returns the 1-based line and column of a parse error, if known.
+    #[must_use]
+    pub fn location(&self) -> Option<(usize, usize)> {
+        match self {
+            Self::ParseError(err) if err.line() > 0 => Some((err.line(), err.column())),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for ConfigError {
@@ -181,10 +265,18 @@ impl std::fmt::Display for ConfigError {
             Self::NotFound => write!(
                 f,
                 "No configuration file found. Expected at ~/.config/stache/config.json, \
-                ~/Library/Application Support/stache/config.json, or ~/.stache.json"
+                ~/Library/Application Support/stache/config.json, or ~/.stache.json \
+                (or the .jsonc and .toml variants)"
             ),
             Self::IoError(err) => write!(f, "Failed to read configuration file: {err}"),
             Self::ParseError(err) => write!(f, "Failed to parse configuration file: {err}"),
+            Self::TomlParseError(err) => write!(f, "Failed to parse configuration file: {err}"),
+            Self::InvalidConfig(msg) => write!(f, "Invalid configuration: {msg}"),
+            Self::UnsupportedVersion(version) => write!(
+                f,
+                "Configuration version {version} is newer than this app supports \
+                (version {CONFIG_VERSION}). Please update Stache to use this config file."
+            ),
         }
     }
 }
@@ -194,7 +286,8 @@ impl std::error::Error for ConfigError {
         match self {
             Self::IoError(err) => Some(err),
             Self::ParseError(err) => Some(err),
-            Self::NotFound => None,
+            Self::TomlParseError(err) => Some(err),
+            Self::NotFound | Self::InvalidConfig(_) | Self::UnsupportedVersion(_) => None,
         }
     }
 }
@@ -207,20 +300,27 @@ impl From<serde_json::Error> for ConfigError {
     fn from(err: serde_json::Error) -> Self { Self::ParseError(err) }
 }
 
+impl From<toml::de::Error> for ConfigError {
+    fn from(err: toml::de::Error) -> Self { Self::TomlParseError(err) }
+}
+
 /// Configuration file names to search for (in priority order).
-const CONFIG_FILE_NAMES: &[&str] = &["config.jsonc", "config.json"];
+const CONFIG_FILE_NAMES: &[&str] = &["config.jsonc", "config.json", "config.toml"];
 
 /// Legacy configuration file names in home directory.
-const LEGACY_CONFIG_FILE_NAMES: &[&str] = &[".stache.jsonc", ".stache.json"];
+const LEGACY_CONFIG_FILE_NAMES: &[&str] = &[".stache.jsonc", ".stache.json", ".stache.toml"];
 
 /// Returns the possible configuration file paths in priority order.
 ///
-/// The function checks the following locations (both `.jsonc` and `.json` variants):
-/// 1. `~/.config/stache/config.jsonc` or `config.json`
-/// 2. `~/Library/Application Support/stache/config.jsonc` or `config.json` (macOS native)
-/// 3. `~/.stache.jsonc` or `~/.stache.json` (legacy/simple location)
+/// The function checks the following locations (`.jsonc`, `.json` and `.toml` variants):
+/// 1. `~/.config/stache/config.jsonc`, `config.json` or `config.toml`
+/// 2. `~/Library/Application Support/stache/config.jsonc`, `config.json` or
+///    `config.toml` (macOS native)
+/// 3. `~/.stache.jsonc`, `~/.stache.json` or `~/.stache.toml` (legacy/simple location)
 ///
-/// If `$XDG_CONFIG_HOME` is set, it takes priority over `~/.config`.
+/// If `$XDG_CONFIG_HOME` is set, it takes priority over `~/.config`. A location
+/// always wins over the ones after it, and within a location JSONC wins over
+/// JSON, which wins over TOML.
 #[must_use]
 pub fn config_paths() -> Vec<PathBuf> {
     let mut paths = Vec::new();
@@ -266,11 +366,28 @@ pub fn config_paths() -> Vec<PathBuf> {
     paths
 }
 
+/// Returns whether a configuration file is TOML, going by its `.toml` extension.
+#[must_use]
+pub fn is_toml_config(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("toml"))
+}
+
+/// Parses TOML configuration contents into the JSON value the config types read.
+///
+/// # Errors
+///
+/// Returns `ConfigError::TomlParseError` if the contents are not valid TOML.
+pub fn parse_toml_config(contents: &str) -> Result<serde_json::Value, ConfigError> {
+    Ok(toml::from_str(contents)?)
+}
+
 /// Loads the configuration from a specific file path.
 ///
 /// The configuration file supports JSONC format (JSON with comments).
 /// Both single-line (`//`) and multi-line (`/* */`) comments are stripped
-/// before parsing.
+/// before parsing. Files with a `.toml` extension are read as TOML into the
+/// same configuration. Files written for an older schema version are migrated
+/// to the current one, logging each change.
 ///
 /// # Arguments
 ///
@@ -286,17 +403,32 @@ pub fn config_paths() -> Vec<PathBuf> {
 /// Returns `ConfigError::NotFound` if the configuration file does not exist.
 /// Returns `ConfigError::IoError` if the configuration file could not be read.
 /// Returns `ConfigError::ParseError` if the configuration file contains invalid JSON.
+/// Returns `ConfigError::TomlParseError` if the configuration file contains invalid TOML.
+/// Returns `ConfigError::InvalidConfig` if the configuration violates a constraint.
+/// Returns `ConfigError::UnsupportedVersion` if the file targets a newer schema version.
 pub fn load_config_from_path(path: &PathBuf) -> Result<(StacheConfig, PathBuf), ConfigError> {
     if !path.exists() {
         return Err(ConfigError::NotFound);
     }
 
-    let file = fs::File::open(path)?;
-    // Strip comments from JSONC before parsing
-    let reader = json_comments::StripComments::new(file);
-    let mut config: StacheConfig = serde_json::from_reader(reader)?;
+    let mut value = if is_toml_config(path) {
+        parse_toml_config(&fs::read_to_string(path)?)?
+    } else {
+        let file = fs::File::open(path)?;
+        // Strip comments from JSONC before parsing
+        let reader = json_comments::StripComments::new(file);
+        serde_json::from_reader(reader)?
+    };
+
+    // Upgrade older config shapes to the current schema version
+    for note in migrate(&mut value)? {
+        tracing::info!(path = %path.display(), "config: migrated: {note}");
+    }
+
+    let mut config: StacheConfig = serde_json::from_value(value)?;
     // Pre-compute cached values for faster runtime operations
     config.prepare();
+    config.validate()?;
     Ok((config, path.clone()))
 }
 
@@ -317,6 +449,7 @@ pub fn load_config_from_path(path: &PathBuf) -> Result<(StacheConfig, PathBuf),
 /// Returns `ConfigError::NotFound` if no configuration file exists in any of the expected locations.
 /// Returns `ConfigError::IoError` if a configuration file exists but could not be read.
 /// Returns `ConfigError::ParseError` if the configuration file contains invalid JSON.
+/// Returns `ConfigError::TomlParseError` if the configuration file contains invalid TOML.
 pub fn load_config() -> Result<(StacheConfig, PathBuf), ConfigError> {
     for path in config_paths() {
         if path.exists() {
@@ -391,6 +524,37 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn test_toml_config_reads_into_same_config() {
+        let toml = r#"
+            [keybindings]
+            "Ctrl+Shift+S" = "stache reload"
+
+            [tiling]
+            enabled = true
+            defaultLayout = "master"
+        "#;
+
+        let value = parse_toml_config(toml).unwrap();
+        let config: StacheConfig = serde_json::from_value(value).unwrap();
+
+        assert!(config.tiling.enabled);
+        assert_eq!(config.tiling.default_layout, crate::config::LayoutType::Master);
+        assert_eq!(config.keybindings.len(), 1);
+    }
+
+    #[test]
+    fn test_config_paths_prefer_jsonc_over_toml() {
+        assert!(is_toml_config(Path::new("/tmp/config.toml")));
+        assert!(!is_toml_config(Path::new("/tmp/config.jsonc")));
+
+        let paths = config_paths();
+        let position = |name: &str| paths.iter().position(|p| p.ends_with(name));
+        if let (Some(jsonc), Some(toml)) = (position("config.jsonc"), position("config.toml")) {
+            assert!(jsonc < toml);
+        }
+    }
+
     #[test]
     fn test_config_paths_are_not_empty() {
         let paths = config_paths();
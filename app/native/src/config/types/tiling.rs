@@ -3,12 +3,15 @@
 //! Core configuration types for the tiling window manager including layouts,
 //! animations, floating window settings, and master layout configuration.
 
+use std::collections::HashMap;
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::borders::BordersConfig;
 use super::gaps::{DimensionValue, GapsConfigValue};
-use super::workspaces::{WindowRule, WorkspaceConfig};
+use super::root::ConfigError;
+use super::workspaces::{DisplayRule, WindowRule, WorkspaceConfig};
 
 /// Layout type for workspaces.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
@@ -53,6 +56,46 @@ impl LayoutType {
     }
 }
 
+/// The `"auto"` keyword of [`ScreenLayout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AutoLayout {
+    /// Choose based on screen orientation.
+    Auto,
+}
+
+/// Default layout for a screen: a layout name or `"auto"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum ScreenLayout {
+    /// Always use this layout.
+    Layout(LayoutType),
+    /// Automatically choose based on screen orientation.
+    /// - Landscape screens: `defaultLayout`
+    /// - Portrait screens: split-vertical
+    Auto(AutoLayout),
+}
+
+/// Default layout for workspaces created automatically on a screen.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScreenLayoutConfig {
+    /// Screen identifier: "main"/"primary", "secondary", or screen name.
+    pub screen: String,
+    /// Layout for the screen's auto-created workspaces: a layout or "auto".
+    pub layout: ScreenLayout,
+}
+
+/// Layout a workspace switches to once it holds a number of windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoLayoutThreshold {
+    /// Number of tiled windows from which the layout applies.
+    pub windows: usize,
+    /// Layout used from that window count up to the next threshold.
+    pub layout: LayoutType,
+}
+
 /// Easing function for animations.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
@@ -72,6 +115,22 @@ pub enum EasingType {
     Spring,
 }
 
+/// Entrance animation for windows that open.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum WindowOpenAnimation {
+    /// The window appears in its tile right away.
+    #[default]
+    None,
+    /// The window fades in once in its tile.
+    ///
+    /// Changing another app's window opacity needs a scripting addition with
+    /// SIP partially disabled; without it the window appears right away.
+    Fade,
+    /// The window grows into its tile from a slightly smaller frame.
+    Scale,
+}
+
 /// Default position for floating windows.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
@@ -99,6 +158,11 @@ pub struct AnimationConfig {
     /// Easing function for animations.
     /// Default: "ease-out"
     pub easing: EasingType,
+
+    /// Entrance animation for tiled windows opening in a visible workspace.
+    /// Windows found at startup are never animated.
+    /// Default: "none"
+    pub window_open: WindowOpenAnimation,
 }
 
 impl Default for AnimationConfig {
@@ -107,6 +171,7 @@ impl Default for AnimationConfig {
             enabled: false,
             duration: 200,
             easing: EasingType::EaseOut,
+            window_open: WindowOpenAnimation::None,
         }
     }
 }
@@ -135,6 +200,73 @@ pub struct FloatingPreset {
     /// If true, center the window on screen (x and y are ignored).
     #[serde(default)]
     pub center: bool,
+
+    /// If true, center the window on the mouse cursor, kept on screen
+    /// (x, y and center are ignored).
+    #[serde(default)]
+    pub under_cursor: bool,
+}
+
+impl FloatingPreset {
+    /// Names of the presets available without any configuration.
+    pub const BUILTIN_NAMES: [&str; 16] = [
+        "center",
+        "center-large",
+        "under-cursor",
+        "maximize",
+        "left-half",
+        "right-half",
+        "top-half",
+        "bottom-half",
+        "top-left",
+        "top-right",
+        "bottom-left",
+        "bottom-right",
+        "left-third",
+        "left-two-thirds",
+        "right-third",
+        "right-two-thirds",
+    ];
+
+    /// Returns the built-in preset with the given name (case-insensitive).
+    #[must_use]
+    pub fn builtin(name: &str) -> Option<Self> {
+        let name = name.to_ascii_lowercase();
+
+        // (width, height, (x, y)); no position means centered
+        let (width, height, position) = match name.as_str() {
+            "center" => ("60%", "70%", None),
+            "center-large" => ("80%", "80%", None),
+            "under-cursor" => ("50%", "50%", None),
+            "maximize" => ("100%", "100%", Some(("0%", "0%"))),
+            "left-half" => ("50%", "100%", Some(("0%", "0%"))),
+            "right-half" => ("50%", "100%", Some(("50%", "0%"))),
+            "top-half" => ("100%", "50%", Some(("0%", "0%"))),
+            "bottom-half" => ("100%", "50%", Some(("0%", "50%"))),
+            "top-left" => ("50%", "50%", Some(("0%", "0%"))),
+            "top-right" => ("50%", "50%", Some(("50%", "0%"))),
+            "bottom-left" => ("50%", "50%", Some(("0%", "50%"))),
+            "bottom-right" => ("50%", "50%", Some(("50%", "50%"))),
+            "left-third" => ("33.333%", "100%", Some(("0%", "0%"))),
+            "left-two-thirds" => ("66.667%", "100%", Some(("0%", "0%"))),
+            "right-third" => ("33.333%", "100%", Some(("66.667%", "0%"))),
+            "right-two-thirds" => ("66.667%", "100%", Some(("33.333%", "0%"))),
+            _ => return None,
+        };
+
+        let percentage = |value: &str| DimensionValue::Percentage(value.to_string());
+        let under_cursor = name == "under-cursor";
+
+        Some(Self {
+            width: percentage(width),
+            height: percentage(height),
+            x: position.map(|(x, _)| percentage(x)),
+            y: position.map(|(_, y)| percentage(y)),
+            center: position.is_none() && !under_cursor,
+            under_cursor,
+            name,
+        })
+    }
 }
 
 /// Floating windows configuration.
@@ -146,9 +278,77 @@ pub struct FloatingConfig {
     pub default_position: FloatingPosition,
 
     /// Named presets for window positioning.
+    /// Merged with the built-in presets (`center`, `center-large`, `under-cursor`,
+    /// `maximize`, halves, quarters and thirds); a preset here with the same
+    /// name replaces the built-in one.
     pub presets: Vec<FloatingPreset>,
 }
 
+impl FloatingConfig {
+    /// Finds a preset by name (case-insensitive), checking the configured
+    /// presets before the built-in ones.
+    #[must_use]
+    pub fn find_preset(&self, name: &str) -> Option<FloatingPreset> {
+        self.presets
+            .iter()
+            .find(|p| p.name.eq_ignore_ascii_case(name))
+            .cloned()
+            .or_else(|| FloatingPreset::builtin(name))
+    }
+
+    /// Returns the names of the configured presets followed by the built-in
+    /// presets they don't replace.
+    #[must_use]
+    pub fn preset_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.presets.iter().map(|p| p.name.clone()).collect();
+
+        for builtin in FloatingPreset::BUILTIN_NAMES {
+            if !names.iter().any(|name| name.eq_ignore_ascii_case(builtin)) {
+                names.push(builtin.to_string());
+            }
+        }
+
+        names
+    }
+
+    /// Validates the configured presets.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidConfig` listing every preset with an empty
+    /// name or a width or height that is not a positive size.
+    pub fn validate_presets(&self) -> Result<(), ConfigError> {
+        let mut problems = Vec::new();
+
+        for (index, preset) in self.presets.iter().enumerate() {
+            let label = if preset.name.trim().is_empty() {
+                problems.push(format!("preset #{} has an empty name", index + 1));
+                format!("#{}", index + 1)
+            } else {
+                format!("'{}'", preset.name)
+            };
+
+            for (field, value) in [("width", &preset.width), ("height", &preset.height)] {
+                if !value.is_positive_size() {
+                    problems.push(format!(
+                        "preset {label} has an invalid {field} {} (must be above 0, at most 100%)",
+                        serde_json::to_string(value).unwrap_or_default()
+                    ));
+                }
+            }
+        }
+
+        if problems.is_empty() {
+            return Ok(());
+        }
+
+        Err(ConfigError::InvalidConfig(format!(
+            "invalid floating presets: {}",
+            problems.join("; ")
+        )))
+    }
+}
+
 /// Position of the master window in the master layout.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
@@ -189,6 +389,77 @@ impl Default for MasterConfig {
     }
 }
 
+/// Orientation of the first split in the dwindle layout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum DwindleSplit {
+    /// Automatically choose based on screen orientation.
+    /// - Landscape screens: horizontal
+    /// - Portrait screens: vertical
+    #[default]
+    Auto,
+    /// Windows side by side (left/right).
+    Horizontal,
+    /// Windows stacked (top/bottom).
+    Vertical,
+}
+
+/// Dwindle layout configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DwindleConfig {
+    /// Orientation of the first split. Later splits alternate from it.
+    /// Default: auto (horizontal for landscape, vertical for portrait)
+    pub first_split: DwindleSplit,
+}
+
+/// Window focused when the focused window closes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum FocusAfterClose {
+    /// The window that took the closed window's place in the stack, or the
+    /// one before it if the closed window was last.
+    #[default]
+    Next,
+    /// The most recently focused other window of the workspace.
+    Previous,
+    /// The first tiled window of the workspace.
+    Master,
+}
+
+/// Keyboard move mode configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct MoveModeConfig {
+    /// Pixels a floating window moves per arrow key press.
+    /// Default: 20
+    pub step: u32,
+    /// Seconds without a key press before move mode ends, keeping the window
+    /// where it is.
+    /// Default: 5
+    pub timeout: u64,
+}
+
+impl Default for MoveModeConfig {
+    fn default() -> Self { Self { step: 20, timeout: 5 } }
+}
+
+/// Space kept free around a window inside its tile, in pixels.
+///
+/// Values must not be negative.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct AppInsets {
+    /// Pixels kept free above the window.
+    pub top: f64,
+    /// Pixels kept free left of the window.
+    pub left: f64,
+    /// Pixels kept free below the window.
+    pub bottom: f64,
+    /// Pixels kept free right of the window.
+    pub right: f64,
+}
+
 /// Tiling window manager configuration.
 ///
 /// Provides virtual workspace management with multiple layout modes,
@@ -204,13 +475,93 @@ pub struct TilingConfig {
     /// Default: "dwindle"
     pub default_layout: LayoutType,
 
+    /// Default layout of workspaces created automatically on specific screens
+    /// (when no workspaces are configured, or a screen has none).
+    /// Screens without an entry use `defaultLayout`.
+    /// Default: []
+    pub screen_layouts: Vec<ScreenLayoutConfig>,
+
+    /// Layouts visited when cycling layouts, in order.
+    /// If empty, cycles through every layout.
+    /// Default: []
+    pub layout_cycle: Vec<LayoutType>,
+
+    /// Layout `--layout-toggle` switches a workspace to and back from.
+    /// Default: "monocle"
+    pub layout_toggle_alternate: LayoutType,
+
+    /// Whether cycling layouts briefly outlines where the windows will go
+    /// before moving them.
+    /// Default: false
+    pub preview_on_cycle: bool,
+
+    /// Layouts workspaces switch to by the number of tiled windows they hold.
+    /// Each entry applies from its window count up to the next entry's, e.g.
+    /// monocle from 1, split from 2 and dwindle from 3 windows.
+    /// Setting a layout by hand pins it until `--layout-auto` is used.
+    /// If empty, workspaces keep their layout.
+    /// Default: []
+    pub auto_layout: Vec<AutoLayoutThreshold>,
+
     /// Workspace definitions.
     /// If empty and tiling is enabled, creates one default workspace per screen.
     pub workspaces: Vec<WorkspaceConfig>,
 
+    /// Maximum number of tiled windows per workspace. New windows assigned to
+    /// a full workspace go to `overflowWorkspace`, or else to the next
+    /// workspace on the same screen with room.
+    /// Set to 0 for no limit.
+    /// Default: 0
+    pub max_windows: u32,
+
+    /// Workspace new windows go to when their workspace holds `maxWindows`.
+    /// If not specified, the next workspace on the same screen with room.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overflow_workspace: Option<String>,
+
+    /// Rules pinning windows to a display, whatever their workspace rules say.
+    /// Matching windows join the visible workspace of that display unless a
+    /// workspace rule points to a workspace on it.
+    /// Default: []
+    pub display_rules: Vec<DisplayRule>,
+
     /// Applications/windows to ignore (never managed by tiling).
+    /// Workspaces can replace these rules with their own `ignore` list.
     pub ignore: Vec<WindowRule>,
 
+    /// Whether windows without a title when they appear are never managed.
+    /// Apps in `forceTile` are managed regardless.
+    /// Default: false
+    pub ignore_untitled: bool,
+
+    /// Bundle IDs of apps whose windows are always tiled, even when their size
+    /// or subrole would normally leave them unmanaged.
+    /// Takes precedence over `forceFloat`.
+    /// Default: []
+    pub force_tile: Vec<String>,
+
+    /// Bundle IDs of apps whose windows always float.
+    /// Default: []
+    pub force_float: Vec<String>,
+
+    /// Rules for windows that always float, unless their app is in `forceTile`.
+    /// Useful to float all dialogs with `{ "subrole": "AXDialog" }`.
+    /// Default: []
+    pub float_rules: Vec<WindowRule>,
+
+    /// Insets applied to windows of an app inside their tile, keyed by bundle ID.
+    /// Matching windows are shrunk by the inset on each side, other windows
+    /// keep their whole tile.
+    /// Default: {}
+    pub app_insets: HashMap<String, AppInsets>,
+
+    /// Roles assigned to displays, mapping a role to a display name or UUID.
+    /// Commands and workspace `screen` settings accept the role in place of a
+    /// display name, and the `primary` and `secondary` roles decide which
+    /// displays "main" and "secondary" refer to.
+    /// Default: {}
+    pub screen_roles: HashMap<String, String>,
+
     /// Animation settings for window transitions.
     pub animations: AnimationConfig,
 
@@ -223,9 +574,61 @@ pub struct TilingConfig {
     /// Master layout settings.
     pub master: MasterConfig,
 
+    /// Dwindle layout settings.
+    pub dwindle: DwindleConfig,
+
+    /// Keyboard move mode settings, for `stache tiling window --move-mode`.
+    pub move_mode: MoveModeConfig,
+
     /// Window border configuration.
     /// Borders provide visual feedback for focus state and layout mode.
     pub borders: BordersConfig,
+
+    /// Seconds between passes that resync tracked windows with the windows on
+    /// screen, re-applying the layout of affected workspaces.
+    /// Set to 0 to only resync on demand with `stache tiling reconcile`.
+    /// Default: 0
+    pub reconcile_interval: u64,
+
+    /// Whether focusing the next or previous window also visits floating
+    /// windows, in workspace order. Otherwise only tiled windows are cycled.
+    /// Default: false
+    pub cycle_includes_floating: bool,
+
+    /// Window focused when the focused window closes: "next" (the window
+    /// taking its place in the stack), "previous" (the most recently focused
+    /// other window) or "master" (the first tiled window).
+    /// Default: "next"
+    pub focus_after_close: FocusAfterClose,
+
+    /// How many more times focusing a window is tried when the app does not
+    /// respond, waiting a little longer before each try. Set to 0 to try once.
+    /// Default: 3
+    pub focus_retries: u32,
+
+    /// Whether promoting the first window or demoting the last one wraps it to
+    /// the other end of the stack. Otherwise the window stays put.
+    /// Default: false
+    pub wrap_stack_moves: bool,
+
+    /// Whether windows already open at startup are assigned to a workspace on the
+    /// screen they are on, instead of being moved to the screen of the workspace
+    /// their rule points to. Useful with windows arranged by hand before launch.
+    /// Windows opened later follow their rules as usual.
+    /// Default: false
+    pub respect_existing_screen: bool,
+
+    /// Milliseconds computing a workspace layout may take before a warning
+    /// with the window count and layout type is logged.
+    /// Set to 0 to disable the warning.
+    /// Default: 8
+    pub layout_budget: u64,
+
+    /// Whether the tiling window manager logs each event it handles and the
+    /// decisions it makes. Otherwise only its info, warnings and errors are
+    /// logged. `RUST_LOG` takes precedence when set.
+    /// Default: false
+    pub verbose: bool,
 }
 
 impl Default for TilingConfig {
@@ -233,13 +636,37 @@ impl Default for TilingConfig {
         Self {
             enabled: false,
             default_layout: LayoutType::Dwindle,
+            screen_layouts: Vec::new(),
+            layout_cycle: Vec::new(),
+            layout_toggle_alternate: LayoutType::Monocle,
+            preview_on_cycle: false,
+            auto_layout: Vec::new(),
             workspaces: Vec::new(),
+            max_windows: 0,
+            overflow_workspace: None,
+            display_rules: Vec::new(),
             ignore: Vec::new(),
+            ignore_untitled: false,
+            force_tile: Vec::new(),
+            force_float: Vec::new(),
+            float_rules: Vec::new(),
+            app_insets: HashMap::new(),
+            screen_roles: HashMap::new(),
             animations: AnimationConfig::default(),
             gaps: GapsConfigValue::default(),
             floating: FloatingConfig::default(),
             master: MasterConfig::default(),
+            dwindle: DwindleConfig::default(),
+            move_mode: MoveModeConfig::default(),
             borders: BordersConfig::default(),
+            reconcile_interval: 0,
+            cycle_includes_floating: false,
+            focus_after_close: FocusAfterClose::Next,
+            focus_retries: 3,
+            wrap_stack_moves: false,
+            respect_existing_screen: false,
+            layout_budget: 8,
+            verbose: false,
         }
     }
 }
@@ -248,12 +675,232 @@ impl TilingConfig {
     /// Returns whether the tiling window manager is enabled.
     #[must_use]
     pub const fn is_enabled(&self) -> bool { self.enabled }
+
+    /// Returns whether windows of an app are listed in `forceTile`.
+    #[must_use]
+    pub fn is_force_tiled(&self, app_id: &str) -> bool {
+        self.force_tile.iter().any(|id| id.eq_ignore_ascii_case(app_id))
+    }
+
+    /// Returns the `appInsets` entry for windows of an app, if any.
+    #[must_use]
+    pub fn app_insets_for(&self, app_id: &str) -> Option<&AppInsets> {
+        self.app_insets
+            .iter()
+            .find(|(id, _)| id.eq_ignore_ascii_case(app_id))
+            .map(|(_, insets)| insets)
+    }
+
+    /// Validates the configured app insets.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigError::InvalidConfig` listing every app with a negative
+    /// inset.
+    pub fn validate_app_insets(&self) -> Result<(), ConfigError> {
+        let mut problems: Vec<String> = self
+            .app_insets
+            .iter()
+            .flat_map(|(app_id, insets)| {
+                [
+                    ("top", insets.top),
+                    ("left", insets.left),
+                    ("bottom", insets.bottom),
+                    ("right", insets.right),
+                ]
+                .into_iter()
+                .filter(|(_, value)| *value < 0.0)
+                .map(move |(side, value)| format!("'{app_id}' has a negative {side} inset {value}"))
+            })
+            .collect();
+
+        if problems.is_empty() {
+            return Ok(());
+        }
+
+        problems.sort();
+        Err(ConfigError::InvalidConfig(format!(
+            "invalid app insets: {}",
+            problems.join("; ")
+        )))
+    }
+
+    /// Returns the `autoLayout` layout for a workspace holding `window_count`
+    /// tiled windows: the entry with the highest window count not above it.
+    ///
+    /// `None` when no entry applies, including when `autoLayout` is empty.
+    #[must_use]
+    pub fn auto_layout_for(&self, window_count: usize) -> Option<LayoutType> {
+        self.auto_layout
+            .iter()
+            .filter(|threshold| threshold.windows <= window_count)
+            .max_by_key(|threshold| threshold.windows)
+            .map(|threshold| threshold.layout)
+    }
+
+    /// Returns the roles assigned to a display by its name or UUID, in
+    /// alphabetical order.
+    ///
+    /// Both are compared case-insensitively. A display can hold several
+    /// roles, and answers to each of them.
+    #[must_use]
+    pub fn screen_roles_for(&self, name: &str, uuid: &str) -> Vec<String> {
+        let mut roles: Vec<String> = self
+            .screen_roles
+            .iter()
+            .filter(|(_, display)| {
+                display.eq_ignore_ascii_case(name)
+                    || (!uuid.is_empty() && display.eq_ignore_ascii_case(uuid))
+            })
+            .map(|(role, _)| role.clone())
+            .collect();
+        roles.sort_unstable();
+        roles
+    }
+
+    /// Returns the ignore rules for windows assigned to a workspace: its own
+    /// `ignore` override, or the global `ignore` rules.
+    #[must_use]
+    pub fn ignore_rules_for(&self, workspace: &str) -> &[WindowRule] {
+        self.workspaces
+            .iter()
+            .find(|ws| ws.name == workspace)
+            .and_then(|ws| ws.ignore.as_deref())
+            .unwrap_or(&self.ignore)
+    }
+
+    /// Returns the floating state forced on windows of an app, if any.
+    ///
+    /// `Some(false)` for apps in `forceTile`, `Some(true)` for apps in
+    /// `forceFloat`, and `None` when neither list has the app.
+    #[must_use]
+    pub fn forced_floating(&self, app_id: &str) -> Option<bool> {
+        if self.is_force_tiled(app_id) {
+            return Some(false);
+        }
+        self.force_float
+            .iter()
+            .any(|id| id.eq_ignore_ascii_case(app_id))
+            .then_some(true)
+    }
+
+    /// Returns the layout for workspaces created automatically on a screen.
+    ///
+    /// Uses the first `screenLayouts` entry matching the screen, falling back
+    /// to `defaultLayout`. `"auto"` picks split-vertical on portrait screens.
+    #[must_use]
+    pub fn default_layout_for_screen(
+        &self,
+        screen_name: &str,
+        is_main_screen: bool,
+        is_portrait: bool,
+    ) -> LayoutType {
+        let entry = self.screen_layouts.iter().find(|entry| {
+            entry.screen.eq_ignore_ascii_case(screen_name)
+                || ((entry.screen.eq_ignore_ascii_case("main")
+                    || entry.screen.eq_ignore_ascii_case("primary"))
+                    && is_main_screen)
+                || (entry.screen.eq_ignore_ascii_case("secondary") && !is_main_screen)
+        });
+
+        match entry.map(|entry| entry.layout) {
+            Some(ScreenLayout::Layout(layout)) => layout,
+            Some(ScreenLayout::Auto(_)) if is_portrait => LayoutType::SplitVertical,
+            Some(ScreenLayout::Auto(_)) | None => self.default_layout,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn preset(name: &str, width: DimensionValue, height: DimensionValue) -> FloatingPreset {
+        FloatingPreset {
+            name: name.to_string(),
+            width,
+            height,
+            x: None,
+            y: None,
+            center: false,
+            under_cursor: false,
+        }
+    }
+
+    #[test]
+    fn test_find_preset_prefers_configured_over_builtin() {
+        let config = FloatingConfig {
+            presets: vec![preset(
+                "Center",
+                DimensionValue::Pixels(800),
+                DimensionValue::Pixels(600),
+            )],
+            ..FloatingConfig::default()
+        };
+
+        let found = config.find_preset("center").unwrap();
+        assert!(matches!(found.width, DimensionValue::Pixels(800)));
+        assert!(config.find_preset("center-large").unwrap().center);
+        assert!(config.find_preset("missing").is_none());
+
+        assert_eq!(config.preset_names(), vec![
+            "Center",
+            "center-large",
+            "under-cursor"
+        ]);
+    }
+
+    #[test]
+    fn test_validate_presets_rejects_non_positive_sizes() {
+        let valid = FloatingConfig {
+            presets: vec![preset(
+                "left-third",
+                DimensionValue::Percentage("33.3%".to_string()),
+                DimensionValue::Pixels(900),
+            )],
+            ..FloatingConfig::default()
+        };
+        assert!(valid.validate_presets().is_ok());
+
+        let invalid = FloatingConfig {
+            presets: vec![
+                preset("zero", DimensionValue::Pixels(0), DimensionValue::Pixels(900)),
+                preset(
+                    "text",
+                    DimensionValue::Percentage("half".to_string()),
+                    DimensionValue::Percentage("120%".to_string()),
+                ),
+            ],
+            ..FloatingConfig::default()
+        };
+        let Err(ConfigError::InvalidConfig(message)) = invalid.validate_presets() else {
+            panic!("expected invalid presets");
+        };
+        assert!(message.contains("'zero' has an invalid width"));
+        assert!(message.contains("'text' has an invalid width"));
+        assert!(message.contains("'text' has an invalid height"));
+    }
+
+    #[test]
+    fn test_validate_app_insets_rejects_negative_values() {
+        let mut config = TilingConfig::default();
+        config.app_insets.insert("com.apple.terminal".to_string(), AppInsets {
+            top: 8.0,
+            ..AppInsets::default()
+        });
+        assert!(config.validate_app_insets().is_ok());
+
+        config.app_insets.insert("com.apple.safari".to_string(), AppInsets {
+            left: -4.0,
+            ..AppInsets::default()
+        });
+        let Err(ConfigError::InvalidConfig(message)) = config.validate_app_insets() else {
+            panic!("expected invalid app insets");
+        };
+        assert!(message.contains("'com.apple.safari' has a negative left inset -4"));
+        assert!(!message.contains("com.apple.terminal"));
+    }
+
     #[test]
     fn test_layout_type_default_is_floating() {
         assert_eq!(LayoutType::default(), LayoutType::Floating);
@@ -291,12 +938,42 @@ mod tests {
         assert_eq!(config.position, MasterPosition::Auto);
     }
 
+    #[test]
+    fn test_dwindle_config_deserialize() {
+        assert_eq!(DwindleConfig::default().first_split, DwindleSplit::Auto);
+
+        let config: DwindleConfig = serde_json::from_str(r#"{"firstSplit": "vertical"}"#).unwrap();
+        assert_eq!(config.first_split, DwindleSplit::Vertical);
+    }
+
     #[test]
     fn test_tiling_config_default() {
         let config = TilingConfig::default();
         assert!(!config.is_enabled());
         assert_eq!(config.default_layout, LayoutType::Dwindle);
         assert!(config.workspaces.is_empty());
+        assert!(!config.preview_on_cycle);
+        assert_eq!(config.max_windows, 0);
+        assert!(config.overflow_workspace.is_none());
+        assert!(!config.verbose);
+    }
+
+    #[test]
+    fn test_auto_layout_for_picks_highest_threshold_reached() {
+        let config: TilingConfig = serde_json::from_str(
+            r#"{"autoLayout": [
+                {"windows": 3, "layout": "dwindle"},
+                {"windows": 1, "layout": "monocle"},
+                {"windows": 2, "layout": "split"}
+            ]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.auto_layout_for(0), None);
+        assert_eq!(config.auto_layout_for(1), Some(LayoutType::Monocle));
+        assert_eq!(config.auto_layout_for(2), Some(LayoutType::Split));
+        assert_eq!(config.auto_layout_for(7), Some(LayoutType::Dwindle));
+        assert_eq!(TilingConfig::default().auto_layout_for(3), None);
     }
 
     #[test]
@@ -306,4 +983,134 @@ mod tests {
         assert!(config.enabled);
         assert_eq!(config.default_layout, LayoutType::Master);
     }
+
+    #[test]
+    fn test_forced_floating_prefers_force_tile() {
+        let config = TilingConfig {
+            force_tile: vec!["com.apple.systempreferences".to_string()],
+            force_float: vec![
+                "com.apple.calculator".to_string(),
+                "com.apple.systempreferences".to_string(),
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.forced_floating("com.apple.SystemPreferences"),
+            Some(false)
+        );
+        assert_eq!(config.forced_floating("com.apple.calculator"), Some(true));
+        assert_eq!(config.forced_floating("com.apple.Safari"), None);
+        assert!(config.is_force_tiled("com.apple.systempreferences"));
+        assert!(!config.is_force_tiled("com.apple.calculator"));
+    }
+
+    fn config_with_screen_layouts(screen_layouts: serde_json::Value) -> TilingConfig {
+        serde_json::from_value(serde_json::json!({
+            "defaultLayout": "dwindle",
+            "screenLayouts": screen_layouts,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_default_layout_for_screen_matches_entries() {
+        let config = config_with_screen_layouts(serde_json::json!([
+            { "screen": "main", "layout": "monocle" },
+            { "screen": "DELL U2720Q", "layout": "split-vertical" },
+        ]));
+
+        assert_eq!(
+            config.default_layout_for_screen("Built-in", true, false),
+            LayoutType::Monocle
+        );
+        assert_eq!(
+            config.default_layout_for_screen("dell u2720q", false, false),
+            LayoutType::SplitVertical
+        );
+        assert_eq!(
+            config.default_layout_for_screen("Other", false, false),
+            LayoutType::Dwindle
+        );
+    }
+
+    #[test]
+    fn test_default_layout_for_screen_auto_uses_orientation() {
+        let config = config_with_screen_layouts(serde_json::json!([
+            { "screen": "secondary", "layout": "auto" },
+        ]));
+
+        assert_eq!(
+            config.default_layout_for_screen("Side", false, true),
+            LayoutType::SplitVertical
+        );
+        assert_eq!(
+            config.default_layout_for_screen("Side", false, false),
+            LayoutType::Dwindle
+        );
+    }
+
+    #[test]
+    fn test_screen_roles_for_matches_name_or_uuid() {
+        let config = TilingConfig {
+            screen_roles: HashMap::from([
+                ("secondary".to_string(), "DELL U2720Q".to_string()),
+                (
+                    "side".to_string(),
+                    "37D8832A-2D66-02CA-B9F7-8F30A301B230".to_string(),
+                ),
+            ]),
+            ..TilingConfig::default()
+        };
+
+        assert_eq!(config.screen_roles_for("dell u2720q", ""), ["secondary"]);
+        assert_eq!(
+            config.screen_roles_for("LG HDR 4K", "37d8832a-2d66-02ca-b9f7-8f30a301b230"),
+            ["side"]
+        );
+        assert!(config.screen_roles_for("Built-in", "").is_empty());
+    }
+
+    #[test]
+    fn test_screen_roles_for_returns_every_matching_role() {
+        let config = TilingConfig {
+            screen_roles: HashMap::from([
+                (
+                    "work".to_string(),
+                    "37D8832A-2D66-02CA-B9F7-8F30A301B230".to_string(),
+                ),
+                ("primary".to_string(), "DELL U2720Q".to_string()),
+                ("center".to_string(), "dell u2720q".to_string()),
+            ]),
+            ..TilingConfig::default()
+        };
+
+        assert_eq!(
+            config.screen_roles_for("DELL U2720Q", "37d8832a-2d66-02ca-b9f7-8f30a301b230"),
+            ["center", "primary", "work"]
+        );
+    }
+
+    #[test]
+    fn test_ignore_rules_for_workspace_override() {
+        let json = r#"{
+            "ignore": [{ "appId": "org.videolan.vlc" }],
+            "workspaces": [
+                { "name": "main" },
+                { "name": "media", "ignore": [] },
+                { "name": "web", "ignore": [{ "appName": "Finder" }] }
+            ]
+        }"#;
+        let config: TilingConfig = serde_json::from_str(json).unwrap();
+
+        let main = config.ignore_rules_for("main");
+        assert_eq!(main.len(), 1);
+        assert_eq!(main[0].app_id.as_deref(), Some("org.videolan.vlc"));
+        assert!(config.ignore_rules_for("media").is_empty());
+        assert_eq!(
+            config.ignore_rules_for("web")[0].app_name.as_deref(),
+            Some("Finder")
+        );
+        assert_eq!(config.ignore_rules_for("unknown").len(), 1);
+    }
 }
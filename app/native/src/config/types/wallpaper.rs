@@ -14,6 +14,61 @@ pub enum WallpaperMode {
     Random,
     /// Cycle through wallpapers in order.
     Sequential,
+    /// Rotate through the images of a folder on a timer.
+    ///
+    /// Overrides `path`, `list` and `interval`. The current slide is persisted
+    /// so restarts resume where the slideshow left off.
+    Slideshow {
+        /// Directory containing the slideshow images.
+        folder: String,
+        /// Time in seconds between slides.
+        /// Default: 1800
+        #[serde(default = "default_slideshow_interval")]
+        interval: u64,
+        /// Whether to visit the images in a shuffled order.
+        /// Default: false
+        #[serde(default)]
+        shuffle: bool,
+    },
+}
+
+/// Default slideshow interval (30 minutes).
+const fn default_slideshow_interval() -> u64 { 1800 }
+
+/// How wallpapers are scaled to the screen.
+///
+/// Maps to the `NSWorkspace` desktop image scaling and clipping options.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum WallpaperScaling {
+    /// Scale proportionally to cover the screen, cropping the edges.
+    #[default]
+    Fill,
+    /// Scale proportionally to fit inside the screen, letterboxing with black.
+    Fit,
+    /// Stretch to the screen dimensions, ignoring the aspect ratio.
+    Stretch,
+    /// Keep the original size, centered on the screen.
+    Center,
+    /// Keep the original size, repeated across the screen.
+    Tile,
+}
+
+impl WallpaperScaling {
+    /// Returns the suffix used to keep processed images for different
+    /// scaling modes apart in the cache.
+    ///
+    /// `fill` has no suffix so existing caches stay valid.
+    #[must_use]
+    pub const fn cache_suffix(self) -> &'static str {
+        match self {
+            Self::Fill => "",
+            Self::Fit => "_fit",
+            Self::Stretch => "_stretch",
+            Self::Center => "_center",
+            Self::Tile => "_tile",
+        }
+    }
 }
 
 /// Wallpaper configuration for dynamic wallpaper management.
@@ -37,9 +92,14 @@ pub struct WallpaperConfig {
     /// If set to 0, the wallpaper will not change after the initial setting.
     pub interval: u64,
 
-    /// Wallpaper selection mode: "random" or "sequential".
+    /// Wallpaper selection mode: "random", "sequential" or a slideshow object.
     pub mode: WallpaperMode,
 
+    /// How wallpapers are scaled to the screen: "fill", "fit", "stretch",
+    /// "center" or "tile".
+    /// Default: "fill"
+    pub scaling: WallpaperScaling,
+
     /// Radius in pixels for rounded corners.
     pub radius: u32,
 
@@ -52,9 +112,42 @@ impl WallpaperConfig {
     #[must_use]
     pub const fn is_enabled(&self) -> bool { self.enabled }
 
-    /// Returns whether there are wallpapers configured (path or list).
+    /// Returns whether there are wallpapers configured (path, list or slideshow folder).
+    #[must_use]
+    pub const fn has_wallpapers(&self) -> bool {
+        match &self.mode {
+            WallpaperMode::Slideshow { folder, .. } => !folder.is_empty(),
+            _ => !self.path.is_empty() || !self.list.is_empty(),
+        }
+    }
+
+    /// Returns the directory wallpapers are loaded from, if any.
+    ///
+    /// The slideshow folder takes precedence over `path`.
+    #[must_use]
+    pub fn directory(&self) -> &str {
+        match &self.mode {
+            WallpaperMode::Slideshow { folder, .. } => folder,
+            _ => &self.path,
+        }
+    }
+
+    /// Returns the time in seconds between automatic wallpaper changes.
+    ///
+    /// The slideshow interval takes precedence over `interval`.
     #[must_use]
-    pub const fn has_wallpapers(&self) -> bool { !self.path.is_empty() || !self.list.is_empty() }
+    pub const fn effective_interval(&self) -> u64 {
+        match &self.mode {
+            WallpaperMode::Slideshow { interval, .. } => *interval,
+            _ => self.interval,
+        }
+    }
+
+    /// Returns whether the slideshow order should be shuffled.
+    #[must_use]
+    pub const fn is_shuffled(&self) -> bool {
+        matches!(self.mode, WallpaperMode::Slideshow { shuffle: true, .. })
+    }
 }
 
 #[cfg(test)]
@@ -100,4 +193,86 @@ mod tests {
         let mode = WallpaperMode::default();
         assert_eq!(mode, WallpaperMode::Random);
     }
+
+    #[test]
+    fn test_wallpaper_mode_slideshow_deserialization() {
+        let mode: WallpaperMode =
+            serde_json::from_str(r#"{"slideshow": {"folder": "~/Pictures"}}"#).unwrap();
+        assert_eq!(mode, WallpaperMode::Slideshow {
+            folder: "~/Pictures".to_string(),
+            interval: 1800,
+            shuffle: false,
+        });
+
+        let mode: WallpaperMode = serde_json::from_str("\"sequential\"").unwrap();
+        assert_eq!(mode, WallpaperMode::Sequential);
+    }
+
+    #[test]
+    fn test_wallpaper_config_slideshow_overrides() {
+        let config = WallpaperConfig {
+            path: "/some/path".to_string(),
+            interval: 60,
+            mode: WallpaperMode::Slideshow {
+                folder: "/slides".to_string(),
+                interval: 300,
+                shuffle: true,
+            },
+            ..Default::default()
+        };
+        assert!(config.has_wallpapers());
+        assert_eq!(config.directory(), "/slides");
+        assert_eq!(config.effective_interval(), 300);
+        assert!(config.is_shuffled());
+    }
+
+    #[test]
+    fn test_wallpaper_config_slideshow_without_folder_has_no_wallpapers() {
+        let config = WallpaperConfig {
+            path: "/some/path".to_string(),
+            mode: WallpaperMode::Slideshow {
+                folder: String::new(),
+                interval: 300,
+                shuffle: false,
+            },
+            ..Default::default()
+        };
+        assert!(!config.has_wallpapers());
+    }
+
+    #[test]
+    fn test_wallpaper_config_effective_interval_without_slideshow() {
+        let config = WallpaperConfig {
+            path: "/some/path".to_string(),
+            interval: 60,
+            ..Default::default()
+        };
+        assert_eq!(config.directory(), "/some/path");
+        assert_eq!(config.effective_interval(), 60);
+        assert!(!config.is_shuffled());
+    }
+
+    #[test]
+    fn test_wallpaper_scaling_default_is_fill() {
+        assert_eq!(WallpaperScaling::default(), WallpaperScaling::Fill);
+        assert_eq!(WallpaperConfig::default().scaling, WallpaperScaling::Fill);
+    }
+
+    #[test]
+    fn test_wallpaper_scaling_deserialization() {
+        let scaling: WallpaperScaling = serde_json::from_str("\"tile\"").unwrap();
+        assert_eq!(scaling, WallpaperScaling::Tile);
+        let scaling: WallpaperScaling = serde_json::from_str("\"fit\"").unwrap();
+        assert_eq!(scaling, WallpaperScaling::Fit);
+    }
+
+    #[test]
+    fn test_wallpaper_scaling_cache_suffix() {
+        assert_eq!(WallpaperScaling::Fill.cache_suffix(), "");
+        assert_eq!(WallpaperScaling::Fit.cache_suffix(), "_fit");
+        assert_ne!(
+            WallpaperScaling::Center.cache_suffix(),
+            WallpaperScaling::Tile.cache_suffix()
+        );
+    }
 }
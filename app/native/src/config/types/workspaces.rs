@@ -32,6 +32,10 @@ pub struct WindowRule {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub app_name: Option<String>,
 
+    /// Match by accessibility subrole (e.g., "AXDialog", "AXSystemDialog").
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subrole: Option<String>,
+
     // Cached lowercase versions for fast matching (computed by prepare())
     #[serde(skip)]
     #[schemars(skip)]
@@ -50,7 +54,10 @@ impl WindowRule {
     /// Returns true if the rule has at least one matching criterion.
     #[must_use]
     pub const fn is_valid(&self) -> bool {
-        self.app_id.is_some() || self.title.is_some() || self.app_name.is_some()
+        self.app_id.is_some()
+            || self.title.is_some()
+            || self.app_name.is_some()
+            || self.subrole.is_some()
     }
 
     /// Pre-computes lowercase versions of string fields for faster matching.
@@ -82,6 +89,21 @@ impl WindowRule {
     }
 }
 
+/// Rule pinning matching windows to a physical display.
+///
+/// Matching windows join a workspace on that display, and move back to it when
+/// it reconnects.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayRule {
+    /// Windows to pin. All specified properties must match.
+    #[serde(flatten)]
+    pub rule: WindowRule,
+
+    /// Display to pin to: "main"/"primary", "secondary", or screen name.
+    pub screen: String,
+}
+
 /// Helper function for default screen value.
 fn default_screen() -> String { "main".to_string() }
 
@@ -97,7 +119,8 @@ pub struct WorkspaceConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub layout: Option<LayoutType>,
 
-    /// Screen assignment: "main"/"primary", "secondary", or screen name.
+    /// Screen assignment: "main"/"primary", "secondary", screen name, or
+    /// persistent display UUID.
     /// Default: "main"
     #[serde(default = "default_screen")]
     pub screen: String,
@@ -106,6 +129,12 @@ pub struct WorkspaceConfig {
     #[serde(default)]
     pub rules: Vec<WindowRule>,
 
+    /// Ignore rules for windows assigned to this workspace, replacing the
+    /// global `ignore` rules. Use `[]` to tile windows ignored everywhere else.
+    /// If not specified, the global `ignore` rules apply.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ignore: Option<Vec<WindowRule>>,
+
     /// Floating preset to apply when windows open in this workspace.
     #[serde(
         default,
@@ -113,6 +142,13 @@ pub struct WorkspaceConfig {
         skip_serializing_if = "Option::is_none"
     )]
     pub preset_on_open: Option<String>,
+
+    /// Turn on Do Not Disturb while this workspace is focused, restoring the
+    /// previous state when leaving it. Runs the "Turn On Do Not Disturb" and
+    /// "Turn Off Do Not Disturb" shortcuts, which must exist in Shortcuts.
+    /// Default: false
+    #[serde(default)]
+    pub dnd: bool,
 }
 
 #[cfg(test)]
@@ -129,6 +165,21 @@ mod tests {
             ..Default::default()
         };
         assert!(with_app_id.is_valid());
+
+        let with_subrole = WindowRule {
+            subrole: Some("AXDialog".to_string()),
+            ..Default::default()
+        };
+        assert!(with_subrole.is_valid());
+    }
+
+    #[test]
+    fn test_deserialize_display_rule() {
+        let json = r#"{"appId": "us.zoom.xos", "screen": "Built-in"}"#;
+        let rule: DisplayRule = serde_json::from_str(json).unwrap();
+        assert_eq!(rule.rule.app_id.as_deref(), Some("us.zoom.xos"));
+        assert_eq!(rule.screen, "Built-in");
+        assert!(rule.rule.is_valid());
     }
 
     #[test]
@@ -0,0 +1,589 @@
+//! Configuration file validation.
+//!
+//! Checks a configuration file without applying it and reports every problem
+//! found, each with the field path and, when known, the line and column.
+//!
+//! Errors are mistakes that either prevent the file from loading or that the
+//! app silently ignores (e.g. a misspelled key). Warnings point at values that
+//! are valid but may not behave as intended on this machine, like a workspace
+//! assigned to a screen that is not connected.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::io::Read;
+use std::path::Path;
+
+use serde_json::Value;
+
+use super::types::hotkeys::validate_hotkeys;
+use super::types::migration::migrate;
+use super::types::root::{is_toml_config, parse_toml_config};
+use super::types::{ConfigError, StacheConfig};
+
+/// Maximum nesting followed when resolving schema references.
+const MAX_SCHEMA_DEPTH: usize = 32;
+
+/// How serious a diagnostic is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The configuration is wrong and must be fixed.
+    Error,
+    /// The configuration is valid but may not behave as intended.
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error => f.write_str("error"),
+            Self::Warning => f.write_str("warning"),
+        }
+    }
+}
+
+/// A single problem found in a configuration file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Whether this is an error or a warning.
+    pub severity: Severity,
+    /// Dotted path of the offending field, e.g. `tiling.workspaces[1].name`.
+    /// Empty when the problem is not tied to a field.
+    pub path: String,
+    /// 1-based line, when known.
+    pub line: Option<usize>,
+    /// 1-based column, when known.
+    pub column: Option<usize>,
+    /// Human readable description.
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn error(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            path: path.into(),
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    fn warning(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            ..Self::error(path, message)
+        }
+    }
+
+    const fn at(mut self, location: Option<(usize, usize)>) -> Self {
+        if let Some((line, column)) = location {
+            self.line = Some(line);
+            self.column = Some(column);
+        }
+        self
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.severity)?;
+
+        if let (Some(line), Some(column)) = (self.line, self.column) {
+            write!(f, " [{line}:{column}]")?;
+        }
+
+        if !self.path.is_empty() {
+            write!(f, " {}", self.path)?;
+        }
+
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Validates the configuration file at `path`.
+///
+/// `known_screens` lists the names of the connected screens. Pass `None` to
+/// skip checks that depend on the current display setup.
+///
+/// # Errors
+///
+/// Returns `ConfigError::NotFound` or `ConfigError::IoError` if the file cannot
+/// be read. Problems with the contents are returned as diagnostics instead.
+pub fn validate_file(
+    path: &Path,
+    known_screens: Option<&[String]>,
+) -> Result<Vec<Diagnostic>, ConfigError> {
+    if !path.exists() {
+        return Err(ConfigError::NotFound);
+    }
+
+    let contents = std::fs::read_to_string(path)?;
+    if is_toml_config(path) {
+        return Ok(validate_toml_str(&contents, known_screens));
+    }
+
+    Ok(validate_str(&contents, known_screens))
+}
+
+/// Validates TOML configuration file contents.
+///
+/// The contents are checked as the equivalent JSON, so diagnostics name the
+/// field but only TOML syntax errors carry a position.
+#[must_use]
+pub fn validate_toml_str(contents: &str, known_screens: Option<&[String]>) -> Vec<Diagnostic> {
+    let value = match parse_toml_config(contents) {
+        Ok(value) => value,
+        Err(err) => return vec![Diagnostic::error("", err.to_string())],
+    };
+
+    let json = serde_json::to_string(&value).unwrap_or_default();
+    let mut diagnostics = validate_str(&json, known_screens);
+    for diagnostic in &mut diagnostics {
+        diagnostic.line = None;
+        diagnostic.column = None;
+    }
+    diagnostics
+}
+
+/// Validates configuration file contents (JSONC).
+///
+/// Diagnostics are sorted with errors first.
+#[must_use]
+pub fn validate_str(contents: &str, known_screens: Option<&[String]>) -> Vec<Diagnostic> {
+    // Comments are replaced by whitespace, so positions still match the original file
+    let mut stripped = String::with_capacity(contents.len());
+    if let Err(err) =
+        json_comments::StripComments::new(contents.as_bytes()).read_to_string(&mut stripped)
+    {
+        return vec![Diagnostic::error(
+            "",
+            format!("failed to strip comments: {err}"),
+        )];
+    }
+
+    let mut value: Value = match serde_json::from_str(&stripped) {
+        Ok(value) => value,
+        Err(err) => {
+            let err = ConfigError::ParseError(err);
+            return vec![Diagnostic::error("", err.to_string()).at(err.location())];
+        }
+    };
+
+    let mut diagnostics = Vec::new();
+
+    // Older shapes are reported, then checked as the app will read them
+    let location = locate_key(&stripped, "version");
+    let migrations = match migrate(&mut value) {
+        Ok(notes) => notes,
+        Err(err) => return vec![Diagnostic::error("version", err.to_string()).at(location)],
+    };
+    for note in &migrations {
+        diagnostics.push(Diagnostic::warning(
+            "",
+            format!("outdated config format, {note} on load"),
+        ));
+    }
+
+    let schema = serde_json::to_value(crate::schema::generate_schema()).unwrap_or_default();
+    let defs = schema.get("$defs").cloned().unwrap_or_default();
+    check_unknown_keys(&value, &schema, &defs, "", &mut diagnostics);
+
+    for diagnostic in diagnostics.iter_mut().filter(|d| !d.path.is_empty()) {
+        let key = diagnostic.path.rsplit('.').next().unwrap_or_default();
+        if let Some((line, column)) = locate_key(&stripped, key) {
+            diagnostic.line = Some(line);
+            diagnostic.column = Some(column);
+        }
+    }
+
+    // Deserialize the original text when possible, so errors keep their position
+    let parsed = if migrations.is_empty() {
+        serde_json::from_str::<StacheConfig>(&stripped)
+    } else {
+        serde_json::from_value::<StacheConfig>(value)
+    };
+
+    match parsed {
+        Ok(mut config) => {
+            config.prepare();
+            check_semantics(&config, known_screens, &mut diagnostics);
+        }
+        Err(err) => {
+            let err = ConfigError::ParseError(err);
+            diagnostics.push(Diagnostic::error("", err.to_string()).at(err.location()));
+        }
+    }
+
+    diagnostics.sort_by_key(|diagnostic| diagnostic.severity);
+    diagnostics
+}
+
+/// Returns the position of `"key":` when it appears exactly once in the file.
+fn locate_key(stripped: &str, key: &str) -> Option<(usize, usize)> {
+    let needle = format!("\"{key}\"");
+    let mut matches = stripped
+        .match_indices(&needle)
+        .filter(|(index, _)| stripped[index + needle.len()..].trim_start().starts_with(':'));
+
+    let (index, _) = matches.next()?;
+    if matches.next().is_some() {
+        return None;
+    }
+
+    let before = &stripped[..index];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().map_or(0, |prefix| prefix.chars().count()) + 1;
+    Some((line, column))
+}
+
+/// Resolves `$ref`, `anyOf`, `oneOf` and `allOf` into the list of concrete schemas.
+fn flatten_schema<'a>(schema: &'a Value, defs: &'a Value, depth: usize, out: &mut Vec<&'a Value>) {
+    if depth > MAX_SCHEMA_DEPTH {
+        return;
+    }
+
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str)
+        && let Some(target) = reference.strip_prefix("#/$defs/").and_then(|name| defs.get(name))
+    {
+        flatten_schema(target, defs, depth + 1, out);
+    }
+
+    for keyword in ["anyOf", "oneOf", "allOf"] {
+        if let Some(variants) = schema.get(keyword).and_then(Value::as_array) {
+            for variant in variants {
+                flatten_schema(variant, defs, depth + 1, out);
+            }
+        }
+    }
+
+    out.push(schema);
+}
+
+/// Reports object keys that the schema does not define.
+///
+/// Serde ignores unknown keys, so a typo such as `"gap"` instead of `"gaps"`
+/// would otherwise go unnoticed.
+fn check_unknown_keys(
+    value: &Value,
+    schema: &Value,
+    defs: &Value,
+    path: &str,
+    out: &mut Vec<Diagnostic>,
+) {
+    let mut variants = Vec::new();
+    flatten_schema(schema, defs, 0, &mut variants);
+
+    match value {
+        Value::Object(map) => {
+            let properties: Vec<_> = variants
+                .iter()
+                .filter_map(|variant| variant.get("properties").and_then(Value::as_object))
+                .collect();
+            let additional = variants
+                .iter()
+                .find_map(|variant| variant.get("additionalProperties").filter(|a| a.is_object()));
+
+            for (key, child) in map {
+                let child_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+
+                if let Some(child_schema) = properties.iter().find_map(|props| props.get(key)) {
+                    check_unknown_keys(child, child_schema, defs, &child_path, out);
+                } else if let Some(child_schema) = additional {
+                    check_unknown_keys(child, child_schema, defs, &child_path, out);
+                } else if !properties.is_empty() {
+                    out.push(Diagnostic::error(child_path, format!("unknown key '{key}'")));
+                }
+            }
+        }
+        Value::Array(items) => {
+            let Some(item_schema) = variants.iter().find_map(|variant| variant.get("items")) else {
+                return;
+            };
+
+            for (index, item) in items.iter().enumerate() {
+                check_unknown_keys(item, item_schema, defs, &format!("{path}[{index}]"), out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Checks constraints that the JSON structure alone cannot express.
+fn check_semantics(
+    config: &StacheConfig,
+    known_screens: Option<&[String]>,
+    out: &mut Vec<Diagnostic>,
+) {
+    if let Err(err) = validate_hotkeys(&config.hotkeys, &config.keybindings) {
+        out.push(Diagnostic::error("hotkeys", err.to_string()));
+    }
+
+    let tiling = &config.tiling;
+
+    if let Err(err) = tiling.floating.validate_presets() {
+        out.push(Diagnostic::error("tiling.floating.presets", err.to_string()));
+    }
+
+    if let Err(err) = tiling.validate_app_insets() {
+        out.push(Diagnostic::error("tiling.appInsets", err.to_string()));
+    }
+
+    if tiling.master.ratio == 0 || tiling.master.ratio >= 100 {
+        out.push(Diagnostic::error(
+            "tiling.master.ratio",
+            format!("ratio must be between 1 and 99, got {}", tiling.master.ratio),
+        ));
+    }
+
+    let mut workspace_names = HashSet::new();
+
+    for (index, workspace) in tiling.workspaces.iter().enumerate() {
+        let path = format!("tiling.workspaces[{index}]");
+
+        if workspace.name.trim().is_empty() {
+            out.push(Diagnostic::error(
+                format!("{path}.name"),
+                "workspace name is empty",
+            ));
+        } else if !workspace_names.insert(workspace.name.as_str()) {
+            out.push(Diagnostic::error(
+                format!("{path}.name"),
+                format!("duplicate workspace name '{}'", workspace.name),
+            ));
+        }
+
+        if let Some(screens) = known_screens
+            && !matches!(workspace.screen.as_str(), "main" | "primary" | "secondary")
+            && !screens.contains(&workspace.screen)
+        {
+            out.push(Diagnostic::warning(
+                format!("{path}.screen"),
+                format!("no connected screen is named '{}'", workspace.screen),
+            ));
+        }
+
+        if let Some(preset) = &workspace.preset_on_open
+            && tiling.floating.find_preset(preset).is_none()
+        {
+            out.push(Diagnostic::warning(
+                format!("{path}.preset-on-open"),
+                format!("no floating preset is named '{preset}'"),
+            ));
+        }
+    }
+
+    if let Some(overflow) = &tiling.overflow_workspace
+        && !tiling.workspaces.is_empty()
+        && !workspace_names.contains(overflow.as_str())
+    {
+        out.push(Diagnostic::warning(
+            "tiling.overflowWorkspace",
+            format!("no workspace is named '{overflow}'"),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn errors(diagnostics: &[Diagnostic]) -> Vec<&Diagnostic> {
+        diagnostics.iter().filter(|d| d.severity == Severity::Error).collect()
+    }
+
+    #[test]
+    fn test_valid_config_has_no_diagnostics() {
+        let diagnostics = validate_str(
+            r#"{
+                // Comments are allowed
+                "tiling": { "enabled": true, "workspaces": [{ "name": "code" }] }
+            }"#,
+            None,
+        );
+
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
+
+    #[test]
+    fn test_syntax_error_reports_position() {
+        let diagnostics = validate_str("{\n  \"tiling\": {\n    \"enabled\": true,\n  }\n}", None);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics[0].line, Some(4));
+    }
+
+    #[test]
+    fn test_unknown_key_is_an_error_with_position() {
+        let diagnostics = validate_str("{\n  \"tiling\": {\n    \"gap\": 8\n  }\n}", None);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].path, "tiling.gap");
+        assert_eq!(diagnostics[0].line, Some(3));
+        assert_eq!(diagnostics[0].column, Some(5));
+    }
+
+    #[test]
+    fn test_toml_unknown_key_is_an_error_without_position() {
+        let diagnostics = validate_toml_str("[tiling]\nenabled = true\ngap = 8\n", None);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].path, "tiling.gap");
+        assert_eq!(diagnostics[0].line, None);
+    }
+
+    #[test]
+    fn test_unknown_key_inside_array_item() {
+        let diagnostics = validate_str(
+            r#"{ "tiling": { "workspaces": [{ "name": "code", "layuot": "monocle" }] } }"#,
+            None,
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].path, "tiling.workspaces[0].layuot");
+    }
+
+    #[test]
+    fn test_free_form_maps_accept_any_key() {
+        let diagnostics = validate_str(
+            r#"{ "keybindings": { "Command+Control+R": "stache reload" } }"#,
+            None,
+        );
+
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
+
+    #[test]
+    fn test_invalid_layout_name_is_an_error() {
+        let diagnostics = validate_str(r#"{ "tiling": { "defaultLayout": "spiral" } }"#, None);
+
+        assert_eq!(errors(&diagnostics).len(), 1);
+        assert!(diagnostics[0].message.contains("spiral"));
+        assert!(diagnostics[0].line.is_some());
+    }
+
+    #[test]
+    fn test_out_of_range_ratio_is_an_error() {
+        let diagnostics = validate_str(r#"{ "tiling": { "master": { "ratio": 100 } } }"#, None);
+
+        assert_eq!(errors(&diagnostics).len(), 1);
+        assert_eq!(diagnostics[0].path, "tiling.master.ratio");
+    }
+
+    #[test]
+    fn test_non_positive_preset_size_is_an_error() {
+        let diagnostics = validate_str(
+            r#"{ "tiling": { "floating": { "presets": [
+                { "name": "left-third", "width": "33%", "height": "100%" },
+                { "name": "broken", "width": 0, "height": "150%" }
+            ] } } }"#,
+            None,
+        );
+
+        assert_eq!(errors(&diagnostics).len(), 1);
+        assert_eq!(diagnostics[0].path, "tiling.floating.presets");
+        assert!(diagnostics[0].message.contains("'broken'"));
+    }
+
+    #[test]
+    fn test_negative_app_inset_is_an_error() {
+        let diagnostics = validate_str(
+            r#"{ "tiling": { "appInsets": { "com.apple.terminal": { "top": -2 } } } }"#,
+            None,
+        );
+
+        assert_eq!(errors(&diagnostics).len(), 1);
+        assert_eq!(diagnostics[0].path, "tiling.appInsets");
+    }
+
+    #[test]
+    fn test_duplicate_workspace_names_are_errors() {
+        let diagnostics = validate_str(
+            r#"{ "tiling": { "workspaces": [{ "name": "code" }, { "name": "code" }] } }"#,
+            None,
+        );
+
+        assert_eq!(errors(&diagnostics).len(), 1);
+        assert_eq!(diagnostics[0].path, "tiling.workspaces[1].name");
+    }
+
+    #[test]
+    fn test_unknown_screen_is_a_warning() {
+        let screens = vec!["Built-in Retina Display".to_string()];
+        let diagnostics = validate_str(
+            r#"{ "tiling": { "workspaces": [
+                { "name": "code", "screen": "DELL U2720Q" },
+                { "name": "web", "screen": "secondary" }
+            ] } }"#,
+            Some(screens.as_slice()),
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(diagnostics[0].path, "tiling.workspaces[0].screen");
+    }
+
+    #[test]
+    fn test_screen_check_skipped_without_screen_list() {
+        let diagnostics = validate_str(
+            r#"{ "tiling": { "workspaces": [{ "name": "code", "screen": "DELL U2720Q" }] } }"#,
+            None,
+        );
+
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_overflow_workspace_is_a_warning() {
+        let diagnostics = validate_str(
+            r#"{ "tiling": { "workspaces": [{ "name": "code" }], "overflowWorkspace": "misc" } }"#,
+            None,
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(diagnostics[0].path, "tiling.overflowWorkspace");
+    }
+
+    #[test]
+    fn test_errors_sorted_before_warnings() {
+        let screens = vec![];
+        let diagnostics = validate_str(
+            r#"{ "tiling": { "workspaces": [{ "name": "a", "screen": "missing" }], "typo": 1 } }"#,
+            Some(screens.as_slice()),
+        );
+
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics[1].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_missing_version_is_not_reported() {
+        let diagnostics = validate_str("{}", None);
+
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_newer_version_is_an_error() {
+        let diagnostics = validate_str("{\n  \"version\": 99\n}", None);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics[0].line, Some(2));
+    }
+
+    #[test]
+    fn test_diagnostic_display() {
+        let diagnostic = Diagnostic::error("tiling.gap", "unknown key 'gap'").at(Some((3, 5)));
+        assert_eq!(
+            diagnostic.to_string(),
+            "error [3:5] tiling.gap: unknown key 'gap'"
+        );
+    }
+}
@@ -2,18 +2,89 @@
 //!
 //! This module provides functionality to watch the configuration file
 //! for changes and restart the application when changes are detected.
+//!
+//! Modules can register live-reload handlers for their config section. When
+//! only those sections change, the updated configuration replaces the global
+//! one, the handlers are invoked with it and the restart is skipped.
 
+use std::sync::{LazyLock, Mutex, PoisonError};
 use std::time::{Duration, Instant};
 
 use notify::{RecommendedWatcher, RecursiveMode, Watcher};
 use tauri::AppHandle;
 
-use super::get_config_path;
+use super::{
+    StacheConfig, apply_env_overrides, get_config, get_config_path, load_config_from_path,
+    replace_config,
+};
 
 /// Debounce duration for config file changes.
 /// Some editors trigger multiple events per save (write to temp, rename, etc.).
 const CONFIG_DEBOUNCE_MS: u64 = 200;
 
+/// Callback invoked with the updated configuration.
+///
+/// Returns `false` when the change could not be applied live and the app
+/// must restart instead.
+type LiveReloadHandler = Box<dyn Fn(&StacheConfig) -> bool + Send + Sync>;
+
+/// Registered live-reload handlers, keyed by the config section they own.
+static LIVE_RELOAD_HANDLERS: LazyLock<Mutex<Vec<(&'static str, LiveReloadHandler)>>> =
+    LazyLock::new(|| Mutex::new(Vec::new()));
+
+/// Registers a handler that applies changes to a config section without restarting.
+///
+/// # Arguments
+///
+/// * `section` - The top-level config key owned by the handler (e.g., `"notunes"`).
+/// * `handler` - Called with the updated configuration whenever the file changes.
+///   Returns whether the change was fully applied.
+pub fn register_live_reload<F>(section: &'static str, handler: F)
+where F: Fn(&StacheConfig) -> bool + Send + Sync + 'static {
+    LIVE_RELOAD_HANDLERS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push((section, Box::new(handler)));
+}
+
+/// Returns whether the change between two configs touches any section
+/// that cannot be applied live.
+fn requires_restart(current: &StacheConfig, updated: &StacheConfig, live: &[&str]) -> bool {
+    let strip = |config: &StacheConfig| {
+        let mut value = serde_json::to_value(config).unwrap_or_default();
+        if let Some(map) = value.as_object_mut() {
+            for section in live {
+                map.remove(*section);
+            }
+        }
+        value
+    };
+
+    strip(current) != strip(updated)
+}
+
+/// Applies the updated config to all live-reload handlers.
+///
+/// Returns `true` if every change was handled live and no restart is needed.
+fn apply_live_reload(updated: StacheConfig) -> bool {
+    let handlers = LIVE_RELOAD_HANDLERS.lock().unwrap_or_else(PoisonError::into_inner);
+
+    let live: Vec<&str> = handlers.iter().map(|(section, _)| *section).collect();
+    let restart = requires_restart(get_config(), &updated, &live);
+
+    // Swap first so anything the handlers trigger reads the new values. When a
+    // restart is pending anyway, keep the current config for the rest of the app.
+    let updated = if restart {
+        &updated
+    } else {
+        replace_config(updated);
+        get_config()
+    };
+
+    let applied = handlers.iter().fold(true, |applied, (_, handler)| handler(updated) && applied);
+    applied && !restart
+}
+
 /// Starts watching the configuration file for changes.
 ///
 /// When the config file is modified, the app will restart to apply the new configuration.
@@ -56,7 +127,6 @@ pub fn watch_config_file<R: tauri::Runtime>(app_handle: AppHandle<R>) {
         tracing::debug!(path = %config_path.display(), "watching config file for changes");
 
         // Track last event time for debouncing (None = no previous event)
-        #[allow(unused_variables, unused_mut)]
         let mut last_event_time: Option<Instant> = None;
         let debounce_duration = Duration::from_millis(CONFIG_DEBOUNCE_MS);
 
@@ -79,13 +149,27 @@ pub fn watch_config_file<R: tauri::Runtime>(app_handle: AppHandle<R>) {
                     if last_event_time.is_some_and(|t| now.duration_since(t) < debounce_duration) {
                         continue;
                     }
+                    last_event_time = Some(now);
+
+                    // Apply sections that support live reload; skip the restart
+                    // when nothing else changed
+                    match load_config_from_path(&config_path) {
+                        Ok((mut updated, _)) => {
+                            apply_env_overrides(&mut updated);
+                            if apply_live_reload(updated) {
+                                tracing::info!("config file changed, applied without restart");
+                                continue;
+                            }
+                        }
+                        Err(err) => {
+                            tracing::warn!(error = %err, "failed to parse updated config file");
+                        }
+                    }
 
                     // In debug mode, just log a message since restart kills the dev server.
-                    // Update last_event_time only in debug mode for debouncing subsequent events.
                     // In release mode, restart the app to apply the new configuration.
                     #[cfg(debug_assertions)]
                     {
-                        last_event_time = Some(now);
                         tracing::info!(
                             "config file changed - restart the app to apply new settings"
                         );
@@ -125,4 +209,30 @@ mod tests {
         let duration = Duration::from_millis(CONFIG_DEBOUNCE_MS);
         assert_eq!(duration.as_millis(), u128::from(CONFIG_DEBOUNCE_MS));
     }
+
+    #[test]
+    fn requires_restart_ignores_live_sections() {
+        let current = StacheConfig::default();
+        let mut updated = StacheConfig::default();
+        updated.notunes.enabled = !current.notunes.enabled;
+
+        assert!(!requires_restart(&current, &updated, &["notunes"]));
+        assert!(requires_restart(&current, &updated, &[]));
+    }
+
+    #[test]
+    fn requires_restart_detects_other_sections() {
+        let current = StacheConfig::default();
+        let mut updated = StacheConfig::default();
+        updated.notunes.enabled = !current.notunes.enabled;
+        updated.tiling.enabled = !current.tiling.enabled;
+
+        assert!(requires_restart(&current, &updated, &["notunes"]));
+    }
+
+    #[test]
+    fn requires_restart_false_for_identical_configs() {
+        let config = StacheConfig::default();
+        assert!(!requires_restart(&config, &config, &[]));
+    }
 }
@@ -47,6 +47,23 @@ pub enum StacheError {
     /// Generic command error.
     #[error("{0}")]
     CommandError(String),
+    /// The desktop app is not running and was not launched.
+    #[error("Stache is not running; start it with `stache --desktop`")]
+    AppNotRunning,
+}
+
+impl StacheError {
+    /// Process exit code for the CLI.
+    ///
+    /// `AppNotRunning` uses `EX_UNAVAILABLE` (69) so scripts can tell it apart
+    /// from other failures, which exit with 1.
+    #[must_use]
+    pub const fn exit_code(&self) -> i32 {
+        match self {
+            Self::AppNotRunning => 69,
+            _ => 1,
+        }
+    }
 }
 
 impl From<std::io::Error> for StacheError {
@@ -129,6 +146,14 @@ mod tests {
         assert!(msg.contains("Failed to send notification"));
     }
 
+    #[test]
+    fn test_app_not_running_display_and_exit_code() {
+        let err = StacheError::AppNotRunning;
+        assert!(err.to_string().contains("stache --desktop"));
+        assert_eq!(err.exit_code(), 69);
+        assert_eq!(StacheError::IpcError("test".to_string()).exit_code(), 1);
+    }
+
     #[test]
     fn test_error_is_debug() {
         let err = StacheError::InvalidArguments("test".to_string());
@@ -25,6 +25,14 @@ pub mod menubar {
     pub const VISIBILITY_CHANGED: &str = "stache://menubar/visibility-changed";
 }
 
+/// Status bar events.
+pub mod bar {
+    /// Emitted when the bar window is shown or hidden on demand.
+    ///
+    /// Payload: `bool` - `true` if visible, `false` if hidden.
+    pub const VISIBILITY_CHANGED: &str = "stache://bar/visibility-changed";
+}
+
 /// Keep-awake (caffeinate) related events.
 pub mod keepawake {
     /// Emitted when the keep-awake state changes.
@@ -33,6 +41,14 @@ pub mod keepawake {
     pub const STATE_CHANGED: &str = "stache://keepawake/state-changed";
 }
 
+/// Idle detection events.
+pub mod idle {
+    /// Emitted when the user becomes idle or active again.
+    ///
+    /// Payload: `bool` - `true` when idle, `false` on the next input.
+    pub const STATE_CHANGED: &str = "stache://idle/state-changed";
+}
+
 /// Media playback related events.
 pub mod media {
     /// Emitted when media playback state changes.
@@ -41,6 +57,15 @@ pub mod media {
     pub const PLAYBACK_CHANGED: &str = "stache://media/playback-changed";
 }
 
+/// Audio device related events.
+pub mod audio {
+    /// Emitted when the default output is switched automatically because a
+    /// preferred device connected or disconnected.
+    ///
+    /// Payload: `{ id: number, name: string }`
+    pub const OUTPUT_DEVICE_SWITCHED: &str = "stache://audio/output-device-switched";
+}
+
 /// Spaces/workspace related events.
 ///
 /// These events are triggered by CLI commands (`stache event ...`) and are used
@@ -113,6 +138,12 @@ pub mod tiling {
     /// Payload: `{ workspace: String, windows: Vec<u32> }`
     pub const WORKSPACE_WINDOWS_CHANGED: &str = "stache://tiling/workspace-windows-changed";
 
+    /// Emitted when the window count, visibility or focus of any workspace changes.
+    ///
+    /// Payload: Array of `{ name: String, screen: String, windowCount: usize,
+    /// isVisible: bool, isFocused: bool }`, one per workspace.
+    pub const WORKSPACES_CHANGED: &str = "stache://tiling/workspaces-changed";
+
     /// Emitted when a workspace's layout changes.
     ///
     /// Payload: `{ workspace: String, layout: String }`
@@ -147,6 +178,11 @@ pub mod tiling {
     ///
     /// Payload: `{ windowId: u32, title: String }`
     pub const WINDOW_TITLE_CHANGED: &str = "stache://tiling/window-title-changed";
+
+    /// Emitted while a window is dragged or resized, at most ~30 times per second.
+    ///
+    /// Payload: `{ windowId: u32, frame: { x, y, width, height } }`
+    pub const GEOMETRY_UPDATED: &str = "stache://tiling/geometry-updated";
 }
 
 #[cfg(test)]
@@ -157,8 +193,11 @@ mod tests {
     fn test_all_events_have_stache_prefix() {
         let events = [
             menubar::VISIBILITY_CHANGED,
+            bar::VISIBILITY_CHANGED,
             keepawake::STATE_CHANGED,
+            idle::STATE_CHANGED,
             media::PLAYBACK_CHANGED,
+            audio::OUTPUT_DEVICE_SWITCHED,
             spaces::WINDOW_FOCUS_CHANGED,
             spaces::WORKSPACE_CHANGED,
             widgets::TOGGLE,
@@ -167,6 +206,7 @@ mod tests {
             app::RELOAD,
             tiling::WORKSPACE_CHANGED,
             tiling::WORKSPACE_WINDOWS_CHANGED,
+            tiling::WORKSPACES_CHANGED,
             tiling::LAYOUT_CHANGED,
             tiling::WINDOW_TRACKED,
             tiling::WINDOW_UNTRACKED,
@@ -174,6 +214,7 @@ mod tests {
             tiling::INITIALIZED,
             tiling::WINDOW_FOCUS_CHANGED,
             tiling::WINDOW_TITLE_CHANGED,
+            tiling::GEOMETRY_UPDATED,
         ];
 
         for event in events {
@@ -189,8 +230,11 @@ mod tests {
         // All events should follow stache://<module>/<event-name> pattern
         let events = [
             (menubar::VISIBILITY_CHANGED, "menubar", "visibility-changed"),
+            (bar::VISIBILITY_CHANGED, "bar", "visibility-changed"),
             (keepawake::STATE_CHANGED, "keepawake", "state-changed"),
+            (idle::STATE_CHANGED, "idle", "state-changed"),
             (media::PLAYBACK_CHANGED, "media", "playback-changed"),
+            (audio::OUTPUT_DEVICE_SWITCHED, "audio", "output-device-switched"),
             (spaces::WINDOW_FOCUS_CHANGED, "spaces", "window-focus-changed"),
             (spaces::WORKSPACE_CHANGED, "spaces", "workspace-changed"),
             (widgets::TOGGLE, "widgets", "toggle"),
@@ -203,6 +247,7 @@ mod tests {
                 "tiling",
                 "workspace-windows-changed",
             ),
+            (tiling::WORKSPACES_CHANGED, "tiling", "workspaces-changed"),
             (tiling::LAYOUT_CHANGED, "tiling", "layout-changed"),
             (tiling::WINDOW_TRACKED, "tiling", "window-tracked"),
             (tiling::WINDOW_UNTRACKED, "tiling", "window-untracked"),
@@ -210,6 +255,7 @@ mod tests {
             (tiling::INITIALIZED, "tiling", "initialized"),
             (tiling::WINDOW_FOCUS_CHANGED, "tiling", "window-focus-changed"),
             (tiling::WINDOW_TITLE_CHANGED, "tiling", "window-title-changed"),
+            (tiling::GEOMETRY_UPDATED, "tiling", "geometry-updated"),
         ];
 
         for (event, module, name) in events {
@@ -24,7 +24,7 @@ use std::sync::OnceLock;
 
 pub use modules::{audio, tiling};
 use modules::{bar, cmd_q, hotkey, menu_anywhere, notunes, tray, wallpaper, widgets};
-use tauri::App;
+use tauri::{App, Manager};
 
 /// Cached accessibility permission status.
 static ACCESSIBILITY_GRANTED: OnceLock<bool> = OnceLock::new();
@@ -84,9 +84,12 @@ fn lazy_load_modules(app: &App, config: &config::StacheConfig) {
                 tracing::debug!("initializing wallpaper manager");
                 wallpaper::init();
             }),
-            tokio::task::spawn_blocking(|| {
-                tracing::debug!("initializing audio manager");
-                audio::init();
+            tokio::task::spawn_blocking({
+                let h = handle.clone();
+                move || {
+                    tracing::debug!("initializing audio manager");
+                    audio::init(h);
+                }
             }),
             tokio::task::spawn_blocking(|| {
                 tracing::debug!("initializing notunes");
@@ -126,6 +129,9 @@ fn lazy_load_modules(app: &App, config: &config::StacheConfig) {
             tracing::error!("menu_anywhere init panicked: {e}");
         }
 
+        tracing::debug!("initializing idle monitor");
+        services::idle::init(handle.clone());
+
         // Initialize tiling window manager if enabled (after other modules)
         if tiling_config.is_enabled() {
             tracing::info!("tiling window manager enabled, initializing");
@@ -149,7 +155,15 @@ pub fn run() {
     logging::init();
 
     // Initialize the configuration system early
-    config::init();
+    let config = config::init();
+
+    // The log filter and file only know the config once it's loaded
+    logging::set_tiling_verbose(config.tiling.verbose);
+    logging::apply_config(&config.logs);
+    config::register_live_reload("logs", |config| {
+        logging::apply_config(&config.logs);
+        true
+    });
 
     // Check accessibility permissions once at startup for features that need it
     // (tiling window manager, menu anywhere, etc.)
@@ -175,12 +189,23 @@ pub fn run() {
         .plugin(tauri_plugin_shell::init())
         .plugin(hotkey::create_hotkey_plugin())
         .invoke_handler(tauri::generate_handler![
+            bar::components::apps::list_apps,
             bar::components::apps::open_app,
+            bar::components::audio::get_app_audio,
+            bar::components::audio::get_output_devices,
+            bar::components::audio::set_app_mute,
+            bar::components::audio::set_app_volume,
+            bar::components::audio::set_output_device,
             bar::components::battery::get_battery_info,
             bar::components::cpu::get_cpu_info,
             bar::components::keepawake::is_system_awake,
             bar::components::keepawake::toggle_system_awake,
             bar::components::media::get_current_media_info,
+            bar::components::media::media_next,
+            bar::components::media::media_play_pause,
+            bar::components::media::media_previous,
+            bar::components::memory::get_memory_info,
+            bar::components::network::get_network_info,
             bar::components::tiling::focus_tiling_window,
             bar::components::tiling::focus_tiling_workspace,
             bar::components::tiling::get_tiling_current_workspace_windows,
@@ -190,6 +215,8 @@ pub fn run() {
             bar::components::tiling::get_tiling_workspaces,
             bar::components::tiling::is_tiling_enabled,
             bar::components::weather::get_weather_config,
+            bar::components::weather::refresh_weather,
+            bar::window::get_bar_screen,
             bar::window::get_bar_window_frame,
         ])
         .setup(move |app| {
@@ -212,11 +239,13 @@ pub fn run() {
         })
         .build(context)
         .expect("error while building tauri application")
-        .run(|_app, event| {
+        .run(|app, event| {
             if matches!(event, tauri::RunEvent::Exit) {
                 tracing::info!("application exiting, cleaning up");
                 // Clean up IPC socket on exit
                 platform::ipc_socket::stop_server();
+                // Release display and system sleep assertions
+                app.state::<bar::components::keepawake::KeepAwakeController>().release();
             }
         });
 }
@@ -4,9 +4,56 @@
 //! - Uses `RUST_LOG` environment variable for filtering (default: `info`)
 //! - Outputs to stderr for desktop app compatibility
 //! - Includes timestamps, target, and log levels
+//! - Keeps the tiling window manager at `info` unless `tiling.verbose` is set
+//! - Also writes to a rotating file in the `logs` cache directory, see `logs`
+//!   in the config
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use tracing_subscriber::prelude::*;
-use tracing_subscriber::{EnvFilter, fmt};
+use tracing_subscriber::{EnvFilter, Registry, fmt, reload};
+
+use crate::cache::get_cache_subdir;
+use crate::config::LogsConfig;
+
+/// Target prefix of the tiling window manager logs.
+const TILING_TARGET: &str = concat!(env!("CARGO_CRATE_NAME"), "::modules::tiling");
+
+/// Handle swapping the filter when `tiling.verbose` changes.
+/// Unset when `RUST_LOG` decides the levels.
+static FILTER_HANDLE: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
+
+/// The log file, or `None` until the config is applied or while it's disabled.
+static LOG_FILE: Mutex<Option<RotatingFile>> = Mutex::new(None);
+
+/// Whether window titles are replaced with [`REDACTED_TITLE`] in the logs.
+static REDACT_TITLES: AtomicBool = AtomicBool::new(false);
+
+/// Logged in place of window titles with `logs.redactTitles`.
+const REDACTED_TITLE: &str = "<redacted>";
+
+/// Returns the filter used without `RUST_LOG`.
+///
+/// Stache logs at `info` for release builds and `debug` for debug builds, and
+/// everything else at `warn`. The tiling manager logs every event it handles,
+/// so it stays at `info` unless `tiling_verbose` is set.
+fn default_filter(tiling_verbose: bool) -> EnvFilter {
+    let default_level = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "info"
+    };
+    let tiling_level = if tiling_verbose { "debug" } else { "info" };
+
+    EnvFilter::new(format!(
+        "warn,stache={default_level},{TILING_TARGET}={tiling_level}"
+    ))
+}
 
 /// Initializes the global tracing subscriber.
 ///
@@ -18,17 +65,14 @@ use tracing_subscriber::{EnvFilter, fmt};
 /// - `RUST_LOG=trace` - Show all logs including trace
 ///
 /// Default level is `info` for release builds and `debug` for debug builds.
+/// `RUST_LOG` takes precedence over `tiling.verbose`.
 pub fn init() {
-    let default_level = if cfg!(debug_assertions) {
-        "debug"
-    } else {
-        "info"
-    };
-
-    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| {
-        // Default: show info+ for stache, warn+ for everything else
-        EnvFilter::new(format!("warn,stache={default_level}"))
-    });
+    let env_filter = EnvFilter::try_from_default_env().ok();
+    let from_env = env_filter.is_some();
+    let (filter, handle) = reload::Layer::new(env_filter.unwrap_or_else(|| default_filter(false)));
+    if !from_env {
+        let _ = FILTER_HANDLE.set(handle);
+    }
 
     let subscriber = fmt::layer()
         .with_target(true)
@@ -39,7 +83,294 @@ pub fn init() {
         .with_ansi(true)
         .compact();
 
-    tracing_subscriber::registry().with(filter).with(subscriber).init();
+    let file_subscriber = fmt::layer()
+        .with_target(true)
+        .with_thread_names(true)
+        .with_ansi(false)
+        .with_writer(|| LogFileWriter);
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(subscriber)
+        .with(file_subscriber)
+        .init();
 
     tracing::info!("starting stache desktop application");
 }
+
+/// Sets whether the tiling window manager logs at `debug` level.
+///
+/// Does nothing when `RUST_LOG` is set.
+pub fn set_tiling_verbose(verbose: bool) {
+    let Some(handle) = FILTER_HANDLE.get() else {
+        return;
+    };
+
+    if let Err(err) = handle.reload(default_filter(verbose)) {
+        tracing::warn!(error = %err, "failed to update the log filter");
+    }
+}
+
+/// Applies the `logs` config: opens, updates or closes the log file and sets
+/// whether window titles are redacted.
+pub fn apply_config(config: &LogsConfig) {
+    REDACT_TITLES.store(config.redact_titles, Ordering::Relaxed);
+
+    let result = update_log_file(
+        &mut LOG_FILE.lock().unwrap_or_else(PoisonError::into_inner),
+        config,
+    );
+    if let Err(err) = result {
+        tracing::warn!(error = %err, path = %log_file_path().display(), "failed to open the log file");
+    }
+}
+
+/// Returns a window title to log, or a placeholder with `logs.redactTitles`.
+#[must_use]
+pub fn redact_title(title: &str) -> &str {
+    if REDACT_TITLES.load(Ordering::Relaxed) {
+        REDACTED_TITLE
+    } else {
+        title
+    }
+}
+
+/// Returns the directory holding the log files.
+#[must_use]
+pub fn log_dir() -> PathBuf { get_cache_subdir("logs") }
+
+/// Returns the path of the current log file.
+#[must_use]
+pub fn log_file_path() -> PathBuf { log_dir().join("stache.log") }
+
+// ============================================================================
+// Log File
+// ============================================================================
+
+/// Opens, updates or closes the log file to match `config`.
+fn update_log_file(log_file: &mut Option<RotatingFile>, config: &LogsConfig) -> io::Result<()> {
+    if !config.enabled {
+        *log_file = None;
+        return Ok(());
+    }
+
+    match log_file {
+        Some(file) => {
+            file.max_size = config.max_size_bytes();
+            file.rotate_daily = config.rotate_daily;
+            file.retention = config.retention;
+            file.prune();
+        }
+        None => {
+            let file = RotatingFile::open(
+                log_file_path(),
+                config.max_size_bytes(),
+                config.rotate_daily,
+                config.retention,
+            )?;
+            *log_file = Some(file);
+        }
+    }
+    Ok(())
+}
+
+/// Writer handing formatted events to the log file, if one is open.
+struct LogFileWriter;
+
+impl Write for LogFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match LOG_FILE.lock().unwrap_or_else(PoisonError::into_inner).as_mut() {
+            Some(file) => file.write(buf),
+            None => Ok(buf.len()),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match LOG_FILE.lock().unwrap_or_else(PoisonError::into_inner).as_mut() {
+            Some(file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns the number of days between the Unix epoch and `time`, in UTC.
+fn day_of(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs() / 86_400)
+}
+
+/// Returns the path of the `index`th rotated file, e.g. `stache.1.log`.
+fn rotated_path(path: &Path, index: u32) -> PathBuf { path.with_extension(format!("{index}.log")) }
+
+/// Log file that is rotated once it reaches its maximum size, and optionally
+/// when the day changes.
+///
+/// Rotating renames the file to `stache.1.log`, moves older files up by one
+/// and deletes those past the retention count.
+struct RotatingFile {
+    path: PathBuf,
+    file: File,
+    /// Bytes in the current file.
+    size: u64,
+    /// Bytes the file may reach before it is rotated, or `None` to never rotate.
+    max_size: Option<u64>,
+    /// Whether the file is rotated on the first write of a new day.
+    rotate_daily: bool,
+    /// Day of the last write, see [`day_of`].
+    day: u64,
+    /// Number of rotated files kept.
+    retention: u32,
+}
+
+impl RotatingFile {
+    /// Opens the log file at `path` for appending, creating it if needed.
+    fn open(
+        path: PathBuf,
+        max_size: Option<u64>,
+        rotate_daily: bool,
+        retention: u32,
+    ) -> io::Result<Self> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let metadata = file.metadata()?;
+        let day = day_of(metadata.modified().unwrap_or_else(|_| SystemTime::now()));
+
+        let log_file = Self {
+            path,
+            file,
+            size: metadata.len(),
+            max_size,
+            rotate_daily,
+            day,
+            retention,
+        };
+        log_file.prune();
+        Ok(log_file)
+    }
+
+    /// Deletes rotated files past the retention count, e.g. after it was lowered.
+    fn prune(&self) {
+        let mut index = self.retention + 1;
+        while fs::remove_file(rotated_path(&self.path, index)).is_ok() {
+            index += 1;
+        }
+    }
+
+    /// Moves the current file to `stache.1.log` and starts a new one.
+    fn rotate(&mut self) -> io::Result<()> {
+        if self.retention > 0 {
+            for index in (1..self.retention).rev() {
+                let from = rotated_path(&self.path, index);
+                if from.exists() {
+                    fs::rename(from, rotated_path(&self.path, index + 1))?;
+                }
+            }
+            fs::rename(&self.path, rotated_path(&self.path, 1))?;
+        }
+
+        self.file = OpenOptions::new().create(true).write(true).truncate(true).open(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = u64::try_from(buf.len()).unwrap_or(u64::MAX);
+        let today = day_of(SystemTime::now());
+        let full = self.max_size.is_some_and(|max| self.size.saturating_add(len) > max);
+        let new_day = self.rotate_daily && today != self.day;
+        if self.size > 0 && (full || new_day) {
+            self.rotate()?;
+        }
+        self.day = today;
+
+        let written = self.file.write(buf)?;
+        self.size += u64::try_from(written).unwrap_or(u64::MAX);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> { self.file.flush() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(path: &Path) -> String { fs::read_to_string(path).unwrap_or_default() }
+
+    #[test]
+    fn test_default_filter_sets_tiling_level() {
+        assert!(default_filter(false).to_string().contains("stache_lib::modules::tiling=info"));
+        assert!(default_filter(true).to_string().contains("stache_lib::modules::tiling=debug"));
+    }
+
+    #[test]
+    fn test_rotating_file_rotates_at_max_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stache.log");
+        let mut file = RotatingFile::open(path.clone(), Some(10), false, 2).unwrap();
+
+        file.write_all(b"first\n").unwrap();
+        file.write_all(b"second\n").unwrap();
+        file.write_all(b"third\n").unwrap();
+        file.write_all(b"fourth\n").unwrap();
+
+        assert_eq!(read(&path), "fourth\n");
+        assert_eq!(read(&rotated_path(&path, 1)), "third\n");
+        assert_eq!(read(&rotated_path(&path, 2)), "second\n");
+        assert!(!rotated_path(&path, 3).exists());
+    }
+
+    #[test]
+    fn test_rotating_file_rotates_on_day_change() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stache.log");
+        let mut file = RotatingFile::open(path.clone(), None, true, 2).unwrap();
+
+        file.write_all(b"today\n").unwrap();
+        file.write_all(b"still today\n").unwrap();
+        assert!(!rotated_path(&path, 1).exists());
+
+        file.day -= 1;
+        file.write_all(b"tomorrow\n").unwrap();
+
+        assert_eq!(read(&path), "tomorrow\n");
+        assert_eq!(read(&rotated_path(&path, 1)), "today\nstill today\n");
+    }
+
+    #[test]
+    fn test_rotating_file_without_retention_truncates() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stache.log");
+        let mut file = RotatingFile::open(path.clone(), Some(10), false, 0).unwrap();
+
+        file.write_all(b"first\n").unwrap();
+        file.write_all(b"second\n").unwrap();
+
+        assert_eq!(read(&path), "second\n");
+        assert!(!rotated_path(&path, 1).exists());
+    }
+
+    #[test]
+    fn test_rotating_file_prunes_files_past_retention() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("stache.log");
+        for index in 1..=4 {
+            fs::write(rotated_path(&path, index), "old").unwrap();
+        }
+
+        let _file = RotatingFile::open(path.clone(), None, false, 2).unwrap();
+
+        assert!(rotated_path(&path, 2).exists());
+        assert!(!rotated_path(&path, 3).exists());
+        assert!(!rotated_path(&path, 4).exists());
+    }
+
+    #[test]
+    fn test_rotated_path() {
+        let path = Path::new("/tmp/logs/stache.log");
+        assert_eq!(rotated_path(path, 3), Path::new("/tmp/logs/stache.3.log"));
+    }
+}
@@ -7,7 +7,8 @@
 //! - When called with subcommands (e.g., `stache wallpaper set`): runs CLI commands
 //!
 //! If the desktop app is already running, CLI commands communicate with it directly.
-//! If not running, CLI commands launch the app in the background first.
+//! If not running, CLI commands that need it launch the app in the background
+//! first, unless `--no-launch` is passed.
 
 // Emit a clear compile-time error if attempted to compile on unsupported platforms
 #[cfg(not(target_os = "macos"))]
@@ -39,7 +40,7 @@ fn main() {
         stache_lib::run();
     } else if let Err(err) = stache_lib::cli::run() {
         eprintln!("stache: {err}");
-        std::process::exit(1);
+        std::process::exit(err.exit_code());
     }
 }
 
@@ -0,0 +1,281 @@
+//! Automatic output switching when preferred devices connect.
+//!
+//! Listens for changes to the `CoreAudio` device list and, whenever an output
+//! device is connected or disconnected, switches the default output to the
+//! highest-priority device from `audio.autoSwitch` that is available.
+//!
+//! Only device list transitions trigger a switch. Changes to the default
+//! output alone (e.g. the user picking another device from the menu bar) are
+//! ignored, so the listener never fights the user or itself.
+
+use std::collections::BTreeSet;
+use std::ffi::c_void;
+use std::ptr::NonNull;
+use std::sync::OnceLock;
+use std::sync::mpsc::{Sender, channel};
+
+use objc2_core_audio::{
+    AudioDeviceID, AudioObjectAddPropertyListener, AudioObjectID, AudioObjectPropertyAddress,
+    kAudioHardwarePropertyDevices, kAudioObjectPropertyElementMain,
+    kAudioObjectPropertyScopeGlobal, kAudioObjectSystemObject,
+};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+use super::device::{
+    AudioDevice, find_device_by_priority, get_default_output_device, get_output_devices,
+};
+use super::watcher::set_default_output_device;
+use crate::config::AudioDevicePriority;
+use crate::events;
+use crate::platform::thread::spawn_named_thread;
+
+/// Stores the Sender used by the device list listener.
+/// Kept alive for the application's lifetime since `CoreAudio` holds the raw pointer.
+static LISTENER_SENDER: OnceLock<Box<Sender<()>>> = OnceLock::new();
+
+/// Ensures the auto-switch listener is only started once.
+static AUTO_SWITCH_ONCE: OnceLock<()> = OnceLock::new();
+
+/// Payload emitted when the default output is switched automatically.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputDeviceSwitched {
+    /// `CoreAudio` device ID of the new default output.
+    pub id: u32,
+    /// Human readable device name.
+    pub name: String,
+}
+
+/// Returns the IDs of the given devices as an ordered set.
+fn device_ids(devices: &[AudioDevice]) -> BTreeSet<AudioDeviceID> {
+    devices.iter().map(|device| device.id).collect()
+}
+
+/// Picks the highest-priority available device from the priority list.
+fn select_preferred_device<'a>(
+    devices: &'a [AudioDevice],
+    priorities: &[AudioDevicePriority],
+) -> Option<&'a AudioDevice> {
+    priorities
+        .iter()
+        .find_map(|priority| find_device_by_priority(devices, priority))
+}
+
+/// Decides which device to switch to after the device list changed.
+///
+/// Returns `None` when the set of devices is unchanged (the listener also fires
+/// for property updates that do not add or remove devices), when no configured
+/// device is available, or when the preferred device is already the default.
+fn resolve_switch_target<'a>(
+    previous: &BTreeSet<AudioDeviceID>,
+    devices: &'a [AudioDevice],
+    current_default: Option<AudioDeviceID>,
+    priorities: &[AudioDevicePriority],
+) -> Option<&'a AudioDevice> {
+    if *previous == device_ids(devices) {
+        return None;
+    }
+
+    select_preferred_device(devices, priorities).filter(|target| current_default != Some(target.id))
+}
+
+/// Handles a device list change, switching the default output if needed.
+fn on_device_list_change(
+    app_handle: &AppHandle,
+    previous: &mut BTreeSet<AudioDeviceID>,
+    priorities: &[AudioDevicePriority],
+) {
+    let devices = get_output_devices();
+    let current_default = get_default_output_device().map(|device| device.id);
+    let target = resolve_switch_target(previous, &devices, current_default, priorities);
+
+    *previous = device_ids(&devices);
+
+    let Some(target) = target else {
+        return;
+    };
+
+    if !set_default_output_device(target.id) {
+        tracing::error!(device = %target.name, "auto-switch: failed to set default output device");
+        return;
+    }
+
+    tracing::info!(device = %target.name, "auto-switch: default output device changed");
+
+    let payload = OutputDeviceSwitched {
+        id: target.id,
+        name: target.name.clone(),
+    };
+
+    if let Err(err) = app_handle.emit(events::audio::OUTPUT_DEVICE_SWITCHED, &payload) {
+        tracing::warn!(error = %err, "failed to emit output-device-switched event");
+    }
+}
+
+/// Property listener callback for device list changes.
+///
+/// # Safety
+///
+/// This function is called by `CoreAudio` and expects valid pointers.
+unsafe extern "C-unwind" fn device_list_listener(
+    _in_object_id: AudioObjectID,
+    _in_number_addresses: u32,
+    _in_addresses: NonNull<AudioObjectPropertyAddress>,
+    in_client_data: *mut c_void,
+) -> i32 {
+    if !in_client_data.is_null() {
+        // SAFETY: in_client_data is the Sender stored in LISTENER_SENDER
+        let tx = unsafe { &*in_client_data.cast::<Sender<()>>() };
+        let _ = tx.send(());
+    }
+    0 // kAudioHardwareNoError
+}
+
+/// Registers the device list listener with `CoreAudio`.
+fn register_device_list_listener(tx: Sender<()>) {
+    let sender_box = LISTENER_SENDER.get_or_init(|| Box::new(tx));
+    let tx_ptr: *mut c_void =
+        std::ptr::from_ref::<Sender<()>>(sender_box.as_ref()).cast_mut().cast();
+
+    let devices_property_address = AudioObjectPropertyAddress {
+        mSelector: kAudioHardwarePropertyDevices,
+        mScope: kAudioObjectPropertyScopeGlobal,
+        mElement: kAudioObjectPropertyElementMain,
+    };
+
+    unsafe {
+        AudioObjectAddPropertyListener(
+            kAudioObjectSystemObject as AudioObjectID,
+            NonNull::from(&devices_property_address),
+            Some(device_list_listener),
+            tx_ptr,
+        );
+    }
+}
+
+/// Starts the auto-switch listener.
+///
+/// This is idempotent - calling it multiple times has no effect. The current
+/// device list is recorded as the baseline, so nothing is switched until a
+/// device actually connects or disconnects.
+///
+/// # Arguments
+///
+/// * `app_handle` - Used to emit the switch event to the frontend.
+/// * `priorities` - Device priority list from `audio.autoSwitch`.
+pub fn start(app_handle: AppHandle, priorities: Vec<AudioDevicePriority>) {
+    if AUTO_SWITCH_ONCE.set(()).is_err() {
+        return;
+    }
+
+    spawn_named_thread("audio-auto-switch", move || {
+        let (tx, rx) = channel();
+        let mut previous = device_ids(&get_output_devices());
+
+        register_device_list_listener(tx);
+
+        while rx.recv().is_ok() {
+            on_device_list_change(&app_handle, &mut previous, &priorities);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MatchStrategy;
+
+    fn device(id: AudioDeviceID, name: &str) -> AudioDevice {
+        AudioDevice { id, name: name.to_string() }
+    }
+
+    fn priority(name: &str) -> AudioDevicePriority {
+        AudioDevicePriority {
+            name: name.to_string(),
+            strategy: MatchStrategy::Contains,
+            depends_on: None,
+        }
+    }
+
+    #[test]
+    fn test_switches_when_preferred_device_connects() {
+        let previous = BTreeSet::from([1]);
+        let devices = vec![device(1, "MacBook Pro Speakers"), device(2, "AirPods Pro")];
+        let priorities = vec![priority("AirPods")];
+
+        let target = resolve_switch_target(&previous, &devices, Some(1), &priorities);
+
+        assert_eq!(target.map(|d| d.id), Some(2));
+    }
+
+    #[test]
+    fn test_ignores_unchanged_device_list() {
+        let previous = BTreeSet::from([1, 2]);
+        let devices = vec![device(1, "MacBook Pro Speakers"), device(2, "AirPods Pro")];
+        let priorities = vec![priority("AirPods")];
+
+        // The user picked the speakers manually; do not switch back
+        let target = resolve_switch_target(&previous, &devices, Some(1), &priorities);
+
+        assert!(target.is_none());
+    }
+
+    #[test]
+    fn test_no_switch_when_preferred_is_already_default() {
+        let previous = BTreeSet::from([2]);
+        let devices = vec![device(1, "MacBook Pro Speakers"), device(2, "AirPods Pro")];
+        let priorities = vec![priority("AirPods")];
+
+        let target = resolve_switch_target(&previous, &devices, Some(2), &priorities);
+
+        assert!(target.is_none());
+    }
+
+    #[test]
+    fn test_falls_back_to_next_priority_on_disconnect() {
+        let previous = BTreeSet::from([1, 2, 3]);
+        let devices = vec![
+            device(1, "MacBook Pro Speakers"),
+            device(3, "Studio Display"),
+        ];
+        let priorities = vec![priority("AirPods"), priority("Studio Display")];
+
+        let target = resolve_switch_target(&previous, &devices, Some(1), &priorities);
+
+        assert_eq!(target.map(|d| d.id), Some(3));
+    }
+
+    #[test]
+    fn test_no_switch_without_matching_device() {
+        let previous = BTreeSet::from([1]);
+        let devices = vec![device(1, "MacBook Pro Speakers"), device(4, "USB Audio")];
+        let priorities = vec![priority("AirPods")];
+
+        let target = resolve_switch_target(&previous, &devices, Some(1), &priorities);
+
+        assert!(target.is_none());
+    }
+
+    #[test]
+    fn test_select_preferred_device_respects_order() {
+        let devices = vec![device(1, "AirPods Pro"), device(2, "Studio Display")];
+        let priorities = vec![priority("Studio"), priority("AirPods")];
+
+        assert_eq!(
+            select_preferred_device(&devices, &priorities).map(|d| d.id),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_output_device_switched_serialization() {
+        let payload = OutputDeviceSwitched {
+            id: 7,
+            name: "AirPods Pro".to_string(),
+        };
+        let json = serde_json::to_string(&payload).unwrap();
+
+        assert_eq!(json, r#"{"id":7,"name":"AirPods Pro"}"#);
+    }
+}
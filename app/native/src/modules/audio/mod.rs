@@ -12,28 +12,50 @@
 //!   config file under the `proxyAudio` section.
 //! - **`AirPlay` priority**: `AirPlay` devices are always given highest priority, even
 //!   if not explicitly listed in the configuration.
+//! - **Auto-switch on connect**: Switches the default output to the preferred device
+//!   from `audio.autoSwitch` whenever a device connects or disconnects, and emits an
+//!   event so the bar can announce the change.
+//! - **Per-app volume**: Mute or attenuate a single application via `CoreAudio`
+//!   process taps (macOS 14.4+).
 
+mod auto_switch;
 mod device;
 mod list;
 mod priority;
+pub mod process;
 mod watcher;
 
 // Re-export commonly used types
-pub use device::{AudioDevice, AudioDeviceType};
+pub use auto_switch::OutputDeviceSwitched;
+pub use device::{AudioDevice, AudioDeviceType, get_default_output_device, get_output_devices};
 pub use list::{AudioDeviceInfo, DeviceFilter, format_devices_table, list_devices};
+use tauri::AppHandle;
+pub use watcher::set_default_output_device;
 
 use crate::config::get_config;
 
 /// Initializes the audio module.
 ///\n/// Sets up device watchers and applies initial device configuration.
 /// Only starts if proxy audio is enabled in the config.
-pub fn init() {
+///
+/// Automatic output switching from `audio.autoSwitch` is started when
+/// configured, unless proxy audio is enabled, since both would compete for
+/// the default output device.
+pub fn init(app_handle: AppHandle) {
     let config = get_config();
 
     // Only start if proxy audio is enabled
     if config.proxy_audio.is_enabled() {
         watcher::start(config.proxy_audio.clone());
     }
+
+    if config.audio.is_auto_switch_enabled() {
+        if config.proxy_audio.is_enabled() {
+            tracing::warn!("audio.autoSwitch is ignored while proxyAudio is enabled");
+        } else {
+            auto_switch::start(app_handle, config.audio.auto_switch.clone());
+        }
+    }
 }
 
 #[cfg(test)]
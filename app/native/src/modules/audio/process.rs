@@ -0,0 +1,748 @@
+//! Per-application audio control using `CoreAudio` process taps.
+//!
+//! Process taps (macOS 14.4+) capture the output of a single process. A tap
+//! created with the "muted when tapped" behavior silences the original audio
+//! while it is being read, so the captured signal can be played back through
+//! a private aggregate device at any gain. Untouched apps (full volume, not
+//! muted) have no tap at all and play through the system as usual.
+//!
+//! The aggregate device plays through the default output at the time it is
+//! created, so every tap is rebuilt when the default output changes. Entries
+//! of apps that quit are dropped whenever the app audio state is read or set.
+
+use std::collections::HashMap;
+use std::ffi::{CStr, c_void};
+use std::ptr::{NonNull, null, null_mut};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{LazyLock, Mutex, Once, PoisonError};
+
+use core_foundation::array::CFArray;
+use core_foundation::base::{CFType, TCFType};
+use core_foundation::dictionary::CFDictionary;
+use core_foundation::number::CFNumber;
+use core_foundation::string::{CFString, CFStringRef};
+use core_foundation_sys::dictionary::CFDictionaryRef;
+use objc::runtime::{Class, Object, YES};
+use objc::{msg_send, sel, sel_impl};
+use objc2_core_audio::{
+    AudioDeviceID, AudioObjectAddPropertyListener, AudioObjectGetPropertyData, AudioObjectID,
+    AudioObjectPropertyAddress, kAudioHardwareNoError, kAudioHardwarePropertyDefaultOutputDevice,
+    kAudioObjectPropertyElementMain, kAudioObjectPropertyScopeGlobal, kAudioObjectSystemObject,
+};
+
+use super::device::get_default_output_device;
+use crate::platform::thread::spawn_named_thread;
+
+// ============================================================================
+// FFI
+// ============================================================================
+
+/// Mirror of `AudioBuffer`.
+#[repr(C)]
+#[allow(dead_code)] // Fields mirror the C layout and are not all read
+struct AudioBuffer {
+    number_channels: u32,
+    data_byte_size: u32,
+    data: *mut c_void,
+}
+
+/// Mirror of `AudioBufferList` (variable-length `buffers` array).
+#[repr(C)]
+struct AudioBufferList {
+    number_buffers: u32,
+    buffers: [AudioBuffer; 1],
+}
+
+type AudioDeviceIoProc = unsafe extern "C" fn(
+    device: AudioObjectID,
+    now: *const c_void,
+    input_data: *const AudioBufferList,
+    input_time: *const c_void,
+    output_data: *mut AudioBufferList,
+    output_time: *const c_void,
+    client_data: *mut c_void,
+) -> i32;
+
+#[link(name = "CoreAudio", kind = "framework")]
+unsafe extern "C" {
+    fn AudioHardwareCreateProcessTap(description: *mut Object, tap_id: *mut AudioObjectID) -> i32;
+    fn AudioHardwareDestroyProcessTap(tap_id: AudioObjectID) -> i32;
+    fn AudioHardwareCreateAggregateDevice(
+        description: CFDictionaryRef,
+        device_id: *mut AudioObjectID,
+    ) -> i32;
+    fn AudioHardwareDestroyAggregateDevice(device_id: AudioObjectID) -> i32;
+    fn AudioDeviceCreateIOProcID(
+        device: AudioObjectID,
+        io_proc: AudioDeviceIoProc,
+        client_data: *mut c_void,
+        proc_id: *mut *mut c_void,
+    ) -> i32;
+    fn AudioDeviceDestroyIOProcID(device: AudioObjectID, proc_id: *mut c_void) -> i32;
+    fn AudioDeviceStart(device: AudioObjectID, proc_id: *mut c_void) -> i32;
+    fn AudioDeviceStop(device: AudioObjectID, proc_id: *mut c_void) -> i32;
+}
+
+/// Builds a `CoreAudio` four-character selector.
+const fn four_cc(code: &[u8; 4]) -> u32 { u32::from_be_bytes(*code) }
+
+const PROPERTY_TRANSLATE_PID_TO_PROCESS: u32 = four_cc(b"id2p");
+const PROPERTY_PROCESS_IS_RUNNING_OUTPUT: u32 = four_cc(b"piro");
+const PROPERTY_DEVICE_UID: u32 = four_cc(b"uid ");
+
+/// `CATapMuteBehavior.mutedWhenTapped`: silence the process only while the tap is read.
+const TAP_MUTED_WHEN_TAPPED: isize = 2;
+
+/// Minimum macOS version with process tap support.
+const MIN_OS_VERSION: (isize, isize) = (14, 4);
+
+/// Mirror of `NSOperatingSystemVersion`.
+#[repr(C)]
+#[allow(dead_code)] // Fields mirror the C layout and are not all read
+#[derive(Debug, Clone, Copy)]
+struct NSOperatingSystemVersion {
+    major: isize,
+    minor: isize,
+    patch: isize,
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Errors that can occur when controlling per-application audio.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProcessAudioError {
+    /// The running macOS version has no process tap support.
+    Unsupported,
+    /// No audio process exists for the given PID.
+    ProcessNotFound(i32),
+    /// The requested volume is outside `0.0..=1.0`.
+    InvalidVolume,
+    /// A `CoreAudio` call failed.
+    CoreAudio(&'static str, i32),
+}
+
+impl std::fmt::Display for ProcessAudioError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported => write!(
+                f,
+                "Per-app audio control requires macOS {}.{} or later",
+                MIN_OS_VERSION.0, MIN_OS_VERSION.1
+            ),
+            Self::ProcessNotFound(pid) => write!(f, "No audio process found for PID {pid}"),
+            Self::InvalidVolume => write!(f, "Volume must be between 0.0 and 1.0"),
+            Self::CoreAudio(call, status) => write!(f, "{call} failed with status {status}"),
+        }
+    }
+}
+
+impl std::error::Error for ProcessAudioError {}
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Audio state of a single application.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AppAudioState {
+    /// Output volume applied by Stache (0.0 - 1.0).
+    pub volume: f32,
+    /// Whether the application is muted.
+    pub muted: bool,
+    /// Whether the application is currently producing output.
+    pub is_playing: bool,
+}
+
+/// Volume and mute settings applied to an application.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct AppAudioSettings {
+    volume: f32,
+    muted: bool,
+}
+
+impl Default for AppAudioSettings {
+    fn default() -> Self { Self { volume: 1.0, muted: false } }
+}
+
+impl AppAudioSettings {
+    /// Gain applied to the tapped signal.
+    const fn gain(self) -> f32 { if self.muted { 0.0 } else { self.volume } }
+
+    /// Whether the settings leave the app untouched, so no tap is needed.
+    #[allow(clippy::float_cmp)] // 1.0 is set exactly, never computed
+    fn is_passthrough(self) -> bool { !self.muted && self.volume == 1.0 }
+}
+
+/// A process tap routed through a private aggregate device.
+struct ProcessTap {
+    tap_id: AudioObjectID,
+    aggregate_id: AudioObjectID,
+    io_proc_id: usize,
+    /// Gain read by the IO proc, stored as `f32` bits. Boxed so the pointer
+    /// handed to `CoreAudio` stays stable.
+    gain: Box<AtomicU32>,
+}
+
+/// Per-application entry keyed by PID.
+struct AppAudioEntry {
+    settings: AppAudioSettings,
+    tap: ProcessTap,
+}
+
+static APP_AUDIO: LazyLock<Mutex<HashMap<i32, AppAudioEntry>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Registers the default output listener once, with the first tap.
+static OUTPUT_LISTENER: Once = Once::new();
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Returns whether per-application audio control is available.
+#[must_use]
+pub fn is_supported() -> bool {
+    let Some(process_info_class) = Class::get("NSProcessInfo") else {
+        return false;
+    };
+
+    let version: NSOperatingSystemVersion = unsafe {
+        let process_info: *mut Object = msg_send![process_info_class, processInfo];
+        if process_info.is_null() {
+            return false;
+        }
+        msg_send![process_info, operatingSystemVersion]
+    };
+
+    (version.major, version.minor) >= MIN_OS_VERSION
+}
+
+/// Returns the audio state of the application with the given PID.
+///
+/// # Errors
+///
+/// Returns an error if process taps are unsupported or the PID has no audio process.
+pub fn app_audio(pid: i32) -> Result<AppAudioState, ProcessAudioError> {
+    ensure_supported()?;
+    prune_exited();
+    let process = translate_pid(pid)?;
+
+    let settings = lock_entries().get(&pid).map(|entry| entry.settings).unwrap_or_default();
+
+    Ok(AppAudioState {
+        volume: settings.volume,
+        muted: settings.muted,
+        is_playing: read_u32_property(process, PROPERTY_PROCESS_IS_RUNNING_OUTPUT)
+            .is_some_and(|value| value != 0),
+    })
+}
+
+/// Sets the output volume (0.0 - 1.0) of the application with the given PID.
+///
+/// # Errors
+///
+/// Returns an error if the volume is out of range, process taps are
+/// unsupported, or the tap cannot be created.
+pub fn set_app_volume(pid: i32, volume: f32) -> Result<AppAudioState, ProcessAudioError> {
+    if !(0.0..=1.0).contains(&volume) {
+        return Err(ProcessAudioError::InvalidVolume);
+    }
+
+    update_settings(pid, |settings| settings.volume = volume)?;
+    app_audio(pid)
+}
+
+/// Mutes or unmutes the application with the given PID.
+///
+/// # Errors
+///
+/// Returns an error if process taps are unsupported or the tap cannot be created.
+pub fn set_app_mute(pid: i32, muted: bool) -> Result<AppAudioState, ProcessAudioError> {
+    update_settings(pid, |settings| settings.muted = muted)?;
+    app_audio(pid)
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn ensure_supported() -> Result<(), ProcessAudioError> {
+    if is_supported() {
+        Ok(())
+    } else {
+        Err(ProcessAudioError::Unsupported)
+    }
+}
+
+fn lock_entries() -> std::sync::MutexGuard<'static, HashMap<i32, AppAudioEntry>> {
+    APP_AUDIO.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Applies `update` to the app's settings, creating or tearing down its tap.
+fn update_settings(
+    pid: i32,
+    update: impl FnOnce(&mut AppAudioSettings),
+) -> Result<(), ProcessAudioError> {
+    ensure_supported()?;
+    prune_exited();
+    let process = translate_pid(pid)?;
+
+    let mut entries = lock_entries();
+    let mut settings = entries.get(&pid).map(|entry| entry.settings).unwrap_or_default();
+    update(&mut settings);
+
+    if settings.is_passthrough() {
+        if let Some(entry) = entries.remove(&pid) {
+            destroy_tap(&entry.tap);
+        }
+        return Ok(());
+    }
+
+    if let Some(entry) = entries.get_mut(&pid) {
+        entry.settings = settings;
+        entry.tap.gain.store(settings.gain().to_bits(), Ordering::Relaxed);
+        return Ok(());
+    }
+
+    let tap = create_tap(pid, process, settings.gain())?;
+    entries.insert(pid, AppAudioEntry { settings, tap });
+    drop(entries);
+
+    OUTPUT_LISTENER.call_once(register_output_listener);
+    Ok(())
+}
+
+/// Tears down the taps of apps that are no longer running.
+fn prune_exited() {
+    lock_entries().retain(|&pid, entry| {
+        let running = translate_pid(pid).is_ok();
+        if !running {
+            tracing::debug!(pid, "app audio: dropping the tap of an exited app");
+            destroy_tap(&entry.tap);
+        }
+        running
+    });
+}
+
+/// Rebuilds every tap so its aggregate device plays through the current
+/// default output. The old tap is destroyed first, so apps whose tap can't be
+/// rebuilt are dropped and play at their own volume again.
+fn rebuild_taps() {
+    lock_entries().retain(|&pid, entry| {
+        destroy_tap(&entry.tap);
+
+        let tap =
+            translate_pid(pid).and_then(|process| create_tap(pid, process, entry.settings.gain()));
+        match tap {
+            Ok(tap) => {
+                entry.tap = tap;
+                true
+            }
+            Err(err) => {
+                tracing::warn!(pid, error = %err, "app audio: failed to rebuild the tap");
+                false
+            }
+        }
+    });
+}
+
+/// Listener called by `CoreAudio` when the default output device changes.
+unsafe extern "C-unwind" fn default_output_listener(
+    _object_id: AudioObjectID,
+    _number_addresses: u32,
+    _addresses: NonNull<AudioObjectPropertyAddress>,
+    _client_data: *mut c_void,
+) -> i32 {
+    // Creating devices from a CoreAudio notification can deadlock, so rebuild elsewhere.
+    spawn_named_thread("audio-tap-rebuild", rebuild_taps);
+    0 // kAudioHardwareNoError
+}
+
+/// Starts rebuilding the taps whenever the default output device changes.
+fn register_output_listener() {
+    let address = property_address(kAudioHardwarePropertyDefaultOutputDevice);
+    let status = unsafe {
+        AudioObjectAddPropertyListener(
+            kAudioObjectSystemObject as AudioObjectID,
+            NonNull::from(&address),
+            Some(default_output_listener),
+            null_mut(),
+        )
+    };
+
+    if status != kAudioHardwareNoError {
+        tracing::warn!(status, "app audio: failed to watch the default output device");
+    }
+}
+
+/// Translates a PID into a `CoreAudio` process object.
+fn translate_pid(pid: i32) -> Result<AudioObjectID, ProcessAudioError> {
+    let address = property_address(PROPERTY_TRANSLATE_PID_TO_PROCESS);
+    let mut process: AudioObjectID = 0;
+    #[allow(clippy::cast_possible_truncation)]
+    let mut size = std::mem::size_of::<AudioObjectID>() as u32;
+
+    #[allow(clippy::cast_possible_truncation)]
+    let status = unsafe {
+        AudioObjectGetPropertyData(
+            kAudioObjectSystemObject as AudioObjectID,
+            NonNull::from(&address),
+            std::mem::size_of::<i32>() as u32,
+            std::ptr::from_ref(&pid).cast(),
+            NonNull::from(&mut size),
+            NonNull::from(&mut process).cast(),
+        )
+    };
+
+    if status != kAudioHardwareNoError || process == 0 {
+        return Err(ProcessAudioError::ProcessNotFound(pid));
+    }
+
+    Ok(process)
+}
+
+const fn property_address(selector: u32) -> AudioObjectPropertyAddress {
+    AudioObjectPropertyAddress {
+        mSelector: selector,
+        mScope: kAudioObjectPropertyScopeGlobal,
+        mElement: kAudioObjectPropertyElementMain,
+    }
+}
+
+fn read_u32_property(object: AudioObjectID, selector: u32) -> Option<u32> {
+    let address = property_address(selector);
+    let mut value: u32 = 0;
+    #[allow(clippy::cast_possible_truncation)]
+    let mut size = std::mem::size_of::<u32>() as u32;
+
+    let status = unsafe {
+        AudioObjectGetPropertyData(
+            object,
+            NonNull::from(&address),
+            0,
+            null(),
+            NonNull::from(&mut size),
+            NonNull::from(&mut value).cast(),
+        )
+    };
+
+    (status == kAudioHardwareNoError).then_some(value)
+}
+
+/// Reads the UID of an audio device.
+fn device_uid(device: AudioDeviceID) -> Option<String> {
+    let address = property_address(PROPERTY_DEVICE_UID);
+    let mut uid: CFStringRef = null();
+    #[allow(clippy::cast_possible_truncation)]
+    let mut size = std::mem::size_of::<CFStringRef>() as u32;
+
+    let status = unsafe {
+        AudioObjectGetPropertyData(
+            device,
+            NonNull::from(&address),
+            0,
+            null(),
+            NonNull::from(&mut size),
+            NonNull::from(&mut uid).cast(),
+        )
+    };
+
+    if status != kAudioHardwareNoError || uid.is_null() {
+        return None;
+    }
+
+    Some(unsafe { CFString::wrap_under_create_rule(uid) }.to_string())
+}
+
+/// Creates a muted-when-tapped process tap and returns its ID and UUID.
+fn create_process_tap(
+    process: AudioObjectID,
+) -> Result<(AudioObjectID, String), ProcessAudioError> {
+    let (Some(description_class), Some(number_class), Some(array_class)) = (
+        Class::get("CATapDescription"),
+        Class::get("NSNumber"),
+        Class::get("NSArray"),
+    ) else {
+        return Err(ProcessAudioError::Unsupported);
+    };
+
+    unsafe {
+        let process_number: *mut Object = msg_send![number_class, numberWithUnsignedInt: process];
+        let processes: *mut Object = msg_send![array_class, arrayWithObject: process_number];
+
+        let description: *mut Object = msg_send![description_class, alloc];
+        let description: *mut Object =
+            msg_send![description, initStereoMixdownOfProcesses: processes];
+        if description.is_null() {
+            return Err(ProcessAudioError::CoreAudio("CATapDescription init", -1));
+        }
+
+        let _: () = msg_send![description, setMuteBehavior: TAP_MUTED_WHEN_TAPPED];
+        let _: () = msg_send![description, setPrivate: YES];
+
+        let uuid: *mut Object = msg_send![description, UUID];
+        let uuid_string: *mut Object = msg_send![uuid, UUIDString];
+        let uuid_bytes: *const std::ffi::c_char = msg_send![uuid_string, UTF8String];
+        let tap_uuid = if uuid_bytes.is_null() {
+            String::new()
+        } else {
+            CStr::from_ptr(uuid_bytes).to_string_lossy().into_owned()
+        };
+
+        let mut tap_id: AudioObjectID = 0;
+        let status = AudioHardwareCreateProcessTap(description, &raw mut tap_id);
+        let _: () = msg_send![description, release];
+
+        if status != 0 {
+            return Err(ProcessAudioError::CoreAudio(
+                "AudioHardwareCreateProcessTap",
+                status,
+            ));
+        }
+
+        Ok((tap_id, tap_uuid))
+    }
+}
+
+/// Creates a private aggregate device that reads the tap and plays to the default output.
+fn create_aggregate_device(tap_uuid: &str, pid: i32) -> Result<AudioObjectID, ProcessAudioError> {
+    let output_uid = get_default_output_device()
+        .and_then(|device| device_uid(device.id))
+        .ok_or(ProcessAudioError::CoreAudio("default output device UID", -1))?;
+
+    let key = |name: &str| CFString::new(name).as_CFType();
+    let sub_device = CFDictionary::from_CFType_pairs(&[(key("uid"), key(&output_uid))]);
+    let tap = CFDictionary::from_CFType_pairs(&[
+        (key("uid"), key(tap_uuid)),
+        (key("drift"), CFNumber::from(1).as_CFType()),
+    ]);
+    let description: CFDictionary<CFType, CFType> = CFDictionary::from_CFType_pairs(&[
+        (
+            key("uid"),
+            key(&format!("com.marcosmoura.stache.app-audio.{pid}")),
+        ),
+        (key("name"), key(&format!("Stache App Audio {pid}"))),
+        (key("private"), CFNumber::from(1).as_CFType()),
+        (key("master"), key(&output_uid)),
+        (
+            key("subdevices"),
+            CFArray::from_CFTypes(&[sub_device]).as_CFType(),
+        ),
+        (key("taps"), CFArray::from_CFTypes(&[tap]).as_CFType()),
+        (key("tapautostart"), CFNumber::from(1).as_CFType()),
+    ]);
+
+    let mut aggregate_id: AudioObjectID = 0;
+    let status = unsafe {
+        AudioHardwareCreateAggregateDevice(description.as_concrete_TypeRef(), &raw mut aggregate_id)
+    };
+
+    if status != 0 {
+        return Err(ProcessAudioError::CoreAudio(
+            "AudioHardwareCreateAggregateDevice",
+            status,
+        ));
+    }
+
+    Ok(aggregate_id)
+}
+
+/// Creates the full tap pipeline for a process at the given gain.
+fn create_tap(
+    pid: i32,
+    process: AudioObjectID,
+    gain: f32,
+) -> Result<ProcessTap, ProcessAudioError> {
+    let (tap_id, tap_uuid) = create_process_tap(process)?;
+
+    let aggregate_id = match create_aggregate_device(&tap_uuid, pid) {
+        Ok(id) => id,
+        Err(err) => {
+            unsafe { AudioHardwareDestroyProcessTap(tap_id) };
+            return Err(err);
+        }
+    };
+
+    let gain = Box::new(AtomicU32::new(gain.to_bits()));
+    let client_data: *mut c_void = std::ptr::from_ref::<AtomicU32>(&gain).cast_mut().cast();
+    let mut io_proc_id: *mut c_void = null_mut();
+
+    let status = unsafe {
+        let status = AudioDeviceCreateIOProcID(
+            aggregate_id,
+            render_with_gain,
+            client_data,
+            &raw mut io_proc_id,
+        );
+        if status == 0 {
+            AudioDeviceStart(aggregate_id, io_proc_id)
+        } else {
+            status
+        }
+    };
+
+    let tap = ProcessTap {
+        tap_id,
+        aggregate_id,
+        io_proc_id: io_proc_id as usize,
+        gain,
+    };
+
+    if status != 0 {
+        destroy_tap(&tap);
+        return Err(ProcessAudioError::CoreAudio("AudioDeviceStart", status));
+    }
+
+    Ok(tap)
+}
+
+/// Stops and releases a tap pipeline.
+fn destroy_tap(tap: &ProcessTap) {
+    unsafe {
+        let io_proc_id = tap.io_proc_id as *mut c_void;
+        if !io_proc_id.is_null() {
+            AudioDeviceStop(tap.aggregate_id, io_proc_id);
+            AudioDeviceDestroyIOProcID(tap.aggregate_id, io_proc_id);
+        }
+        AudioHardwareDestroyAggregateDevice(tap.aggregate_id);
+        AudioHardwareDestroyProcessTap(tap.tap_id);
+    }
+}
+
+/// IO proc that copies the tapped input to the output, scaled by the gain.
+///
+/// Both sides use the tap's native `f32` format.
+unsafe extern "C" fn render_with_gain(
+    _device: AudioObjectID,
+    _now: *const c_void,
+    input_data: *const AudioBufferList,
+    _input_time: *const c_void,
+    output_data: *mut AudioBufferList,
+    _output_time: *const c_void,
+    client_data: *mut c_void,
+) -> i32 {
+    if output_data.is_null() || client_data.is_null() {
+        return 0;
+    }
+
+    let gain = f32::from_bits(unsafe { &*client_data.cast::<AtomicU32>() }.load(Ordering::Relaxed));
+    let outputs = unsafe { buffers_mut(output_data) };
+    let inputs = if input_data.is_null() {
+        &[][..]
+    } else {
+        unsafe { buffers(input_data) }
+    };
+
+    for (index, output) in outputs.iter_mut().enumerate() {
+        let out = unsafe { samples_mut(output) };
+        let input = inputs.get(index).map_or(&[][..], |buffer| unsafe { samples(buffer) });
+        apply_gain(input, out, gain);
+    }
+
+    0
+}
+
+unsafe fn buffers<'a>(list: *const AudioBufferList) -> &'a [AudioBuffer] {
+    unsafe {
+        std::slice::from_raw_parts(
+            (&raw const (*list).buffers).cast(),
+            (*list).number_buffers as usize,
+        )
+    }
+}
+
+unsafe fn buffers_mut<'a>(list: *mut AudioBufferList) -> &'a mut [AudioBuffer] {
+    unsafe {
+        std::slice::from_raw_parts_mut(
+            (&raw mut (*list).buffers).cast(),
+            (*list).number_buffers as usize,
+        )
+    }
+}
+
+unsafe fn samples<'a>(buffer: &AudioBuffer) -> &'a [f32] {
+    if buffer.data.is_null() {
+        return &[];
+    }
+    let len = buffer.data_byte_size as usize / std::mem::size_of::<f32>();
+    unsafe { std::slice::from_raw_parts(buffer.data.cast(), len) }
+}
+
+unsafe fn samples_mut<'a>(buffer: &mut AudioBuffer) -> &'a mut [f32] {
+    if buffer.data.is_null() {
+        return &mut [];
+    }
+    let len = buffer.data_byte_size as usize / std::mem::size_of::<f32>();
+    unsafe { std::slice::from_raw_parts_mut(buffer.data.cast(), len) }
+}
+
+/// Writes `input * gain` into `output`, zero-filling any remainder.
+fn apply_gain(input: &[f32], output: &mut [f32], gain: f32) {
+    let copied = input.len().min(output.len());
+    for (out, sample) in output.iter_mut().zip(input) {
+        *out = sample * gain;
+    }
+    output[copied..].fill(0.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_four_cc() {
+        assert_eq!(four_cc(b"id2p"), 0x6964_3270);
+        assert_eq!(four_cc(b"uid "), 0x7569_6420);
+    }
+
+    #[test]
+    fn test_settings_passthrough_and_gain() {
+        let default = AppAudioSettings::default();
+        assert!(default.is_passthrough());
+        assert!((default.gain() - 1.0).abs() < f32::EPSILON);
+
+        let quiet = AppAudioSettings { volume: 0.25, muted: false };
+        assert!(!quiet.is_passthrough());
+        assert!((quiet.gain() - 0.25).abs() < f32::EPSILON);
+
+        let muted = AppAudioSettings { volume: 1.0, muted: true };
+        assert!(!muted.is_passthrough());
+        assert!(muted.gain().abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_apply_gain_scales_and_zero_fills() {
+        let input = [1.0, -0.5];
+        let mut output = [9.0; 4];
+        apply_gain(&input, &mut output, 0.5);
+        assert_eq!(output, [0.5, -0.25, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_apply_gain_truncates_longer_input() {
+        let input = [1.0, 1.0, 1.0];
+        let mut output = [0.0; 2];
+        apply_gain(&input, &mut output, 1.0);
+        assert_eq!(output, [1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_set_app_volume_rejects_out_of_range() {
+        assert_eq!(set_app_volume(1, 1.5), Err(ProcessAudioError::InvalidVolume));
+        assert_eq!(set_app_volume(1, -0.1), Err(ProcessAudioError::InvalidVolume));
+        assert_eq!(
+            set_app_volume(1, f32::NAN),
+            Err(ProcessAudioError::InvalidVolume)
+        );
+    }
+
+    #[test]
+    fn test_process_audio_error_display() {
+        assert!(ProcessAudioError::Unsupported.to_string().contains("macOS 14.4"));
+        assert!(ProcessAudioError::ProcessNotFound(42).to_string().contains("42"));
+        let err = ProcessAudioError::CoreAudio("AudioDeviceStart", -50);
+        assert_eq!(err.to_string(), "AudioDeviceStart failed with status -50");
+    }
+}
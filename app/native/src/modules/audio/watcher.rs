@@ -38,7 +38,7 @@ const AUDIO_DEVICE_ID_SIZE: u32 = std::mem::size_of::<AudioDeviceID>() as u32;
 /// Sets the default output device.
 ///
 /// Returns `true` if the device was set successfully.
-fn set_default_output_device(device_id: AudioDeviceID) -> bool {
+pub fn set_default_output_device(device_id: AudioDeviceID) -> bool {
     let property_address = AudioObjectPropertyAddress {
         mSelector: kAudioHardwarePropertyDefaultOutputDevice,
         mScope: kAudioObjectPropertyScopeGlobal,
@@ -1,13 +1,51 @@
 //! Application launcher component.
 //!
-//! Manages opening whitelisted macOS applications and URLs via the Tauri command interface.
+//! Lists the applications installed in `/Applications` and `~/Applications`,
+//! and opens them or a few whitelisted shortcuts via the Tauri command
+//! interface. The list is cached in `~/Library/Caches/{APP_BUNDLE_ID}/apps`
+//! and scanned again when one of the application folders changes.
 
 #![allow(unexpected_cfgs)]
 
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+use serde::{Deserialize, Serialize};
 use tauri::AppHandle;
 use tauri_plugin_shell::ShellExt;
 
+use crate::cache::get_cache_subdir;
 use crate::error::StacheError;
+use crate::platform::objc::{nsstring, nsstring_to_string};
+
+/// Name of the cache file inside the `apps` cache subdirectory.
+const CACHE_FILE_NAME: &str = "installed.json";
+
+/// An application installed in one of the application folders.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstalledApp {
+    /// Name of the app bundle, without the `.app` extension.
+    pub name: String,
+    /// Bundle identifier (e.g., "com.apple.Safari").
+    pub bundle_id: String,
+    /// Path of the app bundle.
+    pub path: String,
+}
+
+/// Installed apps, along with the folders they were found in.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AppsCache {
+    /// Modification time of each scanned folder, in seconds since the epoch.
+    directories: BTreeMap<String, u64>,
+    /// Apps found in those folders.
+    apps: Vec<InstalledApp>,
+}
 
 #[derive(Clone, Copy)]
 enum LaunchTarget {
@@ -92,26 +130,199 @@ fn launch_url(app: &AppHandle, url: &str) -> Result<(), StacheError> {
     run_open_command(app, [url], url)
 }
 
-/// Opens a whitelisted macOS application by its display name.
+fn launch_bundle(app: &AppHandle, bundle_id: &str) -> Result<(), StacheError> {
+    run_open_command(app, ["-b", bundle_id], bundle_id)
+}
+
+// ============================================================================
+// Installed Apps
+// ============================================================================
+
+/// Returns the folders applications are installed in.
+fn app_directories() -> Vec<PathBuf> {
+    let mut directories = vec![PathBuf::from("/Applications")];
+    if let Some(home) = dirs::home_dir() {
+        directories.push(home.join("Applications"));
+    }
+    directories
+}
+
+/// Returns whether a path is an app bundle.
+fn is_app_bundle(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("app"))
+}
+
+/// Returns the modification time of a path, in seconds since the epoch.
+fn modified_secs(path: &Path) -> Option<u64> {
+    let modified = fs::metadata(path).and_then(|metadata| metadata.modified()).ok()?;
+    modified.duration_since(UNIX_EPOCH).ok().map(|duration| duration.as_secs())
+}
+
+/// Returns the folders to scan for apps with their modification time.
+///
+/// Besides the application folders themselves, this includes the folders
+/// inside them that are not app bundles, such as `Utilities`. Folders that
+/// don't exist are left out.
+fn scanned_directories(roots: &[PathBuf]) -> BTreeMap<String, u64> {
+    let mut directories = BTreeMap::new();
+
+    for root in roots {
+        let Some(modified) = modified_secs(root) else {
+            continue;
+        };
+        directories.insert(root.to_string_lossy().into_owned(), modified);
+
+        let Ok(entries) = fs::read_dir(root) else {
+            continue;
+        };
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if path.is_dir()
+                && !is_app_bundle(&path)
+                && let Some(modified) = modified_secs(&path)
+            {
+                directories.insert(path.to_string_lossy().into_owned(), modified);
+            }
+        }
+    }
+
+    directories
+}
+
+/// Reads the bundle identifier of an app bundle.
+fn read_bundle_id(path: &Path) -> Option<String> {
+    objc::rc::autoreleasepool(|| {
+        // SAFETY: NSBundle is safe to use from any thread, and the path is a
+        // valid NSString. Both objects are autoreleased by the enclosing pool.
+        unsafe {
+            let path = nsstring(&path.to_string_lossy());
+            let bundle: *mut Object = msg_send![class!(NSBundle), bundleWithPath: path];
+            if bundle.is_null() {
+                return None;
+            }
+            let bundle_id: *mut Object = msg_send![bundle, bundleIdentifier];
+            Some(nsstring_to_string(bundle_id)).filter(|id| !id.is_empty())
+        }
+    })
+}
+
+/// Scans folders for app bundles, sorted by name.
+///
+/// When the same app is installed twice, only the first copy found is listed.
+fn scan_apps<'a>(directories: impl IntoIterator<Item = &'a str>) -> Vec<InstalledApp> {
+    let mut apps: Vec<InstalledApp> = Vec::new();
+
+    for directory in directories {
+        let Ok(entries) = fs::read_dir(directory) else {
+            continue;
+        };
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if !is_app_bundle(&path) {
+                continue;
+            }
+            let (Some(name), Some(bundle_id)) = (path.file_stem(), read_bundle_id(&path)) else {
+                continue;
+            };
+            if apps.iter().any(|app| app.bundle_id.eq_ignore_ascii_case(&bundle_id)) {
+                continue;
+            }
+            apps.push(InstalledApp {
+                name: name.to_string_lossy().into_owned(),
+                bundle_id,
+                path: path.to_string_lossy().into_owned(),
+            });
+        }
+    }
+
+    apps.sort_by_cached_key(|app| app.name.to_lowercase());
+    apps
+}
+
+/// Returns the path of the cache file.
+fn cache_file() -> PathBuf { get_cache_subdir("apps").join(CACHE_FILE_NAME) }
+
+/// Reads the cached app list, if any.
+fn read_cache(path: &Path) -> Option<AppsCache> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Writes the app list to the cache.
+fn write_cache(path: &Path, cache: &AppsCache) {
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(path, serde_json::to_vec(cache).unwrap_or_default()));
+
+    if let Err(err) = result {
+        tracing::debug!(error = %err, path = %path.display(), "failed to write apps cache");
+    }
+}
+
+/// Returns the installed apps, scanning the application folders only when
+/// one of them changed since the cached list was built.
+fn installed_apps() -> Vec<InstalledApp> {
+    let directories = scanned_directories(&app_directories());
+    let path = cache_file();
+
+    if let Some(cache) = read_cache(&path).filter(|cache| cache.directories == directories) {
+        return cache.apps;
+    }
+
+    let apps = scan_apps(directories.keys().map(String::as_str));
+
+    tracing::debug!(count = apps.len(), "scanned installed apps");
+    write_cache(&path, &AppsCache {
+        directories,
+        apps: apps.clone(),
+    });
+    apps
+}
+
+/// Finds an installed app by bundle identifier, or else by name.
+fn find_installed_app<'a>(apps: &'a [InstalledApp], query: &str) -> Option<&'a InstalledApp> {
+    let query = query.trim();
+    if query.is_empty() {
+        return None;
+    }
+
+    apps.iter()
+        .find(|app| app.bundle_id.eq_ignore_ascii_case(query))
+        .or_else(|| apps.iter().find(|app| app.name.eq_ignore_ascii_case(query)))
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Lists the applications installed in `/Applications` and `~/Applications`,
+/// sorted by name.
+#[tauri::command(async)]
+pub fn list_apps() -> Vec<InstalledApp> { installed_apps() }
+
+/// Opens a macOS application.
+///
+/// `name` is a whitelisted display name, or the bundle identifier or name of
+/// an app listed by [`list_apps`].
 ///
 /// # Errors
 ///
-/// Returns an error if the application name is not whitelisted or if launching the
+/// Returns an error if no such application exists or if launching the
 /// application fails.
 #[tauri::command]
 #[allow(clippy::needless_pass_by_value)]
 pub fn open_app(app: tauri::AppHandle, name: &str) -> Result<(), StacheError> {
-    resolve_allowed_app(name).map_or_else(
-        || {
-            Err(StacheError::InvalidArguments(format!(
-                "Application '{name}' is not allowed."
-            )))
-        },
-        |entry| match entry.target {
+    if let Some(entry) = resolve_allowed_app(name) {
+        return match entry.target {
             LaunchTarget::Application(app_name) => launch_application(&app, app_name),
             LaunchTarget::Url(url) => launch_url(&app, url),
-        },
-    )
+        };
+    }
+
+    let apps = installed_apps();
+    let installed = find_installed_app(&apps, name).ok_or_else(|| {
+        StacheError::InvalidArguments(format!("Application '{name}' is not installed."))
+    })?;
+    launch_bundle(&app, &installed.bundle_id)
 }
 
 #[cfg(test)]
@@ -147,4 +358,61 @@ mod tests {
         assert!(resolve_allowed_app("   ").is_none());
         assert!(resolve_allowed_app("Nonexistent App").is_none());
     }
+
+    fn installed(name: &str, bundle_id: &str) -> InstalledApp {
+        InstalledApp {
+            name: name.to_string(),
+            bundle_id: bundle_id.to_string(),
+            path: format!("/Applications/{name}.app"),
+        }
+    }
+
+    #[test]
+    fn find_installed_app_matches_bundle_id_before_name() {
+        let apps = [
+            installed("com.example.notes", "com.example.editor"),
+            installed("Notes", "com.example.notes"),
+        ];
+
+        let by_id = find_installed_app(&apps, "COM.EXAMPLE.NOTES").unwrap();
+        assert_eq!(by_id.name, "Notes");
+
+        let by_name = find_installed_app(&apps, " notes ").unwrap();
+        assert_eq!(by_name.bundle_id, "com.example.notes");
+
+        assert!(find_installed_app(&apps, "Editor").is_none());
+        assert!(find_installed_app(&apps, "  ").is_none());
+    }
+
+    #[test]
+    fn scanned_directories_include_subfolders_but_not_bundles() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("Utilities")).unwrap();
+        fs::create_dir_all(dir.path().join("Safari.app/Contents")).unwrap();
+        fs::write(dir.path().join("notes.txt"), "").unwrap();
+
+        let roots = [dir.path().to_path_buf(), dir.path().join("missing")];
+        let directories = scanned_directories(&roots);
+
+        let names: Vec<String> = directories.into_keys().collect();
+        let root = dir.path().to_string_lossy().into_owned();
+        assert_eq!(names, vec![root.clone(), format!("{root}/Utilities")]);
+    }
+
+    #[test]
+    fn apps_cache_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("apps").join(CACHE_FILE_NAME);
+        let cache = AppsCache {
+            directories: BTreeMap::from([("/Applications".to_string(), 42)]),
+            apps: vec![installed("Safari", "com.apple.Safari")],
+        };
+
+        assert!(read_cache(&path).is_none());
+        write_cache(&path, &cache);
+
+        let read = read_cache(&path).unwrap();
+        assert_eq!(read.directories, cache.directories);
+        assert_eq!(read.apps, cache.apps);
+    }
 }
@@ -0,0 +1,248 @@
+//! Audio output device component.
+//!
+//! Lists the available output devices and switches the system default output
+//! so the bar can render a device picker. Also exposes per-application volume
+//! and mute control.
+
+use objc2_core_audio::AudioDeviceID;
+use serde::Serialize;
+
+use crate::error::StacheError;
+use crate::modules::audio::process::{self, AppAudioState, ProcessAudioError};
+use crate::modules::audio::{self, AudioDevice};
+
+/// Output device entry for the bar device picker.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputDevice {
+    /// The `CoreAudio` device ID.
+    id: AudioDeviceID,
+    /// The human-readable device name.
+    name: String,
+    /// Whether this is the current default output device.
+    is_default: bool,
+}
+
+/// Audio state of a single application.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppAudio {
+    /// Process ID of the application.
+    pid: i32,
+    /// Output volume applied by Stache (0.0 - 1.0).
+    volume: f32,
+    /// Whether the application is muted.
+    muted: bool,
+    /// Whether the application is currently producing output.
+    is_playing: bool,
+}
+
+impl AppAudio {
+    const fn new(pid: i32, state: AppAudioState) -> Self {
+        Self {
+            pid,
+            volume: state.volume,
+            muted: state.muted,
+            is_playing: state.is_playing,
+        }
+    }
+}
+
+/// List all output devices, flagging the current default.
+#[tauri::command]
+pub fn get_output_devices() -> Vec<OutputDevice> {
+    let default_id = audio::get_default_output_device().map(|device| device.id);
+    build_output_devices(audio::get_output_devices(), default_id)
+}
+
+/// Switch the system default output device.
+///
+/// Returns the updated device entry.
+///
+/// # Errors
+///
+/// Returns an error if the device is no longer connected or `CoreAudio`
+/// rejects the change.
+#[tauri::command]
+pub fn set_output_device(device_id: AudioDeviceID) -> Result<OutputDevice, StacheError> {
+    // The device may have been disconnected since the list was fetched
+    let device = find_output_device(audio::get_output_devices(), device_id)?;
+
+    if !audio::set_default_output_device(device.id) {
+        return Err(StacheError::AudioError(format!(
+            "Failed to switch output to '{}' (device {device_id}); it may have been disconnected",
+            device.name
+        )));
+    }
+
+    tracing::debug!(device = %device.name, "switched default output device");
+
+    Ok(OutputDevice {
+        id: device.id,
+        name: device.name,
+        is_default: true,
+    })
+}
+
+/// Get the output volume and mute state of an application.
+///
+/// # Errors
+///
+/// Returns an error if per-app audio is unsupported on this macOS version or
+/// the application has no audio process.
+#[tauri::command]
+pub fn get_app_audio(pid: i32) -> Result<AppAudio, StacheError> {
+    process::app_audio(pid)
+        .map(|state| AppAudio::new(pid, state))
+        .map_err(to_stache_error)
+}
+
+/// Set the output volume (0.0 - 1.0) of an application.
+///
+/// # Errors
+///
+/// Returns an error if the volume is out of range, per-app audio is
+/// unsupported on this macOS version, or the volume cannot be applied.
+#[tauri::command]
+pub fn set_app_volume(pid: i32, volume: f32) -> Result<AppAudio, StacheError> {
+    process::set_app_volume(pid, volume)
+        .map(|state| AppAudio::new(pid, state))
+        .map_err(to_stache_error)
+}
+
+/// Mute or unmute an application.
+///
+/// # Errors
+///
+/// Returns an error if per-app audio is unsupported on this macOS version or
+/// the mute state cannot be applied.
+#[tauri::command]
+pub fn set_app_mute(pid: i32, muted: bool) -> Result<AppAudio, StacheError> {
+    process::set_app_mute(pid, muted)
+        .map(|state| AppAudio::new(pid, state))
+        .map_err(to_stache_error)
+}
+
+/// Convert `ProcessAudioError` to `StacheError`.
+#[allow(clippy::needless_pass_by_value)]
+fn to_stache_error(err: ProcessAudioError) -> StacheError {
+    match err {
+        ProcessAudioError::InvalidVolume => StacheError::InvalidArguments(err.to_string()),
+        _ => StacheError::AudioError(err.to_string()),
+    }
+}
+
+/// Builds the picker entries, sorted by name for a stable order.
+fn build_output_devices(
+    devices: Vec<AudioDevice>,
+    default_id: Option<AudioDeviceID>,
+) -> Vec<OutputDevice> {
+    let mut entries: Vec<OutputDevice> = devices
+        .into_iter()
+        .map(|device| OutputDevice {
+            is_default: Some(device.id) == default_id,
+            id: device.id,
+            name: device.name,
+        })
+        .collect();
+
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    entries
+}
+
+/// Finds a connected output device by ID.
+fn find_output_device(
+    devices: Vec<AudioDevice>,
+    device_id: AudioDeviceID,
+) -> Result<AudioDevice, StacheError> {
+    devices.into_iter().find(|device| device.id == device_id).ok_or_else(|| {
+        StacheError::AudioError(format!(
+            "Output device {device_id} is not connected; refresh the device list"
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_devices() -> Vec<AudioDevice> {
+        vec![
+            AudioDevice {
+                id: 3,
+                name: "MacBook Pro Speakers".to_string(),
+            },
+            AudioDevice {
+                id: 7,
+                name: "AirPods Pro".to_string(),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_build_output_devices_flags_default_and_sorts() {
+        let entries = build_output_devices(make_devices(), Some(3));
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].name, "AirPods Pro");
+        assert!(!entries[0].is_default);
+        assert_eq!(entries[1].id, 3);
+        assert!(entries[1].is_default);
+    }
+
+    #[test]
+    fn test_build_output_devices_without_default() {
+        let entries = build_output_devices(make_devices(), None);
+        assert!(entries.iter().all(|entry| !entry.is_default));
+    }
+
+    #[test]
+    fn test_find_output_device_returns_connected_device() {
+        let device = find_output_device(make_devices(), 7).unwrap();
+        assert_eq!(device.name, "AirPods Pro");
+    }
+
+    #[test]
+    fn test_find_output_device_errors_when_disconnected() {
+        let err = find_output_device(make_devices(), 42).unwrap_err();
+        assert!(matches!(err, StacheError::AudioError(_)));
+        assert!(err.to_string().contains("42"));
+    }
+
+    #[test]
+    fn test_output_device_serialization() {
+        let entry = OutputDevice {
+            id: 7,
+            name: "AirPods Pro".to_string(),
+            is_default: true,
+        };
+        let json = serde_json::to_string(&entry).unwrap();
+        assert!(json.contains("\"id\":7"));
+        assert!(json.contains("\"isDefault\":true"));
+    }
+
+    #[test]
+    fn test_app_audio_serialization() {
+        let entry = AppAudio::new(123, AppAudioState {
+            volume: 0.5,
+            muted: false,
+            is_playing: true,
+        });
+        let json = serde_json::to_string(&entry).unwrap();
+        assert!(json.contains("\"pid\":123"));
+        assert!(json.contains("\"volume\":0.5"));
+        assert!(json.contains("\"isPlaying\":true"));
+    }
+
+    #[test]
+    fn test_process_audio_errors_map_to_stache_errors() {
+        assert!(matches!(
+            to_stache_error(ProcessAudioError::InvalidVolume),
+            StacheError::InvalidArguments(_)
+        ));
+
+        let err = to_stache_error(ProcessAudioError::Unsupported);
+        assert!(matches!(err, StacheError::AudioError(_)));
+        assert!(err.to_string().contains("macOS 14.4"));
+    }
+}
@@ -1,7 +1,7 @@
 use std::ffi::c_void;
 use std::sync::{Mutex, MutexGuard, OnceLock};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use core_foundation::base::TCFType;
 use core_foundation::string::CFString;
@@ -9,7 +9,7 @@ use core_foundation_sys::base::{CFRelease, CFTypeRef};
 use core_foundation_sys::dictionary::{CFDictionaryGetValue, CFDictionaryRef};
 use core_foundation_sys::number::{CFBooleanGetValue, CFBooleanRef};
 use keepawake::{Builder, KeepAwake};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use tauri::{Emitter, Manager};
 
 use crate::error::StacheError;
@@ -33,10 +33,39 @@ fn emit_keep_awake_changed(
         .map_err(|err| err.to_string())
 }
 
+/// Which kind of sleep the wake lock prevents.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeepAwakeMode {
+    /// Keep the screen on (`PreventUserIdleDisplaySleep`) and the system running.
+    #[default]
+    Display,
+    /// Keep the system running in the background (`PreventUserIdleSystemSleep`)
+    /// while allowing the display to sleep.
+    System,
+}
+
+/// Keep-awake status reported to the frontend.
+#[derive(Debug, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct KeepAwakeStatus {
+    /// Whether the system is currently being kept awake.
+    awake: bool,
+    /// The active (or last requested) keep-awake mode.
+    mode: KeepAwakeMode,
+    /// Seconds until a timed keep-awake expires, if one is active.
+    remaining_seconds: Option<u64>,
+}
+
 #[derive(Default)]
 struct KeepAwakeState {
     desired_awake: bool,
+    mode: KeepAwakeMode,
     handle: Option<KeepAwake>,
+    /// When the current timed keep-awake expires, if any.
+    expires_at: Option<Instant>,
+    /// Incremented on every toggle so stale expiry timers become no-ops.
+    timer_generation: u64,
 }
 
 #[derive(Default)]
@@ -49,9 +78,9 @@ impl KeepAwakeController {
         self.state.lock().map_err(|err| err.to_string())
     }
 
-    fn acquire_awake_handle() -> Result<KeepAwake, String> {
+    fn acquire_awake_handle(mode: KeepAwakeMode) -> Result<KeepAwake, String> {
         Builder::default()
-            .display(true)
+            .display(mode == KeepAwakeMode::Display)
             .idle(true)
             .sleep(true)
             .reason(KEEP_AWAKE_REASON)
@@ -63,7 +92,7 @@ impl KeepAwakeController {
 
     fn ensure_awake_handle(state: &mut KeepAwakeState) -> Result<(), String> {
         if state.handle.is_none() {
-            state.handle = Some(Self::acquire_awake_handle()?);
+            state.handle = Some(Self::acquire_awake_handle(state.mode)?);
         }
         Ok(())
     }
@@ -75,23 +104,123 @@ impl KeepAwakeController {
         })
     }
 
-    fn toggle_impl(&self) -> Result<bool, String> {
+    /// Toggles the awake state, optionally expiring after `duration`.
+    ///
+    /// Requesting a different `mode` while awake switches the assertion type
+    /// instead of releasing it.
+    ///
+    /// Returns the new awake state and the timer generation to pass to
+    /// [`Self::expire`] once the duration elapses.
+    fn toggle_impl(
+        &self,
+        mode: KeepAwakeMode,
+        duration: Option<Duration>,
+    ) -> Result<(bool, u64), String> {
         self.lock_state().and_then(|mut state| {
-            if state.desired_awake {
+            // Any pending expiry timer belongs to the previous state
+            state.timer_generation = state.timer_generation.wrapping_add(1);
+
+            if state.desired_awake && state.mode == mode {
                 state.desired_awake = false;
                 state.handle = None;
-                Ok(false)
+                state.expires_at = None;
+                Ok((false, state.timer_generation))
             } else {
+                if state.mode != mode {
+                    state.mode = mode;
+                    state.handle = None;
+                }
                 state.desired_awake = true;
+                state.expires_at = duration.map(|duration| Instant::now() + duration);
                 Self::ensure_awake_handle(&mut state)?;
-                Ok(true)
+                Ok((true, state.timer_generation))
             }
         })
     }
 
-    fn is_awake(&self) -> Result<bool, String> {
+    /// Releases any held assertion without changing the desired state.
+    ///
+    /// Called on application exit so no assertion outlives the process.
+    pub fn release(&self) {
+        if let Ok(mut state) = self.lock_state() {
+            state.handle = None;
+            state.expires_at = None;
+            state.timer_generation = state.timer_generation.wrapping_add(1);
+        }
+    }
+
+    /// Releases a timed keep-awake if `generation` is still current.
+    ///
+    /// Returns `None` if the state was toggled since the timer was scheduled.
+    fn expire(&self, generation: u64) -> Result<Option<KeepAwakeChangedPayload>, String> {
+        let mut state = self.lock_state()?;
+        if state.timer_generation != generation || !state.desired_awake {
+            return Ok(None);
+        }
+
+        state.desired_awake = false;
+        state.handle = None;
+        state.expires_at = None;
+
+        Ok(Some(KeepAwakeChangedPayload {
+            locked: false,
+            desired_awake: false,
+        }))
+    }
+
+    /// Releases the wake lock because the user went idle.
+    ///
+    /// With `restore`, the desired state is kept so [`Self::resume_after_idle`]
+    /// can re-acquire it; otherwise keep-awake is turned off.
+    ///
+    /// Returns `None` if no wake lock was held.
+    fn release_for_idle(&self, restore: bool) -> Result<Option<KeepAwakeChangedPayload>, String> {
+        let mut state = self.lock_state()?;
+        if state.handle.is_none() {
+            return Ok(None);
+        }
+
+        state.handle = None;
+        if !restore {
+            state.desired_awake = false;
+            state.expires_at = None;
+            state.timer_generation = state.timer_generation.wrapping_add(1);
+        }
+
+        Ok(Some(KeepAwakeChangedPayload {
+            locked: false,
+            desired_awake: state.desired_awake,
+        }))
+    }
+
+    /// Re-acquires a wake lock released by [`Self::release_for_idle`].
+    ///
+    /// Returns `None` if keep-awake is no longer desired or already held.
+    fn resume_after_idle(&self) -> Result<Option<KeepAwakeChangedPayload>, String> {
+        let mut state = self.lock_state()?;
+        if !state.desired_awake || state.handle.is_some() {
+            return Ok(None);
+        }
+
+        Self::ensure_awake_handle(&mut state)?;
+
+        Ok(Some(KeepAwakeChangedPayload {
+            locked: false,
+            desired_awake: true,
+        }))
+    }
+
+    fn status(&self) -> Result<KeepAwakeStatus, String> {
         let state = self.lock_state()?;
-        Ok(state.handle.is_some())
+        let remaining_seconds = state
+            .expires_at
+            .map(|expires_at| expires_at.saturating_duration_since(Instant::now()).as_secs());
+
+        Ok(KeepAwakeStatus {
+            awake: state.handle.is_some(),
+            mode: state.mode,
+            remaining_seconds,
+        })
     }
 
     fn handle_system_locked_event(&self) -> Result<KeepAwakeChangedPayload, String> {
@@ -119,24 +248,105 @@ impl KeepAwakeController {
 
 /// Toggles the system awake state.
 ///
+/// `mode` selects between keeping the display on (`display`, default) and only
+/// keeping the system running in the background (`system`).
+///
+/// When `duration_minutes` is provided while enabling, the wake lock is
+/// released automatically after that many minutes and a state-changed event is
+/// emitted. Toggling again before expiry cancels the timer.
+///
 /// # Errors
 ///
 /// Returns an error if the awake state cannot be toggled.
 #[tauri::command]
 #[allow(clippy::needless_pass_by_value)]
-pub fn toggle_system_awake(state: tauri::State<KeepAwakeController>) -> Result<bool, StacheError> {
-    state.toggle_impl().map_err(StacheError::CommandError)
+pub fn toggle_system_awake(
+    app: tauri::AppHandle,
+    state: tauri::State<KeepAwakeController>,
+    mode: Option<KeepAwakeMode>,
+    duration_minutes: Option<u64>,
+) -> Result<bool, StacheError> {
+    let duration = duration_minutes
+        .filter(|minutes| *minutes > 0)
+        .map(|minutes| Duration::from_secs(minutes * 60));
+
+    let (awake, generation) = state
+        .toggle_impl(mode.unwrap_or_default(), duration)
+        .map_err(StacheError::CommandError)?;
+
+    if awake && let Some(duration) = duration {
+        schedule_expiry(app, duration, generation);
+    }
+
+    Ok(awake)
 }
 
 /// Checks if the system is currently being kept awake.
 ///
+/// Also reports the remaining time when a timed keep-awake is active.
+///
 /// # Errors
 ///
 /// Returns an error if the awake state cannot be determined.
 #[tauri::command]
 #[allow(clippy::needless_pass_by_value)]
-pub fn is_system_awake(state: tauri::State<KeepAwakeController>) -> Result<bool, StacheError> {
-    state.is_awake().map_err(StacheError::CommandError)
+pub fn is_system_awake(
+    state: tauri::State<KeepAwakeController>,
+) -> Result<KeepAwakeStatus, StacheError> {
+    state.status().map_err(StacheError::CommandError)
+}
+
+/// Releases the wake lock after `duration` unless the state was toggled meanwhile.
+fn schedule_expiry(app_handle: tauri::AppHandle, duration: Duration, generation: u64) {
+    spawn_named_thread("keepawake-timer", move || {
+        thread::sleep(duration);
+
+        match app_handle.state::<KeepAwakeController>().expire(generation) {
+            Ok(Some(payload)) => {
+                tracing::info!("timed keep awake expired");
+                if let Err(err) = emit_keep_awake_changed(&app_handle, payload) {
+                    tracing::warn!(error = %err, "failed to emit keep_awake_changed event");
+                }
+            }
+            Ok(None) => {}
+            Err(err) => tracing::warn!(error = %err, "failed to expire keep awake"),
+        }
+    });
+}
+
+/// Releases the wake lock because the user went idle.
+///
+/// With `restore`, [`resume_after_idle`] re-acquires it on the next input.
+/// Returns whether a wake lock was released.
+pub fn release_for_idle(app_handle: &tauri::AppHandle, restore: bool) -> bool {
+    match app_handle.state::<KeepAwakeController>().release_for_idle(restore) {
+        Ok(Some(payload)) => {
+            tracing::info!("keep awake released while idle");
+            if let Err(err) = emit_keep_awake_changed(app_handle, payload) {
+                tracing::warn!(error = %err, "failed to emit keep_awake_changed event");
+            }
+            true
+        }
+        Ok(None) => false,
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to release keep awake");
+            false
+        }
+    }
+}
+
+/// Re-acquires a wake lock released by [`release_for_idle`], if still desired.
+pub fn resume_after_idle(app_handle: &tauri::AppHandle) {
+    match app_handle.state::<KeepAwakeController>().resume_after_idle() {
+        Ok(Some(payload)) => {
+            tracing::info!("keep awake restored after idle");
+            if let Err(err) = emit_keep_awake_changed(app_handle, payload) {
+                tracing::warn!(error = %err, "failed to emit keep_awake_changed event");
+            }
+        }
+        Ok(None) => {}
+        Err(err) => tracing::warn!(error = %err, "failed to restore keep awake"),
+    }
 }
 
 static LOCK_WATCHER_ONCE: OnceLock<()> = OnceLock::new();
@@ -325,6 +535,18 @@ mod tests {
         assert!(TEST_ONCE.get().is_some());
     }
 
+    #[test]
+    fn test_idle_release_without_wake_lock_is_noop() {
+        let controller = KeepAwakeController::default();
+        controller.lock_state().unwrap().desired_awake = true;
+
+        assert!(controller.release_for_idle(false).unwrap().is_none());
+        assert!(controller.lock_state().unwrap().desired_awake);
+
+        controller.lock_state().unwrap().desired_awake = false;
+        assert!(controller.resume_after_idle().unwrap().is_none());
+    }
+
     #[test]
     fn test_keep_awake_controller_locking() {
         let controller = KeepAwakeController::default();
@@ -381,9 +603,9 @@ mod tests {
     #[test]
     fn test_keep_awake_controller_is_awake_initially_false() {
         let controller = KeepAwakeController::default();
-        let result = controller.is_awake();
+        let result = controller.status();
         assert!(result.is_ok());
-        assert!(!result.unwrap());
+        assert!(!result.unwrap().awake);
     }
 
     #[test]
@@ -411,7 +633,7 @@ mod tests {
 
         // After toggle, desired_awake should be true (but handle may or may not be acquired
         // depending on system state)
-        let result = controller.toggle_impl();
+        let result = controller.toggle_impl(KeepAwakeMode::Display, None);
         assert!(result.is_ok());
 
         {
@@ -504,4 +726,131 @@ mod tests {
         assert!(!SCREEN_LOCKED_KEY.is_empty());
         assert!(SCREEN_LOCKED_KEY.starts_with("CGS"));
     }
+
+    // ========================================================================
+    // Timed keep-awake tests
+    // ========================================================================
+
+    #[test]
+    #[allow(clippy::significant_drop_tightening)]
+    fn test_toggle_with_duration_sets_expiry() {
+        let controller = KeepAwakeController::default();
+
+        let (awake, _) = controller
+            .toggle_impl(KeepAwakeMode::Display, Some(Duration::from_secs(90 * 60)))
+            .unwrap();
+        assert!(awake);
+
+        let state = controller.lock_state().unwrap();
+        assert!(state.expires_at.is_some());
+    }
+
+    #[test]
+    #[allow(clippy::significant_drop_tightening)]
+    fn test_toggle_again_cancels_timer() {
+        let controller = KeepAwakeController::default();
+
+        let (_, generation) = controller
+            .toggle_impl(KeepAwakeMode::Display, Some(Duration::from_secs(60)))
+            .unwrap();
+        let (awake, _) = controller.toggle_impl(KeepAwakeMode::Display, None).unwrap();
+        assert!(!awake);
+
+        // The pending timer must not flip the state anymore
+        assert!(controller.expire(generation).unwrap().is_none());
+
+        let state = controller.lock_state().unwrap();
+        assert!(state.expires_at.is_none());
+    }
+
+    #[test]
+    #[allow(clippy::significant_drop_tightening)]
+    fn test_expire_releases_current_timer() {
+        let controller = KeepAwakeController::default();
+
+        let (_, generation) = controller
+            .toggle_impl(KeepAwakeMode::Display, Some(Duration::from_secs(60)))
+            .unwrap();
+        let payload = controller.expire(generation).unwrap().expect("timer should expire");
+        assert!(!payload.locked);
+        assert!(!payload.desired_awake);
+
+        let state = controller.lock_state().unwrap();
+        assert!(!state.desired_awake);
+        assert!(state.handle.is_none());
+        assert!(state.expires_at.is_none());
+    }
+
+    #[test]
+    fn test_status_reports_remaining_time() {
+        let controller = KeepAwakeController::default();
+        let status = controller.status().unwrap();
+        assert!(status.remaining_seconds.is_none());
+
+        let _ = controller
+            .toggle_impl(KeepAwakeMode::Display, Some(Duration::from_secs(90 * 60)))
+            .unwrap();
+        let status = controller.status().unwrap();
+        let remaining = status.remaining_seconds.expect("timed keep awake should report time");
+        assert!(remaining > 89 * 60 && remaining <= 90 * 60);
+    }
+
+    #[test]
+    fn test_keep_awake_status_serialization() {
+        let status = KeepAwakeStatus {
+            awake: true,
+            mode: KeepAwakeMode::System,
+            remaining_seconds: Some(60),
+        };
+        let json = serde_json::to_string(&status).unwrap();
+        assert!(json.contains("\"awake\":true"));
+        assert!(json.contains("\"mode\":\"system\""));
+        assert!(json.contains("\"remainingSeconds\":60"));
+    }
+
+    // ========================================================================
+    // Keep-awake mode tests
+    // ========================================================================
+
+    #[test]
+    fn test_keep_awake_mode_default_is_display() {
+        assert_eq!(KeepAwakeMode::default(), KeepAwakeMode::Display);
+    }
+
+    #[test]
+    fn test_keep_awake_mode_deserialization() {
+        let mode: KeepAwakeMode = serde_json::from_str("\"system\"").unwrap();
+        assert_eq!(mode, KeepAwakeMode::System);
+        let mode: KeepAwakeMode = serde_json::from_str("\"display\"").unwrap();
+        assert_eq!(mode, KeepAwakeMode::Display);
+    }
+
+    #[test]
+    #[allow(clippy::significant_drop_tightening)]
+    fn test_toggle_with_other_mode_switches_instead_of_releasing() {
+        let controller = KeepAwakeController::default();
+
+        let (awake, _) = controller.toggle_impl(KeepAwakeMode::Display, None).unwrap();
+        assert!(awake);
+
+        let (awake, _) = controller.toggle_impl(KeepAwakeMode::System, None).unwrap();
+        assert!(awake);
+        assert_eq!(controller.status().unwrap().mode, KeepAwakeMode::System);
+
+        let (awake, _) = controller.toggle_impl(KeepAwakeMode::System, None).unwrap();
+        assert!(!awake);
+    }
+
+    #[test]
+    #[allow(clippy::significant_drop_tightening)]
+    fn test_release_drops_assertion() {
+        let controller = KeepAwakeController::default();
+        let _ = controller.toggle_impl(KeepAwakeMode::System, None).unwrap();
+
+        controller.release();
+
+        let state = controller.lock_state().unwrap();
+        assert!(state.handle.is_none());
+        assert!(state.expires_at.is_none());
+    }
 }
@@ -1,8 +1,10 @@
 //! Media playback component.
 //!
 //! Monitors currently playing media using the bundled `media-control` sidecar.
-//! Streams media metadata changes and processes artwork for display in the frontend.
-//! Artwork is resized to 128x128, cached to disk, and sent as base64-encoded PNG data.
+//! Streams media metadata changes, processes artwork for display in the frontend,
+//! and forwards playback controls (play/pause, next, previous) to the sidecar.
+//! Artwork is downscaled to the configured size (128x128 by default), cached to disk,
+//! and sent as base64-encoded PNG data.
 
 #![allow(unexpected_cfgs)]
 
@@ -12,41 +14,62 @@ use std::hash::{Hash, Hasher};
 use std::io::{self, Read, Write};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Mutex, OnceLock, PoisonError};
+use std::time::{Duration, Instant};
 
 use base64::Engine as _;
 use base64::engine::general_purpose::STANDARD;
 use image::ImageFormat;
+use serde::Serialize;
 use serde_json::{Map, Value};
 use tauri::{AppHandle, Emitter, Manager, WebviewWindow};
 use tauri_plugin_shell::ShellExt;
 use tauri_plugin_shell::process::CommandEvent;
 
 use crate::cache::get_cache_subdir_str;
+use crate::config::get_config;
+use crate::error::StacheError;
 use crate::events;
 use crate::platform::thread::spawn_named_thread;
 
-/// Resize the provided image to 128x128 and encode it as PNG.
-///
-/// # Errors
-/// Returns an IO error if image processing fails.
-fn resize_artwork(img: &image::DynamicImage) -> io::Result<(Vec<u8>, String)> {
-    static PNG_BUFFER: OnceLock<std::sync::Mutex<Vec<u8>>> = OnceLock::new();
+/// Smallest artwork size accepted from the configuration.
+const MIN_ARTWORK_SIZE: u32 = 16;
+/// Largest artwork size accepted from the configuration.
+const MAX_ARTWORK_SIZE: u32 = 1024;
 
-    const SIZE: u32 = 128;
+/// Returns the configured artwork size, clamped to a sane range.
+fn artwork_size() -> u32 {
+    get_config().bar.media.artwork_size.clamp(MIN_ARTWORK_SIZE, MAX_ARTWORK_SIZE)
+}
 
+/// Center-crops the provided image to a square and downscales it to at most `size`.
+///
+/// Artwork smaller than `size` keeps its own size rather than being upscaled.
+fn square_artwork(img: &image::DynamicImage, size: u32) -> image::DynamicImage {
     // Center-crop to square if the image is not square
     let (width, height) = (img.width(), img.height());
+    let min_dim = width.min(height);
     let cropped = if width == height {
         img.clone()
     } else {
-        let min_dim = width.min(height);
         let x_offset = (width - min_dim) / 2;
         let y_offset = (height - min_dim) / 2;
         img.crop_imm(x_offset, y_offset, min_dim, min_dim)
     };
 
-    let resized = cropped.resize_exact(SIZE, SIZE, image::imageops::FilterType::Lanczos3);
-    let rgba = resized.to_rgba8();
+    if min_dim <= size {
+        return cropped;
+    }
+    cropped.resize_exact(size, size, image::imageops::FilterType::Lanczos3)
+}
+
+/// Resize the provided image to at most a `size`x`size` square and encode it as PNG.
+///
+/// # Errors
+/// Returns an IO error if image processing fails.
+fn resize_artwork(img: &image::DynamicImage, size: u32) -> io::Result<(Vec<u8>, String)> {
+    static PNG_BUFFER: OnceLock<std::sync::Mutex<Vec<u8>>> = OnceLock::new();
+
+    let rgba = square_artwork(img, size).to_rgba8();
 
     let buffer = PNG_BUFFER.get_or_init(|| std::sync::Mutex::new(Vec::with_capacity(4096)));
     let mut buffer = buffer.lock().unwrap_or_else(PoisonError::into_inner);
@@ -90,6 +113,9 @@ static UNKNOWN: &str = "unknown";
 
 static LAST_MEDIA_PAYLOAD: OnceLock<Mutex<Value>> = OnceLock::new();
 static LAST_STATE_HASH: AtomicU64 = AtomicU64::new(0);
+static LAST_MEDIA_UPDATED_AT: Mutex<Option<Instant>> = Mutex::new(None);
+/// Key of the last processed artwork (raw data and size) and its encoded result.
+static LAST_ARTWORK: Mutex<Option<(u64, String)>> = Mutex::new(None);
 
 fn get_cache_path(state: &Map<String, Value>, extension: &str) -> String {
     let cache_dir = get_cache_dir();
@@ -163,11 +189,23 @@ fn save_artwork(state: &Map<String, Value>) -> io::Result<Option<String>> {
         return Ok(None);
     };
 
-    let path = get_cache_path(state, "txt");
+    // Include the size in the cache key so a config change doesn't reuse stale artwork
+    let size = artwork_size();
+
+    // The stream repeats the artwork with every update; reuse it while unchanged
+    let artwork_key = artwork_key(art, size);
+    if let Some((key, cached)) = &*LAST_ARTWORK.lock().unwrap_or_else(PoisonError::into_inner)
+        && *key == artwork_key
+    {
+        return Ok(Some(cached.clone()));
+    }
+
+    let path = get_cache_path(state, &format!("{size}.txt"));
 
     if let Ok(mut existing) = File::open(&path) {
         let mut cached = String::new();
         if existing.read_to_string(&mut cached).is_ok() && !cached.is_empty() {
+            remember_artwork(artwork_key, &cached);
             return Ok(Some(cached));
         }
     }
@@ -186,18 +224,33 @@ fn save_artwork(state: &Map<String, Value>) -> io::Result<Option<String>> {
     };
     drop(buffer);
 
-    let (enc_bytes, _ext) = resize_artwork(&img)?;
+    let (enc_bytes, _ext) = resize_artwork(&img, size)?;
     let base64_encoded = STANDARD.encode(enc_bytes);
 
     CACHE_DIR_CREATED.get_or_init(|| {
         let _ = create_dir_all(get_cache_dir());
     });
 
+    remember_artwork(artwork_key, &base64_encoded);
+
     let mut out = File::create(&path)?;
     out.write_all(base64_encoded.as_bytes())?;
     Ok(Some(base64_encoded))
 }
 
+/// Returns the key identifying raw artwork data processed at `size`.
+fn artwork_key(art: &str, size: u32) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    art.hash(&mut hasher);
+    size.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Remembers the encoded artwork for the given key.
+fn remember_artwork(key: u64, encoded: &str) {
+    *LAST_ARTWORK.lock().unwrap_or_else(PoisonError::into_inner) = Some((key, encoded.to_string()));
+}
+
 fn calculate_state_hash(state: &Map<String, Value>) -> u64 {
     let mut hasher = DefaultHasher::new();
     state.hash(&mut hasher);
@@ -207,9 +260,19 @@ fn calculate_state_hash(state: &Map<String, Value>) -> u64 {
 fn set_last_media_payload(payload: Option<Value>) {
     let storage = LAST_MEDIA_PAYLOAD.get_or_init(|| Mutex::new(Value::Null));
     let mut guard = storage.lock().unwrap_or_else(PoisonError::into_inner);
+    *LAST_MEDIA_UPDATED_AT.lock().unwrap_or_else(PoisonError::into_inner) =
+        payload.is_some().then(Instant::now);
     *guard = payload.unwrap_or(Value::Null);
 }
 
+/// Returns the time elapsed since the last payload was stored.
+fn time_since_last_update() -> Duration {
+    LAST_MEDIA_UPDATED_AT
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .map_or(Duration::ZERO, |updated_at| updated_at.elapsed())
+}
+
 fn get_last_media_payload() -> Option<Value> {
     let storage = LAST_MEDIA_PAYLOAD.get_or_init(|| Mutex::new(Value::Null));
     let guard = storage.lock().unwrap_or_else(PoisonError::into_inner);
@@ -220,9 +283,209 @@ fn get_last_media_payload() -> Option<Value> {
     }
 }
 
+/// Returns the current media info.
+///
+/// The payload always contains `elapsed` and `duration` (in seconds, `null` when
+/// the source doesn't report them). When `include_artwork` is `true` (default),
+/// `artwork` holds the base64-encoded PNG or `null` if the source has none;
+/// passing `false` omits the field for callers that only need text metadata.
 #[tauri::command]
 #[must_use]
-pub fn get_current_media_info() -> Option<Value> { get_last_media_payload() }
+pub fn get_current_media_info(include_artwork: Option<bool>) -> Option<Value> {
+    let payload = get_last_media_payload()?;
+    Some(build_media_info(
+        payload,
+        include_artwork.unwrap_or(true),
+        time_since_last_update(),
+    ))
+}
+
+/// Adds artwork and progress fields to a stored media payload.
+///
+/// `since_update` is the time since the payload was received; while playing,
+/// it is added to the reported elapsed time (scaled by the playback rate).
+fn build_media_info(payload: Value, include_artwork: bool, since_update: Duration) -> Value {
+    let Value::Object(mut state) = payload else {
+        return payload;
+    };
+
+    if include_artwork {
+        state.entry("artwork").or_insert(Value::Null);
+    } else {
+        state.remove("artwork");
+    }
+
+    let duration = state.get("duration").and_then(Value::as_f64);
+    let elapsed = state.get("elapsedTime").and_then(Value::as_f64).map(|elapsed| {
+        let playing = state.get("playing").and_then(Value::as_bool).unwrap_or(false);
+        let rate = state.get("playbackRate").and_then(Value::as_f64).unwrap_or(1.0);
+        let current = if playing {
+            since_update.as_secs_f64().mul_add(rate, elapsed)
+        } else {
+            elapsed
+        };
+        duration.map_or(current, |duration| current.min(duration)).max(0.0)
+    });
+
+    state.insert("elapsed".to_string(), elapsed.map_or(Value::Null, Value::from));
+    state.insert("duration".to_string(), duration.map_or(Value::Null, Value::from));
+
+    Value::Object(state)
+}
+
+/// Outcome of a playback control command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MediaControlStatus {
+    /// The command was sent to the now-playing source.
+    Sent,
+    /// No media source is active, so the command was not sent.
+    NothingPlaying,
+}
+
+/// Result of a playback control command.
+#[derive(Debug, Clone, Serialize)]
+pub struct MediaControlResult {
+    /// Whether the command was sent.
+    pub status: MediaControlStatus,
+    /// Media info with the command's expected effect, if any source is active.
+    pub media: Option<Value>,
+}
+
+/// Toggles play/pause on the now-playing source.
+///
+/// # Errors
+///
+/// Returns an error if the `media-control` sidecar cannot be executed.
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)]
+pub async fn media_play_pause(app: AppHandle) -> Result<MediaControlResult, StacheError> {
+    send_media_command(&app, "toggle-play-pause").await
+}
+
+/// Skips to the next track on the now-playing source.
+///
+/// # Errors
+///
+/// Returns an error if the `media-control` sidecar cannot be executed.
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)]
+pub async fn media_next(app: AppHandle) -> Result<MediaControlResult, StacheError> {
+    send_media_command(&app, "next-track").await
+}
+
+/// Goes back to the previous track on the now-playing source.
+///
+/// # Errors
+///
+/// Returns an error if the `media-control` sidecar cannot be executed.
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)]
+pub async fn media_previous(app: AppHandle) -> Result<MediaControlResult, StacheError> {
+    send_media_command(&app, "previous-track").await
+}
+
+/// Sends a playback command and returns the updated media info.
+///
+/// When nothing is playing the command is skipped so the source is not
+/// accidentally started (e.g. launching Music.app on play/pause). The state
+/// read for that check is reused for the result; the stream reports the rest
+/// of the change (e.g. the new track) shortly after.
+async fn send_media_command(
+    app: &AppHandle,
+    command: &str,
+) -> Result<MediaControlResult, StacheError> {
+    let Some(mut fresh) = fetch_now_playing(app).await? else {
+        return Ok(MediaControlResult {
+            status: MediaControlStatus::NothingPlaying,
+            media: None,
+        });
+    };
+
+    run_media_control(app, &[command]).await?;
+
+    if command == "toggle-play-pause" {
+        toggle_playing(&mut fresh);
+    }
+    let payload = merge_media_payload(get_last_media_payload(), fresh);
+    set_last_media_payload(Some(payload.clone()));
+
+    Ok(MediaControlResult {
+        status: MediaControlStatus::Sent,
+        media: Some(payload),
+    })
+}
+
+/// Flips the `playing` flag of a now-playing state.
+fn toggle_playing(state: &mut Map<String, Value>) {
+    let playing = state.get("playing").and_then(Value::as_bool).unwrap_or(false);
+    state.insert("playing".to_string(), Value::Bool(!playing));
+}
+
+/// Reads the current now-playing state without artwork.
+///
+/// Returns `None` when no media source is active.
+async fn fetch_now_playing(app: &AppHandle) -> Result<Option<Map<String, Value>>, StacheError> {
+    let output = run_media_control(app, &["get"]).await?;
+    Ok(parse_now_playing(&output))
+}
+
+/// Parses the output of `media-control get`, dropping the raw artwork fields.
+fn parse_now_playing(output: &str) -> Option<Map<String, Value>> {
+    let Value::Object(mut state) = parse_json(output.trim())? else {
+        return None;
+    };
+
+    state.remove("artworkMimeType");
+    state.remove("artworkData");
+
+    (!state.is_empty()).then_some(state)
+}
+
+/// Overlays freshly read state onto the last emitted payload.
+///
+/// The processed artwork is kept only while the track is unchanged; the stream
+/// delivers new artwork shortly after a track change.
+fn merge_media_payload(last: Option<Value>, fresh: Map<String, Value>) -> Value {
+    let mut merged = match last {
+        Some(Value::Object(map)) => map,
+        _ => Map::new(),
+    };
+
+    let same_track = ["title", "artist"].iter().all(|key| merged.get(*key) == fresh.get(*key));
+    if !same_track {
+        merged.remove("artwork");
+    }
+
+    merged.extend(fresh);
+    Value::Object(merged)
+}
+
+/// Runs the `media-control` sidecar and returns its stdout.
+async fn run_media_control(app: &AppHandle, args: &[&str]) -> Result<String, StacheError> {
+    let sidecar = app
+        .shell()
+        .sidecar("media-control")
+        .map_err(|err| StacheError::ShellError(format!("Failed to create media-control: {err}")))?;
+
+    let output =
+        sidecar.args(args).output().await.map_err(|err| {
+            StacheError::ShellError(format!("Failed to run media-control: {err}"))
+        })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(StacheError::ShellError(format!(
+            "media-control exited with status {:?}: {}",
+            output.status.code(),
+            stderr.trim()
+        )));
+    }
+
+    String::from_utf8(output.stdout).map_err(|err| {
+        StacheError::ShellError(format!("media-control returned invalid UTF-8: {err}"))
+    })
+}
 
 fn save_artwork_and_emit(
     state: &mut Map<String, Value>,
@@ -361,13 +624,16 @@ pub fn init(window: &WebviewWindow) {
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use image::ImageFormat;
     use serde_json::{Map, Value, json};
 
     use super::{
-        UNKNOWN, calculate_state_hash, cleanup_string_for_filename, get_cache_dir, get_cache_path,
-        get_current_media_info, image_format_from_mime, parse_json, parse_output,
-        set_last_media_payload,
+        UNKNOWN, artwork_key, build_media_info, calculate_state_hash, cleanup_string_for_filename,
+        get_cache_dir, get_cache_path, get_current_media_info, image_format_from_mime,
+        merge_media_payload, parse_json, parse_now_playing, parse_output, set_last_media_payload,
+        square_artwork, toggle_playing,
     };
 
     #[test]
@@ -555,7 +821,7 @@ mod tests {
     #[test]
     fn test_get_current_media_info_none_when_unset() {
         set_last_media_payload(None);
-        assert!(get_current_media_info().is_none());
+        assert!(get_current_media_info(None).is_none());
     }
 
     #[test]
@@ -570,8 +836,12 @@ mod tests {
 
         set_last_media_payload(Some(payload.clone()));
 
-        let result = get_current_media_info().expect("payload should be returned");
-        assert_eq!(result, payload);
+        let result = get_current_media_info(None).expect("payload should be returned");
+        let mut expected = payload;
+        expected["artwork"] = Value::Null;
+        expected["elapsed"] = Value::Null;
+        expected["duration"] = Value::Null;
+        assert_eq!(result, expected);
 
         set_last_media_payload(None);
     }
@@ -842,7 +1112,7 @@ mod tests {
 
         set_last_media_payload(Some(payload));
 
-        let result = get_current_media_info().unwrap();
+        let result = get_current_media_info(None).unwrap();
         assert_eq!(result["artist"], "Complex Artist");
         assert_eq!(result["duration"], 240.5);
 
@@ -859,8 +1129,8 @@ mod tests {
         set_last_media_payload(Some(payload1));
         set_last_media_payload(Some(payload2.clone()));
 
-        let result = get_current_media_info().unwrap();
-        assert_eq!(result, payload2);
+        let result = get_current_media_info(None).unwrap();
+        assert_eq!(result["artist"], payload2["artist"]);
 
         set_last_media_payload(None);
     }
@@ -882,4 +1152,144 @@ mod tests {
         // Should end with path separator for easy concatenation
         assert!(dir.ends_with('/') || dir.ends_with('\\'));
     }
+
+    // ========================================================================
+    // Playback control tests
+    // ========================================================================
+
+    #[test]
+    fn test_parse_now_playing_null_is_none() {
+        assert!(parse_now_playing("null").is_none());
+        assert!(parse_now_playing("{}").is_none());
+        assert!(parse_now_playing("").is_none());
+    }
+
+    #[test]
+    fn test_parse_now_playing_strips_raw_artwork() {
+        let output = json!({
+            "title": "Song",
+            "playing": false,
+            "artworkData": "abc",
+            "artworkMimeType": "image/png",
+        })
+        .to_string();
+
+        let state = parse_now_playing(&output).expect("state should be parsed");
+        assert_eq!(state.get("playing"), Some(&Value::Bool(false)));
+        assert!(!state.contains_key("artworkData"));
+        assert!(!state.contains_key("artworkMimeType"));
+    }
+
+    #[test]
+    fn test_merge_media_payload_reflects_play_state() {
+        let last = json!({"title": "Song", "artist": "Artist", "playing": true, "artwork": "png"});
+        let fresh = json!({"title": "Song", "artist": "Artist", "playing": false});
+
+        let merged = merge_media_payload(Some(last), fresh.as_object().unwrap().clone());
+        assert_eq!(merged["playing"], false);
+        assert_eq!(merged["artwork"], "png");
+    }
+
+    #[test]
+    fn test_merge_media_payload_drops_artwork_on_track_change() {
+        let last = json!({"title": "Old", "artist": "Artist", "artwork": "png"});
+        let fresh = json!({"title": "New", "artist": "Artist", "playing": true});
+
+        let merged = merge_media_payload(Some(last), fresh.as_object().unwrap().clone());
+        assert_eq!(merged["title"], "New");
+        assert!(merged.get("artwork").is_none());
+    }
+
+    #[test]
+    fn test_merge_media_payload_without_last() {
+        let fresh = json!({"title": "Song"});
+
+        let merged = merge_media_payload(None, fresh.as_object().unwrap().clone());
+        assert_eq!(merged, fresh);
+    }
+
+    // ========================================================================
+    // build_media_info tests
+    // ========================================================================
+
+    #[test]
+    fn test_toggle_playing() {
+        let mut state = Map::new();
+        state.insert("playing".to_string(), Value::Bool(true));
+        toggle_playing(&mut state);
+        assert_eq!(state["playing"], false);
+        toggle_playing(&mut state);
+        assert_eq!(state["playing"], true);
+    }
+
+    #[test]
+    fn test_square_artwork_downscales_and_crops() {
+        let img = image::DynamicImage::new_rgba8(400, 300);
+        let squared = square_artwork(&img, 128);
+        assert_eq!((squared.width(), squared.height()), (128, 128));
+    }
+
+    #[test]
+    fn test_square_artwork_does_not_upscale() {
+        let img = image::DynamicImage::new_rgba8(64, 80);
+        let squared = square_artwork(&img, 128);
+        assert_eq!((squared.width(), squared.height()), (64, 64));
+    }
+
+    #[test]
+    fn test_artwork_key_depends_on_data_and_size() {
+        assert_eq!(artwork_key("abc", 128), artwork_key("abc", 128));
+        assert_ne!(artwork_key("abc", 128), artwork_key("abd", 128));
+        assert_ne!(artwork_key("abc", 128), artwork_key("abc", 256));
+    }
+
+    #[test]
+    fn test_build_media_info_without_artwork() {
+        let payload = json!({"title": "Song", "artwork": "base64data"});
+
+        let result = build_media_info(payload, false, Duration::ZERO);
+        assert!(result.get("artwork").is_none());
+    }
+
+    #[test]
+    fn test_build_media_info_missing_artwork_is_null() {
+        let payload = json!({"title": "Song"});
+
+        let result = build_media_info(payload, true, Duration::ZERO);
+        assert_eq!(result["artwork"], Value::Null);
+    }
+
+    #[test]
+    fn test_build_media_info_progress_while_playing() {
+        let payload = json!({"playing": true, "elapsedTime": 10.0, "duration": 200.0});
+
+        let result = build_media_info(payload, true, Duration::from_secs(5));
+        assert_eq!(result["elapsed"], 15.0);
+        assert_eq!(result["duration"], 200.0);
+    }
+
+    #[test]
+    fn test_build_media_info_progress_while_paused() {
+        let payload = json!({"playing": false, "elapsedTime": 10.0, "duration": 200.0});
+
+        let result = build_media_info(payload, true, Duration::from_secs(5));
+        assert_eq!(result["elapsed"], 10.0);
+    }
+
+    #[test]
+    fn test_build_media_info_progress_clamped_to_duration() {
+        let payload = json!({"playing": true, "elapsedTime": 195.0, "duration": 200.0});
+
+        let result = build_media_info(payload, true, Duration::from_secs(30));
+        assert_eq!(result["elapsed"], 200.0);
+    }
+
+    #[test]
+    fn test_build_media_info_progress_unknown() {
+        let payload = json!({"title": "Live Stream"});
+
+        let result = build_media_info(payload, true, Duration::ZERO);
+        assert_eq!(result["elapsed"], Value::Null);
+        assert_eq!(result["duration"], Value::Null);
+    }
 }
@@ -0,0 +1,326 @@
+//! Memory monitoring component.
+//!
+//! Provides synchronous helpers that read memory metrics on demand using the
+//! Mach `host_statistics64` API and `sysctl`, mirroring the breakdown shown by
+//! Activity Monitor.
+
+use std::ffi::{CStr, c_void};
+
+use serde::Serialize;
+
+use crate::error::StacheError;
+
+// ============================================================================
+// FFI
+// ============================================================================
+
+#[link(name = "System", kind = "dylib")]
+unsafe extern "C" {
+    fn mach_host_self() -> u32;
+    fn host_page_size(host: u32, page_size: *mut usize) -> i32;
+    fn host_statistics64(host: u32, flavor: i32, info: *mut i32, count: *mut u32) -> i32;
+    fn sysctlbyname(
+        name: *const i8,
+        oldp: *mut c_void,
+        oldlenp: *mut usize,
+        newp: *mut c_void,
+        newlen: usize,
+    ) -> i32;
+}
+
+const KERN_SUCCESS: i32 = 0;
+const HOST_VM_INFO64: i32 = 4;
+
+/// Mirror of the Mach `vm_statistics64` structure.
+#[repr(C, align(8))]
+#[derive(Debug, Default)]
+#[allow(dead_code)] // Fields mirror the C layout and are not all read
+struct VmStatistics64 {
+    free_count: u32,
+    active_count: u32,
+    inactive_count: u32,
+    wire_count: u32,
+    zero_fill_count: u64,
+    reactivations: u64,
+    pageins: u64,
+    pageouts: u64,
+    faults: u64,
+    cow_faults: u64,
+    lookups: u64,
+    hits: u64,
+    purges: u64,
+    purgeable_count: u32,
+    speculative_count: u32,
+    decompressions: u64,
+    compressions: u64,
+    swapins: u64,
+    swapouts: u64,
+    compressor_page_count: u32,
+    throttled_count: u32,
+    external_page_count: u32,
+    internal_page_count: u32,
+    total_uncompressed_pages_in_compressor: u64,
+}
+
+/// Mirror of the `xsw_usage` structure returned by the `vm.swapusage` sysctl.
+#[repr(C)]
+#[derive(Debug, Default)]
+#[allow(dead_code)] // Fields mirror the C layout and are not all read
+struct XswUsage {
+    total: u64,
+    avail: u64,
+    used: u64,
+    page_size: u32,
+    encrypted: i32,
+}
+
+// ============================================================================
+// Payload
+// ============================================================================
+
+/// Memory pressure level reported by the kernel.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MemoryPressure {
+    /// The system has enough free memory.
+    #[default]
+    Normal,
+    /// The system is compressing memory and may start swapping.
+    Warning,
+    /// The system is under heavy memory pressure.
+    Critical,
+}
+
+impl MemoryPressure {
+    /// Converts a `kern.memorystatus_vm_pressure_level` value into a pressure level.
+    const fn from_level(level: i32) -> Self {
+        match level {
+            2 => Self::Warning,
+            4 => Self::Critical,
+            _ => Self::Normal,
+        }
+    }
+}
+
+/// Swap usage in bytes.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SwapInfo {
+    /// Total swap space in bytes.
+    total: u64,
+    /// Used swap space in bytes.
+    used: u64,
+}
+
+/// Memory metrics payload. All sizes are in bytes.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryInfo {
+    /// Physical memory installed.
+    total: u64,
+    /// Memory in use (app + wired + compressed).
+    used: u64,
+    /// Memory used by applications.
+    app: u64,
+    /// Memory that cannot be paged out.
+    wired: u64,
+    /// Memory held by the compressor.
+    compressed: u64,
+    /// Swap usage.
+    swap: SwapInfo,
+    /// Current memory pressure level.
+    pressure: MemoryPressure,
+}
+
+/// Fetch current memory metrics on demand.
+///
+/// # Errors
+///
+/// Returns an error if the VM statistics cannot be read from the kernel.
+#[tauri::command]
+pub fn get_memory_info() -> Result<MemoryInfo, StacheError> {
+    let total = read_sysctl::<u64>(c"hw.memsize").unwrap_or_default();
+    let page_size = get_page_size();
+    let stats = get_vm_statistics()?;
+    let swap = read_sysctl::<XswUsage>(c"vm.swapusage").unwrap_or_default();
+    let pressure = read_sysctl::<i32>(c"kern.memorystatus_vm_pressure_level")
+        .map_or(MemoryPressure::Normal, MemoryPressure::from_level);
+
+    Ok(build_memory_info(total, page_size, &stats, &swap, pressure))
+}
+
+/// Builds the memory payload from raw kernel statistics.
+///
+/// App memory follows the Activity Monitor definition: anonymous (internal)
+/// pages minus purgeable pages.
+fn build_memory_info(
+    total: u64,
+    page_size: u64,
+    stats: &VmStatistics64,
+    swap: &XswUsage,
+    pressure: MemoryPressure,
+) -> MemoryInfo {
+    let pages = |count: u32| u64::from(count) * page_size;
+
+    let app = pages(stats.internal_page_count.saturating_sub(stats.purgeable_count));
+    let wired = pages(stats.wire_count);
+    let compressed = pages(stats.compressor_page_count);
+    let used = app + wired + compressed;
+
+    MemoryInfo {
+        total,
+        used: if total > 0 { used.min(total) } else { used },
+        app,
+        wired,
+        compressed,
+        swap: SwapInfo {
+            total: swap.total,
+            used: swap.used,
+        },
+        pressure,
+    }
+}
+
+/// Get the VM page size of the host, falling back to 16 KB (Apple Silicon).
+fn get_page_size() -> u64 {
+    let mut page_size: usize = 0;
+    let result = unsafe { host_page_size(mach_host_self(), &raw mut page_size) };
+
+    if result == KERN_SUCCESS && page_size > 0 {
+        page_size as u64
+    } else {
+        16 * 1024
+    }
+}
+
+/// Read the 64-bit VM statistics for the host.
+fn get_vm_statistics() -> Result<VmStatistics64, StacheError> {
+    let mut stats = VmStatistics64::default();
+    #[allow(clippy::cast_possible_truncation)]
+    let mut count = (std::mem::size_of::<VmStatistics64>() / std::mem::size_of::<i32>()) as u32;
+
+    let result = unsafe {
+        host_statistics64(
+            mach_host_self(),
+            HOST_VM_INFO64,
+            (&raw mut stats).cast::<i32>(),
+            &raw mut count,
+        )
+    };
+
+    if result == KERN_SUCCESS {
+        Ok(stats)
+    } else {
+        Err(StacheError::CommandError(format!(
+            "host_statistics64 failed with code {result}"
+        )))
+    }
+}
+
+/// Read a fixed-size value from `sysctlbyname`.
+fn read_sysctl<T: Default>(name: &CStr) -> Option<T> {
+    let mut value = T::default();
+    let mut size = std::mem::size_of::<T>();
+
+    let result = unsafe {
+        sysctlbyname(
+            name.as_ptr(),
+            (&raw mut value).cast::<c_void>(),
+            &raw mut size,
+            std::ptr::null_mut(),
+            0,
+        )
+    };
+
+    (result == 0 && size == std::mem::size_of::<T>()).then_some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: u64 = 16 * 1024;
+
+    fn make_stats() -> VmStatistics64 {
+        VmStatistics64 {
+            wire_count: 100,
+            internal_page_count: 500,
+            purgeable_count: 50,
+            compressor_page_count: 25,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_vm_statistics_layout_matches_mach() {
+        // HOST_VM_INFO64_COUNT is 38 natural_t words
+        assert_eq!(std::mem::size_of::<VmStatistics64>(), 152);
+    }
+
+    #[test]
+    fn test_xsw_usage_layout_matches_sysctl() {
+        assert_eq!(std::mem::size_of::<XswUsage>(), 32);
+    }
+
+    #[test]
+    fn test_memory_pressure_from_level() {
+        assert_eq!(MemoryPressure::from_level(1), MemoryPressure::Normal);
+        assert_eq!(MemoryPressure::from_level(2), MemoryPressure::Warning);
+        assert_eq!(MemoryPressure::from_level(4), MemoryPressure::Critical);
+        assert_eq!(MemoryPressure::from_level(0), MemoryPressure::Normal);
+    }
+
+    #[test]
+    fn test_build_memory_info_breakdown() {
+        let swap = XswUsage {
+            total: 2048,
+            used: 1024,
+            ..Default::default()
+        };
+        let info =
+            build_memory_info(PAGE * 1000, PAGE, &make_stats(), &swap, MemoryPressure::Warning);
+
+        assert_eq!(info.total, PAGE * 1000);
+        assert_eq!(info.app, PAGE * 450);
+        assert_eq!(info.wired, PAGE * 100);
+        assert_eq!(info.compressed, PAGE * 25);
+        assert_eq!(info.used, PAGE * 575);
+        assert_eq!(info.swap.total, 2048);
+        assert_eq!(info.swap.used, 1024);
+        assert_eq!(info.pressure, MemoryPressure::Warning);
+    }
+
+    #[test]
+    fn test_build_memory_info_purgeable_exceeds_internal() {
+        let stats = VmStatistics64 {
+            internal_page_count: 10,
+            purgeable_count: 20,
+            ..Default::default()
+        };
+        let info = build_memory_info(
+            PAGE * 100,
+            PAGE,
+            &stats,
+            &XswUsage::default(),
+            MemoryPressure::Normal,
+        );
+
+        assert_eq!(info.app, 0);
+    }
+
+    #[test]
+    fn test_memory_info_serialization() {
+        let info = MemoryInfo {
+            pressure: MemoryPressure::Critical,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&info).unwrap();
+        assert!(json.contains("\"pressure\":\"critical\""));
+        assert!(json.contains("\"swap\""));
+        assert!(json.contains("\"compressed\""));
+    }
+
+    #[test]
+    fn test_get_page_size_is_positive() {
+        assert!(get_page_size() > 0);
+    }
+}
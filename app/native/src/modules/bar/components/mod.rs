@@ -1,10 +1,13 @@
 use tauri::WebviewWindow;
 
 pub mod apps;
+pub mod audio;
 pub mod battery;
 pub mod cpu;
 pub mod keepawake;
 pub mod media;
+pub mod memory;
+pub mod network;
 pub mod tiling;
 pub mod weather;
 
@@ -0,0 +1,456 @@
+//! Network throughput component.
+//!
+//! Provides synchronous helpers that sample interface byte counters on demand
+//! using `getifaddrs`. The previous sample is kept in memory so consecutive
+//! calls report a transfer rate instead of cumulative totals.
+
+use std::collections::HashMap;
+use std::ffi::{CStr, c_void};
+use std::sync::{LazyLock, Mutex, PoisonError};
+use std::time::Instant;
+
+use core_foundation::base::TCFType;
+use core_foundation::string::{CFString, CFStringRef};
+use core_foundation_sys::array::{CFArrayGetCount, CFArrayGetValueAtIndex, CFArrayRef};
+use core_foundation_sys::base::CFRelease;
+use core_foundation_sys::dictionary::{CFDictionaryGetValue, CFDictionaryRef};
+use serde::Serialize;
+
+// ============================================================================
+// FFI
+// ============================================================================
+
+#[repr(C)]
+#[allow(dead_code)] // Fields mirror the C layout and are not all read
+struct IfAddrs {
+    next: *mut Self,
+    name: *const i8,
+    flags: u32,
+    addr: *const SockAddr,
+    netmask: *const SockAddr,
+    dstaddr: *const SockAddr,
+    data: *const c_void,
+}
+
+#[repr(C)]
+#[allow(dead_code)] // Fields mirror the C layout and are not all read
+struct SockAddr {
+    len: u8,
+    family: u8,
+    data: [i8; 14],
+}
+
+/// Leading fields of the BSD `if_data` structure, up to the byte counters.
+#[repr(C)]
+#[allow(dead_code)] // Fields mirror the C layout and are not all read
+struct IfData {
+    kind: u8,
+    typelen: u8,
+    physical: u8,
+    addrlen: u8,
+    hdrlen: u8,
+    recvquota: u8,
+    xmitquota: u8,
+    unused1: u8,
+    mtu: u32,
+    metric: u32,
+    baudrate: u32,
+    ipackets: u32,
+    ierrors: u32,
+    opackets: u32,
+    oerrors: u32,
+    collisions: u32,
+    ibytes: u32,
+    obytes: u32,
+}
+
+#[link(name = "System", kind = "dylib")]
+unsafe extern "C" {
+    fn getifaddrs(ifap: *mut *mut IfAddrs) -> i32;
+    fn freeifaddrs(ifa: *mut IfAddrs);
+}
+
+#[link(name = "SystemConfiguration", kind = "framework")]
+unsafe extern "C" {
+    fn SCDynamicStoreCreate(
+        allocator: *const c_void,
+        name: CFStringRef,
+        callout: *const c_void,
+        context: *mut c_void,
+    ) -> *const c_void;
+    fn SCDynamicStoreCopyValue(store: *const c_void, key: CFStringRef) -> CFDictionaryRef;
+    fn SCNetworkInterfaceCopyAll() -> CFArrayRef;
+    fn SCNetworkInterfaceGetBSDName(interface: *const c_void) -> CFStringRef;
+    fn SCNetworkInterfaceGetInterfaceType(interface: *const c_void) -> CFStringRef;
+}
+
+const AF_LINK: u8 = 18;
+const IFF_UP: u32 = 0x1;
+const IFF_LOOPBACK: u32 = 0x8;
+
+/// Interface name prefixes that carry traffic already counted by a physical
+/// interface (VPN tunnels) and are excluded from the aggregate.
+const TUNNEL_PREFIXES: &[&str] = &["utun", "ipsec", "ppp"];
+
+// ============================================================================
+// Payload
+// ============================================================================
+
+/// The kind of the active network interface.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InterfaceKind {
+    /// Wi-Fi (IEEE 802.11).
+    Wifi,
+    /// Wired Ethernet, including USB and Thunderbolt adapters.
+    Ethernet,
+    /// Any other interface type (cellular, bridge, etc.).
+    Other,
+    /// No active interface could be determined.
+    #[default]
+    Unknown,
+}
+
+impl InterfaceKind {
+    /// Converts a `SCNetworkInterfaceType` string into a kind.
+    fn from_type(kind: &str) -> Self {
+        match kind {
+            "IEEE80211" => Self::Wifi,
+            "Ethernet" => Self::Ethernet,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Byte counters of a single interface over the sampling interval.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterfaceStats {
+    /// BSD interface name (e.g. `en0`).
+    name: String,
+    /// Bytes received during the interval.
+    bytes_in: u64,
+    /// Bytes sent during the interval.
+    bytes_out: u64,
+    /// Receive rate in bytes per second.
+    bytes_in_per_sec: u64,
+    /// Send rate in bytes per second.
+    bytes_out_per_sec: u64,
+}
+
+/// Network throughput payload.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkInfo {
+    /// Per-interface statistics, sorted by name.
+    interfaces: Vec<InterfaceStats>,
+    /// Bytes received during the interval across all interfaces.
+    bytes_in: u64,
+    /// Bytes sent during the interval across all interfaces.
+    bytes_out: u64,
+    /// Aggregate receive rate in bytes per second.
+    bytes_in_per_sec: u64,
+    /// Aggregate send rate in bytes per second.
+    bytes_out_per_sec: u64,
+    /// Length of the sampling interval in milliseconds (0 on the first call).
+    interval_ms: u64,
+    /// BSD name of the primary interface, if any.
+    active_interface: Option<String>,
+    /// Kind of the primary interface.
+    active_interface_kind: InterfaceKind,
+}
+
+/// Raw counters captured at a point in time.
+#[derive(Debug, Clone)]
+struct NetworkSample {
+    taken_at: Instant,
+    /// Interface name to `(ibytes, obytes)`.
+    counters: HashMap<String, (u32, u32)>,
+}
+
+/// Previous sample used to compute deltas between calls.
+static PREVIOUS_SAMPLE: LazyLock<Mutex<Option<NetworkSample>>> = LazyLock::new(|| Mutex::new(None));
+
+/// Fetch current network throughput since the previous call.
+#[tauri::command]
+pub fn get_network_info() -> NetworkInfo {
+    let current = NetworkSample {
+        taken_at: Instant::now(),
+        counters: read_interface_counters(),
+    };
+
+    let previous = {
+        let mut guard = PREVIOUS_SAMPLE.lock().unwrap_or_else(PoisonError::into_inner);
+        guard.replace(current.clone())
+    };
+
+    let mut info = compute_network_info(previous.as_ref(), &current);
+    info.active_interface = get_primary_interface();
+    info.active_interface_kind = info
+        .active_interface
+        .as_deref()
+        .map_or(InterfaceKind::Unknown, get_interface_kind);
+
+    info
+}
+
+/// Computes per-interface and aggregate deltas between two samples.
+///
+/// Counters are 32-bit and wrap around, so deltas use wrapping subtraction.
+/// Interfaces missing from the previous sample report zero.
+fn compute_network_info(previous: Option<&NetworkSample>, current: &NetworkSample) -> NetworkInfo {
+    let elapsed_ms = previous.map_or(0, |prev| {
+        u64::try_from(current.taken_at.duration_since(prev.taken_at).as_millis())
+            .unwrap_or(u64::MAX)
+    });
+    let per_sec = |bytes: u64| (bytes * 1000).checked_div(elapsed_ms).unwrap_or(0);
+
+    let mut info = NetworkInfo {
+        interval_ms: elapsed_ms,
+        ..Default::default()
+    };
+
+    for (name, &(ibytes, obytes)) in &current.counters {
+        let (bytes_in, bytes_out) = previous.and_then(|prev| prev.counters.get(name)).map_or(
+            (0, 0),
+            |&(prev_in, prev_out)| {
+                (
+                    u64::from(ibytes.wrapping_sub(prev_in)),
+                    u64::from(obytes.wrapping_sub(prev_out)),
+                )
+            },
+        );
+
+        if !is_tunnel_interface(name) {
+            info.bytes_in += bytes_in;
+            info.bytes_out += bytes_out;
+        }
+
+        info.interfaces.push(InterfaceStats {
+            name: name.clone(),
+            bytes_in,
+            bytes_out,
+            bytes_in_per_sec: per_sec(bytes_in),
+            bytes_out_per_sec: per_sec(bytes_out),
+        });
+    }
+
+    info.interfaces.sort_by(|a, b| a.name.cmp(&b.name));
+    info.bytes_in_per_sec = per_sec(info.bytes_in);
+    info.bytes_out_per_sec = per_sec(info.bytes_out);
+
+    info
+}
+
+/// Returns true if the interface is a tunnel whose traffic is also counted elsewhere.
+fn is_tunnel_interface(name: &str) -> bool {
+    TUNNEL_PREFIXES.iter().any(|prefix| name.starts_with(prefix))
+}
+
+/// Read byte counters for all link-layer interfaces that are up.
+fn read_interface_counters() -> HashMap<String, (u32, u32)> {
+    let mut counters = HashMap::new();
+    let mut head: *mut IfAddrs = std::ptr::null_mut();
+
+    if unsafe { getifaddrs(&raw mut head) } != 0 {
+        return counters;
+    }
+
+    let mut cursor = head;
+    while !cursor.is_null() {
+        let entry = unsafe { &*cursor };
+        cursor = entry.next;
+
+        if entry.addr.is_null()
+            || entry.data.is_null()
+            || entry.name.is_null()
+            || entry.flags & IFF_UP == 0
+            || entry.flags & IFF_LOOPBACK != 0
+            || unsafe { (*entry.addr).family } != AF_LINK
+        {
+            continue;
+        }
+
+        let name = unsafe { CStr::from_ptr(entry.name) }.to_string_lossy().into_owned();
+        let data = unsafe { &*entry.data.cast::<IfData>() };
+        counters.insert(name, (data.ibytes, data.obytes));
+    }
+
+    unsafe { freeifaddrs(head) };
+    counters
+}
+
+/// Get the BSD name of the primary network interface from the dynamic store.
+fn get_primary_interface() -> Option<String> {
+    let store_name = CFString::new("stache");
+    let key = CFString::new("State:/Network/Global/IPv4");
+    let field = CFString::new("PrimaryInterface");
+
+    unsafe {
+        let store = SCDynamicStoreCreate(
+            std::ptr::null(),
+            store_name.as_concrete_TypeRef(),
+            std::ptr::null(),
+            std::ptr::null_mut(),
+        );
+        if store.is_null() {
+            return None;
+        }
+
+        let dict = SCDynamicStoreCopyValue(store, key.as_concrete_TypeRef());
+        let result = if dict.is_null() {
+            None
+        } else {
+            let value = CFDictionaryGetValue(dict, field.as_concrete_TypeRef().cast());
+            let name =
+                (!value.is_null()).then(|| CFString::wrap_under_get_rule(value.cast()).to_string());
+            CFRelease(dict.cast());
+            name
+        };
+
+        CFRelease(store);
+        result
+    }
+}
+
+/// Get the kind of an interface by its BSD name.
+fn get_interface_kind(bsd_name: &str) -> InterfaceKind {
+    unsafe {
+        let interfaces = SCNetworkInterfaceCopyAll();
+        if interfaces.is_null() {
+            return InterfaceKind::Unknown;
+        }
+
+        let mut kind = InterfaceKind::Other;
+        for index in 0..CFArrayGetCount(interfaces) {
+            let interface = CFArrayGetValueAtIndex(interfaces, index);
+            let name_ref = SCNetworkInterfaceGetBSDName(interface);
+            if name_ref.is_null() || CFString::wrap_under_get_rule(name_ref).to_string() != bsd_name
+            {
+                continue;
+            }
+
+            let type_ref = SCNetworkInterfaceGetInterfaceType(interface);
+            if !type_ref.is_null() {
+                kind =
+                    InterfaceKind::from_type(&CFString::wrap_under_get_rule(type_ref).to_string());
+            }
+            break;
+        }
+
+        CFRelease(interfaces.cast());
+        kind
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn make_sample(taken_at: Instant, counters: &[(&str, u32, u32)]) -> NetworkSample {
+        NetworkSample {
+            taken_at,
+            counters: counters
+                .iter()
+                .map(|&(name, ibytes, obytes)| (name.to_string(), (ibytes, obytes)))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_first_sample_reports_zero() {
+        let current = make_sample(Instant::now(), &[("en0", 1000, 500)]);
+        let info = compute_network_info(None, &current);
+
+        assert_eq!(info.interval_ms, 0);
+        assert_eq!(info.bytes_in, 0);
+        assert_eq!(info.bytes_out, 0);
+        assert_eq!(info.interfaces.len(), 1);
+        assert_eq!(info.interfaces[0].bytes_in_per_sec, 0);
+    }
+
+    #[test]
+    fn test_consecutive_samples_report_rate() {
+        let start = Instant::now();
+        let previous = make_sample(start, &[("en0", 1000, 500), ("en1", 0, 0)]);
+        let current = make_sample(start + Duration::from_secs(2), &[
+            ("en0", 5000, 2500),
+            ("en1", 200, 100),
+        ]);
+        let info = compute_network_info(Some(&previous), &current);
+
+        assert_eq!(info.interval_ms, 2000);
+        assert_eq!(info.bytes_in, 4200);
+        assert_eq!(info.bytes_out, 2100);
+        assert_eq!(info.bytes_in_per_sec, 2100);
+        assert_eq!(info.bytes_out_per_sec, 1050);
+        assert_eq!(info.interfaces[0].name, "en0");
+        assert_eq!(info.interfaces[0].bytes_in_per_sec, 2000);
+        assert_eq!(info.interfaces[1].name, "en1");
+    }
+
+    #[test]
+    fn test_counter_wraparound() {
+        let start = Instant::now();
+        let previous = make_sample(start, &[("en0", u32::MAX - 9, 0)]);
+        let current = make_sample(start + Duration::from_secs(1), &[("en0", 10, 0)]);
+        let info = compute_network_info(Some(&previous), &current);
+
+        assert_eq!(info.bytes_in, 20);
+    }
+
+    #[test]
+    fn test_tunnel_interfaces_excluded_from_aggregate() {
+        let start = Instant::now();
+        let previous = make_sample(start, &[("en0", 0, 0), ("utun3", 0, 0)]);
+        let current = make_sample(start + Duration::from_secs(1), &[
+            ("en0", 100, 100),
+            ("utun3", 90, 90),
+        ]);
+        let info = compute_network_info(Some(&previous), &current);
+
+        assert_eq!(info.bytes_in, 100);
+        assert_eq!(info.interfaces.len(), 2);
+    }
+
+    #[test]
+    fn test_new_interface_reports_zero() {
+        let start = Instant::now();
+        let previous = make_sample(start, &[("en0", 0, 0)]);
+        let current = make_sample(start + Duration::from_secs(1), &[
+            ("en0", 10, 10),
+            ("en5", 9999, 9999),
+        ]);
+        let info = compute_network_info(Some(&previous), &current);
+
+        assert_eq!(info.bytes_in, 10);
+    }
+
+    #[test]
+    fn test_interface_kind_from_type() {
+        assert_eq!(InterfaceKind::from_type("IEEE80211"), InterfaceKind::Wifi);
+        assert_eq!(InterfaceKind::from_type("Ethernet"), InterfaceKind::Ethernet);
+        assert_eq!(InterfaceKind::from_type("Bridge"), InterfaceKind::Other);
+    }
+
+    #[test]
+    fn test_network_info_serialization() {
+        let info = NetworkInfo {
+            active_interface: Some("en0".to_string()),
+            active_interface_kind: InterfaceKind::Wifi,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&info).unwrap();
+        assert!(json.contains("\"activeInterface\":\"en0\""));
+        assert!(json.contains("\"activeInterfaceKind\":\"wifi\""));
+        assert!(json.contains("\"bytesInPerSec\""));
+    }
+
+    #[test]
+    fn test_read_interface_counters_excludes_loopback() {
+        let counters = read_interface_counters();
+        assert!(!counters.contains_key("lo0"));
+    }
+}
@@ -62,14 +62,15 @@ pub struct WindowInfo {
 
 /// Gets all workspaces from the tiling manager.
 ///
-/// Returns workspaces for all screens, or for a specific screen if specified.
+/// Returns workspaces for all screens, or for the screen with a display ID if specified.
 ///
 /// # Errors
 ///
 /// Returns an error if the tiling manager is not available or the screen is not found.
 #[tauri::command]
+#[allow(clippy::needless_pass_by_value)] // Tauri commands require owned values
 pub async fn get_tiling_workspaces(
-    _screen: Option<String>,
+    screen_id: Option<u32>,
 ) -> Result<Vec<WorkspaceInfo>, StacheError> {
     use tiling::actor::{QueryResult, StateQuery};
 
@@ -97,9 +98,19 @@ pub async fn get_tiling_workspaces(
         _ => Vec::new(),
     };
 
-    // Convert to WorkspaceInfo format
-    let infos: Vec<WorkspaceInfo> = workspaces
+    Ok(workspace_infos(workspaces, &screens, screen_id))
+}
+
+/// Converts workspaces to the frontend format, keeping those of the screen
+/// with `screen_id` if specified.
+fn workspace_infos(
+    workspaces: Vec<tiling::state::Workspace>,
+    screens: &[tiling::state::Screen],
+    screen_id: Option<u32>,
+) -> Vec<WorkspaceInfo> {
+    workspaces
         .into_iter()
+        .filter(|ws| screen_id.is_none_or(|id| ws.screen_id == id))
         .map(|ws| {
             let screen_name = screens
                 .iter()
@@ -119,9 +130,7 @@ pub async fn get_tiling_workspaces(
                 window_ids: tiled_window_ids,
             }
         })
-        .collect();
-
-    Ok(infos)
+        .collect()
 }
 
 /// Gets all windows from the tiling manager.
@@ -486,6 +495,27 @@ mod tests {
         assert!(json.contains("\"windowCount\":2"));
     }
 
+    #[test]
+    fn workspace_infos_keeps_the_requested_screen() {
+        let workspaces: Vec<tiling::state::Workspace> = [("code", 1), ("web", 2), ("chat", 1)]
+            .into_iter()
+            .map(|(name, screen_id)| tiling::state::Workspace {
+                screen_id,
+                ..tiling::state::Workspace::new(name)
+            })
+            .collect();
+
+        let names = |infos: Vec<WorkspaceInfo>| -> Vec<String> {
+            infos.into_iter().map(|info| info.name).collect()
+        };
+        assert_eq!(names(workspace_infos(workspaces.clone(), &[], Some(1))), [
+            "code", "chat"
+        ]);
+        assert_eq!(names(workspace_infos(workspaces.clone(), &[], Some(2))), ["web"]);
+        assert!(workspace_infos(workspaces.clone(), &[], Some(3)).is_empty());
+        assert_eq!(workspace_infos(workspaces, &[], None).len(), 3);
+    }
+
     #[test]
     fn window_info_serializes_correctly() {
         let info = WindowInfo {
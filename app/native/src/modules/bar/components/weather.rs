@@ -1,16 +1,38 @@
-//! Weather configuration component.
+//! Weather component.
 //!
-//! Exposes the weather configuration from the config file to the frontend.
+//! Exposes the weather configuration from the config file to the frontend and
+//! fetches current conditions for every configured location on demand.
 //! API keys are loaded from a separate environment file to avoid leaking
 //! secrets in the configuration file.
+//!
+//! Responses are cached per location in the app cache directory so a restart
+//! doesn't immediately re-hit the provider API.
 
+use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::AppHandle;
+use tauri_plugin_shell::ShellExt;
+use tauri_plugin_shell::process::CommandEvent;
 
+use crate::cache::get_cache_subdir;
 use crate::config::env::load_api_keys;
 use crate::config::types::WeatherProvider;
 use crate::config::{WeatherConfig, get_config, get_config_path};
+use crate::error::StacheError;
+
+/// Open Meteo forecast API endpoint.
+const OPEN_METEO_FORECAST_URL: &str = "https://api.open-meteo.com/v1/forecast";
+/// Open Meteo geocoding API endpoint.
+const OPEN_METEO_GEOCODING_URL: &str = "https://geocoding-api.open-meteo.com/v1/search";
+/// Visual Crossing timeline API endpoint.
+const VISUAL_CROSSING_URL: &str =
+    "https://weather.visualcrossing.com/VisualCrossingWebServices/rest/services/timeline";
+/// Maximum time in seconds for a single HTTP request.
+const REQUEST_TIMEOUT_SECS: &str = "10";
 
 /// Weather configuration payload for the frontend.
 #[derive(Debug, Clone, Serialize)]
@@ -22,6 +44,8 @@ pub struct WeatherConfigInfo {
     pub visual_crossing_api_key: String,
     /// Default location for weather data when geolocation fails.
     pub default_location: String,
+    /// All configured locations, starting with the default location.
+    pub locations: Vec<String>,
 }
 
 impl WeatherConfigInfo {
@@ -40,6 +64,7 @@ impl WeatherConfigInfo {
             provider: config.provider,
             visual_crossing_api_key: api_keys.visual_crossing_api_key().to_string(),
             default_location: config.default_location.clone(),
+            locations: config.all_locations().into_iter().map(String::from).collect(),
         }
     }
 }
@@ -62,6 +87,382 @@ pub fn get_weather_config() -> WeatherConfigInfo {
     WeatherConfigInfo::from_config(weather_config, &config_dir)
 }
 
+// ============================================================================
+// Current conditions
+// ============================================================================
+
+/// Provider-agnostic current weather conditions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentConditions {
+    /// Location name as resolved by the provider.
+    pub resolved_address: String,
+    /// Temperature in Celsius.
+    pub temperature: f64,
+    /// Apparent temperature in Celsius.
+    pub feels_like: f64,
+    /// Relative humidity percentage.
+    pub humidity: f64,
+    /// Wind speed in km/h.
+    pub wind_speed: f64,
+    /// Provider-specific condition code (Visual Crossing icon name or Open Meteo WMO code).
+    pub condition_code: String,
+    /// Whether it is currently daytime at the location.
+    pub is_day: bool,
+}
+
+/// Freshness of the weather data returned for a location.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WeatherStatus {
+    /// Data was fetched from the provider during this request.
+    Fresh,
+    /// Data was served from the cache because the minimum refresh interval hasn't elapsed.
+    Cached,
+    /// Fetching failed. Previously cached data is returned if available.
+    Error,
+}
+
+/// Weather result for a single location.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocationWeather {
+    /// Location as configured.
+    pub location: String,
+    /// Freshness of the data.
+    pub status: WeatherStatus,
+    /// Current conditions, if any data is available.
+    pub conditions: Option<CurrentConditions>,
+    /// Unix timestamp (seconds) of when the conditions were fetched.
+    pub fetched_at: Option<u64>,
+    /// Error message when the fetch failed.
+    pub error: Option<String>,
+}
+
+/// Cached provider response for a location.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedWeather {
+    fetched_at: u64,
+    provider: WeatherProvider,
+    conditions: CurrentConditions,
+}
+
+/// Fetch current conditions for every configured location.
+///
+/// Locations fetched less than `minRefreshInterval` seconds ago are served from
+/// the cache. A failed fetch is reported with [`WeatherStatus::Error`] and the
+/// last cached conditions (if any), so the bar can distinguish errors from a
+/// location that has no data yet.
+///
+/// # Errors
+///
+/// Returns an error if weather is not enabled in the configuration.
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)]
+pub async fn refresh_weather(app: AppHandle) -> Result<Vec<LocationWeather>, StacheError> {
+    let config = &get_config().bar.weather;
+    if !config.is_enabled() {
+        return Err(StacheError::ConfigError("Weather is not enabled".to_string()));
+    }
+
+    let info = get_weather_config();
+    let provider = resolve_provider(config.provider, &info.visual_crossing_api_key);
+    let now = unix_now();
+
+    let mut results = Vec::with_capacity(info.locations.len());
+    for location in &info.locations {
+        let cached = read_cache(location).filter(|cache| cache.provider == provider);
+
+        if let Some(cache) = cached
+            .as_ref()
+            .filter(|cache| now.saturating_sub(cache.fetched_at) < config.min_refresh_interval)
+        {
+            results.push(LocationWeather {
+                location: location.clone(),
+                status: WeatherStatus::Cached,
+                conditions: Some(cache.conditions.clone()),
+                fetched_at: Some(cache.fetched_at),
+                error: None,
+            });
+            continue;
+        }
+
+        let result =
+            fetch_conditions(&app, provider, location, &info.visual_crossing_api_key).await;
+        results.push(build_location_weather(location, result, cached, provider, now));
+    }
+
+    Ok(results)
+}
+
+/// Resolves `auto` to a concrete provider based on API key availability.
+const fn resolve_provider(provider: WeatherProvider, api_key: &str) -> WeatherProvider {
+    match provider {
+        WeatherProvider::Auto if api_key.is_empty() => WeatherProvider::OpenMeteo,
+        WeatherProvider::Auto => WeatherProvider::VisualCrossing,
+        other => other,
+    }
+}
+
+/// Builds the result for a location from a fetch outcome, updating the cache on success.
+fn build_location_weather(
+    location: &str,
+    result: Result<CurrentConditions, String>,
+    cached: Option<CachedWeather>,
+    provider: WeatherProvider,
+    now: u64,
+) -> LocationWeather {
+    match result {
+        Ok(conditions) => {
+            write_cache(location, &CachedWeather {
+                fetched_at: now,
+                provider,
+                conditions: conditions.clone(),
+            });
+
+            LocationWeather {
+                location: location.to_string(),
+                status: WeatherStatus::Fresh,
+                conditions: Some(conditions),
+                fetched_at: Some(now),
+                error: None,
+            }
+        }
+        Err(err) => {
+            tracing::warn!(location, error = %err, "failed to fetch weather");
+
+            LocationWeather {
+                location: location.to_string(),
+                status: WeatherStatus::Error,
+                fetched_at: cached.as_ref().map(|cache| cache.fetched_at),
+                conditions: cached.map(|cache| cache.conditions),
+                error: Some(err),
+            }
+        }
+    }
+}
+
+/// Fetches current conditions for a location from the given provider.
+async fn fetch_conditions(
+    app: &AppHandle,
+    provider: WeatherProvider,
+    location: &str,
+    api_key: &str,
+) -> Result<CurrentConditions, String> {
+    if provider == WeatherProvider::VisualCrossing {
+        if api_key.is_empty() {
+            return Err("Visual Crossing requires an API key".to_string());
+        }
+
+        let url = format!(
+            "{VISUAL_CROSSING_URL}/{}/today?key={}&unitGroup=metric&include=current&iconSet=icons2&contentType=json",
+            encode_uri_component(location),
+            encode_uri_component(api_key),
+        );
+        let data = fetch_json(app, &url).await?;
+        return parse_visual_crossing(&data);
+    }
+
+    let (lat, lon, name) = match parse_coordinates(location) {
+        Some((lat, lon)) => (lat, lon, location.to_string()),
+        None => {
+            let url = format!(
+                "{OPEN_METEO_GEOCODING_URL}?name={}&count=1&language=en&format=json",
+                encode_uri_component(location)
+            );
+            parse_open_meteo_geocoding(&fetch_json(app, &url).await?)
+                .ok_or_else(|| format!("Failed to geocode location '{location}'"))?
+        }
+    };
+
+    let url = format!(
+        "{OPEN_METEO_FORECAST_URL}?latitude={lat}&longitude={lon}&current=temperature_2m,relative_humidity_2m,apparent_temperature,is_day,weather_code,wind_speed_10m&timezone=auto"
+    );
+    parse_open_meteo(&fetch_json(app, &url).await?, name)
+}
+
+/// Performs an HTTP GET request using the system `curl` and parses the response as JSON.
+///
+/// The URL is passed as a curl config on stdin rather than as an argument, so
+/// API keys in the query string don't show up in the process list.
+async fn fetch_json(app: &AppHandle, url: &str) -> Result<Value, String> {
+    let (mut rx, mut child) = app
+        .shell()
+        .command("curl")
+        .args([
+            "--silent",
+            "--show-error",
+            "--fail",
+            "--max-time",
+            REQUEST_TIMEOUT_SECS,
+            "--config",
+            "-",
+        ])
+        .set_raw_out(true)
+        .spawn()
+        .map_err(|err| format!("Failed to run curl: {err}"))?;
+
+    let config = format!("url = \"{}\"\n", url.replace('\\', "\\\\").replace('"', "\\\""));
+    child
+        .write(config.as_bytes())
+        .map_err(|err| format!("Failed to run curl: {err}"))?;
+    // Closes stdin, so curl stops reading its config
+    drop(child);
+
+    let mut stdout = Vec::new();
+    let mut stderr = Vec::new();
+    let mut success = false;
+    while let Some(event) = rx.recv().await {
+        match event {
+            CommandEvent::Stdout(chunk) => stdout.extend(chunk),
+            CommandEvent::Stderr(chunk) => stderr.extend(chunk),
+            CommandEvent::Error(err) => return Err(format!("Failed to run curl: {err}")),
+            CommandEvent::Terminated(payload) => success = payload.code == Some(0),
+            _ => {}
+        }
+    }
+
+    if !success {
+        let stderr = String::from_utf8_lossy(&stderr);
+        return Err(format!("Request failed: {}", stderr.trim()));
+    }
+
+    serde_json::from_slice(&stdout).map_err(|err| format!("Invalid response: {err}"))
+}
+
+/// Parses a Visual Crossing timeline response.
+fn parse_visual_crossing(data: &Value) -> Result<CurrentConditions, String> {
+    let current = data
+        .get("currentConditions")
+        .ok_or_else(|| "Response is missing current conditions".to_string())?;
+    let number = |key: &str| current.get(key).and_then(Value::as_f64).unwrap_or_default();
+    let icon = current.get("icon").and_then(Value::as_str).unwrap_or_default();
+
+    Ok(CurrentConditions {
+        resolved_address: data
+            .get("resolvedAddress")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+        temperature: number("temp"),
+        feels_like: number("feelslike"),
+        humidity: number("humidity"),
+        wind_speed: number("windspeed"),
+        condition_code: icon.to_string(),
+        is_day: !icon.contains("night"),
+    })
+}
+
+/// Parses the first result of an Open Meteo geocoding response.
+fn parse_open_meteo_geocoding(data: &Value) -> Option<(f64, f64, String)> {
+    let result = data.get("results")?.as_array()?.first()?;
+    let lat = result.get("latitude")?.as_f64()?;
+    let lon = result.get("longitude")?.as_f64()?;
+    let name = result.get("name")?.as_str()?;
+
+    let name = match result.get("country").and_then(Value::as_str) {
+        Some(country) => format!("{name}, {country}"),
+        None => name.to_string(),
+    };
+
+    Some((lat, lon, name))
+}
+
+/// Parses an Open Meteo forecast response.
+fn parse_open_meteo(data: &Value, resolved_address: String) -> Result<CurrentConditions, String> {
+    let current = data
+        .get("current")
+        .ok_or_else(|| "Response is missing current conditions".to_string())?;
+    let number = |key: &str| current.get(key).and_then(Value::as_f64).unwrap_or_default();
+
+    Ok(CurrentConditions {
+        resolved_address,
+        temperature: number("temperature_2m"),
+        feels_like: number("apparent_temperature"),
+        humidity: number("relative_humidity_2m"),
+        wind_speed: number("wind_speed_10m"),
+        condition_code: current
+            .get("weather_code")
+            .and_then(Value::as_i64)
+            .unwrap_or_default()
+            .to_string(),
+        is_day: current.get("is_day").and_then(Value::as_i64) != Some(0),
+    })
+}
+
+/// Parses a `"lat,lon"` location string.
+fn parse_coordinates(location: &str) -> Option<(f64, f64)> {
+    let (lat, lon) = location.split_once(',')?;
+    let lat: f64 = lat.trim().parse().ok()?;
+    let lon: f64 = lon.trim().parse().ok()?;
+
+    ((-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon)).then_some((lat, lon))
+}
+
+/// Percent-encodes a string for use in a URL path segment or query value.
+fn encode_uri_component(value: &str) -> String {
+    use std::fmt::Write as _;
+
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            let _ = write!(encoded, "%{byte:02X}");
+        }
+    }
+    encoded
+}
+
+// ============================================================================
+// Cache
+// ============================================================================
+
+/// Returns the cache file path for a location.
+fn cache_path(location: &str) -> PathBuf {
+    let key: String = location
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    get_cache_subdir("weather").join(format!("{key}.json"))
+}
+
+/// Reads the cached weather for a location, if any.
+fn read_cache(location: &str) -> Option<CachedWeather> {
+    let content = fs::read_to_string(cache_path(location)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Writes the weather for a location to the cache.
+fn write_cache(location: &str, cached: &CachedWeather) {
+    let path = cache_path(location);
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::write(&path, serde_json::to_vec(cached).unwrap_or_default()));
+
+    if let Err(err) = result {
+        tracing::debug!(error = %err, path = %path.display(), "failed to write weather cache");
+    }
+}
+
+/// Returns the current Unix timestamp in seconds.
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
@@ -93,6 +494,7 @@ mod tests {
             provider: WeatherProvider::Auto,
             api_keys: ".env".to_string(),
             default_location: "New York".to_string(),
+            ..Default::default()
         };
 
         let info = WeatherConfigInfo::from_config(&config, temp_dir.path());
@@ -107,6 +509,7 @@ mod tests {
             provider: WeatherProvider::Auto,
             api_keys: "nonexistent.env".to_string(),
             default_location: "London".to_string(),
+            ..Default::default()
         };
 
         let config_dir = Path::new("/nonexistent/dir");
@@ -128,6 +531,7 @@ mod tests {
             provider: WeatherProvider::VisualCrossing,
             api_keys: env_path.to_string_lossy().to_string(),
             default_location: "Paris".to_string(),
+            ..Default::default()
         };
 
         // Config dir doesn't matter for absolute paths
@@ -137,4 +541,183 @@ mod tests {
         assert_eq!(info.visual_crossing_api_key, "absolute_path_key");
         assert_eq!(info.default_location, "Paris");
     }
+
+    #[test]
+    fn test_weather_config_info_locations() {
+        let config = WeatherConfig {
+            default_location: "Paris".to_string(),
+            locations: vec!["Tokyo".to_string()],
+            ..Default::default()
+        };
+        let info = WeatherConfigInfo::from_config(&config, Path::new("/tmp"));
+
+        assert_eq!(info.locations, vec!["Paris", "Tokyo"]);
+    }
+
+    // ========================================================================
+    // Current conditions tests
+    // ========================================================================
+
+    fn make_conditions() -> CurrentConditions {
+        CurrentConditions {
+            resolved_address: "Paris, France".to_string(),
+            temperature: 20.0,
+            feels_like: 19.0,
+            humidity: 50.0,
+            wind_speed: 10.0,
+            condition_code: "clear-day".to_string(),
+            is_day: true,
+        }
+    }
+
+    #[test]
+    fn test_resolve_provider() {
+        assert_eq!(
+            resolve_provider(WeatherProvider::Auto, ""),
+            WeatherProvider::OpenMeteo
+        );
+        assert_eq!(
+            resolve_provider(WeatherProvider::Auto, "key"),
+            WeatherProvider::VisualCrossing
+        );
+        assert_eq!(
+            resolve_provider(WeatherProvider::OpenMeteo, "key"),
+            WeatherProvider::OpenMeteo
+        );
+    }
+
+    #[test]
+    fn test_parse_visual_crossing() {
+        let data = serde_json::json!({
+            "resolvedAddress": "Paris, France",
+            "currentConditions": {
+                "temp": 20.5,
+                "feelslike": 19.0,
+                "humidity": 60.0,
+                "windspeed": 12.0,
+                "icon": "partly-cloudy-night"
+            }
+        });
+
+        let conditions = parse_visual_crossing(&data).unwrap();
+        assert_eq!(conditions.resolved_address, "Paris, France");
+        assert_eq!(conditions.condition_code, "partly-cloudy-night");
+        assert!(!conditions.is_day);
+    }
+
+    #[test]
+    fn test_parse_visual_crossing_missing_current() {
+        assert!(parse_visual_crossing(&serde_json::json!({})).is_err());
+    }
+
+    #[test]
+    fn test_parse_open_meteo() {
+        let data = serde_json::json!({
+            "current": {
+                "temperature_2m": 15.0,
+                "apparent_temperature": 14.0,
+                "relative_humidity_2m": 70.0,
+                "wind_speed_10m": 5.0,
+                "weather_code": 3,
+                "is_day": 1
+            }
+        });
+
+        let conditions = parse_open_meteo(&data, "Tokyo".to_string()).unwrap();
+        assert_eq!(conditions.condition_code, "3");
+        assert!(conditions.is_day);
+        assert_eq!(conditions.resolved_address, "Tokyo");
+    }
+
+    #[test]
+    fn test_parse_open_meteo_geocoding() {
+        let data = serde_json::json!({
+            "results": [{"latitude": 35.68, "longitude": 139.69, "name": "Tokyo", "country": "Japan"}]
+        });
+
+        let (lat, lon, name) = parse_open_meteo_geocoding(&data).unwrap();
+        assert!((lat - 35.68).abs() < f64::EPSILON);
+        assert!((lon - 139.69).abs() < f64::EPSILON);
+        assert_eq!(name, "Tokyo, Japan");
+
+        assert!(parse_open_meteo_geocoding(&serde_json::json!({"results": []})).is_none());
+    }
+
+    #[test]
+    fn test_parse_coordinates() {
+        assert_eq!(parse_coordinates("40.7128,-74.0060"), Some((40.7128, -74.006)));
+        assert_eq!(parse_coordinates(" 1.5 , 2.5 "), Some((1.5, 2.5)));
+        assert_eq!(parse_coordinates("San Francisco, CA"), None);
+        assert_eq!(parse_coordinates("100,0"), None);
+    }
+
+    #[test]
+    fn test_encode_uri_component() {
+        assert_eq!(
+            encode_uri_component("San Francisco, CA"),
+            "San%20Francisco%2C%20CA"
+        );
+        assert_eq!(encode_uri_component("abc-_.~"), "abc-_.~");
+        assert_eq!(encode_uri_component("São"), "S%C3%A3o");
+    }
+
+    #[test]
+    fn test_cache_path_is_keyed_by_location() {
+        let path = cache_path("San Francisco, CA");
+        assert!(path.ends_with("weather/san_francisco__ca.json"));
+        assert_ne!(cache_path("Paris"), cache_path("Tokyo"));
+    }
+
+    #[test]
+    fn test_build_location_weather_error_keeps_cached_data() {
+        let cached = CachedWeather {
+            fetched_at: 42,
+            provider: WeatherProvider::OpenMeteo,
+            conditions: make_conditions(),
+        };
+
+        let result = build_location_weather(
+            "Paris",
+            Err("Request failed".to_string()),
+            Some(cached),
+            WeatherProvider::OpenMeteo,
+            100,
+        );
+
+        assert_eq!(result.status, WeatherStatus::Error);
+        assert_eq!(result.fetched_at, Some(42));
+        assert_eq!(result.conditions, Some(make_conditions()));
+        assert_eq!(result.error.as_deref(), Some("Request failed"));
+    }
+
+    #[test]
+    fn test_build_location_weather_error_without_data() {
+        let result = build_location_weather(
+            "Paris",
+            Err("Request failed".to_string()),
+            None,
+            WeatherProvider::OpenMeteo,
+            100,
+        );
+
+        assert_eq!(result.status, WeatherStatus::Error);
+        assert!(result.conditions.is_none());
+        assert!(result.fetched_at.is_none());
+    }
+
+    #[test]
+    fn test_location_weather_serialization() {
+        let result = LocationWeather {
+            location: "Paris".to_string(),
+            status: WeatherStatus::Cached,
+            conditions: Some(make_conditions()),
+            fetched_at: Some(1),
+            error: None,
+        };
+
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(json.contains("\"status\":\"cached\""));
+        assert!(json.contains("\"fetchedAt\":1"));
+        assert!(json.contains("\"feelsLike\""));
+    }
 }
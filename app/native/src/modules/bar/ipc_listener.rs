@@ -5,8 +5,9 @@
 
 use tauri::{AppHandle, Emitter, Runtime};
 
+use super::{material, window};
 use crate::events;
-use crate::modules::tiling;
+use crate::modules::{hotkey, tiling};
 use crate::platform::ipc::{self, StacheNotification};
 
 /// Initializes the IPC listener for CLI notifications.
@@ -37,6 +38,17 @@ fn build_tiling_runtime() -> Option<tokio::runtime::Runtime> {
     }
 }
 
+/// Returns the name of the Nth workspace (1-based) of the focused screen.
+fn workspace_name_on_focused_screen(index: usize) -> Option<String> {
+    let handle = tiling::init::get_handle()?;
+    let rt = build_tiling_runtime()?;
+    rt.block_on(handle.get_workspace_on_focused_screen(index.checked_sub(1)?))
+        .ok()
+        .and_then(tiling::actor::QueryResult::into_workspace)
+        .flatten()
+        .map(|ws| ws.name)
+}
+
 /// Handles incoming Stache notifications.
 #[allow(clippy::too_many_lines)]
 fn handle_notification<R: Runtime>(app_handle: &AppHandle<R>, notification: StacheNotification) {
@@ -74,6 +86,18 @@ fn handle_notification<R: Runtime>(app_handle: &AppHandle<R>, notification: Stac
             }
         }
 
+        StacheNotification::BarShow => window::set_bar_visible(app_handle, true),
+        StacheNotification::BarHide => window::set_bar_visible(app_handle, false),
+        StacheNotification::BarToggle => {
+            window::set_bar_visible(app_handle, !window::is_bar_visible());
+        }
+        StacheNotification::BarMaterial(material) => {
+            match serde_json::from_value(serde_json::json!(material)) {
+                Ok(material) => material::set_material(material),
+                Err(e) => tracing::warn!("bar: invalid material '{material}': {e}"),
+            }
+        }
+
         // Tiling notifications - forwarded to the tiling manager
         StacheNotification::TilingFocusWorkspace(workspace) => {
             let app_handle = app_handle.clone();
@@ -105,6 +129,25 @@ fn handle_notification<R: Runtime>(app_handle: &AppHandle<R>, notification: Stac
             });
         }
 
+        StacheNotification::TilingFocusWorkspaceIndex(index) => {
+            let app_handle = app_handle.clone();
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                let Some(workspace) = workspace_name_on_focused_screen(index) else {
+                    tracing::warn!("tiling: no workspace {index} on the focused screen");
+                    return;
+                };
+                handle_notification(
+                    &app_handle,
+                    StacheNotification::TilingFocusWorkspace(workspace),
+                );
+            });
+        }
+
         StacheNotification::TilingSetLayout(layout) => {
             std::thread::spawn(move || {
                 if !tiling::init::is_initialized() {
@@ -156,6 +199,35 @@ fn handle_notification<R: Runtime>(app_handle: &AppHandle<R>, notification: Stac
                 }
 
                 if let Some(handle) = tiling::init::get_handle() {
+                    if target.eq_ignore_ascii_case("mru") {
+                        if let Err(e) = handle.focus_mru(1) {
+                            tracing::warn!("tiling: failed to focus MRU window: {e}");
+                        }
+                        return;
+                    }
+
+                    if target.eq_ignore_ascii_case("global-mru") {
+                        if let Err(e) = handle.focus_global_mru() {
+                            tracing::warn!("tiling: failed to focus global MRU window: {e}");
+                        }
+                        return;
+                    }
+
+                    let app_direction = if target.eq_ignore_ascii_case("app-next") {
+                        Some(tiling::actor::CycleDirection::Next)
+                    } else if target.eq_ignore_ascii_case("app-previous") {
+                        Some(tiling::actor::CycleDirection::Previous)
+                    } else {
+                        None
+                    };
+
+                    if let Some(direction) = app_direction {
+                        if let Err(e) = handle.cycle_app_focus(direction) {
+                            tracing::warn!("tiling: failed to cycle app windows: {e}");
+                        }
+                        return;
+                    }
+
                     // Parse direction
                     if let Some(direction) = tiling::actor::FocusDirection::parse(&target) {
                         if let Err(e) = handle.focus_window(direction) {
@@ -232,7 +304,26 @@ fn handle_notification<R: Runtime>(app_handle: &AppHandle<R>, notification: Stac
             });
         }
 
-        StacheNotification::TilingWindowSendToWorkspace(workspace) => {
+        StacheNotification::TilingWindowSendToWorkspaceIndex { index, follow } => {
+            let app_handle = app_handle.clone();
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                let Some(workspace) = workspace_name_on_focused_screen(index) else {
+                    tracing::warn!("tiling: no workspace {index} on the focused screen");
+                    return;
+                };
+                handle_notification(&app_handle, StacheNotification::TilingWindowSendToWorkspace {
+                    workspace,
+                    follow,
+                });
+            });
+        }
+
+        StacheNotification::TilingWindowSendToWorkspace { workspace, follow } => {
             std::thread::spawn(move || {
                 if !tiling::init::is_initialized() {
                     tracing::warn!("tiling: manager not initialized");
@@ -267,6 +358,7 @@ fn handle_notification<R: Runtime>(app_handle: &AppHandle<R>, notification: Stac
                                 handle.send(tiling::actor::StateMessage::MoveWindowToWorkspace {
                                     window_id: win_id,
                                     workspace_id: ws_id,
+                                    follow,
                                 })
                             {
                                 tracing::warn!("tiling: failed to send window to workspace: {e}");
@@ -287,6 +379,50 @@ fn handle_notification<R: Runtime>(app_handle: &AppHandle<R>, notification: Stac
             });
         }
 
+        StacheNotification::TilingWindowAlsoWorkspace(workspace) => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                let Some(handle) = tiling::init::get_handle() else {
+                    return;
+                };
+                let Some(rt) = build_tiling_runtime() else {
+                    return;
+                };
+
+                let workspace_id = rt
+                    .block_on(handle.get_workspace_by_name(&workspace))
+                    .ok()
+                    .and_then(tiling::actor::QueryResult::into_workspace)
+                    .flatten()
+                    .map(|ws| ws.id);
+                let window_id = rt
+                    .block_on(handle.get_focused_window())
+                    .ok()
+                    .and_then(tiling::actor::QueryResult::into_window)
+                    .flatten()
+                    .map(|w| w.id);
+
+                match (window_id, workspace_id) {
+                    (Some(window_id), Some(workspace_id)) => {
+                        if let Err(e) =
+                            handle.send(tiling::actor::StateMessage::ToggleExtraWorkspace {
+                                window_id,
+                                workspace_id,
+                            })
+                        {
+                            tracing::warn!("tiling: failed to toggle window workspace: {e}");
+                        }
+                    }
+                    (None, _) => tracing::warn!("tiling: no focused window"),
+                    (_, None) => tracing::warn!("tiling: workspace '{workspace}' not found"),
+                }
+            });
+        }
+
         StacheNotification::TilingWindowSendToScreen(screen) => {
             std::thread::spawn(move || {
                 if !tiling::init::is_initialized() {
@@ -304,6 +440,72 @@ fn handle_notification<R: Runtime>(app_handle: &AppHandle<R>, notification: Stac
             });
         }
 
+        StacheNotification::TilingWindowMinimize => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                let Some(handle) = tiling::init::get_handle() else {
+                    return;
+                };
+                let Some(rt) = build_tiling_runtime() else {
+                    return;
+                };
+                let Ok(result) = rt.block_on(handle.get_focused_window()) else {
+                    return;
+                };
+                let Some(Some(window)) = result.into_window() else {
+                    tracing::debug!("tiling: no focused window to minimize");
+                    return;
+                };
+                if let Err(e) = handle.minimize_window(window.id) {
+                    tracing::warn!("tiling: failed to minimize window: {e}");
+                }
+            });
+        }
+
+        StacheNotification::TilingWindowRestore => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle()
+                    && let Err(e) = handle.restore_minimized_window()
+                {
+                    tracing::warn!("tiling: failed to restore window: {e}");
+                }
+            });
+        }
+
+        StacheNotification::TilingWindowPromote | StacheNotification::TilingWindowDemote => {
+            let direction = if matches!(notification, StacheNotification::TilingWindowPromote) {
+                tiling::actor::CycleDirection::Previous
+            } else {
+                tiling::actor::CycleDirection::Next
+            };
+
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle()
+                    && let Err(e) = handle.move_window_in_stack(direction)
+                {
+                    tracing::warn!("tiling: failed to move window in stack: {e}");
+                }
+            });
+        }
+
+        StacheNotification::TilingWindowMoveMode => {
+            std::thread::spawn(hotkey::start_move_mode);
+        }
+
         StacheNotification::TilingWorkspaceBalance => {
             std::thread::spawn(move || {
                 if !tiling::init::is_initialized() {
@@ -332,6 +534,99 @@ fn handle_notification<R: Runtime>(app_handle: &AppHandle<R>, notification: Stac
             });
         }
 
+        StacheNotification::TilingWorkspaceToggleLayout => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                let Some(handle) = tiling::init::get_handle() else {
+                    return;
+                };
+                let Some(rt) = build_tiling_runtime() else {
+                    return;
+                };
+                let Ok(result) = rt.block_on(handle.get_focused_workspace()) else {
+                    return;
+                };
+                let Some(Some(ws)) = result.into_workspace() else {
+                    return;
+                };
+                if let Err(e) = handle.toggle_layout(ws.id) {
+                    tracing::warn!("tiling: failed to toggle workspace layout: {e}");
+                } else {
+                    tracing::debug!("tiling: toggled layout of workspace '{}'", ws.name);
+                }
+            });
+        }
+
+        StacheNotification::TilingWorkspaceAutoLayout => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                let Some(handle) = tiling::init::get_handle() else {
+                    return;
+                };
+                let Some(rt) = build_tiling_runtime() else {
+                    return;
+                };
+                let Ok(result) = rt.block_on(handle.get_focused_workspace()) else {
+                    return;
+                };
+                let Some(Some(ws)) = result.into_workspace() else {
+                    return;
+                };
+                if let Err(e) = handle.reset_auto_layout(ws.id) {
+                    tracing::warn!("tiling: failed to reset workspace auto layout: {e}");
+                } else {
+                    tracing::debug!("tiling: workspace '{}' layout follows autoLayout", ws.name);
+                }
+            });
+        }
+
+        StacheNotification::TilingSetMasterPosition(position) => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                let master_position: crate::config::MasterPosition =
+                    match serde_json::from_value(serde_json::json!(position)) {
+                        Ok(master_position) => master_position,
+                        Err(e) => {
+                            tracing::warn!("tiling: invalid master position '{position}': {e}");
+                            return;
+                        }
+                    };
+
+                let Some(handle) = tiling::init::get_handle() else {
+                    return;
+                };
+                let Some(rt) = build_tiling_runtime() else {
+                    return;
+                };
+                let Ok(result) = rt.block_on(handle.get_focused_workspace()) else {
+                    return;
+                };
+                let Some(Some(ws)) = result.into_workspace() else {
+                    return;
+                };
+                if let Err(e) = handle.set_master_position(ws.id, master_position) {
+                    tracing::warn!("tiling: failed to set master position: {e}");
+                } else {
+                    tracing::debug!(
+                        "tiling: set master position to {master_position:?} for workspace '{}'",
+                        ws.name
+                    );
+                }
+            });
+        }
+
         StacheNotification::TilingWorkspaceSendToScreen(screen) => {
             std::thread::spawn(move || {
                 if !tiling::init::is_initialized() {
@@ -348,6 +643,173 @@ fn handle_notification<R: Runtime>(app_handle: &AppHandle<R>, notification: Stac
                 }
             });
         }
+
+        StacheNotification::TilingWorkspaceSwapScreens { screen_a, screen_b } => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle() {
+                    if let Err(e) = handle.swap_workspaces_between_screens(&screen_a, &screen_b) {
+                        tracing::warn!("tiling: failed to swap workspaces between screens: {e}");
+                    } else {
+                        tracing::debug!("tiling: swapped workspaces of {screen_a} and {screen_b}");
+                    }
+                }
+            });
+        }
+
+        StacheNotification::TilingFocusScreen(screen) => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle() {
+                    if let Err(e) = handle.focus_screen(&screen) {
+                        tracing::warn!("tiling: failed to focus screen: {e}");
+                    } else {
+                        tracing::debug!("tiling: focused screen {screen}");
+                    }
+                }
+            });
+        }
+
+        StacheNotification::TilingCenterCursor => {
+            std::thread::spawn(|| {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle()
+                    && let Err(e) = handle.center_cursor()
+                {
+                    tracing::warn!("tiling: failed to center the cursor: {e}");
+                }
+            });
+        }
+
+        StacheNotification::TilingAppGather(app) => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle() {
+                    if let Err(e) = handle.group_app_windows(&app) {
+                        tracing::warn!("tiling: failed to group app windows: {e}");
+                    } else {
+                        tracing::debug!("tiling: grouped windows of '{app}'");
+                    }
+                }
+            });
+        }
+
+        StacheNotification::TilingToggleGaps { all } => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle()
+                    && let Err(e) = handle.toggle_gaps(all)
+                {
+                    tracing::warn!("tiling: failed to toggle gaps: {e}");
+                }
+            });
+        }
+
+        StacheNotification::TilingAdjustGaps { inner, outer } => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle()
+                    && let Err(e) = handle.adjust_gaps(f64::from(inner), f64::from(outer))
+                {
+                    tracing::warn!("tiling: failed to adjust gaps: {e}");
+                }
+            });
+        }
+
+        StacheNotification::TilingResetGaps => {
+            std::thread::spawn(|| {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle()
+                    && let Err(e) = handle.reset_gaps()
+                {
+                    tracing::warn!("tiling: failed to reset gaps: {e}");
+                }
+            });
+        }
+
+        StacheNotification::TilingPause | StacheNotification::TilingResume => {
+            let paused = notification == StacheNotification::TilingPause;
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle()
+                    && let Err(e) = handle.set_paused(paused)
+                {
+                    tracing::warn!("tiling: failed to set paused: {e}");
+                }
+            });
+        }
+
+        StacheNotification::TilingReconcile => {
+            std::thread::spawn(|| {
+                if !tiling::reconcile::reconcile() {
+                    tracing::warn!("tiling: reconcile did not run");
+                }
+            });
+        }
+
+        StacheNotification::TilingRestart => {
+            // Observers and screen detection need the main thread
+            let result = app_handle.run_on_main_thread(|| {
+                if !tiling::init::restart() {
+                    tracing::warn!("tiling: manager not initialized");
+                }
+            });
+            if let Err(e) = result {
+                tracing::warn!("tiling: failed to schedule restart: {e}");
+            }
+        }
+
+        StacheNotification::TilingWorkspaceGather(app) => {
+            std::thread::spawn(move || {
+                if !tiling::init::is_initialized() {
+                    tracing::warn!("tiling: manager not initialized");
+                    return;
+                }
+
+                if let Some(handle) = tiling::init::get_handle() {
+                    if let Err(e) = handle.gather_windows(app.as_deref()) {
+                        tracing::warn!("tiling: failed to gather windows: {e}");
+                    } else {
+                        tracing::debug!(
+                            "tiling: gathered windows ({})",
+                            app.as_deref().unwrap_or("all apps")
+                        );
+                    }
+                }
+            });
+        }
     }
 }
 
@@ -0,0 +1,164 @@
+//! Bar background material.
+//!
+//! Puts an `NSVisualEffectView` with the `bar.material` material behind the
+//! bar windows and re-applies it whenever the system switches between light
+//! and dark appearance.
+
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use objc::declare::ClassDecl;
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+use tauri::{AppHandle, WebviewWindow};
+
+use super::window::bar_windows;
+use crate::config::{BarMaterial, get_config};
+use crate::platform::objc::nsstring;
+use crate::platform::window::{is_dark_appearance, set_window_material};
+
+/// Distributed notification posted when the system appearance changes.
+const APPEARANCE_CHANGED_NOTIFICATION: &str = "AppleInterfaceThemeChangedNotification";
+
+/// App handle used to reach the bar windows from the appearance observer.
+static APP_HANDLE: OnceLock<AppHandle> = OnceLock::new();
+
+/// Material set with `stache bar material`, overriding `bar.material`.
+static MATERIAL_OVERRIDE: Mutex<Option<BarMaterial>> = Mutex::new(None);
+
+/// Returns the `NSVisualEffectMaterial` value of a material, or `None` for no material.
+const fn ns_material(material: BarMaterial) -> Option<i64> {
+    match material {
+        BarMaterial::None => None,
+        BarMaterial::Titlebar => Some(3),
+        BarMaterial::Menu => Some(5),
+        BarMaterial::Popover => Some(6),
+        BarMaterial::Sidebar => Some(7),
+        BarMaterial::HeaderView => Some(10),
+        BarMaterial::Sheet => Some(11),
+        BarMaterial::WindowBackground => Some(12),
+        BarMaterial::HudWindow => Some(13),
+        BarMaterial::FullScreenUi => Some(15),
+        BarMaterial::Tooltip => Some(17),
+        BarMaterial::ContentBackground => Some(18),
+        BarMaterial::UnderWindowBackground => Some(21),
+        BarMaterial::UnderPageBackground => Some(22),
+    }
+}
+
+/// Returns the material the bar windows currently use.
+fn current_material() -> BarMaterial {
+    MATERIAL_OVERRIDE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .unwrap_or(get_config().bar.material)
+}
+
+/// Applies the current material to a bar window. Must be called on the main thread.
+pub fn apply(window: &WebviewWindow) {
+    set_window_material(window, ns_material(current_material()), is_dark_appearance());
+}
+
+/// Applies the current material to every bar window. Must be called on the main thread.
+fn apply_all(app: &AppHandle) {
+    for window in bar_windows(app) {
+        apply(&window);
+    }
+}
+
+/// Changes the material of the bar windows until the app restarts.
+pub fn set_material(material: BarMaterial) {
+    *MATERIAL_OVERRIDE.lock().unwrap_or_else(PoisonError::into_inner) = Some(material);
+
+    let Some(app) = APP_HANDLE.get() else {
+        return;
+    };
+    if let Err(e) = app.run_on_main_thread(|| {
+        if let Some(app) = APP_HANDLE.get() {
+            apply_all(app);
+        }
+    }) {
+        tracing::warn!(error = %e, "failed to schedule bar material update");
+    }
+}
+
+/// Applies the material to the bar windows and re-applies it on appearance changes.
+///
+/// Must be called on the main thread, whose run loop delivers the appearance
+/// notifications.
+pub fn init(app: &AppHandle) {
+    apply_all(app);
+
+    if APP_HANDLE.set(app.clone()).is_err() {
+        return;
+    }
+
+    // SAFETY: We are adding an NSDistributedNotificationCenter observer:
+    // - The notification center is obtained via the standard defaultCenter method
+    // - The observer object is created once and never released, so it outlives
+    //   its registration
+    // - The notification name is a valid NSString created via nsstring()
+    unsafe {
+        let center: *mut Object = msg_send![class!(NSDistributedNotificationCenter), defaultCenter];
+        if center.is_null() {
+            tracing::error!("failed to get NSDistributedNotificationCenter for appearance changes");
+            return;
+        }
+
+        let observer = create_appearance_observer();
+        let _: () = msg_send![
+            center,
+            addObserver: observer
+            selector: sel!(appearanceChanged:)
+            name: nsstring(APPEARANCE_CHANGED_NOTIFICATION)
+            object: std::ptr::null::<Object>()
+        ];
+    }
+}
+
+/// Creates the Objective-C object observing appearance changes.
+///
+/// # Safety
+///
+/// Caller must ensure this is called within a valid Objective-C runtime context.
+unsafe fn create_appearance_observer() -> *mut Object {
+    let class_name = "StacheAppearanceObserver";
+
+    let observer_class = Class::get(class_name).unwrap_or_else(|| {
+        let mut decl = ClassDecl::new(class_name, class!(NSObject))
+            .expect("Failed to create StacheAppearanceObserver class");
+
+        unsafe {
+            decl.add_method(
+                sel!(appearanceChanged:),
+                handle_appearance_changed as extern "C" fn(&Object, Sel, *mut Object),
+            );
+        }
+
+        decl.register()
+    });
+
+    let instance: *mut Object = unsafe { msg_send![observer_class, alloc] };
+    unsafe { msg_send![instance, init] }
+}
+
+/// Handles the appearance change notification by re-applying the material.
+extern "C" fn handle_appearance_changed(_this: &Object, _cmd: Sel, _notification: *mut Object) {
+    if let Some(app) = APP_HANDLE.get() {
+        tracing::debug!(dark = is_dark_appearance(), "system appearance changed");
+        apply_all(app);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ns_material_maps_to_visual_effect_constants() {
+        assert_eq!(ns_material(BarMaterial::None), None);
+        assert_eq!(ns_material(BarMaterial::Titlebar), Some(3));
+        assert_eq!(ns_material(BarMaterial::Menu), Some(5));
+        assert_eq!(ns_material(BarMaterial::HudWindow), Some(13));
+        assert_eq!(ns_material(BarMaterial::UnderPageBackground), Some(22));
+    }
+}
@@ -1,5 +1,6 @@
 pub mod components;
 mod ipc_listener;
+mod material;
 mod menubar;
 mod screen;
 pub mod window;
@@ -17,17 +18,18 @@ pub fn init(app: &App) {
 
     let app_handle = app.app_handle().clone();
 
-    let Some(webview_window) = app_handle.get_webview_window("bar") else {
+    let Some(webview_window) = app_handle.get_webview_window(window::MAIN_BAR_LABEL) else {
         tracing::error!("'bar' window not found in tauri.conf.json");
         return;
     };
 
     set_window_sticky(&webview_window);
     set_window_below_menu(&webview_window);
-    window::set_window_position(&webview_window);
+    window::sync_screen_bars(&app_handle);
+    material::init(&app_handle);
 
-    let webview_watcher_clone = webview_window.clone();
-    screen::init_screen_watcher(move || window::set_window_position(&webview_watcher_clone));
+    let watcher_app_handle = app_handle.clone();
+    screen::init_screen_watcher(move || window::sync_screen_bars(&watcher_app_handle));
 
     menubar::start_menu_bar_visibility_watcher(&webview_window);
 
@@ -1,9 +1,28 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use core_graphics::display::CGDisplay;
+use core_graphics::geometry::CGPoint;
 use serde::Serialize;
-use tauri::Manager;
+use tauri::{
+    AppHandle, Emitter, Manager, Monitor, PhysicalPosition, Runtime, WebviewWindow,
+    WebviewWindowBuilder,
+};
 
-use crate::config::get_config;
+use super::material;
+use crate::config::{BarPosition, get_config};
 use crate::error::StacheError;
-use crate::platform::window::{get_screen_size, set_position};
+use crate::events;
+use crate::platform::window::{
+    get_screen_size, set_position, set_window_below_menu, set_window_sticky,
+};
+
+/// Label of the bar window on the main screen, defined in `tauri.conf.json`.
+pub const MAIN_BAR_LABEL: &str = "bar";
+
+/// Label prefix of the bar windows created for the other screens.
+const SCREEN_BAR_LABEL_PREFIX: &str = "bar-";
 
 #[derive(Debug, Clone, Copy, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -14,19 +33,261 @@ pub struct WindowFrame {
     pub height: f64,
 }
 
+/// Main bar window, once it has been positioned.
+static MAIN_BAR: OnceLock<WebviewWindow> = OnceLock::new();
+
+/// Whether the bar windows were hidden on demand.
+static BAR_HIDDEN: AtomicBool = AtomicBool::new(false);
+
+/// Display ID (`CGDirectDisplayID`) of the screen each bar window is shown
+/// on, keyed by window label.
+static BAR_SCREENS: Mutex<BTreeMap<String, u32>> = Mutex::new(BTreeMap::new());
+
 pub fn set_window_position(webview_window: &tauri::WebviewWindow) {
-    let Ok((logical_width, _)) = get_screen_size(webview_window) else {
+    let Ok((logical_width, logical_height)) = get_screen_size(webview_window) else {
         tracing::warn!("failed to get screen size for bar window positioning");
         return;
     };
+    let frame = window_frame(logical_width, logical_height);
+
+    set_position(webview_window, frame.x, frame.y, frame.width, frame.height);
+    let _ = MAIN_BAR.set(webview_window.clone());
+}
+
+/// Creates a bar window for every connected screen other than the main one,
+/// when `bar.perScreen` is on, and closes those whose screen is gone.
+///
+/// Positions the main bar and the screen bars on their screens. Meant to run
+/// at startup and whenever the display configuration changes.
+pub fn sync_screen_bars(app: &AppHandle) {
+    let Some(main_bar) = app.get_webview_window(MAIN_BAR_LABEL) else {
+        tracing::warn!("bar window not found");
+        return;
+    };
+    set_window_position(&main_bar);
+
+    let mut screens = BTreeMap::new();
+    let mut screen_bars = BTreeMap::new();
+    if get_config().bar.per_screen {
+        let primary = main_bar.primary_monitor().ok().flatten();
+        let monitors = main_bar.available_monitors().unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "failed to list screens for bar windows");
+            Vec::new()
+        });
+
+        if let Some(display_id) = primary.as_ref().and_then(display_id) {
+            screens.insert(MAIN_BAR_LABEL.to_string(), display_id);
+        }
+
+        for monitor in monitors {
+            if primary.as_ref().is_some_and(|primary| primary.position() == monitor.position()) {
+                continue;
+            }
+
+            let label = screen_bar_label(*monitor.position());
+            if let Some(display_id) = display_id(&monitor) {
+                screens.insert(label.clone(), display_id);
+            }
+            screen_bars.insert(label, monitor);
+        }
+    }
+
+    // Recorded before the bars are created, so they find their screen as they load
+    *BAR_SCREENS.lock().unwrap_or_else(PoisonError::into_inner) = screens;
+
+    for (label, monitor) in &screen_bars {
+        let window = match app.get_webview_window(label) {
+            Some(window) => window,
+            None => match create_screen_bar(app, label) {
+                Ok(window) => window,
+                Err(e) => {
+                    tracing::error!(error = %e, label, "failed to create bar window");
+                    continue;
+                }
+            },
+        };
+
+        let frame = monitor_window_frame(monitor);
+        set_position(&window, frame.x, frame.y, frame.width, frame.height);
+    }
+
+    for (label, window) in app.webview_windows() {
+        if label.starts_with(SCREEN_BAR_LABEL_PREFIX)
+            && !screen_bars.contains_key(&label)
+            && let Err(e) = window.destroy()
+        {
+            tracing::warn!(error = %e, label, "failed to close bar window");
+        }
+    }
+}
+
+/// Returns the display ID (`CGDirectDisplayID`) of a monitor.
+///
+/// Unlike the monitor name, which identical displays share, it tells every
+/// connected screen apart and matches the screen IDs used by tiling.
+fn display_id(monitor: &Monitor) -> Option<u32> {
+    let origin = monitor.position().to_logical::<f64>(monitor.scale_factor());
+    let point = CGPoint::new(origin.x + 1.0, origin.y + 1.0);
+    let (displays, _) = CGDisplay::displays_with_point(point, 1).ok()?;
+    displays.first().copied()
+}
+
+/// Returns the label of the bar window for the screen at a position.
+fn screen_bar_label(position: PhysicalPosition<i32>) -> String {
+    format!("{SCREEN_BAR_LABEL_PREFIX}{}x{}", position.x, position.y)
+}
+
+/// Creates a bar window with the same settings as the main one.
+fn create_screen_bar(app: &AppHandle, label: &str) -> Result<WebviewWindow, StacheError> {
+    let mut window_config = app
+        .config()
+        .app
+        .windows
+        .iter()
+        .find(|window| window.label == MAIN_BAR_LABEL)
+        .cloned()
+        .ok_or_else(|| StacheError::CommandError("'bar' window config not found".to_string()))?;
+    window_config.label = label.to_string();
+
+    let window = WebviewWindowBuilder::from_config(app, &window_config)
+        .and_then(|builder| builder.build())
+        .map_err(|e| StacheError::CommandError(e.to_string()))?;
+
+    // Called from the screen watcher thread, but AppKit must be used on the main thread
+    let native_window = window.clone();
+    if let Err(e) = app.run_on_main_thread(move || {
+        set_window_sticky(&native_window);
+        set_window_below_menu(&native_window);
+        material::apply(&native_window);
+    }) {
+        tracing::error!(error = %e, label, "failed to set up bar window");
+    }
+
+    if is_bar_visible()
+        && let Err(e) = window.show()
+    {
+        tracing::error!(error = %e, label, "failed to show bar window");
+    }
+
+    tracing::debug!(label, "created bar window");
+    Ok(window)
+}
+
+/// Returns the bar frame on a monitor, in logical desktop coordinates.
+fn monitor_window_frame(monitor: &Monitor) -> WindowFrame {
+    let scale = monitor.scale_factor();
+    let origin = monitor.position().to_logical::<f64>(scale);
+    let size = monitor.size().to_logical::<f64>(scale);
+    let frame = window_frame(size.width, size.height);
+
+    WindowFrame {
+        x: origin.x + frame.x,
+        y: origin.y + frame.y,
+        ..frame
+    }
+}
+
+/// Returns the bar windows, the main one first.
+pub fn bar_windows<R: Runtime>(app: &AppHandle<R>) -> Vec<WebviewWindow<R>> {
+    let mut windows: Vec<_> = app
+        .webview_windows()
+        .into_iter()
+        .filter(|(label, _)| label == MAIN_BAR_LABEL || label.starts_with(SCREEN_BAR_LABEL_PREFIX))
+        .collect();
+    windows.sort_by_key(|(label, _)| label != MAIN_BAR_LABEL);
+    windows.into_iter().map(|(_, window)| window).collect()
+}
+
+/// Returns whether the bar windows are shown, i.e. not hidden on demand.
+#[must_use]
+pub fn is_bar_visible() -> bool { !BAR_HIDDEN.load(Ordering::Acquire) }
+
+/// Shows or hides the bar windows.
+///
+/// Emits [`events::bar::VISIBILITY_CHANGED`] and has tiling re-apply the
+/// layout of the screens with a bar, so tiled windows grow into the space the
+/// bar frees up and shrink back once it returns. Does nothing if the bar
+/// already has the requested visibility.
+pub fn set_bar_visible<R: Runtime>(app: &AppHandle<R>, visible: bool) {
+    if BAR_HIDDEN.swap(!visible, Ordering::AcqRel) == !visible {
+        return;
+    }
+
+    for window in bar_windows(app) {
+        let result = if visible {
+            window.show()
+        } else {
+            window.hide()
+        };
+        if let Err(e) = result {
+            tracing::error!(error = %e, visible, "failed to change bar window visibility");
+        }
+    }
+
+    if let Err(e) = app.emit(events::bar::VISIBILITY_CHANGED, visible) {
+        tracing::warn!(error = %e, "failed to emit bar visibility");
+    }
+
+    if let Some(handle) = crate::modules::tiling::init::get_handle()
+        && let Err(e) = handle.bar_region_changed()
+    {
+        tracing::warn!(error = %e, "failed to notify tiling of bar visibility");
+    }
+}
+
+/// Returns the edge of a screen the bar is shown on and how far into the
+/// screen it reaches, or `None` if the screen has no bar or it is hidden.
+///
+/// Only the main screen has a bar, unless `bar.perScreen` is on. Measured on
+/// the actual frame of the main bar window, which every bar shares. Until the
+/// bar is positioned, the configured height and padding are used instead.
+#[must_use]
+pub fn reserved_region(is_main_screen: bool) -> Option<(BarPosition, f64)> {
+    let bar = &get_config().bar;
+    if !bar.is_enabled() || !is_bar_visible() || !(is_main_screen || bar.per_screen) {
+        return None;
+    }
+
+    let extent = MAIN_BAR.get().and_then(actual_frame).map_or_else(
+        || f64::from(bar.height) + f64::from(bar.padding),
+        |(frame, screen_height)| reserved_extent(&frame, screen_height, bar.position),
+    );
+
+    Some((bar.position, extent))
+}
+
+/// Returns the frame of a window relative to its screen, in logical points,
+/// with the logical height of that screen.
+fn actual_frame(window: &WebviewWindow) -> Option<(WindowFrame, f64)> {
+    let monitor = window.current_monitor().ok()??;
+    let scale = monitor.scale_factor();
+    let origin = monitor.position().to_logical::<f64>(scale);
+    let position = window.outer_position().ok()?.to_logical::<f64>(scale);
+    let size = window.outer_size().ok()?.to_logical::<f64>(scale);
+
+    let frame = WindowFrame {
+        x: position.x - origin.x,
+        y: position.y - origin.y,
+        width: size.width,
+        height: size.height,
+    };
+    Some((frame, f64::from(monitor.size().height) / scale))
+}
+
+/// Returns the bar frame on a screen of the given logical size.
+fn window_frame(logical_width: f64, logical_height: f64) -> WindowFrame {
     let config = get_config();
-    let (x, y, width, height) = calculate_window_frame(
-        logical_width,
-        f64::from(config.bar.height),
-        f64::from(config.bar.padding),
+    let (x, y, width, height) = place_on_edge(
+        calculate_window_frame(
+            logical_width,
+            f64::from(config.bar.height),
+            f64::from(config.bar.padding),
+        ),
+        logical_height,
+        config.bar.position,
     );
 
-    set_position(webview_window, x, y, width, height);
+    WindowFrame { x, y, width, height }
 }
 
 const fn calculate_window_frame(
@@ -40,6 +301,28 @@ const fn calculate_window_frame(
     (padding, padding, width, height)
 }
 
+/// Moves a frame calculated for the top edge to the bar's edge, keeping the
+/// same distance to it.
+const fn place_on_edge(
+    frame: (f64, f64, f64, f64),
+    logical_height: f64,
+    position: BarPosition,
+) -> (f64, f64, f64, f64) {
+    let (x, y, width, height) = frame;
+    match position {
+        BarPosition::Top => frame,
+        BarPosition::Bottom => (x, logical_height - y - height, width, height),
+    }
+}
+
+/// Returns how far the bar reaches into the screen from its edge.
+const fn reserved_extent(frame: &WindowFrame, screen_height: f64, position: BarPosition) -> f64 {
+    match position {
+        BarPosition::Top => frame.y + frame.height,
+        BarPosition::Bottom => screen_height - frame.y,
+    }
+}
+
 /// Gets the current bar window frame dimensions.
 ///
 /// # Errors
@@ -51,16 +334,26 @@ pub fn get_bar_window_frame(app: tauri::AppHandle) -> Result<WindowFrame, Stache
     let window = app
         .get_webview_window("bar")
         .ok_or_else(|| StacheError::CommandError("Failed to get bar window".to_string()))?;
-    let (screen_width, _screen_height) = get_screen_size(&window)
+    let (screen_width, screen_height) = get_screen_size(&window)
         .map_err(|_| StacheError::CommandError("Failed to get screen size".to_string()))?;
-    let config = get_config();
-    let (x, y, width, height) = calculate_window_frame(
-        screen_width,
-        f64::from(config.bar.height),
-        f64::from(config.bar.padding),
-    );
 
-    Ok(WindowFrame { x, y, width, height })
+    Ok(window_frame(screen_width, screen_height))
+}
+
+/// Gets the display ID of the screen the calling bar window shows the
+/// workspaces of, or `None` when a single bar shows those of every screen.
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)]
+pub fn get_bar_screen(window: tauri::WebviewWindow) -> Option<u32> {
+    if !get_config().bar.per_screen {
+        return None;
+    }
+
+    BAR_SCREENS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(window.label())
+        .copied()
 }
 
 #[cfg(test)]
@@ -128,4 +421,22 @@ mod tests {
         assert!((width - 2.0f64.mul_add(-custom_padding, logical_width)).abs() < f64::EPSILON);
         assert!((height - custom_height).abs() < f64::EPSILON);
     }
+
+    #[test]
+    fn bottom_bar_reserves_the_same_extent_from_the_bottom_edge() {
+        let top = calculate_window_frame(1920.0, TEST_BAR_HEIGHT, TEST_PADDING);
+        let (x, y, width, height) = place_on_edge(top, 1080.0, BarPosition::Bottom);
+
+        assert!((x - TEST_PADDING).abs() < f64::EPSILON);
+        assert!((y - (1080.0 - TEST_PADDING - TEST_BAR_HEIGHT)).abs() < f64::EPSILON);
+
+        let frame = WindowFrame { x, y, width, height };
+        let extent = reserved_extent(&frame, 1080.0, BarPosition::Bottom);
+        assert!((extent - (TEST_BAR_HEIGHT + TEST_PADDING)).abs() < f64::EPSILON);
+
+        let (x, y, width, height) = top;
+        let frame = WindowFrame { x, y, width, height };
+        let extent = reserved_extent(&frame, 1080.0, BarPosition::Top);
+        assert!((extent - (TEST_BAR_HEIGHT + TEST_PADDING)).abs() < f64::EPSILON);
+    }
 }
@@ -4,11 +4,14 @@
 //! the ⌘Q key combination for a configurable duration before quitting the frontmost
 //! application. If the user only taps ⌘Q, an alert message is displayed instead.
 //!
+//! The behavior can be limited to (or bypassed for) specific apps by bundle
+//! identifier; in apps where it does not apply, ⌘Q quits instantly.
+//!
 //! This is a Rust implementation inspired by the Hammerspoon `HoldToQuit` Spoon.
 
 use std::ffi::c_void;
-use std::sync::Mutex;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
 use std::time::{Duration, Instant};
 
 use core_foundation::base::TCFType;
@@ -20,12 +23,13 @@ use tauri::Emitter;
 
 use crate::config::CommandQuitConfig;
 use crate::events;
+use crate::platform::objc::get_app_bundle_id;
 
-/// Default hold duration in milliseconds (used as fallback).
-const DEFAULT_HOLD_DURATION_MS: u64 = 1500;
+/// Default hold duration in seconds (used as fallback).
+const DEFAULT_HOLD_DURATION_SECS: f64 = 1.5;
 
-/// Configured hold duration in milliseconds (set at init time).
-static HOLD_DURATION_MS: AtomicU64 = AtomicU64::new(DEFAULT_HOLD_DURATION_MS);
+/// Configuration captured at init time (hold duration and per-app rules).
+static CONFIG: OnceLock<CommandQuitConfig> = OnceLock::new();
 
 /// Polling interval when idle (not tracking a key press).
 const IDLE_POLL_MS: u64 = 100;
@@ -100,6 +104,9 @@ static HOLD_STATE: Mutex<HoldState> = Mutex::new(HoldState {
 /// Flag to signal the timer thread to check for quit.
 static CHECK_QUIT: AtomicBool = AtomicBool::new(false);
 
+/// Whether the current ⌘Q press is passed through to the frontmost app.
+static PASS_THROUGH: AtomicBool = AtomicBool::new(false);
+
 /// Flag indicating if the module is running.
 static IS_RUNNING: AtomicBool = AtomicBool::new(false);
 
@@ -121,10 +128,12 @@ pub fn init(app_handle: tauri::AppHandle, config: &CommandQuitConfig) {
         return;
     }
 
-    // Store the configured hold duration
-    HOLD_DURATION_MS.store(config.hold_duration, Ordering::SeqCst);
+    // Store the configured hold duration and per-app rules
+    let _ = CONFIG.set(config.clone());
     tracing::debug!(
         hold_duration_ms = config.hold_duration,
+        apply_to = config.apply_to.len(),
+        bypass = config.bypass.len(),
         "cmd_q: configured hold duration"
     );
 
@@ -147,8 +156,18 @@ pub fn init(app_handle: tauri::AppHandle, config: &CommandQuitConfig) {
 }
 
 /// Returns the configured hold duration in seconds.
-#[allow(clippy::cast_precision_loss)] // Precision loss is negligible for millisecond values
-fn hold_duration_secs() -> f64 { HOLD_DURATION_MS.load(Ordering::SeqCst) as f64 / 1000.0 }
+fn hold_duration_secs() -> f64 {
+    CONFIG
+        .get()
+        .map_or(DEFAULT_HOLD_DURATION_SECS, CommandQuitConfig::hold_duration_secs)
+}
+
+/// Returns whether hold-to-quit applies to the frontmost application.
+fn applies_to_frontmost_app() -> bool {
+    CONFIG
+        .get()
+        .is_none_or(|config| config.applies_to(get_frontmost_app_bundle_id().as_deref()))
+}
 
 /// Main timer loop that checks if the ⌘Q key has been held long enough.
 ///
@@ -270,11 +289,22 @@ extern "C" fn event_tap_callback(
 
     match event_type {
         K_CG_EVENT_KEY_DOWN => {
+            // Let ⌘Q through untouched for apps where hold-to-quit does not apply
+            let pass_through = !applies_to_frontmost_app();
+            PASS_THROUGH.store(pass_through, Ordering::SeqCst);
+            if pass_through {
+                return event;
+            }
+
             on_key_down();
             // Return null to suppress the event (prevent normal ⌘Q behavior)
             std::ptr::null_mut()
         }
         K_CG_EVENT_KEY_UP => {
+            if PASS_THROUGH.swap(false, Ordering::SeqCst) {
+                return event;
+            }
+
             on_key_up();
             // Return null to suppress the event
             std::ptr::null_mut()
@@ -318,6 +348,21 @@ fn on_key_up() {
     }
 }
 
+/// Gets the bundle identifier of the frontmost application.
+fn get_frontmost_app_bundle_id() -> Option<String> {
+    unsafe {
+        let workspace_class = Class::get("NSWorkspace")?;
+        let workspace: *mut Object = msg_send![workspace_class, sharedWorkspace];
+
+        if workspace.is_null() {
+            return None;
+        }
+
+        let frontmost_app: *mut Object = msg_send![workspace, frontmostApplication];
+        get_app_bundle_id(frontmost_app)
+    }
+}
+
 /// Gets the name of the frontmost application.
 fn get_frontmost_app_name() -> Option<String> {
     unsafe {
@@ -446,6 +491,13 @@ mod tests {
         assert!(state.quit_triggered);
     }
 
+    #[test]
+    fn test_hold_duration_defaults_without_config() {
+        if CONFIG.get().is_none() {
+            assert!((hold_duration_secs() - DEFAULT_HOLD_DURATION_SECS).abs() < f64::EPSILON);
+        }
+    }
+
     #[test]
     fn test_is_running_atomic() {
         // Test that IS_RUNNING is an atomic that can be read
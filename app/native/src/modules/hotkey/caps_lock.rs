@@ -629,7 +629,7 @@ unsafe fn mark_synthetic_caps_event(event: CGEventRef) {
     }
 }
 
-fn keycode_for_name(key_name: &str) -> Option<i64> {
+pub(super) fn keycode_for_name(key_name: &str) -> Option<i64> {
     let normalized = key_name.to_ascii_uppercase();
     match normalized.as_str() {
         "A" => Some(0),
@@ -0,0 +1,525 @@
+//! Prefix chords for keybindings.
+//!
+//! A keybinding whose shortcut contains ` then ` is a chord, e.g.
+//! `"Command+Space then W then 1": "stache tiling workspace --focus 1"`.
+//! The first segment (the leader) is registered as a regular global shortcut.
+//! Once it is pressed, the following keys are captured by an event tap until
+//! the sequence matches a chord, no chord can match anymore, Escape is pressed,
+//! or the chord times out. Captured keys never reach the focused application.
+
+use std::collections::HashMap;
+use std::ffi::c_void;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+use std::sync::{LazyLock, Mutex, PoisonError};
+use std::time::Duration;
+
+use core_foundation::base::TCFType;
+use core_foundation::mach_port::CFMachPort;
+use core_foundation::runloop::{CFRunLoop, kCFRunLoopCommonModes};
+use tauri_plugin_global_shortcut::Shortcut;
+
+use super::caps_lock::keycode_for_name;
+use super::{execute_shortcut_commands, normalize_shortcut};
+use crate::config::ShortcutCommands;
+use crate::config::types::hotkeys::CHORD_SEPARATOR;
+
+type CGEventRef = *mut c_void;
+type CGEventTapProxy = *mut c_void;
+type CFMachPortRef = *mut c_void;
+
+type CGEventTapCallBack = extern "C" fn(
+    proxy: CGEventTapProxy,
+    event_type: u32,
+    event: CGEventRef,
+    user_info: *mut c_void,
+) -> CGEventRef;
+
+#[link(name = "CoreGraphics", kind = "framework")]
+unsafe extern "C" {
+    fn CGEventTapCreate(
+        tap: u32,
+        place: u32,
+        options: u32,
+        events_of_interest: u64,
+        callback: CGEventTapCallBack,
+        user_info: *mut c_void,
+    ) -> CFMachPortRef;
+
+    fn CGEventTapEnable(tap: CFMachPortRef, enable: bool);
+    fn CGEventGetIntegerValueField(event: CGEventRef, field: u32) -> i64;
+}
+
+const K_CG_HID_EVENT_TAP: u32 = 0;
+const K_CG_HEAD_INSERT_EVENT_TAP: u32 = 0;
+const K_CG_EVENT_TAP_OPTION_DEFAULT: u32 = 0;
+const K_CG_EVENT_KEY_DOWN: u32 = 10;
+const K_CG_EVENT_KEY_UP: u32 = 11;
+const K_CG_EVENT_TAP_DISABLED_BY_TIMEOUT: u32 = 0xFFFF_FFFE;
+const K_CG_KEYBOARD_EVENT_AUTOREPEAT: u32 = 8;
+const K_CG_KEYBOARD_EVENT_KEYCODE: u32 = 9;
+const KEY_ESCAPE: i64 = 53;
+
+/// How long a chord waits for the next key before it is cancelled.
+const CHORD_TIMEOUT: Duration = Duration::from_millis(1500);
+
+static BINDINGS: LazyLock<Mutex<ChordBindings>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+static STATE: LazyLock<Mutex<ChordState>> = LazyLock::new(|| Mutex::new(ChordState::default()));
+static EVENT_TAP: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+static INITIALIZED: AtomicBool = AtomicBool::new(false);
+
+/// Chords grouped by their leader shortcut.
+pub(super) type ChordBindings = HashMap<Shortcut, Vec<Chord>>;
+
+/// A single chord: the keys pressed after the leader and the commands to run.
+#[derive(Debug, Clone)]
+pub(super) struct Chord {
+    pub raw_shortcut: String,
+    pub keys: Vec<i64>,
+    pub commands: ShortcutCommands,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum ChordError {
+    MissingKey,
+    InvalidLeader(String),
+    UnknownKey(String),
+}
+
+impl std::fmt::Display for ChordError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingKey => formatter.write_str("missing key after chord leader"),
+            Self::InvalidLeader(err) => write!(formatter, "invalid chord leader: {err}"),
+            Self::UnknownKey(key) => write!(formatter, "unknown chord key: {key}"),
+        }
+    }
+}
+
+/// Returns true if the keybinding shortcut describes a chord.
+pub(super) fn is_chord(shortcut: &str) -> bool { shortcut.contains(CHORD_SEPARATOR) }
+
+/// Parses a chord shortcut into its leader and the keys that follow it.
+pub(super) fn parse_chord(shortcut: &str) -> Result<(Shortcut, Vec<i64>), ChordError> {
+    let mut segments = shortcut.split(CHORD_SEPARATOR).map(str::trim);
+    let leader = segments.next().unwrap_or_default();
+    let leader = normalize_shortcut(leader)
+        .parse::<Shortcut>()
+        .map_err(|err| ChordError::InvalidLeader(err.to_string()))?;
+
+    let keys = segments
+        .filter(|key| !key.is_empty())
+        .map(|key| keycode_for_name(key).ok_or_else(|| ChordError::UnknownKey(key.to_string())))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if keys.is_empty() {
+        return Err(ChordError::MissingKey);
+    }
+
+    Ok((leader, keys))
+}
+
+/// Groups the chord keybindings by leader, skipping invalid definitions.
+pub(super) fn collect_chords(keybindings: &HashMap<String, ShortcutCommands>) -> ChordBindings {
+    let mut chords: ChordBindings = HashMap::new();
+    let mut sorted_keybindings: Vec<_> =
+        keybindings.iter().filter(|(shortcut, _)| is_chord(shortcut)).collect();
+
+    sorted_keybindings.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+    for (shortcut_key, commands) in sorted_keybindings {
+        match parse_chord(shortcut_key) {
+            Ok((leader, keys)) => chords.entry(leader).or_default().push(Chord {
+                raw_shortcut: shortcut_key.clone(),
+                keys,
+                commands: commands.clone(),
+            }),
+            Err(err) => {
+                tracing::warn!(shortcut = %shortcut_key, error = %err, "invalid chord");
+            }
+        }
+    }
+
+    chords
+}
+
+/// Stores the chord bindings and starts the key capture event tap.
+pub(super) fn start(bindings: ChordBindings) -> bool {
+    if bindings.is_empty() {
+        return false;
+    }
+
+    *BINDINGS.lock().unwrap_or_else(PoisonError::into_inner) = bindings;
+
+    if INITIALIZED.swap(true, Ordering::SeqCst) {
+        return true;
+    }
+
+    std::thread::Builder::new()
+        .name("stache-hotkey-chords".into())
+        .spawn(start_event_tap)
+        .map_or_else(
+            |err| {
+                tracing::warn!(error = %err, "failed to spawn chord event tap thread");
+                INITIALIZED.store(false, Ordering::SeqCst);
+                false
+            },
+            |_| true,
+        )
+}
+
+/// Starts capturing keys for the chords of the given leader.
+pub(super) fn begin(leader: Shortcut) {
+    if !INITIALIZED.load(Ordering::SeqCst) {
+        return;
+    }
+
+    let generation = STATE.lock().unwrap_or_else(PoisonError::into_inner).begin(leader);
+    tracing::debug!(leader = ?leader, "chord started");
+
+    if let Err(err) =
+        std::thread::Builder::new()
+            .name("stache-hotkey-chord-timeout".into())
+            .spawn(move || {
+                std::thread::sleep(CHORD_TIMEOUT);
+                if STATE.lock().unwrap_or_else(PoisonError::into_inner).expire(generation) {
+                    tracing::debug!(leader = ?leader, "chord timed out");
+                }
+            })
+    {
+        tracing::warn!(error = %err, "failed to spawn chord timeout thread");
+    }
+}
+
+fn start_event_tap() {
+    unsafe {
+        let event_mask = (1u64 << K_CG_EVENT_KEY_DOWN) | (1u64 << K_CG_EVENT_KEY_UP);
+
+        let tap = CGEventTapCreate(
+            K_CG_HID_EVENT_TAP,
+            K_CG_HEAD_INSERT_EVENT_TAP,
+            K_CG_EVENT_TAP_OPTION_DEFAULT,
+            event_mask,
+            event_tap_callback,
+            ptr::null_mut(),
+        );
+
+        if tap.is_null() {
+            tracing::warn!("failed to create chord event tap - check accessibility permissions");
+            INITIALIZED.store(false, Ordering::SeqCst);
+            return;
+        }
+
+        EVENT_TAP.store(tap, Ordering::SeqCst);
+
+        let tap_port = CFMachPort::wrap_under_create_rule(tap.cast());
+        let Ok(run_loop_source) = tap_port.create_runloop_source(0) else {
+            tracing::warn!("failed to create chord event tap run loop source");
+            EVENT_TAP.store(ptr::null_mut(), Ordering::SeqCst);
+            INITIALIZED.store(false, Ordering::SeqCst);
+            return;
+        };
+
+        let run_loop = CFRunLoop::get_current();
+        run_loop.add_source(&run_loop_source, kCFRunLoopCommonModes);
+        CGEventTapEnable(tap, true);
+        tracing::debug!("chord event tap initialized");
+        CFRunLoop::run_current();
+    }
+}
+
+extern "C" fn event_tap_callback(
+    _proxy: CGEventTapProxy,
+    event_type: u32,
+    event: CGEventRef,
+    _user_info: *mut c_void,
+) -> CGEventRef {
+    if event_type >= K_CG_EVENT_TAP_DISABLED_BY_TIMEOUT {
+        let tap = EVENT_TAP.load(Ordering::SeqCst);
+        if !tap.is_null() {
+            unsafe { CGEventTapEnable(tap, true) };
+        }
+        return event;
+    }
+
+    if event.is_null() {
+        return event;
+    }
+
+    let keycode = unsafe { CGEventGetIntegerValueField(event, K_CG_KEYBOARD_EVENT_KEYCODE) };
+
+    let step = {
+        let mut state = STATE.lock().unwrap_or_else(PoisonError::into_inner);
+        if event_type == K_CG_EVENT_KEY_DOWN {
+            let is_repeat =
+                unsafe { CGEventGetIntegerValueField(event, K_CG_KEYBOARD_EVENT_AUTOREPEAT) != 0 };
+            let bindings = BINDINGS.lock().unwrap_or_else(PoisonError::into_inner);
+            state.handle_key_down(keycode, is_repeat, &bindings)
+        } else {
+            state.handle_key_up(keycode)
+        }
+    };
+
+    match step {
+        ChordStep::Pass => event,
+        ChordStep::Suppress => ptr::null_mut(),
+        ChordStep::Execute(chord) => {
+            tracing::debug!(shortcut = %chord.raw_shortcut, "chord completed");
+            execute_shortcut_commands(&chord.commands);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Result of feeding a key event into the chord state machine.
+#[derive(Debug)]
+enum ChordStep {
+    /// Not part of a chord; deliver the event.
+    Pass,
+    /// Captured by a pending chord; swallow the event.
+    Suppress,
+    /// The chord matched; swallow the event and run its commands.
+    Execute(Chord),
+}
+
+#[derive(Debug)]
+struct PendingChord {
+    leader: Shortcut,
+    keys: Vec<i64>,
+}
+
+#[derive(Debug, Default)]
+struct ChordState {
+    pending: Option<PendingChord>,
+    /// Keys whose key-down was swallowed, so their key-up is swallowed too.
+    suppressed_key_ups: Vec<i64>,
+    /// Incremented on every leader press so stale timeouts are ignored.
+    generation: u64,
+}
+
+impl ChordState {
+    fn begin(&mut self, leader: Shortcut) -> u64 {
+        self.generation = self.generation.wrapping_add(1);
+        self.pending = Some(PendingChord { leader, keys: Vec::new() });
+        self.generation
+    }
+
+    /// Cancels the pending chord if it was started by `generation`.
+    fn expire(&mut self, generation: u64) -> bool {
+        if self.generation != generation || self.pending.is_none() {
+            return false;
+        }
+
+        self.pending = None;
+        true
+    }
+
+    fn handle_key_down(
+        &mut self,
+        key: i64,
+        is_repeat: bool,
+        bindings: &ChordBindings,
+    ) -> ChordStep {
+        let Some(pending) = self.pending.as_mut() else {
+            return ChordStep::Pass;
+        };
+
+        if !self.suppressed_key_ups.contains(&key) {
+            self.suppressed_key_ups.push(key);
+        }
+
+        if is_repeat {
+            return ChordStep::Suppress;
+        }
+
+        if key == KEY_ESCAPE {
+            self.pending = None;
+            return ChordStep::Suppress;
+        }
+
+        pending.keys.push(key);
+
+        let chords = bindings.get(&pending.leader).map_or(&[][..], Vec::as_slice);
+
+        if let Some(chord) = chords.iter().find(|chord| chord.keys == pending.keys) {
+            let chord = chord.clone();
+            self.pending = None;
+            return ChordStep::Execute(chord);
+        }
+
+        if !chords.iter().any(|chord| chord.keys.starts_with(&pending.keys)) {
+            // No chord continues with this key
+            self.pending = None;
+        }
+
+        ChordStep::Suppress
+    }
+
+    fn handle_key_up(&mut self, key: i64) -> ChordStep {
+        match self.suppressed_key_ups.iter().position(|suppressed| *suppressed == key) {
+            Some(index) => {
+                self.suppressed_key_ups.swap_remove(index);
+                ChordStep::Suppress
+            }
+            None => ChordStep::Pass,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY_W: i64 = 13;
+    const KEY_1: i64 = 18;
+    const KEY_2: i64 = 19;
+    const KEY_Q: i64 = 12;
+
+    fn leader() -> Shortcut { "Command+Space".parse().unwrap() }
+
+    fn bindings() -> ChordBindings {
+        collect_chords(&HashMap::from([
+            (
+                "Cmd+Space then W then 1".to_string(),
+                ShortcutCommands::Single("stache tiling workspace --focus 1".to_string()),
+            ),
+            (
+                "Cmd+Space then W then 2".to_string(),
+                ShortcutCommands::Single("stache tiling workspace --focus 2".to_string()),
+            ),
+            (
+                "Cmd+Space then Q".to_string(),
+                ShortcutCommands::Single("stache reload".to_string()),
+            ),
+        ]))
+    }
+
+    fn executed(step: &ChordStep) -> Option<&str> {
+        match step {
+            ChordStep::Execute(chord) => Some(chord.raw_shortcut.as_str()),
+            ChordStep::Pass | ChordStep::Suppress => None,
+        }
+    }
+
+    #[test]
+    fn test_is_chord() {
+        assert!(is_chord("Command+Space then W"));
+        assert!(!is_chord("Command+Space"));
+    }
+
+    #[test]
+    fn test_parse_chord() {
+        let (parsed_leader, keys) = parse_chord("Cmd+Space then W then 1").unwrap();
+        assert_eq!(parsed_leader, leader());
+        assert_eq!(keys, vec![KEY_W, KEY_1]);
+    }
+
+    #[test]
+    fn test_parse_chord_rejects_unknown_key() {
+        assert_eq!(
+            parse_chord("Cmd+Space then Shift+W"),
+            Err(ChordError::UnknownKey("Shift+W".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_chord_rejects_missing_key() {
+        assert_eq!(parse_chord("Cmd+Space then "), Err(ChordError::MissingKey));
+    }
+
+    #[test]
+    fn test_collect_chords_groups_by_leader() {
+        let chords = bindings();
+        assert_eq!(chords.len(), 1);
+        assert_eq!(chords[&leader()].len(), 3);
+    }
+
+    #[test]
+    fn test_keys_pass_without_pending_chord() {
+        let mut state = ChordState::default();
+        assert!(matches!(
+            state.handle_key_down(KEY_W, false, &bindings()),
+            ChordStep::Pass
+        ));
+        assert!(matches!(state.handle_key_up(KEY_W), ChordStep::Pass));
+    }
+
+    #[test]
+    fn test_multi_key_chord_executes() {
+        let bindings = bindings();
+        let mut state = ChordState::default();
+        state.begin(leader());
+
+        assert!(matches!(
+            state.handle_key_down(KEY_W, false, &bindings),
+            ChordStep::Suppress
+        ));
+        let step = state.handle_key_down(KEY_2, false, &bindings);
+
+        assert_eq!(executed(&step), Some("Cmd+Space then W then 2"));
+        assert!(state.pending.is_none());
+    }
+
+    #[test]
+    fn test_single_key_chord_executes() {
+        let mut state = ChordState::default();
+        state.begin(leader());
+
+        let step = state.handle_key_down(KEY_Q, false, &bindings());
+        assert_eq!(executed(&step), Some("Cmd+Space then Q"));
+    }
+
+    #[test]
+    fn test_unmatched_key_cancels_chord() {
+        let bindings = bindings();
+        let mut state = ChordState::default();
+        state.begin(leader());
+
+        assert!(matches!(
+            state.handle_key_down(KEY_1, false, &bindings),
+            ChordStep::Suppress
+        ));
+        assert!(state.pending.is_none());
+        assert!(matches!(
+            state.handle_key_down(KEY_W, false, &bindings),
+            ChordStep::Pass
+        ));
+    }
+
+    #[test]
+    fn test_escape_cancels_chord() {
+        let bindings = bindings();
+        let mut state = ChordState::default();
+        state.begin(leader());
+
+        state.handle_key_down(KEY_W, false, &bindings);
+        assert!(matches!(
+            state.handle_key_down(KEY_ESCAPE, false, &bindings),
+            ChordStep::Suppress
+        ));
+        assert!(state.pending.is_none());
+    }
+
+    #[test]
+    fn test_swallowed_key_up_is_suppressed_once() {
+        let mut state = ChordState::default();
+        state.begin(leader());
+
+        state.handle_key_down(KEY_W, false, &bindings());
+        assert!(matches!(state.handle_key_up(KEY_W), ChordStep::Suppress));
+        assert!(matches!(state.handle_key_up(KEY_W), ChordStep::Pass));
+    }
+
+    #[test]
+    fn test_expire_cancels_current_generation_only() {
+        let mut state = ChordState::default();
+        let first = state.begin(leader());
+        let second = state.begin(leader());
+
+        assert!(!state.expire(first));
+        assert!(state.pending.is_some());
+        assert!(state.expire(second));
+        assert!(state.pending.is_none());
+        assert!(!state.expire(second));
+    }
+}
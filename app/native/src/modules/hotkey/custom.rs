@@ -0,0 +1,76 @@
+//! Shell commands bound from the `hotkeys.custom` config section.
+//!
+//! Each key combination runs its command with `sh -c` through the shell
+//! plugin. Commands are spawned without waiting for them, and a non-zero exit
+//! is logged once the command finishes.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+use tauri::{AppHandle, Runtime};
+use tauri_plugin_global_shortcut::Shortcut;
+use tauri_plugin_shell::ShellExt;
+use tauri_plugin_shell::process::CommandEvent;
+
+use super::{register_shortcut, unregister_shortcuts};
+
+/// Shortcuts currently registered from the `hotkeys.custom` section.
+static CUSTOM_SHORTCUTS: LazyLock<Mutex<Vec<Shortcut>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
+/// Unregisters the shortcuts registered by [`register`].
+pub fn unregister<R: Runtime>(app: &AppHandle<R>) { unregister_shortcuts(app, &CUSTOM_SHORTCUTS); }
+
+/// Registers the shell command shortcuts from the `hotkeys.custom` section.
+///
+/// Call [`unregister`] first when applying a reloaded configuration.
+pub fn register<R: Runtime>(app: &AppHandle<R>, custom: &HashMap<String, String>) {
+    let mut sorted_hotkeys: Vec<_> = custom.iter().collect();
+    sorted_hotkeys.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+    for (combo, command) in sorted_hotkeys {
+        let combo_owned = combo.clone();
+        let command_owned = command.clone();
+        register_shortcut(app, &CUSTOM_SHORTCUTS, "custom", command, combo, move |app| {
+            run(app, &combo_owned, &command_owned);
+        });
+    }
+
+    let count = CUSTOM_SHORTCUTS.lock().unwrap_or_else(PoisonError::into_inner).len();
+    if count > 0 {
+        tracing::info!(count, "registered custom hotkeys");
+    }
+}
+
+/// Spawns a custom hotkey command and logs how it exits.
+fn run<R: Runtime>(app: &AppHandle<R>, combo: &str, command: &str) {
+    let (mut rx, _child) = match app.shell().command("/bin/sh").args(["-c", command]).spawn() {
+        Ok(spawned) => spawned,
+        Err(err) => {
+            tracing::error!(shortcut = %combo, command = %command, error = %err, "failed to run custom hotkey command");
+            return;
+        }
+    };
+
+    let command = command.to_string();
+    tauri::async_runtime::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            match event {
+                CommandEvent::Terminated(payload) if payload.code == Some(0) => {
+                    tracing::trace!(command = %command, "custom hotkey command completed successfully");
+                }
+                CommandEvent::Terminated(payload) => {
+                    tracing::warn!(
+                        command = %command,
+                        code = ?payload.code,
+                        signal = ?payload.signal,
+                        "custom hotkey command exited with non-zero status"
+                    );
+                }
+                CommandEvent::Error(err) => {
+                    tracing::warn!(command = %command, error = %err, "custom hotkey command failed");
+                }
+                _ => {}
+            }
+        }
+    });
+}
@@ -5,20 +5,41 @@
 //!
 //! The daemon reads its configuration from the global Stache configuration file
 //! and uses Tauri's global-shortcut plugin to register system-wide hotkeys.
+//!
+//! Keybindings may also be prefix chords such as `"Command+Space then W then 1"`:
+//! the leader is registered as a global shortcut and the keys that follow are
+//! captured until the chord matches, is cancelled, or times out.
+//!
+//! Besides `keybindings`, which run shell or CLI commands, the `hotkeys` section
+//! binds tiling actions directly to the tiling manager, and under `custom` binds
+//! shell commands run through the shell plugin. Hotkeys are re-registered when
+//! the configuration file changes, without a restart.
+//!
+//! Move mode, started with `stache tiling window --move-mode`, captures the
+//! arrow keys to move the focused window until Enter, Escape or a timeout.
 
 mod caps_lock;
+mod chord;
+mod custom;
+mod move_mode;
+mod tiling_actions;
 
 use std::collections::HashMap;
 use std::process::Command;
+use std::sync::{LazyLock, Mutex, PoisonError};
 
 use tauri::{AppHandle, Runtime};
 use tauri_plugin_global_shortcut::{Builder, GlobalShortcutExt, Shortcut, ShortcutState};
 
-use crate::config::{ShortcutCommands, get_config};
+use self::tiling_actions::TilingAction;
+use crate::config::{ShortcutCommands, get_config, register_live_reload};
 use crate::platform::command::resolve_binary;
 
 type PlannedShortcutMap = HashMap<Shortcut, (String, String, ShortcutCommands)>;
 
+/// Shortcuts currently registered from the `hotkeys` section.
+static TILING_SHORTCUTS: LazyLock<Mutex<Vec<Shortcut>>> = LazyLock::new(|| Mutex::new(Vec::new()));
+
 /// Creates the global-shortcut plugin.
 ///
 /// Configured shortcuts are registered separately during application setup via
@@ -33,14 +54,96 @@ pub fn create_hotkey_plugin<R: Runtime>() -> tauri::plugin::TauriPlugin<R> {
     Builder::<R>::new().build()
 }
 
+/// Starts keyboard move mode for the focused window.
+///
+/// Blocks while the focused window is looked up, so call it off the main thread.
+pub fn start_move_mode() { move_mode::begin(); }
+
 /// Registers configured global shortcuts after the plugin has initialized.
 ///
 /// Registration is performed one shortcut at a time so a single unavailable macOS
 /// hotkey does not abort application startup.
 pub fn register_configured_hotkeys<R: Runtime>(app: &AppHandle<R>) {
     let config = get_config();
-    let keybindings = &config.keybindings;
 
+    register_keybindings(app, &config.keybindings);
+    register_hotkeys(app, &config.hotkeys.actions, &config.hotkeys.custom);
+
+    let handle = app.clone();
+    register_live_reload("hotkeys", move |config| {
+        register_hotkeys(&handle, &config.hotkeys.actions, &config.hotkeys.custom);
+        true
+    });
+}
+
+/// Registers the tiling action and custom command shortcuts from the
+/// `hotkeys` section.
+///
+/// Both sets registered by a previous call are unregistered before any is
+/// registered again, so a reloaded configuration can move a combination from
+/// one set to the other.
+fn register_hotkeys<R: Runtime>(
+    app: &AppHandle<R>,
+    actions: &HashMap<String, String>,
+    custom: &HashMap<String, String>,
+) {
+    unregister_shortcuts(app, &TILING_SHORTCUTS);
+    custom::unregister(app);
+
+    register_tiling_hotkeys(app, actions);
+    custom::register(app, custom);
+}
+
+/// Unregisters every shortcut recorded in `shortcuts`.
+fn unregister_shortcuts<R: Runtime>(app: &AppHandle<R>, shortcuts: &Mutex<Vec<Shortcut>>) {
+    let global_shortcut = app.global_shortcut();
+    for shortcut in shortcuts.lock().unwrap_or_else(PoisonError::into_inner).drain(..) {
+        if let Err(err) = global_shortcut.unregister(shortcut) {
+            tracing::warn!(error = %err, "failed to unregister hotkey");
+        }
+    }
+}
+
+/// Registers `combo` to call `handler` when pressed, recording it in `shortcuts`.
+///
+/// `kind` and `target` only describe the hotkey in logs, e.g. `"tiling"` and the
+/// action it runs.
+fn register_shortcut<R: Runtime>(
+    app: &AppHandle<R>,
+    shortcuts: &Mutex<Vec<Shortcut>>,
+    kind: &str,
+    target: &str,
+    combo: &str,
+    handler: impl Fn(&AppHandle<R>) + Send + Sync + 'static,
+) {
+    let shortcut = match normalize_shortcut(combo).parse::<Shortcut>() {
+        Ok(shortcut) => shortcut,
+        Err(err) => {
+            tracing::warn!(kind, target, shortcut = %combo, error = %err, "invalid hotkey");
+            return;
+        }
+    };
+
+    match app.global_shortcut().on_shortcut(shortcut, move |app, _shortcut, event| {
+        if event.state == ShortcutState::Pressed {
+            handler(app);
+        }
+    }) {
+        Ok(()) => {
+            shortcuts.lock().unwrap_or_else(PoisonError::into_inner).push(shortcut);
+            tracing::debug!(kind, target, shortcut = %combo, "registered hotkey");
+        }
+        Err(err) => {
+            tracing::warn!(kind, target, shortcut = %combo, error = %err, "failed to register hotkey");
+        }
+    }
+}
+
+/// Registers the command shortcuts from the `keybindings` section.
+fn register_keybindings<R: Runtime>(
+    app: &AppHandle<R>,
+    keybindings: &HashMap<String, ShortcutCommands>,
+) {
     if keybindings.is_empty() {
         return;
     }
@@ -51,6 +154,13 @@ pub fn register_configured_hotkeys<R: Runtime>(app: &AppHandle<R>) {
         caps_lock::start(caps_bindings);
     }
 
+    let chord_bindings = chord::collect_chords(keybindings);
+
+    if !chord_bindings.is_empty() {
+        register_chord_leaders(app, &chord_bindings, &planned_shortcuts);
+        chord::start(chord_bindings);
+    }
+
     if planned_shortcuts.is_empty() {
         return;
     }
@@ -95,6 +205,67 @@ pub fn register_configured_hotkeys<R: Runtime>(app: &AppHandle<R>) {
     tracing::info!(registered, failed, "finished registering global shortcuts");
 }
 
+/// Registers the leader shortcut of every chord.
+///
+/// A leader that is also bound as a regular keybinding is skipped, since the
+/// regular binding would run before the chord could start.
+fn register_chord_leaders<R: Runtime>(
+    app: &AppHandle<R>,
+    chord_bindings: &chord::ChordBindings,
+    planned_shortcuts: &PlannedShortcutMap,
+) {
+    let global_shortcut = app.global_shortcut();
+
+    for (leader, chords) in chord_bindings {
+        let raw_shortcut = chords.first().map_or("", |chord| chord.raw_shortcut.as_str());
+
+        if let Some((previous_raw, _, _)) = planned_shortcuts.get(leader) {
+            tracing::warn!(
+                shortcut = %raw_shortcut,
+                previous = %previous_raw,
+                "chord leader is already bound as a shortcut; chord will be ignored"
+            );
+            continue;
+        }
+
+        let leader = *leader;
+        match global_shortcut.on_shortcut(leader, move |_app, _shortcut, event| {
+            if event.state == ShortcutState::Pressed {
+                chord::begin(leader);
+            }
+        }) {
+            Ok(()) => {
+                tracing::debug!(shortcut = %raw_shortcut, chords = chords.len(), "registered chord leader");
+            }
+            Err(err) => {
+                tracing::warn!(shortcut = %raw_shortcut, error = %err, "failed to register chord leader");
+            }
+        }
+    }
+}
+
+/// Registers the tiling action shortcuts from the `hotkeys` section.
+fn register_tiling_hotkeys<R: Runtime>(app: &AppHandle<R>, hotkeys: &HashMap<String, String>) {
+    let mut sorted_hotkeys: Vec<_> = hotkeys.iter().collect();
+    sorted_hotkeys.sort_by(|(left, _), (right, _)| left.cmp(right));
+
+    for (name, combo) in sorted_hotkeys {
+        let Some(action) = TilingAction::parse(name) else {
+            tracing::warn!(action = %name, "unknown tiling hotkey action");
+            continue;
+        };
+
+        register_shortcut(app, &TILING_SHORTCUTS, "tiling", name, combo, move |_app| {
+            action.dispatch();
+        });
+    }
+
+    let count = TILING_SHORTCUTS.lock().unwrap_or_else(PoisonError::into_inner).len();
+    if count > 0 {
+        tracing::info!(count, "registered tiling hotkeys");
+    }
+}
+
 fn collect_planned_shortcuts(
     keybindings: &HashMap<String, ShortcutCommands>,
 ) -> (PlannedShortcutMap, caps_lock::CapsBindings) {
@@ -105,6 +276,11 @@ fn collect_planned_shortcuts(
     sorted_keybindings.sort_by(|(left, _), (right, _)| left.cmp(right));
 
     for (shortcut_key, commands) in sorted_keybindings {
+        // Chords are collected separately by `chord::collect_chords`
+        if chord::is_chord(shortcut_key) {
+            continue;
+        }
+
         let shortcut_str = normalize_shortcut(shortcut_key);
 
         match caps_lock::parse_shortcut(&shortcut_str) {
@@ -538,6 +714,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_collect_planned_shortcuts_skips_chords() {
+        let keybindings = HashMap::from([
+            (
+                "Command+Space then W then 1".to_string(),
+                ShortcutCommands::Single("stache tiling workspace --focus 1".to_string()),
+            ),
+            (
+                "Command+Control+R".to_string(),
+                ShortcutCommands::Single("stache reload".to_string()),
+            ),
+        ]);
+
+        let (standard, caps) = collect_planned_shortcuts(&keybindings);
+
+        assert_eq!(standard.len(), 1);
+        assert!(caps.is_empty());
+    }
+
     // ========================================================================
     // execute_shortcut_commands tests (empty commands)
     // ========================================================================
@@ -0,0 +1,514 @@
+//! Keyboard move mode.
+//!
+//! `stache tiling window --move-mode` captures the keyboard until the mode
+//! ends: arrow keys nudge the focused floating window by `tiling.moveMode.step`
+//! pixels or swap a tiled one with its neighbor, Enter keeps the new position
+//! and Escape puts the window back. Other keys are swallowed while the mode is
+//! active. After `tiling.moveMode.timeout` seconds without a key press, the
+//! mode ends and the window stays where it is. The mode also ends when the
+//! tiling manager ends the session, e.g. because the window closed.
+//!
+//! The event tap is only enabled while a session is active.
+
+use std::ffi::c_void;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+use std::sync::{LazyLock, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use core_foundation::base::TCFType;
+use core_foundation::mach_port::CFMachPort;
+use core_foundation::runloop::{CFRunLoop, kCFRunLoopCommonModes};
+
+use super::tiling_actions::build_runtime;
+use crate::config::get_config;
+use crate::modules::tiling;
+use crate::modules::tiling::actor::FocusDirection;
+
+type CGEventRef = *mut c_void;
+type CGEventTapProxy = *mut c_void;
+type CFMachPortRef = *mut c_void;
+
+type CGEventTapCallBack = extern "C" fn(
+    proxy: CGEventTapProxy,
+    event_type: u32,
+    event: CGEventRef,
+    user_info: *mut c_void,
+) -> CGEventRef;
+
+#[link(name = "CoreGraphics", kind = "framework")]
+unsafe extern "C" {
+    fn CGEventTapCreate(
+        tap: u32,
+        place: u32,
+        options: u32,
+        events_of_interest: u64,
+        callback: CGEventTapCallBack,
+        user_info: *mut c_void,
+    ) -> CFMachPortRef;
+
+    fn CGEventTapEnable(tap: CFMachPortRef, enable: bool);
+    fn CGEventGetIntegerValueField(event: CGEventRef, field: u32) -> i64;
+}
+
+const K_CG_HID_EVENT_TAP: u32 = 0;
+const K_CG_HEAD_INSERT_EVENT_TAP: u32 = 0;
+const K_CG_EVENT_TAP_OPTION_DEFAULT: u32 = 0;
+const K_CG_EVENT_KEY_DOWN: u32 = 10;
+const K_CG_EVENT_KEY_UP: u32 = 11;
+const K_CG_EVENT_TAP_DISABLED_BY_TIMEOUT: u32 = 0xFFFF_FFFE;
+const K_CG_KEYBOARD_EVENT_KEYCODE: u32 = 9;
+const KEY_RETURN: i64 = 36;
+const KEY_KEYPAD_ENTER: i64 = 76;
+const KEY_ESCAPE: i64 = 53;
+const KEY_LEFT: i64 = 123;
+const KEY_RIGHT: i64 = 124;
+const KEY_DOWN: i64 = 125;
+const KEY_UP: i64 = 126;
+
+/// Delay between checks for an inactive or ended move mode.
+const TIMEOUT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+static STATE: LazyLock<Mutex<MoveModeState>> =
+    LazyLock::new(|| Mutex::new(MoveModeState::default()));
+static EVENT_TAP: AtomicPtr<c_void> = AtomicPtr::new(ptr::null_mut());
+static INITIALIZED: AtomicBool = AtomicBool::new(false);
+
+/// Starts move mode for the focused window.
+///
+/// Blocks until the state actor has started the move mode, so call it off
+/// the main thread.
+pub(super) fn begin() {
+    if !tiling::init::is_initialized() {
+        tracing::warn!("tiling: manager not initialized");
+        return;
+    }
+
+    let Some(handle) = tiling::init::get_handle() else {
+        tracing::warn!("tiling: handle not available");
+        return;
+    };
+
+    if !start_event_tap_thread() {
+        return;
+    }
+
+    let started = build_runtime().and_then(|runtime| {
+        runtime.block_on(handle.begin_move_mode()).map_err(|err| err.to_string())
+    });
+    match started {
+        Ok(true) => {}
+        Ok(false) => {
+            tracing::debug!("move mode not started");
+            return;
+        }
+        Err(err) => {
+            tracing::warn!(error = %err, "failed to start move mode");
+            return;
+        }
+    }
+
+    let generation = {
+        let mut state = STATE.lock().unwrap_or_else(PoisonError::into_inner);
+        let generation = state.begin(Instant::now());
+        sync_event_tap(&state);
+        generation
+    };
+    tracing::debug!("move mode started");
+
+    if let Err(err) = std::thread::Builder::new()
+        .name("stache-hotkey-move-mode-timeout".into())
+        .spawn(move || watch_timeout(generation))
+    {
+        tracing::warn!(error = %err, "failed to spawn move mode timeout thread");
+    }
+}
+
+/// Ends the move mode started by `generation` once it has been inactive for
+/// `tiling.moveMode.timeout` seconds, or once the tiling manager has ended the
+/// session.
+fn watch_timeout(generation: u64) {
+    let runtime = match build_runtime() {
+        Ok(runtime) => Some(runtime),
+        Err(err) => {
+            tracing::warn!(error = %err, "move mode will only end by key or timeout");
+            None
+        }
+    };
+
+    loop {
+        std::thread::sleep(TIMEOUT_POLL_INTERVAL);
+
+        let timeout = Duration::from_secs(get_config().tiling.move_mode.timeout);
+        let expiry = {
+            let mut state = STATE.lock().unwrap_or_else(PoisonError::into_inner);
+            let expiry = state.expire(generation, Instant::now(), timeout);
+            sync_event_tap(&state);
+            expiry
+        };
+
+        match expiry {
+            Expiry::Active => {}
+            Expiry::Ended => return,
+            Expiry::Expired => {
+                tracing::debug!("move mode timed out");
+                dispatch(MoveStep::End { commit: true });
+                return;
+            }
+        }
+
+        if let Some(runtime) = &runtime
+            && let Some(handle) = tiling::init::get_handle()
+            && matches!(runtime.block_on(handle.has_move_mode()), Ok(false))
+        {
+            let mut state = STATE.lock().unwrap_or_else(PoisonError::into_inner);
+            if state.end(generation) {
+                tracing::debug!("move mode ended by the tiling manager");
+            }
+            sync_event_tap(&state);
+            return;
+        }
+    }
+}
+
+/// Enables the event tap while a session is active, or has key-ups left to
+/// swallow, and disables it otherwise. Call with the state locked.
+fn sync_event_tap(state: &MoveModeState) {
+    let tap = EVENT_TAP.load(Ordering::SeqCst);
+    if !tap.is_null() {
+        unsafe { CGEventTapEnable(tap, !state.is_idle()) };
+    }
+}
+
+/// Starts the key capture event tap, once.
+fn start_event_tap_thread() -> bool {
+    if INITIALIZED.swap(true, Ordering::SeqCst) {
+        return true;
+    }
+
+    std::thread::Builder::new()
+        .name("stache-hotkey-move-mode".into())
+        .spawn(start_event_tap)
+        .map_or_else(
+            |err| {
+                tracing::warn!(error = %err, "failed to spawn move mode event tap thread");
+                INITIALIZED.store(false, Ordering::SeqCst);
+                false
+            },
+            |_| true,
+        )
+}
+
+fn start_event_tap() {
+    unsafe {
+        let event_mask = (1u64 << K_CG_EVENT_KEY_DOWN) | (1u64 << K_CG_EVENT_KEY_UP);
+
+        let tap = CGEventTapCreate(
+            K_CG_HID_EVENT_TAP,
+            K_CG_HEAD_INSERT_EVENT_TAP,
+            K_CG_EVENT_TAP_OPTION_DEFAULT,
+            event_mask,
+            event_tap_callback,
+            ptr::null_mut(),
+        );
+
+        if tap.is_null() {
+            tracing::warn!(
+                "failed to create move mode event tap - check accessibility permissions"
+            );
+            INITIALIZED.store(false, Ordering::SeqCst);
+            return;
+        }
+
+        EVENT_TAP.store(tap, Ordering::SeqCst);
+
+        let tap_port = CFMachPort::wrap_under_create_rule(tap.cast());
+        let Ok(run_loop_source) = tap_port.create_runloop_source(0) else {
+            tracing::warn!("failed to create move mode event tap run loop source");
+            EVENT_TAP.store(ptr::null_mut(), Ordering::SeqCst);
+            INITIALIZED.store(false, Ordering::SeqCst);
+            return;
+        };
+
+        let run_loop = CFRunLoop::get_current();
+        run_loop.add_source(&run_loop_source, kCFRunLoopCommonModes);
+        sync_event_tap(&STATE.lock().unwrap_or_else(PoisonError::into_inner));
+        tracing::debug!("move mode event tap initialized");
+        CFRunLoop::run_current();
+    }
+}
+
+extern "C" fn event_tap_callback(
+    _proxy: CGEventTapProxy,
+    event_type: u32,
+    event: CGEventRef,
+    _user_info: *mut c_void,
+) -> CGEventRef {
+    if event_type >= K_CG_EVENT_TAP_DISABLED_BY_TIMEOUT {
+        sync_event_tap(&STATE.lock().unwrap_or_else(PoisonError::into_inner));
+        return event;
+    }
+
+    if event.is_null() {
+        return event;
+    }
+
+    let keycode = unsafe { CGEventGetIntegerValueField(event, K_CG_KEYBOARD_EVENT_KEYCODE) };
+
+    let step = {
+        let mut state = STATE.lock().unwrap_or_else(PoisonError::into_inner);
+        let step = if event_type == K_CG_EVENT_KEY_DOWN {
+            state.handle_key_down(keycode, Instant::now())
+        } else {
+            state.handle_key_up(keycode)
+        };
+        if state.is_idle() {
+            sync_event_tap(&state);
+        }
+        step
+    };
+
+    match step {
+        MoveStep::Pass => event,
+        MoveStep::Suppress => ptr::null_mut(),
+        MoveStep::Move(_) | MoveStep::End { .. } => {
+            dispatch(step);
+            ptr::null_mut()
+        }
+    }
+}
+
+/// Sends a move mode step to the tiling manager.
+fn dispatch(step: MoveStep) {
+    let Some(handle) = tiling::init::get_handle() else {
+        return;
+    };
+
+    let result = match step {
+        MoveStep::Move(direction) => handle.move_mode_step(direction),
+        MoveStep::End { commit } => handle.end_move_mode(commit),
+        MoveStep::Pass | MoveStep::Suppress => return,
+    };
+
+    if let Err(err) = result {
+        tracing::warn!(step = ?step, error = %err, "move mode step failed");
+    }
+}
+
+/// Result of feeding a key event into the move mode state machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MoveStep {
+    /// Move mode is not active; deliver the event.
+    Pass,
+    /// Captured by move mode; swallow the event.
+    Suppress,
+    /// Swallow the event and move the window in a direction.
+    Move(FocusDirection),
+    /// Swallow the event and end move mode.
+    End { commit: bool },
+}
+
+/// Result of checking move mode for inactivity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Expiry {
+    /// Still active, with a recent key press.
+    Active,
+    /// Ended by a key, or replaced by a newer session.
+    Ended,
+    /// Inactive for the timeout; now ended.
+    Expired,
+}
+
+#[derive(Debug, Default)]
+struct MoveModeState {
+    /// When the last key was pressed, while move mode is active.
+    last_input: Option<Instant>,
+    /// Keys whose key-down was swallowed, so their key-up is swallowed too.
+    suppressed_key_ups: Vec<i64>,
+    /// Incremented on every start so stale timeouts are ignored.
+    generation: u64,
+}
+
+impl MoveModeState {
+    fn begin(&mut self, now: Instant) -> u64 {
+        self.generation = self.generation.wrapping_add(1);
+        self.last_input = Some(now);
+        self.generation
+    }
+
+    /// Ends move mode if it was started by `generation`, forgetting swallowed
+    /// keys. Returns whether it was still active.
+    fn end(&mut self, generation: u64) -> bool {
+        if self.generation != generation || self.last_input.is_none() {
+            return false;
+        }
+
+        self.last_input = None;
+        self.suppressed_key_ups.clear();
+        true
+    }
+
+    /// Returns whether no session is active and no key-up is left to swallow.
+    const fn is_idle(&self) -> bool {
+        self.last_input.is_none() && self.suppressed_key_ups.is_empty()
+    }
+
+    /// Ends move mode if it was started by `generation` and has been inactive
+    /// for `timeout`.
+    fn expire(&mut self, generation: u64, now: Instant, timeout: Duration) -> Expiry {
+        let Some(last_input) = self.last_input.filter(|_| self.generation == generation) else {
+            return Expiry::Ended;
+        };
+
+        if now.duration_since(last_input) < timeout {
+            return Expiry::Active;
+        }
+
+        self.last_input = None;
+        Expiry::Expired
+    }
+
+    fn handle_key_down(&mut self, key: i64, now: Instant) -> MoveStep {
+        if self.last_input.is_none() {
+            return MoveStep::Pass;
+        }
+
+        if !self.suppressed_key_ups.contains(&key) {
+            self.suppressed_key_ups.push(key);
+        }
+
+        self.last_input = Some(now);
+
+        // Arrow keys auto-repeat, so holding one keeps the window moving
+        match key {
+            KEY_LEFT => MoveStep::Move(FocusDirection::Left),
+            KEY_RIGHT => MoveStep::Move(FocusDirection::Right),
+            KEY_UP => MoveStep::Move(FocusDirection::Up),
+            KEY_DOWN => MoveStep::Move(FocusDirection::Down),
+            KEY_RETURN | KEY_KEYPAD_ENTER => {
+                self.last_input = None;
+                MoveStep::End { commit: true }
+            }
+            KEY_ESCAPE => {
+                self.last_input = None;
+                MoveStep::End { commit: false }
+            }
+            _ => MoveStep::Suppress,
+        }
+    }
+
+    fn handle_key_up(&mut self, key: i64) -> MoveStep {
+        match self.suppressed_key_ups.iter().position(|suppressed| *suppressed == key) {
+            Some(index) => {
+                self.suppressed_key_ups.swap_remove(index);
+                MoveStep::Suppress
+            }
+            None => MoveStep::Pass,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY_A: i64 = 0;
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    #[test]
+    fn test_keys_pass_when_inactive() {
+        let mut state = MoveModeState::default();
+        assert_eq!(state.handle_key_down(KEY_LEFT, Instant::now()), MoveStep::Pass);
+        assert_eq!(state.handle_key_up(KEY_LEFT), MoveStep::Pass);
+    }
+
+    #[test]
+    fn test_arrows_move_and_other_keys_are_swallowed() {
+        let now = Instant::now();
+        let mut state = MoveModeState::default();
+        state.begin(now);
+
+        assert_eq!(
+            state.handle_key_down(KEY_UP, now),
+            MoveStep::Move(FocusDirection::Up)
+        );
+        assert_eq!(
+            state.handle_key_down(KEY_RIGHT, now),
+            MoveStep::Move(FocusDirection::Right)
+        );
+        assert_eq!(state.handle_key_down(KEY_A, now), MoveStep::Suppress);
+        assert_eq!(state.handle_key_up(KEY_A), MoveStep::Suppress);
+        assert_eq!(state.handle_key_up(KEY_A), MoveStep::Pass);
+    }
+
+    #[test]
+    fn test_return_commits_and_escape_cancels() {
+        let now = Instant::now();
+        let mut state = MoveModeState::default();
+
+        state.begin(now);
+        assert_eq!(state.handle_key_down(KEY_RETURN, now), MoveStep::End {
+            commit: true
+        });
+        assert_eq!(state.handle_key_down(KEY_LEFT, now), MoveStep::Pass);
+
+        state.begin(now);
+        assert_eq!(state.handle_key_down(KEY_ESCAPE, now), MoveStep::End {
+            commit: false
+        });
+        assert!(state.last_input.is_none());
+    }
+
+    #[test]
+    fn test_expire_after_inactivity() {
+        let start = Instant::now();
+        let mut state = MoveModeState::default();
+        let generation = state.begin(start);
+
+        assert_eq!(
+            state.expire(generation, start + Duration::from_secs(4), TIMEOUT),
+            Expiry::Active
+        );
+
+        // A key press pushes the timeout back
+        state.handle_key_down(KEY_DOWN, start + Duration::from_secs(4));
+        assert_eq!(
+            state.expire(generation, start + Duration::from_secs(8), TIMEOUT),
+            Expiry::Active
+        );
+        assert_eq!(
+            state.expire(generation, start + Duration::from_secs(9), TIMEOUT),
+            Expiry::Expired
+        );
+        assert_eq!(
+            state.expire(generation, start + Duration::from_secs(10), TIMEOUT),
+            Expiry::Ended
+        );
+    }
+
+    #[test]
+    fn test_end_clears_session_and_swallowed_keys() {
+        let now = Instant::now();
+        let mut state = MoveModeState::default();
+        let generation = state.begin(now);
+        state.handle_key_down(KEY_A, now);
+        assert!(!state.is_idle());
+
+        assert!(!state.end(generation.wrapping_add(1)));
+        assert!(state.end(generation));
+        assert!(state.is_idle());
+        assert!(!state.end(generation));
+        assert_eq!(state.handle_key_down(KEY_LEFT, now), MoveStep::Pass);
+    }
+
+    #[test]
+    fn test_expire_ignores_previous_generation() {
+        let start = Instant::now();
+        let mut state = MoveModeState::default();
+        let first = state.begin(start);
+        let second = state.begin(start);
+
+        assert_eq!(state.expire(first, start + TIMEOUT, TIMEOUT), Expiry::Ended);
+        assert_eq!(state.expire(second, start + TIMEOUT, TIMEOUT), Expiry::Expired);
+    }
+}
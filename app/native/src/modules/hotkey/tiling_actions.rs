@@ -0,0 +1,309 @@
+//! Tiling actions bindable from the `hotkeys` config section.
+//!
+//! Each action name maps to a tiling command, e.g. `focus-left`,
+//! `swap-right`, `resize-width-grow`, `workspace-code` or `layout-monocle`.
+//! Actions are dispatched directly to the tiling state actor.
+
+use crate::modules::tiling;
+use crate::modules::tiling::actor::{FocusDirection, QueryResult, StateMessage};
+use crate::modules::tiling::state::LayoutType;
+
+/// Pixels added or removed by the `resize-*` actions.
+const RESIZE_STEP: i32 = 50;
+
+/// A tiling action that can be bound to a key combination.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TilingAction {
+    /// `focus-<direction>`: focus the window in a direction.
+    Focus(FocusDirection),
+    /// `swap-<direction>`: swap the focused window with its neighbor.
+    Swap(FocusDirection),
+    /// `resize-<width|height>-<grow|shrink>`: resize the focused window.
+    Resize {
+        dimension: &'static str,
+        amount: i32,
+    },
+    /// `workspace-<name>`: switch to a workspace.
+    FocusWorkspace(String),
+    /// `send-to-workspace-<name>`: move the focused window to a workspace and follow it.
+    SendToWorkspace(String),
+    /// `move-to-workspace-<name>`: move the focused window to a workspace, staying put.
+    MoveToWorkspace(String),
+    /// `send-to-screen-<target>`: move the focused window to a screen.
+    SendToScreen(String),
+    /// `workspace-to-screen-<target>`: move the focused workspace to a screen.
+    WorkspaceToScreen(String),
+    /// `focus-screen-<target>`: focus the visible workspace on a screen.
+    FocusScreen(String),
+    /// `layout-<layout>`: set the layout of the focused workspace.
+    SetLayout(LayoutType),
+    /// `cycle-layout`: cycle the layout of the focused workspace.
+    CycleLayout,
+    /// `toggle-layout`: toggle the focused workspace to the alternate layout and back.
+    ToggleLayout,
+    /// `balance`: balance window sizes in the focused workspace.
+    Balance,
+    /// `toggle-floating`: toggle floating for the focused window.
+    ToggleFloating,
+    /// `preset-<name>`: apply a floating preset to the focused window.
+    Preset(String),
+}
+
+impl TilingAction {
+    /// Parses an action name from the `hotkeys` config section.
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        let name = name.trim();
+        let non_empty = |value: &str| (!value.is_empty()).then(|| value.to_string());
+
+        match name {
+            "cycle-layout" => return Some(Self::CycleLayout),
+            "toggle-layout" => return Some(Self::ToggleLayout),
+            "balance" => return Some(Self::Balance),
+            "toggle-floating" => return Some(Self::ToggleFloating),
+            "resize-width-grow" => return Some(Self::resize("width", RESIZE_STEP)),
+            "resize-width-shrink" => return Some(Self::resize("width", -RESIZE_STEP)),
+            "resize-height-grow" => return Some(Self::resize("height", RESIZE_STEP)),
+            "resize-height-shrink" => return Some(Self::resize("height", -RESIZE_STEP)),
+            _ => {}
+        }
+
+        // Longer prefixes first, so `workspace-to-screen-*` is not read as a workspace name
+        if let Some(target) = name.strip_prefix("workspace-to-screen-") {
+            return non_empty(target).map(Self::WorkspaceToScreen);
+        }
+        if let Some(workspace) = name.strip_prefix("send-to-workspace-") {
+            return non_empty(workspace).map(Self::SendToWorkspace);
+        }
+        if let Some(workspace) = name.strip_prefix("move-to-workspace-") {
+            return non_empty(workspace).map(Self::MoveToWorkspace);
+        }
+        if let Some(target) = name.strip_prefix("send-to-screen-") {
+            return non_empty(target).map(Self::SendToScreen);
+        }
+        if let Some(target) = name.strip_prefix("focus-screen-") {
+            return non_empty(target).map(Self::FocusScreen);
+        }
+        if let Some(workspace) = name.strip_prefix("workspace-") {
+            return non_empty(workspace).map(Self::FocusWorkspace);
+        }
+        if let Some(preset) = name.strip_prefix("preset-") {
+            return non_empty(preset).map(Self::Preset);
+        }
+        if let Some(layout) = name.strip_prefix("layout-") {
+            return serde_json::from_value(serde_json::json!(layout)).ok().map(Self::SetLayout);
+        }
+        if let Some(direction) = name.strip_prefix("focus-") {
+            return FocusDirection::parse(direction).map(Self::Focus);
+        }
+        if let Some(direction) = name.strip_prefix("swap-") {
+            return FocusDirection::parse(direction).map(Self::Swap);
+        }
+
+        None
+    }
+
+    const fn resize(dimension: &'static str, amount: i32) -> Self {
+        Self::Resize { dimension, amount }
+    }
+
+    /// Dispatches the action to the tiling manager on a background thread.
+    pub fn dispatch(&self) {
+        let action = self.clone();
+        std::thread::spawn(move || {
+            if !tiling::init::is_initialized() {
+                tracing::warn!("tiling: manager not initialized");
+                return;
+            }
+
+            let Some(handle) = tiling::init::get_handle() else {
+                tracing::warn!("tiling: handle not available");
+                return;
+            };
+
+            if let Err(e) = action.send(handle) {
+                tracing::warn!(action = ?action, "tiling: hotkey action failed: {e}");
+            }
+        });
+    }
+
+    /// Sends the state message for this action.
+    fn send(&self, handle: &tiling::StateActorHandle) -> Result<(), String> {
+        let result = match self {
+            Self::Focus(direction) => handle.focus_window(*direction),
+            Self::Swap(direction) => handle.swap_window_in_direction(*direction),
+            Self::Resize { dimension, amount } => handle.resize_focused_window(dimension, *amount),
+            Self::FocusWorkspace(name) => handle.switch_workspace(name),
+            Self::SendToScreen(target) => handle.send_window_to_screen(target),
+            Self::WorkspaceToScreen(target) => handle.send_workspace_to_screen(target),
+            Self::FocusScreen(target) => handle.focus_screen(target),
+            Self::Preset(preset) => handle.apply_preset(preset),
+            Self::SetLayout(layout) => handle.set_layout(focused_workspace_id(handle)?, *layout),
+            Self::CycleLayout => handle.cycle_layout(focused_workspace_id(handle)?),
+            Self::ToggleLayout => handle.toggle_layout(focused_workspace_id(handle)?),
+            Self::Balance => handle.balance_workspace(focused_workspace_id(handle)?),
+            Self::ToggleFloating => handle.toggle_floating(focused_window_id(handle)?),
+            Self::SendToWorkspace(name) | Self::MoveToWorkspace(name) => {
+                let window_id = focused_window_id(handle)?;
+                let workspace_id = workspace_id_by_name(handle, name)?;
+                let follow = matches!(self, Self::SendToWorkspace(_));
+                handle.send(StateMessage::MoveWindowToWorkspace {
+                    window_id,
+                    workspace_id,
+                    follow,
+                })
+            }
+        };
+
+        result.map_err(|e| e.to_string())
+    }
+}
+
+pub(super) fn build_runtime() -> Result<tokio::runtime::Runtime, String> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .map_err(|e| format!("failed to create runtime: {e}"))
+}
+
+fn focused_workspace_id(handle: &tiling::StateActorHandle) -> Result<uuid::Uuid, String> {
+    build_runtime()?
+        .block_on(handle.get_focused_workspace())
+        .ok()
+        .and_then(QueryResult::into_workspace)
+        .flatten()
+        .map(|ws| ws.id)
+        .ok_or_else(|| "no focused workspace".to_string())
+}
+
+fn focused_window_id(handle: &tiling::StateActorHandle) -> Result<u32, String> {
+    build_runtime()?
+        .block_on(handle.get_focused_window())
+        .ok()
+        .and_then(QueryResult::into_window)
+        .flatten()
+        .map(|w| w.id)
+        .ok_or_else(|| "no focused window".to_string())
+}
+
+fn workspace_id_by_name(
+    handle: &tiling::StateActorHandle,
+    name: &str,
+) -> Result<uuid::Uuid, String> {
+    build_runtime()?
+        .block_on(handle.get_workspace_by_name(name))
+        .ok()
+        .and_then(QueryResult::into_workspace)
+        .flatten()
+        .map(|ws| ws.id)
+        .ok_or_else(|| format!("workspace '{name}' not found"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_focus_and_swap() {
+        assert_eq!(
+            TilingAction::parse("focus-left"),
+            Some(TilingAction::Focus(FocusDirection::Left))
+        );
+        assert_eq!(
+            TilingAction::parse("focus-prev"),
+            Some(TilingAction::Focus(FocusDirection::Previous))
+        );
+        assert_eq!(
+            TilingAction::parse("swap-down"),
+            Some(TilingAction::Swap(FocusDirection::Down))
+        );
+        assert_eq!(TilingAction::parse("focus-sideways"), None);
+    }
+
+    #[test]
+    fn test_parse_resize() {
+        assert_eq!(
+            TilingAction::parse("resize-width-grow"),
+            Some(TilingAction::Resize {
+                dimension: "width",
+                amount: RESIZE_STEP
+            })
+        );
+        assert_eq!(
+            TilingAction::parse("resize-height-shrink"),
+            Some(TilingAction::Resize {
+                dimension: "height",
+                amount: -RESIZE_STEP
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_workspace_actions() {
+        assert_eq!(
+            TilingAction::parse("workspace-code"),
+            Some(TilingAction::FocusWorkspace("code".to_string()))
+        );
+        assert_eq!(
+            TilingAction::parse("send-to-workspace-web"),
+            Some(TilingAction::SendToWorkspace("web".to_string()))
+        );
+        assert_eq!(
+            TilingAction::parse("move-to-workspace-web"),
+            Some(TilingAction::MoveToWorkspace("web".to_string()))
+        );
+        assert_eq!(
+            TilingAction::parse("workspace-to-screen-secondary"),
+            Some(TilingAction::WorkspaceToScreen("secondary".to_string()))
+        );
+        assert_eq!(TilingAction::parse("workspace-"), None);
+    }
+
+    #[test]
+    fn test_parse_layout() {
+        assert_eq!(
+            TilingAction::parse("layout-monocle"),
+            Some(TilingAction::SetLayout(LayoutType::Monocle))
+        );
+        assert_eq!(
+            TilingAction::parse("layout-split-vertical"),
+            Some(TilingAction::SetLayout(LayoutType::SplitVertical))
+        );
+        assert_eq!(TilingAction::parse("layout-spiral"), None);
+    }
+
+    #[test]
+    fn test_parse_simple_actions() {
+        assert_eq!(TilingAction::parse("balance"), Some(TilingAction::Balance));
+        assert_eq!(
+            TilingAction::parse("cycle-layout"),
+            Some(TilingAction::CycleLayout)
+        );
+        assert_eq!(
+            TilingAction::parse("toggle-layout"),
+            Some(TilingAction::ToggleLayout)
+        );
+        assert_eq!(
+            TilingAction::parse("toggle-floating"),
+            Some(TilingAction::ToggleFloating)
+        );
+        assert_eq!(
+            TilingAction::parse("preset-centered"),
+            Some(TilingAction::Preset("centered".to_string()))
+        );
+        assert_eq!(
+            TilingAction::parse("send-to-screen-main"),
+            Some(TilingAction::SendToScreen("main".to_string()))
+        );
+        assert_eq!(
+            TilingAction::parse("focus-screen-left"),
+            Some(TilingAction::FocusScreen("left".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_action() {
+        assert_eq!(TilingAction::parse("launch-rockets"), None);
+        assert_eq!(TilingAction::parse(""), None);
+    }
+}
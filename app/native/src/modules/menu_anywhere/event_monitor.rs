@@ -2,10 +2,13 @@
 //!
 //! This module provides a global event tap that monitors for the configured
 //! mouse button + modifier key combination to trigger the menu display.
+//!
+//! The tap listens for every supported mouse button so the trigger can be
+//! reconfigured at runtime without recreating it.
 
 use std::ffi::c_void;
 use std::ptr;
-use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 
 use core_foundation::base::TCFType;
 use core_foundation::mach_port::CFMachPort;
@@ -16,6 +19,7 @@ use parking_lot::Mutex;
 
 use super::menu_builder;
 use crate::config::{MenuAnywhereConfig, MenuAnywhereMouseButton};
+use crate::platform::objc::get_app_bundle_id;
 
 // FFI declarations for Core Graphics event tap functions
 type CGEventRef = *mut c_void;
@@ -67,21 +71,34 @@ const K_CG_EVENT_TAP_DISABLED_BY_TIMEOUT: u32 = 0xFFFF_FFFE;
 const ALL_MODIFIER_FLAGS: u64 = 0x001E_0000; // Shift | Control | Option | Command
 
 // Pre-computed configuration stored in atomics for fast access in callback
+static ENABLED: AtomicBool = AtomicBool::new(false);
 static EXPECTED_EVENT_TYPE: AtomicU32 = AtomicU32::new(0);
 static REQUIRED_MODIFIERS: AtomicU64 = AtomicU64::new(0);
 
-/// Starts the event monitor with the given configuration.
-pub fn start(config: &MenuAnywhereConfig) {
-    // Pre-compute and store configuration in atomics for fast callback access
-    let expected_event = match config.mouse_button {
+/// Bundle identifiers where the trigger is passed through.
+static EXCLUDED_APPS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Returns the event type that corresponds to the configured mouse button.
+const fn event_type_for(button: &MenuAnywhereMouseButton) -> u32 {
+    match button {
         MenuAnywhereMouseButton::RightClick => K_CG_EVENT_RIGHT_MOUSE_DOWN,
         MenuAnywhereMouseButton::MiddleClick => K_CG_EVENT_OTHER_MOUSE_DOWN,
-    };
-    EXPECTED_EVENT_TYPE.store(expected_event, Ordering::Relaxed);
+    }
+}
+
+/// Updates the trigger and exclusions used by the event tap callback.
+pub fn configure(config: &MenuAnywhereConfig) {
+    EXPECTED_EVENT_TYPE.store(event_type_for(&config.mouse_button), Ordering::Relaxed);
     REQUIRED_MODIFIERS.store(config.required_modifier_flags(), Ordering::Relaxed);
+    EXCLUDED_APPS.lock().clone_from(&config.excluded_apps);
+    ENABLED.store(config.is_enabled(), Ordering::Relaxed);
+}
 
+/// Starts the event monitor using the configuration set by [`configure`].
+pub fn start() {
     unsafe {
-        let event_mask = 1u64 << expected_event;
+        let event_mask =
+            (1u64 << K_CG_EVENT_RIGHT_MOUSE_DOWN) | (1u64 << K_CG_EVENT_OTHER_MOUSE_DOWN);
 
         let tap = CGEventTapCreate(
             K_CG_HID_EVENT_TAP,
@@ -118,6 +135,7 @@ extern "C" fn event_tap_callback(
     // Fast path: check event type first (atomic load is very fast)
     if event_type >= K_CG_EVENT_TAP_DISABLED_BY_TIMEOUT
         || event.is_null()
+        || !ENABLED.load(Ordering::Relaxed)
         || event_type != EXPECTED_EVENT_TYPE.load(Ordering::Relaxed)
     {
         return event;
@@ -129,6 +147,11 @@ extern "C" fn event_tap_callback(
         return event;
     }
 
+    // Pass the click through to apps that handle the gesture themselves
+    if is_frontmost_app_excluded() {
+        return event;
+    }
+
     // Get location and trigger menu
     let location = unsafe { CGEventGetLocation(event) };
     trigger_menu_display(location);
@@ -136,6 +159,30 @@ extern "C" fn event_tap_callback(
     ptr::null_mut()
 }
 
+/// Returns whether the frontmost app is in the exclusion list.
+fn is_frontmost_app_excluded() -> bool {
+    let excluded = EXCLUDED_APPS.lock();
+    if excluded.is_empty() {
+        return false;
+    }
+
+    get_frontmost_app_bundle_id().is_some_and(|bundle_id| excluded.contains(&bundle_id))
+}
+
+/// Gets the bundle identifier of the frontmost application.
+fn get_frontmost_app_bundle_id() -> Option<String> {
+    unsafe {
+        let workspace_class = Class::get("NSWorkspace")?;
+        let workspace: *mut Object = msg_send![workspace_class, sharedWorkspace];
+        if workspace.is_null() {
+            return None;
+        }
+
+        let frontmost_app: *mut Object = msg_send![workspace, frontmostApplication];
+        get_app_bundle_id(frontmost_app)
+    }
+}
+
 /// Pending menu location for cross-thread communication.
 static PENDING_MENU_LOCATION: Mutex<Option<(f64, f64)>> = Mutex::new(None);
 
@@ -272,6 +319,18 @@ mod tests {
         assert_eq!(ALL_MODIFIER_FLAGS, 0x001E_0000);
     }
 
+    #[test]
+    fn test_event_type_for_mouse_button() {
+        assert_eq!(
+            event_type_for(&MenuAnywhereMouseButton::RightClick),
+            K_CG_EVENT_RIGHT_MOUSE_DOWN
+        );
+        assert_eq!(
+            event_type_for(&MenuAnywhereMouseButton::MiddleClick),
+            K_CG_EVENT_OTHER_MOUSE_DOWN
+        );
+    }
+
     #[test]
     fn test_cgpoint_is_repr_c() {
         assert_eq!(std::mem::size_of::<CGPoint>(), 16);
@@ -7,6 +7,9 @@
 //! frontmost application and rebuild it as an `NSMenu` that can be displayed at
 //! the cursor position.
 //!
+//! The trigger and the list of excluded apps are read from the `menuAnywhere`
+//! config section and re-applied whenever the config file changes.
+//!
 //! This is a Rust implementation inspired by the menuanywhere project:
 //! <https://github.com/acsandmann/menuanywhere>
 
@@ -16,7 +19,7 @@ mod menu_builder;
 use std::sync::Mutex;
 use std::sync::atomic::{AtomicBool, Ordering};
 
-use crate::config::get_config;
+use crate::config::{self, MenuAnywhereConfig, get_config};
 use crate::is_accessibility_granted;
 
 /// Flag indicating if the module is running.
@@ -33,9 +36,26 @@ static APP_HANDLE: Mutex<Option<tauri::AppHandle>> = Mutex::new(None);
 /// # Arguments
 /// * `app_handle` - The Tauri app handle for emitting events.
 pub fn init(app_handle: tauri::AppHandle) {
-    let config = get_config();
+    // Store the app handle for later use
+    if let Ok(mut handle) = APP_HANDLE.lock() {
+        *handle = Some(app_handle);
+    }
+
+    config::register_live_reload("menuAnywhere", |config| {
+        apply_config(&config.menu_anywhere);
+        true
+    });
+    apply_config(&get_config().menu_anywhere);
+}
 
-    if !config.menu_anywhere.is_enabled() {
+/// Applies a `menuAnywhere` configuration.
+///
+/// Updates the trigger and exclusions used by the running event monitor, and
+/// starts the monitor the first time the feature is enabled.
+pub fn apply_config(config: &MenuAnywhereConfig) {
+    event_monitor::configure(config);
+
+    if !config.is_enabled() || IS_RUNNING.load(Ordering::SeqCst) {
         return;
     }
 
@@ -44,18 +64,12 @@ pub fn init(app_handle: tauri::AppHandle) {
         return;
     }
 
-    // Store the app handle for later use
-    if let Ok(mut handle) = APP_HANDLE.lock() {
-        *handle = Some(app_handle);
+    if IS_RUNNING.swap(true, Ordering::SeqCst) {
+        return;
     }
 
     // Start the event monitor in a separate thread
-    let menu_config = config.menu_anywhere.clone();
-    std::thread::spawn(move || {
-        event_monitor::start(&menu_config);
-    });
-
-    IS_RUNNING.store(true, Ordering::SeqCst);
+    std::thread::spawn(event_monitor::start);
 }
 
 #[cfg(test)]
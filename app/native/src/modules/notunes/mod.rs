@@ -5,79 +5,173 @@
 //! launch Apple Music - this module intercepts those launches and optionally opens
 //! a preferred music player instead.
 //!
-//! The target music player is configurable via the `notunes.target_app` config option.
+//! The intercepted bundle identifiers and the replacement app are configurable
+//! via the `notunes` config section. Changes to that section are applied live:
+//! disabling noTunes removes the launch observer without restarting the app.
 //!
 //! Inspired by <https://github.com/tombonez/noTunes> (MIT License, Tom Taylor 2017).
 
+use std::path::Path;
 use std::ptr::null_mut;
-use std::sync::OnceLock;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::{LazyLock, Mutex, PoisonError};
 
 use objc::declare::ClassDecl;
 use objc::runtime::{Class, Object, Sel};
 use objc::{class, msg_send, sel, sel_impl};
 
-use crate::config::{self, TargetMusicApp};
+use crate::config::{self, NoTunesConfig};
 use crate::platform::objc::{get_app_bundle_id, nsstring};
-use crate::platform::thread::spawn_named_thread;
 
-/// Bundle identifier for Apple Music.
-const APPLE_MUSIC_BUNDLE_ID: &str = "com.apple.Music";
+/// The registered `NSWorkspace` observer, or null when noTunes is not running.
+static OBSERVER: AtomicPtr<Object> = AtomicPtr::new(null_mut());
+
+/// Active settings (updated from config at init time and on live reload).
+static SETTINGS: LazyLock<Mutex<Option<NoTunesSettings>>> = LazyLock::new(|| Mutex::new(None));
+
+/// App launched in place of a blocked app.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LaunchTarget {
+    /// Name used in log messages.
+    name: String,
+    /// Path to the `.app` bundle, if known.
+    path: Option<String>,
+    /// Bundle identifier, if known.
+    bundle_id: Option<String>,
+}
+
+impl LaunchTarget {
+    /// Parses a `launchInstead` value, which is either an `.app` path or a bundle identifier.
+    fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value.is_empty() {
+            return None;
+        }
+
+        if value.contains('/') || value.ends_with(".app") {
+            let name = Path::new(value)
+                .file_stem()
+                .map_or_else(|| value.to_string(), |stem| stem.to_string_lossy().into_owned());
+            return Some(Self {
+                name,
+                path: Some(value.to_string()),
+                bundle_id: None,
+            });
+        }
 
-/// Bundle identifier for iTunes (legacy).
-const ITUNES_BUNDLE_ID: &str = "com.apple.iTunes";
+        Some(Self {
+            name: value.to_string(),
+            path: None,
+            bundle_id: Some(value.to_string()),
+        })
+    }
 
-/// Flag indicating if the module is running.
-static IS_RUNNING: AtomicBool = AtomicBool::new(false);
+    /// Resolves the launch target from config, preferring `launchInstead` over `targetApp`.
+    fn from_config(config: &NoTunesConfig) -> Option<Self> {
+        if let Some(value) = config.launch_instead.as_deref() {
+            return Self::parse(value);
+        }
 
-/// Configured target music app (cached from config at init time).
-static TARGET_APP: OnceLock<TargetMusicApp> = OnceLock::new();
+        let target = &config.target_app;
+        target.app_path().map(|path| Self {
+            name: target.display_name().to_string(),
+            path: Some(path.to_string()),
+            bundle_id: target.bundle_id().map(ToString::to_string),
+        })
+    }
+}
 
-/// Checks if a bundle identifier belongs to Apple Music or iTunes.
-#[inline]
-fn is_music_app(bundle_id: &str) -> bool {
-    bundle_id == APPLE_MUSIC_BUNDLE_ID || bundle_id == ITUNES_BUNDLE_ID
+/// Runtime settings derived from the `notunes` config section.
+#[derive(Debug, Clone)]
+struct NoTunesSettings {
+    /// Bundle identifiers whose launches are blocked.
+    intercept: Vec<String>,
+    /// App to launch instead, if any.
+    launch_target: Option<LaunchTarget>,
+}
+
+impl NoTunesSettings {
+    fn from_config(config: &NoTunesConfig) -> Self {
+        Self {
+            intercept: config.intercept.clone(),
+            launch_target: LaunchTarget::from_config(config),
+        }
+    }
+}
+
+/// Returns a copy of the active settings, if noTunes is enabled.
+fn current_settings() -> Option<NoTunesSettings> {
+    SETTINGS.lock().unwrap_or_else(PoisonError::into_inner).clone()
+}
+
+/// Checks if a bundle identifier is currently intercepted.
+fn is_intercepted(bundle_id: &str) -> bool {
+    current_settings().is_some_and(|settings| settings.intercept.iter().any(|id| id == bundle_id))
 }
 
 /// Initializes the noTunes module.
 ///
 /// This sets up an observer for `NSWorkspace.willLaunchApplicationNotification`
-/// to intercept and terminate Apple Music/iTunes launches, optionally starting
-/// the configured target music app instead.
+/// to intercept and terminate the configured app launches, optionally starting
+/// a replacement app instead. The `notunes` config section is also registered
+/// for live reload.
 pub fn init() {
-    let config = config::get_config();
-
-    // Check if noTunes is enabled in config
-    if !config.notunes.is_enabled() {
-        return;
-    }
+    config::register_live_reload("notunes", |config| {
+        apply_config(&config.notunes);
+        true
+    });
+    apply_config(&config::get_config().notunes);
+}
 
-    if IS_RUNNING.swap(true, Ordering::SeqCst) {
+/// Applies a `notunes` configuration.
+///
+/// Enabling registers the launch observer (if not already registered) and
+/// terminates running intercepted apps; disabling removes the observer.
+pub fn apply_config(config: &NoTunesConfig) {
+    if !config.is_enabled() {
+        stop();
         return;
     }
 
-    // Cache the target app setting
-    let _ = TARGET_APP.set(config.notunes.target_app.clone());
+    *SETTINGS.lock().unwrap_or_else(PoisonError::into_inner) =
+        Some(NoTunesSettings::from_config(config));
 
-    spawn_named_thread("notunes-init", move || {
-        // SAFETY: These functions interact with NSWorkspace and NSNotificationCenter APIs:
-        // - All Objective-C calls use valid selectors and message passing
-        // - Pointers are checked for null before dereferencing
-        // - The observer is retained by NSNotificationCenter automatically
-        unsafe {
+    // SAFETY: These functions interact with NSWorkspace and NSNotificationCenter APIs:
+    // - All Objective-C calls use valid selectors and message passing
+    // - Pointers are checked for null before dereferencing
+    // - The observer is owned by OBSERVER until it is removed in stop()
+    unsafe {
+        if OBSERVER.load(Ordering::SeqCst).is_null() {
             setup_workspace_observer();
-            // Also terminate any already-running instances
-            terminate_music_apps();
+            tracing::info!("notunes: interception enabled");
         }
-    });
+        // Also terminate any already-running instances
+        terminate_music_apps();
+    }
 }
 
-/// Returns the configured target music app.
-fn get_target_app() -> &'static TargetMusicApp {
-    TARGET_APP.get().unwrap_or(&TargetMusicApp::Tidal)
+/// Removes the launch observer and clears the active settings.
+fn stop() {
+    *SETTINGS.lock().unwrap_or_else(PoisonError::into_inner) = None;
+
+    let observer = OBSERVER.swap(null_mut(), Ordering::SeqCst);
+    if observer.is_null() {
+        return;
+    }
+
+    // SAFETY: observer was created by create_observer_object and registered
+    // with the workspace notification center in setup_workspace_observer
+    unsafe {
+        let workspace: *mut Object = msg_send![class!(NSWorkspace), sharedWorkspace];
+        let notification_center: *mut Object = msg_send![workspace, notificationCenter];
+        let _: () = msg_send![notification_center, removeObserver: observer];
+        let _: () = msg_send![observer, release];
+    }
+
+    tracing::info!("notunes: interception disabled");
 }
 
-/// Terminates any currently running Apple Music or iTunes instances.
+/// Terminates any currently running intercepted app instances.
 ///
 /// # Safety
 ///
@@ -93,7 +187,7 @@ unsafe fn terminate_music_apps() {
         let app: *mut Object = msg_send![running_apps, objectAtIndex: i];
 
         if let Some(bundle_id_str) = unsafe { get_app_bundle_id(app) }
-            && is_music_app(&bundle_id_str)
+            && is_intercepted(&bundle_id_str)
         {
             tracing::info!(bundle_id = %bundle_id_str, "notunes: terminating running instance");
             let _: () = msg_send![app, forceTerminate];
@@ -108,7 +202,7 @@ unsafe fn terminate_music_apps() {
 /// Caller must ensure:
 /// - This is called within a valid Objective-C runtime context
 /// - `NSWorkspace` and its notification center are accessible
-/// - This should only be called once (idempotent due to class registration check)
+/// - This should only be called while no observer is registered
 unsafe fn setup_workspace_observer() {
     // Get the workspace and notification center
     let workspace: *mut Object = msg_send![class!(NSWorkspace), sharedWorkspace];
@@ -127,6 +221,8 @@ unsafe fn setup_workspace_observer() {
         name: notification_name
         object: null_mut::<Object>()
     ];
+
+    OBSERVER.store(observer, Ordering::SeqCst);
 }
 
 /// Creates an Objective-C observer object that handles the notification.
@@ -135,7 +231,7 @@ unsafe fn setup_workspace_observer() {
 ///
 /// Caller must ensure:
 /// - This is called within a valid Objective-C runtime context
-/// - The returned object is retained by `NSNotificationCenter` (do not release manually)
+/// - The returned object is owned by the caller and released in `stop()`
 /// - The `NoTunesObserver` class is only registered once (handled via `Class::get` check)
 unsafe fn create_observer_object() -> *mut Object {
     // Dynamically create a class for our observer
@@ -160,7 +256,7 @@ unsafe fn create_observer_object() -> *mut Object {
         decl.register()
     });
 
-    // Create an instance - the observer will be retained by NSNotificationCenter
+    // Create an instance - released when the observer is removed
     let instance: *mut Object = msg_send![observer_class, alloc];
     msg_send![instance, init]
 }
@@ -185,9 +281,9 @@ extern "C" fn handle_app_launch(_self: &Object, _cmd: Sel, notification: *mut Ob
             return;
         }
 
-        // Get the bundle identifier and check if it's a music app
+        // Get the bundle identifier and check if it's intercepted
         if let Some(bundle_id_str) = get_app_bundle_id(app)
-            && is_music_app(&bundle_id_str)
+            && is_intercepted(&bundle_id_str)
         {
             tracing::info!(bundle_id = %bundle_id_str, "notunes: blocking launch");
 
@@ -200,26 +296,23 @@ extern "C" fn handle_app_launch(_self: &Object, _cmd: Sel, notification: *mut Ob
     }
 }
 
-/// Launches the configured target music app as replacement.
+/// Launches the configured replacement app.
 fn launch_target_app() {
-    let target = get_target_app();
-
-    // Get app path and bundle ID, or return if target is None
-    let Some(app_path) = target.app_path() else {
+    let Some(target) = current_settings().and_then(|settings| settings.launch_target) else {
         return;
     };
-    let bundle_id = target.bundle_id();
-    let display_name = target.display_name();
+    let name = &target.name;
 
     // Check if the app is installed
-    let path = std::path::Path::new(app_path);
-    if !path.exists() {
-        tracing::warn!(app = %display_name, path = %app_path, "notunes: target app not found");
+    if let Some(app_path) = target.path.as_deref()
+        && !Path::new(app_path).exists()
+    {
+        tracing::warn!(app = %name, path = %app_path, "notunes: target app not found");
         return;
     }
 
     // Check if the app is already running
-    if let Some(bundle_id) = bundle_id {
+    if let Some(bundle_id) = target.bundle_id.as_deref() {
         unsafe {
             let workspace: *mut Object = msg_send![class!(NSWorkspace), sharedWorkspace];
             let running_apps: *mut Object = msg_send![workspace, runningApplications];
@@ -238,11 +331,18 @@ fn launch_target_app() {
         }
     }
 
-    // Launch the app using /usr/bin/open
-    match std::process::Command::new("/usr/bin/open").arg(app_path).spawn() {
-        Ok(_) => tracing::info!(app = %display_name, "notunes: launched replacement app"),
+    // Launch the app using /usr/bin/open, by path when known, otherwise by bundle ID
+    let mut command = std::process::Command::new("/usr/bin/open");
+    match (target.path.as_deref(), target.bundle_id.as_deref()) {
+        (Some(app_path), _) => command.arg(app_path),
+        (None, Some(bundle_id)) => command.args(["-b", bundle_id]),
+        (None, None) => return,
+    };
+
+    match command.spawn() {
+        Ok(_) => tracing::info!(app = %name, "notunes: launched replacement app"),
         Err(e) => {
-            tracing::error!(app = %display_name, error = %e, "notunes: failed to launch replacement app");
+            tracing::error!(app = %name, error = %e, "notunes: failed to launch replacement app");
         }
     }
 }
@@ -250,50 +350,70 @@ fn launch_target_app() {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::TargetMusicApp;
 
     #[test]
-    fn test_is_music_app_apple_music() {
-        assert!(is_music_app(APPLE_MUSIC_BUNDLE_ID));
-        assert!(is_music_app("com.apple.Music"));
+    fn test_launch_target_parse_bundle_id() {
+        let target = LaunchTarget::parse("com.spotify.client").unwrap();
+        assert_eq!(target.name, "com.spotify.client");
+        assert_eq!(target.bundle_id.as_deref(), Some("com.spotify.client"));
+        assert!(target.path.is_none());
     }
 
     #[test]
-    fn test_is_music_app_itunes() {
-        assert!(is_music_app(ITUNES_BUNDLE_ID));
-        assert!(is_music_app("com.apple.iTunes"));
+    fn test_launch_target_parse_app_path() {
+        let target = LaunchTarget::parse("/Applications/Spotify.app").unwrap();
+        assert_eq!(target.name, "Spotify");
+        assert_eq!(target.path.as_deref(), Some("/Applications/Spotify.app"));
+        assert!(target.bundle_id.is_none());
     }
 
     #[test]
-    fn test_is_music_app_other_apps() {
-        assert!(!is_music_app("com.spotify.client"));
-        assert!(!is_music_app("com.tidal.desktop"));
-        assert!(!is_music_app("org.jeffvli.feishin"));
-        assert!(!is_music_app("com.apple.Safari"));
-        assert!(!is_music_app(""));
+    fn test_launch_target_parse_empty() {
+        assert!(LaunchTarget::parse("").is_none());
+        assert!(LaunchTarget::parse("   ").is_none());
     }
 
     #[test]
-    fn test_bundle_ids_are_correct() {
-        assert_eq!(APPLE_MUSIC_BUNDLE_ID, "com.apple.Music");
-        assert_eq!(ITUNES_BUNDLE_ID, "com.apple.iTunes");
+    fn test_launch_target_from_target_app() {
+        let config = NoTunesConfig {
+            target_app: TargetMusicApp::Tidal,
+            ..Default::default()
+        };
+        let target = LaunchTarget::from_config(&config).unwrap();
+        assert_eq!(target.name, "Tidal");
+        assert_eq!(target.path.as_deref(), Some("/Applications/TIDAL.app"));
+        assert_eq!(target.bundle_id.as_deref(), Some("com.tidal.desktop"));
     }
 
     #[test]
-    fn test_is_running_initially_false() {
-        // Note: This test may not be reliable if init() has been called
-        // The atomic is static and persists across tests
-        // We're just testing that the constant exists and is an AtomicBool
-        let _ = IS_RUNNING.load(Ordering::SeqCst);
+    fn test_launch_instead_overrides_target_app() {
+        let config = NoTunesConfig {
+            target_app: TargetMusicApp::Tidal,
+            launch_instead: Some("com.spotify.client".to_string()),
+            ..Default::default()
+        };
+        let target = LaunchTarget::from_config(&config).unwrap();
+        assert_eq!(target.bundle_id.as_deref(), Some("com.spotify.client"));
     }
 
     #[test]
-    fn test_apple_music_bundle_id_format() {
-        assert!(APPLE_MUSIC_BUNDLE_ID.starts_with("com.apple."));
+    fn test_launch_target_none() {
+        let config = NoTunesConfig {
+            target_app: TargetMusicApp::None,
+            ..Default::default()
+        };
+        assert!(LaunchTarget::from_config(&config).is_none());
     }
 
     #[test]
-    fn test_itunes_bundle_id_format() {
-        assert!(ITUNES_BUNDLE_ID.starts_with("com.apple."));
+    fn test_settings_from_config_copies_intercept_list() {
+        let config = NoTunesConfig {
+            intercept: vec!["com.apple.TV".to_string()],
+            ..Default::default()
+        };
+        let settings = NoTunesSettings::from_config(&config);
+        assert_eq!(settings.intercept, vec!["com.apple.TV"]);
     }
 
     #[test]
@@ -7,7 +7,11 @@ use std::time::Duration;
 
 use tokio::sync::{mpsc, oneshot};
 
-use super::messages::{QueryResult, ResizeDimension, StateMessage, StateQuery, TargetScreen};
+use super::messages::{
+    CycleDirection, QueryResult, ResizeDimension, StateMessage, StateQuery, TargetScreen,
+    WindowPlacement,
+};
+use crate::modules::tiling::state::Rect;
 
 /// Error types for actor communication.
 #[derive(Debug, thiserror::Error)]
@@ -149,6 +153,15 @@ impl StateActorHandle {
         self.query(StateQuery::GetEnabled).await
     }
 
+    /// Get whether window management is paused.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication with the actor fails.
+    pub async fn get_paused(&self) -> Result<QueryResult, ActorError> {
+        self.query(StateQuery::GetPaused).await
+    }
+
     /// Get a screen by ID.
     ///
     /// # Errors
@@ -176,6 +189,18 @@ impl StateActorHandle {
         self.query(StateQuery::GetWorkspaceByName { name: name.to_string() }).await
     }
 
+    /// Get the Nth workspace (0-based) of the focused screen, in config order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication with the actor fails.
+    pub async fn get_workspace_on_focused_screen(
+        &self,
+        index: usize,
+    ) -> Result<QueryResult, ActorError> {
+        self.query(StateQuery::GetWorkspaceOnFocusedScreen { index }).await
+    }
+
     /// Get a window by ID.
     ///
     /// # Errors
@@ -238,6 +263,80 @@ impl StateActorHandle {
         self.send(StateMessage::ToggleFloating { window_id })
     }
 
+    /// Toggle whether a window is kept above normal windows.
+    ///
+    /// Returns the new state, or `None` if the level could not be changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed, or
+    /// [`ActorError::ReceiveFailed`] if the response channel is closed.
+    pub async fn toggle_always_on_top(&self, window_id: u32) -> Result<Option<bool>, ActorError> {
+        let (tx, rx) = oneshot::channel();
+
+        self.sender
+            .send(StateMessage::ToggleAlwaysOnTop { window_id, respond_to: tx })
+            .await
+            .map_err(|_| ActorError::SendFailed)?;
+
+        rx.await.map_err(|_| ActorError::ReceiveFailed)
+    }
+
+    /// Set the opacity of a window, from 0.0 (transparent) to 1.0 (opaque).
+    ///
+    /// Returns whether the window server accepted the new opacity.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed, or
+    /// [`ActorError::ReceiveFailed`] if the response channel is closed.
+    pub async fn set_window_opacity(
+        &self,
+        window_id: u32,
+        opacity: f64,
+    ) -> Result<bool, ActorError> {
+        let (tx, rx) = oneshot::channel();
+
+        self.sender
+            .send(StateMessage::SetWindowOpacity {
+                window_id,
+                opacity,
+                respond_to: tx,
+            })
+            .await
+            .map_err(|_| ActorError::SendFailed)?;
+
+        rx.await.map_err(|_| ActorError::ReceiveFailed)
+    }
+
+    /// Minimize a window to the Dock.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn minimize_window(&self, window_id: u32) -> Result<(), ActorError> {
+        self.send(StateMessage::MinimizeWindow { window_id })
+    }
+
+    /// Restore the most recently minimized window of the focused workspace.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn restore_minimized_window(&self) -> Result<(), ActorError> {
+        self.send(StateMessage::RestoreMinimizedWindow)
+    }
+
+    /// Move the focused window one position earlier (`Previous`) or later
+    /// (`Next`) in the stack.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn move_window_in_stack(&self, direction: CycleDirection) -> Result<(), ActorError> {
+        self.send(StateMessage::MoveWindowInStack { direction })
+    }
+
     /// Enable or disable tiling.
     ///
     /// # Errors
@@ -247,6 +346,15 @@ impl StateActorHandle {
         self.send(StateMessage::SetEnabled { enabled })
     }
 
+    /// Pause or resume window management.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn set_paused(&self, paused: bool) -> Result<(), ActorError> {
+        self.send(StateMessage::SetPaused { paused })
+    }
+
     /// Focus a window in a direction.
     ///
     /// Supports spatial directions (up/down/left/right) and cycling (next/previous).
@@ -258,6 +366,35 @@ impl StateActorHandle {
         self.send(StateMessage::FocusWindow { direction })
     }
 
+    /// Focus the next or previous window of the focused window's app.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn cycle_app_focus(&self, direction: CycleDirection) -> Result<(), ActorError> {
+        self.send(StateMessage::CycleAppFocus { direction })
+    }
+
+    /// Focus the window `steps` back in the focused workspace's focus history.
+    ///
+    /// Repeated calls in quick succession walk further back, like alt-tab.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn focus_mru(&self, steps: usize) -> Result<(), ActorError> {
+        self.send(StateMessage::FocusMru { steps })
+    }
+
+    /// Focus the most recently used other window, switching workspaces if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn focus_global_mru(&self) -> Result<(), ActorError> {
+        self.send(StateMessage::FocusGlobalMru)
+    }
+
     /// Swap focused window with another in a direction.
     ///
     /// Supports spatial directions (up/down/left/right) and cycling (next/previous).
@@ -281,6 +418,42 @@ impl StateActorHandle {
         self.send(StateMessage::BalanceWorkspace { workspace_id })
     }
 
+    /// Toggle gaps off or back on for the focused workspace, or with `all`
+    /// for every workspace.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn toggle_gaps(&self, all: bool) -> Result<(), ActorError> {
+        self.send(StateMessage::ToggleGaps { all })
+    }
+
+    /// Grow or shrink the gaps of the focused screen by a number of pixels,
+    /// on top of the configured gaps.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn adjust_gaps(&self, inner: f64, outer: f64) -> Result<(), ActorError> {
+        self.send(StateMessage::AdjustGaps { inner, outer })
+    }
+
+    /// Drop runtime gap adjustments, going back to the configured gaps.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn reset_gaps(&self) -> Result<(), ActorError> { self.send(StateMessage::ResetGaps) }
+
+    /// Re-apply the layout of the screens with a bar after the bar region changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn bar_region_changed(&self) -> Result<(), ActorError> {
+        self.send(StateMessage::BarRegionChanged)
+    }
+
     /// Cycle through layouts for a workspace.
     ///
     /// # Errors
@@ -290,9 +463,41 @@ impl StateActorHandle {
         self.send(StateMessage::CycleLayout { workspace_id })
     }
 
+    /// Toggle a workspace between its layout and the alternate layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn toggle_layout(&self, workspace_id: uuid::Uuid) -> Result<(), ActorError> {
+        self.send(StateMessage::ToggleLayout { workspace_id })
+    }
+
+    /// Let `tiling.autoLayout` pick a workspace's layout again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn reset_auto_layout(&self, workspace_id: uuid::Uuid) -> Result<(), ActorError> {
+        self.send(StateMessage::ResetAutoLayout { workspace_id })
+    }
+
+    /// Override where the master window sits in a workspace's Master layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn set_master_position(
+        &self,
+        workspace_id: uuid::Uuid,
+        position: crate::config::MasterPosition,
+    ) -> Result<(), ActorError> {
+        self.send(StateMessage::SetMasterPosition { workspace_id, position })
+    }
+
     /// Send focused window to another screen.
     ///
-    /// Supports "main"/"primary", "secondary", or display name.
+    /// Supports "main"/"primary", "secondary", display name, or a direction
+    /// from the focused screen ("left", "right", "up", "down", "next", "previous").
     ///
     /// # Errors
     ///
@@ -303,9 +508,17 @@ impl StateActorHandle {
         })
     }
 
+    /// Move the mouse cursor to the center of the focused screen.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn center_cursor(&self) -> Result<(), ActorError> { self.send(StateMessage::CenterCursor) }
+
     /// Send focused workspace to another screen.
     ///
-    /// Supports "main"/"primary", "secondary", or display name.
+    /// Supports "main"/"primary", "secondary", display name, or a direction
+    /// from the focused screen ("left", "right", "up", "down", "next", "previous").
     ///
     /// # Errors
     ///
@@ -316,6 +529,62 @@ impl StateActorHandle {
         })
     }
 
+    /// Exchange the visible workspaces of two screens.
+    ///
+    /// Supports "main"/"primary", "secondary", or display name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn swap_workspaces_between_screens(
+        &self,
+        screen_a: &str,
+        screen_b: &str,
+    ) -> Result<(), ActorError> {
+        self.send(StateMessage::SwapWorkspacesBetweenScreens {
+            screen_a: TargetScreen::parse(screen_a),
+            screen_b: TargetScreen::parse(screen_b),
+        })
+    }
+
+    /// Move every window, or only those of an app, into the focused workspace.
+    ///
+    /// `filter` matches an app name or bundle ID, case-insensitive.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn gather_windows(&self, filter: Option<&str>) -> Result<(), ActorError> {
+        self.send(StateMessage::GatherWindows {
+            filter: filter.map(String::from),
+        })
+    }
+
+    /// Make the windows of an app adjacent in the focused workspace.
+    ///
+    /// `app` is a process ID, or an app name or bundle ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn group_app_windows(&self, app: &str) -> Result<(), ActorError> {
+        self.send(StateMessage::GroupAppWindows { app: app.to_string() })
+    }
+
+    /// Focus the visible workspace on another screen.
+    ///
+    /// Supports "main"/"primary", "secondary", display name, or a direction
+    /// from the focused screen ("left", "right", "up", "down", "next", "previous").
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn focus_screen(&self, target_screen: &str) -> Result<(), ActorError> {
+        self.send(StateMessage::FocusScreen {
+            target_screen: TargetScreen::parse(target_screen),
+        })
+    }
+
     /// Resize the focused window in a dimension.
     ///
     /// Adjusts split ratios to resize the window by the specified amount.
@@ -351,6 +620,86 @@ impl StateActorHandle {
         })
     }
 
+    /// Set the focused window's frame to an exact placement and wait for it.
+    ///
+    /// Returns the frame applied after clamping to the screen, or `None`
+    /// without a focused window.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed, or
+    /// [`ActorError::ReceiveFailed`] if the response channel is closed.
+    pub async fn place_window(
+        &self,
+        placement: WindowPlacement,
+    ) -> Result<Option<Rect>, ActorError> {
+        let (tx, rx) = oneshot::channel();
+
+        self.sender
+            .send(StateMessage::PlaceWindow { placement, respond_to: tx })
+            .await
+            .map_err(|_| ActorError::SendFailed)?;
+
+        rx.await.map_err(|_| ActorError::ReceiveFailed)
+    }
+
+    /// Start moving the focused window with the arrow keys.
+    ///
+    /// Returns whether the move mode started, which it doesn't without a
+    /// focused window or when it is in native fullscreen.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed, or
+    /// [`ActorError::ReceiveFailed`] if the response channel is closed.
+    pub async fn begin_move_mode(&self) -> Result<bool, ActorError> {
+        let (tx, rx) = oneshot::channel();
+
+        self.sender
+            .send(StateMessage::BeginMoveMode { respond_to: tx })
+            .await
+            .map_err(|_| ActorError::SendFailed)?;
+
+        rx.await.map_err(|_| ActorError::ReceiveFailed)
+    }
+
+    /// Nudge a floating window, or swap a tiled one, while in move mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn move_mode_step(&self, direction: super::FocusDirection) -> Result<(), ActorError> {
+        self.send(StateMessage::MoveModeStep { direction })
+    }
+
+    /// Get whether a move mode session is in progress.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if communication with the actor fails.
+    pub async fn has_move_mode(&self) -> Result<bool, ActorError> {
+        match self.query(StateQuery::HasMoveMode).await? {
+            QueryResult::Exists(exists) => Ok(exists),
+            _ => Err(ActorError::ReceiveFailed),
+        }
+    }
+
+    /// End move mode, keeping the window where it is with `commit`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn end_move_mode(&self, commit: bool) -> Result<(), ActorError> {
+        self.send(StateMessage::EndMoveMode { commit })
+    }
+
+    /// Drop all tracked state, for a restart of tiling.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn reset(&self) -> Result<(), ActorError> { self.send(StateMessage::Reset) }
+
     /// Request shutdown of the actor.
     ///
     /// # Errors
@@ -358,6 +707,22 @@ impl StateActorHandle {
     /// Returns [`ActorError::SendFailed`] if the channel is closed.
     pub fn shutdown(&self) -> Result<(), ActorError> { self.send(StateMessage::Shutdown) }
 
+    /// Resync tracked windows with every window that currently exists.
+    ///
+    /// `server_ids` are the IDs of the windows that are on screen or on a Space;
+    /// tracked windows among them are kept even when `windows` misses them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn reconcile(
+        &self,
+        windows: Vec<crate::modules::tiling::actor::WindowCreatedInfo>,
+        server_ids: std::collections::HashSet<u32>,
+    ) -> Result<(), ActorError> {
+        self.send(StateMessage::Reconcile { windows, server_ids })
+    }
+
     /// Set expected frames for windows (for minimum size detection).
     ///
     /// This should be called after computing layout but before effects are applied,
@@ -374,6 +739,29 @@ impl StateActorHandle {
         self.send(StateMessage::SetExpectedFrames { frames })
     }
 
+    /// Report that a window kept its old size instead of resizing to `requested`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn report_resize_refused(
+        &self,
+        window_id: u32,
+        requested: crate::modules::tiling::state::Rect,
+        actual: crate::modules::tiling::state::Rect,
+    ) -> Result<(), ActorError> {
+        self.send(StateMessage::WindowResizeRefused { window_id, requested, actual })
+    }
+
+    /// Report that a window resized to the frame it was asked for.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ActorError::SendFailed`] if the channel is closed.
+    pub fn report_resize_applied(&self, window_id: u32) -> Result<(), ActorError> {
+        self.send(StateMessage::WindowResizeApplied { window_id })
+    }
+
     // ========================================================================
     // Channel state
     // ========================================================================
@@ -5,7 +5,7 @@
 //! - App terminated → remove all windows from this app
 //! - App hidden → mark all windows from this app as hidden
 //! - App shown → mark all windows from this app as visible
-//! - App activated → potentially switch workspace
+//! - App activated → record the app as the most recently used one
 
 use std::collections::HashSet;
 
@@ -14,6 +14,7 @@ use uuid::Uuid;
 use crate::modules::tiling::effects::get_window_cache;
 use crate::modules::tiling::init::get_subscriber_handle;
 use crate::modules::tiling::state::TilingState;
+use crate::modules::tiling::window::running_app_for_pid;
 
 /// Handles an app launched event.
 ///
@@ -35,6 +36,7 @@ pub fn on_app_terminated(state: &mut TilingState, pid: i32) -> HashSet<Uuid> {
     tracing::debug!("Handling app terminated: pid={pid}");
 
     crate::modules::tiling::tabs::clear_tabs_for_pid(pid);
+    state.remove_app_from_history(pid);
 
     // Find all windows for this PID
     let window_ids: Vec<u32> = state.get_windows_for_pid(pid).iter().map(|w| w.id).collect();
@@ -55,14 +57,13 @@ pub fn on_app_terminated(state: &mut TilingState, pid: i32) -> HashSet<Uuid> {
 
     // Remove each window
     for window_id in &window_ids {
-        // Get workspace before removing
-        let workspace_id = state.get_window(*window_id).map(|w| w.workspace_id);
-
-        // Remove from state
-        state.remove_window(*window_id);
+        // Remove from state, keeping the workspaces it was in
+        let Some(window) = state.remove_window(*window_id) else {
+            continue;
+        };
 
-        // Remove from workspace's window list
-        if let Some(ws_id) = workspace_id {
+        // Remove from the window lists of its workspaces
+        for ws_id in std::iter::once(window.workspace_id).chain(window.extra_workspace_ids) {
             affected_workspaces.insert(ws_id);
 
             state.update_workspace(ws_id, |ws| {
@@ -134,12 +135,14 @@ pub fn on_app_shown(state: &mut TilingState, pid: i32) {
 
 /// Handles an app activated event (brought to front).
 ///
-/// This is informational - focus changes happen via window focus events.
+/// Records the app in the history of recently used apps, for `stache app
+/// switch`. Focus changes happen via window focus events.
 pub fn on_app_activated(state: &mut TilingState, pid: i32) {
     tracing::debug!("Handling app activated: pid={pid}");
 
-    // Nothing specific to do - focus will be handled by window focus events
-    let _ = state;
+    if let Some(app) = running_app_for_pid(pid) {
+        state.record_app_activation(app);
+    }
 }
 
 // ============================================================================
@@ -151,7 +154,9 @@ mod tests {
     use smallvec::smallvec;
 
     use super::*;
-    use crate::modules::tiling::state::{LayoutType, Rect, Window, WindowIdList, Workspace};
+    use crate::modules::tiling::state::{
+        LayoutType, RecentApp, Rect, Window, WindowIdList, Workspace,
+    };
     use crate::modules::tiling::tabs;
 
     fn make_state_with_workspace() -> (TilingState, Uuid) {
@@ -169,6 +174,11 @@ mod tests {
             split_ratios: Vec::new(),
             master_ratio: None,
             configured_screen: None,
+            home_screen_uuid: None,
+            toggled_from: None,
+            layout_pinned: false,
+            master_position: None,
+            gaps_disabled: false,
         };
         let ws_id = ws.id;
         state.upsert_workspace(ws);
@@ -183,15 +193,22 @@ mod tests {
             app_id: format!("com.test.app{pid}"),
             app_name: format!("App {pid}"),
             title: format!("Window {id}"),
+            subrole: None,
             frame: Rect::new(0.0, 0.0, 800.0, 600.0),
             minimum_size: None,
             inferred_minimum_size: None,
             expected_frame: None,
             workspace_id,
+            extra_workspace_ids: Vec::new(),
             is_minimized: false,
             is_fullscreen: false,
             is_hidden: false,
             is_floating: false,
+            float_frame: None,
+            always_on_top: false,
+            opacity: None,
+            non_resizable: false,
+            awaiting_frame: false,
             tab_group_id: None,
             is_active_tab: true,
             matched_rule: None,
@@ -313,4 +330,21 @@ mod tests {
 
         tabs::clear_all_tabs();
     }
+
+    #[test]
+    fn test_app_terminated_prunes_recent_apps() {
+        let mut state = TilingState::new();
+        for (pid, name) in [(1000, "Safari"), (2000, "Terminal")] {
+            state.record_app_activation(RecentApp {
+                pid,
+                bundle_id: format!("com.apple.{name}"),
+                name: name.to_string(),
+            });
+        }
+
+        let _ = on_app_terminated(&mut state, 2000);
+
+        let pids: Vec<i32> = state.get_app_mru().iter().map(|app| app.pid).collect();
+        assert_eq!(pids, vec![1000]);
+    }
 }
@@ -1,17 +1,55 @@
 //! Focus command handlers.
 //!
-//! These handlers manage focus cycling, directional focus, and swapping
-//! windows in a direction.
+//! These handlers manage focus cycling, app window cycling, directional focus,
+//! MRU focus, and swapping windows in a direction.
 
+use std::time::Instant;
+
+use super::workspace::on_switch_workspace;
+use crate::config::get_config;
 use crate::modules::tiling::actor::{CycleDirection, FocusDirection};
 use crate::modules::tiling::init::get_subscriber_handle;
-use crate::modules::tiling::state::{Rect, TilingState};
+use crate::modules::tiling::state::{Rect, TilingState, Window, Workspace};
 
 // ============================================================================
 // Focus Cycling
 // ============================================================================
 
+/// Returns whether a window takes part in focus cycling.
+///
+/// Tiled windows always do. Floating windows only with `include_floating`,
+/// and under the same conditions as tiled ones: visible and the active tab.
+const fn is_cycle_candidate(window: &Window, include_floating: bool) -> bool {
+    if window.is_floating {
+        include_floating
+            && !window.is_minimized
+            && !window.is_hidden
+            && !window.is_fullscreen
+            && (window.tab_group_id.is_none() || window.is_active_tab)
+    } else {
+        window.is_layoutable()
+    }
+}
+
+/// Returns the windows of a workspace visited by focus cycling, in order.
+fn cycle_candidates(
+    state: &TilingState,
+    workspace: &Workspace,
+    include_floating: bool,
+) -> Vec<u32> {
+    workspace
+        .window_ids
+        .iter()
+        .filter(|&&id| {
+            state.get_window(id).is_some_and(|w| is_cycle_candidate(&w, include_floating))
+        })
+        .copied()
+        .collect()
+}
+
 /// Cycle focus through windows in the current workspace.
+///
+/// Floating windows are included when `tiling.cycleIncludesFloating` is set.
 pub fn on_cycle_focus(state: &mut TilingState, direction: CycleDirection) {
     let focus = state.get_focus_state();
     let Some(workspace_id) = focus.focused_workspace_id else {
@@ -24,38 +62,34 @@ pub fn on_cycle_focus(state: &mut TilingState, direction: CycleDirection) {
         return;
     };
 
-    // Get layoutable windows (exclude minimized, hidden, etc.)
-    let layoutable: Vec<u32> = workspace
-        .window_ids
-        .iter()
-        .filter(|&&id| state.get_window(id).is_some_and(|w| w.is_layoutable()))
-        .copied()
-        .collect();
+    // Exclude minimized, hidden, and (unless configured) floating windows
+    let include_floating = get_config().tiling.cycle_includes_floating;
+    let candidates = cycle_candidates(state, &workspace, include_floating);
 
-    if layoutable.is_empty() {
-        tracing::debug!("cycle_focus: no layoutable windows");
+    if candidates.is_empty() {
+        tracing::debug!("cycle_focus: no windows to cycle through");
         return;
     }
 
     // Find current focused position
     let current_idx = focus
         .focused_window_id
-        .and_then(|id| layoutable.iter().position(|&w| w == id))
+        .and_then(|id| candidates.iter().position(|&w| w == id))
         .unwrap_or(0);
 
     // Calculate next index
     let next_idx = match direction {
-        CycleDirection::Next => (current_idx + 1) % layoutable.len(),
+        CycleDirection::Next => (current_idx + 1) % candidates.len(),
         CycleDirection::Previous => {
             if current_idx == 0 {
-                layoutable.len() - 1
+                candidates.len() - 1
             } else {
                 current_idx - 1
             }
         }
     };
 
-    let next_window_id = layoutable[next_idx];
+    let next_window_id = candidates[next_idx];
 
     // Update focus state
     state.update_focus(|focus| {
@@ -78,6 +112,185 @@ pub fn on_cycle_focus(state: &mut TilingState, direction: CycleDirection) {
 
     // Actually focus the window via AX API
     let _ = crate::modules::tiling::effects::window_ops::focus_window(next_window_id);
+
+    // Activating the app can bring its other windows forward, so raise a
+    // floating window again once focused to keep it on top of the tiles
+    if state.get_window(next_window_id).is_some_and(|w| w.is_floating) {
+        let _ = crate::modules::tiling::effects::window_ops::raise_window(next_window_id);
+    }
+}
+
+// ============================================================================
+// App Window Cycling
+// ============================================================================
+
+/// Cycle focus through the windows of the focused window's app.
+///
+/// Only windows of the same process in the focused workspace take part, tiled
+/// or floating. Each one is raised as it is focused. Does nothing if the app
+/// has a single window there.
+pub fn on_cycle_app_focus(state: &mut TilingState, direction: CycleDirection) {
+    let focus = state.get_focus_state();
+    let (Some(workspace_id), Some(focused_id)) =
+        (focus.focused_workspace_id, focus.focused_window_id)
+    else {
+        tracing::debug!("cycle_app_focus: no focused window");
+        return;
+    };
+
+    let Some(pid) = state.get_window(focused_id).map(|w| w.pid) else {
+        tracing::debug!("cycle_app_focus: window {focused_id} not found");
+        return;
+    };
+
+    let Some(workspace) = state.get_workspace(workspace_id) else {
+        tracing::debug!("cycle_app_focus: workspace {workspace_id} not found");
+        return;
+    };
+
+    let candidates: Vec<u32> = workspace
+        .window_ids
+        .iter()
+        .filter(|&&id| {
+            state
+                .get_window(id)
+                .is_some_and(|w| w.pid == pid && w.is_active_tab && !w.is_minimized && !w.is_hidden)
+        })
+        .copied()
+        .collect();
+
+    if candidates.len() < 2 {
+        tracing::debug!("cycle_app_focus: app {pid} has no other window here");
+        return;
+    }
+
+    let current_idx = candidates.iter().position(|&id| id == focused_id).unwrap_or(0);
+    let next_idx = match direction {
+        CycleDirection::Next => (current_idx + 1) % candidates.len(),
+        CycleDirection::Previous => (current_idx + candidates.len() - 1) % candidates.len(),
+    };
+    let next_window_id = candidates[next_idx];
+
+    state.update_focus(|focus| {
+        focus.focused_window_id = Some(next_window_id);
+    });
+
+    if let Some(idx) = workspace.window_index(next_window_id) {
+        state.update_workspace(workspace_id, |ws| {
+            ws.focused_window_index = Some(idx);
+        });
+    }
+
+    tracing::debug!("Cycled app focus to window {next_window_id} ({direction:?})");
+
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_focus_changed();
+    }
+
+    let _ = crate::modules::tiling::effects::window_ops::focus_window(next_window_id);
+    let _ = crate::modules::tiling::effects::window_ops::raise_window(next_window_id);
+}
+
+// ============================================================================
+// MRU Focus
+// ============================================================================
+
+/// Focus the window `steps` back in the focused workspace's focus history.
+///
+/// Works like alt-tab: repeated calls in quick succession keep walking back
+/// through the same order, and the selected window moves to the front of the
+/// history once they stop.
+pub fn on_focus_mru(state: &mut TilingState, steps: usize) {
+    let focus = state.get_focus_state();
+    let Some(workspace_id) = focus.focused_workspace_id else {
+        tracing::debug!("focus_mru: no focused workspace");
+        return;
+    };
+
+    let Some(workspace) = state.get_workspace(workspace_id) else {
+        tracing::debug!("focus_mru: workspace {workspace_id} not found");
+        return;
+    };
+
+    // Floating windows can be focused too, unlike in cycling
+    let candidates: Vec<u32> = workspace
+        .window_ids
+        .iter()
+        .filter(|&&id| {
+            state
+                .get_window(id)
+                .is_some_and(|w| w.is_active_tab && !w.is_minimized && !w.is_hidden)
+        })
+        .copied()
+        .collect();
+
+    let Some(target_id) = state.step_focus_mru(
+        workspace_id,
+        focus.focused_window_id,
+        &candidates,
+        steps.max(1),
+        Instant::now(),
+    ) else {
+        tracing::debug!("focus_mru: no other window to focus");
+        return;
+    };
+
+    state.update_focus(|focus| {
+        focus.focused_window_id = Some(target_id);
+    });
+
+    if let Some(idx) = workspace.window_index(target_id) {
+        state.update_workspace(workspace_id, |ws| {
+            ws.focused_window_index = Some(idx);
+        });
+    }
+
+    tracing::debug!("Focused MRU window {target_id} ({steps} back)");
+
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_focus_changed();
+    }
+
+    let _ = crate::modules::tiling::effects::window_ops::focus_window(target_id);
+}
+
+/// Focus the most recently used other window, in any workspace.
+///
+/// A window in a hidden workspace is focused by switching to that workspace.
+pub fn on_focus_global_mru(state: &mut TilingState) {
+    let current = state.get_focus_state().focused_window_id;
+
+    let Some(window) = state
+        .get_global_focus_mru()
+        .iter()
+        .filter(|&&id| Some(id) != current)
+        .filter_map(|&id| state.get_window(id))
+        .find(|w| !w.is_minimized)
+    else {
+        tracing::debug!("focus_global_mru: no other window to focus");
+        return;
+    };
+
+    let Some(workspace) = state.get_workspace(window.workspace_id) else {
+        tracing::debug!("focus_global_mru: workspace {} not found", window.workspace_id);
+        return;
+    };
+
+    tracing::debug!(
+        "Focusing global MRU window {} in workspace '{}'",
+        window.id,
+        workspace.name
+    );
+
+    if !workspace.is_visible {
+        // Switching focuses the workspace's most recent window: this one
+        state.record_focus_history(workspace.id, window.id);
+        on_switch_workspace(state, &workspace.name);
+        return;
+    }
+
+    // The focus event that follows moves focus to the window's workspace and screen
+    let _ = crate::modules::tiling::effects::window_ops::focus_window(window.id);
 }
 
 // ============================================================================
@@ -396,6 +609,80 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_focus_mru_walks_back_through_history() {
+        let mut state = create_test_state();
+        let ws_id = state.get_focus_state().focused_workspace_id.unwrap();
+        add_window(&mut state, 100, 0.0, 0.0, 400.0, 300.0);
+        add_window(&mut state, 200, 400.0, 0.0, 400.0, 300.0);
+        add_window(&mut state, 300, 0.0, 300.0, 800.0, 300.0);
+
+        for id in [300, 200, 100] {
+            state.record_focus_history(ws_id, id);
+        }
+        state.update_focus(|f| f.focused_window_id = Some(100));
+
+        on_focus_mru(&mut state, 1);
+        assert_eq!(state.get_focus_state().focused_window_id, Some(200));
+
+        // Repeating walks further back instead of returning to 100
+        on_focus_mru(&mut state, 1);
+        assert_eq!(state.get_focus_state().focused_window_id, Some(300));
+    }
+
+    #[test]
+    fn test_focus_global_mru_switches_to_hidden_workspace() {
+        let mut state = create_test_state();
+        let ws1_id = state.get_focus_state().focused_workspace_id.unwrap();
+        add_window(&mut state, 100, 0.0, 0.0, 400.0, 300.0);
+
+        let mut ws2 = Workspace::new("workspace2");
+        ws2.screen_id = 1;
+        let ws2_id = ws2.id;
+        state.upsert_workspace(ws2);
+        state.upsert_window(Window {
+            id: 200,
+            workspace_id: ws2_id,
+            ..Default::default()
+        });
+        state.update_workspace(ws2_id, |ws| ws.window_ids.push(200));
+
+        state.set_focus(Some(200), Some(ws2_id), Some(1));
+        state.set_focus(Some(100), Some(ws1_id), Some(1));
+
+        on_focus_global_mru(&mut state);
+
+        assert_eq!(state.get_focus_state().focused_workspace_id, Some(ws2_id));
+        assert_eq!(state.get_focus_state().focused_window_id, Some(200));
+    }
+
+    #[test]
+    fn test_cycle_app_focus_stays_within_app() {
+        let mut state = create_test_state();
+        add_window(&mut state, 100, 0.0, 0.0, 400.0, 300.0);
+        add_window(&mut state, 200, 400.0, 0.0, 400.0, 300.0);
+        add_window(&mut state, 300, 0.0, 300.0, 800.0, 300.0);
+        add_window(&mut state, 400, 800.0, 0.0, 400.0, 300.0);
+        for (id, pid) in [(100, 1), (200, 2), (300, 1), (400, 3)] {
+            state.update_window(id, |w| w.pid = pid);
+        }
+
+        state.update_focus(|f| f.focused_window_id = Some(100));
+        on_cycle_app_focus(&mut state, CycleDirection::Next);
+        assert_eq!(state.get_focus_state().focused_window_id, Some(300));
+
+        on_cycle_app_focus(&mut state, CycleDirection::Next);
+        assert_eq!(state.get_focus_state().focused_window_id, Some(100));
+
+        on_cycle_app_focus(&mut state, CycleDirection::Previous);
+        assert_eq!(state.get_focus_state().focused_window_id, Some(300));
+
+        // An app with a single window is left alone
+        state.update_focus(|f| f.focused_window_id = Some(400));
+        on_cycle_app_focus(&mut state, CycleDirection::Next);
+        assert_eq!(state.get_focus_state().focused_window_id, Some(400));
+    }
+
     #[test]
     fn test_cycle_focus() {
         let mut state = create_test_state();
@@ -423,6 +710,24 @@ mod tests {
         assert_eq!(state.get_focus_state().focused_window_id, Some(300));
     }
 
+    #[test]
+    fn test_cycle_candidates_include_floating_only_when_enabled() {
+        let mut state = create_test_state();
+        let ws_id = state.get_focus_state().focused_workspace_id.unwrap();
+        add_window(&mut state, 100, 0.0, 0.0, 400.0, 300.0);
+        add_window(&mut state, 200, 400.0, 0.0, 400.0, 300.0);
+        add_window(&mut state, 300, 0.0, 300.0, 800.0, 300.0);
+        state.update_window(200, |w| w.is_floating = true);
+        state.update_window(300, |w| {
+            w.is_floating = true;
+            w.is_minimized = true;
+        });
+
+        let workspace = state.get_workspace(ws_id).unwrap();
+        assert_eq!(cycle_candidates(&state, &workspace, false), vec![100]);
+        assert_eq!(cycle_candidates(&state, &workspace, true), vec![100, 200]);
+    }
+
     #[test]
     fn test_find_window_in_direction() {
         let mut state = create_test_state();
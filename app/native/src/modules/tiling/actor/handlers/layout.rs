@@ -4,8 +4,12 @@
 
 use uuid::Uuid;
 
+use super::screen::convert_layout_type;
+use crate::config::{BarPosition, MasterPosition, TilingConfig, get_config};
+use crate::modules::bar::window::reserved_region;
 use crate::modules::tiling::init::get_subscriber_handle;
-use crate::modules::tiling::state::{LayoutType, TilingState};
+use crate::modules::tiling::layout::Gaps;
+use crate::modules::tiling::state::{LayoutType, SavedLayout, Screen, TilingState, Workspace};
 
 // ============================================================================
 // Layout Commands
@@ -13,16 +17,27 @@ use crate::modules::tiling::state::{LayoutType, TilingState};
 
 /// Set the layout for a workspace.
 pub fn on_set_layout(state: &mut TilingState, workspace_id: Uuid, layout: LayoutType) {
+    set_workspace_layout(state, workspace_id, layout);
+    notify_layout_set(workspace_id, layout);
+}
+
+/// Set the layout for a workspace without telling the subscriber.
+///
+/// The windows keep their positions until [`notify_layout_set`] is called.
+pub fn set_workspace_layout(state: &mut TilingState, workspace_id: Uuid, layout: LayoutType) {
     state.update_workspace(workspace_id, |ws| {
         ws.layout = layout;
         // Clear all runtime ratio overrides when layout changes
         ws.split_ratios.clear();
         ws.master_ratio = None;
+        ws.toggled_from = None;
     });
 
     tracing::debug!("Set workspace {workspace_id} layout to {layout:?}");
+}
 
-    // Notify subscriber about layout change
+/// Tell the subscriber a workspace's layout was set, so its windows move.
+pub fn notify_layout_set(workspace_id: Uuid, layout: LayoutType) {
     if let Some(handle) = get_subscriber_handle() {
         handle.notify_workspace_layout_changed(workspace_id, layout);
         handle.notify_layout_changed(workspace_id, true);
@@ -30,25 +45,297 @@ pub fn on_set_layout(state: &mut TilingState, workspace_id: Uuid, layout: Layout
 }
 
 /// Cycle through layouts for a workspace.
+///
+/// Follows `tiling.layoutCycle` when set, otherwise every layout in turn.
 pub fn on_cycle_layout(state: &mut TilingState, workspace_id: Uuid) {
+    let Some(next_layout) = cycled_layout(state, workspace_id) else {
+        return;
+    };
+
+    on_set_layout(state, workspace_id, next_layout);
+    tracing::debug!("Cycled workspace {workspace_id} layout to {next_layout:?}");
+}
+
+/// Returns the layout cycling a workspace switches it to.
+#[must_use]
+pub fn cycled_layout(state: &TilingState, workspace_id: Uuid) -> Option<LayoutType> {
     let Some(workspace) = state.get_workspace(workspace_id) else {
         tracing::warn!("cycle_layout: workspace {workspace_id} not found");
+        return None;
+    };
+
+    let cycle: Vec<LayoutType> = get_config()
+        .tiling
+        .layout_cycle
+        .iter()
+        .copied()
+        .map(convert_layout_type)
+        .collect();
+    Some(next_layout(workspace.layout, &cycle))
+}
+
+/// Toggle a workspace between its layout and `tiling.layoutToggleAlternate`.
+///
+/// Switching to the alternate remembers the layout and ratios the workspace
+/// had, and toggling again restores them exactly. A workspace already on the
+/// alternate with nothing remembered goes to `tiling.defaultLayout`.
+pub fn on_toggle_layout(state: &mut TilingState, workspace_id: Uuid) {
+    let Some(workspace) = state.get_workspace(workspace_id) else {
+        tracing::warn!("toggle_layout: workspace {workspace_id} not found");
         return;
     };
 
-    let next_layout = match workspace.layout {
-        LayoutType::Floating => LayoutType::Dwindle,
-        LayoutType::Dwindle => LayoutType::Monocle,
-        LayoutType::Monocle => LayoutType::Master,
-        LayoutType::Master => LayoutType::Split,
-        LayoutType::Split => LayoutType::SplitVertical,
-        LayoutType::SplitVertical => LayoutType::SplitHorizontal,
-        LayoutType::SplitHorizontal => LayoutType::Grid,
-        LayoutType::Grid => LayoutType::Floating,
+    let config = get_config();
+    let alternate = convert_layout_type(config.tiling.layout_toggle_alternate);
+
+    if workspace.layout != alternate {
+        let saved = SavedLayout {
+            layout: workspace.layout,
+            split_ratios: workspace.split_ratios.clone(),
+            master_ratio: workspace.master_ratio,
+        };
+        on_set_layout(state, workspace_id, alternate);
+        state.update_workspace(workspace_id, |ws| ws.toggled_from = Some(saved));
+        tracing::debug!("Toggled workspace {workspace_id} to {alternate:?}");
+        return;
+    }
+
+    let Some(saved) = workspace.toggled_from.clone() else {
+        let layout = convert_layout_type(config.tiling.default_layout);
+        if layout != alternate {
+            on_set_layout(state, workspace_id, layout);
+        }
+        return;
     };
 
-    on_set_layout(state, workspace_id, next_layout);
-    tracing::debug!("Cycled workspace {workspace_id} layout to {next_layout:?}");
+    // The subscriber re-applies the layout later, after the ratios are back
+    on_set_layout(state, workspace_id, saved.layout);
+    state.update_workspace(workspace_id, |ws| {
+        ws.split_ratios = saved.split_ratios;
+        ws.master_ratio = saved.master_ratio;
+    });
+    tracing::debug!("Toggled workspace {workspace_id} back to {:?}", saved.layout);
+}
+
+/// Switch workspaces to the `tiling.autoLayout` layout for their window count.
+///
+/// Runs after the actor messages that can change how many windows a workspace
+/// tiles, so workspaces follow windows being tracked, closed, moved or floated.
+/// Workspaces whose layout was set by hand are left alone until
+/// `--layout-auto` unpins them.
+pub fn apply_auto_layouts(state: &mut TilingState, config: &TilingConfig) {
+    if config.auto_layout.is_empty() {
+        return;
+    }
+
+    let changes: Vec<(Uuid, LayoutType)> = state
+        .workspaces
+        .iter()
+        .filter(|ws| !ws.layout_pinned)
+        .filter_map(|ws| {
+            let window_count = state.get_layoutable_window_ids(ws.id).len();
+            let layout = config.auto_layout_for(window_count).map(convert_layout_type)?;
+            (layout != ws.layout).then(|| {
+                tracing::debug!(
+                    "Auto layout: workspace '{}' holds {window_count} windows, using {layout:?}",
+                    ws.name
+                );
+                (ws.id, layout)
+            })
+        })
+        .collect();
+
+    for (workspace_id, layout) in changes {
+        on_set_layout(state, workspace_id, layout);
+    }
+}
+
+/// Unpin a workspace's layout, handing it back to `tiling.autoLayout`.
+pub fn on_reset_auto_layout(state: &mut TilingState, workspace_id: Uuid) {
+    state.update_workspace(workspace_id, |ws| ws.layout_pinned = false);
+    tracing::debug!("Workspace {workspace_id} layout follows autoLayout again");
+}
+
+/// Override where the master window sits in a workspace and re-apply its layout.
+///
+/// Kept across layout changes. `Auto` resolves by the orientation of the
+/// workspace's screen when laying out, like `tiling.master.position`.
+pub fn on_set_master_position(
+    state: &mut TilingState,
+    workspace_id: Uuid,
+    position: MasterPosition,
+) {
+    state.update_workspace(workspace_id, |ws| ws.master_position = Some(position));
+    tracing::debug!("Set workspace {workspace_id} master position to {position:?}");
+
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_layout_changed(workspace_id, true);
+    }
+}
+
+/// Returns the layout after `current` in `cycle`.
+///
+/// Wraps around at the end of the cycle, and starts over at its first entry
+/// if `current` is not part of it. An empty cycle visits every layout.
+fn next_layout(current: LayoutType, cycle: &[LayoutType]) -> LayoutType {
+    if cycle.is_empty() {
+        return match current {
+            LayoutType::Floating => LayoutType::Dwindle,
+            LayoutType::Dwindle => LayoutType::Monocle,
+            LayoutType::Monocle => LayoutType::Master,
+            LayoutType::Master => LayoutType::Split,
+            LayoutType::Split => LayoutType::SplitVertical,
+            LayoutType::SplitVertical => LayoutType::SplitHorizontal,
+            LayoutType::SplitHorizontal => LayoutType::Grid,
+            LayoutType::Grid => LayoutType::Floating,
+        };
+    }
+
+    cycle
+        .iter()
+        .position(|&layout| layout == current)
+        .map_or(cycle[0], |idx| cycle[(idx + 1) % cycle.len()])
+}
+
+// ============================================================================
+// Gaps
+// ============================================================================
+
+/// Returns the configured gaps of a screen, without the bar offset.
+fn configured_gaps(screen: &Screen) -> Gaps {
+    Gaps::from_config(&get_config().tiling.gaps, &screen.name, screen.is_main, 0.0)
+}
+
+/// Returns the gaps to lay out a workspace on a screen with.
+///
+/// These are the configured gaps with the screen's runtime adjustment, or
+/// zero gaps while the workspace has them toggled off. The region taken by the
+/// screen's bar is kept free either way, on the edge it is shown on.
+#[must_use]
+pub fn workspace_gaps(state: &TilingState, workspace: &Workspace, screen: &Screen) -> Gaps {
+    let gaps = if workspace.gaps_disabled {
+        Gaps::zero()
+    } else {
+        let (inner, outer) = state.get_gap_adjustment(screen.id);
+        configured_gaps(screen).adjusted(inner, outer)
+    };
+
+    match reserved_region(screen.is_main) {
+        Some((BarPosition::Top, extent)) => gaps.with_top_offset(extent),
+        Some((BarPosition::Bottom, extent)) => gaps.with_bottom_offset(extent),
+        None => gaps,
+    }
+}
+
+/// Notify the subscriber to re-apply the layout of visible workspaces on screens.
+fn relayout_screens(state: &TilingState, screen_ids: &[u32]) {
+    let Some(handle) = get_subscriber_handle() else {
+        return;
+    };
+
+    for workspace in state.get_visible_workspaces() {
+        if screen_ids.contains(&workspace.screen_id) {
+            handle.notify_layout_changed(workspace.id, true);
+        }
+    }
+}
+
+/// Grow or shrink the gaps of the focused screen by a number of pixels.
+///
+/// The adjustment is held in memory on top of the configured gaps, and stops
+/// shrinking once every inner or outer gap reaches zero.
+pub fn on_adjust_gaps(state: &mut TilingState, inner: f64, outer: f64) {
+    let Some(screen_id) = state.get_focus_state().focused_screen_id else {
+        tracing::debug!("adjust_gaps: no focused screen");
+        return;
+    };
+
+    let Some(screen) = state.get_screen(screen_id) else {
+        tracing::debug!("adjust_gaps: screen {screen_id} not found");
+        return;
+    };
+
+    let base = configured_gaps(&screen);
+    let max_inner = base.inner_h.max(base.inner_v);
+    let max_outer =
+        base.outer_top.max(base.outer_right).max(base.outer_bottom).max(base.outer_left);
+
+    let (current_inner, current_outer) = state.get_gap_adjustment(screen_id);
+    let inner = (current_inner + inner).max(-max_inner);
+    let outer = (current_outer + outer).max(-max_outer);
+    state.set_gap_adjustment(screen_id, inner, outer);
+
+    tracing::debug!("Adjusted gaps of screen {screen_id} by inner {inner}px, outer {outer}px");
+
+    relayout_screens(state, &[screen_id]);
+}
+
+/// Drop all runtime gap adjustments, going back to the configured gaps.
+pub fn on_reset_gaps(state: &mut TilingState) {
+    let screen_ids = state.clear_gap_adjustments();
+    tracing::debug!("Reset gap adjustments of {} screen(s)", screen_ids.len());
+
+    relayout_screens(state, &screen_ids);
+}
+
+/// Re-apply the layout of the screens with a bar after the bar region changed.
+///
+/// Gaps are computed from the bar's reserved region when laying out, so this
+/// lets tiled windows grow into the space of a hidden bar and shrink back
+/// once it is shown again.
+pub fn on_bar_region_changed(state: &TilingState) {
+    let per_screen = get_config().bar.per_screen;
+    let screen_ids: Vec<u32> = state
+        .screens
+        .iter()
+        .filter(|screen| screen.is_main || per_screen)
+        .map(|screen| screen.id)
+        .collect();
+
+    tracing::debug!(
+        "Bar region changed, re-applying layout of {} screen(s)",
+        screen_ids.len()
+    );
+    relayout_screens(state, &screen_ids);
+}
+
+/// Toggle gaps off or back on and re-apply the layout.
+///
+/// Flips the focused workspace, or with `all` every workspace: gaps go off
+/// everywhere if any workspace still has them, and back on otherwise.
+pub fn on_toggle_gaps(state: &mut TilingState, all: bool) {
+    let workspace_ids: Vec<Uuid> = if all {
+        state.get_all_workspace_ids()
+    } else {
+        let Some(workspace_id) = state.get_focus_state().focused_workspace_id else {
+            tracing::debug!("toggle_gaps: no focused workspace");
+            return;
+        };
+        vec![workspace_id]
+    };
+
+    let disable = workspace_ids
+        .iter()
+        .any(|&id| state.get_workspace(id).is_some_and(|ws| !ws.gaps_disabled));
+
+    for &workspace_id in &workspace_ids {
+        state.update_workspace(workspace_id, |ws| ws.gaps_disabled = disable);
+    }
+
+    tracing::debug!(
+        "Turned gaps {} for {} workspace(s)",
+        if disable { "off" } else { "on" },
+        workspace_ids.len()
+    );
+
+    // Hidden workspaces pick the change up when they are shown
+    if let Some(handle) = get_subscriber_handle() {
+        for workspace_id in workspace_ids {
+            if state.get_workspace(workspace_id).is_some_and(|ws| ws.is_visible) {
+                handle.notify_layout_changed(workspace_id, true);
+            }
+        }
+    }
 }
 
 // ============================================================================
@@ -58,7 +345,7 @@ pub fn on_cycle_layout(state: &mut TilingState, workspace_id: Uuid) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::modules::tiling::state::{Screen, Workspace};
+    use crate::modules::tiling::state::{Screen, Window, Workspace};
 
     fn create_test_state() -> (TilingState, Uuid) {
         let mut state = TilingState::new();
@@ -99,6 +386,105 @@ mod tests {
         assert_eq!(ws.layout, LayoutType::Master);
     }
 
+    #[test]
+    fn test_toggle_layout_twice_restores_original() {
+        let (mut state, ws_id) = create_test_state();
+        on_set_layout(&mut state, ws_id, LayoutType::Master);
+        state.update_workspace(ws_id, |ws| {
+            ws.split_ratios = vec![0.3, 0.7];
+            ws.master_ratio = Some(0.65);
+        });
+
+        on_toggle_layout(&mut state, ws_id);
+        let ws = state.get_workspace(ws_id).unwrap();
+        assert_eq!(ws.layout, LayoutType::Monocle);
+        assert!(ws.split_ratios.is_empty());
+
+        on_toggle_layout(&mut state, ws_id);
+        let ws = state.get_workspace(ws_id).unwrap();
+        assert_eq!(ws.layout, LayoutType::Master);
+        assert_eq!(ws.split_ratios, vec![0.3, 0.7]);
+        assert_eq!(ws.master_ratio, Some(0.65));
+        assert!(ws.toggled_from.is_none());
+    }
+
+    fn auto_layout_config() -> TilingConfig {
+        serde_json::from_str(
+            r#"{"autoLayout": [
+                {"windows": 1, "layout": "monocle"},
+                {"windows": 2, "layout": "split"},
+                {"windows": 3, "layout": "dwindle"}
+            ]}"#,
+        )
+        .unwrap()
+    }
+
+    fn add_window(state: &mut TilingState, ws_id: Uuid, window_id: u32) {
+        state.upsert_window(Window {
+            id: window_id,
+            workspace_id: ws_id,
+            ..Default::default()
+        });
+        state.update_workspace(ws_id, |ws| ws.window_ids.push(window_id));
+    }
+
+    #[test]
+    fn test_auto_layout_switches_at_thresholds() {
+        let (mut state, ws_id) = create_test_state();
+        let config = auto_layout_config();
+
+        add_window(&mut state, ws_id, 1);
+        apply_auto_layouts(&mut state, &config);
+        assert_eq!(state.get_workspace(ws_id).unwrap().layout, LayoutType::Monocle);
+
+        add_window(&mut state, ws_id, 2);
+        apply_auto_layouts(&mut state, &config);
+        assert_eq!(state.get_workspace(ws_id).unwrap().layout, LayoutType::Split);
+
+        add_window(&mut state, ws_id, 3);
+        apply_auto_layouts(&mut state, &config);
+        assert_eq!(state.get_workspace(ws_id).unwrap().layout, LayoutType::Dwindle);
+
+        state.remove_window(3);
+        apply_auto_layouts(&mut state, &config);
+        assert_eq!(state.get_workspace(ws_id).unwrap().layout, LayoutType::Split);
+    }
+
+    #[test]
+    fn test_auto_layout_skips_pinned_workspace_until_reset() {
+        let (mut state, ws_id) = create_test_state();
+        let config = auto_layout_config();
+        add_window(&mut state, ws_id, 1);
+        add_window(&mut state, ws_id, 2);
+
+        on_set_layout(&mut state, ws_id, LayoutType::Grid);
+        state.update_workspace(ws_id, |ws| ws.layout_pinned = true);
+        apply_auto_layouts(&mut state, &config);
+        assert_eq!(state.get_workspace(ws_id).unwrap().layout, LayoutType::Grid);
+
+        on_reset_auto_layout(&mut state, ws_id);
+        apply_auto_layouts(&mut state, &config);
+        assert_eq!(state.get_workspace(ws_id).unwrap().layout, LayoutType::Split);
+    }
+
+    #[test]
+    fn test_set_master_position_survives_layout_change() {
+        let (mut state, ws_id) = create_test_state();
+
+        on_set_master_position(&mut state, ws_id, MasterPosition::Top);
+        assert_eq!(
+            state.get_workspace(ws_id).unwrap().master_position,
+            Some(MasterPosition::Top)
+        );
+
+        on_set_layout(&mut state, ws_id, LayoutType::Dwindle);
+        on_set_layout(&mut state, ws_id, LayoutType::Master);
+        assert_eq!(
+            state.get_workspace(ws_id).unwrap().master_position,
+            Some(MasterPosition::Top)
+        );
+    }
+
     #[test]
     fn test_cycle_layout() {
         let (mut state, ws_id) = create_test_state();
@@ -116,4 +502,81 @@ mod tests {
         on_cycle_layout(&mut state, ws_id);
         assert_eq!(state.get_workspace(ws_id).unwrap().layout, LayoutType::Master);
     }
+
+    #[test]
+    fn test_toggle_gaps_focused_workspace() {
+        let (mut state, ws_id) = create_test_state();
+
+        on_toggle_gaps(&mut state, false);
+        assert!(state.get_workspace(ws_id).unwrap().gaps_disabled);
+
+        on_toggle_gaps(&mut state, false);
+        assert!(!state.get_workspace(ws_id).unwrap().gaps_disabled);
+    }
+
+    #[test]
+    fn test_toggle_gaps_all_workspaces() {
+        let (mut state, ws_id) = create_test_state();
+        let other = Workspace::new("workspace2");
+        let other_id = other.id;
+        state.upsert_workspace(other);
+
+        // Mixed states turn gaps off everywhere first
+        on_toggle_gaps(&mut state, false);
+        on_toggle_gaps(&mut state, true);
+        assert!(state.get_workspace(ws_id).unwrap().gaps_disabled);
+        assert!(state.get_workspace(other_id).unwrap().gaps_disabled);
+
+        on_toggle_gaps(&mut state, true);
+        assert!(!state.get_workspace(ws_id).unwrap().gaps_disabled);
+        assert!(!state.get_workspace(other_id).unwrap().gaps_disabled);
+    }
+
+    #[test]
+    fn test_workspace_gaps_disabled_keeps_only_bar_offset() {
+        let (mut state, ws_id) = create_test_state();
+        let mut workspace = state.get_workspace(ws_id).unwrap();
+        workspace.gaps_disabled = true;
+        let screen = Screen {
+            id: 2,
+            is_main: false,
+            ..Default::default()
+        };
+        state.set_gap_adjustment(2, 10.0, 10.0);
+
+        assert!(workspace_gaps(&state, &workspace, &screen).is_zero());
+    }
+
+    #[test]
+    fn test_adjust_gaps_stops_at_zero_and_resets() {
+        let (mut state, _) = create_test_state();
+        let base = configured_gaps(&state.get_screen(1).unwrap());
+
+        on_adjust_gaps(&mut state, 4.0, 0.0);
+        on_adjust_gaps(&mut state, 4.0, 0.0);
+        assert_eq!(state.get_gap_adjustment(1), (8.0, 0.0));
+
+        // Shrinking past zero is held at zero, so growing again is immediate
+        on_adjust_gaps(&mut state, -1000.0, 0.0);
+        let max_inner = base.inner_h.max(base.inner_v);
+        assert_eq!(state.get_gap_adjustment(1), (-max_inner, 0.0));
+
+        on_reset_gaps(&mut state);
+        assert_eq!(state.get_gap_adjustment(1), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_next_layout_two_element_cycle() {
+        let cycle = [LayoutType::Split, LayoutType::Monocle];
+
+        assert_eq!(next_layout(LayoutType::Split, &cycle), LayoutType::Monocle);
+        assert_eq!(next_layout(LayoutType::Monocle, &cycle), LayoutType::Split);
+    }
+
+    #[test]
+    fn test_next_layout_outside_cycle_jumps_to_first() {
+        let cycle = [LayoutType::Split, LayoutType::Monocle];
+
+        assert_eq!(next_layout(LayoutType::Grid, &cycle), LayoutType::Split);
+    }
 }
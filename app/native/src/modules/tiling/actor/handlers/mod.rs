@@ -9,12 +9,14 @@
 //! - `layout` - Layout switching and cycling
 //! - `focus` - Focus cycling and directional focus
 //! - `window_move` - Moving windows between workspaces
+//! - `move_mode` - Moving the focused window with the arrow keys
 //! - `preset` - Floating preset application
 //! - `resize` - Split ratio manipulation and window resizing
 
 pub mod app;
 pub mod focus;
 pub mod layout;
+pub mod move_mode;
 pub mod preset;
 pub mod resize;
 pub mod screen;
@@ -24,19 +26,34 @@ pub mod workspace;
 
 // Re-export handler functions for convenience
 pub use app::{on_app_activated, on_app_hidden, on_app_launched, on_app_shown, on_app_terminated};
-pub use focus::{on_cycle_focus, on_focus_window, on_swap_window_in_direction};
-pub use layout::{on_cycle_layout, on_set_layout};
-pub use preset::on_apply_preset;
+pub use focus::{
+    on_cycle_app_focus, on_cycle_focus, on_focus_global_mru, on_focus_mru, on_focus_window,
+    on_swap_window_in_direction,
+};
+pub use layout::{
+    apply_auto_layouts, on_adjust_gaps, on_bar_region_changed, on_cycle_layout,
+    on_reset_auto_layout, on_reset_gaps, on_set_layout, on_set_master_position, on_toggle_gaps,
+    on_toggle_layout,
+};
+pub use move_mode::{on_begin_move_mode, on_end_move_mode, on_move_mode_step};
+pub use preset::{on_apply_preset, on_place_window};
 pub use resize::{on_resize_focused_window, on_resize_split, on_user_resize_completed};
-pub use screen::{get_screens_from_macos, on_screens_changed, on_set_screens};
+pub use screen::{
+    get_screens_from_macos, on_apply_config_changes, on_screens_changed, on_set_screens,
+};
 pub use window::{
-    on_batched_geometry_updates, on_window_created, on_window_created_silent, on_window_destroyed,
-    on_window_focused, on_window_fullscreen_changed, on_window_minimized, on_window_moved,
-    on_window_resized, on_window_title_changed, on_window_unfocused,
+    on_batched_geometry_updates, on_reconcile, on_window_created, on_window_created_silent,
+    on_window_destroyed, on_window_focused, on_window_fullscreen_changed, on_window_minimized,
+    on_window_moved, on_window_resize_applied, on_window_resize_refused, on_window_resized,
+    on_window_title_changed, on_window_unfocused,
 };
 pub use window_move::{
-    on_move_window_to_workspace, on_send_window_to_screen, on_swap_windows, on_toggle_floating,
+    on_gather_windows, on_group_app_windows, on_minimize_window, on_move_window_in_stack,
+    on_move_window_to_workspace, on_restore_minimized_window, on_send_window_to_screen,
+    on_set_window_opacity, on_swap_windows, on_toggle_always_on_top, on_toggle_extra_workspace,
+    on_toggle_floating, reset_always_on_top, reset_window_opacity,
 };
 pub use workspace::{
-    on_balance_workspace, on_cycle_workspace, on_send_workspace_to_screen, on_switch_workspace,
+    on_balance_workspace, on_center_cursor, on_cycle_workspace, on_focus_screen,
+    on_send_workspace_to_screen, on_swap_workspaces_between_screens, on_switch_workspace,
 };
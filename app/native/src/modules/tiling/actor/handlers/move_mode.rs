@@ -0,0 +1,284 @@
+//! Keyboard move mode handlers.
+//!
+//! While move mode is active, the hotkey module turns arrow keys into steps:
+//! floating windows are nudged by a fixed number of pixels, tiled windows are
+//! swapped with their neighbor in that direction. The focused border is drawn
+//! wider for the duration of the session, and ending it without committing
+//! puts the window back where it was. The session also ends when its window
+//! is destroyed, or when tiling is disabled or reset.
+
+use super::focus::on_swap_window_in_direction;
+use crate::modules::tiling::actor::messages::FocusDirection;
+use crate::modules::tiling::borders;
+use crate::modules::tiling::effects::window_ops;
+use crate::modules::tiling::init::get_subscriber_handle;
+use crate::modules::tiling::state::{LayoutType, MoveModeSession, Rect, TilingState};
+
+/// Start a move mode session for the focused window.
+///
+/// Returns `false` if there is no focused window to move.
+pub fn on_begin_move_mode(state: &mut TilingState) -> bool {
+    let focus = state.get_focus_state();
+    let (Some(workspace_id), Some(window_id)) =
+        (focus.focused_workspace_id, focus.focused_window_id)
+    else {
+        tracing::debug!("move_mode: no focused window");
+        return false;
+    };
+
+    let Some(window) = state.get_window(window_id) else {
+        tracing::debug!("move_mode: window {window_id} not found");
+        return false;
+    };
+
+    if window.is_fullscreen {
+        tracing::debug!("move_mode: window {window_id} is in native fullscreen");
+        return false;
+    }
+
+    let Some(workspace) = state.get_workspace(workspace_id) else {
+        tracing::debug!("move_mode: workspace {workspace_id} not found");
+        return false;
+    };
+
+    // A session left over from another window is committed as is
+    if state.move_mode().is_some_and(|session| session.window_id != window_id) {
+        on_end_move_mode(state, true);
+    }
+
+    state.begin_move_mode(MoveModeSession {
+        window_id,
+        workspace_id,
+        frame: window.frame,
+        window_ids: workspace.window_ids.to_vec(),
+    });
+
+    borders::set_move_mode(true);
+    tracing::debug!("move_mode: started for window {window_id}");
+    true
+}
+
+/// Move the window of the move mode session one step in a direction.
+///
+/// Floating windows move by `step` pixels. Tiled windows swap with their
+/// neighbor in that direction.
+pub fn on_move_mode_step(state: &mut TilingState, direction: FocusDirection, step: u32) {
+    let Some(session) = state.move_mode().cloned() else {
+        tracing::debug!("move_mode: no session in progress");
+        return;
+    };
+
+    let Some(window) = state.get_window(session.window_id) else {
+        tracing::debug!("move_mode: window {} is gone", session.window_id);
+        on_end_move_mode(state, true);
+        return;
+    };
+
+    if !is_floating(state, &session) {
+        on_swap_window_in_direction(state, direction);
+        return;
+    }
+
+    let Some(frame) = nudged_frame(window.frame, direction, f64::from(step)) else {
+        return;
+    };
+
+    state.update_window(session.window_id, |w| w.frame = frame);
+    let _ = window_ops::set_window_frame_fast(session.window_id, &frame);
+}
+
+/// End the move mode session.
+///
+/// With `commit`, the window stays where it was moved. Otherwise a floating
+/// window goes back to its frame, and a tiled one to its place in the stack.
+pub fn on_end_move_mode(state: &mut TilingState, commit: bool) {
+    let Some(session) = state.end_move_mode() else {
+        return;
+    };
+
+    borders::set_move_mode(false);
+
+    if commit || !state.has_window(session.window_id) {
+        tracing::debug!("move_mode: committed window {}", session.window_id);
+        return;
+    }
+
+    if is_floating(state, &session) {
+        state.update_window(session.window_id, |w| w.frame = session.frame);
+        let _ = window_ops::set_window_frame(session.window_id, &session.frame);
+    } else if restore_window_order(state, &session)
+        && let Some(handle) = get_subscriber_handle()
+    {
+        handle.notify_layout_changed(session.workspace_id, true);
+    }
+
+    tracing::debug!("move_mode: cancelled for window {}", session.window_id);
+}
+
+/// Returns whether the session's window is moved freely rather than swapped.
+fn is_floating(state: &TilingState, session: &MoveModeSession) -> bool {
+    state.get_window(session.window_id).is_some_and(|w| w.is_floating)
+        || state
+            .get_workspace(session.workspace_id)
+            .is_some_and(|ws| ws.layout == LayoutType::Floating)
+}
+
+/// Puts the windows of the session's workspace back in their original order.
+///
+/// Does nothing if windows were added or removed meanwhile. Returns whether
+/// the order changed.
+fn restore_window_order(state: &mut TilingState, session: &MoveModeSession) -> bool {
+    let Some(workspace) = state.get_workspace(session.workspace_id) else {
+        return false;
+    };
+
+    if workspace.window_ids.as_slice() == session.window_ids.as_slice() {
+        return false;
+    }
+
+    let mut current = workspace.window_ids.to_vec();
+    let mut original = session.window_ids.clone();
+    current.sort_unstable();
+    original.sort_unstable();
+    if current != original {
+        tracing::debug!("move_mode: workspace windows changed, not restoring order");
+        return false;
+    }
+
+    state.update_workspace(session.workspace_id, |ws| {
+        ws.window_ids = session.window_ids.iter().copied().collect();
+        ws.focused_window_index = ws.window_ids.iter().position(|&id| id == session.window_id);
+    });
+    true
+}
+
+/// Returns `frame` moved by `step` pixels in a spatial direction.
+fn nudged_frame(frame: Rect, direction: FocusDirection, step: f64) -> Option<Rect> {
+    let (dx, dy) = match direction {
+        FocusDirection::Up => (0.0, -step),
+        FocusDirection::Down => (0.0, step),
+        FocusDirection::Left => (-step, 0.0),
+        FocusDirection::Right => (step, 0.0),
+        FocusDirection::Next | FocusDirection::Previous => return None,
+    };
+
+    Some(Rect::new(frame.x + dx, frame.y + dy, frame.width, frame.height))
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::modules::tiling::state::{Screen, Window, Workspace};
+
+    fn create_test_state(layout: LayoutType) -> (TilingState, Uuid) {
+        let mut state = TilingState::new();
+        state.upsert_screen(Screen {
+            id: 1,
+            name: "Test Screen".to_string(),
+            is_main: true,
+            ..Default::default()
+        });
+
+        let mut workspace = Workspace::new("workspace1");
+        workspace.screen_id = 1;
+        workspace.is_visible = true;
+        workspace.is_focused = true;
+        workspace.layout = layout;
+        let workspace_id = workspace.id;
+        state.upsert_workspace(workspace);
+
+        for (window_id, x) in [(100, 0.0), (200, 960.0)] {
+            state.upsert_window(Window {
+                id: window_id,
+                workspace_id,
+                frame: Rect::new(x, 0.0, 960.0, 1080.0),
+                ..Default::default()
+            });
+            state.update_workspace(workspace_id, |ws| ws.window_ids.push(window_id));
+        }
+
+        state.update_workspace(workspace_id, |ws| ws.focused_window_index = Some(0));
+        state.update_focus(|focus| {
+            focus.focused_workspace_id = Some(workspace_id);
+            focus.focused_window_id = Some(100);
+            focus.focused_screen_id = Some(1);
+        });
+
+        (state, workspace_id)
+    }
+
+    #[test]
+    fn test_nudged_frame() {
+        let frame = Rect::new(100.0, 100.0, 400.0, 300.0);
+
+        assert_eq!(
+            nudged_frame(frame, FocusDirection::Up, 20.0),
+            Some(Rect::new(100.0, 80.0, 400.0, 300.0))
+        );
+        assert_eq!(
+            nudged_frame(frame, FocusDirection::Right, 20.0),
+            Some(Rect::new(120.0, 100.0, 400.0, 300.0))
+        );
+        assert_eq!(nudged_frame(frame, FocusDirection::Next, 20.0), None);
+    }
+
+    #[test]
+    fn test_move_mode_cancel_restores_floating_frame() {
+        let (mut state, _) = create_test_state(LayoutType::Floating);
+
+        assert!(on_begin_move_mode(&mut state));
+        on_move_mode_step(&mut state, FocusDirection::Down, 20);
+        on_move_mode_step(&mut state, FocusDirection::Down, 20);
+        assert_eq!(state.get_window(100).unwrap().frame.y, 40.0);
+
+        on_end_move_mode(&mut state, false);
+        assert_eq!(state.get_window(100).unwrap().frame.y, 0.0);
+        assert!(state.move_mode().is_none());
+    }
+
+    #[test]
+    fn test_move_mode_cancel_restores_tiled_order() {
+        let (mut state, workspace_id) = create_test_state(LayoutType::Dwindle);
+
+        assert!(on_begin_move_mode(&mut state));
+        on_move_mode_step(&mut state, FocusDirection::Right, 20);
+        assert_eq!(
+            state.get_workspace(workspace_id).unwrap().window_ids.as_slice(),
+            &[200, 100]
+        );
+
+        on_end_move_mode(&mut state, false);
+        let workspace = state.get_workspace(workspace_id).unwrap();
+        assert_eq!(workspace.window_ids.as_slice(), &[100, 200]);
+        assert_eq!(workspace.focused_window_index, Some(0));
+    }
+
+    #[test]
+    fn test_move_mode_ends_when_window_is_destroyed() {
+        let (mut state, _) = create_test_state(LayoutType::Floating);
+
+        assert!(on_begin_move_mode(&mut state));
+        super::super::on_window_destroyed(&mut state, 100);
+
+        assert!(state.move_mode().is_none());
+    }
+
+    #[test]
+    fn test_move_mode_commit_keeps_position() {
+        let (mut state, _) = create_test_state(LayoutType::Floating);
+
+        assert!(on_begin_move_mode(&mut state));
+        on_move_mode_step(&mut state, FocusDirection::Left, 10);
+        on_end_move_mode(&mut state, true);
+
+        assert_eq!(state.get_window(100).unwrap().frame.x, -10.0);
+        assert!(state.move_mode().is_none());
+    }
+}
@@ -2,7 +2,10 @@
 //!
 //! These handlers manage applying floating presets to windows.
 
-use crate::modules::tiling::state::{LayoutType, TilingState};
+use super::layout::workspace_gaps;
+use super::window_move::on_toggle_floating;
+use crate::modules::tiling::actor::WindowPlacement;
+use crate::modules::tiling::state::{LayoutType, Rect, TilingState};
 
 // ============================================================================
 // Floating Preset Commands
@@ -11,7 +14,8 @@ use crate::modules::tiling::state::{LayoutType, TilingState};
 /// Apply a floating preset to the focused window.
 ///
 /// Presets define window size and position relative to the screen (centered, half-screen, etc.).
-/// Only works when the workspace is in Floating layout mode.
+/// A tiled window is made floating first. Applying `left-half` or `right-half`
+/// to a window already at that side cycles through the side's thirds.
 ///
 /// # Arguments
 ///
@@ -20,7 +24,7 @@ use crate::modules::tiling::state::{LayoutType, TilingState};
 #[allow(clippy::cast_possible_truncation)]
 pub fn on_apply_preset(state: &mut TilingState, preset_name: &str) {
     use crate::config::get_config;
-    use crate::modules::tiling::layout::{Gaps, calculate_preset_frame, find_preset};
+    use crate::modules::tiling::layout::{calculate_preset_frame, cycle_preset, find_preset};
 
     // Find the preset
     let Some(preset) = find_preset(preset_name) else {
@@ -39,42 +43,51 @@ pub fn on_apply_preset(state: &mut TilingState, preset_name: &str) {
         return;
     };
 
-    // Presets only work in floating layout
-    if workspace.layout != LayoutType::Floating {
-        tracing::debug!(
-            "apply_preset: workspace '{}' is not in Floating layout (is {:?})",
-            workspace.name,
-            workspace.layout
-        );
-        return;
-    }
-
     let focused_idx = workspace.focused_window_index.unwrap_or(0);
     let Some(&window_id) = workspace.window_ids.get(focused_idx) else {
         tracing::debug!("apply_preset: no window at focused index");
         return;
     };
 
+    if state.get_window(window_id).is_some_and(|w| w.is_fullscreen) {
+        tracing::debug!("apply_preset: window {window_id} is in native fullscreen");
+        return;
+    }
+
     let Some(screen) = state.get_screen(workspace.screen_id) else {
         tracing::debug!("apply_preset: screen not found");
         return;
     };
 
-    // Get gaps from config
-    let config = get_config();
-    let bar_offset = if config.bar.is_enabled() {
-        f64::from(config.bar.height) + f64::from(config.bar.padding)
-    } else {
-        0.0
-    };
-    let gaps = Gaps::from_config(&config.tiling.gaps, &screen.name, screen.is_main, bar_offset);
+    // Float a tiled window first, so the next layout pass leaves the preset frame alone
+    let is_floating = state.get_window(window_id).is_some_and(|w| w.is_floating);
+    if workspace.layout != LayoutType::Floating && !is_floating {
+        on_toggle_floating(state, window_id);
+    }
 
-    // Calculate the target frame
-    let target_frame = calculate_preset_frame(&preset, &screen.visible_frame, &gaps);
+    // Get gaps from config, unless toggled off for the workspace
+    let gaps = workspace_gaps(state, &workspace, &screen);
+    let config = get_config();
 
     // Get current frame for animation
     let current_frame = state.get_window(window_id).map(|w| w.frame);
 
+    // Repeating a cycling preset moves on to the next one
+    let preset = match &current_frame {
+        Some(frame) => cycle_preset(&config.tiling.floating, preset, frame, |p| {
+            calculate_preset_frame(p, &screen.visible_frame, &gaps, None)
+        }),
+        None => preset,
+    };
+
+    // Calculate the target frame
+    let cursor = if preset.under_cursor {
+        crate::modules::tiling::effects::window_ops::cursor_position()
+    } else {
+        None
+    };
+    let target_frame = calculate_preset_frame(&preset, &screen.visible_frame, &gaps, cursor);
+
     // Update window frame in state
     state.update_window(window_id, |w| {
         w.frame = target_frame;
@@ -95,7 +108,7 @@ pub fn on_apply_preset(state: &mut TilingState, preset_name: &str) {
 
     tracing::debug!(
         "Applied preset '{}' to window {window_id}: ({}, {}, {}, {})",
-        preset_name,
+        preset.name,
         target_frame.x as i32,
         target_frame.y as i32,
         target_frame.width as i32,
@@ -103,6 +116,55 @@ pub fn on_apply_preset(state: &mut TilingState, preset_name: &str) {
     );
 }
 
+/// Set the focused window's frame to an exact placement.
+///
+/// The placement is relative to the visible frame of the window's screen, or
+/// to the area inside the outer gaps with `placement.gaps`, and is clamped to
+/// stay inside it. A tiled window is made floating first.
+///
+/// Returns the frame applied, or `None` without a focused window.
+pub fn on_place_window(state: &mut TilingState, placement: &WindowPlacement) -> Option<Rect> {
+    use crate::modules::tiling::layout::calculate_placement_frame;
+
+    let focus = state.get_focus_state();
+    let Some(window_id) = focus.focused_window_id else {
+        tracing::debug!("place_window: no focused window");
+        return None;
+    };
+
+    let window = state.get_window(window_id)?;
+    if window.is_fullscreen {
+        tracing::debug!("place_window: window {window_id} is in native fullscreen");
+        return None;
+    }
+
+    let workspace = state.get_workspace(window.workspace_id)?;
+    let screen = state.get_screen(workspace.screen_id)?;
+
+    if workspace.layout != LayoutType::Floating && !window.is_floating {
+        on_toggle_floating(state, window_id);
+    }
+
+    let area = if placement.gaps {
+        workspace_gaps(state, &workspace, &screen).apply_outer(&screen.visible_frame)
+    } else {
+        screen.visible_frame
+    };
+    let frame = calculate_placement_frame(
+        &placement.x,
+        &placement.y,
+        &placement.width,
+        &placement.height,
+        &area,
+    );
+
+    state.update_window(window_id, |w| w.frame = frame);
+    let _ = crate::modules::tiling::effects::window_ops::set_window_frame(window_id, &frame);
+
+    tracing::debug!("Placed window {window_id} at {frame:?}");
+    Some(frame)
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -111,6 +173,7 @@ pub fn on_apply_preset(state: &mut TilingState, preset_name: &str) {
 #[allow(clippy::float_cmp)]
 mod tests {
     use super::*;
+    use crate::config::DimensionValue;
     use crate::modules::tiling::state::{Rect, Screen, Window, Workspace};
 
     fn create_test_state() -> TilingState {
@@ -145,7 +208,7 @@ mod tests {
     }
 
     #[test]
-    fn test_apply_preset_not_floating() {
+    fn test_apply_preset_floats_tiled_window() {
         let mut state = create_test_state();
         let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
 
@@ -167,14 +230,14 @@ mod tests {
             ws.focused_window_index = Some(0);
         });
 
-        // Try to apply preset (should be ignored)
-        let original_frame = state.get_window(100).unwrap().frame;
-        on_apply_preset(&mut state, "center");
+        on_apply_preset(&mut state, "maximize");
 
-        // Frame should be unchanged since not in floating layout
-        let current_frame = state.get_window(100).unwrap().frame;
-        assert_eq!(original_frame.x, current_frame.x);
-        assert_eq!(original_frame.y, current_frame.y);
+        // The window is floated first, keeping its tiled frame to return to
+        let window = state.get_window(100).unwrap();
+        assert!(window.is_floating);
+        assert_eq!(window.float_frame, Some(Rect::new(100.0, 100.0, 400.0, 300.0)));
+        assert_eq!(window.frame.width, 1920.0);
+        assert_eq!(window.frame.height, 1080.0);
     }
 
     #[test]
@@ -206,4 +269,47 @@ mod tests {
         // Try to apply invalid preset (should not panic)
         on_apply_preset(&mut state, "nonexistent_preset_xyz");
     }
+
+    #[test]
+    fn test_place_window_floats_and_clamps_to_screen() {
+        let mut state = create_test_state();
+        let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        state.update_workspace(ws_id, |ws| ws.layout = LayoutType::Dwindle);
+        state.upsert_window(Window {
+            id: 100,
+            workspace_id: ws_id,
+            frame: Rect::new(0.0, 0.0, 960.0, 1080.0),
+            ..Default::default()
+        });
+        state.update_workspace(ws_id, |ws| ws.window_ids.push(100));
+        state.update_focus(|focus| focus.focused_window_id = Some(100));
+
+        let placement = WindowPlacement {
+            x: DimensionValue::Percentage("75%".to_string()),
+            y: DimensionValue::Pixels(100),
+            width: DimensionValue::Percentage("50%".to_string()),
+            height: DimensionValue::Pixels(600),
+            gaps: false,
+        };
+        let frame = on_place_window(&mut state, &placement);
+
+        assert_eq!(frame, Some(Rect::new(960.0, 100.0, 960.0, 600.0)));
+        let window = state.get_window(100).unwrap();
+        assert!(window.is_floating);
+        assert_eq!(window.frame, Rect::new(960.0, 100.0, 960.0, 600.0));
+    }
+
+    #[test]
+    fn test_place_window_without_focused_window() {
+        let mut state = create_test_state();
+        let placement = WindowPlacement {
+            x: DimensionValue::Pixels(0),
+            y: DimensionValue::Pixels(0),
+            width: DimensionValue::Pixels(100),
+            height: DimensionValue::Pixels(100),
+            gaps: true,
+        };
+
+        assert_eq!(on_place_window(&mut state, &placement), None);
+    }
 }
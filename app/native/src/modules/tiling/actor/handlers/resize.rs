@@ -5,10 +5,12 @@
 
 use uuid::Uuid;
 
+use super::layout::workspace_gaps;
 use crate::config::get_config;
 use crate::modules::tiling::actor::messages::ResizeDimension;
+use crate::modules::tiling::actor::minimum_size::is_dwindle_split_horizontal;
 use crate::modules::tiling::init::get_subscriber_handle;
-use crate::modules::tiling::layout::MasterPosition;
+use crate::modules::tiling::layout::{DwindleSplit, Gaps, MasterPosition};
 use crate::modules::tiling::state::{LayoutType, Rect, TilingState};
 
 // ============================================================================
@@ -131,11 +133,14 @@ pub fn on_resize_split(
         tracing::debug!("resize_split: screen not found");
         return;
     };
+    let gaps = workspace_gaps(state, &workspace, &screen);
 
     // Determine which dimension we're resizing (for split layouts)
-    let is_horizontal = matches!(layout, LayoutType::Split | LayoutType::SplitHorizontal)
-        || (layout == LayoutType::Split
-            && screen.visible_frame.width >= screen.visible_frame.height);
+    let is_horizontal = match layout {
+        LayoutType::SplitHorizontal => true,
+        LayoutType::Split => screen.visible_frame.is_landscape(),
+        _ => false,
+    };
     let total_size = if is_horizontal {
         screen.visible_frame.width
     } else {
@@ -167,8 +172,7 @@ pub fn on_resize_split(
                 &layoutable,
                 state,
                 &screen.visible_frame,
-                &screen.name,
-                screen.is_main,
+                &gaps,
             );
         }
         LayoutType::Grid => {
@@ -180,8 +184,7 @@ pub fn on_resize_split(
                 &layoutable,
                 state,
                 &screen.visible_frame,
-                &screen.name,
-                screen.is_main,
+                &gaps,
             );
         }
         _ => {
@@ -238,24 +241,24 @@ fn on_resize_master(state: &mut TilingState, workspace_id: Uuid, delta: f64) {
 /// Resolve the effective master position for the given workspace/screen,
 /// applying the same Auto logic used by the layout engine.
 fn resolve_master_position(state: &TilingState, workspace_id: Uuid) -> MasterPosition {
-    let config = get_config();
-    let config_pos = MasterPosition::from(config.tiling.master.position);
+    let workspace = state.get_workspace(workspace_id);
+    let position = MasterPosition::from(
+        workspace
+            .as_ref()
+            .and_then(|ws| ws.master_position)
+            .unwrap_or(get_config().tiling.master.position),
+    );
 
     // Auto needs to know the screen orientation
-    if config_pos != MasterPosition::Auto {
-        return config_pos;
+    if position != MasterPosition::Auto {
+        return position;
     }
 
-    let is_landscape = state
-        .get_workspace(workspace_id)
+    let is_landscape = workspace
         .and_then(|ws| state.get_screen(ws.screen_id))
-        .is_none_or(|s| s.visible_frame.width >= s.visible_frame.height);
+        .is_none_or(|s| s.visible_frame.is_landscape());
 
-    if is_landscape {
-        MasterPosition::Left
-    } else {
-        MasterPosition::Top
-    }
+    position.resolve(is_landscape)
 }
 
 // ============================================================================
@@ -361,7 +364,6 @@ fn apply_split_resize_with_minimums(
 /// In Dwindle, ratio[i] controls the split between window i and window i+1.
 /// This function checks if the resize would violate minimum sizes and
 /// limits the resize accordingly.
-#[allow(clippy::too_many_arguments)]
 fn apply_dwindle_resize_with_minimums(
     ratios: &mut [f64],
     index: usize,
@@ -369,25 +371,16 @@ fn apply_dwindle_resize_with_minimums(
     layoutable: &[u32],
     state: &TilingState,
     screen_frame: &Rect,
-    screen_name: &str,
-    is_main_screen: bool,
+    gaps: &Gaps,
 ) {
-    use crate::config::get_config;
-    use crate::modules::tiling::layout::{Gaps, MasterPosition, calculate_layout_full};
+    use crate::modules::tiling::layout::{MasterPosition, calculate_layout_full};
+
+    let first_split = DwindleSplit::from(get_config().tiling.dwindle.first_split);
 
     if index >= ratios.len() {
         return;
     }
 
-    // Get config for gaps
-    let config = get_config();
-    let bar_offset = if config.bar.is_enabled() && is_main_screen {
-        f64::from(config.bar.height) + f64::from(config.bar.padding)
-    } else {
-        0.0
-    };
-    let gaps = Gaps::from_config(&config.tiling.gaps, screen_name, is_main_screen, bar_offset);
-
     // Calculate proposed new ratio
     let current_ratio = ratios[index];
     let proposed_ratio = (current_ratio + delta).clamp(0.1, 0.9);
@@ -402,9 +395,10 @@ fn apply_dwindle_resize_with_minimums(
         layoutable,
         screen_frame,
         0.5,
-        &gaps,
+        gaps,
         &proposed_ratios,
         MasterPosition::Auto,
+        first_split,
     );
 
     // Check if any window would violate its minimum size
@@ -432,7 +426,6 @@ fn apply_dwindle_resize_with_minimums(
 ///
 /// In Grid, the first ratio typically controls the primary split.
 /// This function checks if the resize would violate minimum sizes.
-#[allow(clippy::too_many_arguments)]
 fn apply_grid_resize_with_minimums(
     ratios: &mut [f64],
     index: usize,
@@ -440,25 +433,14 @@ fn apply_grid_resize_with_minimums(
     layoutable: &[u32],
     state: &TilingState,
     screen_frame: &Rect,
-    screen_name: &str,
-    is_main_screen: bool,
+    gaps: &Gaps,
 ) {
-    use crate::config::get_config;
-    use crate::modules::tiling::layout::{Gaps, MasterPosition, calculate_layout_full};
+    use crate::modules::tiling::layout::{MasterPosition, calculate_layout_full};
 
     if index >= ratios.len() {
         return;
     }
 
-    // Get config for gaps
-    let config = get_config();
-    let bar_offset = if config.bar.is_enabled() && is_main_screen {
-        f64::from(config.bar.height) + f64::from(config.bar.padding)
-    } else {
-        0.0
-    };
-    let gaps = Gaps::from_config(&config.tiling.gaps, screen_name, is_main_screen, bar_offset);
-
     // Calculate proposed new ratio
     let current_ratio = ratios[index];
     let proposed_ratio = (current_ratio + delta).clamp(0.1, 0.9);
@@ -473,9 +455,10 @@ fn apply_grid_resize_with_minimums(
         layoutable,
         screen_frame,
         0.5,
-        &gaps,
+        gaps,
         &proposed_ratios,
         MasterPosition::Auto,
+        DwindleSplit::Auto,
     );
 
     // Check if any window would violate its minimum size
@@ -556,7 +539,8 @@ pub fn on_resize_focused_window(state: &mut TilingState, dimension: ResizeDimens
         return;
     };
 
-    let is_landscape = screen.visible_frame.width >= screen.visible_frame.height;
+    let starts_horizontal = DwindleSplit::from(get_config().tiling.dwindle.first_split)
+        .starts_horizontal(screen.visible_frame.is_landscape());
 
     // Calculate delta as a ratio of screen dimension
     let delta_ratio = match dimension {
@@ -630,11 +614,8 @@ pub fn on_resize_focused_window(state: &mut TilingState, dimension: ResizeDimens
                 }
             } else {
                 // Determine if this split is horizontal or vertical
-                let is_horizontal_split = if is_landscape {
-                    (split_index + 1) % 2 == 1 // odd splits are horizontal in landscape
-                } else {
-                    (split_index + 1) % 2 == 0 // even splits are horizontal in portrait
-                };
+                let is_horizontal_split =
+                    is_dwindle_split_horizontal(split_index + 1, starts_horizontal);
 
                 // Apply delta based on dimension matching split direction
                 let effective = if (dimension == ResizeDimension::Width && is_horizontal_split)
@@ -2,11 +2,15 @@
 //!
 //! These handlers process display configuration changes:
 //! - Screens changed → refresh screen list, create/reassign workspaces
+//! - Config reloaded → add configured workspaces, update layouts
 
 use core_graphics::display::CGDisplay;
 
-use crate::config::get_config;
+use crate::config::{DisplayRule, WorkspaceConfig, get_config};
+use crate::modules::tiling::ffi::display::display_uuid;
 use crate::modules::tiling::init::get_subscriber_handle;
+use crate::modules::tiling::layout_cache;
+use crate::modules::tiling::rules::matches_window;
 use crate::modules::tiling::state::{
     LayoutType, Rect, Screen, TilingState, WindowIdList, Workspace,
 };
@@ -45,10 +49,11 @@ pub fn on_screens_changed(state: &mut TilingState) {
         state.upsert_screen(screen);
     }
 
-    // Remove old screens
-    for screen_id in &removed_screens {
-        state.remove_screen(*screen_id);
-        tracing::debug!("Removed screen {screen_id}");
+    // Remove old screens, keeping them to recognize their UUIDs on reconnect
+    let removed_screens: Vec<Screen> =
+        removed_screens.into_iter().filter_map(|id| state.remove_screen(id)).collect();
+    for screen in &removed_screens {
+        tracing::debug!("Removed screen {}", screen.id);
     }
 
     // Track workspaces that need layout recomputation
@@ -73,6 +78,11 @@ pub fn on_screens_changed(state: &mut TilingState) {
     // Ensure each screen has at least one workspace
     ensure_screen_workspaces(state);
 
+    // Move pinned windows back to their display if it came back
+    if !is_initial_setup {
+        relocate_pinned_windows(state, &get_config().tiling.display_rules);
+    }
+
     // Set initial focus if not already set
     if state.get_focused_workspace().is_none() {
         set_initial_focus(state);
@@ -116,10 +126,11 @@ pub fn on_set_screens(state: &mut TilingState, screens: Vec<Screen>) {
         state.upsert_screen(screen);
     }
 
-    // Remove old screens
-    for screen_id in &removed_screens {
-        state.remove_screen(*screen_id);
-        tracing::debug!("Removed screen {screen_id}");
+    // Remove old screens, keeping them to recognize their UUIDs on reconnect
+    let removed_screens: Vec<Screen> =
+        removed_screens.into_iter().filter_map(|id| state.remove_screen(id)).collect();
+    for screen in &removed_screens {
+        tracing::debug!("Removed screen {}", screen.id);
     }
 
     // Track workspaces that need layout recomputation
@@ -144,6 +155,11 @@ pub fn on_set_screens(state: &mut TilingState, screens: Vec<Screen>) {
     // Ensure each screen has at least one workspace
     ensure_screen_workspaces(state);
 
+    // Move pinned windows back to their display if it came back
+    if !is_initial_setup {
+        relocate_pinned_windows(state, &get_config().tiling.display_rules);
+    }
+
     // Set initial focus if not already set
     if state.get_focused_workspace().is_none() {
         set_initial_focus(state);
@@ -178,50 +194,117 @@ fn create_workspaces_from_config(state: &mut TilingState) {
             tiling_config.workspaces.len()
         );
         for ws_config in &tiling_config.workspaces {
-            // Find the screen for this workspace
-            // If the configured screen doesn't exist, fall back to main screen
-            let screen_id = resolve_screen_name(state, &ws_config.screen).or_else(|| {
-                tracing::trace!(
-                    "tiling: workspace '{}' screen '{}' not found, falling back to main",
-                    ws_config.name,
-                    ws_config.screen
-                );
-                state.get_main_screen().map(|s| s.id)
-            });
+            add_workspace_from_config(state, ws_config, tiling_config.default_layout);
+        }
+    }
+}
 
-            if let Some(screen_id) = screen_id {
-                let config_layout = ws_config.layout.unwrap_or(tiling_config.default_layout);
-                let layout = convert_layout_type(config_layout);
-                let workspace = Workspace {
-                    id: uuid::Uuid::now_v7(),
-                    name: ws_config.name.clone(),
-                    screen_id,
-                    layout,
-                    is_visible: false,
-                    is_focused: false,
-                    window_ids: WindowIdList::new(),
-                    focused_window_index: None,
-                    split_ratios: Vec::new(),
-                    master_ratio: None,
-                    configured_screen: Some(ws_config.screen.clone()),
-                };
-                state.upsert_workspace(workspace);
-                tracing::debug!(
-                    "Created workspace '{}' on screen {} with layout {:?}",
-                    ws_config.name,
-                    screen_id,
-                    layout
-                );
-            }
+/// Creates a workspace from its configuration and adds it to the state.
+///
+/// If the configured screen doesn't exist, the workspace is placed on the main screen.
+fn add_workspace_from_config(
+    state: &mut TilingState,
+    ws_config: &WorkspaceConfig,
+    default_layout: crate::config::LayoutType,
+) {
+    let screen_id = resolve_screen_name(state, &ws_config.screen).or_else(|| {
+        tracing::trace!(
+            "tiling: workspace '{}' screen '{}' not found, falling back to main",
+            ws_config.name,
+            ws_config.screen
+        );
+        state.get_main_screen().map(|s| s.id)
+    });
+
+    let Some(screen_id) = screen_id else {
+        return;
+    };
+
+    let layout = convert_layout_type(ws_config.layout.unwrap_or(default_layout));
+    let mut workspace = Workspace {
+        id: uuid::Uuid::now_v7(),
+        name: ws_config.name.clone(),
+        screen_id,
+        layout,
+        is_visible: false,
+        is_focused: false,
+        window_ids: WindowIdList::new(),
+        focused_window_index: None,
+        split_ratios: Vec::new(),
+        master_ratio: None,
+        configured_screen: Some(ws_config.screen.clone()),
+        home_screen_uuid: None,
+        toggled_from: None,
+        layout_pinned: false,
+        master_position: None,
+        gaps_disabled: false,
+    };
+    layout_cache::restore(&mut workspace);
+
+    tracing::debug!(
+        "Created workspace '{}' on screen {} with layout {:?}",
+        ws_config.name,
+        screen_id,
+        workspace.layout
+    );
+    state.upsert_workspace(workspace);
+}
+
+/// Applies tiling config changes from a live reload, keeping all tracked windows.
+///
+/// Adds newly configured workspaces, updates the layout of workspaces whose
+/// configured layout changed, re-assigns screen roles, and re-layouts visible
+/// workspaces when gaps or master settings changed.
+pub fn on_apply_config_changes(
+    state: &mut TilingState,
+    added_workspaces: &[WorkspaceConfig],
+    layout_changes: &[(String, crate::config::LayoutType)],
+    relayout: bool,
+) {
+    let default_layout = get_config().tiling.default_layout;
+
+    let screens: Vec<Screen> = state.screens.iter().cloned().collect();
+    for mut screen in screens {
+        let roles = get_config().tiling.screen_roles_for(&screen.name, &screen.uuid);
+        if screen.roles != roles {
+            tracing::debug!(
+                "tiling: reload: screen '{}' roles are now {roles:?}",
+                screen.name
+            );
+            screen.roles = roles;
+            state.upsert_screen(screen);
+        }
+    }
+
+    for ws_config in added_workspaces {
+        if state.get_workspace_by_name(&ws_config.name).is_none() {
+            add_workspace_from_config(state, ws_config, default_layout);
+        }
+    }
+
+    for (name, layout) in layout_changes {
+        let Some(workspace) = state.get_workspace_by_name(name) else {
+            tracing::warn!("tiling: reload: workspace '{name}' not found");
+            continue;
+        };
+        super::on_set_layout(state, workspace.id, convert_layout_type(*layout));
+    }
+
+    if relayout && let Some(handle) = get_subscriber_handle() {
+        for ws_id in state.get_visible_workspace_ids() {
+            handle.notify_layout_changed(ws_id, true);
         }
     }
+
+    tracing::info!(
+        "tiling: config reloaded ({} workspace(s) added, {} layout change(s))",
+        added_workspaces.len(),
+        layout_changes.len()
+    );
 }
 
 /// Creates a default workspace for each screen.
 fn create_default_workspaces(state: &mut TilingState) {
-    let config = get_config();
-    let default_layout = convert_layout_type(config.tiling.default_layout);
-
     let screen_info: Vec<(usize, u32)> =
         state.screens.iter().enumerate().map(|(i, s)| (i, s.id)).collect();
 
@@ -232,7 +315,7 @@ fn create_default_workspaces(state: &mut TilingState) {
             id: uuid::Uuid::now_v7(),
             name: name.clone(),
             screen_id,
-            layout: default_layout,
+            layout: default_layout_for_screen(state, screen_id),
             is_visible: false,
             is_focused: false,
             window_ids: WindowIdList::new(),
@@ -240,6 +323,11 @@ fn create_default_workspaces(state: &mut TilingState) {
             split_ratios: Vec::new(),
             master_ratio: None,
             configured_screen: None,
+            home_screen_uuid: None,
+            toggled_from: None,
+            layout_pinned: false,
+            master_position: None,
+            gaps_disabled: false,
         };
         state.upsert_workspace(workspace);
         tracing::debug!("Created default workspace '{name}' on screen {screen_id}");
@@ -259,7 +347,7 @@ fn ensure_screen_workspaces(state: &mut TilingState) {
                 id: uuid::Uuid::now_v7(),
                 name: name.clone(),
                 screen_id,
-                layout: LayoutType::Dwindle,
+                layout: default_layout_for_screen(state, screen_id),
                 is_visible: false,
                 is_focused: false,
                 window_ids: WindowIdList::new(),
@@ -267,6 +355,11 @@ fn ensure_screen_workspaces(state: &mut TilingState) {
                 split_ratios: Vec::new(),
                 master_ratio: None,
                 configured_screen: None,
+                home_screen_uuid: None,
+                toggled_from: None,
+                layout_pinned: false,
+                master_position: None,
+                gaps_disabled: false,
             };
             state.upsert_workspace(workspace);
             tracing::debug!("Created fallback workspace '{name}' for screen {screen_id}");
@@ -274,10 +367,28 @@ fn ensure_screen_workspaces(state: &mut TilingState) {
     }
 }
 
+/// Returns the configured layout for workspaces created automatically on a screen.
+fn default_layout_for_screen(state: &TilingState, screen_id: u32) -> LayoutType {
+    let tiling = &get_config().tiling;
+    let layout = state.get_screen(screen_id).map_or(tiling.default_layout, |screen| {
+        let is_portrait = screen.visible_frame.is_portrait();
+        tiling.default_layout_for_screen(&screen.name, screen.is_main, is_portrait)
+    });
+    convert_layout_type(layout)
+}
+
 /// Resolves a screen name to a screen ID.
-fn resolve_screen_name(state: &TilingState, name: &str) -> Option<u32> {
-    // "main" or "primary" matches the main screen
+pub(super) fn resolve_screen_name(state: &TilingState, name: &str) -> Option<u32> {
+    // A role from `tiling.screenRoles` wins over the built-in names
+    if let Some(screen) = state.get_screen_with_role(name) {
+        return Some(screen.id);
+    }
+
+    // "main" or "primary" matches the main screen, unless another has the role
     if name == "main" || name == "primary" {
+        if let Some(screen) = state.get_screen_with_role("primary") {
+            return Some(screen.id);
+        }
         return state.get_main_screen().map(|s| s.id);
     }
 
@@ -286,6 +397,15 @@ fn resolve_screen_name(state: &TilingState, name: &str) -> Option<u32> {
         return state.screens.iter().find(|s| !s.is_main).map(|s| s.id);
     }
 
+    // Try to match by persistent display UUID
+    if let Some(screen) = state
+        .screens
+        .iter()
+        .find(|s| !s.uuid.is_empty() && s.uuid.eq_ignore_ascii_case(name))
+    {
+        return Some(screen.id);
+    }
+
     // Try to match by display name (exact)
     if let Some(screen) = state.screens.iter().find(|s| s.name == name) {
         return Some(screen.id);
@@ -345,7 +465,7 @@ fn set_initial_focus(state: &mut TilingState) {
 }
 
 /// Converts config `LayoutType` to state `LayoutType`.
-const fn convert_layout_type(config_layout: crate::config::LayoutType) -> LayoutType {
+pub(super) const fn convert_layout_type(config_layout: crate::config::LayoutType) -> LayoutType {
     match config_layout {
         crate::config::LayoutType::Dwindle => LayoutType::Dwindle,
         crate::config::LayoutType::Split => LayoutType::Split,
@@ -360,10 +480,13 @@ const fn convert_layout_type(config_layout: crate::config::LayoutType) -> Layout
 
 /// Reassigns workspaces from removed screens to the main screen.
 ///
+/// Each workspace remembers the UUID of the display it was on, to move back
+/// once that display reconnects.
+///
 /// Returns the IDs of workspaces that were reassigned (for layout recomputation).
 fn reassign_workspaces_from_removed_screens(
     state: &mut TilingState,
-    removed_screens: &[u32],
+    removed_screens: &[Screen],
 ) -> Vec<uuid::Uuid> {
     let main_screen_id = state.get_main_screen().map_or(0, |s| s.id);
 
@@ -373,14 +496,17 @@ fn reassign_workspaces_from_removed_screens(
     }
 
     // Find workspaces on removed screens
-    let workspaces_to_reassign: Vec<uuid::Uuid> = state
+    let workspaces_to_reassign: Vec<(uuid::Uuid, Option<String>)> = state
         .workspaces
         .iter()
-        .filter(|ws| removed_screens.contains(&ws.screen_id))
-        .map(|ws| ws.id)
+        .filter_map(|ws| {
+            let screen = removed_screens.iter().find(|s| s.id == ws.screen_id)?;
+            let uuid = (!screen.uuid.is_empty()).then(|| screen.uuid.clone());
+            Some((ws.id, uuid))
+        })
         .collect();
 
-    for ws_id in &workspaces_to_reassign {
+    for (ws_id, uuid) in &workspaces_to_reassign {
         state.update_workspace(*ws_id, |ws| {
             tracing::info!(
                 "Screen unplugged: moving workspace '{}' from screen {} to main screen {}",
@@ -389,30 +515,37 @@ fn reassign_workspaces_from_removed_screens(
                 main_screen_id
             );
             ws.screen_id = main_screen_id;
+            if uuid.is_some() {
+                ws.home_screen_uuid.clone_from(uuid);
+            }
         });
     }
 
-    workspaces_to_reassign
+    workspaces_to_reassign.into_iter().map(|(ws_id, _)| ws_id).collect()
 }
 
 /// Restores workspaces to their configured screens when those screens become available.
 ///
-/// This is called when screens change (hotplug). For each workspace that has a
-/// `configured_screen` set, we check if that screen is now available. If it is
-/// and the workspace is currently on a different screen, we move it back.
+/// This is called when screens change (hotplug). For each workspace that was
+/// unplugged from a display or has a `configured_screen` set, we check if that
+/// screen is now available. If it is and the workspace is currently on a
+/// different screen, we move it back. The display a workspace was unplugged
+/// from is matched by UUID and wins over the configured screen.
 ///
 /// Returns the IDs of workspaces that were restored (for layout recomputation).
 fn restore_workspaces_to_configured_screens(state: &mut TilingState) -> Vec<uuid::Uuid> {
     let mut restored_workspaces = Vec::new();
 
     // Collect workspaces that might need restoration
-    // (those with a configured_screen that doesn't match their current screen)
+    // (those with a home or configured screen that doesn't match their current screen)
     let workspaces_to_check: Vec<(uuid::Uuid, String, u32)> = state
         .workspaces
         .iter()
         .filter_map(|ws| {
-            ws.configured_screen
+            ws.home_screen_uuid
                 .as_ref()
+                .filter(|uuid| state.screens.iter().any(|s| s.uuid.eq_ignore_ascii_case(uuid)))
+                .or(ws.configured_screen.as_ref())
                 .map(|configured| (ws.id, configured.clone(), ws.screen_id))
         })
         .collect();
@@ -420,6 +553,12 @@ fn restore_workspaces_to_configured_screens(state: &mut TilingState) -> Vec<uuid
     for (ws_id, configured_screen, current_screen_id) in workspaces_to_check {
         // Try to resolve the configured screen name to an ID
         if let Some(target_screen_id) = resolve_screen_name(state, &configured_screen) {
+            state.update_workspace(ws_id, |ws| {
+                if ws.home_screen_uuid.as_ref() == Some(&configured_screen) {
+                    ws.home_screen_uuid = None;
+                }
+            });
+
             // Only move if the workspace is not already on its configured screen
             if current_screen_id != target_screen_id {
                 state.update_workspace(ws_id, |ws| {
@@ -439,6 +578,37 @@ fn restore_workspaces_to_configured_screens(state: &mut TilingState) -> Vec<uuid
     restored_workspaces
 }
 
+/// Moves windows pinned by a display rule to a workspace on their display.
+///
+/// Called on screen hotplug, so pinned windows return to their display once it
+/// reconnects. Windows go to the display's visible workspace.
+fn relocate_pinned_windows(state: &mut TilingState, display_rules: &[DisplayRule]) {
+    if display_rules.is_empty() {
+        return;
+    }
+
+    let moves: Vec<(u32, uuid::Uuid)> = state
+        .windows
+        .iter()
+        .filter_map(|window| {
+            let rule = display_rules.iter().find(|r| matches_window(&r.rule, window))?;
+            let screen_id = resolve_screen_name(state, &rule.screen)?;
+            let current = state.get_workspace(window.workspace_id)?;
+            if current.screen_id == screen_id {
+                return None;
+            }
+
+            let target = super::window::visible_workspace_on_screen(state, screen_id)?;
+            Some((window.id, target.id))
+        })
+        .collect();
+
+    for (window_id, workspace_id) in moves {
+        tracing::info!("Screen plugged back in: moving pinned window {window_id} to its display");
+        super::on_move_window_to_workspace(state, window_id, workspace_id, false);
+    }
+}
+
 /// Gets the current screen list from macOS.
 ///
 /// NOTE: This function uses `NSScreen` APIs which must be called from the main thread.
@@ -588,6 +758,10 @@ fn get_screen_info(display_id: u32, main_screen_height: f64) -> Option<Screen> {
             // Check if built-in (laptop screen)
             let is_builtin = CGDisplay::new(display_id).is_builtin();
 
+            // The UUID survives reconnecting to another port, unlike the ID
+            let uuid = display_uuid(display_id).unwrap_or_default();
+            let roles = get_config().tiling.screen_roles_for(&name, &uuid);
+
             // Convert from NSScreen (bottom-left origin) to AX API (top-left origin)
             // Formula: new_y = main_screen_height - old_y - rect_height
             let converted_frame = convert_to_top_left_origin(frame, main_screen_height);
@@ -603,6 +777,8 @@ fn get_screen_info(display_id: u32, main_screen_height: f64) -> Option<Screen> {
                 is_main,
                 is_builtin,
                 refresh_rate,
+                uuid,
+                roles,
             });
         }
 
@@ -691,6 +867,8 @@ mod tests {
             is_main,
             is_builtin: is_main,
             refresh_rate: 60.0,
+            uuid: String::new(),
+            roles: Vec::new(),
         }
     }
 
@@ -707,6 +885,11 @@ mod tests {
             split_ratios: Vec::new(),
             master_ratio: None,
             configured_screen: None,
+            home_screen_uuid: None,
+            toggled_from: None,
+            layout_pinned: false,
+            master_position: None,
+            gaps_disabled: false,
         }
     }
 
@@ -739,7 +922,8 @@ mod tests {
         assert_eq!(state.get_workspace(ws2_id).unwrap().screen_id, 2);
 
         // Remove screen 2
-        reassign_workspaces_from_removed_screens(&mut state, &[2]);
+        let removed = state.remove_screen(2).unwrap();
+        reassign_workspaces_from_removed_screens(&mut state, &[removed]);
 
         // Workspace should now be on screen 1 (main)
         assert_eq!(state.get_workspace(ws2_id).unwrap().screen_id, 1);
@@ -789,8 +973,8 @@ mod tests {
         assert_eq!(state.get_workspace(ws_id).unwrap().screen_id, 2);
 
         // UNPLUG: Remove the external screen
-        state.remove_screen(2);
-        reassign_workspaces_from_removed_screens(&mut state, &[2]);
+        let removed = state.remove_screen(2).unwrap();
+        reassign_workspaces_from_removed_screens(&mut state, &[removed]);
 
         // Workspace should move to main screen
         assert_eq!(state.get_workspace(ws_id).unwrap().screen_id, 1);
@@ -808,6 +992,72 @@ mod tests {
         assert_eq!(state.get_workspace(ws_id).unwrap().screen_id, 2);
     }
 
+    #[test]
+    fn test_unplugged_workspace_returns_to_display_by_uuid() {
+        let mut state = TilingState::new();
+        let screen_with_uuid = |id, name: &str, uuid: &str| Screen {
+            uuid: uuid.to_string(),
+            ..make_screen(id, name, false)
+        };
+
+        state.upsert_screen(make_screen(1, "Built-in Retina Display", true));
+        state.upsert_screen(screen_with_uuid(2, "DELL U2720Q", "AAAA-1111"));
+        state.upsert_screen(screen_with_uuid(3, "DELL U2720Q", "BBBB-2222"));
+
+        let ws = make_workspace("docs", 3);
+        let ws_id = ws.id;
+        state.upsert_workspace(ws);
+
+        let removed = state.remove_screen(3).unwrap();
+        reassign_workspaces_from_removed_screens(&mut state, &[removed]);
+        assert_eq!(state.get_workspace(ws_id).unwrap().screen_id, 1);
+
+        // The same display reconnects with another ID, next to its twin
+        state.upsert_screen(screen_with_uuid(4, "DELL U2720Q", "BBBB-2222"));
+        restore_workspaces_to_configured_screens(&mut state);
+
+        let ws = state.get_workspace(ws_id).unwrap();
+        assert_eq!(ws.screen_id, 4);
+        assert_eq!(ws.home_screen_uuid, None);
+    }
+
+    #[test]
+    fn test_relocate_pinned_windows_to_reconnected_display() {
+        use crate::config::WindowRule;
+        use crate::modules::tiling::state::Window;
+
+        let mut state = TilingState::new();
+        state.upsert_screen(make_screen(1, "LG UltraFine", true));
+        state.upsert_screen(make_screen(2, "Built-in Retina Display", false));
+
+        let mut main_ws = make_workspace("main", 1);
+        main_ws.window_ids.push(10);
+        let main_ws_id = main_ws.id;
+        let builtin_ws = make_workspace("laptop", 2);
+        let builtin_ws_id = builtin_ws.id;
+        state.upsert_workspace(main_ws);
+        state.upsert_workspace(builtin_ws);
+        state.upsert_window(Window {
+            id: 10,
+            app_id: "us.zoom.xos".to_string(),
+            workspace_id: main_ws_id,
+            ..Default::default()
+        });
+
+        let rules = [DisplayRule {
+            rule: WindowRule {
+                app_id: Some("us.zoom.xos".to_string()),
+                ..Default::default()
+            },
+            screen: "Built-in".to_string(),
+        }];
+        relocate_pinned_windows(&mut state, &rules);
+
+        assert_eq!(state.get_window(10).unwrap().workspace_id, builtin_ws_id);
+        assert!(state.get_workspace(builtin_ws_id).unwrap().window_ids.contains(&10));
+        assert!(state.get_workspace(main_ws_id).unwrap().window_ids.is_empty());
+    }
+
     #[test]
     fn test_get_screen_info_main() {
         let main_id = CGDisplay::main().id;
@@ -7,13 +7,21 @@
 //! - Window moved/resized → update frame
 //! - Window minimized/fullscreen → update state flags
 
+use std::collections::HashSet;
+
 use uuid::Uuid;
 
+use super::screen::resolve_screen_name;
+use crate::config::{FocusAfterClose, WindowOpenAnimation};
 use crate::modules::tiling::actor::messages::{
     GeometryUpdate, GeometryUpdateType, WindowCreatedInfo,
 };
-use crate::modules::tiling::effects::{get_window_cache, should_ignore_geometry_events};
-use crate::modules::tiling::init::get_subscriber_handle;
+use crate::modules::tiling::effects::animation::{Entrance, queue_entrance, take_entrance};
+use crate::modules::tiling::effects::{
+    get_window_cache, should_ignore_geometry_events, window_ops,
+};
+use crate::modules::tiling::init::{get_subscriber_handle, is_initialized};
+use crate::modules::tiling::rules::any_rule_matches;
 use crate::modules::tiling::state::{Rect, TilingState, Window, WindowIdList, Workspace};
 use crate::modules::tiling::tabs;
 
@@ -25,7 +33,12 @@ use crate::modules::tiling::tabs;
 /// 2. The focused workspace on the window's screen
 /// 3. A default workspace
 pub fn on_window_created(state: &mut TilingState, info: WindowCreatedInfo) {
-    let workspace_id = on_window_created_internal(state, info);
+    let window_id = info.window_id;
+    let workspace_id = on_window_created_internal(state, info, false);
+
+    if let Some(ws_id) = workspace_id {
+        queue_open_animation(state, window_id, ws_id);
+    }
 
     // Notify subscriber that layout needs to be recomputed for this workspace
     if let (Some(ws_id), Some(handle)) = (workspace_id, get_subscriber_handle()) {
@@ -33,24 +46,61 @@ pub fn on_window_created(state: &mut TilingState, info: WindowCreatedInfo) {
     }
 }
 
+/// Queues the `tiling.animations.windowOpen` entrance for a window that opened.
+///
+/// Only tiled windows joining a visible workspace are animated, and never
+/// the windows tracked at startup or while window management is paused.
+fn queue_open_animation(state: &TilingState, window_id: u32, workspace_id: Uuid) {
+    let animations = &crate::config::get_config().tiling.animations;
+    if !animations.enabled || !is_initialized() || state.is_paused() {
+        return;
+    }
+
+    let Some(window) = state.get_window(window_id) else {
+        return;
+    };
+
+    let visible = state.get_workspace(workspace_id).is_some_and(|ws| ws.is_visible);
+    if !visible || window.is_floating || !window.is_layoutable() {
+        return;
+    }
+
+    let entrance = match animations.window_open {
+        WindowOpenAnimation::None => return,
+        WindowOpenAnimation::Fade => Entrance::Fade {
+            opacity: window.opacity.unwrap_or(1.0),
+        },
+        WindowOpenAnimation::Scale => Entrance::Scale,
+    };
+
+    queue_entrance(window_id, entrance);
+}
+
 /// Handles a window created event without triggering layout notifications.
 ///
 /// Used during batch initialization to track all windows before applying layouts.
 pub fn on_window_created_silent(state: &mut TilingState, info: WindowCreatedInfo) {
-    let _ = on_window_created_internal(state, info);
+    let _ = on_window_created_internal(state, info, true);
     // No notification - caller will trigger layout after batch is complete
 }
 
 /// Internal implementation of window creation.
 ///
-/// Returns the workspace ID if a new window was created and layout should be triggered,
-/// None if window was just updated or is a tab (no layout needed).
-fn on_window_created_internal(state: &mut TilingState, info: WindowCreatedInfo) -> Option<Uuid> {
+/// Returns the workspace ID if a new window was created, or an already tracked
+/// one got its first real frame, and layout should be triggered. None if the
+/// window was just updated or is a tab (no layout needed).
+///
+/// `at_startup` is set for the windows already open when tiling starts.
+fn on_window_created_internal(
+    state: &mut TilingState,
+    info: WindowCreatedInfo,
+    at_startup: bool,
+) -> Option<Uuid> {
     tracing::debug!(
         "Handling window created: id={}, app={}, title='{}'",
         info.window_id,
         info.app_id,
-        info.title
+        crate::logging::redact_title(&info.title)
     );
 
     // Check if window already exists
@@ -58,10 +108,23 @@ fn on_window_created_internal(state: &mut TilingState, info: WindowCreatedInfo)
         tracing::debug!("Window {} already tracked, updating", info.window_id);
         state.update_window(info.window_id, |w| {
             w.title.clone_from(&info.title);
+            if info.subrole.is_some() {
+                w.subrole.clone_from(&info.subrole);
+            }
             w.frame = info.frame;
             w.is_minimized = info.is_minimized;
             w.is_fullscreen = info.is_fullscreen;
         });
+        return settle_awaiting_frame(state, info.window_id);
+    }
+
+    // Untitled windows are often transient splash or loader windows
+    if is_ignored_untitled(&info, &crate::config::get_config().tiling) {
+        tracing::debug!(
+            "Window {} (app={}) has no title, ignoring",
+            info.window_id,
+            info.app_id
+        );
         return None;
     }
 
@@ -74,8 +137,8 @@ fn on_window_created_internal(state: &mut TilingState, info: WindowCreatedInfo)
         return None;
     }
 
-    // Find workspace to assign the window to
-    let workspace_id = find_workspace_for_window(state, &info);
+    // Find workspace to assign the window to, unless it is ignored
+    let workspace_id = find_workspace_for_window(state, &info, at_startup)?;
 
     // Get workspace window IDs for tab detection
     let workspace_window_ids: Vec<u32> = state
@@ -90,26 +153,50 @@ fn on_window_created_internal(state: &mut TilingState, info: WindowCreatedInfo)
         return None;
     }
 
+    let awaiting_frame = !has_usable_frame(state, &info.frame);
+    if awaiting_frame {
+        tracing::debug!(
+            "Window {} (app={}) has no usable frame yet, keeping it out of layouts",
+            info.window_id,
+            info.app_id
+        );
+    }
+
     // Create the window (this is a real window, not a tab)
-    let window = Window {
+    let mut window = Window {
         id: info.window_id,
         pid: info.pid,
         app_id: info.app_id,
         app_name: info.app_name,
         title: info.title,
+        subrole: info.subrole,
         frame: info.frame,
         minimum_size: info.minimum_size,
         inferred_minimum_size: None,
         expected_frame: None,
         workspace_id,
+        extra_workspace_ids: Vec::new(),
         is_minimized: info.is_minimized,
         is_fullscreen: info.is_fullscreen,
         is_hidden: false,
-        is_floating: false,  // TODO: Check window rules for float
+        is_floating: false,
+        float_frame: None,
+        always_on_top: false,
+        opacity: None,
+        non_resizable: false,
+        awaiting_frame,
         tab_group_id: None,  // Not using old tab detection
         is_active_tab: true, // Real windows are always "active"
         matched_rule: None,  // TODO: Set from window rules
     };
+    window.is_floating = forced_floating(&window).unwrap_or(false);
+
+    // Floating windows don't count towards tiling.maxWindows
+    if !window.is_floating {
+        window.workspace_id =
+            route_overflow(state, &crate::config::get_config().tiling, workspace_id);
+    }
+    let workspace_id = window.workspace_id;
 
     // Track window in state
     state.upsert_window(window);
@@ -150,6 +237,21 @@ fn on_window_created_internal(state: &mut TilingState, info: WindowCreatedInfo)
 /// Returns the workspace ID if the window was tracked AND was a real window (for layout recomputation).
 /// Tabs return None since they don't affect layout.
 pub fn on_window_destroyed(state: &mut TilingState, window_id: u32) -> Option<uuid::Uuid> {
+    let _ = take_entrance(window_id);
+    if state.move_mode().is_some_and(|session| session.window_id == window_id) {
+        super::move_mode::on_end_move_mode(state, true);
+    }
+    let focus_after_close = crate::config::get_config().tiling.focus_after_close;
+    untrack_window(state, window_id, focus_after_close)
+}
+
+/// Untracks a destroyed window, moving focus on per `focus_after_close` if it
+/// was the focused one.
+fn untrack_window(
+    state: &mut TilingState,
+    window_id: u32,
+    focus_after_close: FocusAfterClose,
+) -> Option<uuid::Uuid> {
     tracing::debug!("tiling: handler on_window_destroyed called for window_id={window_id}");
 
     // Check if this window is a tracked tab - if so, just unregister and skip layout
@@ -159,9 +261,9 @@ pub fn on_window_destroyed(state: &mut TilingState, window_id: u32) -> Option<uu
     }
 
     // Get the window info before removing
-    let window_info = state.get_window(window_id).map(|w| w.workspace_id);
+    let window_info = state.get_window(window_id).map(|w| (w.workspace_id, w.extra_workspace_ids));
 
-    let Some(workspace_id) = window_info else {
+    let Some((workspace_id, extra_workspace_ids)) = window_info else {
         tracing::debug!("tiling: window {window_id} was not tracked in state");
         // Still try to unregister from tab registry in case it was there
         tabs::unregister_tab(window_id);
@@ -170,6 +272,11 @@ pub fn on_window_destroyed(state: &mut TilingState, window_id: u32) -> Option<uu
 
     tracing::debug!("tiling: window {window_id} workspace_id={workspace_id:?}");
 
+    let was_focused = eyeball::Observable::get(&state.focus).focused_window_id == Some(window_id);
+    let closed_index = state
+        .get_workspace(workspace_id)
+        .and_then(|ws| ws.window_ids.iter().position(|&id| id == window_id));
+
     // Remove the window from state
     state.remove_window(window_id);
     tracing::debug!("tiling: window {window_id} removed from state");
@@ -196,6 +303,14 @@ pub fn on_window_destroyed(state: &mut TilingState, window_id: u32) -> Option<uu
         }
     });
 
+    // Remove from the other workspaces it was shown in
+    for extra_id in extra_workspace_ids {
+        super::window_move::remove_from_workspace(state, extra_id, window_id);
+        if let Some(handle) = get_subscriber_handle() {
+            handle.notify_layout_changed(extra_id, false);
+        }
+    }
+
     // Clear focus if this was the focused window
     let focus = eyeball::Observable::get(&state.focus);
     if focus.focused_window_id == Some(window_id) {
@@ -205,12 +320,145 @@ pub fn on_window_destroyed(state: &mut TilingState, window_id: u32) -> Option<uu
 
     // Remove window from focus history (it may have been the last focused window in some workspace)
     state.remove_window_from_focus_history(window_id);
+    state.remove_minimized(window_id);
     tracing::debug!("tiling: removed window {window_id} from focus history");
 
+    if was_focused && let Some(index) = closed_index {
+        focus_after_window_closed(state, workspace_id, index, focus_after_close);
+    }
+
     tracing::debug!("tiling: returning workspace_id={workspace_id} for layout recalculation");
     Some(workspace_id)
 }
 
+/// Focuses the window picked by `mode` after the focused window at `closed_index`
+/// of a workspace closed.
+fn focus_after_window_closed(
+    state: &mut TilingState,
+    workspace_id: Uuid,
+    closed_index: usize,
+    mode: FocusAfterClose,
+) {
+    let Some(target_id) = select_focus_after_close(state, workspace_id, closed_index, mode) else {
+        return;
+    };
+
+    state.update_workspace(workspace_id, |ws| {
+        ws.focused_window_index = ws.window_ids.iter().position(|&id| id == target_id);
+    });
+
+    // The focus event of the window updates the focus state
+    if state.get_workspace(workspace_id).is_some_and(|ws| ws.is_visible) {
+        tracing::debug!("tiling: focusing window {target_id} after close ({mode:?})");
+        let _ = window_ops::focus_window(target_id);
+    }
+}
+
+/// Picks the window to focus after the focused window at `closed_index` of a
+/// workspace closed. The closed window must already be removed.
+///
+/// Minimized windows are skipped. `Previous` and `Master` fall back to `Next`
+/// when there is no focus history or tiled window.
+fn select_focus_after_close(
+    state: &TilingState,
+    workspace_id: Uuid,
+    closed_index: usize,
+    mode: FocusAfterClose,
+) -> Option<u32> {
+    let workspace = state.get_workspace(workspace_id)?;
+    let candidates: Vec<u32> = workspace
+        .window_ids
+        .iter()
+        .copied()
+        .filter(|&id| state.get_window(id).is_some_and(|w| !w.is_minimized))
+        .collect();
+
+    let picked = match mode {
+        FocusAfterClose::Next => None,
+        FocusAfterClose::Previous => state
+            .get_focus_mru(workspace_id)
+            .iter()
+            .copied()
+            .find(|id| candidates.contains(id)),
+        FocusAfterClose::Master => candidates
+            .iter()
+            .copied()
+            .find(|&id| state.get_window(id).is_some_and(|w| w.is_layoutable())),
+    };
+
+    // Windows after the closed one moved up a slot, so the same index is the next one
+    picked.or_else(|| {
+        let next = workspace.window_ids[closed_index.min(workspace.window_ids.len())..]
+            .iter()
+            .find(|id| candidates.contains(id));
+        next.or_else(|| candidates.last()).copied()
+    })
+}
+
+/// Reconciles tracked windows with the windows that currently exist.
+///
+/// Windows that no longer exist are untracked, untracked ones are tracked and
+/// the frames of the others are refreshed. Windows on other Spaces or in
+/// fullscreen are missing from `windows`, so a tracked window is only
+/// untracked when `server_ids` doesn't list it either. Workspaces that gained or lost
+/// windows, or whose visible windows drifted from their last layout, are laid
+/// out again.
+///
+/// Returns the IDs of the affected workspaces.
+pub fn on_reconcile(
+    state: &mut TilingState,
+    windows: Vec<WindowCreatedInfo>,
+    server_ids: &HashSet<u32>,
+) -> Vec<Uuid> {
+    // Tolerance for frame drift (pixels)
+    const DRIFT_TOLERANCE: f64 = 5.0;
+
+    let existing: HashSet<u32> = windows.iter().map(|w| w.window_id).collect();
+    let stale: Vec<u32> = state
+        .windows
+        .iter()
+        .filter(|w| !existing.contains(&w.id) && !server_ids.contains(&w.id))
+        .map(|w| w.id)
+        .collect();
+
+    let mut affected: Vec<Uuid> = Vec::new();
+    for window_id in &stale {
+        affected.extend(on_window_destroyed(state, *window_id));
+    }
+
+    let mut tracked = 0;
+    for info in windows {
+        let drifted_workspace = state.get_window(info.window_id).and_then(|w| {
+            let expected = w.expected_frame.filter(|_| w.is_layoutable())?;
+            let visible = state.get_workspace(w.workspace_id).is_some_and(|ws| ws.is_visible);
+            (visible && !expected.approx_eq(&info.frame, DRIFT_TOLERANCE)).then_some(w.workspace_id)
+        });
+        affected.extend(drifted_workspace);
+
+        if let Some(workspace_id) = on_window_created_internal(state, info, false) {
+            tracked += 1;
+            affected.push(workspace_id);
+        }
+    }
+
+    affected.sort_unstable();
+    affected.dedup();
+
+    tracing::info!(
+        "tiling: reconciled windows ({} untracked, {tracked} tracked, {} workspaces affected)",
+        stale.len(),
+        affected.len()
+    );
+
+    if let Some(handle) = get_subscriber_handle() {
+        for workspace_id in &affected {
+            handle.notify_layout_changed(*workspace_id, false);
+        }
+    }
+
+    affected
+}
+
 /// Handles a window focused event.
 ///
 /// Updates the focus state to point to this window, its workspace, and screen.
@@ -222,10 +470,13 @@ pub fn on_window_focused(state: &mut TilingState, window_id: u32) {
         return;
     };
 
+    // A window shown in several workspaces keeps the visible one focused
+    let workspace_id = state.layout_workspace_of(&window);
+
     tracing::debug!(
         "Window {} focused -> workspace {} (app: {})",
         window_id,
-        window.workspace_id,
+        workspace_id,
         window.app_name
     );
 
@@ -233,11 +484,12 @@ pub fn on_window_focused(state: &mut TilingState, window_id: u32) {
     let previous_focus = eyeball::Observable::get(&state.focus).clone();
     let previous_workspace_id = previous_focus.focused_workspace_id;
 
-    let workspace = state.get_workspace(window.workspace_id);
+    let workspace = state.get_workspace(workspace_id);
     let screen_id = workspace.as_ref().map(|ws| ws.screen_id);
 
     // Update focus state
-    state.set_focus(Some(window_id), Some(window.workspace_id), screen_id);
+    state.set_focus(Some(window_id), Some(workspace_id), screen_id);
+    state.record_window_focus(workspace_id, window_id, std::time::Instant::now());
 
     // Update workspace's focused window index
     if let Some(ws) = workspace
@@ -249,7 +501,7 @@ pub fn on_window_focused(state: &mut TilingState, window_id: u32) {
     }
 
     // Get the screen ID for visibility updates
-    let focused_ws_id = window.workspace_id;
+    let focused_ws_id = workspace_id;
     let focused_ws_screen_id = state.get_workspace(focused_ws_id).map(|ws| ws.screen_id);
 
     // Track workspaces that change visibility
@@ -292,8 +544,11 @@ pub fn on_window_focused(state: &mut TilingState, window_id: u32) {
         }
     }
 
-    // Sync window visibility if any workspace visibility changed
-    if !workspaces_becoming_visible.is_empty() || !workspaces_becoming_hidden.is_empty() {
+    // Sync window visibility if any workspace visibility changed; while paused
+    // apps are left alone and the visibility is synced on resume
+    if !state.is_paused()
+        && (!workspaces_becoming_visible.is_empty() || !workspaces_becoming_hidden.is_empty())
+    {
         tracing::debug!(
             "Visibility changed - showing: {workspaces_becoming_visible:?}, hiding: {workspaces_becoming_hidden:?}"
         );
@@ -318,10 +573,10 @@ pub fn on_window_focused(state: &mut TilingState, window_id: u32) {
     }
 
     // Emit workspace changed event if the focused workspace changed
-    let workspace_changed = previous_workspace_id != Some(window.workspace_id);
+    let workspace_changed = previous_workspace_id != Some(workspace_id);
     if workspace_changed {
         // Get workspace and screen names for the event
-        if let Some(ws) = state.get_workspace(window.workspace_id) {
+        if let Some(ws) = state.get_workspace(workspace_id) {
             let screen_name = state.get_screen(ws.screen_id).map_or_else(
                 || {
                     let screen_id = ws.screen_id;
@@ -346,15 +601,20 @@ pub fn on_window_focused(state: &mut TilingState, window_id: u32) {
 ///
 /// - Shows (unhides) apps that have windows in newly visible workspaces
 /// - Hides apps that have windows ONLY in hidden workspaces (not in any visible workspace)
+///
+/// Does nothing while window management is paused.
 pub fn sync_window_visibility_for_workspaces(
     state: &TilingState,
     becoming_visible: &[Uuid],
     becoming_hidden: &[Uuid],
 ) {
-    use std::collections::HashSet;
-
     use crate::modules::tiling::effects::window_ops::{hide_app, unhide_app};
 
+    if state.is_paused() {
+        tracing::trace!("Window management paused, not syncing visibility");
+        return;
+    }
+
     if becoming_visible.is_empty() && becoming_hidden.is_empty() {
         tracing::trace!("No visibility changes to sync");
         return;
@@ -371,7 +631,7 @@ pub fn sync_window_visibility_for_workspaces(
     // Collect PIDs from windows in becoming-visible workspaces (need to unhide)
     let mut pids_to_show: HashSet<i32> = HashSet::new();
     for ws_id in becoming_visible {
-        for window in state.windows.iter().filter(|w| w.workspace_id == *ws_id) {
+        for window in state.windows.iter().filter(|w| w.belongs_to(*ws_id)) {
             pids_to_show.insert(window.pid);
         }
     }
@@ -379,7 +639,7 @@ pub fn sync_window_visibility_for_workspaces(
     // Collect PIDs from windows in becoming-hidden workspaces
     let mut pids_in_hidden: HashSet<i32> = HashSet::new();
     for ws_id in becoming_hidden {
-        for window in state.windows.iter().filter(|w| w.workspace_id == *ws_id) {
+        for window in state.windows.iter().filter(|w| w.belongs_to(*ws_id)) {
             pids_in_hidden.insert(window.pid);
         }
     }
@@ -387,7 +647,7 @@ pub fn sync_window_visibility_for_workspaces(
     // Find PIDs that have windows in ANY visible workspace (shouldn't be hidden)
     let mut pids_in_visible: HashSet<i32> = HashSet::new();
     for window in state.windows.iter() {
-        if visible_ws_ids.contains(&window.workspace_id) {
+        if visible_ws_ids.iter().any(|&id| window.belongs_to(id)) {
             pids_in_visible.insert(window.pid);
         }
     }
@@ -430,6 +690,7 @@ pub fn on_window_moved(state: &mut TilingState, window_id: u32, frame: Rect) {
         state.update_window(window_id, |w| {
             w.frame = frame;
         });
+        drag_state::emit_drag_geometry([(window_id, &frame)]);
         return;
     }
 
@@ -455,6 +716,12 @@ pub fn on_window_moved(state: &mut TilingState, window_id: u32, frame: Rect) {
     state.update_window(window_id, |w| {
         w.frame = frame;
     });
+
+    if let (Some(workspace_id), Some(handle)) =
+        (settle_awaiting_frame(state, window_id), get_subscriber_handle())
+    {
+        handle.notify_layout_changed(workspace_id, false);
+    }
 }
 
 /// Handles a window resized event.
@@ -466,6 +733,7 @@ pub fn on_window_resized(state: &mut TilingState, window_id: u32, frame: Rect) {
         state.update_window(window_id, |w| {
             w.frame = frame;
         });
+        drag_state::emit_drag_geometry([(window_id, &frame)]);
         return;
     }
 
@@ -488,6 +756,12 @@ pub fn on_window_resized(state: &mut TilingState, window_id: u32, frame: Rect) {
     state.update_window(window_id, |w| {
         w.frame = frame;
     });
+
+    if let (Some(workspace_id), Some(handle)) =
+        (settle_awaiting_frame(state, window_id), get_subscriber_handle())
+    {
+        handle.notify_layout_changed(workspace_id, false);
+    }
 }
 
 /// Detect if a window failed to resize to its expected frame and update inferred minimum size.
@@ -512,6 +786,11 @@ fn detect_and_update_inferred_minimum(
         return None;
     };
 
+    // A window in native fullscreen fills its own Space, whatever its minimum size
+    if window.is_fullscreen {
+        return None;
+    }
+
     let Some(expected_frame) = window.expected_frame else {
         return None; // No expected frame set, can't detect mismatch
     };
@@ -554,6 +833,76 @@ fn detect_and_update_inferred_minimum(
     None
 }
 
+/// Number of refused resizes after which a window is treated as non-resizable.
+pub const NON_RESIZABLE_THRESHOLD: u8 = 3;
+
+/// Handles a window that kept its old size instead of resizing to its layout frame.
+///
+/// Refusals explained by the window's minimum size don't count. After
+/// [`NON_RESIZABLE_THRESHOLD`] refusals in a row, the window is marked
+/// non-resizable, so layouts keep it at its current size and give the rest to
+/// its neighbors.
+pub fn on_window_resize_refused(
+    state: &mut TilingState,
+    window_id: u32,
+    requested: &Rect,
+    actual: Rect,
+) {
+    let Some(window) = state.get_window(window_id) else {
+        return;
+    };
+
+    if window.non_resizable || window.is_fullscreen || window.would_violate_minimum_size(requested)
+    {
+        return;
+    }
+
+    let workspace_id = window.workspace_id;
+    let refusals = state.record_resize_refusal(window_id);
+    let non_resizable = refusals >= NON_RESIZABLE_THRESHOLD;
+
+    tracing::debug!("Window {window_id} refused to resize ({refusals} times)");
+
+    state.update_window(window_id, |w| {
+        w.frame = actual;
+        w.non_resizable = non_resizable;
+    });
+
+    if non_resizable {
+        tracing::info!(
+            "tiling: window {window_id} ignores resize requests, keeping it at {}x{}",
+            actual.width,
+            actual.height
+        );
+        if let Some(handle) = get_subscriber_handle() {
+            handle.notify_layout_changed(workspace_id, false);
+        }
+    }
+}
+
+/// Handles a window that resized to its layout frame.
+///
+/// Its refusal count starts over, and a window marked non-resizable is laid
+/// out like any other again.
+pub fn on_window_resize_applied(state: &mut TilingState, window_id: u32) {
+    state.clear_resize_refusals(window_id);
+
+    let Some(window) = state.get_window(window_id) else {
+        return;
+    };
+    if !window.non_resizable {
+        return;
+    }
+
+    let workspace_id = window.workspace_id;
+    state.update_window(window_id, |w| w.non_resizable = false);
+    tracing::debug!("Window {window_id} resized again, no longer non-resizable");
+
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_layout_changed(workspace_id, false);
+    }
+}
+
 /// Handles a window minimized/unminimized event.
 pub fn on_window_minimized(state: &mut TilingState, window_id: u32, minimized: bool) {
     tracing::debug!("Handling window minimized: {window_id} = {minimized}");
@@ -567,6 +916,12 @@ pub fn on_window_minimized(state: &mut TilingState, window_id: u32, minimized: b
         w.is_minimized = minimized;
     });
 
+    match (&workspace_info, minimized) {
+        (Some((ws_id, _, _)), true) => state.push_minimized(*ws_id, window_id),
+        (_, false) => state.remove_minimized(window_id),
+        (None, true) => {}
+    }
+
     // Minimized state affects layout and window list
     if let Some((ws_id, ws_name, window_ids)) = workspace_info {
         // Notify subscriber to recalculate layout
@@ -579,17 +934,79 @@ pub fn on_window_minimized(state: &mut TilingState, window_id: u32, minimized: b
     }
 }
 
+/// Returns whether `tiling.ignoreUntitled` leaves out a window without a title.
+///
+/// Apps listed in `tiling.forceTile` are always tracked.
+fn is_ignored_untitled(info: &WindowCreatedInfo, tiling: &crate::config::TilingConfig) -> bool {
+    tiling.ignore_untitled && info.title.trim().is_empty() && !tiling.is_force_tiled(&info.app_id)
+}
+
+/// Returns whether a frame has a size and overlaps a screen.
+///
+/// Screens without a known frame are not considered, so any sized frame is
+/// usable until screens are detected.
+fn has_usable_frame(state: &TilingState, frame: &Rect) -> bool {
+    let mut screens = state.screens.iter().filter(|s| s.frame.is_valid()).peekable();
+    frame.is_valid() && (screens.peek().is_none() || screens.any(|s| s.frame.intersects(frame)))
+}
+
+/// Includes a window in layouts once it has a usable frame, if it was tracked
+/// without one.
+///
+/// Returns the window's workspace ID if it got one, so its layout is re-applied.
+fn settle_awaiting_frame(state: &mut TilingState, window_id: u32) -> Option<Uuid> {
+    let window = state.get_window(window_id).filter(|w| w.awaiting_frame)?;
+    if !has_usable_frame(state, &window.frame) {
+        return None;
+    }
+
+    tracing::debug!("Window {window_id} got a usable frame, including it in layouts");
+    state.update_window(window_id, |w| w.awaiting_frame = false);
+    Some(window.workspace_id)
+}
+
+/// Returns the floating state forced by `tiling.forceTile`/`tiling.forceFloat`,
+/// or by a matching `tiling.floatRules` rule.
+fn forced_floating(window: &Window) -> Option<bool> {
+    let tiling = &crate::config::get_config().tiling;
+    tiling
+        .forced_floating(&window.app_id)
+        .or_else(|| any_rule_matches(&tiling.float_rules, window).then_some(true))
+}
+
 /// Handles a window title changed event.
-pub fn on_window_title_changed(state: &mut TilingState, window_id: u32, title: &str) {
-    tracing::debug!("Handling window title changed: {window_id} to '{title}'");
+///
+/// The subrole is refreshed when it was read again, and the `forceTile`/
+/// `forceFloat` lists and `floatRules` are re-evaluated, so a window matching
+/// them returns to its forced state even if it was toggled since.
+pub fn on_window_title_changed(
+    state: &mut TilingState,
+    window_id: u32,
+    title: &str,
+    subrole: Option<&str>,
+) {
+    tracing::debug!(
+        "Handling window title changed: {window_id} to '{}'",
+        crate::logging::redact_title(title)
+    );
 
     // Get window's workspace before updating
     let window_workspace_id = state.get_window(window_id).map(|w| w.workspace_id);
 
     state.update_window(window_id, |w| {
         w.title = title.to_string();
+        if let Some(subrole) = subrole {
+            w.subrole = Some(subrole.to_string());
+        }
     });
 
+    let needs_toggle = state
+        .get_window(window_id)
+        .is_some_and(|w| forced_floating(w).is_some_and(|floating| floating != w.is_floating));
+    if needs_toggle {
+        super::window_move::on_toggle_floating(state, window_id);
+    }
+
     // Only emit event to frontend if window is in the focused workspace
     let focused_workspace_id = state.get_focus_state().focused_workspace_id;
     if window_workspace_id.is_some() && window_workspace_id == focused_workspace_id {
@@ -598,6 +1015,11 @@ pub fn on_window_title_changed(state: &mut TilingState, window_id: u32, title: &
 }
 
 /// Handles a window fullscreen state changed event.
+///
+/// Native fullscreen moves the window to its own Space. Fullscreen windows
+/// are not layoutable, so the other windows reflow without it, and it rejoins
+/// the stack at its previous position on exit. While fullscreen, it is never
+/// repositioned and its frame changes don't count as minimum size hints.
 pub fn on_window_fullscreen_changed(state: &mut TilingState, window_id: u32, fullscreen: bool) {
     tracing::debug!("Handling window fullscreen changed: {window_id} = {fullscreen}");
 
@@ -606,6 +1028,10 @@ pub fn on_window_fullscreen_changed(state: &mut TilingState, window_id: u32, ful
 
     state.update_window(window_id, |w| {
         w.is_fullscreen = fullscreen;
+        if fullscreen {
+            // The tiled frame no longer applies; the next layout sets a new one on exit
+            w.expected_frame = None;
+        }
     });
 
     // Fullscreen state affects layout
@@ -682,6 +1108,7 @@ pub fn on_batched_geometry_updates(state: &mut TilingState, updates: &[GeometryU
                 w.frame = update.frame;
             });
         }
+        drag_state::emit_drag_geometry(updates.iter().map(|u| (u.window_id, &u.frame)));
         return;
     }
 
@@ -713,6 +1140,12 @@ pub fn on_batched_geometry_updates(state: &mut TilingState, updates: &[GeometryU
         state.update_window(update.window_id, |w| {
             w.frame = update.frame;
         });
+
+        if let Some(workspace_id) = settle_awaiting_frame(state, update.window_id)
+            .filter(|ws_id| !workspaces_to_relayout.contains(ws_id))
+        {
+            workspaces_to_relayout.push(workspace_id);
+        }
     }
 
     // Trigger layout recalculation for workspaces with minimum size violations
@@ -734,17 +1167,152 @@ pub fn on_batched_geometry_updates(state: &mut TilingState, updates: &[GeometryU
 /// 2. Focused workspace
 /// 3. First visible workspace
 /// 4. Create a default workspace
-fn find_workspace_for_window(state: &mut TilingState, info: &WindowCreatedInfo) -> Uuid {
+///
+/// A window pinned by a display rule, or already open at startup with
+/// `tiling.respectExistingScreen` on its current screen, stays on that screen:
+/// only rules pointing to a workspace on it apply, and its visible workspace
+/// is used instead of the focused one.
+///
+/// Returns `None` if the window is ignored. Ignore rules can be overridden per
+/// workspace, so they are checked against the chosen workspace: a window
+/// ignored in the workspace its rule points to falls back to the workspace it
+/// would get without a rule, and is ignored if that one ignores it too.
+fn find_workspace_for_window(
+    state: &mut TilingState,
+    info: &WindowCreatedInfo,
+    at_startup: bool,
+) -> Option<Uuid> {
+    let config = crate::config::get_config();
+    let on_screen = pinned_screen(state, info).or_else(|| {
+        (at_startup && config.tiling.respect_existing_screen)
+            .then(|| screen_for_frame(state, &info.frame))
+            .flatten()
+    });
+
     // Check window rules from config
-    if let Some(workspace_id) = find_workspace_by_rules(state, info) {
+    if let Some(workspace_id) = find_workspace_by_rules(state, info, on_screen) {
+        if !is_ignored_in_workspace(state, &config.tiling, workspace_id, info) {
+            tracing::debug!(
+                "Window {} (app={}) matched rule, assigned to workspace {:?}",
+                info.window_id,
+                info.app_id,
+                workspace_id
+            );
+            return Some(workspace_id);
+        }
+
         tracing::debug!(
-            "Window {} (app={}) matched rule, assigned to workspace {:?}",
+            "Window {} (app={}) ignored in its rule's workspace, falling back",
             info.window_id,
-            info.app_id,
-            workspace_id
+            info.app_id
         );
+    }
+
+    let workspace_id = find_fallback_workspace(state, info, on_screen);
+    if is_ignored_in_workspace(state, &config.tiling, workspace_id, info) {
+        tracing::debug!(
+            "Window {} (app={}) matched ignore rule",
+            info.window_id,
+            info.app_id
+        );
+        return None;
+    }
+
+    Some(workspace_id)
+}
+
+/// Re-routes a new tiled window away from a workspace holding `tiling.maxWindows`.
+///
+/// The window goes to `tiling.overflowWorkspace`, or else to the next workspace
+/// on the same screen with room, in config order. If there is no such workspace
+/// the limit is ignored and the window stays where it was assigned.
+fn route_overflow(
+    state: &TilingState,
+    tiling: &crate::config::TilingConfig,
+    workspace_id: Uuid,
+) -> Uuid {
+    let max_windows = tiling.max_windows as usize;
+    let has_room = |id: Uuid| state.get_layoutable_window_ids(id).len() < max_windows;
+    if max_windows == 0 || has_room(workspace_id) {
         return workspace_id;
     }
+    let Some(workspace) = state.get_workspace(workspace_id) else {
+        return workspace_id;
+    };
+
+    let overflow = match &tiling.overflow_workspace {
+        Some(name) => state
+            .get_workspace_by_name(name)
+            .map(|ws| ws.id)
+            .filter(|&id| id != workspace_id && has_room(id)),
+        None => {
+            let ids: Vec<Uuid> = state
+                .get_workspaces_for_screen(workspace.screen_id)
+                .iter()
+                .map(|ws| ws.id)
+                .collect();
+            let index = ids.iter().position(|&id| id == workspace_id).unwrap_or(0);
+            ids.iter()
+                .cycle()
+                .skip(index + 1)
+                .take(ids.len().saturating_sub(1))
+                .copied()
+                .find(|&id| has_room(id))
+        }
+    };
+
+    match overflow {
+        Some(id) => {
+            tracing::debug!(
+                "Workspace '{}' holds {max_windows} windows, overflowing to {id:?}",
+                workspace.name
+            );
+            id
+        }
+        None => {
+            tracing::warn!(
+                "Workspace '{}' holds {max_windows} windows and no overflow workspace exists with \
+                 room, ignoring tiling.maxWindows",
+                workspace.name
+            );
+            workspace_id
+        }
+    }
+}
+
+/// Returns whether the ignore rules in effect for a workspace match a window.
+///
+/// Apps in `tiling.forceTile` are never ignored.
+fn is_ignored_in_workspace(
+    state: &TilingState,
+    tiling: &crate::config::TilingConfig,
+    workspace_id: Uuid,
+    info: &WindowCreatedInfo,
+) -> bool {
+    let rules = state
+        .get_workspace(workspace_id)
+        .map_or(tiling.ignore.as_slice(), |ws| tiling.ignore_rules_for(&ws.name));
+    rules.iter().any(|rule| rule_matches_window(rule, info)) && !tiling.is_force_tiled(&info.app_id)
+}
+
+/// Finds the workspace for a window that no rule assigns.
+fn find_fallback_workspace(
+    state: &mut TilingState,
+    info: &WindowCreatedInfo,
+    on_screen: Option<u32>,
+) -> Uuid {
+    // Keep the window on its screen
+    if let Some(screen_id) = on_screen
+        && let Some(ws) = visible_workspace_on_screen(state, screen_id)
+    {
+        tracing::debug!(
+            "Window {} (app={}) kept on screen {screen_id}, using workspace '{}'",
+            info.window_id,
+            info.app_id,
+            ws.name
+        );
+        return ws.id;
+    }
 
     // Try focused workspace as fallback
     if let Some(ws) = state.get_focused_workspace() {
@@ -775,13 +1343,39 @@ fn find_workspace_for_window(state: &mut TilingState, info: &WindowCreatedInfo)
     id
 }
 
+/// Returns the connected screen a display rule pins a window to, if any.
+fn pinned_screen(state: &TilingState, info: &WindowCreatedInfo) -> Option<u32> {
+    let display_rules = &crate::config::get_config().tiling.display_rules;
+    let rule = display_rules.iter().find(|r| rule_matches_window(&r.rule, info))?;
+    resolve_screen_name(state, &rule.screen)
+}
+
+/// Returns the screen containing the center of a frame.
+fn screen_for_frame(state: &TilingState, frame: &Rect) -> Option<u32> {
+    let (x, y) = frame.center();
+    state.screens.iter().find(|s| s.frame.contains_point(x, y)).map(|s| s.id)
+}
+
+/// Returns the visible workspace of a screen.
+pub(super) fn visible_workspace_on_screen(
+    state: &TilingState,
+    screen_id: u32,
+) -> Option<Workspace> {
+    state.get_workspaces_for_screen(screen_id).into_iter().find(|ws| ws.is_visible)
+}
+
 /// Finds a workspace for a window based on config rules.
 ///
 /// Checks each workspace's rules against the window's `app_id`/`app_name`/`title`.
 /// Returns the UUID of the first matching workspace, or None if no match.
+/// With `on_screen`, workspaces on other screens are skipped.
 ///
 /// Rules use AND logic - all specified criteria must match.
-fn find_workspace_by_rules(state: &TilingState, info: &WindowCreatedInfo) -> Option<Uuid> {
+fn find_workspace_by_rules(
+    state: &TilingState,
+    info: &WindowCreatedInfo,
+    on_screen: Option<u32>,
+) -> Option<Uuid> {
     use crate::config::get_config;
 
     let config = get_config();
@@ -792,7 +1386,9 @@ fn find_workspace_by_rules(state: &TilingState, info: &WindowCreatedInfo) -> Opt
         for rule in &ws_config.rules {
             if rule_matches_window(rule, info) {
                 // Found a match - find the workspace by name in state
-                if let Some(ws) = state.get_workspace_by_name(&ws_config.name) {
+                if let Some(ws) = state.get_workspace_by_name(&ws_config.name)
+                    && on_screen.is_none_or(|screen_id| ws.screen_id == screen_id)
+                {
                     tracing::debug!(
                         "Rule match: app_id='{}' → workspace '{}'",
                         info.app_id,
@@ -842,6 +1438,16 @@ fn rule_matches_window(rule: &crate::config::WindowRule, info: &WindowCreatedInf
         return false;
     }
 
+    // Check subrole - case-insensitive exact match
+    if rule.subrole.as_ref().is_some_and(|rule_subrole| {
+        !info
+            .subrole
+            .as_ref()
+            .is_some_and(|subrole| subrole.eq_ignore_ascii_case(rule_subrole))
+    }) {
+        return false;
+    }
+
     // All specified criteria matched
     true
 }
@@ -862,6 +1468,11 @@ fn create_default_workspace(state: &TilingState) -> Workspace {
         split_ratios: Vec::new(),
         master_ratio: None,
         configured_screen: None,
+        home_screen_uuid: None,
+        toggled_from: None,
+        layout_pinned: false,
+        master_position: None,
+        gaps_disabled: false,
     }
 }
 
@@ -890,6 +1501,11 @@ mod tests {
             split_ratios: Vec::new(),
             master_ratio: None,
             configured_screen: None,
+            home_screen_uuid: None,
+            toggled_from: None,
+            layout_pinned: false,
+            master_position: None,
+            gaps_disabled: false,
         };
         let ws_id = ws.id;
         state.upsert_workspace(ws);
@@ -904,6 +1520,7 @@ mod tests {
             app_id: "com.test.app".to_string(),
             app_name: "Test App".to_string(),
             title: format!("Window {window_id}"),
+            subrole: None,
             frame: Rect::new(0.0, 0.0, 800.0, 600.0),
             is_minimized: false,
             is_fullscreen: false,
@@ -913,6 +1530,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_rule_matches_window_subrole() {
+        let rule = crate::config::WindowRule {
+            app_id: Some("com.test.app".to_string()),
+            subrole: Some("AXDialog".to_string()),
+            ..Default::default()
+        };
+        let mut info = make_window_info(100);
+        assert!(!rule_matches_window(&rule, &info));
+
+        info.subrole = Some("AXDialog".to_string());
+        assert!(rule_matches_window(&rule, &info));
+    }
+
+    #[test]
+    fn test_titleless_zero_size_window_awaits_a_frame() {
+        let (mut state, ws_id) = make_state_with_workspace();
+        let mut info = make_window_info(100);
+        info.title = String::new();
+        info.frame = Rect::zero();
+
+        let mut tiling = crate::config::TilingConfig::default();
+        assert!(!is_ignored_untitled(&info, &tiling));
+        tiling.ignore_untitled = true;
+        assert!(is_ignored_untitled(&info, &tiling));
+
+        // Without the option, the window is tracked but left out of layouts
+        on_window_created(&mut state, info);
+        let window = state.get_window(100).unwrap();
+        assert!(window.awaiting_frame);
+        assert!(!window.is_layoutable());
+        assert!(state.get_workspace(ws_id).unwrap().window_ids.contains(&100));
+
+        on_window_resized(&mut state, 100, Rect::new(0.0, 0.0, 800.0, 600.0));
+        let window = state.get_window(100).unwrap();
+        assert!(!window.awaiting_frame);
+        assert!(window.is_layoutable());
+    }
+
     #[test]
     fn test_window_created() {
         let (mut state, ws_id) = make_state_with_workspace();
@@ -930,6 +1586,180 @@ mod tests {
         assert!(ws.window_ids.contains(&100));
     }
 
+    #[test]
+    fn test_workspace_ignore_rules_override_global_ones() {
+        let (state, ws_id) = make_state_with_workspace();
+        let info = make_window_info(100);
+
+        let tiling: crate::config::TilingConfig = serde_json::from_str(
+            r#"{"ignore": [{ "appId": "com.test.app" }], "workspaces": [{ "name": "other" }]}"#,
+        )
+        .unwrap();
+        assert!(is_ignored_in_workspace(&state, &tiling, ws_id, &info));
+
+        // An empty override tiles the app in this workspace only
+        let tiling: crate::config::TilingConfig = serde_json::from_str(
+            r#"{"ignore": [{ "appId": "com.test.app" }], "workspaces": [{ "name": "test", "ignore": [] }]}"#,
+        )
+        .unwrap();
+        assert!(!is_ignored_in_workspace(&state, &tiling, ws_id, &info));
+
+        // And an override can ignore an app tiled everywhere else
+        let tiling: crate::config::TilingConfig = serde_json::from_str(
+            r#"{"workspaces": [{ "name": "test", "ignore": [{ "appName": "test" }] }]}"#,
+        )
+        .unwrap();
+        assert!(is_ignored_in_workspace(&state, &tiling, ws_id, &info));
+    }
+
+    #[test]
+    fn test_force_tiled_apps_are_never_ignored() {
+        let (state, ws_id) = make_state_with_workspace();
+        let info = make_window_info(100);
+
+        let tiling: crate::config::TilingConfig = serde_json::from_str(
+            r#"{"ignore": [{ "appId": "com.test.app" }], "forceTile": ["com.test.app"]}"#,
+        )
+        .unwrap();
+        assert!(!is_ignored_in_workspace(&state, &tiling, ws_id, &info));
+
+        let tiling: crate::config::TilingConfig = serde_json::from_str(
+            r#"{"forceTile": ["com.test.app"], "workspaces": [{ "name": "test", "ignore": [{ "appName": "test" }] }]}"#,
+        )
+        .unwrap();
+        assert!(!is_ignored_in_workspace(&state, &tiling, ws_id, &info));
+    }
+
+    #[test]
+    fn test_screen_for_frame_and_its_visible_workspace() {
+        use crate::modules::tiling::state::Screen;
+
+        let (mut state, ws_id) = make_state_with_workspace();
+        state.upsert_screen(Screen {
+            id: 1,
+            frame: Rect::new(0.0, 0.0, 1920.0, 1080.0),
+            ..Default::default()
+        });
+        state.upsert_screen(Screen {
+            id: 2,
+            frame: Rect::new(1920.0, 0.0, 1920.0, 1080.0),
+            ..Default::default()
+        });
+
+        // The center decides, so a window straddling both screens belongs to one
+        let frame = Rect::new(1800.0, 100.0, 800.0, 600.0);
+        assert_eq!(screen_for_frame(&state, &frame), Some(2));
+        assert_eq!(
+            screen_for_frame(&state, &Rect::new(-900.0, 0.0, 100.0, 100.0)),
+            None
+        );
+
+        assert_eq!(
+            visible_workspace_on_screen(&state, 1).map(|ws| ws.id),
+            Some(ws_id)
+        );
+        assert!(visible_workspace_on_screen(&state, 2).is_none());
+    }
+
+    fn add_workspace(state: &mut TilingState, name: &str, screen_id: u32) -> Uuid {
+        let ws = Workspace {
+            name: name.to_string(),
+            screen_id,
+            ..Default::default()
+        };
+        let id = ws.id;
+        state.upsert_workspace(ws);
+        id
+    }
+
+    fn fill_workspace(state: &mut TilingState, ws_id: Uuid, first_id: u32, count: u32) {
+        for window_id in first_id..first_id + count {
+            state.upsert_window(Window {
+                id: window_id,
+                workspace_id: ws_id,
+                ..Default::default()
+            });
+            state.update_workspace(ws_id, |ws| ws.window_ids.push(window_id));
+        }
+    }
+
+    fn max_windows_config(max_windows: u32, overflow: Option<&str>) -> crate::config::TilingConfig {
+        crate::config::TilingConfig {
+            max_windows,
+            overflow_workspace: overflow.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_route_overflow_at_the_limit() {
+        let (mut state, ws_id) = make_state_with_workspace();
+        let next_id = add_workspace(&mut state, "next", 1);
+        let tiling = max_windows_config(2, None);
+
+        fill_workspace(&mut state, ws_id, 100, 1);
+        assert_eq!(route_overflow(&state, &tiling, ws_id), ws_id);
+
+        fill_workspace(&mut state, ws_id, 101, 1);
+        assert_eq!(route_overflow(&state, &tiling, ws_id), next_id);
+    }
+
+    #[test]
+    fn test_route_overflow_skips_full_and_other_screen_workspaces() {
+        let (mut state, ws_id) = make_state_with_workspace();
+        let other_screen_id = add_workspace(&mut state, "other-screen", 2);
+        let full_id = add_workspace(&mut state, "full", 1);
+        let free_id = add_workspace(&mut state, "free", 1);
+        let tiling = max_windows_config(1, None);
+
+        fill_workspace(&mut state, ws_id, 100, 1);
+        fill_workspace(&mut state, full_id, 200, 1);
+        assert_eq!(route_overflow(&state, &tiling, ws_id), free_id);
+
+        // Workspaces before the full one are tried after wrapping around
+        assert_eq!(route_overflow(&state, &tiling, full_id), free_id);
+        assert_ne!(route_overflow(&state, &tiling, ws_id), other_screen_id);
+    }
+
+    #[test]
+    fn test_route_overflow_to_configured_workspace() {
+        let (mut state, ws_id) = make_state_with_workspace();
+        add_workspace(&mut state, "next", 1);
+        let misc_id = add_workspace(&mut state, "misc", 2);
+        let tiling = max_windows_config(1, Some("misc"));
+
+        fill_workspace(&mut state, ws_id, 100, 1);
+        assert_eq!(route_overflow(&state, &tiling, ws_id), misc_id);
+    }
+
+    #[test]
+    fn test_route_overflow_ignores_the_limit_without_room() {
+        let (mut state, ws_id) = make_state_with_workspace();
+        let next_id = add_workspace(&mut state, "next", 1);
+        fill_workspace(&mut state, ws_id, 100, 1);
+        fill_workspace(&mut state, next_id, 200, 1);
+
+        assert_eq!(
+            route_overflow(&state, &max_windows_config(1, None), ws_id),
+            ws_id
+        );
+        // A missing or full overflow workspace keeps the window where it was
+        assert_eq!(
+            route_overflow(&state, &max_windows_config(1, Some("missing")), ws_id),
+            ws_id
+        );
+        assert_eq!(
+            route_overflow(&state, &max_windows_config(1, Some("next")), ws_id),
+            ws_id
+        );
+        // No limit
+        fill_workspace(&mut state, ws_id, 101, 5);
+        assert_eq!(
+            route_overflow(&state, &max_windows_config(0, None), ws_id),
+            ws_id
+        );
+    }
+
     #[test]
     fn test_window_destroyed() {
         let (mut state, ws_id) = make_state_with_workspace();
@@ -980,6 +1810,130 @@ mod tests {
         assert!(!window.is_minimized);
     }
 
+    #[test]
+    fn test_window_fullscreen_leaves_layout_and_skips_minimum_inference() {
+        let (mut state, ws_id) = make_state_with_workspace();
+        on_window_created(&mut state, make_window_info(100));
+        on_window_created(&mut state, make_window_info(101));
+        state.update_window(100, |w| {
+            w.expected_frame = Some(Rect::new(0.0, 0.0, 400.0, 600.0))
+        });
+
+        on_window_fullscreen_changed(&mut state, 100, true);
+
+        let layoutable: Vec<u32> =
+            state.get_layoutable_windows(ws_id).iter().map(|w| w.id).collect();
+        assert_eq!(layoutable, vec![101]);
+
+        // Filling the fullscreen Space is not a minimum size violation
+        on_window_resized(&mut state, 100, Rect::new(0.0, 0.0, 1920.0, 1080.0));
+        let window = state.get_window(100).unwrap();
+        assert!(window.inferred_minimum_size.is_none());
+        assert!(window.expected_frame.is_none());
+
+        on_window_fullscreen_changed(&mut state, 100, false);
+        assert_eq!(state.get_layoutable_windows(ws_id).len(), 2);
+    }
+
+    #[test]
+    fn test_window_marked_non_resizable_after_repeated_refusals() {
+        let (mut state, _) = make_state_with_workspace();
+        on_window_created(&mut state, make_window_info(100));
+        state.update_window(100, |w| w.minimum_size = Some((300.0, 200.0)));
+
+        // Shrinking below the minimum size is not a refusal
+        let actual = Rect::new(0.0, 0.0, 500.0, 400.0);
+        on_window_resize_refused(&mut state, 100, &Rect::new(0.0, 0.0, 200.0, 400.0), actual);
+        on_window_resize_refused(&mut state, 100, &Rect::new(0.0, 0.0, 200.0, 400.0), actual);
+        on_window_resize_refused(&mut state, 100, &Rect::new(0.0, 0.0, 200.0, 400.0), actual);
+        assert!(!state.get_window(100).unwrap().non_resizable);
+
+        let requested = Rect::new(0.0, 0.0, 900.0, 800.0);
+        for _ in 1..NON_RESIZABLE_THRESHOLD {
+            on_window_resize_refused(&mut state, 100, &requested, actual);
+        }
+        assert!(!state.get_window(100).unwrap().non_resizable);
+
+        on_window_resize_refused(&mut state, 100, &requested, actual);
+        let window = state.get_window(100).unwrap();
+        assert!(window.non_resizable);
+        assert_eq!(window.effective_minimum_size(), Some((500.0, 400.0)));
+    }
+
+    #[test]
+    fn test_applied_resize_resets_refusals() {
+        let (mut state, _) = make_state_with_workspace();
+        on_window_created(&mut state, make_window_info(100));
+
+        let requested = Rect::new(0.0, 0.0, 900.0, 800.0);
+        let actual = Rect::new(0.0, 0.0, 500.0, 400.0);
+        for _ in 1..NON_RESIZABLE_THRESHOLD {
+            on_window_resize_refused(&mut state, 100, &requested, actual);
+        }
+        on_window_resize_applied(&mut state, 100);
+        on_window_resize_refused(&mut state, 100, &requested, actual);
+        assert!(!state.get_window(100).unwrap().non_resizable);
+
+        for _ in 1..NON_RESIZABLE_THRESHOLD {
+            on_window_resize_refused(&mut state, 100, &requested, actual);
+        }
+        assert!(state.get_window(100).unwrap().non_resizable);
+
+        on_window_resize_applied(&mut state, 100);
+        assert!(!state.get_window(100).unwrap().non_resizable);
+    }
+
+    #[test]
+    fn test_reconcile_untracks_vanished_and_tracks_new_windows() {
+        let (mut state, ws_id) = make_state_with_workspace();
+        on_window_created(&mut state, make_window_info(100));
+        on_window_created(&mut state, make_window_info(101));
+
+        let affected = on_reconcile(
+            &mut state,
+            vec![make_window_info(101), make_window_info(102)],
+            &HashSet::from([101, 102]),
+        );
+
+        assert_eq!(affected, vec![ws_id]);
+        assert!(state.get_window(100).is_none());
+        assert!(state.get_window(102).is_some());
+        let window_ids = state.get_workspace(ws_id).unwrap().window_ids;
+        assert!(!window_ids.contains(&100));
+        assert!(window_ids.contains(&102));
+    }
+
+    #[test]
+    fn test_reconcile_relayouts_only_drifted_workspaces() {
+        let (mut state, ws_id) = make_state_with_workspace();
+        on_window_created(&mut state, make_window_info(100));
+        state.update_window(100, |w| w.expected_frame = Some(w.frame));
+
+        let server_ids = HashSet::from([100]);
+        assert!(on_reconcile(&mut state, vec![make_window_info(100)], &server_ids).is_empty());
+
+        let mut moved = make_window_info(100);
+        moved.frame = Rect::new(300.0, 0.0, 800.0, 600.0);
+        assert_eq!(on_reconcile(&mut state, vec![moved], &server_ids), vec![ws_id]);
+        assert_eq!(state.get_window(100).unwrap().frame.x, 300.0);
+    }
+
+    #[test]
+    fn test_reconcile_keeps_windows_the_window_server_lists() {
+        let (mut state, _) = make_state_with_workspace();
+        on_window_created(&mut state, make_window_info(100));
+        on_window_created(&mut state, make_window_info(101));
+
+        // Window 100 is on another Space: not enumerated, but still listed
+        on_reconcile(
+            &mut state,
+            vec![make_window_info(101)],
+            &HashSet::from([100, 101]),
+        );
+
+        assert!(state.get_window(100).is_some());
+    }
+
     #[test]
     fn test_window_moved() {
         let (mut state, _) = make_state_with_workspace();
@@ -1034,4 +1988,27 @@ mod tests {
 
         assert!(!eyeball::Observable::get(&state.focus).has_focus());
     }
+
+    #[test]
+    fn test_focus_after_closing_middle_window() {
+        for (mode, expected) in [
+            (FocusAfterClose::Next, 300),
+            (FocusAfterClose::Previous, 100),
+            (FocusAfterClose::Master, 100),
+        ] {
+            let (mut state, ws_id) = make_state_with_workspace();
+            for window_id in [100, 200, 300] {
+                on_window_created(&mut state, make_window_info(window_id));
+            }
+            on_window_focused(&mut state, 300);
+            on_window_focused(&mut state, 100);
+            on_window_focused(&mut state, 200);
+
+            untrack_window(&mut state, 200, mode);
+
+            let ws = state.get_workspace(ws_id).unwrap();
+            let focused = ws.focused_window_index.map(|idx| ws.window_ids[idx]);
+            assert_eq!(focused, Some(expected), "{mode:?}");
+        }
+    }
 }
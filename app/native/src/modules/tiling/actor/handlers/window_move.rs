@@ -1,21 +1,34 @@
 //! Window movement command handlers.
 //!
-//! These handlers manage moving windows between workspaces, swapping windows,
-//! toggling floating state, and sending windows to screens.
+//! These handlers manage moving windows between workspaces, gathering them
+//! into one, showing them in several workspaces, grouping an app's windows,
+//! swapping windows, moving them in the stack, toggling floating state, and
+//! sending windows to screens.
 
 use uuid::Uuid;
 
-use super::workspace::resolve_screen;
-use crate::modules::tiling::actor::messages::TargetScreen;
+use super::window::sync_window_visibility_for_workspaces;
+use super::workspace::{on_switch_workspace, resolve_screen};
+use crate::modules::tiling::actor::messages::{CycleDirection, TargetScreen};
+use crate::modules::tiling::effects::window_ops;
 use crate::modules::tiling::init::get_subscriber_handle;
-use crate::modules::tiling::state::TilingState;
+use crate::modules::tiling::state::{LayoutType, TilingState};
 
 // ============================================================================
 // Move Window to Workspace
 // ============================================================================
 
 /// Move a window to a different workspace.
-pub fn on_move_window_to_workspace(state: &mut TilingState, window_id: u32, workspace_id: Uuid) {
+///
+/// With `follow`, the target workspace is switched to and the moved window is
+/// focused. Without it, focus stays on the source workspace and the window is
+/// hidden if the target workspace is not visible.
+pub fn on_move_window_to_workspace(
+    state: &mut TilingState,
+    window_id: u32,
+    workspace_id: Uuid,
+    follow: bool,
+) {
     let Some(window) = state.get_window(window_id) else {
         tracing::warn!("move_window: window {window_id} not found");
         return;
@@ -27,12 +40,117 @@ pub fn on_move_window_to_workspace(state: &mut TilingState, window_id: u32, work
         return;
     }
 
+    let Some(target_workspace) = state.get_workspace(workspace_id) else {
+        tracing::warn!("move_window: workspace {workspace_id} not found");
+        return;
+    };
+
     // Remove from old workspace
-    state.update_workspace(old_workspace_id, |ws| {
+    remove_from_workspace(state, old_workspace_id, window_id);
+
+    // Add to new workspace, unless the window was already shown there
+    if !window.extra_workspace_ids.contains(&workspace_id) {
+        state.update_workspace(workspace_id, |ws| {
+            ws.window_ids.push(window_id);
+        });
+    }
+
+    // Update window's workspace reference
+    state.update_window(window_id, |w| {
+        w.workspace_id = workspace_id;
+        w.extra_workspace_ids.retain(|&id| id != workspace_id);
+    });
+
+    tracing::debug!("Moved window {window_id} to workspace {workspace_id} (follow={follow})");
+
+    // Notify subscriber to recalculate layouts for both workspaces
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_layout_changed(old_workspace_id, true);
+        handle.notify_layout_changed(workspace_id, true);
+    }
+
+    if follow {
+        on_switch_workspace(state, &target_workspace.name);
+        state.update_focus(|focus| focus.focused_window_id = Some(window_id));
+        let _ = window_ops::focus_window(window_id);
+        if let Some(handle) = get_subscriber_handle() {
+            handle.notify_focus_changed();
+        }
+        return;
+    }
+
+    if !target_workspace.is_visible {
+        hide_moved_window(state, window.pid);
+    }
+
+    if state.get_focus_state().focused_window_id == Some(window_id) {
+        focus_remaining_window(state, old_workspace_id);
+    }
+}
+
+// ============================================================================
+// Show Window in Another Workspace
+// ============================================================================
+
+/// Toggle whether a window is also shown in another workspace.
+///
+/// The window keeps its assigned workspace and is added to, or removed from,
+/// the window list of `workspace_id`, so switching to either workspace shows
+/// it. Does nothing for the window's assigned workspace.
+pub fn on_toggle_extra_workspace(state: &mut TilingState, window_id: u32, workspace_id: Uuid) {
+    let Some(window) = state.get_window(window_id) else {
+        tracing::warn!("toggle_extra_workspace: window {window_id} not found");
+        return;
+    };
+
+    if window.workspace_id == workspace_id {
+        tracing::debug!("toggle_extra_workspace: window {window_id} is assigned to {workspace_id}");
+        return;
+    }
+
+    let Some(workspace) = state.get_workspace(workspace_id) else {
+        tracing::warn!("toggle_extra_workspace: workspace {workspace_id} not found");
+        return;
+    };
+
+    if window.extra_workspace_ids.contains(&workspace_id) {
+        remove_from_workspace(state, workspace_id, window_id);
+        state.update_window(window_id, |w| {
+            w.extra_workspace_ids.retain(|&id| id != workspace_id);
+        });
+        tracing::debug!(
+            "Window {window_id} no longer shown in workspace '{}'",
+            workspace.name
+        );
+
+        if workspace.is_visible {
+            hide_moved_window(state, window.pid);
+        }
+    } else {
+        state.update_workspace(workspace_id, |ws| ws.window_ids.push(window_id));
+        state.update_window(window_id, |w| w.extra_workspace_ids.push(workspace_id));
+        tracing::debug!("Window {window_id} also shown in workspace '{}'", workspace.name);
+
+        if workspace.is_visible {
+            let _ = window_ops::unhide_app(window.pid);
+        }
+    }
+
+    // The window may move between the layouts of both workspaces
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_layout_changed(window.workspace_id, true);
+        handle.notify_layout_changed(workspace_id, true);
+    }
+}
+
+/// Removes a window from a workspace's window list, keeping its focused index valid.
+pub(super) fn remove_from_workspace(state: &mut TilingState, workspace_id: Uuid, window_id: u32) {
+    state.update_workspace(workspace_id, |ws| {
+        let pos = ws.window_ids.iter().position(|&id| id == window_id);
         ws.window_ids.retain(|id| *id != window_id);
         // Update focused index if needed
         if let Some(idx) = ws.focused_window_index
-            && let Some(pos) = ws.window_ids.iter().position(|&id| id == window_id)
+            && let Some(pos) = pos
         {
             if idx > pos {
                 ws.focused_window_index = Some(idx - 1);
@@ -41,23 +159,42 @@ pub fn on_move_window_to_workspace(state: &mut TilingState, window_id: u32, work
             }
         }
     });
+}
 
-    // Add to new workspace
-    state.update_workspace(workspace_id, |ws| {
-        ws.window_ids.push(window_id);
-    });
+/// Hides the app of a window moved to a hidden workspace.
+///
+/// Windows are hidden per app, so this is skipped while the app still has
+/// windows in a visible workspace.
+fn hide_moved_window(state: &TilingState, pid: i32) {
+    let visible_ws_ids = state.get_visible_workspace_ids();
+    let has_visible_window = state
+        .windows
+        .iter()
+        .any(|w| w.pid == pid && visible_ws_ids.iter().any(|&id| w.belongs_to(id)));
 
-    // Update window's workspace reference
-    state.update_window(window_id, |w| {
-        w.workspace_id = workspace_id;
+    if has_visible_window {
+        tracing::debug!("move_window: app {pid} has visible windows, not hiding");
+        return;
+    }
+
+    let _ = window_ops::hide_app(pid);
+}
+
+/// Focuses a window left in a workspace after the focused window moved away.
+fn focus_remaining_window(state: &mut TilingState, workspace_id: Uuid) {
+    let target = state.get_workspace(workspace_id).and_then(|ws| {
+        ws.focused_window_index
+            .and_then(|idx| ws.window_ids.get(idx).copied())
+            .or_else(|| ws.window_ids.first().copied())
     });
 
-    tracing::debug!("Moved window {window_id} to workspace {workspace_id}");
+    state.update_focus(|focus| focus.focused_window_id = target);
+    if let Some(window_id) = target {
+        let _ = window_ops::focus_window(window_id);
+    }
 
-    // Notify subscriber to recalculate layouts for both workspaces
     if let Some(handle) = get_subscriber_handle() {
-        handle.notify_layout_changed(old_workspace_id, true);
-        handle.notify_layout_changed(workspace_id, true);
+        handle.notify_focus_changed();
     }
 }
 
@@ -111,11 +248,126 @@ pub fn on_swap_windows(state: &mut TilingState, window_id_a: u32, window_id_b: u
     }
 }
 
+// ============================================================================
+// Move Window in Stack
+// ============================================================================
+
+/// Move the focused window one position earlier (`Previous`) or later (`Next`)
+/// in its workspace's stack, keeping focus on it.
+///
+/// Only tiled windows count as positions, so floating windows keep their
+/// slot. In split layouts the window keeps its size. At either end, the window
+/// wraps around to the other end with `wrap`, and stays put otherwise.
+pub fn on_move_window_in_stack(state: &mut TilingState, direction: CycleDirection, wrap: bool) {
+    let focus = state.get_focus_state();
+    let (Some(workspace_id), Some(window_id)) =
+        (focus.focused_workspace_id, focus.focused_window_id)
+    else {
+        tracing::debug!("move_in_stack: no focused window");
+        return;
+    };
+
+    let Some(workspace) = state.get_workspace(workspace_id) else {
+        tracing::debug!("move_in_stack: workspace {workspace_id} not found");
+        return;
+    };
+
+    let layoutable: Vec<u32> = workspace
+        .window_ids
+        .iter()
+        .filter(|&&id| state.get_window(id).is_some_and(|w| w.is_layoutable()))
+        .copied()
+        .collect();
+
+    let Some(from) = layoutable.iter().position(|&id| id == window_id) else {
+        tracing::debug!("move_in_stack: focused window {window_id} is not tiled");
+        return;
+    };
+
+    let Some(to) = stack_target(from, layoutable.len(), direction, wrap) else {
+        return;
+    };
+
+    let mut order = layoutable.clone();
+    let moved = order.remove(from);
+    order.insert(to, moved);
+
+    let is_split = matches!(
+        workspace.layout,
+        LayoutType::Split | LayoutType::SplitHorizontal | LayoutType::SplitVertical
+    );
+    let split_ratios = if is_split && workspace.split_ratios.len() + 1 == layoutable.len() {
+        move_split_size(&workspace.split_ratios, from, to)
+    } else {
+        workspace.split_ratios.clone()
+    };
+
+    state.update_workspace(workspace_id, |ws| {
+        // Refill the slots of tiled windows in their new order
+        let mut next = order.iter();
+        for id in &mut ws.window_ids {
+            if layoutable.contains(id)
+                && let Some(&new_id) = next.next()
+            {
+                *id = new_id;
+            }
+        }
+        ws.focused_window_index = ws.window_ids.iter().position(|&id| id == window_id);
+        ws.split_ratios = split_ratios;
+    });
+
+    tracing::debug!("Moved window {window_id} from stack position {from} to {to}");
+
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_layout_changed(workspace_id, true);
+    }
+}
+
+/// Returns the stack position a window at `from` moves to, if it moves at all.
+const fn stack_target(
+    from: usize,
+    len: usize,
+    direction: CycleDirection,
+    wrap: bool,
+) -> Option<usize> {
+    match direction {
+        CycleDirection::Previous if from > 0 => Some(from - 1),
+        CycleDirection::Next if from + 1 < len => Some(from + 1),
+        CycleDirection::Previous if wrap && len > 1 => Some(len - 1),
+        CycleDirection::Next if wrap && len > 1 => Some(0),
+        _ => None,
+    }
+}
+
+/// Moves the size of the window at `from` to `to` in cumulative split ratios.
+fn move_split_size(ratios: &[f64], from: usize, to: usize) -> Vec<f64> {
+    let bounds: Vec<f64> = std::iter::once(0.0)
+        .chain(ratios.iter().copied())
+        .chain(std::iter::once(1.0))
+        .collect();
+    let mut sizes: Vec<f64> = bounds.windows(2).map(|pair| pair[1] - pair[0]).collect();
+
+    let size = sizes.remove(from);
+    sizes.insert(to, size);
+
+    sizes[..sizes.len() - 1]
+        .iter()
+        .scan(0.0, |total, size| {
+            *total += size;
+            Some(*total)
+        })
+        .collect()
+}
+
 // ============================================================================
 // Toggle Floating
 // ============================================================================
 
 /// Toggle floating state for a window.
+///
+/// The floating frame is remembered when the window goes back to tiling, and
+/// restored the next time it floats. A window returning to tiling keeps its
+/// position in the workspace's stack.
 pub fn on_toggle_floating(state: &mut TilingState, window_id: u32) {
     let Some(window) = state.get_window(window_id) else {
         tracing::warn!("toggle_floating: window {window_id} not found");
@@ -124,10 +376,31 @@ pub fn on_toggle_floating(state: &mut TilingState, window_id: u32) {
 
     let workspace_id = window.workspace_id;
     let new_floating = !window.is_floating;
+    let is_fullscreen = window.is_fullscreen;
+    let restored_frame = if new_floating {
+        window.float_frame
+    } else {
+        None
+    };
+
     state.update_window(window_id, |w| {
         w.is_floating = new_floating;
+        // A window in native fullscreen has its own Space: leave its frames alone
+        if is_fullscreen {
+            return;
+        }
+        if let Some(frame) = restored_frame {
+            w.frame = frame;
+        } else {
+            // Floating from a tiled position, or leaving floating: remember where it is
+            w.float_frame = Some(w.frame);
+        }
     });
 
+    if let Some(frame) = restored_frame.filter(|_| !is_fullscreen) {
+        let _ = window_ops::set_window_frame(window_id, &frame);
+    }
+
     tracing::debug!("Window {window_id} floating = {new_floating}");
 
     // Notify subscriber about floating change and layout recalculation
@@ -137,6 +410,269 @@ pub fn on_toggle_floating(state: &mut TilingState, window_id: u32) {
     }
 }
 
+// ============================================================================
+// Always On Top
+// ============================================================================
+
+/// Toggle whether a window is kept above normal windows.
+///
+/// Only the window level changes, so the window keeps its place in the
+/// layout and still hides with its workspace.
+///
+/// Returns the new state, or `None` if the window is not tracked or its level
+/// could not be changed, which is the case for other apps' windows without a
+/// scripting addition (see [`window_ops::set_window_always_on_top`]).
+pub fn on_toggle_always_on_top(state: &mut TilingState, window_id: u32) -> Option<bool> {
+    let Some(window) = state.get_window(window_id) else {
+        tracing::warn!("toggle_always_on_top: window {window_id} not found");
+        return None;
+    };
+
+    let on_top = !window.always_on_top;
+    if !window_ops::set_window_always_on_top(window_id, on_top) {
+        tracing::warn!("toggle_always_on_top: failed to set level of window {window_id}");
+        return None;
+    }
+
+    state.update_window(window_id, |w| w.always_on_top = on_top);
+    tracing::debug!("Window {window_id} always on top = {on_top}");
+    Some(on_top)
+}
+
+/// Put every always-on-top window back at the normal level.
+///
+/// Called when tiling is disabled or shut down, so windows don't stay above
+/// others once Stache no longer manages them.
+pub fn reset_always_on_top(state: &mut TilingState) {
+    let window_ids: Vec<u32> =
+        state.windows.iter().filter(|w| w.always_on_top).map(|w| w.id).collect();
+
+    for window_id in window_ids {
+        let _ = window_ops::set_window_always_on_top(window_id, false);
+        state.update_window(window_id, |w| w.always_on_top = false);
+    }
+}
+
+// ============================================================================
+// Opacity
+// ============================================================================
+
+/// Set the opacity of a window, from 0.0 (transparent) to 1.0 (opaque).
+///
+/// The value is stored on the window so it survives layout and focus
+/// changes. Full opacity clears the stored value.
+///
+/// Returns whether the opacity was applied, which fails for other apps'
+/// windows without a scripting addition (see [`window_ops::set_window_opacity`]).
+pub fn on_set_window_opacity(state: &mut TilingState, window_id: u32, opacity: f64) -> bool {
+    if state.get_window(window_id).is_none() {
+        tracing::warn!("set_window_opacity: window {window_id} not found");
+        return false;
+    }
+
+    let opacity = opacity.clamp(0.0, 1.0);
+    if !window_ops::set_window_opacity(window_id, opacity) {
+        tracing::warn!("set_window_opacity: failed to set opacity of window {window_id}");
+        return false;
+    }
+
+    state.update_window(window_id, |w| w.opacity = (opacity < 1.0).then_some(opacity));
+    tracing::debug!("Window {window_id} opacity = {opacity}");
+    true
+}
+
+/// Make every window with an opacity override fully opaque again.
+///
+/// Called when tiling is disabled or shuts down, so no window stays
+/// see-through once Stache no longer manages it.
+pub fn reset_window_opacity(state: &mut TilingState) {
+    let window_ids: Vec<u32> =
+        state.windows.iter().filter(|w| w.opacity.is_some()).map(|w| w.id).collect();
+
+    for window_id in window_ids {
+        let _ = window_ops::set_window_opacity(window_id, 1.0);
+        state.update_window(window_id, |w| w.opacity = None);
+    }
+}
+
+// ============================================================================
+// Minimize / Restore
+// ============================================================================
+
+/// Minimize a window to the Dock.
+///
+/// The state is updated once the minimized event arrives, which takes the
+/// window out of the layout.
+pub fn on_minimize_window(state: &TilingState, window_id: u32) {
+    let Some(window) = state.get_window(window_id) else {
+        tracing::warn!("minimize_window: window {window_id} not found");
+        return;
+    };
+
+    if window.is_minimized {
+        return;
+    }
+
+    let _ = window_ops::set_window_minimized(window_id, true);
+    tracing::debug!("Minimizing window {window_id}");
+}
+
+/// Restore the most recently minimized window of the focused workspace.
+///
+/// Minimized windows keep their place in the workspace's window list, so the
+/// restored window goes back to its prior position in the layout.
+///
+/// Returns the restored window, if any.
+pub fn on_restore_minimized_window(state: &TilingState) -> Option<u32> {
+    let Some(workspace_id) = state.get_focus_state().focused_workspace_id else {
+        tracing::debug!("restore_minimized_window: no focused workspace");
+        return None;
+    };
+
+    // Skip windows that moved away or were restored outside of Stache
+    let window_id = state.get_minimized_stack(workspace_id).iter().rev().copied().find(|&id| {
+        state
+            .get_window(id)
+            .is_some_and(|w| w.workspace_id == workspace_id && w.is_minimized)
+    });
+
+    let Some(window_id) = window_id else {
+        tracing::debug!("restore_minimized_window: no minimized window in workspace");
+        return None;
+    };
+
+    let _ = window_ops::set_window_minimized(window_id, false);
+    let _ = window_ops::focus_window(window_id);
+    tracing::debug!("Restoring window {window_id}");
+    Some(window_id)
+}
+
+// ============================================================================
+// Gather Windows
+// ============================================================================
+
+/// Move every tracked window into the focused workspace.
+///
+/// With a `filter`, only windows whose app name or bundle ID matches it
+/// (case-insensitive) are moved. The split ratios of the focused workspace are
+/// cleared so the gathered windows share it evenly.
+///
+/// Returns the focused workspace if any window was moved.
+pub fn on_gather_windows(state: &mut TilingState, filter: Option<&str>) -> Option<Uuid> {
+    let Some(target_id) = state.get_focus_state().focused_workspace_id else {
+        tracing::debug!("gather_windows: no focused workspace");
+        return None;
+    };
+
+    let matches_filter = |app_name: &str, app_id: &str| {
+        filter.is_none_or(|f| app_name.eq_ignore_ascii_case(f) || app_id.eq_ignore_ascii_case(f))
+    };
+
+    let moved: Vec<(u32, Uuid)> = state
+        .windows
+        .iter()
+        .filter(|w| w.workspace_id != target_id && matches_filter(&w.app_name, &w.app_id))
+        .map(|w| (w.id, w.workspace_id))
+        .collect();
+
+    if moved.is_empty() {
+        tracing::debug!("gather_windows: no windows to gather");
+        return None;
+    }
+
+    for &(window_id, source_id) in &moved {
+        remove_from_workspace(state, source_id, window_id);
+        state.update_window(window_id, |w| w.workspace_id = target_id);
+    }
+
+    state.update_workspace(target_id, |ws| {
+        ws.window_ids.extend(moved.iter().map(|&(window_id, _)| window_id));
+        ws.split_ratios.clear();
+    });
+
+    tracing::debug!("Gathered {} windows into workspace {target_id}", moved.len());
+
+    // Windows from hidden workspaces belong to hidden apps
+    sync_window_visibility_for_workspaces(state, &[target_id], &[]);
+
+    if let Some(handle) = get_subscriber_handle() {
+        let mut source_ids: Vec<Uuid> = moved.iter().map(|&(_, source_id)| source_id).collect();
+        source_ids.sort_unstable();
+        source_ids.dedup();
+        for source_id in source_ids {
+            handle.notify_layout_changed(source_id, true);
+        }
+        handle.notify_layout_changed(target_id, true);
+    }
+
+    Some(target_id)
+}
+
+// ============================================================================
+// Group App Windows
+// ============================================================================
+
+/// Reorders `window_ids` so the members are contiguous.
+///
+/// Members keep their relative order and are placed where the first one was;
+/// other windows keep theirs around them.
+fn group_contiguous(window_ids: &[u32], is_member: impl Fn(u32) -> bool) -> Vec<u32> {
+    let Some(anchor) = window_ids.iter().position(|&id| is_member(id)) else {
+        return window_ids.to_vec();
+    };
+
+    let (members, others): (Vec<u32>, Vec<u32>) =
+        window_ids.iter().copied().partition(|&id| is_member(id));
+
+    let mut grouped = others;
+    grouped.splice(anchor..anchor, members);
+    grouped
+}
+
+/// Make the windows of an app adjacent in the focused workspace.
+///
+/// `app` is a process ID, or an app name or bundle ID (case-insensitive).
+/// The windows stay in their workspace; only their order changes, so
+/// directional focus steps through them one after another.
+pub fn on_group_app_windows(state: &mut TilingState, app: &str) {
+    let Some(workspace_id) = state.get_focus_state().focused_workspace_id else {
+        tracing::debug!("group_app_windows: no focused workspace");
+        return;
+    };
+    let Some(workspace) = state.get_workspace(workspace_id) else {
+        return;
+    };
+
+    let members: Vec<u32> = match app.parse::<i32>() {
+        Ok(pid) => state.get_windows_for_pid(pid).iter().map(|w| w.id).collect(),
+        Err(_) => state
+            .windows
+            .iter()
+            .filter(|w| w.app_id.eq_ignore_ascii_case(app) || w.app_name.eq_ignore_ascii_case(app))
+            .map(|w| w.id)
+            .collect(),
+    };
+
+    let grouped = group_contiguous(&workspace.window_ids, |id| members.contains(&id));
+    if grouped.as_slice() == workspace.window_ids.as_slice() {
+        tracing::debug!("group_app_windows: windows of '{app}' already adjacent");
+        return;
+    }
+
+    state.update_workspace(workspace_id, |ws| {
+        // Keep the same window focused now that it may sit at another index
+        let focused_id = ws.focused_window_index.and_then(|idx| ws.window_ids.get(idx).copied());
+        ws.focused_window_index =
+            focused_id.and_then(|id| grouped.iter().position(|&other| other == id));
+        ws.window_ids = grouped.into();
+    });
+    tracing::debug!("Grouped windows of '{app}' in workspace '{}'", workspace.name);
+
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_layout_changed(workspace_id, true);
+    }
+}
+
 // ============================================================================
 // Send Window to Screen
 // ============================================================================
@@ -193,7 +729,7 @@ pub fn on_send_window_to_screen(state: &mut TilingState, target_screen: &TargetS
     };
 
     // Use move_window_to_workspace to do the actual work
-    on_move_window_to_workspace(state, window_id, target_workspace_id);
+    on_move_window_to_workspace(state, window_id, target_workspace_id, true);
     tracing::debug!("Sent window {window_id} to screen '{}'", target_screen.as_str());
 }
 
@@ -204,7 +740,7 @@ pub fn on_send_window_to_screen(state: &mut TilingState, target_screen: &TargetS
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::modules::tiling::state::{Screen, Window, Workspace};
+    use crate::modules::tiling::state::{Rect, Screen, Window, Workspace};
 
     fn create_test_state() -> TilingState {
         let mut state = TilingState::new();
@@ -251,6 +787,83 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_gather_windows() {
+        let mut state = create_test_state();
+        let ws1_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        let ws2_id = state.get_workspace_by_name("workspace2").unwrap().id;
+
+        add_window_to_workspace(&mut state, 100, ws1_id);
+        add_window_to_workspace(&mut state, 200, ws2_id);
+        add_window_to_workspace(&mut state, 201, ws2_id);
+        state.update_workspace(ws1_id, |ws| ws.split_ratios = vec![0.7]);
+        state.update_workspace(ws2_id, |ws| ws.focused_window_index = Some(1));
+
+        assert_eq!(on_gather_windows(&mut state, None), Some(ws1_id));
+
+        let ws1 = state.get_workspace(ws1_id).unwrap();
+        assert_eq!(ws1.window_ids.as_slice(), &[100, 200, 201]);
+        assert!(ws1.split_ratios.is_empty());
+        assert_eq!(state.get_window(201).unwrap().workspace_id, ws1_id);
+
+        let ws2 = state.get_workspace(ws2_id).unwrap();
+        assert!(ws2.window_ids.is_empty());
+        assert_eq!(ws2.focused_window_index, None);
+
+        // Nothing left to gather
+        assert_eq!(on_gather_windows(&mut state, None), None);
+    }
+
+    #[test]
+    fn test_gather_windows_with_app_filter() {
+        let mut state = create_test_state();
+        let ws1_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        let ws2_id = state.get_workspace_by_name("workspace2").unwrap().id;
+
+        add_window_to_workspace(&mut state, 200, ws2_id);
+        add_window_to_workspace(&mut state, 201, ws2_id);
+        state.update_window(200, |w| w.app_name = "Safari".to_string());
+        state.update_window(201, |w| w.app_id = "com.apple.Terminal".to_string());
+
+        on_gather_windows(&mut state, Some("safari"));
+        let ws1 = state.get_workspace(ws1_id).unwrap();
+        assert_eq!(ws1.window_ids.as_slice(), &[200]);
+
+        on_gather_windows(&mut state, Some("com.apple.terminal"));
+        let ws1 = state.get_workspace(ws1_id).unwrap();
+        assert_eq!(ws1.window_ids.as_slice(), &[200, 201]);
+    }
+
+    #[test]
+    fn test_group_contiguous() {
+        let is_member = |id| id % 10 == 0;
+
+        assert_eq!(group_contiguous(&[1, 10, 2, 20, 3, 30], is_member), vec![
+            1, 10, 20, 30, 2, 3
+        ]);
+        assert_eq!(group_contiguous(&[1, 2, 3], is_member), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_group_app_windows() {
+        let mut state = create_test_state();
+        let ws1_id = state.get_workspace_by_name("workspace1").unwrap().id;
+
+        for id in [100, 101, 102, 103] {
+            add_window_to_workspace(&mut state, id, ws1_id);
+        }
+        state.update_window(101, |w| w.app_id = "com.apple.Safari".to_string());
+        state.update_window(103, |w| w.app_id = "com.apple.Safari".to_string());
+        state.update_workspace(ws1_id, |ws| ws.focused_window_index = Some(2));
+
+        on_group_app_windows(&mut state, "com.apple.safari");
+
+        let ws1 = state.get_workspace(ws1_id).unwrap();
+        assert_eq!(ws1.window_ids.as_slice(), &[100, 101, 103, 102]);
+        assert_eq!(ws1.focused_window_index, Some(3));
+        assert_eq!(state.get_window(103).unwrap().workspace_id, ws1_id);
+    }
+
     #[test]
     fn test_move_window_to_workspace() {
         let mut state = create_test_state();
@@ -259,7 +872,7 @@ mod tests {
 
         add_window_to_workspace(&mut state, 100, ws1_id);
 
-        on_move_window_to_workspace(&mut state, 100, ws2_id);
+        on_move_window_to_workspace(&mut state, 100, ws2_id, true);
 
         // Window should be in new workspace
         let window = state.get_window(100).unwrap();
@@ -274,6 +887,45 @@ mod tests {
         assert!(ws2.window_ids.contains(&100));
     }
 
+    #[test]
+    fn test_move_window_to_workspace_follows() {
+        let mut state = create_test_state();
+        let ws1_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        let ws2_id = state.get_workspace_by_name("workspace2").unwrap().id;
+
+        add_window_to_workspace(&mut state, 100, ws1_id);
+        state.update_focus(|focus| focus.focused_window_id = Some(100));
+
+        on_move_window_to_workspace(&mut state, 100, ws2_id, true);
+
+        let focus = state.get_focus_state();
+        assert_eq!(focus.focused_workspace_id, Some(ws2_id));
+        assert_eq!(focus.focused_window_id, Some(100));
+        assert!(state.get_workspace(ws2_id).unwrap().is_visible);
+    }
+
+    #[test]
+    fn test_move_window_to_workspace_without_following() {
+        let mut state = create_test_state();
+        let ws1_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        let ws2_id = state.get_workspace_by_name("workspace2").unwrap().id;
+
+        add_window_to_workspace(&mut state, 100, ws1_id);
+        add_window_to_workspace(&mut state, 200, ws1_id);
+        state.update_workspace(ws1_id, |ws| ws.focused_window_index = Some(1));
+        state.update_focus(|focus| focus.focused_window_id = Some(200));
+
+        on_move_window_to_workspace(&mut state, 200, ws2_id, false);
+
+        // Source workspace keeps focus, on its remaining window
+        let focus = state.get_focus_state();
+        assert_eq!(focus.focused_workspace_id, Some(ws1_id));
+        assert_eq!(focus.focused_window_id, Some(100));
+        assert!(!state.get_workspace(ws2_id).unwrap().is_visible);
+        assert_eq!(state.get_window(200).unwrap().workspace_id, ws2_id);
+        assert_eq!(state.get_workspace(ws1_id).unwrap().focused_window_index, None);
+    }
+
     #[test]
     fn test_swap_windows() {
         let mut state = create_test_state();
@@ -316,4 +968,205 @@ mod tests {
         let window = state.get_window(100).unwrap();
         assert!(!window.is_floating);
     }
+
+    #[test]
+    fn test_toggle_always_on_top_keeps_flag_when_level_not_applied() {
+        let mut state = create_test_state();
+        let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        add_window_to_workspace(&mut state, 100, ws_id);
+
+        // The window server doesn't know window 100, so the level can't change
+        on_toggle_always_on_top(&mut state, 100);
+        assert!(!state.get_window(100).unwrap().always_on_top);
+    }
+
+    #[test]
+    fn test_reset_always_on_top_clears_flags() {
+        let mut state = create_test_state();
+        let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        add_window_to_workspace(&mut state, 100, ws_id);
+        add_window_to_workspace(&mut state, 101, ws_id);
+        state.update_window(100, |w| w.always_on_top = true);
+
+        reset_always_on_top(&mut state);
+
+        assert!(state.windows.iter().all(|w| !w.always_on_top));
+    }
+
+    #[test]
+    fn test_reset_window_opacity_clears_overrides() {
+        let mut state = create_test_state();
+        let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        add_window_to_workspace(&mut state, 100, ws_id);
+        state.update_window(100, |w| w.opacity = Some(0.5));
+
+        reset_window_opacity(&mut state);
+
+        assert_eq!(state.get_window(100).unwrap().opacity, None);
+    }
+
+    #[test]
+    fn test_restore_minimized_window_picks_most_recent() {
+        let mut state = create_test_state();
+        let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        state.update_focus(|f| f.focused_workspace_id = Some(ws_id));
+        add_window_to_workspace(&mut state, 100, ws_id);
+        add_window_to_workspace(&mut state, 101, ws_id);
+        add_window_to_workspace(&mut state, 102, ws_id);
+
+        for id in [100, 101, 102] {
+            state.update_window(id, |w| w.is_minimized = true);
+            state.push_minimized(ws_id, id);
+        }
+        // Restored outside of Stache: skipped
+        state.update_window(102, |w| w.is_minimized = false);
+
+        assert_eq!(on_restore_minimized_window(&state), Some(101));
+    }
+
+    #[test]
+    fn test_restore_minimized_window_without_minimized_windows() {
+        let mut state = create_test_state();
+        let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        state.update_focus(|f| f.focused_workspace_id = Some(ws_id));
+        add_window_to_workspace(&mut state, 100, ws_id);
+
+        assert_eq!(on_restore_minimized_window(&state), None);
+    }
+
+    #[test]
+    fn test_toggle_floating_round_trip_preserves_float_frame() {
+        let mut state = create_test_state();
+        let ws_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        add_window_to_workspace(&mut state, 100, ws_id);
+
+        let float_frame = Rect::new(200.0, 150.0, 800.0, 600.0);
+        state.update_window(100, |w| {
+            w.is_floating = true;
+            w.frame = float_frame;
+        });
+
+        // Floating -> tiled: the layout moves the window
+        on_toggle_floating(&mut state, 100);
+        state.update_window(100, |w| w.frame = Rect::new(0.0, 0.0, 960.0, 1080.0));
+
+        // Tiled -> floating: back to the floating frame
+        on_toggle_floating(&mut state, 100);
+
+        let window = state.get_window(100).unwrap();
+        assert!(window.is_floating);
+        assert_eq!(window.frame, float_frame);
+        assert_eq!(window.float_frame, Some(float_frame));
+    }
+
+    #[test]
+    fn test_stack_target_clamps_or_wraps() {
+        assert_eq!(stack_target(1, 3, CycleDirection::Previous, false), Some(0));
+        assert_eq!(stack_target(1, 3, CycleDirection::Next, false), Some(2));
+        assert_eq!(stack_target(0, 3, CycleDirection::Previous, false), None);
+        assert_eq!(stack_target(2, 3, CycleDirection::Next, false), None);
+        assert_eq!(stack_target(0, 3, CycleDirection::Previous, true), Some(2));
+        assert_eq!(stack_target(2, 3, CycleDirection::Next, true), Some(0));
+        assert_eq!(stack_target(0, 1, CycleDirection::Next, true), None);
+    }
+
+    #[test]
+    fn test_move_in_stack_carries_split_proportions() {
+        let mut state = create_test_state();
+        let ws1_id = state.get_workspace_by_name("workspace1").unwrap().id;
+
+        add_window_to_workspace(&mut state, 100, ws1_id);
+        add_window_to_workspace(&mut state, 101, ws1_id);
+        add_window_to_workspace(&mut state, 102, ws1_id);
+        state.update_workspace(ws1_id, |ws| {
+            ws.layout = LayoutType::Split;
+            // 100 takes half, 101 and 102 a quarter each
+            ws.split_ratios = vec![0.5, 0.75];
+            ws.focused_window_index = Some(0);
+        });
+        state.update_focus(|focus| focus.focused_window_id = Some(100));
+
+        on_move_window_in_stack(&mut state, CycleDirection::Next, false);
+
+        let ws = state.get_workspace(ws1_id).unwrap();
+        assert_eq!(ws.window_ids.as_slice(), &[101, 100, 102]);
+        assert_eq!(ws.focused_window_index, Some(1));
+        assert!((ws.split_ratios[0] - 0.25).abs() < 1e-9);
+        assert!((ws.split_ratios[1] - 0.75).abs() < 1e-9);
+
+        // At the end of the stack without wrapping, nothing moves
+        on_move_window_in_stack(&mut state, CycleDirection::Next, false);
+        on_move_window_in_stack(&mut state, CycleDirection::Next, false);
+        let ws = state.get_workspace(ws1_id).unwrap();
+        assert_eq!(ws.window_ids.as_slice(), &[101, 102, 100]);
+
+        // Wrapping moves it back to the front with its size
+        on_move_window_in_stack(&mut state, CycleDirection::Next, true);
+        let ws = state.get_workspace(ws1_id).unwrap();
+        assert_eq!(ws.window_ids.as_slice(), &[100, 101, 102]);
+        assert!((ws.split_ratios[0] - 0.5).abs() < 1e-9);
+        assert!((ws.split_ratios[1] - 0.75).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_move_in_stack_skips_floating_windows() {
+        let mut state = create_test_state();
+        let ws1_id = state.get_workspace_by_name("workspace1").unwrap().id;
+
+        add_window_to_workspace(&mut state, 100, ws1_id);
+        add_window_to_workspace(&mut state, 101, ws1_id);
+        add_window_to_workspace(&mut state, 102, ws1_id);
+        state.update_window(101, |w| w.is_floating = true);
+        state.update_focus(|focus| focus.focused_window_id = Some(102));
+
+        on_move_window_in_stack(&mut state, CycleDirection::Previous, false);
+
+        // The floating window keeps its slot
+        let ws = state.get_workspace(ws1_id).unwrap();
+        assert_eq!(ws.window_ids.as_slice(), &[102, 101, 100]);
+        assert_eq!(ws.focused_window_index, Some(0));
+    }
+
+    #[test]
+    fn test_toggle_extra_workspace() {
+        let mut state = create_test_state();
+        let ws1_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        let ws2_id = state.get_workspace_by_name("workspace2").unwrap().id;
+        add_window_to_workspace(&mut state, 100, ws1_id);
+
+        on_toggle_extra_workspace(&mut state, 100, ws2_id);
+
+        let window = state.get_window(100).unwrap();
+        assert_eq!(window.workspace_id, ws1_id);
+        assert_eq!(window.extra_workspace_ids, vec![ws2_id]);
+        assert!(state.get_workspace(ws1_id).unwrap().window_ids.contains(&100));
+        assert!(state.get_workspace(ws2_id).unwrap().window_ids.contains(&100));
+
+        // Toggling again removes it from the extra workspace only
+        on_toggle_extra_workspace(&mut state, 100, ws2_id);
+        assert!(state.get_window(100).unwrap().extra_workspace_ids.is_empty());
+        assert!(!state.get_workspace(ws2_id).unwrap().window_ids.contains(&100));
+        assert!(state.get_workspace(ws1_id).unwrap().window_ids.contains(&100));
+
+        // The assigned workspace cannot be toggled
+        on_toggle_extra_workspace(&mut state, 100, ws1_id);
+        assert!(state.get_window(100).unwrap().extra_workspace_ids.is_empty());
+    }
+
+    #[test]
+    fn test_move_to_extra_workspace_makes_it_the_assigned_one() {
+        let mut state = create_test_state();
+        let ws1_id = state.get_workspace_by_name("workspace1").unwrap().id;
+        let ws2_id = state.get_workspace_by_name("workspace2").unwrap().id;
+        add_window_to_workspace(&mut state, 100, ws1_id);
+        on_toggle_extra_workspace(&mut state, 100, ws2_id);
+
+        on_move_window_to_workspace(&mut state, 100, ws2_id, false);
+
+        let window = state.get_window(100).unwrap();
+        assert_eq!(window.workspace_id, ws2_id);
+        assert!(window.extra_workspace_ids.is_empty());
+        let ws2 = state.get_workspace(ws2_id).unwrap();
+        assert_eq!(ws2.window_ids.iter().filter(|&&id| id == 100).count(), 1);
+    }
 }
@@ -6,9 +6,9 @@
 use uuid::Uuid;
 
 use super::window::sync_window_visibility_for_workspaces;
-use crate::modules::tiling::actor::messages::TargetScreen;
+use crate::modules::tiling::actor::messages::{FocusDirection, TargetScreen};
 use crate::modules::tiling::init::get_subscriber_handle;
-use crate::modules::tiling::state::TilingState;
+use crate::modules::tiling::state::{Screen, TilingState};
 
 // ============================================================================
 // Workspace Switching
@@ -411,6 +411,7 @@ pub fn on_send_workspace_to_screen(state: &mut TilingState, target_screen: &Targ
     // Update workspace's screen assignment
     state.update_workspace(workspace_id, |ws| {
         ws.screen_id = target_screen_id;
+        ws.home_screen_uuid = None;
         ws.is_visible = true;
         ws.is_focused = true;
     });
@@ -443,22 +444,238 @@ pub fn on_send_workspace_to_screen(state: &mut TilingState, target_screen: &Targ
     }
 }
 
+// ============================================================================
+// Swap Workspaces Between Screens
+// ============================================================================
+
+/// Exchange the visible workspaces of two screens.
+///
+/// Both workspaces stay visible, keep their windows and are re-laid out on
+/// their new screen. Focus follows the focused workspace to its new screen.
+/// Configured screens are left unchanged, as when sending a workspace to a
+/// screen, so reconnecting a display still restores the configured assignment.
+pub fn on_swap_workspaces_between_screens(
+    state: &mut TilingState,
+    screen_a: &TargetScreen,
+    screen_b: &TargetScreen,
+) {
+    let (Some(screen_a_id), Some(screen_b_id)) =
+        (resolve_screen(state, screen_a), resolve_screen(state, screen_b))
+    else {
+        tracing::warn!(
+            "swap_workspaces_between_screens: screen '{}' or '{}' not found",
+            screen_a.as_str(),
+            screen_b.as_str()
+        );
+        return;
+    };
+
+    if screen_a_id == screen_b_id {
+        tracing::debug!("swap_workspaces_between_screens: screens are the same");
+        return;
+    }
+
+    let visible_on = |screen_id: u32| {
+        state
+            .workspaces
+            .iter()
+            .find(|ws| ws.screen_id == screen_id && ws.is_visible)
+            .map(|ws| ws.id)
+    };
+
+    let (Some(workspace_a_id), Some(workspace_b_id)) =
+        (visible_on(screen_a_id), visible_on(screen_b_id))
+    else {
+        tracing::warn!("swap_workspaces_between_screens: a screen has no visible workspace");
+        return;
+    };
+
+    for (ws_id, screen_id) in [(workspace_a_id, screen_b_id), (workspace_b_id, screen_a_id)] {
+        state.update_workspace(ws_id, |ws| {
+            ws.screen_id = screen_id;
+            ws.home_screen_uuid = None;
+        });
+    }
+
+    // Keep the focused screen in sync with the focused workspace
+    let focused_workspace_id = state.get_focus_state().focused_workspace_id;
+    if focused_workspace_id == Some(workspace_a_id) {
+        state.update_focus(|focus| focus.focused_screen_id = Some(screen_b_id));
+    } else if focused_workspace_id == Some(workspace_b_id) {
+        state.update_focus(|focus| focus.focused_screen_id = Some(screen_a_id));
+    }
+
+    tracing::debug!(
+        "Swapped workspaces {workspace_a_id} and {workspace_b_id} between screens '{}' and '{}'",
+        screen_a.as_str(),
+        screen_b.as_str()
+    );
+
+    if let Some(handle) = get_subscriber_handle() {
+        handle.notify_layout_changed(workspace_a_id, true);
+        handle.notify_layout_changed(workspace_b_id, true);
+        handle.notify_focus_changed();
+    }
+}
+
+// ============================================================================
+// Focus Screen
+// ============================================================================
+
+/// Focus the visible workspace on another screen.
+///
+/// Does nothing if the screen is not found, is already focused, or has no
+/// visible workspace.
+pub fn on_focus_screen(state: &mut TilingState, target_screen: &TargetScreen) {
+    let Some(screen_id) = resolve_screen(state, target_screen) else {
+        tracing::debug!("focus_screen: no screen '{}'", target_screen.as_str());
+        return;
+    };
+
+    let focus = state.get_focus_state();
+    if focus.focused_screen_id == Some(screen_id) {
+        tracing::trace!("focus_screen: screen {screen_id} already focused");
+        return;
+    }
+
+    let Some(workspace) = state
+        .workspaces
+        .iter()
+        .find(|ws| ws.screen_id == screen_id && ws.is_visible)
+        .cloned()
+    else {
+        tracing::debug!("focus_screen: screen {screen_id} has no visible workspace");
+        return;
+    };
+
+    // The workspace being left stays visible on its screen, it just loses focus
+    if let Some(previous_id) = focus.focused_workspace_id {
+        state.update_workspace(previous_id, |ws| ws.is_focused = false);
+    }
+    state.update_workspace(workspace.id, |ws| ws.is_focused = false);
+
+    on_switch_workspace(state, &workspace.name);
+}
+
+/// Move the mouse cursor to the center of the focused screen.
+pub fn on_center_cursor(state: &TilingState) {
+    let Some((x, y)) = focused_screen_center(state) else {
+        tracing::debug!("center_cursor: no focused screen");
+        return;
+    };
+
+    if !crate::modules::tiling::effects::window_ops::warp_cursor(x, y) {
+        tracing::warn!("center_cursor: failed to move the cursor to ({x}, {y})");
+    }
+}
+
+/// Returns the center of the focused screen's visible frame, or the main
+/// screen's when no screen is focused, in global top-left coordinates.
+fn focused_screen_center(state: &TilingState) -> Option<(f64, f64)> {
+    let screen = state
+        .get_focus_state()
+        .focused_screen_id
+        .and_then(|id| state.get_screen(id))
+        .or_else(|| state.get_main_screen())?;
+
+    Some(screen.visible_frame.center())
+}
+
 // ============================================================================
 // Screen Resolution Helper
 // ============================================================================
 
 /// Resolve a target screen to a screen ID.
 ///
-/// Supports `Main`/`Secondary` or named display.
+/// Supports `Main`/`Secondary`, named display, or a direction from the
+/// focused screen. Displays assigned the `primary` or `secondary` role in
+/// `tiling.screenRoles` take precedence, and other names match roles first.
 #[must_use]
 pub fn resolve_screen(state: &TilingState, target: &TargetScreen) -> Option<u32> {
     match target {
-        TargetScreen::Main => state.screens.iter().find(|s| s.is_main).map(|s| s.id),
-        TargetScreen::Secondary => state.screens.iter().find(|s| !s.is_main).map(|s| s.id),
-        TargetScreen::Named(name) => {
-            state.screens.iter().find(|s| s.name.eq_ignore_ascii_case(name)).map(|s| s.id)
+        TargetScreen::Main => state
+            .get_screen_with_role("primary")
+            .or_else(|| state.screens.iter().find(|s| s.is_main))
+            .map(|s| s.id),
+        TargetScreen::Secondary => state
+            .get_screen_with_role("secondary")
+            .or_else(|| state.screens.iter().find(|s| !s.is_main))
+            .map(|s| s.id),
+        TargetScreen::Named(name) => state
+            .get_screen_with_role(name)
+            .or_else(|| state.screens.iter().find(|s| s.name.eq_ignore_ascii_case(name)))
+            .map(|s| s.id),
+        TargetScreen::Direction(direction) => {
+            let from_id = state
+                .get_focus_state()
+                .focused_screen_id
+                .or_else(|| state.screens.iter().find(|s| s.is_main).map(|s| s.id))?;
+            let screens: Vec<Screen> = state.screens.iter().cloned().collect();
+            screen_in_direction(&screens, from_id, *direction)
+        }
+    }
+}
+
+/// Finds the screen next to `from_id` in a direction, using the screen frames.
+///
+/// Spatial directions prefer screens that overlap the current one on the
+/// other axis, then the closest. `Next`/`Previous` walk the screens from left
+/// to right (top to bottom for equal positions) and wrap around.
+fn screen_in_direction(screens: &[Screen], from_id: u32, direction: FocusDirection) -> Option<u32> {
+    let from = screens.iter().find(|s| s.id == from_id)?;
+
+    if !direction.is_spatial() {
+        let mut ordered: Vec<&Screen> = screens.iter().collect();
+        ordered
+            .sort_by(|a, b| a.frame.x.total_cmp(&b.frame.x).then(a.frame.y.total_cmp(&b.frame.y)));
+        if ordered.len() < 2 {
+            return None;
         }
+
+        let index = ordered.iter().position(|s| s.id == from_id)?;
+        let next = if direction == FocusDirection::Next {
+            (index + 1) % ordered.len()
+        } else {
+            (index + ordered.len() - 1) % ordered.len()
+        };
+        return Some(ordered[next].id);
     }
+
+    let (from_x, from_y) = from.frame.center();
+    let horizontal = matches!(direction, FocusDirection::Left | FocusDirection::Right);
+
+    screens
+        .iter()
+        .filter(|s| s.id != from_id)
+        .filter_map(|s| {
+            let (x, y) = s.frame.center();
+            let along = match direction {
+                FocusDirection::Left => from_x - x,
+                FocusDirection::Right => x - from_x,
+                FocusDirection::Up => from_y - y,
+                _ => y - from_y,
+            };
+            if along <= 0.0 {
+                return None;
+            }
+
+            let (across, overlaps) = if horizontal {
+                (
+                    (y - from_y).abs(),
+                    s.frame.y < from.frame.y + from.frame.height
+                        && from.frame.y < s.frame.y + s.frame.height,
+                )
+            } else {
+                (
+                    (x - from_x).abs(),
+                    s.frame.x < from.frame.x + from.frame.width
+                        && from.frame.x < s.frame.x + s.frame.width,
+                )
+            };
+            Some((s.id, !overlaps, along + across))
+        })
+        .min_by(|a, b| a.1.cmp(&b.1).then(a.2.total_cmp(&b.2)))
+        .map(|(id, _, _)| id)
 }
 
 // ============================================================================
@@ -468,7 +685,7 @@ pub fn resolve_screen(state: &TilingState, target: &TargetScreen) -> Option<u32>
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::modules::tiling::state::{Screen, Workspace};
+    use crate::modules::tiling::state::{Rect, Workspace};
 
     fn create_test_state() -> TilingState {
         let mut state = TilingState::new();
@@ -558,4 +775,174 @@ mod tests {
         let focus = state.get_focus_state();
         assert_eq!(focus.focused_workspace_id, Some(ws2_id));
     }
+
+    #[test]
+    fn test_swap_workspaces_between_screens() {
+        let mut state = create_test_state();
+        state.upsert_screen(Screen {
+            id: 2,
+            name: "Second Screen".to_string(),
+            ..Default::default()
+        });
+
+        let mut ws3 = Workspace::new("workspace3");
+        ws3.screen_id = 2;
+        ws3.is_visible = true;
+        state.upsert_workspace(ws3);
+
+        let ws1_id = state.get_workspace_by_name("workspace1").unwrap().id;
+
+        on_swap_workspaces_between_screens(
+            &mut state,
+            &TargetScreen::Main,
+            &TargetScreen::Secondary,
+        );
+
+        let ws1 = state.get_workspace(ws1_id).unwrap();
+        let ws3 = state.get_workspace_by_name("workspace3").unwrap();
+        assert_eq!(ws1.screen_id, 2);
+        assert_eq!(ws3.screen_id, 1);
+        assert!(ws1.is_visible && ws3.is_visible);
+
+        // Hidden workspaces stay where they were
+        assert_eq!(state.get_workspace_by_name("workspace2").unwrap().screen_id, 1);
+
+        // Focus follows the focused workspace
+        let focus = state.get_focus_state();
+        assert_eq!(focus.focused_workspace_id, Some(ws1_id));
+        assert_eq!(focus.focused_screen_id, Some(2));
+    }
+
+    fn screen_at(id: u32, x: f64, y: f64, width: f64, height: f64) -> Screen {
+        Screen {
+            id,
+            name: format!("screen-{id}"),
+            frame: Rect::new(x, y, width, height),
+            is_main: id == 1,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_screen_in_direction() {
+        // [3][1][2], with 4 below 1
+        let screens = vec![
+            screen_at(1, 0.0, 0.0, 1920.0, 1080.0),
+            screen_at(2, 1920.0, 0.0, 2560.0, 1440.0),
+            screen_at(3, -1080.0, -400.0, 1080.0, 1920.0),
+            screen_at(4, 200.0, 1080.0, 1440.0, 900.0),
+        ];
+
+        assert_eq!(screen_in_direction(&screens, 1, FocusDirection::Right), Some(2));
+        assert_eq!(screen_in_direction(&screens, 1, FocusDirection::Left), Some(3));
+        assert_eq!(screen_in_direction(&screens, 1, FocusDirection::Down), Some(4));
+        assert_eq!(screen_in_direction(&screens, 4, FocusDirection::Up), Some(1));
+        assert_eq!(screen_in_direction(&screens, 2, FocusDirection::Right), None);
+        assert_eq!(screen_in_direction(&screens, 1, FocusDirection::Up), None);
+    }
+
+    #[test]
+    fn test_screen_in_direction_next_previous_wraps() {
+        let screens = vec![
+            screen_at(1, 0.0, 0.0, 1920.0, 1080.0),
+            screen_at(2, 1920.0, 0.0, 1920.0, 1080.0),
+            screen_at(3, -1920.0, 0.0, 1920.0, 1080.0),
+        ];
+
+        assert_eq!(screen_in_direction(&screens, 1, FocusDirection::Next), Some(2));
+        assert_eq!(screen_in_direction(&screens, 2, FocusDirection::Next), Some(3));
+        assert_eq!(
+            screen_in_direction(&screens, 3, FocusDirection::Previous),
+            Some(2)
+        );
+        assert_eq!(screen_in_direction(&screens[..1], 1, FocusDirection::Next), None);
+    }
+
+    #[test]
+    fn test_focus_screen_in_direction() {
+        let mut state = create_test_state();
+        state.upsert_screen(screen_at(1, 0.0, 0.0, 1920.0, 1080.0));
+        state.upsert_screen(screen_at(2, 1920.0, 0.0, 1920.0, 1080.0));
+
+        let mut ws3 = Workspace::new("workspace3");
+        ws3.screen_id = 2;
+        ws3.is_visible = true;
+        let ws3_id = ws3.id;
+        state.upsert_workspace(ws3);
+
+        // Nothing to the left of the main screen
+        on_focus_screen(&mut state, &TargetScreen::Direction(FocusDirection::Left));
+        assert_eq!(state.get_focus_state().focused_screen_id, Some(1));
+
+        on_focus_screen(&mut state, &TargetScreen::Direction(FocusDirection::Right));
+
+        let focus = state.get_focus_state();
+        assert_eq!(focus.focused_workspace_id, Some(ws3_id));
+        assert_eq!(focus.focused_screen_id, Some(2));
+
+        // The workspace left behind stays visible but unfocused
+        let ws1 = state.get_workspace_by_name("workspace1").unwrap();
+        assert!(ws1.is_visible);
+        assert!(!ws1.is_focused);
+    }
+
+    #[test]
+    fn test_swap_workspaces_same_screen_is_noop() {
+        let mut state = create_test_state();
+
+        on_swap_workspaces_between_screens(&mut state, &TargetScreen::Main, &TargetScreen::Main);
+
+        assert_eq!(state.get_workspace_by_name("workspace1").unwrap().screen_id, 1);
+    }
+
+    #[test]
+    fn test_resolve_screen_prefers_roles() {
+        let mut state = create_test_state();
+        for (id, roles) in [(2, vec![]), (3, vec!["secondary".to_string()])] {
+            state.upsert_screen(Screen {
+                id,
+                name: format!("screen-{id}"),
+                roles,
+                ..Default::default()
+            });
+        }
+
+        assert_eq!(resolve_screen(&state, &TargetScreen::Main), Some(1));
+        assert_eq!(resolve_screen(&state, &TargetScreen::Secondary), Some(3));
+        assert_eq!(
+            resolve_screen(&state, &TargetScreen::Named("Secondary".to_string())),
+            Some(3)
+        );
+        assert_eq!(
+            resolve_screen(&state, &TargetScreen::Named("screen-2".to_string())),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_focused_screen_center_uses_visible_frame() {
+        let mut state = create_test_state();
+        state.upsert_screen(Screen {
+            id: 1,
+            name: "Test Screen".to_string(),
+            is_main: true,
+            visible_frame: Rect::new(0.0, 40.0, 1600.0, 960.0),
+            ..Default::default()
+        });
+        // A screen above the main one has a negative y in top-left coordinates
+        state.upsert_screen(Screen {
+            id: 2,
+            name: "Above".to_string(),
+            visible_frame: Rect::new(200.0, -1080.0, 1920.0, 1080.0),
+            ..Default::default()
+        });
+
+        assert_eq!(focused_screen_center(&state), Some((800.0, 520.0)));
+
+        state.update_focus(|focus| focus.focused_screen_id = Some(2));
+        assert_eq!(focused_screen_center(&state), Some((1160.0, -540.0)));
+
+        state.update_focus(|focus| focus.focused_screen_id = None);
+        assert_eq!(focused_screen_center(&state), Some((800.0, 520.0)));
+    }
 }
@@ -5,10 +5,17 @@
 //! - `StateQuery` - requests for state data (with response channel)
 //! - `QueryResult` - responses from queries
 
+use std::collections::HashSet;
+
 use tokio::sync::oneshot;
 use uuid::Uuid;
 
-use crate::modules::tiling::state::{FocusState, LayoutType, Rect, Screen, Window, Workspace};
+use crate::config::{
+    DimensionValue, LayoutType as ConfigLayoutType, MasterPosition, WorkspaceConfig,
+};
+use crate::modules::tiling::state::{
+    FocusState, LayoutType, RecentApp, Rect, Screen, Window, Workspace,
+};
 
 // ============================================================================
 // State Messages
@@ -42,7 +49,11 @@ pub enum StateMessage {
     WindowMinimized { window_id: u32, minimized: bool },
 
     /// Window title changed.
-    WindowTitleChanged { window_id: u32, title: String },
+    WindowTitleChanged {
+        window_id: u32,
+        title: String,
+        subrole: Option<String>,
+    },
 
     /// Window fullscreen state changed.
     WindowFullscreenChanged { window_id: u32, fullscreen: bool },
@@ -100,15 +111,58 @@ pub enum StateMessage {
     /// Cycle through layouts.
     CycleLayout { workspace_id: Uuid },
 
+    /// Toggle between the workspace's layout and the alternate layout.
+    ToggleLayout { workspace_id: Uuid },
+
+    /// Hand a workspace's layout back to `tiling.autoLayout` after it was set by hand.
+    ResetAutoLayout { workspace_id: Uuid },
+
+    /// Override where the master window sits in a workspace's Master layout.
+    SetMasterPosition {
+        workspace_id: Uuid,
+        position: MasterPosition,
+    },
+
     /// Move window to different workspace.
-    MoveWindowToWorkspace { window_id: u32, workspace_id: Uuid },
+    ///
+    /// With `follow`, the target workspace is switched to and the window focused.
+    MoveWindowToWorkspace {
+        window_id: u32,
+        workspace_id: Uuid,
+        follow: bool,
+    },
+
+    /// Move every window (or those of one app) into the focused workspace.
+    ///
+    /// `filter` matches an app name or bundle ID, case-insensitive.
+    GatherWindows { filter: Option<String> },
+
+    /// Toggle whether a window is also shown in another workspace.
+    ToggleExtraWorkspace { window_id: u32, workspace_id: Uuid },
+
+    /// Make the windows of an app adjacent in the focused workspace.
+    ///
+    /// `app` is a process ID, or an app name or bundle ID.
+    GroupAppWindows { app: String },
 
     /// Swap two windows.
     SwapWindows { window_id_a: u32, window_id_b: u32 },
 
+    /// Move the focused window one position earlier or later in the stack.
+    MoveWindowInStack { direction: CycleDirection },
+
     /// Focus next/previous window (cycle).
     CycleFocus { direction: CycleDirection },
 
+    /// Focus next/previous window of the focused window's app.
+    CycleAppFocus { direction: CycleDirection },
+
+    /// Focus the window `steps` back in the focused workspace's focus history.
+    FocusMru { steps: usize },
+
+    /// Focus the most recently used other window, in any workspace.
+    FocusGlobalMru,
+
     /// Focus window in a direction (spatial or cycle).
     FocusWindow { direction: FocusDirection },
 
@@ -118,6 +172,30 @@ pub enum StateMessage {
     /// Toggle window floating state.
     ToggleFloating { window_id: u32 },
 
+    /// Toggle whether a window is kept above normal windows.
+    ///
+    /// Responds with the new state, or `None` if the window is not tracked or
+    /// the window server refused the new level.
+    ToggleAlwaysOnTop {
+        window_id: u32,
+        respond_to: oneshot::Sender<Option<bool>>,
+    },
+
+    /// Set the opacity of a window (0.0-1.0).
+    ///
+    /// Responds with whether the window server accepted the new opacity.
+    SetWindowOpacity {
+        window_id: u32,
+        opacity: f64,
+        respond_to: oneshot::Sender<bool>,
+    },
+
+    /// Minimize a window to the Dock.
+    MinimizeWindow { window_id: u32 },
+
+    /// Restore the most recently minimized window of the focused workspace.
+    RestoreMinimizedWindow,
+
     /// Resize split ratio.
     ResizeSplit {
         workspace_id: Uuid,
@@ -128,12 +206,36 @@ pub enum StateMessage {
     /// Balance all split ratios.
     BalanceWorkspace { workspace_id: Uuid },
 
+    /// Toggle gaps off or back on for the focused workspace, or all of them.
+    ToggleGaps { all: bool },
+
+    /// Grow or shrink the gaps of the focused screen, in pixels.
+    AdjustGaps { inner: f64, outer: f64 },
+
+    /// Drop runtime gap adjustments on all screens.
+    ResetGaps,
+
+    /// The region reserved for the bar changed, e.g. the bar was hidden.
+    BarRegionChanged,
+
     /// Send focused window to another screen.
     SendWindowToScreen { target_screen: TargetScreen },
 
     /// Send focused workspace to another screen.
     SendWorkspaceToScreen { target_screen: TargetScreen },
 
+    /// Exchange the visible workspaces of two screens.
+    SwapWorkspacesBetweenScreens {
+        screen_a: TargetScreen,
+        screen_b: TargetScreen,
+    },
+
+    /// Focus the visible workspace on another screen.
+    FocusScreen { target_screen: TargetScreen },
+
+    /// Move the mouse cursor to the center of the focused screen.
+    CenterCursor,
+
     /// Resize the focused window in a dimension.
     ResizeFocusedWindow {
         dimension: ResizeDimension,
@@ -143,9 +245,42 @@ pub enum StateMessage {
     /// Apply a floating preset to the focused window.
     ApplyPreset { preset: String },
 
+    /// Set the focused window's frame to an exact placement, floating it first.
+    ///
+    /// Responds with the frame applied, or `None` without a focused window.
+    PlaceWindow {
+        placement: WindowPlacement,
+        respond_to: oneshot::Sender<Option<Rect>>,
+    },
+
+    /// Start moving the focused window with the arrow keys.
+    ///
+    /// Responds with whether the move mode started.
+    BeginMoveMode { respond_to: oneshot::Sender<bool> },
+
+    /// Nudge or swap the window being moved in a direction.
+    MoveModeStep { direction: FocusDirection },
+
+    /// End the move mode, keeping the new position with `commit` or putting
+    /// the window back otherwise.
+    EndMoveMode { commit: bool },
+
     /// Enable/disable tiling.
     SetEnabled { enabled: bool },
 
+    /// Pause or resume window management without dropping state.
+    ///
+    /// While paused, windows are tracked but not repositioned. Resuming
+    /// re-applies the layout of every visible workspace.
+    SetPaused { paused: bool },
+
+    /// Apply tiling config changes from a live reload without dropping state.
+    ApplyConfigChanges {
+        added_workspaces: Vec<WorkspaceConfig>,
+        layout_changes: Vec<(String, ConfigLayoutType)>,
+        relayout: bool,
+    },
+
     // ════════════════════════════════════════════════════════════════════════
     // Queries (with response channel)
     // ════════════════════════════════════════════════════════════════════════
@@ -165,6 +300,16 @@ pub enum StateMessage {
     /// Used during startup to track all existing windows before applying layouts.
     BatchWindowsCreated(Vec<WindowCreatedInfo>),
 
+    /// Every window that currently exists, to resync tracked state with.
+    /// Vanished windows are untracked, new ones tracked and affected layouts re-applied.
+    Reconcile {
+        /// Windows enumerated like at startup.
+        windows: Vec<WindowCreatedInfo>,
+        /// Every window ID the window server lists, including windows on other
+        /// Spaces or in fullscreen that `windows` misses.
+        server_ids: HashSet<u32>,
+    },
+
     // ════════════════════════════════════════════════════════════════════════
     // User-Initiated Drag Operations
     // ════════════════════════════════════════════════════════════════════════
@@ -191,6 +336,21 @@ pub enum StateMessage {
     /// Called after layout is computed but before effects are applied.
     SetExpectedFrames { frames: Vec<(u32, Rect)> },
 
+    /// A window kept its old size instead of resizing to its layout frame.
+    WindowResizeRefused {
+        window_id: u32,
+        requested: Rect,
+        actual: Rect,
+    },
+
+    /// A window resized to its layout frame.
+    WindowResizeApplied { window_id: u32 },
+
+    /// Drop all tracked state before tiling is started again.
+    ///
+    /// Layouts are saved first, so the restarted workspaces pick them up.
+    Reset,
+
     /// Shutdown the actor gracefully.
     Shutdown,
 }
@@ -229,19 +389,46 @@ impl StateMessage {
             Self::CycleWorkspace { .. } => "CycleWorkspace",
             Self::SetLayout { .. } => "SetLayout",
             Self::CycleLayout { .. } => "CycleLayout",
+            Self::ToggleLayout { .. } => "ToggleLayout",
+            Self::ResetAutoLayout { .. } => "ResetAutoLayout",
+            Self::SetMasterPosition { .. } => "SetMasterPosition",
             Self::MoveWindowToWorkspace { .. } => "MoveWindowToWorkspace",
+            Self::GatherWindows { .. } => "GatherWindows",
+            Self::ToggleExtraWorkspace { .. } => "ToggleExtraWorkspace",
+            Self::GroupAppWindows { .. } => "GroupAppWindows",
             Self::SwapWindows { .. } => "SwapWindows",
+            Self::MoveWindowInStack { .. } => "MoveWindowInStack",
             Self::CycleFocus { .. } => "CycleFocus",
+            Self::CycleAppFocus { .. } => "CycleAppFocus",
+            Self::FocusMru { .. } => "FocusMru",
+            Self::FocusGlobalMru => "FocusGlobalMru",
             Self::FocusWindow { .. } => "FocusWindow",
             Self::SwapWindowInDirection { .. } => "SwapWindowInDirection",
             Self::ToggleFloating { .. } => "ToggleFloating",
+            Self::ToggleAlwaysOnTop { .. } => "ToggleAlwaysOnTop",
+            Self::SetWindowOpacity { .. } => "SetWindowOpacity",
+            Self::MinimizeWindow { .. } => "MinimizeWindow",
+            Self::RestoreMinimizedWindow => "RestoreMinimizedWindow",
             Self::ResizeSplit { .. } => "ResizeSplit",
             Self::BalanceWorkspace { .. } => "BalanceWorkspace",
+            Self::ToggleGaps { .. } => "ToggleGaps",
+            Self::AdjustGaps { .. } => "AdjustGaps",
+            Self::ResetGaps => "ResetGaps",
+            Self::BarRegionChanged => "BarRegionChanged",
             Self::SendWindowToScreen { .. } => "SendWindowToScreen",
             Self::SendWorkspaceToScreen { .. } => "SendWorkspaceToScreen",
+            Self::SwapWorkspacesBetweenScreens { .. } => "SwapWorkspacesBetweenScreens",
+            Self::FocusScreen { .. } => "FocusScreen",
+            Self::CenterCursor => "CenterCursor",
             Self::ResizeFocusedWindow { .. } => "ResizeFocusedWindow",
             Self::ApplyPreset { .. } => "ApplyPreset",
+            Self::PlaceWindow { .. } => "PlaceWindow",
+            Self::BeginMoveMode { .. } => "BeginMoveMode",
+            Self::MoveModeStep { .. } => "MoveModeStep",
+            Self::EndMoveMode { .. } => "EndMoveMode",
             Self::SetEnabled { .. } => "SetEnabled",
+            Self::SetPaused { .. } => "SetPaused",
+            Self::ApplyConfigChanges { .. } => "ApplyConfigChanges",
 
             // Queries
             Self::Query { .. } => "Query",
@@ -249,6 +436,7 @@ impl StateMessage {
             // Batched Events
             Self::BatchedGeometryUpdates(_) => "BatchedGeometryUpdates",
             Self::BatchWindowsCreated(_) => "BatchWindowsCreated",
+            Self::Reconcile { .. } => "Reconcile",
 
             // User Drag Operations
             Self::UserResizeCompleted { .. } => "UserResizeCompleted",
@@ -257,15 +445,72 @@ impl StateMessage {
             // Internal
             Self::InitComplete => "InitComplete",
             Self::SetExpectedFrames { .. } => "SetExpectedFrames",
+            Self::WindowResizeRefused { .. } => "WindowResizeRefused",
+            Self::WindowResizeApplied { .. } => "WindowResizeApplied",
+            Self::Reset => "Reset",
             Self::Shutdown => "Shutdown",
         }
     }
+
+    /// Returns whether handling this message can change how many windows a
+    /// workspace tiles, or which `tiling.autoLayout` entry applies to it.
+    #[must_use]
+    pub const fn changes_window_counts(&self) -> bool {
+        matches!(
+            self,
+            Self::WindowCreated(_)
+                | Self::WindowDestroyed { .. }
+                | Self::WindowMoved { .. }
+                | Self::WindowResized { .. }
+                | Self::WindowMinimized { .. }
+                | Self::WindowTitleChanged { .. }
+                | Self::WindowFullscreenChanged { .. }
+                | Self::AppTerminated { .. }
+                | Self::AppHidden { .. }
+                | Self::AppShown { .. }
+                | Self::ScreensChanged
+                | Self::SetScreens { .. }
+                | Self::SwitchWorkspace { .. }
+                | Self::CycleWorkspace { .. }
+                | Self::ResetAutoLayout { .. }
+                | Self::MoveWindowToWorkspace { .. }
+                | Self::GatherWindows { .. }
+                | Self::ToggleExtraWorkspace { .. }
+                | Self::ToggleFloating { .. }
+                | Self::MinimizeWindow { .. }
+                | Self::RestoreMinimizedWindow
+                | Self::SendWindowToScreen { .. }
+                | Self::ApplyPreset { .. }
+                | Self::PlaceWindow { .. }
+                | Self::ApplyConfigChanges { .. }
+                | Self::BatchedGeometryUpdates(_)
+                | Self::BatchWindowsCreated(_)
+                | Self::Reconcile { .. }
+                | Self::UserMoveCompleted { .. }
+                | Self::InitComplete
+        )
+    }
 }
 
 // ============================================================================
 // Supporting Types
 // ============================================================================
 
+/// Exact frame for a floating window, for `stache tiling window --place`.
+#[derive(Debug, Clone)]
+pub struct WindowPlacement {
+    /// Offset from the left edge of the screen's visible frame.
+    pub x: DimensionValue,
+    /// Offset from the top edge of the screen's visible frame.
+    pub y: DimensionValue,
+    /// Window width.
+    pub width: DimensionValue,
+    /// Window height.
+    pub height: DimensionValue,
+    /// Whether to keep the outer gaps free, placing within them.
+    pub gaps: bool,
+}
+
 /// Information about a newly created window.
 #[derive(Debug, Clone)]
 pub struct WindowCreatedInfo {
@@ -274,6 +519,8 @@ pub struct WindowCreatedInfo {
     pub app_id: String,
     pub app_name: String,
     pub title: String,
+    /// Accessibility subrole, if the window reports one.
+    pub subrole: Option<String>,
     pub frame: Rect,
     pub is_minimized: bool,
     pub is_fullscreen: bool,
@@ -349,7 +596,7 @@ impl ResizeDimension {
     }
 }
 
-/// Target screen for send operations.
+/// Target screen for send and focus operations.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TargetScreen {
     /// Main/primary display.
@@ -358,6 +605,9 @@ pub enum TargetScreen {
     Secondary,
     /// Display by name.
     Named(String),
+    /// Display next to the focused one (left/right/up/down), or the next or
+    /// previous display in left-to-right order.
+    Direction(FocusDirection),
 }
 
 impl TargetScreen {
@@ -365,13 +615,16 @@ impl TargetScreen {
     ///
     /// "main" or "primary" -> `Main`
     /// "secondary" -> `Secondary`
+    /// a direction ("left", "right", "up", "down", "next", "previous") -> `Direction`
     /// anything else -> `Named(s)`
     #[must_use]
     pub fn parse(s: &str) -> Self {
         match s.to_lowercase().as_str() {
             "main" | "primary" => Self::Main,
             "secondary" => Self::Secondary,
-            _ => Self::Named(s.to_string()),
+            _ => {
+                FocusDirection::parse(s).map_or_else(|| Self::Named(s.to_string()), Self::Direction)
+            }
         }
     }
 
@@ -382,6 +635,7 @@ impl TargetScreen {
             Self::Main => "main",
             Self::Secondary => "secondary",
             Self::Named(name) => name,
+            Self::Direction(direction) => direction.as_str(),
         }
     }
 }
@@ -403,6 +657,19 @@ impl FocusDirection {
         }
     }
 
+    /// Returns the direction as a lowercase string.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Up => "up",
+            Self::Down => "down",
+            Self::Left => "left",
+            Self::Right => "right",
+            Self::Next => "next",
+            Self::Previous => "previous",
+        }
+    }
+
     /// Returns true if this is a spatial direction (up/down/left/right).
     #[must_use]
     pub const fn is_spatial(self) -> bool {
@@ -426,6 +693,7 @@ pub enum StateQuery {
     GetAllWindows,
     GetFocusState,
     GetEnabled,
+    GetPaused,
 
     // By ID
     GetScreen {
@@ -451,12 +719,18 @@ pub enum StateQuery {
     GetWorkspacesForScreen {
         screen_id: u32,
     },
+    /// Get the Nth workspace (0-based) of the focused screen, in config order.
+    GetWorkspaceOnFocusedScreen {
+        index: usize,
+    },
     GetVisibleWorkspaces,
     GetFocusedWorkspace,
     GetFocusedWindow,
     GetLayoutableWindows {
         workspace_id: Uuid,
     },
+    /// Get the activated apps, most recently activated first.
+    GetRecentApps,
 
     // Tab groups
     GetTabGroup {
@@ -499,6 +773,8 @@ pub enum StateQuery {
     HasScreen {
         id: u32,
     },
+    /// Check if a move mode session is in progress. O(1).
+    HasMoveMode,
 }
 
 /// Results from queries.
@@ -513,7 +789,9 @@ pub enum QueryResult {
     Window(Option<Window>),
     Focus(FocusState),
     Enabled(bool),
+    Paused(bool),
     Layout(Vec<(u32, Rect)>),
+    RecentApps(Vec<RecentApp>),
 
     // ID-only results (zero-clone)
     ScreenIds(Vec<u32>),
@@ -596,6 +874,15 @@ impl QueryResult {
         }
     }
 
+    /// Try to get paused state from the result.
+    #[must_use]
+    pub fn into_paused(self) -> Option<bool> {
+        match self {
+            Self::Paused(paused) => Some(paused),
+            _ => None,
+        }
+    }
+
     /// Try to get layout from the result.
     #[must_use]
     pub fn into_layout(self) -> Option<Vec<(u32, Rect)>> {
@@ -605,6 +892,15 @@ impl QueryResult {
         }
     }
 
+    /// Try to get recently activated apps from the result.
+    #[must_use]
+    pub fn into_recent_apps(self) -> Option<Vec<RecentApp>> {
+        match self {
+            Self::RecentApps(apps) => Some(apps),
+            _ => None,
+        }
+    }
+
     // ════════════════════════════════════════════════════════════════════════
     // ID-Only Result Conversions
     // ════════════════════════════════════════════════════════════════════════
@@ -659,6 +955,30 @@ mod tests {
         assert_ne!(CycleDirection::Next, CycleDirection::Previous);
     }
 
+    #[test]
+    fn test_changes_window_counts() {
+        assert!(StateMessage::WindowDestroyed { window_id: 1 }.changes_window_counts());
+        assert!(StateMessage::ToggleFloating { window_id: 1 }.changes_window_counts());
+        assert!(
+            StateMessage::WindowMoved {
+                window_id: 1,
+                frame: Rect::default()
+            }
+            .changes_window_counts()
+        );
+        assert!(StateMessage::BatchedGeometryUpdates(vec![]).changes_window_counts());
+        assert!(
+            StateMessage::WindowTitleChanged {
+                window_id: 1,
+                title: String::new(),
+                subrole: None,
+            }
+            .changes_window_counts()
+        );
+        assert!(!StateMessage::WindowFocused { window_id: 1 }.changes_window_counts());
+        assert!(!StateMessage::ResetGaps.changes_window_counts());
+    }
+
     #[test]
     fn test_geometry_update_type() {
         assert_ne!(GeometryUpdateType::Move, GeometryUpdateType::Resize);
@@ -685,6 +1005,7 @@ mod tests {
             app_id: "com.test.app".to_string(),
             app_name: "Test App".to_string(),
             title: "Window Title".to_string(),
+            subrole: None,
             frame: Rect::new(0.0, 0.0, 800.0, 600.0),
             is_minimized: false,
             is_fullscreen: false,
@@ -730,6 +1051,14 @@ mod tests {
             TargetScreen::parse("custom"),
             TargetScreen::Named("custom".to_string())
         );
+        assert_eq!(
+            TargetScreen::parse("Left"),
+            TargetScreen::Direction(FocusDirection::Left)
+        );
+        assert_eq!(
+            TargetScreen::parse("prev"),
+            TargetScreen::Direction(FocusDirection::Previous)
+        );
     }
 
     #[test]
@@ -740,5 +1069,6 @@ mod tests {
             TargetScreen::Named("Dell U2720Q".to_string()).as_str(),
             "Dell U2720Q"
         );
+        assert_eq!(TargetScreen::Direction(FocusDirection::Down).as_str(), "down");
     }
 }
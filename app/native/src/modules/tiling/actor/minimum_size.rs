@@ -16,8 +16,16 @@
 //! - **Split/SplitHorizontal/SplitVertical**: Linear split with cumulative ratios
 //! - **Dwindle**: Binary tree structure with per-level ratios
 //! - **Grid**: Grid-based layout with primary ratio adjustment
-
-use crate::modules::tiling::layout::{Gaps, LayoutResult, MasterPosition, calculate_layout_full};
+//!
+//! Windows that ignore resize requests are treated as fixed-size: their minimum
+//! is their current size, and [`fit_non_resizable_windows`] hands the rest of
+//! their frame to their neighbors. [`apply_app_insets`] then shrinks windows of
+//! apps with `tiling.appInsets` inside their frame.
+
+use crate::config::TilingConfig;
+use crate::modules::tiling::layout::{
+    DwindleSplit, Gaps, LayoutResult, MasterPosition, calculate_layout_full,
+};
 use crate::modules::tiling::state::{LayoutType, Rect, Window};
 
 // ============================================================================
@@ -46,15 +54,16 @@ pub fn enforce_minimum_sizes_for_split(
         return None; // Single window always gets full space
     }
 
-    // Determine if horizontal or vertical split
-    let is_horizontal = matches!(
-        layout,
-        LayoutType::SplitHorizontal
-            | LayoutType::Split if screen_frame.width >= screen_frame.height
-    );
-
-    // Get usable dimension (accounting for outer gaps)
+    // Get usable area (accounting for outer gaps)
     let usable_frame = gaps.apply_outer(screen_frame);
+
+    // Determine if horizontal or vertical split, like the layout does
+    let is_horizontal = match layout {
+        LayoutType::SplitHorizontal => true,
+        LayoutType::Split => usable_frame.is_landscape(),
+        _ => false,
+    };
+
     let total_dimension = if is_horizontal {
         usable_frame.width
     } else {
@@ -281,6 +290,7 @@ pub fn enforce_minimum_sizes_for_dwindle(
     screen_frame: &Rect,
     gaps: &Gaps,
     current_ratios: &[f64],
+    first_split: DwindleSplit,
 ) -> Option<LayoutResult> {
     // Reduced from 10 - proportional adjustments converge faster
     const MAX_ITERATIONS: usize = 3;
@@ -322,7 +332,9 @@ pub fn enforce_minimum_sizes_for_dwindle(
         ratios.push(0.5);
     }
 
-    let is_landscape = screen_frame.width >= screen_frame.height;
+    // Same orientation as the layout, which works on the usable area
+    let starts_horizontal =
+        first_split.starts_horizontal(gaps.apply_outer(screen_frame).is_landscape());
 
     for _iteration in 0..MAX_ITERATIONS {
         // Collect adjustment magnitudes based on violation severity
@@ -348,7 +360,7 @@ pub fn enforce_minimum_sizes_for_dwindle(
             if window_idx == 0 {
                 // Window 0 gets space from the first split
                 if !ratios.is_empty() {
-                    let is_h = is_dwindle_split_horizontal(0, is_landscape);
+                    let is_h = is_dwindle_split_horizontal(1, starts_horizontal);
                     let deficit = if is_h { width_deficit } else { height_deficit };
                     let total_dim = if is_h {
                         screen_frame.width
@@ -364,7 +376,7 @@ pub fn enforce_minimum_sizes_for_dwindle(
             } else {
                 let ratio_idx = window_idx - 1;
                 if ratio_idx < ratios.len() {
-                    let is_h_split = is_dwindle_split_horizontal(window_idx, is_landscape);
+                    let is_h_split = is_dwindle_split_horizontal(window_idx, starts_horizontal);
 
                     if (is_h_split && width_violated) || (!is_h_split && height_violated) {
                         let deficit = if is_h_split {
@@ -401,6 +413,7 @@ pub fn enforce_minimum_sizes_for_dwindle(
             gaps,
             &ratios,
             MasterPosition::Auto,
+            first_split,
         );
 
         // Check if violations are resolved
@@ -419,13 +432,18 @@ pub fn enforce_minimum_sizes_for_dwindle(
         gaps,
         &ratios,
         MasterPosition::Auto,
+        first_split,
     );
     Some(final_result)
 }
 
 /// Determines if a Dwindle split at the given index is horizontal.
-pub const fn is_dwindle_split_horizontal(split_index: usize, is_landscape: bool) -> bool {
-    if is_landscape {
+///
+/// Splits are numbered from 1, like in the Dwindle layout: split `n` divides
+/// the space of window `n - 1` to make room for window `n`. `starts_horizontal`
+/// is the orientation of the first split, see [`DwindleSplit::starts_horizontal`].
+pub const fn is_dwindle_split_horizontal(split_index: usize, starts_horizontal: bool) -> bool {
+    if starts_horizontal {
         !split_index.is_multiple_of(2)
     } else {
         split_index.is_multiple_of(2)
@@ -483,7 +501,8 @@ pub fn enforce_minimum_sizes_for_grid(
         current_ratios.to_vec()
     };
 
-    let is_landscape = screen_frame.width >= screen_frame.height;
+    // Same orientation as the layout, which works on the usable area
+    let is_landscape = gaps.apply_outer(screen_frame).is_landscape();
 
     for _iteration in 0..MAX_ITERATIONS {
         // Collect proportional adjustments based on violation severity
@@ -566,6 +585,7 @@ pub fn enforce_minimum_sizes_for_grid(
             gaps,
             &ratios,
             MasterPosition::Auto,
+            DwindleSplit::Auto,
         );
 
         // Check if violations are resolved
@@ -584,10 +604,71 @@ pub fn enforce_minimum_sizes_for_grid(
         gaps,
         &ratios,
         MasterPosition::Auto,
+        DwindleSplit::Auto,
     );
     Some(final_result)
 }
 
+// ============================================================================
+// Non-Resizable Windows
+// ============================================================================
+
+/// Keeps non-resizable windows at their current size within their layout frame.
+///
+/// The window stays anchored at the top-left of its frame. Neighbors directly
+/// to its right or below, within its span, grow into the space it leaves unused.
+pub fn fit_non_resizable_windows(
+    frames: &mut [(u32, Rect)],
+    layoutable_windows: &[Window],
+    gaps: &Gaps,
+) {
+    // Tolerance for matching window edges (pixels)
+    const EDGE_TOLERANCE: f64 = 1.0;
+
+    for idx in 0..frames.len() {
+        let (window_id, slot) = frames[idx];
+        let Some(window) = layoutable_windows.iter().find(|w| w.id == window_id && w.non_resizable)
+        else {
+            continue;
+        };
+
+        let fitted = Rect::new(slot.x, slot.y, window.frame.width, window.frame.height);
+        frames[idx].1 = fitted;
+
+        let slot_right = slot.x + slot.width;
+        let slot_bottom = slot.y + slot.height;
+        let fitted_right = fitted.x + fitted.width;
+        let fitted_bottom = fitted.y + fitted.height;
+
+        for (other_id, frame) in frames.iter_mut() {
+            if *other_id == window_id {
+                continue;
+            }
+
+            let within_rows = frame.y >= slot.y - EDGE_TOLERANCE
+                && frame.y + frame.height <= slot_bottom + EDGE_TOLERANCE;
+            let within_columns = frame.x >= slot.x - EDGE_TOLERANCE
+                && frame.x + frame.width <= slot_right + EDGE_TOLERANCE;
+
+            if fitted_right < slot_right
+                && within_rows
+                && (frame.x - (slot_right + gaps.inner_h)).abs() <= EDGE_TOLERANCE
+            {
+                let x = fitted_right + gaps.inner_h;
+                frame.width += frame.x - x;
+                frame.x = x;
+            } else if fitted_bottom < slot_bottom
+                && within_columns
+                && (frame.y - (slot_bottom + gaps.inner_v)).abs() <= EDGE_TOLERANCE
+            {
+                let y = fitted_bottom + gaps.inner_v;
+                frame.height += frame.y - y;
+                frame.y = y;
+            }
+        }
+    }
+}
+
 // ============================================================================
 // Violation Detection
 // ============================================================================
@@ -625,6 +706,46 @@ pub fn find_minimum_size_violations(
     violations
 }
 
+// ============================================================================
+// App Insets
+// ============================================================================
+
+/// Shrinks windows of apps listed in `tiling.appInsets` inside their frame.
+///
+/// Non-resizable windows keep their size, and insets that would leave no room
+/// for the window are ignored.
+pub fn apply_app_insets(
+    frames: &mut [(u32, Rect)],
+    layoutable_windows: &[Window],
+    config: &TilingConfig,
+) {
+    if config.app_insets.is_empty() {
+        return;
+    }
+
+    for (window_id, frame) in frames.iter_mut() {
+        let Some(window) = layoutable_windows.iter().find(|w| w.id == *window_id) else {
+            continue;
+        };
+        if window.non_resizable {
+            continue;
+        }
+        let Some(insets) = config.app_insets_for(&window.app_id) else {
+            continue;
+        };
+
+        let inset = Rect::new(
+            frame.x + insets.left,
+            frame.y + insets.top,
+            frame.width - insets.left - insets.right,
+            frame.height - insets.top - insets.bottom,
+        );
+        if inset.is_valid() {
+            *frame = inset;
+        }
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -632,6 +753,7 @@ pub fn find_minimum_size_violations(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::AppInsets;
 
     #[test]
     fn test_compute_adjusted_ratios_no_minimums() {
@@ -840,6 +962,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_enforce_minimum_sizes_split_horizontal_on_portrait_screen() {
+        use smallvec::smallvec;
+
+        // An explicit horizontal split stays horizontal on a rotated display
+        let initial_result: LayoutResult = smallvec![
+            (1, Rect::new(0.0, 0.0, 800.0, 1600.0)),
+            (2, Rect::new(810.0, 0.0, 190.0, 1600.0)),
+        ];
+        let layoutable_windows = vec![Window { id: 1, ..Default::default() }, Window {
+            id: 2,
+            minimum_size: Some((400.0, 100.0)),
+            ..Default::default()
+        }];
+        let screen_frame = Rect::new(0.0, 0.0, 1000.0, 1600.0);
+        let gaps = Gaps::uniform(10.0, 0.0);
+
+        let adjusted = enforce_minimum_sizes_for_split(
+            &initial_result,
+            &layoutable_windows,
+            &[1, 2],
+            &screen_frame,
+            &gaps,
+            LayoutType::SplitHorizontal,
+            &[0.8],
+        )
+        .expect("width violation should be resolved");
+
+        let (_, frame2) = adjusted[1];
+        assert!(frame2.width >= 399.0, "got width {}", frame2.width);
+        assert_eq!(frame2.height, 1600.0);
+    }
+
     #[test]
     fn test_enforce_minimum_sizes_single_window() {
         use smallvec::smallvec;
@@ -907,6 +1062,7 @@ mod tests {
             &screen_frame,
             &gaps,
             &[0.5],
+            DwindleSplit::Auto,
         );
 
         // No adjustment needed
@@ -946,6 +1102,7 @@ mod tests {
             &screen_frame,
             &gaps,
             &[0.9], // 90% to first window, 10% to second
+            DwindleSplit::Auto,
         );
 
         // Should have adjusted
@@ -962,6 +1119,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_enforce_minimum_sizes_dwindle_vertical_first_split() {
+        use smallvec::smallvec;
+
+        // Stacked by the vertical seed, window 2 is too short
+        let initial_result: LayoutResult = smallvec![
+            (1, Rect::new(0.0, 0.0, 1000.0, 900.0)),
+            (2, Rect::new(0.0, 900.0, 1000.0, 100.0)),
+        ];
+
+        let layoutable_windows = vec![Window { id: 1, ..Default::default() }, Window {
+            id: 2,
+            minimum_size: Some((100.0, 300.0)),
+            ..Default::default()
+        }];
+        let screen_frame = Rect::new(0.0, 0.0, 1000.0, 1000.0);
+
+        let adjusted = enforce_minimum_sizes_for_dwindle(
+            &initial_result,
+            &layoutable_windows,
+            &[1, 2],
+            &screen_frame,
+            &Gaps::default(),
+            &[0.9],
+            DwindleSplit::Vertical,
+        )
+        .unwrap();
+
+        // The split stays vertical and gives window 2 more height
+        let (_, frame2) = adjusted[1];
+        assert!((frame2.width - 1000.0).abs() < 1.0);
+        assert!(frame2.height >= 299.0);
+    }
+
     #[test]
     fn test_is_dwindle_split_horizontal() {
         // Landscape mode: odd indices are horizontal
@@ -1103,4 +1294,74 @@ mod tests {
         // Window 3 (index 2) has height violation (axis 1)
         assert!(violations.iter().any(|&(idx, axis)| idx == 2 && axis == 1));
     }
+
+    #[test]
+    fn test_fit_non_resizable_windows_gives_space_to_neighbors() {
+        let windows = vec![
+            Window {
+                id: 1,
+                frame: Rect::new(0.0, 0.0, 300.0, 400.0),
+                non_resizable: true,
+                ..Default::default()
+            },
+            Window { id: 2, ..Default::default() },
+            Window { id: 3, ..Default::default() },
+        ];
+        let gaps = Gaps::uniform(10.0, 0.0);
+        let mut frames = vec![
+            (1, Rect::new(0.0, 0.0, 495.0, 1000.0)),
+            (2, Rect::new(505.0, 0.0, 495.0, 495.0)),
+            (3, Rect::new(505.0, 505.0, 495.0, 495.0)),
+        ];
+
+        fit_non_resizable_windows(&mut frames, &windows, &gaps);
+
+        assert_eq!(frames[0].1, Rect::new(0.0, 0.0, 300.0, 400.0));
+        assert_eq!(frames[1].1, Rect::new(310.0, 0.0, 690.0, 495.0));
+        assert_eq!(frames[2].1, Rect::new(310.0, 505.0, 690.0, 495.0));
+    }
+
+    #[test]
+    fn test_non_resizable_window_needs_its_current_size() {
+        let window = Window {
+            frame: Rect::new(0.0, 0.0, 300.0, 400.0),
+            minimum_size: Some((100.0, 100.0)),
+            non_resizable: true,
+            ..Default::default()
+        };
+
+        assert_eq!(window.effective_minimum_size(), Some((300.0, 400.0)));
+    }
+
+    #[test]
+    fn test_apply_app_insets_shrinks_matching_windows() {
+        let windows = vec![
+            Window {
+                id: 1,
+                app_id: "com.apple.Terminal".to_string(),
+                ..Default::default()
+            },
+            Window {
+                id: 2,
+                app_id: "com.apple.Safari".to_string(),
+                ..Default::default()
+            },
+        ];
+        let mut config = TilingConfig::default();
+        config.app_insets.insert("com.apple.terminal".to_string(), AppInsets {
+            top: 10.0,
+            left: 20.0,
+            bottom: 30.0,
+            right: 40.0,
+        });
+        let mut frames = vec![
+            (1, Rect::new(0.0, 0.0, 500.0, 1000.0)),
+            (2, Rect::new(500.0, 0.0, 500.0, 1000.0)),
+        ];
+
+        apply_app_insets(&mut frames, &windows, &config);
+
+        assert_eq!(frames[0].1, Rect::new(20.0, 10.0, 440.0, 960.0));
+        assert_eq!(frames[1].1, Rect::new(500.0, 0.0, 500.0, 1000.0));
+    }
 }
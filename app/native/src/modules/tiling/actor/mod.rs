@@ -20,18 +20,21 @@ mod messages;
 mod minimum_size;
 
 use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::time::Instant;
 
 pub use handle::{ActorError, StateActorHandle};
 pub use messages::{
     CycleDirection, FocusDirection, GeometryUpdate, GeometryUpdateType, QueryResult, StateMessage,
-    StateQuery, WindowCreatedInfo,
+    StateQuery, WindowCreatedInfo, WindowPlacement,
 };
 use tokio::sync::mpsc;
+use uuid::Uuid;
 
 use crate::config::get_config;
-use crate::modules::tiling::init::get_subscriber_handle;
-use crate::modules::tiling::layout::{Gaps, MasterPosition, calculate_layout_full};
+use crate::modules::tiling::init::{emit_workspaces_changed, get_subscriber_handle};
+use crate::modules::tiling::layout::{DwindleSplit, MasterPosition, calculate_layout_full};
 use crate::modules::tiling::state::{LayoutType, Rect, TilingState};
+use crate::modules::tiling::{layout_cache, metrics, preview};
 
 /// Channel buffer size for the state actor.
 ///
@@ -49,6 +52,9 @@ pub struct StateActor {
 
     /// Receiver for incoming messages.
     receiver: mpsc::Receiver<StateMessage>,
+
+    /// Summary of each workspace as of the last `WORKSPACES_CHANGED` event.
+    workspace_summaries: Vec<WorkspaceSummary>,
 }
 
 impl StateActor {
@@ -63,6 +69,7 @@ impl StateActor {
         let actor = Self {
             state: TilingState::new(),
             receiver,
+            workspace_summaries: Vec::new(),
         };
 
         // Spawn the actor task using Tauri's async runtime
@@ -84,9 +91,26 @@ impl StateActor {
         while let Some(msg) = self.receiver.recv().await {
             if matches!(msg, StateMessage::Shutdown) {
                 tracing::debug!("State actor received shutdown message");
+                layout_cache::save(&self.state);
+                handlers::reset_always_on_top(&mut self.state);
+                handlers::reset_window_opacity(&mut self.state);
                 return;
             }
 
+            // Layout changes are persisted so the next launch starts from them
+            let persists_layout = matches!(
+                msg,
+                StateMessage::SetLayout { .. }
+                    | StateMessage::CycleLayout { .. }
+                    | StateMessage::ToggleLayout { .. }
+                    | StateMessage::ResetAutoLayout { .. }
+                    | StateMessage::ResizeSplit { .. }
+                    | StateMessage::BalanceWorkspace { .. }
+                    | StateMessage::ResizeFocusedWindow { .. }
+                    | StateMessage::UserResizeCompleted { .. }
+            );
+            let changes_window_counts = msg.changes_window_counts();
+
             // Wrap message handling in catch_unwind for panic recovery
             // This ensures a single bad event doesn't take down the entire tiling system
             let msg_name = msg.name();
@@ -94,6 +118,20 @@ impl StateActor {
                 self.handle_message(msg);
             }));
 
+            // Follow window count changes with the configured auto layouts
+            if changes_window_counts && result.is_ok() && !self.state.is_paused() {
+                handlers::apply_auto_layouts(&mut self.state, &get_config().tiling);
+            }
+
+            if persists_layout && result.is_ok() {
+                layout_cache::save(&self.state);
+            }
+
+            // Wait for the queue to drain, so bursts like startup tracking emit once
+            if self.receiver.is_empty() {
+                self.emit_workspaces_if_changed();
+            }
+
             if let Err(panic_info) = result {
                 // Extract panic message if possible
                 let panic_msg = panic_info
@@ -113,9 +151,27 @@ impl StateActor {
         tracing::debug!("State actor channel closed, exiting");
     }
 
+    /// Emit `WORKSPACES_CHANGED` if the window count, visibility or focus of
+    /// any workspace changed.
+    fn emit_workspaces_if_changed(&mut self) {
+        let summaries = workspace_summaries(&self.state);
+        if summaries == self.workspace_summaries {
+            return;
+        }
+
+        self.workspace_summaries = summaries;
+        emit_workspaces_changed(&self.state);
+    }
+
     /// Handle a single message.
     #[allow(clippy::too_many_lines)]
     fn handle_message(&mut self, msg: StateMessage) {
+        metrics::EVENTS_PROCESSED.increment();
+
+        if self.state.is_paused() && self.consume_while_paused(&msg) {
+            return;
+        }
+
         match msg {
             // Window events - delegated to handlers
             StateMessage::WindowCreated(info) => {
@@ -161,8 +217,13 @@ impl StateActor {
             StateMessage::WindowMinimized { window_id, minimized } => {
                 handlers::on_window_minimized(&mut self.state, window_id, minimized);
             }
-            StateMessage::WindowTitleChanged { window_id, title } => {
-                handlers::on_window_title_changed(&mut self.state, window_id, &title);
+            StateMessage::WindowTitleChanged { window_id, title, subrole } => {
+                handlers::on_window_title_changed(
+                    &mut self.state,
+                    window_id,
+                    &title,
+                    subrole.as_deref(),
+                );
             }
             StateMessage::WindowFullscreenChanged { window_id, fullscreen } => {
                 handlers::on_window_fullscreen_changed(&mut self.state, window_id, fullscreen);
@@ -201,18 +262,52 @@ impl StateActor {
                 self.on_set_layout(workspace_id, layout);
             }
             StateMessage::CycleLayout { workspace_id } => self.on_cycle_layout(workspace_id),
-            StateMessage::MoveWindowToWorkspace { window_id, workspace_id } => {
-                self.on_move_window_to_workspace(window_id, workspace_id);
+            StateMessage::ToggleLayout { workspace_id } => self.on_toggle_layout(workspace_id),
+            StateMessage::ResetAutoLayout { workspace_id } => {
+                handlers::on_reset_auto_layout(&mut self.state, workspace_id);
+            }
+            StateMessage::SetMasterPosition { workspace_id, position } => {
+                handlers::on_set_master_position(&mut self.state, workspace_id, position);
+            }
+            StateMessage::MoveWindowToWorkspace {
+                window_id,
+                workspace_id,
+                follow,
+            } => {
+                self.on_move_window_to_workspace(window_id, workspace_id, follow);
             }
+            StateMessage::GatherWindows { filter } => self.on_gather_windows(filter.as_deref()),
+            StateMessage::ToggleExtraWorkspace { window_id, workspace_id } => {
+                self.on_toggle_extra_workspace(window_id, workspace_id);
+            }
+            StateMessage::GroupAppWindows { app } => self.on_group_app_windows(&app),
             StateMessage::SwapWindows { window_id_a, window_id_b } => {
                 self.on_swap_windows(window_id_a, window_id_b);
             }
+            StateMessage::MoveWindowInStack { direction } => {
+                self.on_move_window_in_stack(direction);
+            }
             StateMessage::CycleFocus { direction } => self.on_cycle_focus(direction),
+            StateMessage::CycleAppFocus { direction } => self.on_cycle_app_focus(direction),
+            StateMessage::FocusMru { steps } => self.on_focus_mru(steps),
+            StateMessage::FocusGlobalMru => self.on_focus_global_mru(),
             StateMessage::FocusWindow { direction } => self.on_focus_window(direction),
             StateMessage::SwapWindowInDirection { direction } => {
                 self.on_swap_window_in_direction(direction);
             }
             StateMessage::ToggleFloating { window_id } => self.on_toggle_floating(window_id),
+            StateMessage::ToggleAlwaysOnTop { window_id, respond_to } => {
+                let _ = respond_to.send(self.on_toggle_always_on_top(window_id));
+            }
+            StateMessage::SetWindowOpacity { window_id, opacity, respond_to } => {
+                let _ = respond_to.send(self.on_set_window_opacity(window_id, opacity));
+            }
+            StateMessage::MinimizeWindow { window_id } => {
+                handlers::on_minimize_window(&self.state, window_id);
+            }
+            StateMessage::RestoreMinimizedWindow => {
+                handlers::on_restore_minimized_window(&self.state);
+            }
             StateMessage::ResizeSplit {
                 workspace_id,
                 window_index,
@@ -221,19 +316,49 @@ impl StateActor {
             StateMessage::BalanceWorkspace { workspace_id } => {
                 self.on_balance_workspace(workspace_id);
             }
+            StateMessage::ToggleGaps { all } => self.on_toggle_gaps(all),
+            StateMessage::AdjustGaps { inner, outer } => self.on_adjust_gaps(inner, outer),
+            StateMessage::ResetGaps => self.on_reset_gaps(),
+            StateMessage::BarRegionChanged => self.on_bar_region_changed(),
             StateMessage::SendWindowToScreen { target_screen } => {
                 self.on_send_window_to_screen(&target_screen);
             }
             StateMessage::SendWorkspaceToScreen { target_screen } => {
                 self.on_send_workspace_to_screen(&target_screen);
             }
+            StateMessage::SwapWorkspacesBetweenScreens { screen_a, screen_b } => {
+                self.on_swap_workspaces_between_screens(&screen_a, &screen_b);
+            }
+            StateMessage::FocusScreen { target_screen } => {
+                self.on_focus_screen(&target_screen);
+            }
+            StateMessage::CenterCursor => handlers::on_center_cursor(&self.state),
             StateMessage::ResizeFocusedWindow { dimension, amount } => {
                 self.on_resize_focused_window(dimension, amount);
             }
             StateMessage::ApplyPreset { preset } => {
                 self.on_apply_preset(&preset);
             }
+            StateMessage::PlaceWindow { placement, respond_to } => {
+                let _ = respond_to.send(handlers::on_place_window(&mut self.state, &placement));
+            }
+            StateMessage::BeginMoveMode { respond_to } => {
+                let _ = respond_to.send(self.on_begin_move_mode());
+            }
+            StateMessage::MoveModeStep { direction } => self.on_move_mode_step(direction),
+            StateMessage::EndMoveMode { commit } => self.on_end_move_mode(commit),
             StateMessage::SetEnabled { enabled } => self.on_set_enabled(enabled),
+            StateMessage::SetPaused { paused } => self.on_set_paused(paused),
+            StateMessage::ApplyConfigChanges {
+                added_workspaces,
+                layout_changes,
+                relayout,
+            } => handlers::on_apply_config_changes(
+                &mut self.state,
+                &added_workspaces,
+                &layout_changes,
+                relayout,
+            ),
 
             // Queries
             StateMessage::Query { query, respond_to } => {
@@ -270,21 +395,64 @@ impl StateActor {
                 self.on_batch_windows_created(windows);
             }
 
+            // Resync tracked windows with the windows that exist
+            StateMessage::Reconcile { windows, server_ids } => {
+                handlers::on_reconcile(&mut self.state, windows, &server_ids);
+            }
+
             // Initialization complete - apply layouts
             StateMessage::InitComplete => {
                 self.on_init_complete();
             }
+            StateMessage::Reset => self.on_reset(),
 
             // Update expected frames for minimum size detection
             StateMessage::SetExpectedFrames { frames } => {
                 self.on_set_expected_frames(frames);
             }
+            StateMessage::WindowResizeRefused { window_id, requested, actual } => {
+                handlers::on_window_resize_refused(&mut self.state, window_id, &requested, actual);
+            }
+            StateMessage::WindowResizeApplied { window_id } => {
+                handlers::on_window_resize_applied(&mut self.state, window_id);
+            }
 
             // Shutdown handled in run()
             StateMessage::Shutdown => unreachable!(),
         }
     }
 
+    /// Drop all tracked state, restoring window properties tiling changed.
+    fn on_reset(&mut self) {
+        layout_cache::save(&self.state);
+        handlers::on_end_move_mode(&mut self.state, true);
+        handlers::reset_always_on_top(&mut self.state);
+        handlers::reset_window_opacity(&mut self.state);
+
+        self.state = TilingState::new();
+        self.workspace_summaries.clear();
+        tracing::debug!("tiling: actor state reset");
+    }
+
+    /// Handles observer geometry events while window management is paused.
+    ///
+    /// Returns whether the message was consumed: reported frames are recorded,
+    /// but no drag operation starts and no window is snapped back or resized.
+    fn consume_while_paused(&mut self, msg: &StateMessage) -> bool {
+        match msg {
+            StateMessage::BatchedGeometryUpdates(updates) => {
+                for update in updates {
+                    self.state.update_window(update.window_id, |w| w.frame = update.frame);
+                }
+                true
+            }
+            StateMessage::UserResizeCompleted { .. } | StateMessage::UserMoveCompleted { .. } => {
+                true
+            }
+            _ => false,
+        }
+    }
+
     /// Update expected frames for all windows in the list.
     ///
     /// This also updates the window's actual `frame` field so that directional
@@ -318,6 +486,7 @@ impl StateActor {
                 QueryResult::Focus(eyeball::Observable::get(&self.state.focus).clone())
             }
             StateQuery::GetEnabled => QueryResult::Enabled(self.state.is_enabled()),
+            StateQuery::GetPaused => QueryResult::Paused(self.state.is_paused()),
 
             StateQuery::GetScreen { id } => QueryResult::Screen(self.state.get_screen(id)),
             StateQuery::GetWorkspace { id } => QueryResult::Workspace(self.state.get_workspace(id)),
@@ -337,6 +506,12 @@ impl StateActor {
             StateQuery::GetWorkspacesForScreen { screen_id } => {
                 QueryResult::Workspaces(self.state.get_workspaces_for_screen(screen_id))
             }
+            StateQuery::GetWorkspaceOnFocusedScreen { index } => QueryResult::Workspace(
+                self.state
+                    .get_focus_state()
+                    .focused_screen_id
+                    .and_then(|screen_id| self.state.get_workspace_on_screen_at(screen_id, index)),
+            ),
             StateQuery::GetVisibleWorkspaces => {
                 QueryResult::Workspaces(self.state.get_visible_workspaces())
             }
@@ -347,6 +522,7 @@ impl StateActor {
             StateQuery::GetLayoutableWindows { workspace_id } => {
                 QueryResult::Windows(self.state.get_layoutable_windows(workspace_id))
             }
+            StateQuery::GetRecentApps => QueryResult::RecentApps(self.state.get_app_mru().to_vec()),
 
             StateQuery::GetTabGroup { tab_group_id } => {
                 QueryResult::Windows(self.state.get_windows_in_tab_group(tab_group_id))
@@ -376,6 +552,7 @@ impl StateActor {
             StateQuery::HasWindow { id } => QueryResult::Exists(self.state.has_window(id)),
             StateQuery::HasWorkspace { id } => QueryResult::Exists(self.state.has_workspace(id)),
             StateQuery::HasScreen { id } => QueryResult::Exists(self.state.has_screen(id)),
+            StateQuery::HasMoveMode => QueryResult::Exists(self.state.move_mode().is_some()),
         }
     }
 
@@ -391,6 +568,9 @@ impl StateActor {
         &self,
         workspace_id: uuid::Uuid,
     ) -> Vec<(u32, crate::modules::tiling::state::Rect)> {
+        metrics::LAYOUTS_COMPUTED.increment();
+        let started = Instant::now();
+
         // Get workspace
         let Some(workspace) = self.state.get_workspace(workspace_id) else {
             tracing::warn!("compute_layout: workspace {workspace_id} not found");
@@ -425,14 +605,9 @@ impl StateActor {
             return Vec::new();
         }
 
-        // Get gaps from config with bar offset for main screen
+        // Get gaps from config with bar offset for main screen, unless toggled off
+        let gaps = handlers::layout::workspace_gaps(&self.state, &workspace, &screen);
         let config = get_config();
-        let bar_offset = if config.bar.is_enabled() {
-            f64::from(config.bar.height) + f64::from(config.bar.padding)
-        } else {
-            0.0
-        };
-        let gaps = Gaps::from_config(&config.tiling.gaps, &screen.name, screen.is_main, bar_offset);
 
         // Get master ratio: prefer workspace runtime value (set by user resize),
         // falling back to the config default.
@@ -440,8 +615,14 @@ impl StateActor {
             .master_ratio
             .unwrap_or_else(|| f64::from(config.tiling.master.ratio) / 100.0);
 
-        // Get master position from config
-        let master_position = MasterPosition::from(config.tiling.master.position);
+        // Get master position: prefer the workspace override, falling back to
+        // the config default.
+        let master_position = MasterPosition::from(
+            workspace.master_position.unwrap_or(config.tiling.master.position),
+        );
+
+        // Get the first dwindle split orientation from config
+        let dwindle_split = DwindleSplit::from(config.tiling.dwindle.first_split);
 
         // Get split ratios from workspace (may be adjusted for minimum sizes)
         let split_ratios = workspace.split_ratios.clone();
@@ -455,6 +636,7 @@ impl StateActor {
             &gaps,
             &split_ratios,
             master_position,
+            dwindle_split,
         );
 
         // Enforce minimum sizes by adjusting ratios if needed
@@ -477,6 +659,7 @@ impl StateActor {
                 &screen.visible_frame,
                 &gaps,
                 &split_ratios,
+                dwindle_split,
             ),
             LayoutType::Grid => minimum_size::enforce_minimum_sizes_for_grid(
                 &result,
@@ -490,12 +673,24 @@ impl StateActor {
             _ => None,
         };
 
-        if let Some(adjusted) = adjusted_result {
-            return adjusted.into_vec();
+        // Convert SmallVec to Vec for the query result
+        let mut frames = adjusted_result.unwrap_or(result).into_vec();
+        minimum_size::fit_non_resizable_windows(&mut frames, &layoutable_windows, &gaps);
+        minimum_size::apply_app_insets(&mut frames, &layoutable_windows, &config.tiling);
+
+        let elapsed = started.elapsed();
+        let budget = config.tiling.layout_budget;
+        if metrics::exceeds_budget(elapsed, budget) {
+            tracing::warn!(
+                "tiling: computing the {} layout of workspace '{}' with {} windows took {elapsed:?}, over the {budget}ms budget",
+                workspace.layout.as_str(),
+                workspace.name,
+                window_ids.len()
+            );
         }
+        metrics::record_layout(&workspace.name, workspace.layout, window_ids.len(), elapsed);
 
-        // Convert SmallVec to Vec for the query result
-        result.into_vec()
+        frames
     }
 
     // ========================================================================
@@ -516,24 +711,104 @@ impl StateActor {
         layout: crate::modules::tiling::state::LayoutType,
     ) {
         handlers::on_set_layout(&mut self.state, workspace_id, layout);
+        self.pin_layout(workspace_id);
     }
 
     fn on_cycle_layout(&mut self, workspace_id: uuid::Uuid) {
-        handlers::on_cycle_layout(&mut self.state, workspace_id);
+        if get_config().tiling.preview_on_cycle && !self.state.is_paused() {
+            self.preview_cycle_layout(workspace_id);
+        } else {
+            handlers::on_cycle_layout(&mut self.state, workspace_id);
+        }
+        self.pin_layout(workspace_id);
+    }
+
+    /// Cycle a workspace's layout, outlining the new layout before its windows move.
+    fn preview_cycle_layout(&mut self, workspace_id: uuid::Uuid) {
+        let Some(layout) = handlers::layout::cycled_layout(&self.state, workspace_id) else {
+            return;
+        };
+
+        handlers::layout::set_workspace_layout(&mut self.state, workspace_id, layout);
+        let frames =
+            self.compute_layout(workspace_id).into_iter().map(|(_, frame)| frame).collect();
+        preview::show(frames, move || {
+            handlers::layout::notify_layout_set(workspace_id, layout);
+        });
+        tracing::debug!("Cycled workspace {workspace_id} layout to {layout:?} with a preview");
     }
 
-    fn on_move_window_to_workspace(&mut self, window_id: u32, workspace_id: uuid::Uuid) {
-        handlers::on_move_window_to_workspace(&mut self.state, window_id, workspace_id);
+    fn on_toggle_layout(&mut self, workspace_id: uuid::Uuid) {
+        handlers::on_toggle_layout(&mut self.state, workspace_id);
+        self.pin_layout(workspace_id);
+    }
+
+    /// Keep a layout chosen by hand from being replaced by `tiling.autoLayout`.
+    fn pin_layout(&mut self, workspace_id: uuid::Uuid) {
+        self.state.update_workspace(workspace_id, |ws| ws.layout_pinned = true);
+    }
+
+    fn on_move_window_to_workspace(
+        &mut self,
+        window_id: u32,
+        workspace_id: uuid::Uuid,
+        follow: bool,
+    ) {
+        handlers::on_move_window_to_workspace(&mut self.state, window_id, workspace_id, follow);
+    }
+
+    fn on_gather_windows(&mut self, filter: Option<&str>) {
+        let Some(workspace_id) = handlers::on_gather_windows(&mut self.state, filter) else {
+            return;
+        };
+
+        // A crowded workspace may not fit every window at its minimum size
+        let too_small = self
+            .compute_layout(workspace_id)
+            .iter()
+            .filter(|(window_id, frame)| {
+                self.state
+                    .get_window(*window_id)
+                    .is_some_and(|w| w.would_violate_minimum_size(frame))
+            })
+            .count();
+
+        if too_small > 0 {
+            tracing::warn!(
+                "gather_windows: {too_small} windows are below their minimum size in workspace {workspace_id}"
+            );
+        }
+    }
+
+    fn on_toggle_extra_workspace(&mut self, window_id: u32, workspace_id: uuid::Uuid) {
+        handlers::on_toggle_extra_workspace(&mut self.state, window_id, workspace_id);
+    }
+
+    fn on_group_app_windows(&mut self, app: &str) {
+        handlers::on_group_app_windows(&mut self.state, app);
     }
 
     fn on_swap_windows(&mut self, window_id_a: u32, window_id_b: u32) {
         handlers::on_swap_windows(&mut self.state, window_id_a, window_id_b);
     }
 
+    fn on_move_window_in_stack(&mut self, direction: CycleDirection) {
+        let wrap = get_config().tiling.wrap_stack_moves;
+        handlers::on_move_window_in_stack(&mut self.state, direction, wrap);
+    }
+
     fn on_cycle_focus(&mut self, direction: CycleDirection) {
         handlers::on_cycle_focus(&mut self.state, direction);
     }
 
+    fn on_cycle_app_focus(&mut self, direction: CycleDirection) {
+        handlers::on_cycle_app_focus(&mut self.state, direction);
+    }
+
+    fn on_focus_mru(&mut self, steps: usize) { handlers::on_focus_mru(&mut self.state, steps); }
+
+    fn on_focus_global_mru(&mut self) { handlers::on_focus_global_mru(&mut self.state); }
+
     fn on_focus_window(&mut self, direction: FocusDirection) {
         handlers::on_focus_window(&mut self.state, direction);
     }
@@ -546,6 +821,14 @@ impl StateActor {
         handlers::on_toggle_floating(&mut self.state, window_id);
     }
 
+    fn on_toggle_always_on_top(&mut self, window_id: u32) -> Option<bool> {
+        handlers::on_toggle_always_on_top(&mut self.state, window_id)
+    }
+
+    fn on_set_window_opacity(&mut self, window_id: u32, opacity: f64) -> bool {
+        handlers::on_set_window_opacity(&mut self.state, window_id, opacity)
+    }
+
     fn on_resize_split(&mut self, workspace_id: uuid::Uuid, window_index: usize, delta: f64) {
         handlers::on_resize_split(&mut self.state, workspace_id, window_index, delta);
     }
@@ -554,6 +837,16 @@ impl StateActor {
         handlers::on_balance_workspace(&mut self.state, workspace_id);
     }
 
+    fn on_toggle_gaps(&mut self, all: bool) { handlers::on_toggle_gaps(&mut self.state, all); }
+
+    fn on_adjust_gaps(&mut self, inner: f64, outer: f64) {
+        handlers::on_adjust_gaps(&mut self.state, inner, outer);
+    }
+
+    fn on_reset_gaps(&mut self) { handlers::on_reset_gaps(&mut self.state); }
+
+    fn on_bar_region_changed(&self) { handlers::on_bar_region_changed(&self.state); }
+
     fn on_send_window_to_screen(&mut self, target_screen: &messages::TargetScreen) {
         handlers::on_send_window_to_screen(&mut self.state, target_screen);
     }
@@ -562,6 +855,18 @@ impl StateActor {
         handlers::on_send_workspace_to_screen(&mut self.state, target_screen);
     }
 
+    fn on_swap_workspaces_between_screens(
+        &mut self,
+        screen_a: &messages::TargetScreen,
+        screen_b: &messages::TargetScreen,
+    ) {
+        handlers::on_swap_workspaces_between_screens(&mut self.state, screen_a, screen_b);
+    }
+
+    fn on_focus_screen(&mut self, target_screen: &messages::TargetScreen) {
+        handlers::on_focus_screen(&mut self.state, target_screen);
+    }
+
     fn on_resize_focused_window(&mut self, dimension: messages::ResizeDimension, amount: i32) {
         handlers::on_resize_focused_window(&mut self.state, dimension, amount);
     }
@@ -570,11 +875,67 @@ impl StateActor {
         handlers::on_apply_preset(&mut self.state, preset_name);
     }
 
+    fn on_begin_move_mode(&mut self) -> bool {
+        let started = handlers::on_begin_move_mode(&mut self.state);
+        if !started {
+            tracing::debug!("tiling: move mode not started");
+        }
+        started
+    }
+
+    fn on_move_mode_step(&mut self, direction: FocusDirection) {
+        let step = get_config().tiling.move_mode.step;
+        handlers::on_move_mode_step(&mut self.state, direction, step);
+    }
+
+    fn on_end_move_mode(&mut self, commit: bool) {
+        handlers::on_end_move_mode(&mut self.state, commit);
+    }
+
     fn on_set_enabled(&mut self, enabled: bool) {
         tracing::debug!("Set enabled: {enabled}");
+        if !enabled {
+            handlers::on_end_move_mode(&mut self.state, true);
+            handlers::reset_always_on_top(&mut self.state);
+            handlers::reset_window_opacity(&mut self.state);
+        }
         self.state.set_enabled(enabled);
     }
 
+    fn on_set_paused(&mut self, paused: bool) {
+        if self.state.is_paused() == paused {
+            return;
+        }
+
+        tracing::info!(
+            "tiling: window management {}",
+            if paused { "paused" } else { "resumed" }
+        );
+        self.state.set_paused(paused);
+
+        if paused {
+            return;
+        }
+
+        // Workspaces may have been switched while apps were left alone
+        let visible = self.state.get_visible_workspace_ids();
+        let hidden: Vec<_> = self
+            .state
+            .workspaces
+            .iter()
+            .filter(|ws| !ws.is_visible)
+            .map(|ws| ws.id)
+            .collect();
+        handlers::window::sync_window_visibility_for_workspaces(&self.state, &visible, &hidden);
+
+        // Windows may have moved or opened while paused
+        if let Some(handle) = get_subscriber_handle() {
+            for workspace_id in visible {
+                handle.notify_layout_changed(workspace_id, true);
+            }
+        }
+    }
+
     fn on_user_resize_completed(
         &mut self,
         workspace_id: uuid::Uuid,
@@ -616,9 +977,12 @@ impl StateActor {
     ///
     /// Triggers layout calculation for all visible workspaces and hides
     /// windows from non-visible workspaces.
-    fn on_init_complete(&self) {
+    fn on_init_complete(&mut self) {
         tracing::debug!("Initialization complete, applying initial layouts");
 
+        // Cached split ratios only fit the window count they were saved with
+        layout_cache::validate_restored(&mut self.state);
+
         // Sync window visibility based on workspace visibility
         self.sync_window_visibility();
 
@@ -683,6 +1047,31 @@ impl StateActor {
     }
 }
 
+/// The parts of a workspace reported by `WORKSPACES_CHANGED`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct WorkspaceSummary {
+    id: Uuid,
+    screen_id: u32,
+    window_count: usize,
+    is_visible: bool,
+    is_focused: bool,
+}
+
+/// Returns the summary of each workspace, in state order.
+fn workspace_summaries(state: &TilingState) -> Vec<WorkspaceSummary> {
+    state
+        .workspaces
+        .iter()
+        .map(|ws| WorkspaceSummary {
+            id: ws.id,
+            screen_id: ws.screen_id,
+            window_count: ws.window_ids.len(),
+            is_visible: ws.is_visible,
+            is_focused: ws.is_focused,
+        })
+        .collect()
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -719,6 +1108,22 @@ mod tests {
         handle.shutdown().unwrap();
     }
 
+    #[tokio::test]
+    async fn test_actor_query_paused() {
+        let handle = StateActor::spawn();
+
+        let result = handle.get_paused().await.unwrap();
+        assert_eq!(result.into_paused(), Some(false));
+
+        handle.set_paused(true).unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+
+        let result = handle.get_paused().await.unwrap();
+        assert_eq!(result.into_paused(), Some(true));
+
+        handle.shutdown().unwrap();
+    }
+
     #[tokio::test]
     async fn test_actor_query_empty_state() {
         let handle = StateActor::spawn();
@@ -745,4 +1150,39 @@ mod tests {
 
         handle.shutdown().unwrap();
     }
+
+    #[test]
+    fn test_workspace_summaries() {
+        use crate::modules::tiling::state::Workspace;
+
+        let mut state = TilingState::new();
+        let mut ws = Workspace::new("code");
+        ws.window_ids = vec![1, 2].into();
+        ws.is_visible = true;
+        let ws_id = ws.id;
+        state.upsert_workspace(ws);
+        state.upsert_workspace(Workspace::new("empty"));
+
+        let summaries = workspace_summaries(&state);
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].id, ws_id);
+        assert_eq!(summaries[0].window_count, 2);
+        assert!(summaries[0].is_visible);
+        assert_eq!(summaries[1].window_count, 0);
+    }
+
+    #[test]
+    fn test_workspace_summaries_track_focus() {
+        use crate::modules::tiling::state::Workspace;
+
+        let mut state = TilingState::new();
+        let ws = Workspace::new("code");
+        let ws_id = ws.id;
+        state.upsert_workspace(ws);
+        let before = workspace_summaries(&state);
+
+        state.update_workspace(ws_id, |ws| ws.is_focused = true);
+
+        assert_ne!(workspace_summaries(&state), before);
+    }
 }
@@ -5,6 +5,7 @@
 //!
 //! 1. On init: Configure `JankyBorders` with style settings and blacklist
 //! 2. On focus change: Send a single batched command with all border colors
+//! 3. During keyboard move mode: Widen the focused border
 //!
 //! # Architecture
 //!
@@ -15,6 +16,7 @@
 use std::ffi::CString;
 use std::process::Command;
 use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 
 use parking_lot::Mutex;
 
@@ -29,6 +31,9 @@ use crate::modules::tiling::state::LayoutType;
 /// Mach service name for `JankyBorders`.
 const JANKY_BORDERS_SERVICE: &str = "git.felix.borders";
 
+/// Factor the focused border width is multiplied by during move mode.
+const MOVE_MODE_WIDTH_FACTOR: u32 = 2;
+
 // ============================================================================
 // State
 // ============================================================================
@@ -39,6 +44,12 @@ static LAST_COMMAND: OnceLock<Mutex<String>> = OnceLock::new();
 /// Mach port for IPC communication.
 static MACH_PORT: OnceLock<Mutex<Option<u32>>> = OnceLock::new();
 
+/// Width of the focused border for the focused layout, outside move mode.
+static ACTIVE_WIDTH: AtomicU32 = AtomicU32::new(0);
+
+/// Whether a keyboard move mode session is in progress.
+static MOVE_MODE: AtomicBool = AtomicBool::new(false);
+
 fn get_last_command() -> &'static Mutex<String> {
     LAST_COMMAND.get_or_init(|| Mutex::new(String::new()))
 }
@@ -329,6 +340,8 @@ pub fn init() -> bool {
 
     // Get style settings
     let width = borders.focused.width().unwrap_or(4);
+    ACTIVE_WIDTH.store(width, Ordering::Relaxed);
+    let width = move_mode_width(width);
     let style = borders.style.as_deref().unwrap_or("round");
     let style_char = if style == "square" { 's' } else { 'r' };
     let hidpi = if borders.hidpi.unwrap_or(true) {
@@ -393,6 +406,8 @@ pub fn on_focus_changed(layout: LayoutType, is_window_floating: bool) {
     // Get colors and width
     let (active_color, width) = get_border_settings(active_config);
     let (inactive_color, _) = get_border_settings(&borders.unfocused);
+    ACTIVE_WIDTH.store(width, Ordering::Relaxed);
+    let width = move_mode_width(width);
 
     // Build and send command
     let command =
@@ -401,6 +416,30 @@ pub fn on_focus_changed(layout: LayoutType, is_window_floating: bool) {
     send_command(&command);
 }
 
+/// Widens the focused border while keyboard move mode is active.
+///
+/// Serves as the move mode indicator, so it only shows when borders are
+/// enabled. The normal width comes back once move mode ends.
+pub fn set_move_mode(active: bool) {
+    if MOVE_MODE.swap(active, Ordering::Relaxed) == active
+        || !get_config().tiling.borders.is_enabled()
+    {
+        return;
+    }
+
+    let width = move_mode_width(ACTIVE_WIDTH.load(Ordering::Relaxed));
+    send_command(&format!("width={width}"));
+}
+
+/// Returns the focused border width, widened during move mode.
+fn move_mode_width(width: u32) -> u32 {
+    if MOVE_MODE.load(Ordering::Relaxed) {
+        width.saturating_mul(MOVE_MODE_WIDTH_FACTOR)
+    } else {
+        width
+    }
+}
+
 /// Refreshes border configuration.
 ///
 /// Call this when configuration is reloaded.
@@ -0,0 +1,247 @@
+//! Do Not Disturb for focused workspaces.
+//!
+//! Workspaces with `dnd: true` turn on Do Not Disturb while they are focused.
+//! The state Do Not Disturb had before entering the first of them is restored
+//! when focus moves to a workspace without the flag, so switching between
+//! flagged workspaces keeps it on and a state the user chose is never lost.
+//! When the prior state can't be read, Do Not Disturb is still turned on and
+//! treated as something Stache turned on, so it is turned off when leaving.
+//!
+//! macOS has no public API to change the Focus mode, so the change runs the
+//! "Turn On Do Not Disturb" and "Turn Off Do Not Disturb" shortcuts with the
+//! `shortcuts` tool. Running a shortcut takes a while, so changes happen on a
+//! worker thread that only applies the latest workspace when switches pile up.
+
+use std::path::Path;
+use std::process::Command;
+use std::sync::OnceLock;
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use crate::config::get_config;
+use crate::services::thread::spawn_named_thread;
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// Shortcut run to turn Do Not Disturb on.
+const DND_ON_SHORTCUT: &str = "Turn On Do Not Disturb";
+
+/// Shortcut run to turn Do Not Disturb off.
+const DND_OFF_SHORTCUT: &str = "Turn Off Do Not Disturb";
+
+/// Command-line tool running shortcuts.
+const SHORTCUTS_BIN: &str = "/usr/bin/shortcuts";
+
+/// Focus assertions, relative to the home directory. An active Focus mode has
+/// at least one assertion record.
+const ASSERTIONS_PATH: &str = "Library/DoNotDisturb/DB/Assertions.json";
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Channel to the worker, started on the first workspace change.
+static WORKER: OnceLock<Sender<bool>> = OnceLock::new();
+
+/// Tracks the Do Not Disturb state to restore when leaving flagged workspaces.
+#[derive(Debug, Default)]
+struct DndTracker {
+    /// Whether the focused workspace is flagged.
+    in_flagged: bool,
+
+    /// State before entering the current run of flagged workspaces, or `None`
+    /// if it couldn't be read or the focused workspace is not flagged. An
+    /// unknown state counts as off when leaving, since Stache turned it on.
+    prior: Option<bool>,
+}
+
+impl DndTracker {
+    /// Records that a workspace was entered and returns the state Do Not
+    /// Disturb should be set to, if it needs to change.
+    ///
+    /// `current` reads the current state, or `None` if it is unknown; it is
+    /// only called when entering a flagged workspace from one without the flag.
+    fn enter(&mut self, dnd: bool, current: impl FnOnce() -> Option<bool>) -> Option<bool> {
+        match (dnd, self.in_flagged) {
+            (true, false) => {
+                self.in_flagged = true;
+                self.prior = current();
+                (self.prior != Some(true)).then_some(true)
+            }
+            (false, true) => {
+                self.in_flagged = false;
+                (self.prior.take() != Some(true)).then_some(false)
+            }
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Applies the Do Not Disturb setting of the workspace that gained focus.
+///
+/// Does nothing unless a workspace in the config has `dnd: true`.
+pub fn on_workspace_changed(workspace: &str) {
+    let config = get_config();
+    let workspaces = &config.tiling.workspaces;
+    if WORKER.get().is_none() && !workspaces.iter().any(|ws| ws.dnd) {
+        return;
+    }
+
+    let dnd = workspaces.iter().any(|ws| ws.dnd && ws.name == workspace);
+    let sender = WORKER.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel();
+        spawn_named_thread("tiling-dnd", move || run_worker(&receiver));
+        sender
+    });
+    let _ = sender.send(dnd);
+}
+
+// ============================================================================
+// Worker
+// ============================================================================
+
+/// Applies the flag of the latest entered workspace until the channel closes.
+fn run_worker(receiver: &Receiver<bool>) {
+    let mut tracker = DndTracker::default();
+
+    while let Ok(mut dnd) = receiver.recv() {
+        // Skip workspaces that were left before we got to them.
+        while let Ok(next) = receiver.try_recv() {
+            dnd = next;
+        }
+
+        if let Some(enabled) = tracker.enter(dnd, is_dnd_enabled) {
+            set_dnd_enabled(enabled);
+        }
+    }
+}
+
+/// Returns whether a Focus mode is active, or `None` if it can't be read.
+fn is_dnd_enabled() -> Option<bool> {
+    let path = dirs::home_dir()?.join(ASSERTIONS_PATH);
+    read_assertions(&path).map(|json| has_active_assertion(&json))
+}
+
+/// Reads the Focus assertions file.
+fn read_assertions(path: &Path) -> Option<serde_json::Value> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+/// Returns whether the Focus assertions hold an active assertion.
+fn has_active_assertion(json: &serde_json::Value) -> bool {
+    json["data"].as_array().is_some_and(|data| {
+        data.iter().any(|entry| {
+            entry["storeAssertionRecords"]
+                .as_array()
+                .is_some_and(|records| !records.is_empty())
+        })
+    })
+}
+
+/// Turns Do Not Disturb on or off by running its shortcut.
+fn set_dnd_enabled(enabled: bool) {
+    let shortcut = if enabled {
+        DND_ON_SHORTCUT
+    } else {
+        DND_OFF_SHORTCUT
+    };
+
+    match Command::new(SHORTCUTS_BIN).args(["run", shortcut]).output() {
+        Ok(output) if output.status.success() => {
+            tracing::debug!(enabled, "tiling: changed Do Not Disturb");
+        }
+        Ok(output) => tracing::warn!(
+            shortcut,
+            stderr = %String::from_utf8_lossy(&output.stderr).trim(),
+            "tiling: failed to run the Do Not Disturb shortcut"
+        ),
+        Err(e) => tracing::warn!(error = %e, "tiling: failed to run shortcuts"),
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracker_turns_dnd_on_and_restores_it_off() {
+        let mut tracker = DndTracker::default();
+
+        assert_eq!(tracker.enter(true, || Some(false)), Some(true));
+        assert_eq!(tracker.enter(false, || unreachable!()), Some(false));
+    }
+
+    #[test]
+    fn tracker_keeps_dnd_the_user_turned_on() {
+        let mut tracker = DndTracker::default();
+
+        assert_eq!(tracker.enter(true, || Some(true)), None);
+        assert_eq!(tracker.enter(false, || unreachable!()), None);
+    }
+
+    #[test]
+    fn tracker_keeps_the_prior_state_across_flagged_workspaces() {
+        let mut tracker = DndTracker::default();
+
+        assert_eq!(tracker.enter(true, || Some(false)), Some(true));
+        // DND is on now, but the state to restore is still the one before.
+        assert_eq!(tracker.enter(true, || Some(true)), None);
+        assert_eq!(tracker.enter(true, || Some(true)), None);
+        assert_eq!(tracker.enter(false, || Some(true)), Some(false));
+    }
+
+    #[test]
+    fn tracker_ignores_unflagged_workspaces() {
+        let mut tracker = DndTracker::default();
+
+        assert_eq!(tracker.enter(false, || unreachable!()), None);
+        assert_eq!(tracker.enter(false, || unreachable!()), None);
+    }
+
+    #[test]
+    fn tracker_turns_dnd_off_when_the_prior_state_is_unknown() {
+        let mut tracker = DndTracker::default();
+
+        assert_eq!(tracker.enter(true, || None), Some(true));
+        assert_eq!(tracker.enter(true, || unreachable!()), None);
+        assert_eq!(tracker.enter(false, || unreachable!()), Some(false));
+
+        // The next run reads the state again
+        assert_eq!(tracker.enter(true, || Some(false)), Some(true));
+        assert_eq!(tracker.enter(false, || unreachable!()), Some(false));
+    }
+
+    #[test]
+    fn tracker_reads_the_state_again_after_leaving() {
+        let mut tracker = DndTracker::default();
+
+        assert_eq!(tracker.enter(true, || Some(false)), Some(true));
+        assert_eq!(tracker.enter(false, || Some(true)), Some(false));
+        // The user turned DND on in between, so leaving again keeps it on.
+        assert_eq!(tracker.enter(true, || Some(true)), None);
+        assert_eq!(tracker.enter(false, || Some(true)), None);
+    }
+
+    #[test]
+    fn has_active_assertion_reads_assertion_records() {
+        let active = serde_json::json!({
+            "data": [{ "storeAssertionRecords": [{ "assertionDetails": {} }] }]
+        });
+        let inactive = serde_json::json!({ "data": [{ "storeAssertionRecords": [] }] });
+        let empty = serde_json::json!({ "data": [{}] });
+
+        assert!(has_active_assertion(&active));
+        assert!(!has_active_assertion(&inactive));
+        assert!(!has_active_assertion(&empty));
+    }
+}
@@ -0,0 +1,145 @@
+//! Entrance animations for windows that open.
+//!
+//! The state actor queues an entrance when a tiled window opens in a visible
+//! workspace, and the executor plays it when it first moves the window into
+//! its tile: `scale` animates the frame from a slightly smaller rect centered
+//! in the tile, `fade` makes the window transparent right before moving it
+//! and then fades it in. An entrance that no layout plays shortly after it
+//! was queued is dropped, so it can't fire on an unrelated later move.
+//!
+//! Fading needs to change the window's opacity, which the window server only
+//! allows for other apps' windows through a scripting addition. When the
+//! window can't be made transparent, it is moved without an animation.
+//!
+//! There is no matching close animation: by the time a window is reported as
+//! destroyed, it is already gone from the screen.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use super::easing::{apply_easing, lerp};
+use super::sync::{precision_sleep, target_fps};
+use crate::config::EasingType;
+use crate::modules::tiling::effects::window_ops;
+use crate::modules::tiling::state::Rect;
+use crate::services::thread::spawn_named_thread;
+
+/// Scale of the frame a `scale` entrance starts from.
+const SCALE_FROM: f64 = 0.9;
+
+/// How long a queued entrance waits for its window to be positioned.
+const ENTRANCE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Entrance animation waiting for its window to be moved into its tile.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Entrance {
+    /// Fade in up to the window's opacity.
+    Fade { opacity: f64 },
+    /// Grow into the tile.
+    Scale,
+}
+
+/// Queued entrances with when they were queued, keyed by window ID.
+static PENDING: LazyLock<Mutex<HashMap<u32, (Entrance, Instant)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Returns whether an entrance queued at `queued_at` is still waiting to play.
+fn is_live(queued_at: Instant) -> bool { queued_at.elapsed() < ENTRANCE_TIMEOUT }
+
+/// Queues an entrance for a window, played the next time it is positioned.
+pub fn queue_entrance(window_id: u32, entrance: Entrance) {
+    let mut pending = PENDING.lock().unwrap_or_else(PoisonError::into_inner);
+    pending.retain(|_, (_, queued_at)| is_live(*queued_at));
+    pending.insert(window_id, (entrance, Instant::now()));
+}
+
+/// Returns the entrance queued for a window, if any.
+#[must_use]
+pub fn pending_entrance(window_id: u32) -> Option<Entrance> {
+    PENDING
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&window_id)
+        .filter(|(_, queued_at)| is_live(*queued_at))
+        .map(|(entrance, _)| *entrance)
+}
+
+/// Removes and returns the entrance queued for a window, if any.
+pub fn take_entrance(window_id: u32) -> Option<Entrance> {
+    PENDING
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .remove(&window_id)
+        .filter(|(_, queued_at)| is_live(*queued_at))
+        .map(|(entrance, _)| entrance)
+}
+
+/// Returns the frame a `scale` entrance starts from, centered in `target`.
+#[must_use]
+pub fn scale_from(target: Rect) -> Rect {
+    let width = target.width * SCALE_FROM;
+    let height = target.height * SCALE_FROM;
+
+    Rect::new(
+        target.x + (target.width - width) / 2.0,
+        target.y + (target.height - height) / 2.0,
+        width,
+        height,
+    )
+}
+
+/// Fades a transparent window in up to `opacity` on a background thread.
+pub fn play_fade_in(window_id: u32, opacity: f64, duration: Duration, easing: EasingType) {
+    spawn_named_thread("tiling-fade-in", move || {
+        let frame_duration = Duration::from_secs(1) / target_fps();
+        let start = Instant::now();
+
+        loop {
+            let progress = (start.elapsed().as_secs_f64() / duration.as_secs_f64()).min(1.0);
+            let alpha = lerp(0.0, opacity, apply_easing(progress, easing));
+            if !window_ops::set_window_opacity(window_id, alpha) || progress >= 1.0 {
+                return;
+            }
+
+            precision_sleep(frame_duration);
+        }
+    });
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale_from_is_centered_in_target() {
+        let from = scale_from(Rect::new(100.0, 50.0, 1000.0, 500.0));
+
+        assert!(from.approx_eq(&Rect::new(150.0, 75.0, 900.0, 450.0), 0.001));
+    }
+
+    #[test]
+    fn test_take_entrance_consumes_it() {
+        queue_entrance(4242, Entrance::Scale);
+
+        assert_eq!(pending_entrance(4242), Some(Entrance::Scale));
+        assert_eq!(take_entrance(4242), Some(Entrance::Scale));
+        assert_eq!(take_entrance(4242), None);
+    }
+
+    #[test]
+    fn test_expired_entrance_is_dropped() {
+        let queued_at = Instant::now().checked_sub(ENTRANCE_TIMEOUT).unwrap();
+        PENDING
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(4343, (Entrance::Scale, queued_at));
+
+        assert_eq!(pending_entrance(4343), None);
+        assert_eq!(take_entrance(4343), None);
+    }
+}
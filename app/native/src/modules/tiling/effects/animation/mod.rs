@@ -7,6 +7,7 @@
 //!
 //! The animation system is split into several submodules:
 //! - `easing` - Time-based easing curves (linear, ease-in, ease-out, etc.)
+//! - `entrance` - Fade and scale entrances for windows that open
 //! - `spring` - Physics-based spring animations
 //! - `transition` - Window transition types
 //! - `state` - Animation lifecycle and cancellation management
@@ -25,6 +26,7 @@
 //! ```
 
 mod easing;
+mod entrance;
 mod spring;
 mod state;
 mod sync;
@@ -36,6 +38,9 @@ use std::time::{Duration, Instant};
 use core_foundation::base::TCFType;
 // Re-export public types and functions
 pub use easing::{apply_easing, lerp};
+pub use entrance::{
+    Entrance, pending_entrance, play_fade_in, queue_entrance, scale_from, take_entrance,
+};
 use smallvec::SmallVec;
 pub use spring::{SpringParams, SpringState};
 pub use state::{
@@ -54,6 +59,7 @@ use crate::config::{EasingType, get_config};
 use crate::modules::tiling::effects::window_cache::get_cache;
 use crate::modules::tiling::ffi::skylight::UpdateGuard;
 use crate::modules::tiling::layout::LAYOUT_INLINE_CAP;
+use crate::modules::tiling::metrics;
 use crate::modules::tiling::state::Rect;
 
 // ============================================================================
@@ -247,6 +253,8 @@ impl AnimationSystem {
 
     /// Runs the animation loop for the given transitions.
     fn run_animation(&self, transitions: &[WindowTransition]) -> usize {
+        metrics::ANIMATIONS_STARTED.increment();
+
         let max_distance =
             transitions.iter().map(WindowTransition::max_distance).fold(0.0_f64, f64::max);
 
@@ -478,6 +486,7 @@ fn set_frame_direct(element: *mut c_void, frame: &Rect) -> bool {
         if pos_value.is_null() {
             return false;
         }
+        metrics::AX_CALLS.add(2);
         let pos_result = AXUIElementSetAttributeValue(element, cf_pos, pos_value.cast());
         CFRelease(pos_value.cast());
 
@@ -18,6 +18,7 @@
 
 use tauri::Emitter;
 
+use super::animation::{Entrance, pending_entrance, play_fade_in, scale_from, take_entrance};
 use super::{
     AnimationSystem, BorderState, TilingEffect, WindowTransition, get_interrupted_position,
     window_cache, window_ops,
@@ -28,6 +29,40 @@ use crate::modules::tiling::state::Rect;
 // Effect Executor
 // ============================================================================
 
+/// Reports to the state actor which windows kept their old size and which
+/// resized as requested.
+fn report_refused_resizes(resizes: &[(u32, Rect, Rect)]) {
+    // Only windows asked to change size can refuse
+    let mut resizes = resizes
+        .iter()
+        .filter(|(_, before, requested)| !window_ops::same_size(before, requested))
+        .peekable();
+    if resizes.peek().is_none() {
+        return;
+    }
+
+    let Some(handle) = crate::modules::tiling::init::get_handle() else {
+        return;
+    };
+
+    let cache = window_cache::get_cache();
+    for (window_id, before, requested) in resizes {
+        let Some(actual) = cache.get_window_frame(*window_id) else {
+            continue;
+        };
+
+        if window_ops::resize_was_refused(before, requested, &actual) {
+            if let Err(e) = handle.report_resize_refused(*window_id, *requested, actual) {
+                tracing::warn!("tiling: failed to report refused resize: {e}");
+            }
+        } else if window_ops::same_size(requested, &actual)
+            && let Err(e) = handle.report_resize_applied(*window_id)
+        {
+            tracing::warn!("tiling: failed to report applied resize: {e}");
+        }
+    }
+}
+
 /// Executes tiling effects on the system.
 ///
 /// The executor batches effects by type for efficient execution:
@@ -162,6 +197,7 @@ impl EffectExecutor {
     ///
     /// Uses the window element cache for efficient batch resolution,
     /// avoiding repeated O(n*m) lookups during animation setup.
+    /// Windows that kept their old size are reported to the state actor.
     fn execute_frame_updates(&self, updates: &[(u32, Rect, bool)]) -> usize {
         if updates.is_empty() {
             return 0;
@@ -169,6 +205,9 @@ impl EffectExecutor {
 
         let mut success_count = 0;
 
+        // (window_id, frame before, requested frame) of windows to verify afterwards
+        let mut resizes: Vec<(u32, Rect, Rect)> = Vec::new();
+
         // Separate animated and immediate updates
         let (animated, immediate): (Vec<_>, Vec<_>) =
             updates.iter().partition(|(_, _, animate)| *animate);
@@ -177,8 +216,32 @@ impl EffectExecutor {
         if !immediate.is_empty() {
             let cache = window_cache::get_cache();
             for (window_id, frame, _) in &immediate {
-                if cache.set_window_frame_fast(*window_id, frame) {
+                let before = cache.get_window_frame_fast(*window_id);
+
+                // A fade entrance hides the window while it moves into its tile,
+                // and is skipped when the window can't be made transparent
+                let entrance = take_entrance(*window_id).filter(|entrance| {
+                    !matches!(entrance, Entrance::Fade { .. })
+                        || window_ops::set_window_opacity(*window_id, 0.0)
+                });
+
+                let moved = cache.set_window_frame_fast(*window_id, frame);
+
+                // Fade in even if the move failed, so the window never stays transparent
+                if let Some(Entrance::Fade { opacity }) = entrance {
+                    play_fade_in(
+                        *window_id,
+                        opacity,
+                        self.animation_system.duration(),
+                        self.animation_system.easing(),
+                    );
+                }
+
+                if moved {
                     success_count += 1;
+                    if let Some(before) = before {
+                        resizes.push((*window_id, before, *frame));
+                    }
                 } else {
                     tracing::warn!("Failed to set frame for window {window_id}");
                 }
@@ -196,6 +259,12 @@ impl EffectExecutor {
             let transitions: Vec<WindowTransition> = animated
                 .iter()
                 .filter_map(|(window_id, target_frame, _)| {
+                    // A scale entrance grows the window from inside its tile
+                    if take_entrance(*window_id) == Some(Entrance::Scale) {
+                        let from_frame = scale_from(*target_frame);
+                        return Some(WindowTransition::new(*window_id, from_frame, *target_frame));
+                    }
+
                     // Check for interrupted position first, then fall back to cached frame
                     let from_frame = get_interrupted_position(*window_id)
                         .or_else(|| cache.get_window_frame_fast(*window_id))?;
@@ -204,10 +273,21 @@ impl EffectExecutor {
                 .collect();
 
             if !transitions.is_empty() {
+                let targets: Vec<(u32, Rect, Rect)> =
+                    transitions.iter().map(|t| (t.window_id, t.from, t.to)).collect();
                 success_count += self.animation_system.animate(transitions);
+
+                // Interrupted animations never reached their target size
+                resizes.extend(
+                    targets
+                        .into_iter()
+                        .filter(|(id, _, _)| get_interrupted_position(*id).is_none()),
+                );
             }
         }
 
+        report_refused_resizes(&resizes);
+
         success_count
     }
 
@@ -332,8 +412,10 @@ pub fn effects_from_layout_change(change: &super::LayoutChange) -> Vec<TilingEff
             // - Animate existing windows (those in old_positions) that are moving
             // - Don't animate new windows (not in old_positions) - they just appear
             // This means when a window is created/destroyed, existing windows
-            // animate to their new positions while the new window appears instantly.
-            let animate = old_positions.contains_key(window_id);
+            // animate to their new positions while the new window appears instantly,
+            // unless it has a scale entrance to play.
+            let animate = old_positions.contains_key(window_id)
+                || pending_entrance(*window_id) == Some(Entrance::Scale);
 
             effects.push(TilingEffect::SetWindowFrame {
                 window_id: *window_id,
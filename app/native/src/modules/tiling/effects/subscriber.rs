@@ -36,8 +36,19 @@
 //! let subscriber = EffectSubscriber::new(actor_handle, executor);
 //! tauri::async_runtime::spawn(subscriber.run());
 //! ```
+//!
+//! # Coalescing
+//!
+//! Bursts such as an app opening several windows send many layout
+//! notifications for the same workspace in quick succession. They are held
+//! until no new one arrived for `LAYOUT_COALESCE_DELAY`, at most
+//! `LAYOUT_COALESCE_MAX_DELAY` after the first, and each workspace is then
+//! laid out once. A user-triggered notification makes the merged one
+//! user-triggered. Any other notification first flushes the pending layouts,
+//! so notifications are still handled in the order they were sent.
 
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 use tokio::sync::mpsc;
 use uuid::Uuid;
@@ -45,8 +56,59 @@ use uuid::Uuid;
 use super::executor::{EffectExecutor, effects_from_focus_change, effects_from_layout_change};
 use super::{FocusChange, LayoutChange, TilingEffect, begin_animation, cancel_animation};
 use crate::modules::tiling::actor::{QueryResult, StateActorHandle, StateQuery};
+use crate::modules::tiling::metrics;
 use crate::modules::tiling::state::{FocusState, LayoutType, Rect};
 
+/// Quiet time after the last layout notification before pending layouts apply.
+const LAYOUT_COALESCE_DELAY: Duration = Duration::from_millis(16);
+
+/// Longest a layout notification is held while notifications keep arriving.
+const LAYOUT_COALESCE_MAX_DELAY: Duration = Duration::from_millis(100);
+
+// ============================================================================
+// Layout Coalescer
+// ============================================================================
+
+/// Merges layout notifications received in quick succession.
+#[derive(Debug, Default)]
+struct LayoutCoalescer {
+    /// Pending workspaces in arrival order, with whether any of their
+    /// notifications was user-triggered.
+    pending: Vec<(Uuid, bool)>,
+
+    /// When the first pending notification arrived.
+    first_at: Option<Instant>,
+
+    /// When the last pending notification arrived.
+    last_at: Option<Instant>,
+}
+
+impl LayoutCoalescer {
+    /// Adds a layout notification, merging it with a pending one for the same workspace.
+    fn push(&mut self, workspace_id: Uuid, user_triggered: bool, now: Instant) {
+        match self.pending.iter_mut().find(|(id, _)| *id == workspace_id) {
+            Some((_, pending_user_triggered)) => *pending_user_triggered |= user_triggered,
+            None => self.pending.push((workspace_id, user_triggered)),
+        }
+
+        self.first_at.get_or_insert(now);
+        self.last_at = Some(now);
+    }
+
+    /// Returns when the pending layouts are due, if there are any.
+    fn deadline(&self) -> Option<Instant> {
+        let (first_at, last_at) = self.first_at.zip(self.last_at)?;
+        Some((last_at + LAYOUT_COALESCE_DELAY).min(first_at + LAYOUT_COALESCE_MAX_DELAY))
+    }
+
+    /// Takes the pending layouts, in the order their workspaces were first notified.
+    fn take(&mut self) -> Vec<(Uuid, bool)> {
+        self.first_at = None;
+        self.last_at = None;
+        std::mem::take(&mut self.pending)
+    }
+}
+
 // ============================================================================
 // Subscriber State
 // ============================================================================
@@ -201,6 +263,9 @@ pub struct EffectSubscriber {
 
     /// Previous state for computing deltas.
     state: SubscriberState,
+
+    /// Layout notifications waiting for a burst to settle.
+    pending_layouts: LayoutCoalescer,
 }
 
 /// Handle for sending notifications to the subscriber.
@@ -297,6 +362,7 @@ impl EffectSubscriber {
             executor,
             notification_rx,
             state: SubscriberState::new(),
+            pending_layouts: LayoutCoalescer::default(),
         };
 
         let handle = EffectSubscriberHandle { notification_tx };
@@ -320,21 +386,53 @@ impl EffectSubscriber {
         // Apply initial border colors based on focused workspace layout
         self.apply_initial_border_colors().await;
 
-        while let Some(notification) = self.notification_rx.recv().await {
+        loop {
+            let notification = match self.pending_layouts.deadline() {
+                Some(deadline) => {
+                    tokio::select! {
+                        notification = self.notification_rx.recv() => notification,
+                        () = tokio::time::sleep_until(deadline.into()) => {
+                            self.flush_pending_layouts().await;
+                            continue;
+                        }
+                    }
+                }
+                None => self.notification_rx.recv().await,
+            };
+
             match notification {
-                SubscriberNotification::Shutdown => {
+                Some(SubscriberNotification::LayoutChanged { workspace_id, user_triggered }) => {
+                    self.pending_layouts.push(workspace_id, user_triggered, Instant::now());
+                }
+                Some(SubscriberNotification::Shutdown) => {
                     tracing::debug!("Effect subscriber received shutdown");
                     break;
                 }
-                notification => {
+                Some(notification) => {
+                    self.flush_pending_layouts().await;
                     self.handle_notification(notification).await;
                 }
+                None => {
+                    self.flush_pending_layouts().await;
+                    break;
+                }
             }
         }
 
         tracing::debug!("Effect subscriber stopped");
     }
 
+    /// Applies the layouts held by the coalescer, once per workspace.
+    async fn flush_pending_layouts(&mut self) {
+        for (workspace_id, user_triggered) in self.pending_layouts.take() {
+            self.handle_notification(SubscriberNotification::LayoutChanged {
+                workspace_id,
+                user_triggered,
+            })
+            .await;
+        }
+    }
+
     /// Handles a single notification.
     async fn handle_notification(&mut self, notification: SubscriberNotification) {
         tracing::debug!("tiling: subscriber received notification: {notification:?}");
@@ -395,6 +493,15 @@ impl EffectSubscriber {
             "tiling: handle_layout_changed for workspace {workspace_id}, user_triggered={user_triggered}"
         );
 
+        // Nothing is repositioned while paused; resuming re-applies every visible layout
+        if matches!(
+            self.actor_handle.query(StateQuery::GetPaused).await,
+            Ok(QueryResult::Paused(true))
+        ) {
+            tracing::debug!("tiling: paused, skipping layout for workspace {workspace_id}");
+            return Vec::new();
+        }
+
         // Query the current layout for this workspace
         let layout_result =
             self.actor_handle.query(StateQuery::GetWindowLayout { workspace_id }).await;
@@ -423,11 +530,13 @@ impl EffectSubscriber {
         // Update state and get the change
         let Some(change) = self.state.update_layout(workspace_id, new_positions, user_triggered)
         else {
+            metrics::LAYOUT_CACHE_HITS.increment();
             tracing::debug!(
                 "tiling: no actual layout change detected for workspace {workspace_id}"
             );
             return Vec::new(); // No actual change
         };
+        metrics::LAYOUT_CACHE_MISSES.increment();
 
         tracing::debug!(
             "tiling: layout change detected - old: {} windows, new: {} windows",
@@ -636,6 +745,42 @@ impl EffectSubscriber {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_coalescer_merges_notifications_per_workspace() {
+        let now = Instant::now();
+        let (ws_a, ws_b) = (Uuid::now_v7(), Uuid::now_v7());
+        let mut coalescer = LayoutCoalescer::default();
+        assert_eq!(coalescer.deadline(), None);
+
+        coalescer.push(ws_a, false, now);
+        coalescer.push(ws_b, false, now);
+        coalescer.push(ws_a, true, now);
+        coalescer.push(ws_a, false, now);
+
+        // Forced requests are merged, not dropped
+        assert_eq!(coalescer.take(), vec![(ws_a, true), (ws_b, false)]);
+        assert_eq!(coalescer.deadline(), None);
+    }
+
+    #[test]
+    fn test_coalescer_deadline_is_debounced_and_capped() {
+        let start = Instant::now();
+        let ws_id = Uuid::now_v7();
+        let mut coalescer = LayoutCoalescer::default();
+
+        coalescer.push(ws_id, false, start);
+        assert_eq!(coalescer.deadline(), Some(start + LAYOUT_COALESCE_DELAY));
+
+        // Each notification pushes the deadline back
+        let later = start + Duration::from_millis(10);
+        coalescer.push(ws_id, false, later);
+        assert_eq!(coalescer.deadline(), Some(later + LAYOUT_COALESCE_DELAY));
+
+        // A steady stream still applies within the cap
+        coalescer.push(ws_id, false, start + Duration::from_millis(95));
+        assert_eq!(coalescer.deadline(), Some(start + LAYOUT_COALESCE_MAX_DELAY));
+    }
+
     #[test]
     fn test_subscriber_state_default() {
         let state = SubscriberState::new();
@@ -23,6 +23,7 @@ use std::time::{Duration, Instant};
 use dashmap::DashMap;
 
 use crate::modules::tiling::ffi::skylight;
+use crate::modules::tiling::metrics;
 use crate::modules::tiling::state::Rect;
 
 // ============================================================================
@@ -709,6 +710,7 @@ fn set_frame_on_element(element: AXUIElementRef, frame: &Rect) -> bool {
     if pos_value.is_null() {
         return false;
     }
+    metrics::AX_CALLS.increment();
     let pos_result =
         unsafe { AXUIElementSetAttributeValue(element, cf_position(), pos_value.cast()) };
     unsafe { CFRelease(pos_value.cast()) };
@@ -719,6 +721,7 @@ fn set_frame_on_element(element: AXUIElementRef, frame: &Rect) -> bool {
     if size_value.is_null() {
         return false;
     }
+    metrics::AX_CALLS.increment();
     let size_result =
         unsafe { AXUIElementSetAttributeValue(element, cf_size(), size_value.cast()) };
     unsafe { CFRelease(size_value.cast()) };
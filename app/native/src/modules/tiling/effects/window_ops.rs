@@ -22,12 +22,20 @@
 use std::cell::OnceCell;
 use std::ffi::c_void;
 use std::ptr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc;
+use std::time::Duration;
 
 use core_foundation::base::TCFType;
 use core_foundation::boolean::CFBoolean;
 use core_foundation::string::CFString;
 
+use crate::config::get_config;
+use crate::modules::tiling::ffi::{Transaction, skylight};
+use crate::modules::tiling::metrics;
 use crate::modules::tiling::state::Rect;
+use crate::modules::tiling::window::get_focused_window_id;
+use crate::services::thread::spawn_named_thread;
 
 // ============================================================================
 // FFI Declarations
@@ -37,6 +45,8 @@ type AXUIElementRef = *mut c_void;
 type AXError = i32;
 
 const K_AX_ERROR_SUCCESS: AXError = 0;
+/// The app didn't respond to the request in time.
+const K_AX_ERROR_CANNOT_COMPLETE: AXError = -25204;
 
 #[link(name = "ApplicationServices", kind = "framework")]
 unsafe extern "C" {
@@ -83,6 +93,7 @@ thread_local! {
     static CF_MAIN: OnceCell<CFString> = const { OnceCell::new() };
     static CF_RAISE: OnceCell<CFString> = const { OnceCell::new() };
     static CF_ROLE: OnceCell<CFString> = const { OnceCell::new() };
+    static CF_MINIMIZED: OnceCell<CFString> = const { OnceCell::new() };
 }
 
 /// Gets or creates a cached `CFString`.
@@ -113,6 +124,9 @@ fn cf_raise() -> *const c_void { cached_cfstring!(CF_RAISE, "AXRaise") }
 #[inline]
 fn cf_role() -> *const c_void { cached_cfstring!(CF_ROLE, "AXRole") }
 
+#[inline]
+fn cf_minimized() -> *const c_void { cached_cfstring!(CF_MINIMIZED, "AXMinimized") }
+
 // ============================================================================
 // AX Element Resolution
 // ============================================================================
@@ -368,6 +382,7 @@ unsafe fn set_ax_position(element: AXUIElementRef, x: f64, y: f64) -> bool {
         return false;
     }
 
+    metrics::AX_CALLS.increment();
     let result = unsafe { AXUIElementSetAttributeValue(element, cf_position(), value.cast()) };
     unsafe { CFRelease(value.cast()) };
 
@@ -387,6 +402,7 @@ unsafe fn set_ax_size(element: AXUIElementRef, width: f64, height: f64) -> bool
         return false;
     }
 
+    metrics::AX_CALLS.increment();
     let result = unsafe { AXUIElementSetAttributeValue(element, cf_size(), value.cast()) };
     unsafe { CFRelease(value.cast()) };
 
@@ -618,6 +634,24 @@ pub fn set_window_frame_verified(window_id: u32, target: &Rect) -> FrameSetResul
     }
 }
 
+/// Tolerance in points when comparing requested and actual window sizes.
+pub const RESIZE_TOLERANCE: f64 = 5.0;
+
+/// Returns whether two frames have the same size, within [`RESIZE_TOLERANCE`].
+#[must_use]
+pub fn same_size(a: &Rect, b: &Rect) -> bool {
+    (a.width - b.width).abs() <= RESIZE_TOLERANCE && (a.height - b.height).abs() <= RESIZE_TOLERANCE
+}
+
+/// Returns whether a window ignored a resize request.
+///
+/// That is the case when `requested` has a different size than `before`, but
+/// the window still has the size it had before the request.
+#[must_use]
+pub fn resize_was_refused(before: &Rect, requested: &Rect, actual: &Rect) -> bool {
+    !same_size(before, requested) && same_size(before, actual)
+}
+
 /// Gets the minimum size constraints for a window, if available.
 ///
 /// # Arguments
@@ -647,8 +681,40 @@ fn get_ax_element_for_window(
     app.windows().into_iter().find(|w| w.window_id() == Some(window_id))
 }
 
+/// Returns whether a window still has an accessibility element.
+///
+/// Looks the window up in its app's AX windows, bypassing the element cache.
+/// A tracked window without one is a phantom: macOS no longer knows it, but
+/// no destroy event reached the state actor.
+#[must_use]
+pub fn has_ax_element(window_id: u32) -> bool { get_ax_element_for_window(window_id).is_some() }
+
+/// Delay before the first focus retry; each later retry waits one step longer.
+const FOCUS_RETRY_BACKOFF: Duration = Duration::from_millis(10);
+
+/// Longest a focus retry waits for the main thread to run a try.
+const FOCUS_MAIN_THREAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Request number of the last programmatic focus, bumped by every
+/// [`focus_window`] call so retries of an older request stop.
+static LAST_PROGRAMMATIC_FOCUS: AtomicU64 = AtomicU64::new(0);
+
+/// Outcome of one try to focus a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FocusOutcome {
+    /// The app accepted the focus.
+    Focused,
+    /// The app didn't respond in time, so trying again may work.
+    Busy,
+    /// Focusing failed in a way retrying won't fix, e.g. the window is gone.
+    Failed,
+}
+
 /// Focuses a window (gives it keyboard focus).
 ///
+/// If the app does not respond, the focus is tried again up to
+/// `tiling.focusRetries` times on a background thread.
+///
 /// # Arguments
 ///
 /// * `window_id` - The window ID to focus.
@@ -658,30 +724,139 @@ fn get_ax_element_for_window(
 /// `true` if the operation succeeded (optimistically, since execution is async).
 #[must_use]
 pub fn focus_window(window_id: u32) -> bool {
+    let request = LAST_PROGRAMMATIC_FOCUS.fetch_add(1, Ordering::SeqCst) + 1;
+    let retries = get_config().tiling.focus_retries;
+
     // Dispatch to main thread using the project's existing dispatch utility
     crate::platform::thread::dispatch_on_main(move || {
-        focus_window_impl(window_id);
+        if focus_window_impl(window_id) != FocusOutcome::Busy || retries == 0 {
+            return;
+        }
+
+        // The try didn't take, so this is still the window focused before it,
+        // unless the focus already landed late
+        let previous = get_focused_window_id();
+        if previous == Some(window_id) {
+            return;
+        }
+
+        tracing::debug!("focus_window: focusing window {window_id} failed, retrying");
+        spawn_named_thread("tiling-focus-retry", move || {
+            let backend = AxFocusBackend;
+            if !retry_focus(
+                &backend,
+                &LAST_PROGRAMMATIC_FOCUS,
+                request,
+                window_id,
+                previous,
+                retries,
+            ) {
+                tracing::debug!("focus_window: gave up focusing window {window_id}");
+            }
+        });
     });
 
     // Return true optimistically - the actual operation runs async
     true
 }
 
+/// Window focus operations used by [`retry_focus`].
+trait FocusBackend {
+    /// Tries once to focus a window.
+    fn focus(&self, window_id: u32) -> FocusOutcome;
+
+    /// Returns the currently focused window.
+    fn focused_window(&self) -> Option<u32>;
+
+    /// Waits before the next try.
+    fn wait(&self, delay: Duration);
+}
+
+/// Focuses windows through the Accessibility API on the main thread.
+struct AxFocusBackend;
+
+impl AxFocusBackend {
+    /// Runs `f` on the main thread and waits for its result.
+    fn on_main<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> Option<T> {
+        let (tx, rx) = mpsc::channel();
+        crate::platform::thread::dispatch_on_main(move || {
+            let _ = tx.send(f());
+        });
+        rx.recv_timeout(FOCUS_MAIN_THREAD_TIMEOUT).ok()
+    }
+}
+
+impl FocusBackend for AxFocusBackend {
+    fn focus(&self, window_id: u32) -> FocusOutcome {
+        // A busy main thread is no reason to give up
+        Self::on_main(move || focus_window_impl(window_id)).unwrap_or(FocusOutcome::Busy)
+    }
+
+    fn focused_window(&self) -> Option<u32> { Self::on_main(get_focused_window_id).flatten() }
+
+    fn wait(&self, delay: Duration) { std::thread::sleep(delay); }
+}
+
+/// Tries again to focus a window after the app didn't respond, up to
+/// `retries` times, waiting one `FOCUS_RETRY_BACKOFF` step longer before each
+/// try.
+///
+/// Stops once `request` is no longer the last programmatic focus, once focus
+/// moved to a window other than `previous`, the one focused before the first
+/// try, so the retries never fight a focus change made since, or once a try
+/// fails for another reason than the app not responding.
+///
+/// Returns whether the window was focused.
+fn retry_focus(
+    backend: &impl FocusBackend,
+    last_request: &AtomicU64,
+    request: u64,
+    window_id: u32,
+    previous: Option<u32>,
+    retries: u32,
+) -> bool {
+    for attempt in 1..=retries {
+        backend.wait(FOCUS_RETRY_BACKOFF * attempt);
+
+        if last_request.load(Ordering::SeqCst) != request {
+            return false;
+        }
+
+        // An earlier try may have landed late.
+        let focused = backend.focused_window();
+        if focused == Some(window_id) {
+            return true;
+        }
+        if focused != previous {
+            return false;
+        }
+
+        match backend.focus(window_id) {
+            FocusOutcome::Focused => return true,
+            FocusOutcome::Busy => {}
+            FocusOutcome::Failed => return false,
+        }
+    }
+
+    false
+}
+
 /// Internal implementation of `focus_window` (runs on main thread).
-fn focus_window_impl(window_id: u32) {
+fn focus_window_impl(window_id: u32) -> FocusOutcome {
     // First, get the PID for this window so we can activate the app
     let pid = get_window_pid(window_id);
 
     // Activate the owning application first - this is critical for focus to work
     if let Some(pid) = pid {
-        activate_app(pid);
+        let _ = activate_app(pid);
     }
 
     let Some(element) = resolve_window_element(window_id) else {
         tracing::debug!("focus_window: could not resolve window {window_id}");
-        return;
+        return FocusOutcome::Failed;
     };
 
+    metrics::AX_CALLS.add(3);
     unsafe {
         let true_value = CFBoolean::true_value();
 
@@ -693,7 +868,7 @@ fn focus_window_impl(window_id: u32) {
         );
 
         // Set AXFocused to true
-        let _focus_result = AXUIElementSetAttributeValue(
+        let focus_result = AXUIElementSetAttributeValue(
             element,
             cf_focused(),
             true_value.as_concrete_TypeRef().cast(),
@@ -703,7 +878,13 @@ fn focus_window_impl(window_id: u32) {
         let _raise_result = AXUIElementPerformAction(element, cf_raise());
 
         CFRelease(element.cast());
-    };
+
+        match focus_result {
+            K_AX_ERROR_SUCCESS => FocusOutcome::Focused,
+            K_AX_ERROR_CANNOT_COMPLETE => FocusOutcome::Busy,
+            _ => FocusOutcome::Failed,
+        }
+    }
 }
 
 /// Gets the PID of the app that owns a window.
@@ -750,7 +931,8 @@ fn get_window_pid(window_id: u32) -> Option<i32> {
 ///
 /// Uses `NSApplicationActivateAllWindows | NSApplicationActivateIgnoringOtherApps` (3)
 /// which is important for cycling through same-app windows in monocle layout.
-fn activate_app(pid: i32) -> bool {
+#[must_use]
+pub fn activate_app(pid: i32) -> bool {
     use objc::runtime::{BOOL, Class, Object, YES};
     use objc::{msg_send, sel, sel_impl};
 
@@ -796,12 +978,52 @@ fn raise_window_impl(window_id: u32) {
         return;
     };
 
+    metrics::AX_CALLS.increment();
     unsafe {
         let _result = AXUIElementPerformAction(element, cf_raise());
         CFRelease(element.cast());
     };
 }
 
+/// Minimizes a window to the Dock, or restores it.
+///
+/// # Arguments
+///
+/// * `window_id` - The window ID to minimize or restore.
+/// * `minimized` - Whether the window should be minimized.
+///
+/// # Returns
+///
+/// `true` if the operation succeeded (optimistically, since execution is async).
+#[must_use]
+pub fn set_window_minimized(window_id: u32, minimized: bool) -> bool {
+    crate::platform::thread::dispatch_on_main(move || {
+        set_window_minimized_impl(window_id, minimized);
+    });
+
+    // Return true optimistically - the actual operation runs async
+    true
+}
+
+/// Internal implementation of `set_window_minimized` (runs on main thread).
+fn set_window_minimized_impl(window_id: u32, minimized: bool) {
+    let Some(element) = resolve_window_element(window_id) else {
+        tracing::debug!("set_window_minimized: could not resolve window {window_id}");
+        return;
+    };
+
+    metrics::AX_CALLS.increment();
+    unsafe {
+        let value = CFBoolean::from(minimized);
+        let _result = AXUIElementSetAttributeValue(
+            element,
+            cf_minimized(),
+            value.as_concrete_TypeRef().cast(),
+        );
+        CFRelease(element.cast());
+    };
+}
+
 /// Sets multiple window frames in batch.
 ///
 /// Uses a single main thread dispatch for all frames, reducing IPC overhead
@@ -834,6 +1056,57 @@ pub fn set_window_frames_batch(frames: &[(u32, Rect)]) -> usize {
     count
 }
 
+/// Keeps a window above normal windows, or puts it back at the normal level.
+///
+/// The window server only lets a connection change the level of windows it
+/// owns, so this fails for other apps' windows unless a scripting addition
+/// running in the Dock (which requires SIP to be partially disabled) does it.
+///
+/// # Arguments
+///
+/// * `window_id` - The window ID to update.
+/// * `on_top` - Whether the window should stay above normal windows.
+///
+/// # Returns
+///
+/// `true` if the window server accepted the new level.
+#[must_use]
+pub fn set_window_always_on_top(window_id: u32, on_top: bool) -> bool {
+    let level = if on_top {
+        skylight::FLOATING_WINDOW_LEVEL
+    } else {
+        skylight::NORMAL_WINDOW_LEVEL
+    };
+    skylight::set_window_level(window_id, level)
+}
+
+/// Sets the opacity of a window.
+///
+/// Like the window level, the window server only lets a connection change
+/// the alpha of windows it owns; other apps' windows need a scripting addition
+/// running in the Dock, which requires SIP to be partially disabled.
+///
+/// # Arguments
+///
+/// * `window_id` - The window ID to update.
+/// * `opacity` - The opacity, from 0.0 (transparent) to 1.0 (opaque).
+///
+/// # Returns
+///
+/// `true` if the window server accepted the new opacity.
+#[must_use]
+pub fn set_window_opacity(window_id: u32, opacity: f64) -> bool {
+    let result = Transaction::new().and_then(|mut transaction| {
+        transaction.set_window_alpha(window_id, opacity)?;
+        transaction.commit_async()
+    });
+
+    if let Err(err) = &result {
+        tracing::debug!("set_window_opacity: window {window_id}: {err}");
+    }
+    result.is_ok()
+}
+
 // ============================================================================
 // App Visibility Operations
 // ============================================================================
@@ -936,12 +1209,67 @@ pub fn hide_apps(pids: &[i32]) -> usize { pids.iter().filter(|&&pid| hide_app(pi
 #[must_use]
 pub fn unhide_apps(pids: &[i32]) -> usize { pids.iter().filter(|&&pid| unhide_app(pid)).count() }
 
+// ============================================================================
+// Cursor
+// ============================================================================
+
+/// Returns the current mouse cursor location in global screen coordinates.
+///
+/// Uses the same top-left origin as window frames.
+///
+/// # Returns
+///
+/// The cursor position as `(x, y)`, or `None` if it cannot be read.
+#[must_use]
+pub fn cursor_position() -> Option<(f64, f64)> {
+    use core_graphics::event::CGEvent;
+    use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+
+    let source = CGEventSource::new(CGEventSourceStateID::CombinedSessionState).ok()?;
+    let event = CGEvent::new(source).ok()?;
+    let location = event.location();
+    Some((location.x, location.y))
+}
+
+/// Moves the mouse cursor to a point in global screen coordinates.
+///
+/// Takes the same top-left origin as window frames and [`cursor_position`].
+/// `CGWarpMouseCursorPosition` uses it too, so the point is passed through
+/// as is; only `NSScreen` frames need flipping, and screen frames in the
+/// tiling state already are.
+///
+/// # Returns
+///
+/// `true` if the cursor was moved.
+#[must_use]
+pub fn warp_cursor(x: f64, y: f64) -> bool {
+    use core_graphics::geometry::CGPoint;
+
+    #[link(name = "CoreGraphics", kind = "framework")]
+    unsafe extern "C" {
+        fn CGWarpMouseCursorPosition(new_cursor_position: CGPoint) -> i32;
+        fn CGAssociateMouseAndMouseCursorPosition(connected: u32) -> i32;
+    }
+
+    if unsafe { CGWarpMouseCursorPosition(CGPoint::new(x, y)) } != 0 {
+        return false;
+    }
+
+    // Warping suppresses mouse movement for a moment; reconnecting the mouse
+    // and the cursor lifts that right away.
+    unsafe { CGAssociateMouseAndMouseCursorPosition(1) };
+    true
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
 
 #[cfg(test)]
 mod tests {
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
     use super::*;
 
     // Note: test_resolve_nonexistent_window is disabled because it requires
@@ -958,6 +1286,35 @@ mod tests {
         let _ = pids;
     }
 
+    #[test]
+    fn test_resize_was_refused() {
+        let before = Rect::new(0.0, 0.0, 800.0, 600.0);
+        let requested = Rect::new(100.0, 0.0, 400.0, 600.0);
+
+        assert!(resize_was_refused(
+            &before,
+            &requested,
+            &Rect::new(100.0, 0.0, 800.0, 600.0)
+        ));
+        assert!(!resize_was_refused(
+            &before,
+            &requested,
+            &Rect::new(100.0, 0.0, 402.0, 600.0)
+        ));
+        // Close to the minimum size, but the window did shrink
+        assert!(!resize_was_refused(
+            &before,
+            &requested,
+            &Rect::new(100.0, 0.0, 500.0, 600.0)
+        ));
+        // Moves alone are never refusals
+        assert!(!resize_was_refused(
+            &before,
+            &Rect::new(100.0, 0.0, 800.0, 600.0),
+            &before
+        ));
+    }
+
     #[test]
     fn test_cached_cfstrings() {
         // Verify cached CFString functions don't panic
@@ -968,5 +1325,119 @@ mod tests {
         let _ = cf_main();
         let _ = cf_raise();
         let _ = cf_role();
+        let _ = cf_minimized();
+    }
+
+    /// Focus backend whose app fails the first `failures` tries with `failure`.
+    struct FlakyFocusBackend {
+        failures: u32,
+        failure: FocusOutcome,
+        tries: Cell<u32>,
+        focused: Cell<Option<u32>>,
+        waits: RefCell<Vec<Duration>>,
+        /// Called before every try, to change state in the middle of retrying.
+        on_try: Box<dyn Fn(u32)>,
+    }
+
+    impl FlakyFocusBackend {
+        fn new(failures: u32) -> Self {
+            Self {
+                failures,
+                failure: FocusOutcome::Busy,
+                tries: Cell::new(0),
+                focused: Cell::new(Some(1)),
+                waits: RefCell::new(Vec::new()),
+                on_try: Box::new(|_| {}),
+            }
+        }
+    }
+
+    impl FocusBackend for FlakyFocusBackend {
+        fn focus(&self, window_id: u32) -> FocusOutcome {
+            let tries = self.tries.get() + 1;
+            self.tries.set(tries);
+            (self.on_try)(tries);
+
+            if tries <= self.failures {
+                return self.failure;
+            }
+            self.focused.set(Some(window_id));
+            FocusOutcome::Focused
+        }
+
+        fn focused_window(&self) -> Option<u32> { self.focused.get() }
+
+        fn wait(&self, delay: Duration) { self.waits.borrow_mut().push(delay); }
+    }
+
+    #[test]
+    fn test_retry_focus_backs_off_until_the_app_responds() {
+        let backend = FlakyFocusBackend::new(2);
+        let last_request = AtomicU64::new(7);
+
+        assert!(retry_focus(&backend, &last_request, 7, 2, Some(1), 3));
+        assert_eq!(backend.tries.get(), 3);
+        assert_eq!(backend.focused.get(), Some(2));
+        assert_eq!(*backend.waits.borrow(), vec![
+            Duration::from_millis(10),
+            Duration::from_millis(20),
+            Duration::from_millis(30),
+        ]);
+    }
+
+    #[test]
+    fn test_retry_focus_gives_up_after_the_configured_retries() {
+        let backend = FlakyFocusBackend::new(u32::MAX);
+        let last_request = AtomicU64::new(1);
+
+        assert!(!retry_focus(&backend, &last_request, 1, 2, Some(1), 3));
+        assert_eq!(backend.tries.get(), 3);
+
+        let backend = FlakyFocusBackend::new(u32::MAX);
+        assert!(!retry_focus(&backend, &last_request, 1, 2, Some(1), 0));
+        assert_eq!(backend.tries.get(), 0);
+    }
+
+    #[test]
+    fn test_retry_focus_only_retries_a_busy_app() {
+        let mut backend = FlakyFocusBackend::new(u32::MAX);
+        backend.failure = FocusOutcome::Failed;
+        let last_request = AtomicU64::new(1);
+
+        assert!(!retry_focus(&backend, &last_request, 1, 2, Some(1), 3));
+        assert_eq!(backend.tries.get(), 1);
+    }
+
+    #[test]
+    fn test_retry_focus_stops_for_a_newer_focus_request() {
+        let last_request = Rc::new(AtomicU64::new(1));
+        let mut backend = FlakyFocusBackend::new(u32::MAX);
+        let newer = Rc::clone(&last_request);
+        backend.on_try = Box::new(move |_| {
+            newer.store(2, Ordering::SeqCst);
+        });
+
+        assert!(!retry_focus(&backend, &last_request, 1, 2, Some(1), 3));
+        assert_eq!(backend.tries.get(), 1);
+    }
+
+    #[test]
+    fn test_retry_focus_does_not_fight_focus_moving_elsewhere() {
+        let backend = FlakyFocusBackend::new(u32::MAX);
+        backend.focused.set(Some(3));
+        let last_request = AtomicU64::new(1);
+
+        assert!(!retry_focus(&backend, &last_request, 1, 2, Some(1), 3));
+        assert_eq!(backend.tries.get(), 0);
+    }
+
+    #[test]
+    fn test_retry_focus_accepts_a_late_focus() {
+        let backend = FlakyFocusBackend::new(u32::MAX);
+        backend.focused.set(Some(2));
+        let last_request = AtomicU64::new(1);
+
+        assert!(retry_focus(&backend, &last_request, 1, 2, Some(1), 3));
+        assert_eq!(backend.tries.get(), 0);
     }
 }
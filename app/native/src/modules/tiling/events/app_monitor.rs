@@ -32,7 +32,7 @@
 //! `NSWorkspace` notifications are delivered on the main thread. The adapter
 //! references a thread-safe `EventProcessor`.
 
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
 use std::sync::{Arc, OnceLock};
 
 use objc::declare::ClassDecl;
@@ -57,6 +57,9 @@ pub struct AppMonitorAdapter {
 
     /// Whether the adapter is initialized (observer registered).
     initialized: AtomicBool,
+
+    /// The registered lifecycle observer, removed on shutdown.
+    observer: AtomicPtr<Object>,
 }
 
 impl AppMonitorAdapter {
@@ -66,6 +69,7 @@ impl AppMonitorAdapter {
         Self {
             processor,
             initialized: AtomicBool::new(false),
+            observer: AtomicPtr::new(std::ptr::null_mut()),
         }
     }
 
@@ -128,12 +132,39 @@ impl AppMonitorAdapter {
                 name: terminate_notification
                 object: std::ptr::null::<Object>()
             ];
+
+            self.observer.store(observer, Ordering::SeqCst);
         }
 
         tracing::debug!("AppMonitorAdapter initialized");
         true
     }
 
+    /// Unregisters the lifecycle observer, so [`Self::init`] can be called again.
+    ///
+    /// # Safety
+    ///
+    /// This function must be called from the main thread.
+    pub fn shutdown(&self) {
+        if !self.initialized.swap(false, Ordering::SeqCst) {
+            return;
+        }
+
+        let observer = self.observer.swap(std::ptr::null_mut(), Ordering::SeqCst);
+        if observer.is_null() {
+            return;
+        }
+
+        unsafe {
+            let workspace: *mut Object = msg_send![class!(NSWorkspace), sharedWorkspace];
+            let notification_center: *mut Object = msg_send![workspace, notificationCenter];
+            let _: () = msg_send![notification_center, removeObserver: observer];
+            let _: () = msg_send![observer, release];
+        }
+
+        tracing::debug!("AppMonitorAdapter shut down");
+    }
+
     /// Returns whether the adapter is initialized.
     #[must_use]
     pub fn is_initialized(&self) -> bool { self.initialized.load(Ordering::SeqCst) }
@@ -215,7 +215,11 @@ impl AXObserverAdapter {
             }
         };
 
-        if !should_manage {
+        // Get app info from PID
+        let (app_id, app_name) = get_app_info_for_pid(pid);
+
+        // Apps listed in `tiling.forceTile` are managed regardless of subrole and size
+        if !should_manage && !crate::config::get_config().tiling.is_force_tiled(&app_id) {
             return;
         }
 
@@ -224,9 +228,6 @@ impl AXObserverAdapter {
         let is_fullscreen = get_window_fullscreen(ax_element).unwrap_or(false);
         let minimum_size = get_window_minimum_size(ax_element);
 
-        // Get app info from PID
-        let (app_id, app_name) = get_app_info_for_pid(pid);
-
         // Note: Tab detection is now handled in the window handler using the TabRegistry.
         // We pass tab_group_id=None and is_active_tab=true here; the handler will
         // check the TabRegistry and update accordingly.
@@ -236,6 +237,7 @@ impl AXObserverAdapter {
             app_id,
             app_name,
             title,
+            subrole,
             frame,
             is_minimized,
             is_fullscreen,
@@ -342,7 +344,8 @@ impl AXObserverAdapter {
         };
 
         let title = get_window_title(ax_element).unwrap_or_default();
-        self.processor.on_window_title_changed(window_id, title);
+        let subrole = get_window_subrole(ax_element);
+        self.processor.on_window_title_changed(window_id, title, subrole);
     }
 
     /// Handles app activation by querying the focused window and emitting a focus event.
@@ -15,6 +15,7 @@
 
 use std::sync::Mutex;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::time::{Duration, Instant};
 
 use uuid::Uuid;
 
@@ -82,6 +83,12 @@ static OPERATION_DRAG_SEQUENCE: AtomicU32 = AtomicU32::new(0);
 /// Details about the current operation.
 static CURRENT_OPERATION: Mutex<Option<DragInfo>> = Mutex::new(None);
 
+/// Minimum delay between geometry events sent to the frontend (~30fps).
+const GEOMETRY_EMIT_INTERVAL: Duration = Duration::from_millis(33);
+
+/// When the last geometry event of the current operation was emitted.
+static LAST_GEOMETRY_EMIT: Mutex<Option<Instant>> = Mutex::new(None);
+
 // ============================================================================
 // Public API
 // ============================================================================
@@ -121,6 +128,7 @@ pub fn start_operation(
         };
 
         *guard = Some(info);
+        reset_geometry_throttle();
         OPERATION_DRAG_SEQUENCE.store(drag_sequence, Ordering::Release);
         OPERATION_IN_PROGRESS.store(true, Ordering::Release);
     }
@@ -147,6 +155,58 @@ pub fn finish_operation() -> Option<DragInfo> {
 #[must_use]
 pub fn operation_drag_sequence() -> u32 { OPERATION_DRAG_SEQUENCE.load(Ordering::Acquire) }
 
+// ============================================================================
+// Geometry Events
+// ============================================================================
+
+/// Returns whether enough time passed since `last` to emit another geometry event.
+fn geometry_emit_due(last: Option<Instant>, now: Instant) -> bool {
+    last.is_none_or(|last| now.duration_since(last) >= GEOMETRY_EMIT_INTERVAL)
+}
+
+/// Clears the geometry throttle so the next operation emits its first frame right away.
+fn reset_geometry_throttle() {
+    if let Ok(mut last) = LAST_GEOMETRY_EMIT.lock() {
+        *last = None;
+    }
+}
+
+/// Claims the next geometry event slot of the current operation.
+///
+/// Returns `true` at most once per [`GEOMETRY_EMIT_INTERVAL`], and only while
+/// an operation is in progress.
+#[must_use]
+pub fn claim_geometry_emit() -> bool {
+    if !is_operation_in_progress() {
+        return false;
+    }
+
+    let Ok(mut last) = LAST_GEOMETRY_EMIT.lock() else {
+        return false;
+    };
+
+    let now = Instant::now();
+    if !geometry_emit_due(*last, now) {
+        return false;
+    }
+
+    *last = Some(now);
+    true
+}
+
+/// Emits the frames of windows changed by the current operation, throttled to ~30fps.
+///
+/// Lets the frontend render a live preview while a window is dragged.
+pub fn emit_drag_geometry<'a>(frames: impl IntoIterator<Item = (u32, &'a Rect)>) {
+    if !claim_geometry_emit() {
+        return;
+    }
+
+    for (window_id, frame) in frames {
+        crate::modules::tiling::init::emit_geometry_updated(window_id, frame);
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -200,6 +260,30 @@ mod tests {
         reset_state();
     }
 
+    #[test]
+    fn test_geometry_emit_due_throttles_to_interval() {
+        let start = Instant::now();
+
+        assert!(geometry_emit_due(None, start));
+        assert!(!geometry_emit_due(
+            Some(start),
+            start + Duration::from_millis(10)
+        ));
+        assert!(geometry_emit_due(Some(start), start + GEOMETRY_EMIT_INTERVAL));
+    }
+
+    #[test]
+    fn test_claim_geometry_emit_requires_operation() {
+        reset_state();
+        assert!(!claim_geometry_emit());
+
+        start_operation(DragOperation::Move, 1, Uuid::nil(), "test", 1, Vec::new(), 3);
+        assert!(claim_geometry_emit());
+        assert!(!claim_geometry_emit());
+
+        reset_state();
+    }
+
     #[test]
     fn test_cancel_operation() {
         reset_state();
@@ -53,6 +53,18 @@ unsafe extern "C" {
     fn CGEventTapEnable(tap: CFMachPortRef, enable: bool);
 }
 
+#[link(name = "CoreFoundation", kind = "framework")]
+unsafe extern "C" {
+    fn CFRunLoopStop(rl: *const c_void);
+}
+
+/// Wrapper for the event tap thread's `CFRunLoopRef` that's `Send`.
+struct RunLoopRef(*const c_void);
+
+// SAFETY: `CFRunLoopStop` may be called from any thread, and the reference is
+// only used for that.
+unsafe impl Send for RunLoopRef {}
+
 // Constants for event tap configuration
 const K_CG_HID_EVENT_TAP: u32 = 0;
 const K_CG_HEAD_INSERT_EVENT_TAP: u32 = 0;
@@ -82,6 +94,9 @@ static MOUSE_UP_CALLBACK: Mutex<Option<fn()>> = Mutex::new(None);
 /// Counter for drag operations (incremented on each mouse down).
 static DRAG_SEQUENCE: AtomicU32 = AtomicU32::new(0);
 
+/// Run loop of the event tap thread, stopped on shutdown.
+static RUN_LOOP: Mutex<Option<RunLoopRef>> = Mutex::new(None);
+
 // ============================================================================
 // Public API
 // ============================================================================
@@ -145,6 +160,24 @@ pub fn init() -> bool {
 #[must_use]
 pub fn is_initialized() -> bool { INITIALIZED.load(Ordering::SeqCst) }
 
+/// Stops the mouse event monitor.
+///
+/// The event tap thread exits and [`init`] can be called again afterwards.
+pub fn shutdown() {
+    if !INITIALIZED.swap(false, Ordering::SeqCst) {
+        return;
+    }
+
+    MOUSE_DOWN.store(false, Ordering::SeqCst);
+    if let Ok(mut run_loop) = RUN_LOOP.lock()
+        && let Some(run_loop) = run_loop.take()
+    {
+        unsafe { CFRunLoopStop(run_loop.0) };
+    }
+
+    tracing::debug!("tiling: mouse monitor shut down");
+}
+
 // ============================================================================
 // Event Tap Implementation
 // ============================================================================
@@ -192,10 +225,16 @@ fn start_mouse_event_tap() {
         // Enable the event tap
         CGEventTapEnable(tap, true);
 
+        if let Ok(mut stored) = RUN_LOOP.lock() {
+            *stored = Some(RunLoopRef(run_loop.as_concrete_TypeRef().cast()));
+        }
+
         tracing::debug!("tiling: mouse monitor initialized");
 
-        // Run the run loop (this blocks)
+        // Run the run loop (this blocks until shutdown)
         CFRunLoop::run_current();
+
+        CGEventTapEnable(tap, false);
     }
 }
 
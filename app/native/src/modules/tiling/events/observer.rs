@@ -117,6 +117,7 @@ unsafe extern "C" {
 unsafe extern "C" {
     fn CFRelease(cf: *const c_void);
     fn CFRunLoopAddSource(rl: *const c_void, source: *const c_void, mode: *const c_void);
+    fn CFRunLoopRemoveSource(rl: *const c_void, source: *const c_void, mode: *const c_void);
 }
 
 // ============================================================================
@@ -196,6 +197,27 @@ pub fn init() -> bool {
     true
 }
 
+/// Removes all observers and marks the observer system as uninitialized.
+///
+/// [`init`] can be called again afterwards.
+///
+/// # Safety
+///
+/// This function must be called from the main thread.
+pub fn shutdown() {
+    if !INITIALIZED.swap(false, Ordering::SeqCst) {
+        return;
+    }
+
+    let observers = OBSERVER_STATE.lock().take().map(|state| state.observers).unwrap_or_default();
+    let count = observers.len();
+    for observer in observers.into_values() {
+        release_observer(observer);
+    }
+
+    tracing::debug!("tiling: observers shut down ({count} apps)");
+}
+
 /// Adds an observer for a new application by PID.
 ///
 /// Call this when a new application is launched.
@@ -292,12 +314,25 @@ pub fn remove_observer_for_pid(pid: i32) {
     if let Some(state) = state_guard.as_mut()
         && let Some(observer) = state.observers.remove(&pid)
     {
-        // Release the observer
-        unsafe { CFRelease(observer.0.cast()) };
+        release_observer(observer);
         tracing::trace!("Removed observer for pid {pid}");
     }
 }
 
+/// Detaches an observer from the main run loop and releases it.
+fn release_observer(observer: ObserverRef) {
+    let source = unsafe { AXObserverGetRunLoopSource(observer.0) };
+    if !source.is_null() {
+        let run_loop = CFRunLoop::get_main();
+        let mode = unsafe { core_foundation::runloop::kCFRunLoopDefaultMode };
+        unsafe {
+            CFRunLoopRemoveSource(run_loop.as_concrete_TypeRef().cast(), source, mode.cast());
+        }
+    }
+
+    unsafe { CFRelease(observer.0.cast()) };
+}
+
 /// Checks if we should observe an app.
 #[must_use]
 pub fn should_observe_app(bundle_id: &str, name: &str) -> bool {
@@ -307,6 +307,12 @@ impl EventProcessor {
         tracing::debug!("EventProcessor stopped");
     }
 
+    /// Forget all tracked windows, for a restart of window tracking.
+    pub fn clear_windows(&self) {
+        self.window_screen_map.clear();
+        self.pid_windows.lock().clear();
+    }
+
     /// Check if the processor is running.
     #[must_use]
     pub fn is_running(&self) -> bool { self.running.load(Ordering::SeqCst) }
@@ -412,9 +418,16 @@ impl EventProcessor {
     }
 
     /// Dispatch a window title changed event immediately.
-    pub fn on_window_title_changed(&self, window_id: u32, title: String) {
-        tracing::trace!("Window title changed: {window_id} = '{title}'");
-        let _ = self.actor_handle.send(StateMessage::WindowTitleChanged { window_id, title });
+    ///
+    /// The subrole is re-read along with the title, in case it changed.
+    pub fn on_window_title_changed(&self, window_id: u32, title: String, subrole: Option<String>) {
+        tracing::trace!(
+            "Window title changed: {window_id} = '{}'",
+            crate::logging::redact_title(&title)
+        );
+        let _ =
+            self.actor_handle
+                .send(StateMessage::WindowTitleChanged { window_id, title, subrole });
     }
 
     /// Dispatch a window fullscreen changed event immediately.
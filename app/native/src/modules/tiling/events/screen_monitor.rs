@@ -77,6 +77,10 @@ unsafe extern "C" {
         callback: unsafe extern "C" fn(u32, u32, *mut c_void),
         user_info: *mut c_void,
     ) -> i32;
+    fn CGDisplayRemoveReconfigurationCallback(
+        callback: unsafe extern "C" fn(u32, u32, *mut c_void),
+        user_info: *mut c_void,
+    ) -> i32;
 }
 
 // ============================================================================
@@ -139,6 +143,22 @@ impl ScreenMonitorAdapter {
         true
     }
 
+    /// Unregisters from CoreGraphics, so [`Self::init`] can be called again.
+    pub fn shutdown(&self) {
+        if !self.initialized.swap(false, Ordering::SeqCst) {
+            return;
+        }
+
+        unsafe {
+            CGDisplayRemoveReconfigurationCallback(
+                display_reconfiguration_callback,
+                std::ptr::null_mut(),
+            );
+        }
+
+        tracing::debug!("ScreenMonitorAdapter shut down");
+    }
+
     /// Returns whether the adapter is initialized.
     #[must_use]
     pub fn is_initialized(&self) -> bool { self.initialized.load(Ordering::SeqCst) }
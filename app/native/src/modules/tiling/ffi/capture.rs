@@ -0,0 +1,246 @@
+//! Window capture via CoreGraphics.
+//!
+#![allow(clippy::doc_markdown)] // Allow CGWindowID, CoreGraphics, etc. without backticks
+//!
+//! Captures a region of the screen with `CGWindowListCreateImage`, either with
+//! everything on screen or limited to a list of windows, and converts the
+//! result to an RGBA image. Capturing needs the screen recording permission.
+
+use std::ffi::c_void;
+
+use image::RgbaImage;
+
+use super::skylight::{CGPoint, CGRect, CGSize};
+use crate::modules::tiling::state::Rect;
+
+// ============================================================================
+// FFI Declarations
+// ============================================================================
+
+type CGImageRef = *const c_void;
+type CFArrayRef = *const c_void;
+type CFDataRef = *const c_void;
+
+/// Include windows that are currently visible on screen.
+const K_CG_WINDOW_LIST_OPTION_ON_SCREEN_ONLY: u32 = 1 << 0;
+/// Capture the window contents without their shadow.
+const K_CG_WINDOW_IMAGE_BOUNDS_IGNORE_FRAMING: u32 = 1 << 0;
+/// Capture at the resolution of the screen the windows are on.
+const K_CG_WINDOW_IMAGE_BEST_RESOLUTION: u32 = 1 << 3;
+/// `kCGNullWindowID`.
+const K_CG_NULL_WINDOW_ID: u32 = 0;
+
+#[link(name = "CoreGraphics", kind = "framework")]
+unsafe extern "C" {
+    fn CGPreflightScreenCaptureAccess() -> bool;
+    fn CGRequestScreenCaptureAccess() -> bool;
+    fn CGWindowListCreateImage(
+        screen_bounds: CGRect,
+        list_option: u32,
+        window_id: u32,
+        image_option: u32,
+    ) -> CGImageRef;
+    fn CGWindowListCreateImageFromArray(
+        screen_bounds: CGRect,
+        window_array: CFArrayRef,
+        image_option: u32,
+    ) -> CGImageRef;
+    fn CGImageGetWidth(image: CGImageRef) -> usize;
+    fn CGImageGetHeight(image: CGImageRef) -> usize;
+    fn CGImageGetBitsPerPixel(image: CGImageRef) -> usize;
+    fn CGImageGetBytesPerRow(image: CGImageRef) -> usize;
+    fn CGImageGetDataProvider(image: CGImageRef) -> *const c_void;
+    fn CGDataProviderCopyData(provider: *const c_void) -> CFDataRef;
+}
+
+#[link(name = "CoreFoundation", kind = "framework")]
+unsafe extern "C" {
+    fn CFArrayCreate(
+        allocator: *const c_void,
+        values: *const *const c_void,
+        count: isize,
+        callbacks: *const c_void,
+    ) -> CFArrayRef;
+    fn CFDataGetBytePtr(data: CFDataRef) -> *const u8;
+    fn CFDataGetLength(data: CFDataRef) -> isize;
+    fn CFRelease(cf: *const c_void);
+}
+
+// ============================================================================
+// Permission
+// ============================================================================
+
+/// Returns whether the app may capture the screen.
+#[must_use]
+pub fn has_screen_capture_access() -> bool { unsafe { CGPreflightScreenCaptureAccess() } }
+
+/// Asks the user for the screen recording permission.
+///
+/// macOS shows its prompt only the first time; later calls return `false`
+/// right away until the permission is granted in System Settings.
+pub fn request_screen_capture_access() -> bool { unsafe { CGRequestScreenCaptureAccess() } }
+
+// ============================================================================
+// Capture
+// ============================================================================
+
+/// Captures a region of the screen.
+///
+/// `bounds` is in global screen coordinates with a top-left origin. With
+/// `window_ids`, only those windows are drawn and everything else in the
+/// region is left transparent; otherwise the region is captured as shown on
+/// screen. Returns `None` if the capture fails, e.g. without the screen
+/// recording permission.
+#[must_use]
+pub fn capture_region(bounds: Rect, window_ids: Option<&[u32]>) -> Option<RgbaImage> {
+    let screen_bounds = CGRect {
+        origin: CGPoint { x: bounds.x, y: bounds.y },
+        size: CGSize {
+            width: bounds.width,
+            height: bounds.height,
+        },
+    };
+    let image_option = K_CG_WINDOW_IMAGE_BOUNDS_IGNORE_FRAMING | K_CG_WINDOW_IMAGE_BEST_RESOLUTION;
+
+    let image = match window_ids {
+        Some(ids) => {
+            // The array holds the CGWindowIDs themselves, not CFNumbers.
+            let values: Vec<*const c_void> =
+                ids.iter().map(|&id| id as usize as *const c_void).collect();
+            let count = isize::try_from(values.len()).ok()?;
+            let array = unsafe {
+                CFArrayCreate(std::ptr::null(), values.as_ptr(), count, std::ptr::null())
+            };
+            if array.is_null() {
+                return None;
+            }
+
+            let image =
+                unsafe { CGWindowListCreateImageFromArray(screen_bounds, array, image_option) };
+            unsafe { CFRelease(array) };
+            image
+        }
+        None => unsafe {
+            CGWindowListCreateImage(
+                screen_bounds,
+                K_CG_WINDOW_LIST_OPTION_ON_SCREEN_ONLY,
+                K_CG_NULL_WINDOW_ID,
+                image_option,
+            )
+        },
+    };
+
+    if image.is_null() {
+        return None;
+    }
+
+    let result = image_to_rgba(image);
+    unsafe { CFRelease(image) };
+    result
+}
+
+/// Copies the pixels of a 32-bit CGImage into an RGBA image.
+fn image_to_rgba(image: CGImageRef) -> Option<RgbaImage> {
+    let (width, height, bits_per_pixel, bytes_per_row) = unsafe {
+        (
+            CGImageGetWidth(image),
+            CGImageGetHeight(image),
+            CGImageGetBitsPerPixel(image),
+            CGImageGetBytesPerRow(image),
+        )
+    };
+    if bits_per_pixel != 32 || width == 0 || height == 0 {
+        return None;
+    }
+
+    let provider = unsafe { CGImageGetDataProvider(image) };
+    if provider.is_null() {
+        return None;
+    }
+    let data = unsafe { CGDataProviderCopyData(provider) };
+    if data.is_null() {
+        return None;
+    }
+
+    let length = usize::try_from(unsafe { CFDataGetLength(data) }).unwrap_or(0);
+    let bytes = unsafe { CFDataGetBytePtr(data) };
+    let pixels = if bytes.is_null() || length < bytes_per_row * height {
+        None
+    } else {
+        let slice = unsafe { std::slice::from_raw_parts(bytes, length) };
+        Some(bgra_to_rgba(slice, width, height, bytes_per_row))
+    };
+    unsafe { CFRelease(data) };
+
+    RgbaImage::from_raw(u32::try_from(width).ok()?, u32::try_from(height).ok()?, pixels?)
+}
+
+/// Converts premultiplied BGRA rows, as window captures are stored, to
+/// straight RGBA pixels without row padding.
+fn bgra_to_rgba(data: &[u8], width: usize, height: usize, bytes_per_row: usize) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(width * height * 4);
+
+    for row in data.chunks(bytes_per_row).take(height) {
+        for bgra in row[..width * 4].chunks_exact(4) {
+            let alpha = bgra[3];
+            let unpremultiply = |channel: u8| {
+                if alpha == 0 || alpha == u8::MAX {
+                    channel
+                } else {
+                    let value = u16::from(channel) * 255 / u16::from(alpha);
+                    u8::try_from(value).unwrap_or(u8::MAX)
+                }
+            };
+            pixels.extend_from_slice(&[
+                unpremultiply(bgra[2]),
+                unpremultiply(bgra[1]),
+                unpremultiply(bgra[0]),
+                alpha,
+            ]);
+        }
+    }
+
+    pixels
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bgra_to_rgba_swaps_channels_and_drops_row_padding() {
+        // Two pixels per row plus 4 bytes of padding, two rows.
+        let data = [
+            1, 2, 3, 255, 4, 5, 6, 255, 0, 0, 0, 0, //
+            7, 8, 9, 255, 10, 11, 12, 255, 0, 0, 0, 0,
+        ];
+
+        let pixels = bgra_to_rgba(&data, 2, 2, 12);
+
+        assert_eq!(pixels, vec![
+            3, 2, 1, 255, 6, 5, 4, 255, //
+            9, 8, 7, 255, 12, 11, 10, 255,
+        ]);
+    }
+
+    #[test]
+    fn bgra_to_rgba_unpremultiplies_translucent_pixels() {
+        let pixels = bgra_to_rgba(&[64, 32, 0, 128], 1, 1, 4);
+        assert_eq!(pixels, vec![0, 63, 127, 128]);
+
+        let pixels = bgra_to_rgba(&[0, 0, 0, 0], 1, 1, 4);
+        assert_eq!(pixels, vec![0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn capture_constants_are_correct() {
+        assert_eq!(K_CG_WINDOW_LIST_OPTION_ON_SCREEN_ONLY, 1);
+        assert_eq!(K_CG_WINDOW_IMAGE_BOUNDS_IGNORE_FRAMING, 1);
+        assert_eq!(K_CG_WINDOW_IMAGE_BEST_RESOLUTION, 8);
+        assert_eq!(K_CG_NULL_WINDOW_ID, 0);
+    }
+}
@@ -0,0 +1,44 @@
+//! Display identity via ColorSync.
+//!
+#![allow(clippy::doc_markdown)] // Allow ColorSync, CoreGraphics, etc. without backticks
+//!
+//! CoreGraphics display IDs can change when displays are reconnected, while
+//! their UUID stays the same, so the UUID is what config and saved state refer
+//! to displays by.
+
+use core_foundation::base::TCFType;
+use core_foundation::string::{CFString, CFStringRef};
+use core_foundation_sys::base::{CFRelease, kCFAllocatorDefault};
+use core_foundation_sys::uuid::{CFUUIDCreateString, CFUUIDRef};
+
+// ============================================================================
+// FFI Declarations
+// ============================================================================
+
+#[link(name = "ColorSync", kind = "framework")]
+unsafe extern "C" {
+    fn CGDisplayCreateUUIDFromDisplayID(display: u32) -> CFUUIDRef;
+}
+
+// ============================================================================
+// Display UUID
+// ============================================================================
+
+/// Returns the persistent UUID of a display, or `None` if it has none.
+#[must_use]
+pub fn display_uuid(display_id: u32) -> Option<String> {
+    unsafe {
+        let uuid = CGDisplayCreateUUIDFromDisplayID(display_id);
+        if uuid.is_null() {
+            return None;
+        }
+
+        let string: CFStringRef = CFUUIDCreateString(kCFAllocatorDefault, uuid);
+        CFRelease(uuid.cast());
+        if string.is_null() {
+            return None;
+        }
+
+        Some(CFString::wrap_under_create_rule(string).to_string())
+    }
+}
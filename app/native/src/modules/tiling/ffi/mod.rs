@@ -7,6 +7,8 @@
 //! # Modules
 //!
 //! - [`accessibility`] - Safe wrappers for `AXUIElement` and related APIs
+//! - [`capture`] - Window capture for screenshots
+//! - [`display`] - Persistent display UUIDs
 //! - [`skylight`] - Safe wrappers for `SkyLight` private framework (screen update batching)
 //! - [`transaction`] - RAII wrapper for `SkyLight` transactions
 //! - [`window_query`] - Fast window enumeration using `SkyLight` APIs
@@ -17,6 +19,8 @@
 //! - [`ffi_try_opt!`] - Returns `None` if a pointer is null
 
 pub mod accessibility;
+pub mod capture;
+pub mod display;
 pub mod skylight;
 pub mod transaction;
 pub mod window_query;
@@ -24,7 +28,7 @@ pub mod window_query;
 pub use accessibility::AXElement;
 pub use skylight::{UpdateGuard, get_connection_id, get_window_bounds_fast, get_window_id_from_ax};
 pub use transaction::Transaction;
-pub use window_query::{WindowInfo, WindowQuery};
+pub use window_query::{WindowInfo, WindowQuery, window_server_ids};
 
 /// Returns early with an error if the given pointer is null.
 ///
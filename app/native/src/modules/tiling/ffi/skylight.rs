@@ -103,6 +103,9 @@ unsafe extern "C" {
     /// - `SLSMoveWindow`: ~0.1-0.3ms
     /// - AX position: ~2-5ms
     fn SLSMoveWindow(cid: u32, wid: u32, point: *const CGPoint) -> i32;
+
+    /// Sets the window level (stacking band) of a window.
+    fn SLSSetWindowLevel(cid: u32, wid: u32, level: i32) -> i32;
 }
 
 // Private Accessibility API to get the window server ID from an AXUIElement.
@@ -214,6 +217,33 @@ pub fn move_window_fast(window_id: u32, x: f64, y: f64) -> bool {
     result == 0
 }
 
+// ============================================================================
+// Window Level
+// ============================================================================
+
+/// `kCGNormalWindowLevel`: the level of regular app windows.
+pub const NORMAL_WINDOW_LEVEL: i32 = 0;
+
+/// `kCGFloatingWindowLevel`: above normal windows, below the menu bar.
+pub const FLOATING_WINDOW_LEVEL: i32 = 3;
+
+/// Sets the level of a window using the SkyLight API.
+///
+/// Only windows owned by this connection can be changed without a scripting
+/// addition in the Dock, which requires SIP to be partially disabled.
+///
+/// Returns `true` if the window server accepted the new level.
+#[must_use]
+pub fn set_window_level(window_id: u32, level: i32) -> bool {
+    let cid = get_connection_id();
+    if cid == 0 {
+        return false;
+    }
+
+    let result = unsafe { SLSSetWindowLevel(cid, window_id, level) };
+    result == 0
+}
+
 // ============================================================================
 // AXUIElement to CGWindowID Mapping
 // ============================================================================
@@ -342,6 +372,12 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn set_window_level_with_invalid_id_returns_false() {
+        assert!(!set_window_level(0, FLOATING_WINDOW_LEVEL));
+        assert!(!set_window_level(u32::MAX, NORMAL_WINDOW_LEVEL));
+    }
+
     #[test]
     fn move_window_fast_with_invalid_id_returns_false() {
         // Invalid window ID should fail gracefully
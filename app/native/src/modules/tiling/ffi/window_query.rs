@@ -12,8 +12,16 @@
 //! - CGWindowList: ~5-15ms for 50+ windows
 //! - Individual AX queries: ~100-400ms for 50+ windows
 
+use std::collections::HashSet;
 use std::ffi::c_void;
 
+use core_foundation::array::CFArray;
+use core_foundation::base::{CFType, TCFType};
+use core_foundation::boolean::CFBoolean;
+use core_foundation::dictionary::CFDictionary;
+use core_foundation::number::CFNumber;
+use core_foundation::string::CFString;
+
 use super::skylight::{CGRect, get_connection_id};
 use crate::modules::tiling::state::Rect;
 
@@ -24,10 +32,14 @@ use crate::modules::tiling::state::Rect;
 type CFTypeRef = *mut c_void;
 type CFArrayRef = *const c_void;
 
+/// Include every window, on any Space.
+const K_CG_WINDOW_LIST_OPTION_ALL: u32 = 0;
 /// Include windows that are currently visible on screen.
 const K_CG_WINDOW_LIST_OPTION_ON_SCREEN_ONLY: u32 = 1 << 0;
 /// Exclude windows with a window layer of 0 (desktop).
 const K_CG_WINDOW_LIST_EXCLUDE_DESKTOP_ELEMENTS: u32 = 1 << 4;
+/// Space mask covering current, other and fullscreen Spaces.
+const K_SLS_SPACE_MASK_ALL: u32 = 0x7;
 
 #[link(name = "SkyLight", kind = "framework")]
 unsafe extern "C" {
@@ -44,6 +56,12 @@ unsafe extern "C" {
     fn SLSWindowIteratorGetPID(iterator: CFTypeRef) -> i32;
     fn SLSWindowIteratorGetLevel(iterator: CFTypeRef) -> i32;
     fn SLSWindowIteratorGetTags(iterator: CFTypeRef) -> u64;
+    fn SLSCopySpacesForWindows(cid: u32, mask: u32, window_ids: CFArrayRef) -> CFArrayRef;
+}
+
+#[link(name = "CoreGraphics", kind = "framework")]
+unsafe extern "C" {
+    fn CGWindowListCopyWindowInfo(option: u32, relative_to_window: u32) -> CFArrayRef;
 }
 
 #[link(name = "CoreFoundation", kind = "framework")]
@@ -222,6 +240,66 @@ impl Iterator for WindowIterator<'_> {
     }
 }
 
+// ============================================================================
+// Window Server IDs
+// ============================================================================
+
+/// Returns the IDs of the windows that are on screen or on a Space.
+///
+/// Unlike the AX API, this includes windows on other Spaces and in fullscreen,
+/// so a tracked window missing from it is really gone. The full window list
+/// also keeps windows an app ordered out without releasing them; those are on
+/// no Space and are left out. Only normal-layer windows are considered, as no
+/// other window is ever tracked. Returns `None` if the window list could not
+/// be read.
+#[must_use]
+pub fn window_server_ids() -> Option<HashSet<u32>> {
+    let list = unsafe { CGWindowListCopyWindowInfo(K_CG_WINDOW_LIST_OPTION_ALL, 0) };
+    if list.is_null() {
+        return None;
+    }
+
+    let windows =
+        unsafe { CFArray::<CFDictionary<CFString, CFType>>::wrap_under_create_rule(list.cast()) };
+    let number_key = CFString::from_static_string("kCGWindowNumber");
+    let layer_key = CFString::from_static_string("kCGWindowLayer");
+    let onscreen_key = CFString::from_static_string("kCGWindowIsOnscreen");
+    let cid = get_connection_id();
+
+    Some(
+        windows
+            .iter()
+            .filter(|window| {
+                window.find(&layer_key).and_then(|layer| layer.downcast::<CFNumber>()?.to_i64())
+                    == Some(0)
+            })
+            .filter_map(|window| {
+                let id = window.find(&number_key)?.downcast::<CFNumber>()?.to_i64()?;
+                let id = u32::try_from(id).ok()?;
+                let on_screen = window
+                    .find(&onscreen_key)
+                    .and_then(|value| value.downcast::<CFBoolean>())
+                    .is_some_and(bool::from);
+                (on_screen || is_on_any_space(cid, id)).then_some(id)
+            })
+            .collect(),
+    )
+}
+
+/// Returns whether the window belongs to any Space.
+fn is_on_any_space(cid: u32, window_id: u32) -> bool {
+    let ids = CFArray::from_CFTypes(&[CFNumber::from(i64::from(window_id))]);
+    let spaces = unsafe {
+        SLSCopySpacesForWindows(cid, K_SLS_SPACE_MASK_ALL, ids.as_concrete_TypeRef().cast())
+    };
+    if spaces.is_null() {
+        return false;
+    }
+
+    let spaces = unsafe { CFArray::<CFType>::wrap_under_create_rule(spaces.cast()) };
+    !spaces.is_empty()
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -274,7 +352,9 @@ mod tests {
 
     #[test]
     fn query_constants_are_correct() {
+        assert_eq!(K_CG_WINDOW_LIST_OPTION_ALL, 0);
         assert_eq!(K_CG_WINDOW_LIST_OPTION_ON_SCREEN_ONLY, 1);
         assert_eq!(K_CG_WINDOW_LIST_EXCLUDE_DESKTOP_ELEMENTS, 16);
+        assert_eq!(K_SLS_SPACE_MASK_ALL, 7);
     }
 }
@@ -151,6 +151,12 @@ pub fn init(app_handle: tauri::AppHandle) -> bool {
             let _ = INITIALIZED.set(true);
             tracing::info!("tiling: initialized successfully");
 
+            // Apply later tiling config changes in place
+            super::reload::register();
+
+            // Resync tracked windows periodically, when configured
+            super::reconcile::start();
+
             // Emit initialized event
             if let Err(e) = app_handle.emit(
                 events::tiling::INITIALIZED,
@@ -235,6 +241,22 @@ fn init_internal() -> Result<(), String> {
 
     tauri::async_runtime::spawn(subscriber.run());
 
+    start_event_sources(&processor);
+
+    // Initialize the border system (connects to JankyBorders if available)
+    if !borders::init() {
+        tracing::warn!("tiling: borders initialization failed (JankyBorders may not be installed)");
+    }
+
+    // Initialize screens and workspaces
+    initialize_state(&handle);
+
+    tracing::info!("tiling: all components started");
+    Ok(())
+}
+
+/// Starts the adapters that feed macOS events into the processor.
+fn start_event_sources(processor: &Arc<EventProcessor>) {
     // Create and initialize the app monitor adapter
     let app_monitor = Arc::new(AppMonitorAdapter::new(processor.clone()));
     if !app_monitor.init() {
@@ -252,7 +274,7 @@ fn init_internal() -> Result<(), String> {
     super::events::screen_monitor::install_adapter(screen_monitor);
 
     // Create and install the AX observer adapter
-    let ax_adapter = Arc::new(super::events::AXObserverAdapter::new(processor));
+    let ax_adapter = Arc::new(super::events::AXObserverAdapter::new(processor.clone()));
     super::events::ax_observer::install_adapter(ax_adapter.clone());
     ax_adapter.activate();
 
@@ -271,17 +293,61 @@ fn init_internal() -> Result<(), String> {
     } else {
         tracing::warn!("tiling: mouse monitor initialization failed");
     }
+}
 
-    // Initialize the border system (connects to JankyBorders if available)
-    if !borders::init() {
-        tracing::warn!("tiling: borders initialization failed (JankyBorders may not be installed)");
+/// Stops the adapters started by [`start_event_sources`].
+fn stop_event_sources() {
+    super::events::mouse_monitor::clear_mouse_up_callback();
+    super::events::mouse_monitor::shutdown();
+
+    if let Some(ax_adapter) = super::events::ax_observer::get_installed_adapter() {
+        ax_adapter.deactivate();
     }
+    super::events::ax_observer::uninstall_adapter();
+    super::events::observer::shutdown();
 
-    // Initialize screens and workspaces
-    initialize_state(&handle);
+    if let Some(screen_monitor) = super::events::screen_monitor::get_installed_adapter() {
+        screen_monitor.shutdown();
+    }
+    super::events::screen_monitor::uninstall_adapter();
 
-    tracing::info!("tiling: all components started");
-    Ok(())
+    if let Some(app_monitor) = super::events::app_monitor::get_installed_adapter() {
+        app_monitor.shutdown();
+    }
+    super::events::app_monitor::uninstall_adapter();
+}
+
+/// Restarts tiling without restarting the app.
+///
+/// Stops the event sources, drops all tracked state and runs the startup
+/// sequence again: screens are detected, existing windows are tracked and the
+/// initial layouts are applied. The state actor, event processor and effect
+/// subscriber keep running, so the bar and other modules are unaffected.
+///
+/// Must be called from the main thread. Returns `false` if tiling is not running.
+pub fn restart() -> bool {
+    if !is_initialized() {
+        return false;
+    }
+
+    let (Some(handle), Some(processor)) = (get_handle(), get_processor()) else {
+        return false;
+    };
+
+    tracing::info!("tiling: restarting");
+    stop_event_sources();
+
+    if let Err(e) = handle.reset() {
+        tracing::error!("tiling: failed to reset state: {e}");
+        return false;
+    }
+    processor.clear_windows();
+
+    start_event_sources(&processor);
+    initialize_state(handle);
+
+    tracing::info!("tiling: restarted");
+    true
 }
 
 /// Initializes the tiling state (screens, workspaces).
@@ -319,22 +385,14 @@ fn initialize_state(handle: &StateActorHandle) {
 // Window Tracking
 // ============================================================================
 
-/// Tracks all existing windows at startup.
+/// Enumerates the windows that tiling should track.
 ///
-/// Enumerates all windows using the AX-first approach and sends
-/// a batch `BatchWindowsCreated` message to the actor.
-/// Also sends a `WindowFocused` message for the currently focused window,
-/// and an `InitComplete` message to trigger initial layouts.
-fn track_existing_windows(handle: &StateActorHandle) {
+/// Uses the AX-first approach, skipping system apps and registering tabs, so
+/// each returned window is a real window that is not a tab.
+pub(super) fn collect_trackable_windows() -> Vec<super::actor::WindowCreatedInfo> {
     use super::actor::WindowCreatedInfo;
     use super::rules::should_tile_window;
-    use super::window::{get_all_windows_including_hidden, get_focused_window_id};
-
-    tracing::debug!("tiling: tracking existing windows...");
-
-    // Get the currently focused window ID first (before enumeration)
-    let focused_window_id = get_focused_window_id();
-    tracing::trace!("tiling: system focused window id = {focused_window_id:?}");
+    use super::window::get_all_windows_including_hidden;
 
     // Enumerate all windows including hidden ones
     let windows = get_all_windows_including_hidden();
@@ -346,7 +404,7 @@ fn track_existing_windows(handle: &StateActorHandle) {
             w.id,
             w.pid,
             w.app_name,
-            w.title,
+            crate::logging::redact_title(&w.title),
             w.is_minimized,
             w.is_hidden
         );
@@ -374,7 +432,7 @@ fn track_existing_windows(handle: &StateActorHandle) {
         if !should_tile_window(&window.bundle_id, &window.app_name) {
             tracing::trace!(
                 "tiling: skipping system window '{}' from '{}'",
-                window.title,
+                crate::logging::redact_title(&window.title),
                 window.app_name
             );
             continue;
@@ -392,6 +450,7 @@ fn track_existing_windows(handle: &StateActorHandle) {
             app_id: window.bundle_id.clone(),
             app_name: window.app_name.clone(),
             title: window.title.clone(),
+            subrole: window.subrole.clone(),
             frame: window.frame,
             is_minimized: window.is_minimized,
             is_fullscreen: window.is_fullscreen,
@@ -403,15 +462,42 @@ fn track_existing_windows(handle: &StateActorHandle) {
         window_infos.push(info);
     }
 
-    let tracked_count = window_infos.len();
+    window_infos
+}
 
-    // Also track these windows in the event processor for destroy detection
-    // This is necessary because BatchWindowsCreated bypasses the processor
+/// Registers windows with the event processor for destroy detection.
+///
+/// Windows tracked through a batch message bypass the processor, so it would
+/// otherwise miss their destruction.
+pub(super) fn track_for_destroy_detection(window_infos: &[super::actor::WindowCreatedInfo]) {
     if let Some(processor) = get_processor() {
         let window_pids: Vec<(u32, i32)> =
             window_infos.iter().map(|w| (w.window_id, w.pid)).collect();
         processor.track_windows_for_destroy_detection(&window_pids);
     }
+}
+
+/// Tracks all existing windows at startup.
+///
+/// Enumerates all windows using the AX-first approach and sends
+/// a batch `BatchWindowsCreated` message to the actor.
+/// Also sends a `WindowFocused` message for the currently focused window,
+/// and an `InitComplete` message to trigger initial layouts.
+fn track_existing_windows(handle: &StateActorHandle) {
+    use super::window::get_focused_window_id;
+
+    tracing::debug!("tiling: tracking existing windows...");
+
+    // Get the currently focused window ID first (before enumeration)
+    let focused_window_id = get_focused_window_id();
+    tracing::trace!("tiling: system focused window id = {focused_window_id:?}");
+
+    let window_infos = collect_trackable_windows();
+
+    let tracked_count = window_infos.len();
+
+    // Also track these windows in the event processor for destroy detection
+    track_for_destroy_detection(&window_infos);
 
     // Send batch message (no individual layout notifications)
     if !window_infos.is_empty()
@@ -461,7 +547,12 @@ pub fn get_app_handle() -> Option<tauri::AppHandle> {
 // ============================================================================
 
 /// Emits a workspace changed event to the frontend.
+///
+/// Also applies the Do Not Disturb setting of the workspace, since every
+/// change of the focused workspace goes through here.
 pub fn emit_workspace_changed(workspace: &str, screen: &str, previous_workspace: Option<&str>) {
+    super::dnd::on_workspace_changed(workspace);
+
     if let Some(handle) = get_app_handle() {
         let _ = handle.emit(
             events::tiling::WORKSPACE_CHANGED,
@@ -540,6 +631,26 @@ pub fn emit_window_title_changed(window_id: u32, title: &str) {
     }
 }
 
+/// Emits a window geometry updated event to the frontend.
+///
+/// Only called while a drag or resize is in progress, throttled by `drag_state`.
+pub fn emit_geometry_updated(window_id: u32, frame: &super::state::Rect) {
+    if let Some(handle) = get_app_handle() {
+        let _ = handle.emit(
+            events::tiling::GEOMETRY_UPDATED,
+            serde_json::json!({
+                "windowId": window_id,
+                "frame": {
+                    "x": frame.x,
+                    "y": frame.y,
+                    "width": frame.width,
+                    "height": frame.height,
+                },
+            }),
+        );
+    }
+}
+
 /// Emits a workspace windows changed event to the frontend.
 ///
 /// This is called when windows in a workspace change (added, removed, minimized, etc.).
@@ -555,6 +666,30 @@ pub fn emit_workspace_windows_changed(workspace: &str, window_ids: &[u32]) {
     }
 }
 
+/// Emits a workspaces changed event with the window count of every workspace.
+///
+/// Lets the bar tell empty workspaces from populated ones without polling.
+pub fn emit_workspaces_changed(state: &super::state::TilingState) {
+    if let Some(handle) = get_app_handle() {
+        let workspaces: Vec<_> = state
+            .workspaces
+            .iter()
+            .map(|ws| {
+                let screen = state.get_screen(ws.screen_id).map(|s| s.name).unwrap_or_default();
+                serde_json::json!({
+                    "name": ws.name,
+                    "screen": screen,
+                    "windowCount": ws.window_ids.len(),
+                    "isVisible": ws.is_visible,
+                    "isFocused": ws.is_focused,
+                })
+            })
+            .collect();
+
+        let _ = handle.emit(events::tiling::WORKSPACES_CHANGED, workspaces);
+    }
+}
+
 // ============================================================================
 // IPC Query Handler
 // ============================================================================
@@ -596,8 +731,44 @@ pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
             *focused_workspace,
         ),
 
+        IpcQuery::Layout { workspace } => handle_layout_query(workspace),
+
+        IpcQuery::Diagnostics { workspace } => handle_diagnostics_query(workspace),
+
+        IpcQuery::Tree { workspace } => handle_tree_query(workspace.as_deref()),
+
+        IpcQuery::PhantomWindows { prune } => handle_phantom_windows_query(*prune),
+
+        IpcQuery::Explain { window_id } => handle_explain_query(*window_id),
+
+        IpcQuery::PlaceWindow { x, y, width, height, gaps } => {
+            handle_place_window_query(super::actor::WindowPlacement {
+                x: x.clone(),
+                y: y.clone(),
+                width: width.clone(),
+                height: height.clone(),
+                gaps: *gaps,
+            })
+        }
+
+        IpcQuery::ToggleAlwaysOnTop => handle_toggle_always_on_top_query(),
+
+        IpcQuery::SetWindowOpacity { opacity } => handle_set_window_opacity_query(*opacity),
+
+        IpcQuery::Metrics { reset } => Some(IpcResponse::success(super::metrics::snapshot(*reset))),
+
+        IpcQuery::Screenshot {
+            workspace,
+            only_windows,
+            output,
+        } => handle_screenshot_query(*workspace, *only_windows, output.as_deref()),
+
         IpcQuery::Apps => handle_apps_query(),
 
+        IpcQuery::RecentApps => handle_recent_apps_query(),
+
+        IpcQuery::SwitchApp => handle_switch_app_query(),
+
         IpcQuery::V2State => {
             if !is_initialized() {
                 return Some(IpcResponse::error("Tiling v2 not initialized"));
@@ -617,6 +788,13 @@ pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
                     .and_then(super::actor::QueryResult::into_enabled)
                     .unwrap_or(false);
 
+                let paused = handle
+                    .query(super::actor::StateQuery::GetPaused)
+                    .await
+                    .ok()
+                    .and_then(super::actor::QueryResult::into_paused)
+                    .unwrap_or(false);
+
                 // Get focus state
                 let focus = handle
                     .query(super::actor::StateQuery::GetFocusState)
@@ -651,6 +829,7 @@ pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
 
                 Some(IpcResponse::success(serde_json::json!({
                     "isEnabled": enabled,
+                    "isPaused": paused,
                     "screenCount": screens.len(),
                     "workspaceCount": workspaces.len(),
                     "windowCount": windows.len(),
@@ -792,6 +971,8 @@ pub fn handle_ipc_query(query: &IpcQuery) -> Option<IpcResponse> {
                             "isMinimized": w.is_minimized,
                             "isFullscreen": w.is_fullscreen,
                             "isFloating": w.is_floating,
+                            "isAlwaysOnTop": w.always_on_top,
+                            "opacity": w.opacity.unwrap_or(1.0),
                             "isFocused": focused_window_id == Some(w.id),
                         })
                     })
@@ -833,6 +1014,8 @@ fn handle_screens_query() -> Option<IpcResponse> {
                     "name": s.name,
                     "isMain": s.is_main,
                     "isBuiltin": s.is_builtin,
+                    "uuid": s.uuid,
+                    "roles": s.roles,
                     "scaleFactor": s.scale_factor,
                     "frame": {
                         "x": s.frame.x,
@@ -854,6 +1037,28 @@ fn handle_screens_query() -> Option<IpcResponse> {
     })
 }
 
+/// Returns whether a screen matches a `--screen` query filter.
+///
+/// Roles from `tiling.screenRoles` are tried first, with "main" standing for
+/// the `primary` role. Otherwise "main"/"primary" match the main display,
+/// "secondary" any other display, and anything else the display name.
+fn screen_matches_filter(
+    screens: &[super::state::Screen],
+    screen: Option<&super::state::Screen>,
+    filter: &str,
+) -> bool {
+    let role = if filter == "main" { "primary" } else { filter };
+    if screens.iter().any(|s| s.has_role(role)) {
+        return screen.is_some_and(|s| s.has_role(role));
+    }
+
+    match filter {
+        "main" | "primary" => screen.is_some_and(|s| s.is_main),
+        "secondary" => screen.is_some_and(|s| !s.is_main),
+        _ => screen.is_some_and(|s| s.name == filter),
+    }
+}
+
 /// Handles the standard `workspaces` query using v2 state.
 #[allow(clippy::too_many_lines)]
 fn handle_workspaces_query(screen: Option<&str>, focused_screen: bool) -> Option<IpcResponse> {
@@ -900,6 +1105,8 @@ fn handle_workspaces_query(screen: Option<&str>, focused_screen: bool) -> Option
             None
         };
 
+        let default_master_position = get_config().tiling.master.position;
+
         // Filter workspaces
         let filtered_workspaces: Vec<_> = workspaces
             .iter()
@@ -908,14 +1115,7 @@ fn handle_workspaces_query(screen: Option<&str>, focused_screen: bool) -> Option
                 if let Some(ref filter) = screen_filter {
                     let screen = screens.iter().find(|s| s.id == ws.screen_id);
 
-                    // Handle special screen names
-                    let matches = match filter.as_str() {
-                        "main" | "primary" => screen.is_some_and(|s| s.is_main),
-                        "secondary" => screen.is_some_and(|s| !s.is_main),
-                        _ => screen.is_some_and(|s| s.name == *filter),
-                    };
-
-                    if !matches {
+                    if !screen_matches_filter(&screens, screen, filter) {
                         return false;
                     }
                 }
@@ -936,11 +1136,13 @@ fn handle_workspaces_query(screen: Option<&str>, focused_screen: bool) -> Option
                     .map_or_else(|| format!("screen-{screen_id}"), |s| s.name.clone());
 
                 let layout = ws.layout;
+                let master_position = ws.master_position.unwrap_or(default_master_position);
                 serde_json::json!({
                     "id": ws.id.to_string(),
                     "name": ws.name,
                     "screenName": screen_name,
                     "layout": layout.as_str(),
+                    "masterPosition": master_position,
                     "isVisible": ws.is_visible,
                     "isFocused": ws.is_focused,
                     "windowCount": ws.window_ids.len(),
@@ -1042,14 +1244,7 @@ fn handle_windows_query(
                     if let Some(ws) = ws {
                         let screen = screens.iter().find(|s| s.id == ws.screen_id);
 
-                        // Handle special screen names
-                        let matches = match filter.as_str() {
-                            "main" | "primary" => screen.is_some_and(|s| s.is_main),
-                            "secondary" => screen.is_some_and(|s| !s.is_main),
-                            _ => screen.is_some_and(|s| s.name == *filter),
-                        };
-
-                        if !matches {
+                        if !screen_matches_filter(&screens, screen, filter) {
                             return false;
                         }
                     } else {
@@ -1092,6 +1287,8 @@ fn handle_windows_query(
                     "isMinimized": w.is_minimized,
                     "isFullscreen": w.is_fullscreen,
                     "isFloating": w.is_floating,
+                    "isAlwaysOnTop": w.always_on_top,
+                    "opacity": w.opacity.unwrap_or(1.0),
                     "isFocused": focused_window_id == Some(w.id),
                 })
             })
@@ -1101,6 +1298,549 @@ fn handle_windows_query(
     })
 }
 
+/// Handles the `layout` query - returns the computed layout of a workspace.
+///
+/// Works for any workspace, visible or not. Frames are the ones the layout
+/// engine would apply, after minimum-size enforcement.
+fn handle_layout_query(workspace: &str) -> Option<IpcResponse> {
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    rt.block_on(async {
+        let Some(ws) = handle
+            .query(super::actor::StateQuery::GetWorkspaceByName { name: workspace.to_string() })
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_workspace)
+            .flatten()
+        else {
+            return Some(IpcResponse::error(format!("Workspace '{workspace}' not found")));
+        };
+
+        let layout = handle
+            .query(super::actor::StateQuery::GetWindowLayout { workspace_id: ws.id })
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_layout)
+            .unwrap_or_default();
+
+        let windows: Vec<_> = layout
+            .iter()
+            .map(|(window_id, frame)| {
+                serde_json::json!({
+                    "id": window_id,
+                    "frame": {
+                        "x": frame.x,
+                        "y": frame.y,
+                        "width": frame.width,
+                        "height": frame.height,
+                    },
+                })
+            })
+            .collect();
+
+        Some(IpcResponse::success(serde_json::json!({
+            "workspace": ws.name,
+            "id": ws.id.to_string(),
+            "layout": ws.layout.as_str(),
+            "splitRatios": ws.split_ratios,
+            "masterRatio": ws.master_ratio,
+            "masterPosition": ws.master_position.unwrap_or(get_config().tiling.master.position),
+            "windows": windows,
+        })))
+    })
+}
+
+/// Handles the `tree` query - returns the frames of a workspace's windows.
+///
+/// Tiled windows are reported at the frame the layout engine computes for
+/// them, floating windows at their tracked frame. Used by `stache tiling tree`
+/// to draw the workspace, so the screen's visible frame is included as well.
+fn handle_tree_query(workspace: Option<&str>) -> Option<IpcResponse> {
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    rt.block_on(async {
+        let query = workspace.map_or(super::actor::StateQuery::GetFocusedWorkspace, |name| {
+            super::actor::StateQuery::GetWorkspaceByName { name: name.to_string() }
+        });
+        let Some(ws) = handle
+            .query(query)
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_workspace)
+            .flatten()
+        else {
+            return Some(IpcResponse::error(workspace.map_or_else(
+                || "No focused workspace".to_string(),
+                |name| format!("Workspace '{name}' not found"),
+            )));
+        };
+
+        let screen = handle
+            .query(super::actor::StateQuery::GetScreen { id: ws.screen_id })
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_screen)
+            .flatten();
+
+        let layout = handle
+            .query(super::actor::StateQuery::GetWindowLayout { workspace_id: ws.id })
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_layout)
+            .unwrap_or_default();
+
+        let tracked = handle
+            .query(super::actor::StateQuery::GetWindowsForWorkspace { workspace_id: ws.id })
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_windows)
+            .unwrap_or_default();
+
+        let focused_id = ws.focused_window_id();
+
+        let windows: Vec<_> = ws
+            .window_ids
+            .iter()
+            .filter_map(|id| tracked.iter().find(|w| w.id == *id))
+            .filter(|w| !w.is_minimized && !w.is_hidden)
+            .map(|w| {
+                let frame =
+                    layout.iter().find(|(id, _)| *id == w.id).map_or(w.frame, |(_, frame)| *frame);
+
+                serde_json::json!({
+                    "id": w.id,
+                    "appName": w.app_name,
+                    "title": w.title,
+                    "frame": frame,
+                    "isFloating": w.is_floating,
+                    "isFocused": focused_id == Some(w.id),
+                })
+            })
+            .collect();
+
+        Some(IpcResponse::success(serde_json::json!({
+            "workspace": ws.name,
+            "layout": ws.layout.as_str(),
+            "screenFrame": screen.map(|s| s.visible_frame),
+            "windows": windows,
+        })))
+    })
+}
+
+/// Message returned when the app may not capture the screen.
+const SCREEN_CAPTURE_DENIED: &str = "Screen recording permission is required to take screenshots. \
+     Allow Stache in System Settings > Privacy & Security > Screen Recording, then try again";
+
+/// Handles the `screenshot` query - captures the focused window or workspace.
+///
+/// Without the screen recording permission, asks for it and returns an error
+/// instead of capturing. The PNG is written to `output` or, without one,
+/// copied to the clipboard.
+fn handle_screenshot_query(
+    workspace: bool,
+    only_windows: bool,
+    output: Option<&str>,
+) -> Option<IpcResponse> {
+    use super::ffi::capture;
+
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    if !capture::has_screen_capture_access() {
+        capture::request_screen_capture_access();
+        return Some(IpcResponse::error(SCREEN_CAPTURE_DENIED));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    let windows = rt.block_on(async {
+        let ws = handle
+            .query(super::actor::StateQuery::GetFocusedWorkspace)
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_workspace)
+            .flatten()?;
+
+        let tracked = handle
+            .query(super::actor::StateQuery::GetWindowsForWorkspace { workspace_id: ws.id })
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_windows)
+            .unwrap_or_default();
+
+        let focused_id = ws.focused_window_id();
+        Some(
+            tracked
+                .into_iter()
+                .filter(|w| !w.is_minimized && !w.is_hidden)
+                .filter(|w| workspace || focused_id == Some(w.id))
+                .collect::<Vec<_>>(),
+        )
+    });
+
+    let Some(windows) = windows.filter(|windows| !windows.is_empty()) else {
+        return Some(IpcResponse::error(if workspace {
+            "No windows to capture in the focused workspace"
+        } else {
+            "No focused window to capture"
+        }));
+    };
+
+    let window_ids: Vec<u32> = windows.iter().map(|w| w.id).collect();
+    let bounds = windows
+        .iter()
+        .skip(1)
+        .fold(windows[0].frame, |bounds, w| bounds.union(&w.frame));
+
+    let Some(image) = capture::capture_region(bounds, only_windows.then_some(&window_ids[..]))
+    else {
+        return Some(IpcResponse::error(SCREEN_CAPTURE_DENIED));
+    };
+
+    let mut png = std::io::Cursor::new(Vec::new());
+    if let Err(e) = image.write_to(&mut png, image::ImageFormat::Png) {
+        return Some(IpcResponse::error(format!("Failed to encode screenshot: {e}")));
+    }
+    let png = png.into_inner();
+
+    match output {
+        Some(path) => {
+            if let Err(e) = std::fs::write(path, &png) {
+                return Some(IpcResponse::error(format!("Failed to write {path}: {e}")));
+            }
+        }
+        None => {
+            if !crate::platform::clipboard::copy_png(&png) {
+                return Some(IpcResponse::error("Failed to copy screenshot to the clipboard"));
+            }
+        }
+    }
+
+    Some(IpcResponse::success(serde_json::json!({
+        "path": output,
+        "width": image.width(),
+        "height": image.height(),
+        "windows": window_ids,
+    })))
+}
+
+/// Tolerance in points when comparing tracked and on-screen frames.
+const DIAGNOSTICS_FRAME_TOLERANCE: f64 = 1.0;
+
+/// Handles the `diagnostics` query - returns the engine's view of a workspace.
+///
+/// For each window of the workspace, the tracked frame is compared with the
+/// frame currently on screen, to spot state that drifted from reality. The
+/// layout cache is valid when the frames last applied to the tiled windows
+/// match the layout the engine computes now. Read-only.
+fn handle_diagnostics_query(workspace: &str) -> Option<IpcResponse> {
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    rt.block_on(async {
+        let Some(ws) = handle
+            .query(super::actor::StateQuery::GetWorkspaceByName { name: workspace.to_string() })
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_workspace)
+            .flatten()
+        else {
+            return Some(IpcResponse::error(format!("Workspace '{workspace}' not found")));
+        };
+
+        let layout = handle
+            .query(super::actor::StateQuery::GetWindowLayout { workspace_id: ws.id })
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_layout)
+            .unwrap_or_default();
+
+        let tracked = handle
+            .query(super::actor::StateQuery::GetWindowsForWorkspace { workspace_id: ws.id })
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_windows)
+            .unwrap_or_default();
+
+        let on_screen: std::collections::HashMap<u32, super::state::Rect> =
+            super::window::get_all_windows_including_hidden()
+                .into_iter()
+                .map(|info| (info.id, info.frame))
+                .collect();
+
+        let layout_cache_valid = layout.iter().all(|(window_id, frame)| {
+            tracked
+                .iter()
+                .find(|w| w.id == *window_id)
+                .and_then(|w| w.expected_frame)
+                .is_some_and(|expected| expected.approx_eq(frame, DIAGNOSTICS_FRAME_TOLERANCE))
+        });
+
+        let windows: Vec<_> = ws
+            .window_ids
+            .iter()
+            .filter_map(|id| tracked.iter().find(|w| w.id == *id))
+            .map(|w| {
+                let actual = on_screen.get(&w.id);
+                let layout_frame = layout.iter().find(|(id, _)| *id == w.id).map(|(_, f)| *f);
+                let mismatch = actual
+                    .is_none_or(|actual| !actual.approx_eq(&w.frame, DIAGNOSTICS_FRAME_TOLERANCE));
+
+                serde_json::json!({
+                    "id": w.id,
+                    "appId": w.app_id,
+                    "title": w.title,
+                    "trackedFrame": w.frame,
+                    "actualFrame": actual,
+                    "expectedFrame": w.expected_frame,
+                    "layoutFrame": layout_frame,
+                    "frameMismatch": mismatch,
+                    "isLayoutable": w.is_layoutable(),
+                    "isNonResizable": w.non_resizable,
+                    "minimumSize": w.effective_minimum_size(),
+                })
+            })
+            .collect();
+
+        Some(IpcResponse::success(serde_json::json!({
+            "workspace": ws.name,
+            "id": ws.id.to_string(),
+            "layout": ws.layout.as_str(),
+            "splitRatios": ws.split_ratios,
+            "masterRatio": ws.master_ratio,
+            "masterPosition": ws.master_position.unwrap_or(get_config().tiling.master.position),
+            "layoutCacheValid": layout_cache_valid,
+            "windows": windows,
+        })))
+    })
+}
+
+/// Handles the `phantomWindows` query - returns tracked windows without an AX element.
+///
+/// Windows on other Spaces or in fullscreen have no AX element either, so a
+/// window only counts as a phantom when it is neither on screen nor on a Space.
+/// With `prune`, each phantom window is untracked through the regular destroy
+/// path, which also re-applies the layout of its workspace.
+fn handle_phantom_windows_query(prune: bool) -> Option<IpcResponse> {
+    use super::effects::window_ops::has_ax_element;
+    use super::ffi::window_server_ids;
+
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    rt.block_on(async {
+        let workspaces = handle
+            .query(super::actor::StateQuery::GetAllWorkspaces)
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_workspaces)
+            .unwrap_or_default();
+
+        let windows = handle
+            .query(super::actor::StateQuery::GetAllWindows)
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_windows)
+            .unwrap_or_default();
+
+        let Some(server_ids) = window_server_ids() else {
+            return Some(IpcResponse::error("Failed to read the window list"));
+        };
+
+        let phantoms: Vec<_> = windows
+            .iter()
+            .filter(|w| !has_ax_element(w.id) && !server_ids.contains(&w.id))
+            .map(|w| {
+                let pruned =
+                    prune && handle.send(StateMessage::WindowDestroyed { window_id: w.id }).is_ok();
+                if pruned {
+                    tracing::info!("tiling: pruned phantom window {} ({})", w.id, w.app_id);
+                }
+
+                let workspace = workspaces.iter().find(|ws| ws.id == w.workspace_id);
+                serde_json::json!({
+                    "id": w.id,
+                    "appId": w.app_id,
+                    "appName": w.app_name,
+                    "title": w.title,
+                    "workspace": workspace.map(|ws| ws.name.clone()),
+                    "pruned": pruned,
+                })
+            })
+            .collect();
+
+        Some(IpcResponse::success(phantoms))
+    })
+}
+
+/// Handles the `placeWindow` query - sets the focused window's frame and returns it.
+fn handle_place_window_query(placement: super::actor::WindowPlacement) -> Option<IpcResponse> {
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    match rt.block_on(handle.place_window(placement)) {
+        Ok(Some(frame)) => Some(IpcResponse::success(frame)),
+        Ok(None) => Some(IpcResponse::error("No focused window to place")),
+        Err(e) => Some(IpcResponse::error(e.to_string())),
+    }
+}
+
+/// Handles the `toggleAlwaysOnTop` query - toggles the focused window's level
+/// and returns its new state.
+fn handle_toggle_always_on_top_query() -> Option<IpcResponse> {
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    rt.block_on(async {
+        let Some(window) = handle
+            .get_focused_window()
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_window)
+            .flatten()
+        else {
+            return Some(IpcResponse::error("No focused window to keep on top"));
+        };
+
+        match handle.toggle_always_on_top(window.id).await {
+            Ok(Some(on_top)) => Some(IpcResponse::success(serde_json::json!({
+                "windowId": window.id,
+                "isAlwaysOnTop": on_top,
+            }))),
+            Ok(None) => Some(IpcResponse::error(format!(
+                "Failed to change the level of window {}. Changing the level of another \
+                 app's window requires a scripting addition with SIP partially disabled.",
+                window.id
+            ))),
+            Err(e) => Some(IpcResponse::error(e.to_string())),
+        }
+    })
+}
+
+/// Handles the `setWindowOpacity` query - sets the focused window's opacity,
+/// in percent, and returns it.
+fn handle_set_window_opacity_query(opacity: u8) -> Option<IpcResponse> {
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    rt.block_on(async {
+        let Some(window) = handle
+            .get_focused_window()
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_window)
+            .flatten()
+        else {
+            return Some(IpcResponse::error("No focused window to set opacity of"));
+        };
+
+        let opacity = opacity.min(100);
+        match handle.set_window_opacity(window.id, f64::from(opacity) / 100.0).await {
+            Ok(true) => Some(IpcResponse::success(serde_json::json!({
+                "windowId": window.id,
+                "opacity": opacity,
+            }))),
+            Ok(false) => Some(IpcResponse::error(format!(
+                "Failed to set the opacity of window {}. Changing the opacity of another \
+                 app's window requires a scripting addition with SIP partially disabled.",
+                window.id
+            ))),
+            Err(e) => Some(IpcResponse::error(e.to_string())),
+        }
+    })
+}
+
+/// Handles the `explain` query - returns which rules match a tracked window.
+fn handle_explain_query(window_id: u32) -> Option<IpcResponse> {
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    rt.block_on(async {
+        let Some(window) = handle
+            .query(super::actor::StateQuery::GetWindow { id: window_id })
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_window)
+            .flatten()
+        else {
+            return Some(IpcResponse::error(format!("Window {window_id} is not tracked")));
+        };
+
+        let workspace = handle
+            .query(super::actor::StateQuery::GetWorkspace { id: window.workspace_id })
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_workspace)
+            .flatten();
+
+        let screen = match &workspace {
+            Some(ws) => handle
+                .query(super::actor::StateQuery::GetScreen { id: ws.screen_id })
+                .await
+                .ok()
+                .and_then(super::actor::QueryResult::into_screen)
+                .flatten(),
+            None => None,
+        };
+
+        let workspace_name = workspace.as_ref().map_or("", |ws| ws.name.as_str());
+        let explanation =
+            super::rules::explain_window(&window, workspace_name, &get_config().tiling);
+
+        Some(IpcResponse::success(serde_json::json!({
+            "window": {
+                "id": window.id,
+                "appId": window.app_id,
+                "appName": window.app_name,
+                "title": window.title,
+            },
+            "ignoreRules": explanation.ignore_rules,
+            "ignoreMatches": explanation.ignore_matches,
+            "displayRule": explanation.display_rule,
+            "workspaceRule": explanation.workspace_rule,
+            "fallback": explanation.fallback,
+            "workspace": workspace.map(|ws| ws.name),
+            "screen": screen.map(|s| s.name),
+        })))
+    })
+}
+
 /// Handles the `apps` query - returns all running applications (excluding ignored apps).
 #[allow(clippy::unnecessary_wraps)] // Matches other handler signatures
 fn handle_apps_query() -> Option<IpcResponse> {
@@ -1127,6 +1867,60 @@ fn handle_apps_query() -> Option<IpcResponse> {
     Some(IpcResponse::success(app_infos))
 }
 
+/// Returns the recently activated apps from the actor, most recent first.
+fn recent_apps() -> Option<Vec<super::state::RecentApp>> {
+    let handle = get_handle()?;
+    let rt = tokio::runtime::Builder::new_current_thread().enable_all().build().ok()?;
+
+    rt.block_on(async {
+        handle
+            .query(super::actor::StateQuery::GetRecentApps)
+            .await
+            .ok()
+            .and_then(super::actor::QueryResult::into_recent_apps)
+    })
+}
+
+/// Formats a recently activated app for an IPC response.
+fn recent_app_json(app: &super::state::RecentApp) -> serde_json::Value {
+    serde_json::json!({
+        "pid": app.pid,
+        "name": app.name,
+        "bundleId": app.bundle_id,
+    })
+}
+
+/// Handles recent apps query.
+fn handle_recent_apps_query() -> Option<IpcResponse> {
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let apps = recent_apps()?;
+    Some(IpcResponse::success(
+        apps.iter().map(recent_app_json).collect::<Vec<_>>(),
+    ))
+}
+
+/// Handles switch app query by activating the app used before the current one.
+fn handle_switch_app_query() -> Option<IpcResponse> {
+    use super::effects::window_ops::activate_app;
+
+    if !is_initialized() {
+        return Some(IpcResponse::error("Tiling v2 not initialized"));
+    }
+
+    let apps = recent_apps()?;
+    let Some(app) = apps.get(1) else {
+        return Some(IpcResponse::error("No other recently used app to switch to"));
+    };
+
+    if !activate_app(app.pid) {
+        return Some(IpcResponse::error(format!("Failed to activate {}", app.name)));
+    }
+    Some(IpcResponse::success(recent_app_json(app)))
+}
+
 // ============================================================================
 // Mouse Up Callback (Drag Completion)
 // ============================================================================
@@ -3,10 +3,12 @@
 //! This implements the Dwindle algorithm similar to Hyprland's dwindle layout.
 //! Each new window splits the **last window's space**, creating a spiral pattern.
 //!
-//! The layout adapts to screen orientation:
+//! By default the layout adapts to screen orientation:
 //! - **Landscape** (width >= height): First split is horizontal (left/right)
 //! - **Portrait** (width < height): First split is vertical (top/bottom)
 //!
+//! `tiling.dwindle.firstSplit` can force the first split either way instead.
+//!
 //! ## Landscape Mode (e.g., 1920x1080)
 //!
 //! ```text
@@ -56,11 +58,45 @@ use smallvec::{SmallVec, smallvec};
 use super::{Gaps, LAYOUT_INLINE_CAP, LayoutResult, helpers};
 use crate::modules::tiling::state::Rect;
 
+/// Orientation of the first dwindle split.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DwindleSplit {
+    /// Adapts to screen orientation (horizontal for landscape, vertical for portrait).
+    #[default]
+    Auto,
+    /// Left/right split.
+    Horizontal,
+    /// Top/bottom split.
+    Vertical,
+}
+
+impl DwindleSplit {
+    /// Returns whether the first split is horizontal for the given screen orientation.
+    #[must_use]
+    pub const fn starts_horizontal(self, is_landscape: bool) -> bool {
+        match self {
+            Self::Auto => is_landscape,
+            Self::Horizontal => true,
+            Self::Vertical => false,
+        }
+    }
+}
+
+impl From<crate::config::types::tiling::DwindleSplit> for DwindleSplit {
+    fn from(config_split: crate::config::types::tiling::DwindleSplit) -> Self {
+        match config_split {
+            crate::config::types::tiling::DwindleSplit::Auto => Self::Auto,
+            crate::config::types::tiling::DwindleSplit::Horizontal => Self::Horizontal,
+            crate::config::types::tiling::DwindleSplit::Vertical => Self::Vertical,
+        }
+    }
+}
+
 /// Dwindle layout - windows arranged in a dwindling spiral pattern.
 ///
 /// Each new window splits the last window's space, alternating between
-/// horizontal and vertical splits. The initial split direction is determined
-/// by the screen orientation:
+/// horizontal and vertical splits. The initial split direction is set by
+/// `first_split`, which with `Auto` follows the screen orientation:
 /// - Landscape (width >= height): starts with horizontal split
 /// - Portrait (width < height): starts with vertical split
 ///
@@ -72,12 +108,14 @@ use crate::modules::tiling::state::Rect;
 /// * `ratios` - Split ratios for each split (0.0-1.0, default 0.5 for each).
 ///   For N windows, up to N-1 ratios can be provided. Each ratio controls
 ///   the proportion of space the first half gets at that split level.
+/// * `first_split` - Orientation of the first split
 #[must_use]
 pub fn layout(
     window_ids: &[u32],
     screen_frame: &Rect,
     gaps: &Gaps,
     ratios: &[f64],
+    first_split: DwindleSplit,
 ) -> LayoutResult {
     if window_ids.is_empty() {
         return SmallVec::new();
@@ -87,10 +125,10 @@ pub fn layout(
         return smallvec![(window_ids[0], *screen_frame)];
     }
 
-    // Determine initial split direction based on screen orientation
+    // Determine initial split direction, by default from the screen orientation
     // Landscape: start horizontal (left/right split)
     // Portrait: start vertical (top/bottom split)
-    let is_landscape = screen_frame.width >= screen_frame.height;
+    let starts_horizontal = first_split.starts_horizontal(screen_frame.is_landscape());
 
     let mut result: LayoutResult = SmallVec::with_capacity(window_ids.len().min(LAYOUT_INLINE_CAP));
 
@@ -102,10 +140,10 @@ pub fn layout(
         // Get the frame we're going to split (the last one)
         let parent_frame = frames[i - 1];
 
-        // Alternate split direction starting from the orientation-appropriate direction
-        // For landscape: odd index = horizontal, even = vertical
-        // For portrait: odd index = vertical, even = horizontal
-        let split_horizontal = if is_landscape {
+        // Alternate split direction starting from the initial direction
+        // Starting horizontal: odd index = horizontal, even = vertical
+        // Starting vertical: odd index = vertical, even = horizontal
+        let split_horizontal = if starts_horizontal {
             i % 2 == 1 // 1st split horizontal, 2nd vertical, 3rd horizontal...
         } else {
             i % 2 == 0 // 1st split vertical, 2nd horizontal, 3rd vertical...
@@ -158,14 +196,14 @@ mod tests {
 
     #[test]
     fn test_dwindle_empty() {
-        let result = layout(&[], &landscape_frame(), &no_gaps(), &[]);
+        let result = layout(&[], &landscape_frame(), &no_gaps(), &[], DwindleSplit::Auto);
         assert!(result.is_empty());
     }
 
     #[test]
     fn test_dwindle_single_window() {
         let frame = landscape_frame();
-        let result = layout(&[1], &frame, &no_gaps(), &[]);
+        let result = layout(&[1], &frame, &no_gaps(), &[], DwindleSplit::Auto);
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0], (1, frame));
@@ -178,7 +216,7 @@ mod tests {
     #[test]
     fn test_landscape_two_windows() {
         let frame = landscape_frame();
-        let result = layout(&[1, 2], &frame, &no_gaps(), &[]);
+        let result = layout(&[1, 2], &frame, &no_gaps(), &[], DwindleSplit::Auto);
 
         assert_eq!(result.len(), 2);
 
@@ -202,7 +240,7 @@ mod tests {
     #[test]
     fn test_landscape_three_windows() {
         let frame = landscape_frame();
-        let result = layout(&[1, 2, 3], &frame, &no_gaps(), &[]);
+        let result = layout(&[1, 2, 3], &frame, &no_gaps(), &[], DwindleSplit::Auto);
 
         assert_eq!(result.len(), 3);
 
@@ -231,7 +269,7 @@ mod tests {
     #[test]
     fn test_landscape_four_windows() {
         let frame = landscape_frame();
-        let result = layout(&[1, 2, 3, 4], &frame, &no_gaps(), &[]);
+        let result = layout(&[1, 2, 3, 4], &frame, &no_gaps(), &[], DwindleSplit::Auto);
 
         assert_eq!(result.len(), 4);
 
@@ -270,7 +308,7 @@ mod tests {
     #[test]
     fn test_portrait_two_windows() {
         let frame = portrait_frame();
-        let result = layout(&[1, 2], &frame, &no_gaps(), &[]);
+        let result = layout(&[1, 2], &frame, &no_gaps(), &[], DwindleSplit::Auto);
 
         assert_eq!(result.len(), 2);
 
@@ -291,7 +329,7 @@ mod tests {
     #[test]
     fn test_portrait_three_windows() {
         let frame = portrait_frame();
-        let result = layout(&[1, 2, 3], &frame, &no_gaps(), &[]);
+        let result = layout(&[1, 2, 3], &frame, &no_gaps(), &[], DwindleSplit::Auto);
 
         assert_eq!(result.len(), 3);
 
@@ -320,7 +358,7 @@ mod tests {
     #[test]
     fn test_portrait_four_windows() {
         let frame = portrait_frame();
-        let result = layout(&[1, 2, 3, 4], &frame, &no_gaps(), &[]);
+        let result = layout(&[1, 2, 3, 4], &frame, &no_gaps(), &[], DwindleSplit::Auto);
 
         assert_eq!(result.len(), 4);
 
@@ -360,7 +398,7 @@ mod tests {
     fn test_dwindle_with_gaps() {
         let frame = landscape_frame();
         let gaps = Gaps::uniform(20.0, 0.0);
-        let result = layout(&[1, 2], &frame, &gaps, &[]);
+        let result = layout(&[1, 2], &frame, &gaps, &[], DwindleSplit::Auto);
 
         let (_, frame1) = result[0];
         let (_, frame2) = result[1];
@@ -373,7 +411,7 @@ mod tests {
     #[test]
     fn test_dwindle_preserves_order() {
         let frame = landscape_frame();
-        let result = layout(&[10, 20, 30, 40], &frame, &no_gaps(), &[]);
+        let result = layout(&[10, 20, 30, 40], &frame, &no_gaps(), &[], DwindleSplit::Auto);
 
         assert_eq!(result[0].0, 10);
         assert_eq!(result[1].0, 20);
@@ -384,7 +422,7 @@ mod tests {
     #[test]
     fn test_dwindle_total_area_preserved_landscape() {
         let frame = landscape_frame();
-        let result = layout(&[1, 2, 3, 4, 5, 6], &frame, &no_gaps(), &[]);
+        let result = layout(&[1, 2, 3, 4, 5, 6], &frame, &no_gaps(), &[], DwindleSplit::Auto);
 
         let total_area: f64 = result.iter().map(|(_, f)| f.area()).sum();
         let screen_area = frame.area();
@@ -398,7 +436,7 @@ mod tests {
     #[test]
     fn test_dwindle_total_area_preserved_portrait() {
         let frame = portrait_frame();
-        let result = layout(&[1, 2, 3, 4, 5, 6], &frame, &no_gaps(), &[]);
+        let result = layout(&[1, 2, 3, 4, 5, 6], &frame, &no_gaps(), &[], DwindleSplit::Auto);
 
         let total_area: f64 = result.iter().map(|(_, f)| f.area()).sum();
         let screen_area = frame.area();
@@ -412,7 +450,7 @@ mod tests {
     #[test]
     fn test_dwindle_no_overlap() {
         let frame = landscape_frame();
-        let result = layout(&[1, 2, 3, 4], &frame, &no_gaps(), &[]);
+        let result = layout(&[1, 2, 3, 4], &frame, &no_gaps(), &[], DwindleSplit::Auto);
 
         for (i, (_, frame_a)) in result.iter().enumerate() {
             for (j, (_, frame_b)) in result.iter().enumerate() {
@@ -435,7 +473,7 @@ mod tests {
     fn test_dwindle_many_windows() {
         let frame = landscape_frame();
         let ids: Vec<u32> = (1..=8).collect();
-        let result = layout(&ids, &frame, &no_gaps(), &[]);
+        let result = layout(&ids, &frame, &no_gaps(), &[], DwindleSplit::Auto);
 
         assert_eq!(result.len(), 8);
 
@@ -451,7 +489,7 @@ mod tests {
     fn test_square_screen_uses_landscape_behavior() {
         // Square screens (width == height) should use landscape behavior
         let frame = Rect::new(0.0, 0.0, 1000.0, 1000.0);
-        let result = layout(&[1, 2], &frame, &no_gaps(), &[]);
+        let result = layout(&[1, 2], &frame, &no_gaps(), &[], DwindleSplit::Auto);
 
         let (_, frame1) = result[0];
         let (_, frame2) = result[1];
@@ -471,7 +509,7 @@ mod tests {
     fn test_dwindle_with_custom_ratio_two_windows() {
         let frame = landscape_frame();
         // 70% for first window, 30% for second
-        let result = layout(&[1, 2], &frame, &no_gaps(), &[0.7]);
+        let result = layout(&[1, 2], &frame, &no_gaps(), &[0.7], DwindleSplit::Auto);
 
         let (_, frame1) = result[0];
         let (_, frame2) = result[1];
@@ -485,7 +523,7 @@ mod tests {
     fn test_dwindle_with_custom_ratios_three_windows() {
         let frame = landscape_frame();
         // 60% for first split, 40% for second split
-        let result = layout(&[1, 2, 3], &frame, &no_gaps(), &[0.6, 0.4]);
+        let result = layout(&[1, 2, 3], &frame, &no_gaps(), &[0.6, 0.4], DwindleSplit::Auto);
 
         let (_, frame1) = result[0];
         let (_, frame2) = result[1];
@@ -504,7 +542,7 @@ mod tests {
     fn test_dwindle_partial_ratios() {
         // Provide only some ratios - rest should default to 0.5
         let frame = landscape_frame();
-        let result = layout(&[1, 2, 3, 4], &frame, &no_gaps(), &[0.6]); // Only first ratio
+        let result = layout(&[1, 2, 3, 4], &frame, &no_gaps(), &[0.6], DwindleSplit::Auto); // Only first ratio
 
         let (_, frame1) = result[0];
 
@@ -517,4 +555,33 @@ mod tests {
         assert!((frame2.height - frame.height.mul_add(0.5, 0.0)).abs() < 1.0);
         assert!((frame3.width - (frame.width * 0.4).mul_add(0.5, 0.0)).abs() < 1.0);
     }
+
+    // ========================================================================
+    // First Split Tests
+    // ========================================================================
+
+    #[test]
+    fn test_vertical_first_split_flips_orientation() {
+        let frame = landscape_frame();
+        let auto = layout(&[1, 2, 3], &frame, &no_gaps(), &[], DwindleSplit::Auto);
+        let vertical = layout(&[1, 2, 3], &frame, &no_gaps(), &[], DwindleSplit::Vertical);
+
+        // Auto on landscape: 1 on the left, 2 above 3 on the right
+        assert_eq!(auto[0].1, Rect::new(0.0, 0.0, 960.0, 1080.0));
+        assert_eq!(auto[1].1, Rect::new(960.0, 0.0, 960.0, 540.0));
+
+        // Vertical: 1 on top, 2 left of 3 below
+        assert_eq!(vertical[0].1, Rect::new(0.0, 0.0, 1920.0, 540.0));
+        assert_eq!(vertical[1].1, Rect::new(0.0, 540.0, 960.0, 540.0));
+        assert_eq!(vertical[2].1, Rect::new(960.0, 540.0, 960.0, 540.0));
+    }
+
+    #[test]
+    fn test_horizontal_first_split_on_portrait() {
+        let frame = portrait_frame();
+        let result = layout(&[1, 2], &frame, &no_gaps(), &[], DwindleSplit::Horizontal);
+
+        assert_eq!(result[0].1, Rect::new(0.0, 0.0, 540.0, 1920.0));
+        assert_eq!(result[1].1, Rect::new(540.0, 0.0, 540.0, 1920.0));
+    }
 }
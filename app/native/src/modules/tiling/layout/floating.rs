@@ -3,16 +3,31 @@
 //! This module provides functionality for floating windows, including
 //! preset-based positioning where windows can be quickly placed at
 //! predefined sizes and positions.
+//!
+//! Common presets are built in (centered, under the cursor, halves, quarters,
+//! thirds and `maximize`); presets from the configuration with the same name
+//! take precedence. Applying `left-half` or `right-half` again cycles the
+//! window through that side's thirds.
 
 use super::Gaps;
-use crate::config::{DimensionValue, FloatingPreset, get_config};
+use crate::config::{DimensionValue, FloatingConfig, FloatingPreset, get_config};
 use crate::modules::tiling::state::Rect;
 
+/// Presets applied in turn when the first preset of a cycle is applied again.
+const PRESET_CYCLES: [[&str; 3]; 2] = [["left-half", "left-third", "left-two-thirds"], [
+    "right-half",
+    "right-third",
+    "right-two-thirds",
+]];
+
+/// Tolerance when matching a window frame against a preset frame.
+const CYCLE_FRAME_TOLERANCE: f64 = 2.0;
+
 // ============================================================================
 // Preset Functions
 // ============================================================================
 
-/// Finds a preset by name from the configuration.
+/// Finds a preset by name from the configuration or the built-in presets.
 ///
 /// # Arguments
 ///
@@ -23,21 +38,49 @@ use crate::modules::tiling::state::Rect;
 /// The preset if found, or `None` if no preset with that name exists.
 #[must_use]
 pub fn find_preset(name: &str) -> Option<FloatingPreset> {
-    let config = get_config();
-    config
-        .tiling
-        .floating
-        .presets
-        .iter()
-        .find(|p| p.name.eq_ignore_ascii_case(name))
-        .cloned()
+    get_config().tiling.floating.find_preset(name)
 }
 
-/// Returns a list of all available preset names.
+/// Returns a list of all available preset names, including built-in ones.
+#[must_use]
+pub fn list_preset_names() -> Vec<String> { get_config().tiling.floating.preset_names() }
+
+/// Returns the preset to apply when `preset` is requested for a window at `current_frame`.
+///
+/// If `preset` starts a cycle (`left-half` or `right-half`) and the window
+/// already sits at one of the cycle's frames, the next preset of the cycle is
+/// returned; otherwise `preset` itself.
+///
+/// # Arguments
+///
+/// * `floating` - The floating configuration, used to look up cycle presets.
+/// * `preset` - The requested preset.
+/// * `current_frame` - The window's current frame.
+/// * `frame_of` - Calculates the frame a preset would give the window.
 #[must_use]
-pub fn list_preset_names() -> Vec<String> {
-    let config = get_config();
-    config.tiling.floating.presets.iter().map(|p| p.name.clone()).collect()
+pub fn cycle_preset(
+    floating: &FloatingConfig,
+    preset: FloatingPreset,
+    current_frame: &Rect,
+    frame_of: impl Fn(&FloatingPreset) -> Rect,
+) -> FloatingPreset {
+    let Some(cycle) =
+        PRESET_CYCLES.iter().find(|cycle| cycle[0].eq_ignore_ascii_case(&preset.name))
+    else {
+        return preset;
+    };
+
+    let presets: Vec<FloatingPreset> =
+        cycle.iter().filter_map(|name| floating.find_preset(name)).collect();
+
+    let current = presets
+        .iter()
+        .position(|p| frame_of(p).approx_eq(current_frame, CYCLE_FRAME_TOLERANCE));
+
+    match current {
+        Some(index) => presets[(index + 1) % presets.len()].clone(),
+        None => preset,
+    }
 }
 
 // ============================================================================
@@ -55,12 +98,19 @@ pub fn list_preset_names() -> Vec<String> {
 /// * `preset` - The preset configuration to apply.
 /// * `screen_frame` - The available screen area (already adjusted for menu bar, dock, etc.).
 /// * `gaps` - Gap configuration for outer and inner margins.
+/// * `cursor` - The mouse cursor location, used by `under_cursor` presets.
+///   Without it, those presets are centered in the usable area.
 ///
 /// # Returns
 ///
 /// The calculated window frame as a `Rect`.
 #[must_use]
-pub fn calculate_preset_frame(preset: &FloatingPreset, screen_frame: &Rect, gaps: &Gaps) -> Rect {
+pub fn calculate_preset_frame(
+    preset: &FloatingPreset,
+    screen_frame: &Rect,
+    gaps: &Gaps,
+    cursor: Option<(f64, f64)>,
+) -> Rect {
     // Apply outer gaps to get the usable area
     let usable = gaps.apply_outer(screen_frame);
 
@@ -89,7 +139,12 @@ pub fn calculate_preset_frame(preset: &FloatingPreset, screen_frame: &Rect, gaps
     let height = height.min(usable.height).max(1.0);
 
     // Calculate position
-    let (x, y) = if preset.center {
+    let (x, y) = if preset.under_cursor
+        && let Some((cursor_x, cursor_y)) = cursor
+    {
+        // Center the window on the cursor; clamped to the usable area below
+        (cursor_x - width / 2.0, cursor_y - height / 2.0)
+    } else if preset.center || preset.under_cursor {
         // Center the window in the usable area
         let center_x = usable.x + (usable.width - width) / 2.0;
         let center_y = usable.y + (usable.height - height) / 2.0;
@@ -125,6 +180,27 @@ pub fn calculate_preset_frame(preset: &FloatingPreset, screen_frame: &Rect, gaps
     Rect::new(x, y, width, height)
 }
 
+/// Calculates the window frame for an exact placement.
+///
+/// Positions are offsets from the top-left corner of `area` and percentages
+/// are relative to its size. The frame is clamped to stay inside `area`.
+#[must_use]
+pub fn calculate_placement_frame(
+    x: &DimensionValue,
+    y: &DimensionValue,
+    width: &DimensionValue,
+    height: &DimensionValue,
+    area: &Rect,
+) -> Rect {
+    let width = width.resolve(area.width).min(area.width).max(1.0);
+    let height = height.resolve(area.height).min(area.height).max(1.0);
+
+    let x = (area.x + x.resolve(area.width)).min(area.x + area.width - width);
+    let y = (area.y + y.resolve(area.height)).min(area.y + area.height - height);
+
+    Rect::new(x, y, width, height)
+}
+
 /// Checks if a dimension value is exactly 50%.
 fn is_half_percentage(dim: &DimensionValue) -> bool {
     match dim {
@@ -155,13 +231,14 @@ mod tests {
             x: None,
             y: None,
             center,
+            under_cursor: false,
         }
     }
 
     #[test]
     fn test_preset_centered() {
         let preset = test_preset("50%", "50%", true);
-        let frame = calculate_preset_frame(&preset, &screen_frame(), &Gaps::zero());
+        let frame = calculate_preset_frame(&preset, &screen_frame(), &Gaps::zero(), None);
 
         // Should be centered
         assert!((frame.x - 480.0).abs() < 1.0); // (1920 - 960) / 2
@@ -174,7 +251,7 @@ mod tests {
     fn test_preset_with_gaps() {
         let preset = test_preset("100%", "100%", false);
         let gaps = Gaps::uniform(10.0, 20.0);
-        let frame = calculate_preset_frame(&preset, &screen_frame(), &gaps);
+        let frame = calculate_preset_frame(&preset, &screen_frame(), &gaps, None);
 
         // Should have outer gaps applied
         assert_eq!(frame.x, 20.0);
@@ -187,12 +264,121 @@ mod tests {
     fn test_half_width_with_inner_gap() {
         let preset = test_preset("50%", "100%", false);
         let gaps = Gaps::uniform(10.0, 0.0);
-        let frame = calculate_preset_frame(&preset, &screen_frame(), &gaps);
+        let frame = calculate_preset_frame(&preset, &screen_frame(), &gaps, None);
 
         // Width should account for inner gap
         assert!((frame.width - (1920.0 - 10.0) / 2.0).abs() < 1.0);
     }
 
+    #[test]
+    fn test_under_cursor_centers_on_cursor() {
+        let preset = FloatingPreset::builtin("under-cursor").unwrap();
+        let frame =
+            calculate_preset_frame(&preset, &screen_frame(), &Gaps::zero(), Some((800.0, 500.0)));
+
+        assert_eq!(frame.width, 960.0);
+        assert_eq!(frame.height, 540.0);
+        assert_eq!(frame.x, 320.0); // 800 - 480
+        assert_eq!(frame.y, 230.0); // 500 - 270
+    }
+
+    #[test]
+    fn test_under_cursor_stays_on_screen_within_gaps() {
+        let preset = FloatingPreset::builtin("under-cursor").unwrap();
+        let gaps = Gaps::uniform(10.0, 20.0);
+        let frame = calculate_preset_frame(&preset, &screen_frame(), &gaps, Some((1910.0, 5.0)));
+
+        // Pushed back inside the outer gaps at the top-right corner
+        assert_eq!(frame.y, 20.0);
+        assert_eq!(frame.x + frame.width, 1900.0);
+    }
+
+    #[test]
+    fn test_under_cursor_without_cursor_is_centered() {
+        let preset = FloatingPreset::builtin("under-cursor").unwrap();
+        let frame = calculate_preset_frame(&preset, &screen_frame(), &Gaps::zero(), None);
+
+        assert_eq!(frame.x, 480.0);
+        assert_eq!(frame.y, 270.0);
+    }
+
+    #[test]
+    fn test_builtin_presets() {
+        let large = FloatingPreset::builtin("Center-Large").unwrap();
+        assert!(large.center);
+        let frame = calculate_preset_frame(&large, &screen_frame(), &Gaps::zero(), None);
+        assert_eq!(frame.width, 1536.0);
+        assert_eq!(frame.height, 864.0);
+
+        assert!(FloatingPreset::builtin("center").is_some());
+        assert!(FloatingPreset::builtin("unknown").is_none());
+    }
+
+    #[test]
+    fn test_quarter_presets_leave_inner_gaps() {
+        let gaps = Gaps::uniform(10.0, 0.0);
+        let top_left = FloatingPreset::builtin("top-left").unwrap();
+        let bottom_right = FloatingPreset::builtin("bottom-right").unwrap();
+
+        let first = calculate_preset_frame(&top_left, &screen_frame(), &gaps, None);
+        let second = calculate_preset_frame(&bottom_right, &screen_frame(), &gaps, None);
+
+        assert_eq!(first.x, 0.0);
+        assert_eq!(first.y, 0.0);
+        assert_eq!(second.x, first.width + 10.0);
+        assert_eq!(second.y, first.height + 10.0);
+        assert_eq!(second.x + second.width, 1920.0);
+        assert_eq!(second.y + second.height, 1080.0);
+    }
+
+    #[test]
+    fn test_maximize_fills_usable_area() {
+        let preset = FloatingPreset::builtin("maximize").unwrap();
+        let frame =
+            calculate_preset_frame(&preset, &screen_frame(), &Gaps::uniform(10.0, 20.0), None);
+
+        assert_eq!(frame, Rect::new(20.0, 20.0, 1880.0, 1040.0));
+    }
+
+    #[test]
+    fn test_cycle_preset_advances_through_thirds() {
+        let floating = FloatingConfig::default();
+        let frame_of =
+            |p: &FloatingPreset| calculate_preset_frame(p, &screen_frame(), &Gaps::zero(), None);
+        let left_half = FloatingPreset::builtin("left-half").unwrap();
+
+        // Anywhere else: the requested preset is applied
+        let elsewhere = Rect::new(300.0, 300.0, 500.0, 400.0);
+        assert_eq!(
+            cycle_preset(&floating, left_half.clone(), &elsewhere, frame_of).name,
+            "left-half"
+        );
+
+        let mut frame = frame_of(&left_half);
+        let mut names = Vec::new();
+        for _ in 0..3 {
+            let next = cycle_preset(&floating, left_half.clone(), &frame, frame_of);
+            frame = frame_of(&next);
+            names.push(next.name);
+        }
+
+        assert_eq!(names, vec!["left-third", "left-two-thirds", "left-half"]);
+    }
+
+    #[test]
+    fn test_cycle_preset_ignores_presets_without_cycle() {
+        let floating = FloatingConfig::default();
+        let frame_of =
+            |p: &FloatingPreset| calculate_preset_frame(p, &screen_frame(), &Gaps::zero(), None);
+        let top_half = FloatingPreset::builtin("top-half").unwrap();
+        let frame = frame_of(&top_half);
+
+        assert_eq!(
+            cycle_preset(&floating, top_half, &frame, frame_of).name,
+            "top-half"
+        );
+    }
+
     #[test]
     fn test_is_half_percentage() {
         assert!(is_half_percentage(&DimensionValue::Percentage(
@@ -206,4 +392,31 @@ mod tests {
         )));
         assert!(!is_half_percentage(&DimensionValue::Pixels(50)));
     }
+
+    #[test]
+    fn test_placement_frame_resolves_percentages_from_area() {
+        let area = Rect::new(100.0, 50.0, 1000.0, 800.0);
+        let frame = calculate_placement_frame(
+            &DimensionValue::Percentage("10%".to_string()),
+            &DimensionValue::Pixels(20),
+            &DimensionValue::Percentage("50%".to_string()),
+            &DimensionValue::Pixels(400),
+            &area,
+        );
+
+        assert_eq!(frame, Rect::new(200.0, 70.0, 500.0, 400.0));
+    }
+
+    #[test]
+    fn test_placement_frame_is_clamped_to_area() {
+        let frame = calculate_placement_frame(
+            &DimensionValue::Pixels(1800),
+            &DimensionValue::Pixels(0),
+            &DimensionValue::Pixels(600),
+            &DimensionValue::Pixels(5000),
+            &screen_frame(),
+        );
+
+        assert_eq!(frame, Rect::new(1320.0, 0.0, 600.0, 1080.0));
+    }
 }
@@ -121,6 +121,28 @@ impl Gaps {
         self
     }
 
+    /// Create gaps with an additional offset added to the bottom outer gap.
+    /// Useful for accounting for a status bar on the bottom edge.
+    #[must_use]
+    pub const fn with_bottom_offset(mut self, offset: f64) -> Self {
+        self.outer_bottom += offset;
+        self
+    }
+
+    /// Create gaps with pixel amounts added to the inner and outer gaps.
+    /// Each gap is clamped to zero, so large negative amounts remove it.
+    #[must_use]
+    pub fn adjusted(self, inner: f64, outer: f64) -> Self {
+        Self {
+            inner_h: (self.inner_h + inner).max(0.0),
+            inner_v: (self.inner_v + inner).max(0.0),
+            outer_top: (self.outer_top + outer).max(0.0),
+            outer_right: (self.outer_right + outer).max(0.0),
+            outer_bottom: (self.outer_bottom + outer).max(0.0),
+            outer_left: (self.outer_left + outer).max(0.0),
+        }
+    }
+
     /// Resolves gaps from configuration for a specific screen.
     ///
     /// On the main screen, the bar offset (bar height + padding) is automatically
@@ -266,6 +288,15 @@ mod tests {
         assert_eq!(usable.height, 730.0); // 800 - 50 - 20
     }
 
+    #[test]
+    fn test_gaps_adjusted_clamps_to_zero() {
+        let gaps = Gaps::new(8.0, 4.0, 10.0, 10.0, 2.0, 10.0).adjusted(4.0, -6.0);
+        assert_eq!(gaps.inner_h, 12.0);
+        assert_eq!(gaps.inner_v, 8.0);
+        assert_eq!(gaps.outer_top, 4.0);
+        assert_eq!(gaps.outer_bottom, 0.0);
+    }
+
     #[test]
     fn test_gaps_with_top_offset() {
         let gaps = Gaps::uniform(10.0, 20.0).with_top_offset(40.0);
@@ -68,7 +68,7 @@ pub fn layout(
     };
 
     let count = window_ids.len();
-    let is_landscape = screen_frame.width >= screen_frame.height;
+    let is_landscape = screen_frame.is_landscape();
 
     match count {
         1 => layout_single(window_ids, screen_frame),
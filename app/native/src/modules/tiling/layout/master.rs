@@ -55,6 +55,18 @@ pub enum MasterPosition {
     Auto,
 }
 
+impl MasterPosition {
+    /// Resolves `Auto` to a concrete position for the given screen orientation.
+    #[must_use]
+    pub const fn resolve(self, is_landscape: bool) -> Self {
+        match self {
+            Self::Auto if is_landscape => Self::Left,
+            Self::Auto => Self::Top,
+            other => other,
+        }
+    }
+}
+
 impl From<crate::config::types::tiling::MasterPosition> for MasterPosition {
     fn from(config_pos: crate::config::types::tiling::MasterPosition) -> Self {
         match config_pos {
@@ -101,18 +113,7 @@ pub fn layout(
     }
 
     // Resolve auto position based on screen orientation
-    let resolved_position = match position {
-        MasterPosition::Auto => {
-            if screen_frame.width >= screen_frame.height {
-                MasterPosition::Left
-            } else {
-                MasterPosition::Top
-            }
-        }
-        other => other,
-    };
-
-    match resolved_position {
+    match position.resolve(screen_frame.is_landscape()) {
         MasterPosition::Left => layout_left(window_ids, screen_frame, ratio, gaps),
         MasterPosition::Right => layout_right(window_ids, screen_frame, ratio, gaps),
         MasterPosition::Top => layout_top(window_ids, screen_frame, ratio, gaps),
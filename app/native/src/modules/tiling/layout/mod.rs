@@ -53,7 +53,10 @@ mod master;
 mod monocle;
 mod split;
 
-pub use floating::{calculate_preset_frame, find_preset, list_preset_names};
+pub use dwindle::DwindleSplit;
+pub use floating::{
+    calculate_placement_frame, calculate_preset_frame, cycle_preset, find_preset, list_preset_names,
+};
 pub use gaps::Gaps;
 pub use grid::MAX_GRID_WINDOWS;
 pub use master::MasterPosition;
@@ -132,6 +135,7 @@ pub fn calculate_layout_with_gaps(
         gaps,
         &[],
         MasterPosition::Auto,
+        DwindleSplit::Auto,
     )
 }
 
@@ -146,6 +150,7 @@ pub fn calculate_layout_with_gaps(
 /// * `gaps` - Gap values for spacing
 /// * `split_ratios` - Custom split ratios for split layouts (cumulative 0.0-1.0)
 /// * `master_position` - Position of master window (left/right/top/bottom/auto)
+/// * `dwindle_split` - Orientation of the first dwindle split (horizontal/vertical/auto)
 ///
 /// # Returns
 ///
@@ -159,6 +164,7 @@ pub fn calculate_layout_full(
     gaps: &Gaps,
     split_ratios: &[f64],
     master_position: MasterPosition,
+    dwindle_split: DwindleSplit,
 ) -> LayoutResult {
     if window_ids.is_empty() {
         return SmallVec::new();
@@ -170,7 +176,9 @@ pub fn calculate_layout_full(
     match layout {
         LayoutType::Floating => SmallVec::new(), // No repositioning for floating
         LayoutType::Monocle => monocle::layout(window_ids, &usable_frame),
-        LayoutType::Dwindle => dwindle::layout(window_ids, &usable_frame, gaps, split_ratios),
+        LayoutType::Dwindle => {
+            dwindle::layout(window_ids, &usable_frame, gaps, split_ratios, dwindle_split)
+        }
         LayoutType::Split => split::layout_auto(window_ids, &usable_frame, gaps, split_ratios),
         LayoutType::SplitVertical => {
             split::layout_vertical(window_ids, &usable_frame, gaps, split_ratios)
@@ -196,6 +204,9 @@ mod tests {
 
     fn screen_frame() -> Rect { Rect::new(0.0, 0.0, 1920.0, 1080.0) }
 
+    /// A rotated 1080p display.
+    fn portrait_frame() -> Rect { Rect::new(0.0, 0.0, 1080.0, 1920.0) }
+
     #[test]
     fn test_calculate_layout_empty() {
         let frame = screen_frame();
@@ -287,6 +298,7 @@ mod tests {
             &gaps,
             &[],
             MasterPosition::Right,
+            DwindleSplit::Auto,
         );
 
         assert_eq!(result.len(), 2);
@@ -312,6 +324,7 @@ mod tests {
             &gaps,
             &ratios,
             MasterPosition::Auto,
+            DwindleSplit::Auto,
         );
 
         assert_eq!(result.len(), 2);
@@ -321,4 +334,59 @@ mod tests {
         assert!((w1.width - frame.width.mul_add(0.7, 0.0)).abs() < 1.0);
         assert!((w2.width - frame.width.mul_add(0.3, 0.0)).abs() < 1.0);
     }
+
+    #[test]
+    fn test_split_stacks_vertically_on_portrait_screen() {
+        let frame = portrait_frame();
+        let result = calculate_layout(LayoutType::Split, &[1, 2, 3], &frame, 0.5);
+
+        assert_eq!(result.len(), 3);
+        for (_, window) in &result {
+            assert_eq!(window.width, frame.width);
+        }
+        assert!(result[0].1.y < result[1].1.y);
+        assert!(result[1].1.y < result[2].1.y);
+    }
+
+    #[test]
+    fn test_master_is_on_top_on_portrait_screen() {
+        let frame = portrait_frame();
+        let result = calculate_layout_full(
+            LayoutType::Master,
+            &[1, 2, 3],
+            &frame,
+            0.6,
+            &Gaps::default(),
+            &[],
+            MasterPosition::Auto,
+            DwindleSplit::Auto,
+        );
+
+        assert_eq!(result.len(), 3);
+        let (_, master) = result[0];
+        assert_eq!(master.y, frame.y);
+        assert_eq!(master.width, frame.width);
+
+        // The stack sits below the master, side by side
+        for (_, window) in result.iter().skip(1) {
+            assert!(window.y >= master.y + master.height);
+        }
+        assert!(result[1].1.x < result[2].1.x);
+    }
+
+    #[test]
+    fn test_dwindle_and_grid_split_vertically_first_on_portrait_screen() {
+        let frame = portrait_frame();
+
+        for layout in [LayoutType::Dwindle, LayoutType::Grid] {
+            let result = calculate_layout(layout, &[1, 2], &frame, 0.5);
+
+            assert_eq!(result.len(), 2, "{layout:?}");
+            let (_, first) = result[0];
+            let (_, second) = result[1];
+            assert_eq!(first.width, frame.width, "{layout:?}");
+            assert_eq!(second.width, frame.width, "{layout:?}");
+            assert!(first.y < second.y, "{layout:?}");
+        }
+    }
 }
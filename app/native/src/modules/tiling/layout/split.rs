@@ -30,7 +30,7 @@ pub fn layout_auto(
     gaps: &Gaps,
     ratios: &[f64],
 ) -> LayoutResult {
-    if screen_frame.width >= screen_frame.height {
+    if screen_frame.is_landscape() {
         layout_horizontal(window_ids, screen_frame, gaps, ratios)
     } else {
         layout_vertical(window_ids, screen_frame, gaps, ratios)
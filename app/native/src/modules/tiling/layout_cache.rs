@@ -0,0 +1,273 @@
+//! On-disk cache of workspace layouts.
+//!
+//! The layout type, split ratios and whether the layout was set by hand (and
+//! so is kept from `tiling.autoLayout`) of each workspace are saved to
+//! `~/Library/Caches/{APP_BUNDLE_ID}/tiling/layouts.json` whenever they change,
+//! and restored when workspaces are created from the config at startup. This
+//! way windows reopening into a workspace land in roughly their prior
+//! proportions instead of an even split.
+//!
+//! The cache only smooths startup: a saved layout is ignored when the
+//! workspace's configured layout changed since it was saved, and restored
+//! split ratios are dropped once the initial windows are tracked if the
+//! workspace holds a different number of windows than when it was saved.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::state::{LayoutType, TilingState, Workspace};
+use crate::cache::get_cache_subdir;
+
+/// Name of the cache file inside the `tiling` cache subdirectory.
+const CACHE_FILE_NAME: &str = "layouts.json";
+
+/// Saved layout of a workspace.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedLayout {
+    /// Layout the workspace was configured with when saved.
+    pub configured_layout: LayoutType,
+    /// Layout in use when saved.
+    pub layout: LayoutType,
+    /// Split ratios in use when saved.
+    pub split_ratios: Vec<f64>,
+    /// Runtime master ratio in use when saved.
+    pub master_ratio: Option<f64>,
+    /// Number of windows the split ratios were computed for.
+    pub window_count: usize,
+    /// Whether the layout was set by hand, keeping it from `tiling.autoLayout`.
+    #[serde(default)]
+    pub layout_pinned: bool,
+}
+
+/// Saved layouts, keyed by workspace name.
+type LayoutCache = HashMap<String, PersistedLayout>;
+
+/// Layouts loaded from disk at startup.
+static LOADED: LazyLock<LayoutCache> = LazyLock::new(|| load_from(&cache_file()));
+
+/// Window count expected by each workspace with restored split ratios.
+static RESTORED: LazyLock<Mutex<HashMap<Uuid, usize>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Configured layout of each workspace created from the config.
+static CONFIGURED: LazyLock<Mutex<HashMap<String, LayoutType>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Contents of the last write, to skip writing unchanged layouts.
+static LAST_SAVED: Mutex<Option<String>> = Mutex::new(None);
+
+/// Returns the path of the cache file.
+fn cache_file() -> PathBuf { get_cache_subdir("tiling").join(CACHE_FILE_NAME) }
+
+/// Reads the cache file, returning an empty cache if it is missing or invalid.
+fn load_from(path: &std::path::Path) -> LayoutCache {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return LayoutCache::new();
+    };
+
+    serde_json::from_str(&contents).unwrap_or_else(|err| {
+        tracing::debug!(error = %err, "tiling: ignoring invalid layout cache");
+        LayoutCache::new()
+    })
+}
+
+/// Restores a workspace's saved layout, if it was saved with the same configured layout.
+///
+/// Called when a workspace is created from the config.
+pub fn restore(workspace: &mut Workspace) {
+    CONFIGURED
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(workspace.name.clone(), workspace.layout);
+
+    let Some(saved) = LOADED.get(&workspace.name) else {
+        return;
+    };
+
+    if !apply_saved(workspace, saved) {
+        return;
+    }
+
+    RESTORED
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(workspace.id, saved.window_count);
+
+    tracing::debug!(
+        "tiling: restored cached {:?} layout for workspace '{}'",
+        workspace.layout,
+        workspace.name
+    );
+}
+
+/// Applies a saved layout to a freshly created workspace.
+///
+/// Returns `false` if the configured layout changed since the layout was saved.
+fn apply_saved(workspace: &mut Workspace, saved: &PersistedLayout) -> bool {
+    if saved.configured_layout != workspace.layout {
+        return false;
+    }
+
+    workspace.layout = saved.layout;
+    workspace.split_ratios.clone_from(&saved.split_ratios);
+    workspace.master_ratio = saved.master_ratio;
+    workspace.layout_pinned = saved.layout_pinned;
+    true
+}
+
+/// Drops restored split ratios from workspaces whose window count differs
+/// from the one they were saved with.
+///
+/// Called once the windows present at startup are tracked.
+pub fn validate_restored(state: &mut TilingState) {
+    let restored: Vec<(Uuid, usize)> =
+        RESTORED.lock().unwrap_or_else(PoisonError::into_inner).drain().collect();
+
+    for (workspace_id, window_count) in restored {
+        let Some(workspace) = state.get_workspace(workspace_id) else {
+            continue;
+        };
+
+        if workspace.window_ids.len() != window_count {
+            tracing::debug!(
+                "tiling: discarding cached split ratios for workspace '{}' ({} windows, {} saved)",
+                workspace.name,
+                workspace.window_ids.len(),
+                window_count
+            );
+            state.update_workspace(workspace_id, |ws| ws.split_ratios.clear());
+        }
+    }
+}
+
+/// Builds the cache entries for the current workspaces.
+fn snapshot<'a>(
+    workspaces: impl IntoIterator<Item = &'a Workspace>,
+    configured: &HashMap<String, LayoutType>,
+) -> LayoutCache {
+    workspaces
+        .into_iter()
+        .filter_map(|ws| {
+            let configured_layout = *configured.get(&ws.name)?;
+            Some((ws.name.clone(), PersistedLayout {
+                configured_layout,
+                layout: ws.layout,
+                split_ratios: ws.split_ratios.clone(),
+                master_ratio: ws.master_ratio,
+                window_count: ws.window_ids.len(),
+                layout_pinned: ws.layout_pinned,
+            }))
+        })
+        .collect()
+}
+
+/// Saves the layouts of all workspaces created from the config.
+///
+/// Failures are logged and otherwise ignored, since the cache is optional.
+pub fn save(state: &TilingState) {
+    let cache = {
+        let configured = CONFIGURED.lock().unwrap_or_else(PoisonError::into_inner);
+        snapshot(state.workspaces.iter(), &configured)
+    };
+
+    let Ok(contents) = serde_json::to_string(&cache) else {
+        return;
+    };
+
+    let mut last_saved = LAST_SAVED.lock().unwrap_or_else(PoisonError::into_inner);
+    if last_saved.as_deref() == Some(contents.as_str()) {
+        return;
+    }
+
+    let path = cache_file();
+    let result = path
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|()| std::fs::write(&path, &contents));
+
+    match result {
+        Ok(()) => *last_saved = Some(contents),
+        Err(err) => tracing::debug!(error = %err, "tiling: failed to save layout cache"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace(name: &str, layout: LayoutType) -> Workspace {
+        Workspace {
+            name: name.to_string(),
+            layout,
+            ..Workspace::default()
+        }
+    }
+
+    fn saved(configured_layout: LayoutType) -> PersistedLayout {
+        PersistedLayout {
+            configured_layout,
+            layout: LayoutType::Split,
+            split_ratios: vec![0.3, 0.7],
+            master_ratio: Some(0.6),
+            window_count: 3,
+            layout_pinned: true,
+        }
+    }
+
+    #[test]
+    fn test_apply_saved_restores_layout_and_ratios() {
+        let mut ws = workspace("code", LayoutType::Dwindle);
+
+        assert!(apply_saved(&mut ws, &saved(LayoutType::Dwindle)));
+        assert_eq!(ws.layout, LayoutType::Split);
+        assert_eq!(ws.split_ratios, vec![0.3, 0.7]);
+        assert_eq!(ws.master_ratio, Some(0.6));
+        assert!(ws.layout_pinned);
+    }
+
+    #[test]
+    fn test_apply_saved_ignored_when_configured_layout_changed() {
+        let mut ws = workspace("code", LayoutType::Monocle);
+
+        assert!(!apply_saved(&mut ws, &saved(LayoutType::Dwindle)));
+        assert_eq!(ws.layout, LayoutType::Monocle);
+        assert!(ws.split_ratios.is_empty());
+        assert!(!ws.layout_pinned);
+    }
+
+    #[test]
+    fn test_snapshot_only_includes_configured_workspaces() {
+        let mut ws = workspace("code", LayoutType::Split);
+        ws.split_ratios = vec![0.5];
+        ws.window_ids.extend([1, 2]);
+        let workspaces = vec![ws, workspace("default-1", LayoutType::Dwindle)];
+        let configured = HashMap::from([("code".to_string(), LayoutType::Dwindle)]);
+
+        let cache = snapshot(&workspaces, &configured);
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache["code"].configured_layout, LayoutType::Dwindle);
+        assert_eq!(cache["code"].layout, LayoutType::Split);
+        assert_eq!(cache["code"].window_count, 2);
+    }
+
+    #[test]
+    fn test_load_from_round_trips_and_tolerates_bad_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(CACHE_FILE_NAME);
+
+        assert!(load_from(&path).is_empty());
+
+        let cache = LayoutCache::from([("code".to_string(), saved(LayoutType::Dwindle))]);
+        std::fs::write(&path, serde_json::to_string(&cache).unwrap()).unwrap();
+        assert_eq!(load_from(&path), cache);
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(load_from(&path).is_empty());
+    }
+}
@@ -0,0 +1,170 @@
+//! Internal counters for diagnosing tiling performance.
+//!
+//! Counters are relaxed atomics bumped on hot paths, so they cost next to
+//! nothing when nobody reads them. `stache tiling debug metrics` reads them
+//! over IPC, optionally resetting them to compare before and after a change.
+//!
+//! The durations of the most recent layout computations are kept as well,
+//! to spot pathological layouts such as long minimum size enforcement.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use serde::Serialize;
+
+use super::state::LayoutType;
+
+/// How many layout timings are kept.
+const RECENT_LAYOUTS_CAP: usize = 32;
+
+/// A counter that only goes up until it is reset.
+#[derive(Debug)]
+pub struct Counter(AtomicU64);
+
+impl Counter {
+    /// Creates a counter starting at zero.
+    const fn new() -> Self { Self(AtomicU64::new(0)) }
+
+    /// Adds one to the counter.
+    pub fn increment(&self) { self.add(1); }
+
+    /// Adds `n` to the counter.
+    pub fn add(&self, n: u64) { self.0.fetch_add(n, Ordering::Relaxed); }
+
+    /// Returns the current value, setting the counter back to zero with `reset`.
+    fn read(&self, reset: bool) -> u64 {
+        if reset {
+            self.0.swap(0, Ordering::Relaxed)
+        } else {
+            self.0.load(Ordering::Relaxed)
+        }
+    }
+}
+
+/// Accessibility attribute writes and actions issued by the effects layer.
+pub static AX_CALLS: Counter = Counter::new();
+
+/// Layouts computed by the state actor.
+pub static LAYOUTS_COMPUTED: Counter = Counter::new();
+
+/// Layout changes skipped because the computed frames matched the last applied ones.
+pub static LAYOUT_CACHE_HITS: Counter = Counter::new();
+
+/// Layout changes applied because the computed frames differed.
+pub static LAYOUT_CACHE_MISSES: Counter = Counter::new();
+
+/// Animated transitions started.
+pub static ANIMATIONS_STARTED: Counter = Counter::new();
+
+/// Messages processed by the state actor.
+pub static EVENTS_PROCESSED: Counter = Counter::new();
+
+/// Most recent layout timings, oldest first.
+static RECENT_LAYOUTS: Mutex<VecDeque<LayoutTiming>> = Mutex::new(VecDeque::new());
+
+/// Duration of one layout computation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutTiming {
+    pub workspace: String,
+    pub layout: &'static str,
+    pub windows: usize,
+    pub duration_us: u64,
+}
+
+/// Values of all counters at one point in time.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Metrics {
+    pub ax_calls: u64,
+    pub layouts_computed: u64,
+    pub layout_cache_hits: u64,
+    pub layout_cache_misses: u64,
+    pub animations_started: u64,
+    pub events_processed: u64,
+    pub recent_layouts: Vec<LayoutTiming>,
+}
+
+/// Records how long computing the layout of a workspace took.
+#[allow(clippy::cast_possible_truncation)] // u64 microseconds outlast any layout
+pub fn record_layout(workspace: &str, layout: LayoutType, windows: usize, elapsed: Duration) {
+    let timing = LayoutTiming {
+        workspace: workspace.to_string(),
+        layout: layout.as_str(),
+        windows,
+        duration_us: elapsed.as_micros() as u64,
+    };
+
+    let mut recent = RECENT_LAYOUTS.lock().unwrap_or_else(PoisonError::into_inner);
+    if recent.len() == RECENT_LAYOUTS_CAP {
+        recent.pop_front();
+    }
+    recent.push_back(timing);
+}
+
+/// Returns whether a layout computation took longer than `budget_ms`.
+///
+/// A budget of 0 never counts as exceeded.
+#[must_use]
+pub fn exceeds_budget(elapsed: Duration, budget_ms: u64) -> bool {
+    budget_ms > 0 && elapsed > Duration::from_millis(budget_ms)
+}
+
+/// Reads all counters, setting them back to zero with `reset`.
+#[must_use]
+pub fn snapshot(reset: bool) -> Metrics {
+    let mut recent = RECENT_LAYOUTS.lock().unwrap_or_else(PoisonError::into_inner);
+    let recent_layouts = if reset {
+        std::mem::take(&mut *recent).into()
+    } else {
+        recent.iter().cloned().collect()
+    };
+    drop(recent);
+
+    Metrics {
+        ax_calls: AX_CALLS.read(reset),
+        layouts_computed: LAYOUTS_COMPUTED.read(reset),
+        layout_cache_hits: LAYOUT_CACHE_HITS.read(reset),
+        layout_cache_misses: LAYOUT_CACHE_MISSES.read(reset),
+        animations_started: ANIMATIONS_STARTED.read(reset),
+        events_processed: EVENTS_PROCESSED.read(reset),
+        recent_layouts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counter_read_and_reset() {
+        let counter = Counter::new();
+        counter.increment();
+        counter.add(2);
+
+        assert_eq!(counter.read(false), 3);
+        assert_eq!(counter.read(true), 3);
+        assert_eq!(counter.read(false), 0);
+    }
+
+    #[test]
+    fn test_exceeds_budget() {
+        assert!(!exceeds_budget(Duration::from_millis(8), 8));
+        assert!(exceeds_budget(Duration::from_millis(9), 8));
+        assert!(!exceeds_budget(Duration::from_secs(10), 0));
+    }
+
+    #[test]
+    fn test_metrics_serialization() {
+        let json = serde_json::to_value(Metrics {
+            ax_calls: 4,
+            ..Default::default()
+        })
+        .unwrap();
+
+        assert_eq!(json["axCalls"], 4);
+        assert_eq!(json["layoutCacheHits"], 0);
+    }
+}
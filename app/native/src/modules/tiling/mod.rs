@@ -40,11 +40,17 @@
 pub mod actor;
 pub mod borders;
 pub mod commands;
+pub mod dnd;
 pub mod effects;
 pub mod events;
 pub mod ffi;
 pub mod init;
 pub mod layout;
+pub mod layout_cache;
+pub mod metrics;
+pub mod preview;
+pub mod reconcile;
+pub mod reload;
 pub mod rules;
 pub mod state;
 pub mod tabs;
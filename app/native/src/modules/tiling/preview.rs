@@ -0,0 +1,312 @@
+//! Layout preview overlay.
+//!
+//! With `tiling.previewOnCycle`, cycling layouts first outlines where the
+//! windows of the new layout will go, and only moves them once the outlines
+//! have been shown for `PREVIEW_DURATION`. Cycling again while a preview is
+//! shown replaces it and restarts the wait, so the windows move once, to the
+//! layout the user settles on.
+//!
+//! The outlines are drawn in a borderless non-activating panel that ignores
+//! the mouse, so the focused window keeps focus. The panel belongs to Stache,
+//! whose windows the observer never tracks.
+
+use std::cell::Cell;
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::modules::tiling::ffi::skylight::{CGPoint, CGRect, CGSize};
+use crate::modules::tiling::state::Rect;
+use crate::services::thread::{dispatch_on_main, spawn_named_thread};
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// How long the outlines are shown before the windows move.
+const PREVIEW_DURATION: Duration = Duration::from_millis(350);
+
+/// Width of the outline border, in points.
+const OUTLINE_WIDTH: f64 = 3.0;
+
+/// Corner radius of the outlines, in points.
+const OUTLINE_CORNER_RADIUS: f64 = 10.0;
+
+/// Opacity of the fill inside the outlines.
+const OUTLINE_FILL_ALPHA: f64 = 0.15;
+
+const NS_WINDOW_STYLE_MASK_BORDERLESS: u64 = 0;
+const NS_WINDOW_STYLE_MASK_NONACTIVATING_PANEL: u64 = 1 << 7;
+const NS_BACKING_STORE_BUFFERED: u64 = 2;
+const NS_FLOATING_WINDOW_LEVEL: i64 = 3;
+const NS_WINDOW_COLLECTION_BEHAVIOR_CAN_JOIN_ALL_SPACES: u64 = 1 << 0;
+const NS_WINDOW_COLLECTION_BEHAVIOR_TRANSIENT: u64 = 1 << 3;
+const NS_WINDOW_COLLECTION_BEHAVIOR_IGNORES_CYCLE: u64 = 1 << 6;
+
+type ObjcId = *mut Object;
+
+// ============================================================================
+// State
+// ============================================================================
+
+/// Bumped by every preview, so a timer only ends the preview it started.
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Layout changes waiting for the shown preview to end.
+static PENDING: Mutex<Vec<Box<dyn FnOnce() + Send>>> = Mutex::new(Vec::new());
+
+thread_local! {
+    /// The overlay panel, created on first use. Only touched on the main thread.
+    static PANEL: Cell<ObjcId> = const { Cell::new(std::ptr::null_mut()) };
+}
+
+// ============================================================================
+// Public API
+// ============================================================================
+
+/// Outlines `frames` and runs `apply` once the preview has been shown.
+///
+/// `frames` are in global screen coordinates with a top-left origin. If a
+/// newer preview starts first, `apply` runs when that one ends instead.
+/// Without frames there is nothing to show and `apply` runs right away.
+pub fn show(frames: Vec<Rect>, apply: impl FnOnce() + Send + 'static) {
+    if frames.is_empty() {
+        apply();
+        return;
+    }
+
+    PENDING.lock().unwrap_or_else(PoisonError::into_inner).push(Box::new(apply));
+    let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+
+    dispatch_on_main(move || show_panel(&frames));
+
+    spawn_named_thread("tiling-layout-preview", move || {
+        std::thread::sleep(PREVIEW_DURATION);
+        if GENERATION.load(Ordering::SeqCst) != generation {
+            return;
+        }
+
+        dispatch_on_main(move || {
+            if GENERATION.load(Ordering::SeqCst) == generation {
+                hide_panel();
+            }
+        });
+
+        let pending = std::mem::take(&mut *PENDING.lock().unwrap_or_else(PoisonError::into_inner));
+        for apply in pending {
+            apply();
+        }
+    });
+}
+
+// ============================================================================
+// Overlay Panel
+// ============================================================================
+
+/// Returns the panel frame and the outlines inside it, in Cocoa coordinates
+/// with a bottom-left origin.
+fn overlay_geometry(frames: &[Rect], primary_screen_height: f64) -> (Rect, Vec<Rect>) {
+    let bounds = frames
+        .iter()
+        .copied()
+        .reduce(|bounds, frame| bounds.union(&frame))
+        .unwrap_or_default();
+
+    let panel_frame = Rect::new(
+        bounds.x,
+        primary_screen_height - bounds.y - bounds.height,
+        bounds.width,
+        bounds.height,
+    );
+    let outlines = frames
+        .iter()
+        .map(|frame| {
+            Rect::new(
+                frame.x - bounds.x,
+                bounds.y + bounds.height - frame.y - frame.height,
+                frame.width,
+                frame.height,
+            )
+        })
+        .collect();
+
+    (panel_frame, outlines)
+}
+
+/// Converts a rect to the `NSRect` Cocoa expects.
+const fn ns_rect(rect: Rect) -> CGRect {
+    CGRect {
+        origin: CGPoint { x: rect.x, y: rect.y },
+        size: CGSize {
+            width: rect.width,
+            height: rect.height,
+        },
+    }
+}
+
+/// Returns the height of the primary screen, which global coordinates flip around.
+fn primary_screen_height() -> Option<f64> {
+    unsafe {
+        let screens: ObjcId = msg_send![class!(NSScreen), screens];
+        let primary: ObjcId = msg_send![screens, firstObject];
+        if primary.is_null() {
+            return None;
+        }
+        let frame: CGRect = msg_send![primary, frame];
+        Some(frame.size.height)
+    }
+}
+
+/// Returns the overlay panel, creating it on first use. Must be called on the main thread.
+fn panel() -> ObjcId {
+    PANEL.with(|cell| {
+        if !cell.get().is_null() {
+            return cell.get();
+        }
+
+        // SAFETY: Called on the main thread. The panel is never released, so the
+        // pointer kept in PANEL stays valid for the lifetime of the app.
+        unsafe {
+            let panel: ObjcId = msg_send![class!(NSPanel), alloc];
+            let panel: ObjcId = msg_send![panel,
+                initWithContentRect: CGRect::default()
+                styleMask: NS_WINDOW_STYLE_MASK_BORDERLESS | NS_WINDOW_STYLE_MASK_NONACTIVATING_PANEL
+                backing: NS_BACKING_STORE_BUFFERED
+                defer: true
+            ];
+            if panel.is_null() {
+                return panel;
+            }
+
+            let clear: ObjcId = msg_send![class!(NSColor), clearColor];
+            let _: () = msg_send![panel, setBackgroundColor: clear];
+            let _: () = msg_send![panel, setOpaque: false];
+            let _: () = msg_send![panel, setHasShadow: false];
+            let _: () = msg_send![panel, setIgnoresMouseEvents: true];
+            let _: () = msg_send![panel, setBecomesKeyOnlyIfNeeded: true];
+            // Stache is rarely the active app, and panels hide on deactivation by default.
+            let _: () = msg_send![panel, setHidesOnDeactivate: false];
+            let _: () = msg_send![panel, setReleasedWhenClosed: false];
+            let _: () = msg_send![panel, setLevel: NS_FLOATING_WINDOW_LEVEL];
+            let _: () = msg_send![panel, setCollectionBehavior:
+                NS_WINDOW_COLLECTION_BEHAVIOR_CAN_JOIN_ALL_SPACES
+                    | NS_WINDOW_COLLECTION_BEHAVIOR_TRANSIENT
+                    | NS_WINDOW_COLLECTION_BEHAVIOR_IGNORES_CYCLE
+            ];
+
+            cell.set(panel);
+            panel
+        }
+    })
+}
+
+/// Shows the outlines of `frames` in the overlay panel. Must be called on the main thread.
+fn show_panel(frames: &[Rect]) {
+    let Some(height) = primary_screen_height() else {
+        return;
+    };
+    let (panel_frame, outlines) = overlay_geometry(frames, height);
+
+    let panel = panel();
+    if panel.is_null() {
+        tracing::warn!("tiling: failed to create the layout preview overlay");
+        return;
+    }
+
+    // SAFETY: Called on the main thread with a valid panel. The content view and
+    // outline views are retained by their superviews before being released here.
+    unsafe {
+        let _: () = msg_send![panel, setFrame: ns_rect(panel_frame) display: false];
+
+        let content: ObjcId = msg_send![class!(NSView), alloc];
+        let content: ObjcId = msg_send![content,
+            initWithFrame: ns_rect(Rect::new(0.0, 0.0, panel_frame.width, panel_frame.height))
+        ];
+
+        let accent: ObjcId = msg_send![class!(NSColor), controlAccentColor];
+        let fill: ObjcId = msg_send![accent, colorWithAlphaComponent: OUTLINE_FILL_ALPHA];
+        let border_color: *const c_void = msg_send![accent, CGColor];
+        let fill_color: *const c_void = msg_send![fill, CGColor];
+
+        for outline in outlines {
+            let view: ObjcId = msg_send![class!(NSView), alloc];
+            let view: ObjcId = msg_send![view, initWithFrame: ns_rect(outline)];
+            let _: () = msg_send![view, setWantsLayer: true];
+            let layer: ObjcId = msg_send![view, layer];
+            let _: () = msg_send![layer, setBackgroundColor: fill_color];
+            let _: () = msg_send![layer, setBorderColor: border_color];
+            let _: () = msg_send![layer, setBorderWidth: OUTLINE_WIDTH];
+            let _: () = msg_send![layer, setCornerRadius: OUTLINE_CORNER_RADIUS];
+            let _: () = msg_send![content, addSubview: view];
+            let _: () = msg_send![view, release];
+        }
+
+        let _: () = msg_send![panel, setContentView: content];
+        let _: () = msg_send![content, release];
+
+        // Ordering front without making the panel key leaves focus where it is.
+        let _: () = msg_send![panel, orderFrontRegardless];
+    }
+}
+
+/// Hides the overlay panel. Must be called on the main thread.
+fn hide_panel() {
+    PANEL.with(|cell| {
+        let panel = cell.get();
+        if !panel.is_null() {
+            // SAFETY: Called on the main thread with the panel created by panel().
+            unsafe {
+                let _: () = msg_send![panel, orderOut: std::ptr::null_mut::<Object>()];
+            }
+        }
+    });
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlay_geometry_flips_frames_into_the_panel() {
+        // Two side-by-side windows below a 40pt bar on a 1000pt tall screen.
+        let frames = [
+            Rect::new(10.0, 50.0, 490.0, 940.0),
+            Rect::new(510.0, 50.0, 480.0, 440.0),
+        ];
+
+        let (panel_frame, outlines) = overlay_geometry(&frames, 1000.0);
+
+        assert_eq!(panel_frame, Rect::new(10.0, 10.0, 980.0, 940.0));
+        assert_eq!(outlines, vec![
+            Rect::new(0.0, 0.0, 490.0, 940.0),
+            Rect::new(500.0, 500.0, 480.0, 440.0),
+        ]);
+    }
+
+    #[test]
+    fn overlay_geometry_handles_screens_above_the_primary() {
+        let frames = [Rect::new(0.0, -900.0, 1600.0, 900.0)];
+
+        let (panel_frame, outlines) = overlay_geometry(&frames, 1000.0);
+
+        assert_eq!(panel_frame, Rect::new(0.0, 1000.0, 1600.0, 900.0));
+        assert_eq!(outlines, vec![Rect::new(0.0, 0.0, 1600.0, 900.0)]);
+    }
+
+    #[test]
+    fn panel_constants_are_valid() {
+        assert_eq!(NS_WINDOW_STYLE_MASK_NONACTIVATING_PANEL, 128);
+        assert_eq!(NS_BACKING_STORE_BUFFERED, 2);
+        assert_eq!(NS_FLOATING_WINDOW_LEVEL, 3);
+        assert_eq!(NS_WINDOW_COLLECTION_BEHAVIOR_TRANSIENT, 8);
+        assert_eq!(NS_WINDOW_COLLECTION_BEHAVIOR_IGNORES_CYCLE, 64);
+    }
+}
@@ -0,0 +1,103 @@
+//! Resyncing tracked windows with the windows that actually exist.
+//!
+//! Over a long session, tracked state can drift from reality in ways the event
+//! handlers miss: apps move their own windows, macOS relocates them, or an
+//! observer misses a destroyed window. A reconcile pass enumerates every
+//! window like at startup, and the state actor untracks vanished windows,
+//! tracks new ones, refreshes tracked frames and re-applies the layout of the
+//! affected workspaces.
+//!
+//! Passes run on demand with `stache tiling reconcile`, and periodically when
+//! `tiling.reconcileInterval` is set. The interval is read on every tick, so
+//! changing it applies without a restart.
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::ffi::window_server_ids;
+use super::init::{
+    collect_trackable_windows, get_handle, is_initialized, track_for_destroy_detection,
+};
+use crate::config::get_config;
+use crate::services::thread::spawn_named_thread;
+
+/// Delay between checks of the configured interval.
+const TICK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Runs a reconcile pass.
+///
+/// Blocks while windows are enumerated, so call it off the main thread.
+/// Returns `false` if tiling is not running or the window list can't be read.
+pub fn reconcile() -> bool {
+    if !is_initialized() {
+        return false;
+    }
+
+    let Some(handle) = get_handle() else {
+        return false;
+    };
+
+    // Without the window list, no vanished window can be told apart from one
+    // on another Space, so skip the pass
+    let Some(server_ids) = window_server_ids() else {
+        tracing::warn!("tiling: failed to read the window list, skipping reconcile");
+        return false;
+    };
+
+    let windows = collect_trackable_windows();
+    track_for_destroy_detection(&windows);
+
+    if let Err(e) = handle.reconcile(windows, server_ids) {
+        tracing::warn!("tiling: failed to reconcile windows: {e}");
+        return false;
+    }
+
+    true
+}
+
+/// Returns whether a periodic pass is due.
+///
+/// An interval of 0 disables periodic passes.
+fn is_due(last_run: Instant, now: Instant, interval_secs: u64) -> bool {
+    interval_secs > 0 && now.duration_since(last_run) >= Duration::from_secs(interval_secs)
+}
+
+/// Starts the periodic reconcile thread.
+///
+/// The thread always runs and is a no-op while `tiling.reconcileInterval` is 0.
+pub fn start() {
+    spawn_named_thread("tiling-reconcile", || {
+        let mut last_run = Instant::now();
+
+        loop {
+            thread::sleep(TICK_INTERVAL);
+
+            let now = Instant::now();
+            let interval = get_config().tiling.reconcile_interval;
+            if interval == 0 {
+                last_run = now;
+                continue;
+            }
+
+            if is_due(last_run, now, interval) {
+                tracing::debug!("tiling: running periodic reconcile");
+                reconcile();
+                last_run = now;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_due_respects_interval() {
+        let start = Instant::now();
+
+        assert!(!is_due(start, start + Duration::from_secs(59), 60));
+        assert!(is_due(start, start + Duration::from_secs(60), 60));
+        assert!(!is_due(start, start + Duration::from_secs(3600), 0));
+    }
+}
@@ -0,0 +1,374 @@
+//! Live reload of the `tiling` config section.
+//!
+//! When the config file changes, the previous and updated `TilingConfig` are
+//! diffed and the changes are applied in place by the state actor, so tracked
+//! windows, workspace assignments and runtime layouts survive the reload:
+//!
+//! - Gaps, master, dwindle or inset settings changed → visible workspaces are re-laid out
+//! - New workspaces → created on their configured screen
+//! - Changed workspace layouts → applied to the affected workspaces
+//! - Border or ignore settings changed → `JankyBorders` is reconfigured
+//!
+//! Other fields in [`LIVE_FIELDS`] are read from the config each time they are
+//! used. Changes that cannot be applied in place (toggling tiling or borders,
+//! removing a workspace or moving it to another screen, or any field missing
+//! from [`LIVE_FIELDS`]) still require a restart.
+
+use std::sync::{LazyLock, Mutex, PoisonError};
+
+use super::actor::StateMessage;
+use super::borders;
+use super::init::get_handle;
+use crate::config::{self, LayoutType, TilingConfig, WorkspaceConfig, get_config};
+
+/// Fields of the serialized `tiling` section that apply without a restart,
+/// either through [`apply`] or because they are read from the config on use.
+const LIVE_FIELDS: &[&str] = &[
+    "appInsets",
+    "autoLayout",
+    "borders",
+    "cycleIncludesFloating",
+    "defaultLayout",
+    "displayRules",
+    "dwindle",
+    "enabled",
+    "floatRules",
+    "floating",
+    "focusAfterClose",
+    "focusRetries",
+    "forceFloat",
+    "forceTile",
+    "gaps",
+    "ignore",
+    "ignoreUntitled",
+    "layoutBudget",
+    "layoutCycle",
+    "layoutToggleAlternate",
+    "master",
+    "maxWindows",
+    "moveMode",
+    "overflowWorkspace",
+    "previewOnCycle",
+    "reconcileInterval",
+    "respectExistingScreen",
+    "screenLayouts",
+    "screenRoles",
+    "verbose",
+    "workspaces",
+    "wrapStackMoves",
+];
+
+/// The tiling config that was last applied to the state actor.
+static LAST_APPLIED: LazyLock<Mutex<TilingConfig>> =
+    LazyLock::new(|| Mutex::new(get_config().tiling.clone()));
+
+/// Changes between two tiling configs.
+#[derive(Debug, Default)]
+pub struct TilingConfigDiff {
+    /// Whether visible workspaces need a new layout pass (gaps, master,
+    /// dwindle, insets or auto layouts changed).
+    pub relayout: bool,
+    /// Whether `JankyBorders` needs to be reconfigured (borders or ignore rules changed).
+    pub refresh_borders: bool,
+    /// Workspaces present only in the updated config.
+    pub added_workspaces: Vec<WorkspaceConfig>,
+    /// Existing workspaces whose effective configured layout changed.
+    pub layout_changes: Vec<(String, LayoutType)>,
+    /// Whether the change cannot be applied without restarting.
+    pub requires_restart: bool,
+}
+
+impl TilingConfigDiff {
+    /// Returns whether the diff has nothing for the state actor to apply.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        !self.relayout && self.added_workspaces.is_empty() && self.layout_changes.is_empty()
+    }
+}
+
+/// Compares two values by their serialized form.
+fn changed<T: serde::Serialize>(old: &T, new: &T) -> bool {
+    serde_json::to_value(old).ok() != serde_json::to_value(new).ok()
+}
+
+/// Returns the serialized config without the fields in [`LIVE_FIELDS`].
+fn restart_fields(config: &TilingConfig) -> serde_json::Value {
+    let mut value = serde_json::to_value(config).unwrap_or_default();
+    if let Some(map) = value.as_object_mut() {
+        map.retain(|key, _| !LIVE_FIELDS.contains(&key.as_str()));
+    }
+    value
+}
+
+/// Diffs two tiling configs.
+#[must_use]
+pub fn diff(old: &TilingConfig, new: &TilingConfig) -> TilingConfigDiff {
+    let mut result = TilingConfigDiff {
+        relayout: changed(&old.gaps, &new.gaps)
+            || changed(&old.master, &new.master)
+            || changed(&old.dwindle, &new.dwindle)
+            || changed(&old.app_insets, &new.app_insets)
+            || changed(&old.auto_layout, &new.auto_layout),
+        refresh_borders: changed(&old.borders, &new.borders) || changed(&old.ignore, &new.ignore),
+        ..TilingConfigDiff::default()
+    };
+
+    // Default workspaces (one per screen) are only created without configured ones
+    let toggles_default_workspaces = old.workspaces.is_empty() != new.workspaces.is_empty();
+
+    result.requires_restart = old.is_enabled() != new.is_enabled()
+        || old.borders.is_enabled() != new.borders.is_enabled()
+        || toggles_default_workspaces
+        || restart_fields(old) != restart_fields(new);
+
+    for old_ws in &old.workspaces {
+        match new.workspaces.iter().find(|ws| ws.name == old_ws.name) {
+            Some(new_ws) if new_ws.screen != old_ws.screen => result.requires_restart = true,
+            Some(new_ws) => {
+                let old_layout = old_ws.layout.unwrap_or(old.default_layout);
+                let new_layout = new_ws.layout.unwrap_or(new.default_layout);
+                if old_layout != new_layout {
+                    result.layout_changes.push((new_ws.name.clone(), new_layout));
+                }
+            }
+            None => result.requires_restart = true,
+        }
+    }
+
+    result.added_workspaces = new
+        .workspaces
+        .iter()
+        .filter(|ws| !old.workspaces.iter().any(|old_ws| old_ws.name == ws.name))
+        .cloned()
+        .collect();
+
+    result
+}
+
+/// Applies an updated tiling config to the running tiling manager.
+///
+/// Returns `false` if the change requires a restart.
+pub fn apply(updated: &TilingConfig) -> bool {
+    let mut last_applied = LAST_APPLIED.lock().unwrap_or_else(PoisonError::into_inner);
+    let diff = diff(&last_applied, updated);
+
+    if diff.requires_restart {
+        tracing::info!("tiling: config change requires a restart");
+        return false;
+    }
+
+    crate::logging::set_tiling_verbose(updated.verbose);
+
+    if diff.refresh_borders {
+        borders::refresh();
+    }
+
+    if !diff.is_empty() {
+        let Some(handle) = get_handle() else {
+            return false;
+        };
+
+        let message = StateMessage::ApplyConfigChanges {
+            added_workspaces: diff.added_workspaces,
+            layout_changes: diff.layout_changes,
+            relayout: diff.relayout,
+        };
+
+        if let Err(e) = handle.send(message) {
+            tracing::warn!("tiling: failed to apply config changes: {e}");
+            return false;
+        }
+    }
+
+    *last_applied = updated.clone();
+    true
+}
+
+/// Registers the `tiling` section for live reload.
+///
+/// Called once the tiling manager is initialized.
+pub fn register() {
+    LazyLock::force(&LAST_APPLIED);
+    config::register_live_reload("tiling", |config| apply(&config.tiling));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{GapValue, GapsConfig, GapsConfigValue};
+
+    fn workspace(name: &str, screen: &str, layout: Option<LayoutType>) -> WorkspaceConfig {
+        WorkspaceConfig {
+            name: name.to_string(),
+            layout,
+            screen: screen.to_string(),
+            rules: Vec::new(),
+            ignore: None,
+            preset_on_open: None,
+            dnd: false,
+        }
+    }
+
+    fn config_with_workspaces(workspaces: Vec<WorkspaceConfig>) -> TilingConfig {
+        TilingConfig {
+            enabled: true,
+            workspaces,
+            ..TilingConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_diff_identical_configs_is_empty() {
+        let config = config_with_workspaces(vec![workspace("code", "main", None)]);
+        let diff = diff(&config, &config.clone());
+
+        assert!(diff.is_empty());
+        assert!(!diff.requires_restart);
+    }
+
+    #[test]
+    fn test_diff_gaps_changed_triggers_relayout() {
+        let old = config_with_workspaces(vec![workspace("code", "main", None)]);
+        let mut new = old.clone();
+        new.gaps = GapsConfigValue::Global(GapsConfig {
+            inner: GapValue::Uniform(24),
+            ..GapsConfig::default()
+        });
+
+        let diff = diff(&old, &new);
+
+        assert!(diff.relayout);
+        assert!(!diff.requires_restart);
+        assert!(diff.added_workspaces.is_empty());
+        assert!(diff.layout_changes.is_empty());
+    }
+
+    #[test]
+    fn test_diff_master_ratio_changed_triggers_relayout() {
+        let old = config_with_workspaces(vec![workspace("code", "main", None)]);
+        let mut new = old.clone();
+        new.master.ratio = old.master.ratio + 10;
+
+        assert!(diff(&old, &new).relayout);
+    }
+
+    #[test]
+    fn test_diff_dwindle_changed_triggers_relayout() {
+        let old = config_with_workspaces(vec![workspace("code", "main", None)]);
+        let mut new = old.clone();
+        new.dwindle.first_split = crate::config::DwindleSplit::Vertical;
+
+        assert!(diff(&old, &new).relayout);
+    }
+
+    #[test]
+    fn test_diff_border_changes_refresh_borders() {
+        let old = config_with_workspaces(vec![workspace("code", "main", None)]);
+        let mut new = old.clone();
+        new.borders.style = Some("square".to_string());
+
+        assert!(!diff(&old, &old.clone()).refresh_borders);
+
+        let diff = diff(&old, &new);
+
+        assert!(diff.refresh_borders);
+        assert!(!diff.requires_restart);
+    }
+
+    #[test]
+    fn test_restart_fields_skips_live_fields() {
+        let old = config_with_workspaces(vec![workspace("code", "main", None)]);
+        let mut new = old.clone();
+        new.focus_retries = old.focus_retries + 1;
+        new.layout_cycle = vec![LayoutType::Monocle];
+
+        assert_eq!(restart_fields(&old), restart_fields(&new));
+        assert!(!diff(&old, &new).requires_restart);
+    }
+
+    #[test]
+    fn test_diff_detects_added_workspaces() {
+        let old = config_with_workspaces(vec![workspace("code", "main", None)]);
+        let new = config_with_workspaces(vec![
+            workspace("code", "main", None),
+            workspace("web", "secondary", None),
+        ]);
+
+        let diff = diff(&old, &new);
+
+        assert!(!diff.requires_restart);
+        assert_eq!(diff.added_workspaces.len(), 1);
+        assert_eq!(diff.added_workspaces[0].name, "web");
+    }
+
+    #[test]
+    fn test_diff_detects_layout_changes() {
+        let old = config_with_workspaces(vec![
+            workspace("code", "main", None),
+            workspace("web", "main", Some(LayoutType::Monocle)),
+        ]);
+        let mut new = old.clone();
+        new.workspaces[1].layout = Some(LayoutType::Master);
+
+        let diff = diff(&old, &new);
+
+        assert_eq!(diff.layout_changes, vec![(
+            "web".to_string(),
+            LayoutType::Master
+        )]);
+        assert!(!diff.relayout);
+    }
+
+    #[test]
+    fn test_diff_default_layout_applies_to_unset_workspaces() {
+        let old = config_with_workspaces(vec![
+            workspace("code", "main", None),
+            workspace("web", "main", Some(LayoutType::Monocle)),
+        ]);
+        let mut new = old.clone();
+        new.default_layout = LayoutType::Master;
+
+        // Only the workspace without an explicit layout follows the default
+        assert_eq!(diff(&old, &new).layout_changes, vec![(
+            "code".to_string(),
+            LayoutType::Master
+        )]);
+    }
+
+    #[test]
+    fn test_diff_animation_change_requires_restart() {
+        let old = config_with_workspaces(vec![workspace("code", "main", None)]);
+        let mut new = old.clone();
+        new.animations.enabled = !old.animations.enabled;
+
+        assert!(diff(&old, &new).requires_restart);
+    }
+
+    #[test]
+    fn test_diff_removed_workspace_requires_restart() {
+        let old = config_with_workspaces(vec![
+            workspace("code", "main", None),
+            workspace("web", "main", None),
+        ]);
+        let new = config_with_workspaces(vec![workspace("code", "main", None)]);
+
+        assert!(diff(&old, &new).requires_restart);
+    }
+
+    #[test]
+    fn test_diff_moved_workspace_requires_restart() {
+        let old = config_with_workspaces(vec![workspace("code", "main", None)]);
+        let new = config_with_workspaces(vec![workspace("code", "secondary", None)]);
+
+        assert!(diff(&old, &new).requires_restart);
+    }
+
+    #[test]
+    fn test_diff_toggling_tiling_requires_restart() {
+        let old = config_with_workspaces(vec![workspace("code", "main", None)]);
+        let mut new = old.clone();
+        new.enabled = false;
+
+        assert!(diff(&old, &new).requires_restart);
+    }
+}
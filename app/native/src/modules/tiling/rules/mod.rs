@@ -18,7 +18,9 @@
 //! // Matches: Safari windows with "Settings" in title (AND logic)
 //! ```
 
-use crate::config::WindowRule;
+use serde::Serialize;
+
+use crate::config::{TilingConfig, WindowRule};
 use crate::modules::tiling::state::Window;
 
 /// Checks if a window matches a rule.
@@ -31,6 +33,7 @@ use crate::modules::tiling::state::Window;
 /// - `app_id`: Exact match against bundle identifier (case-insensitive)
 /// - `app_name`: Case-insensitive substring match
 /// - `title`: Case-insensitive substring match
+/// - `subrole`: Exact match against the accessibility subrole (case-insensitive)
 #[must_use]
 pub fn matches_window(rule: &WindowRule, window: &Window) -> bool {
     // Rule must have at least one criterion
@@ -85,6 +88,16 @@ pub fn matches_window(rule: &WindowRule, window: &Window) -> bool {
         }
     }
 
+    // Check subrole - case-insensitive exact match
+    if rule.subrole.as_ref().is_some_and(|subrole| {
+        !window
+            .subrole
+            .as_ref()
+            .is_some_and(|window_subrole| window_subrole.eq_ignore_ascii_case(subrole))
+    }) {
+        return false;
+    }
+
     true
 }
 
@@ -148,6 +161,125 @@ pub fn count_matching_rules(rules: &[WindowRule], window: &Window) -> usize {
     rules.iter().filter(|rule| matches_window(rule, window)).count()
 }
 
+// ============================================================================
+// Rule Explanation
+// ============================================================================
+
+/// A rule checked against a window, for `stache tiling explain`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvaluatedRule {
+    /// Index of the rule within its list.
+    pub index: usize,
+    /// The rule as configured.
+    pub rule: WindowRule,
+    /// Whether the rule matches the window.
+    pub matched: bool,
+}
+
+/// A workspace rule matching a window.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceRuleMatch {
+    /// Name of the workspace the rule belongs to.
+    pub workspace: String,
+    /// Index of the rule within the workspace's rules.
+    pub rule_index: usize,
+    /// The rule as configured.
+    pub rule: WindowRule,
+}
+
+/// A display rule matching a window.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DisplayRuleMatch {
+    /// Index of the rule within `tiling.displayRules`.
+    pub index: usize,
+    /// Display the rule pins the window to.
+    pub screen: String,
+}
+
+/// How the configured rules apply to a tracked window.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleExplanation {
+    /// Ignore rules in effect for the window's workspace.
+    pub ignore_rules: Vec<EvaluatedRule>,
+    /// How many of the ignore rules match the window.
+    pub ignore_matches: usize,
+    /// First display rule matching the window.
+    pub display_rule: Option<DisplayRuleMatch>,
+    /// First workspace rule matching the window.
+    pub workspace_rule: Option<WorkspaceRuleMatch>,
+    /// How the window got to its workspace when its rule did not put it there.
+    pub fallback: Option<&'static str>,
+}
+
+/// Explains which rules match a window tracked on the workspace `workspace`.
+///
+/// Rules are evaluated as when the window was first tracked, except ignore
+/// rules, which are those in effect for the workspace the window is on now.
+#[must_use]
+pub fn explain_window(window: &Window, workspace: &str, tiling: &TilingConfig) -> RuleExplanation {
+    let ignore = tiling.ignore_rules_for(workspace);
+    let ignore_rules = ignore
+        .iter()
+        .enumerate()
+        .map(|(index, rule)| EvaluatedRule {
+            index,
+            rule: rule.clone(),
+            matched: matches_window(rule, window),
+        })
+        .collect();
+
+    let display_rule = tiling
+        .display_rules
+        .iter()
+        .enumerate()
+        .find(|(_, r)| matches_window(&r.rule, window))
+        .map(|(index, r)| DisplayRuleMatch {
+            index,
+            screen: r.screen.clone(),
+        });
+
+    let workspace_rule = find_matching_workspace(
+        window,
+        tiling.workspaces.iter().map(|ws| (ws.name.as_str(), ws.rules.as_slice())),
+    )
+    .map(|m| WorkspaceRuleMatch {
+        rule: tiling
+            .workspaces
+            .iter()
+            .find(|ws| ws.name == m.workspace_name)
+            .map(|ws| ws.rules[m.rule_index].clone())
+            .unwrap_or_default(),
+        workspace: m.workspace_name,
+        rule_index: m.rule_index,
+    });
+
+    let fallback = match &workspace_rule {
+        Some(m) if m.workspace == workspace => None,
+        Some(_) => Some(
+            "the rule's workspace was skipped: it is on another display, ignores the window, or \
+             the window was moved since",
+        ),
+        None if display_rule.is_some() => Some("visible workspace of the pinned display"),
+        None if tiling.respect_existing_screen => Some(
+            "workspace focused when the window appeared, or the visible workspace of its display \
+             if it was open at startup",
+        ),
+        None => Some("workspace focused when the window appeared"),
+    };
+
+    RuleExplanation {
+        ignore_matches: count_matching_rules(ignore, window),
+        ignore_rules,
+        display_rule,
+        workspace_rule,
+        fallback,
+    }
+}
+
 // ============================================================================
 // Window Filtering
 // ============================================================================
@@ -263,7 +395,9 @@ mod tests {
             app_id: bundle_id.to_string(),
             app_name: app_name.to_string(),
             title: title.to_string(),
+            subrole: None,
             workspace_id: Uuid::now_v7(),
+            extra_workspace_ids: Vec::new(),
             frame: Rect::new(0.0, 0.0, 800.0, 600.0),
             minimum_size: None,
             inferred_minimum_size: None,
@@ -271,6 +405,11 @@ mod tests {
             is_minimized: false,
             is_fullscreen: false,
             is_floating: false,
+            float_frame: None,
+            always_on_top: false,
+            opacity: None,
+            non_resizable: false,
+            awaiting_frame: false,
             is_hidden: false,
             tab_group_id: None,
             is_active_tab: true,
@@ -284,6 +423,7 @@ mod tests {
             app_id: app_id.map(String::from),
             app_name: app_name.map(String::from),
             title: title.map(String::from),
+            subrole: None,
             app_id_lower: None,
             app_name_lower: None,
             title_lower: None,
@@ -402,6 +542,24 @@ mod tests {
         assert!(!matches_window(&rule, &window));
     }
 
+    #[test]
+    fn test_matches_window_subrole() {
+        let mut window = make_window("com.apple.safari", "Safari", "Settings");
+        let rule = WindowRule {
+            subrole: Some("AXDialog".to_string()),
+            ..Default::default()
+        };
+
+        // Windows without a known subrole never match a subrole rule
+        assert!(!matches_window(&rule, &window));
+
+        window.subrole = Some("axdialog".to_string());
+        assert!(matches_window(&rule, &window));
+
+        window.subrole = Some("AXStandardWindow".to_string());
+        assert!(!matches_window(&rule, &window));
+    }
+
     // ========================================================================
     // find_matching_workspace tests
     // ========================================================================
@@ -527,6 +685,52 @@ mod tests {
         assert!(!should_skip_app_by_name(""));
     }
 
+    #[test]
+    fn test_explain_window_reports_rule_and_fallback() {
+        use crate::config::WorkspaceConfig;
+
+        let workspace = |name: &str, rules: Vec<WindowRule>| WorkspaceConfig {
+            name: name.to_string(),
+            layout: None,
+            screen: "main".to_string(),
+            rules,
+            ignore: None,
+            preset_on_open: None,
+            dnd: false,
+        };
+        let window = make_window("com.tinyspeck.slackmacgap", "Slack", "general");
+        let tiling = TilingConfig {
+            workspaces: vec![
+                workspace("code", vec![make_rule(Some("com.microsoft.VSCode"), None, None)]),
+                workspace("chat", vec![
+                    make_rule(None, Some("Discord"), None),
+                    make_rule(Some("com.tinyspeck.slackmacgap"), None, None),
+                ]),
+            ],
+            ignore: vec![make_rule(None, None, Some("Huddle"))],
+            ..Default::default()
+        };
+
+        let explanation = explain_window(&window, "chat", &tiling);
+        let rule = explanation.workspace_rule.unwrap();
+        assert_eq!(rule.workspace, "chat");
+        assert_eq!(rule.rule_index, 1);
+        assert_eq!(explanation.ignore_rules.len(), 1);
+        assert_eq!(explanation.ignore_matches, 0);
+        assert!(explanation.fallback.is_none());
+
+        let explanation = explain_window(&window, "code", &tiling);
+        assert!(explanation.fallback.is_some());
+
+        let other = make_window("com.apple.finder", "Finder", "Downloads");
+        let explanation = explain_window(&other, "code", &tiling);
+        assert!(explanation.workspace_rule.is_none());
+        assert_eq!(
+            explanation.fallback,
+            Some("workspace focused when the window appeared")
+        );
+    }
+
     #[test]
     fn test_is_pip_window() {
         // PiP windows have subrole AXFloatingWindow
@@ -7,5 +7,7 @@
 mod tiling_state;
 mod types;
 
-pub use tiling_state::TilingState;
-pub use types::{FocusState, LayoutType, Rect, Screen, Window, WindowIdList, Workspace};
+pub use tiling_state::{MoveModeSession, TilingState};
+pub use types::{
+    FocusState, LayoutType, RecentApp, Rect, SavedLayout, Screen, Window, WindowIdList, Workspace,
+};
@@ -10,12 +10,52 @@
 //! directly manipulating the vectors.
 
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 use eyeball::Observable;
 use eyeball_im::ObservableVector;
 use uuid::Uuid;
 
-use super::types::{FocusState, Screen, Window, Workspace};
+use super::types::{FocusState, RecentApp, Rect, Screen, Window, Workspace};
+
+/// How long after the last MRU step a walk through the focus history ends.
+pub const FOCUS_MRU_COMMIT_DELAY: Duration = Duration::from_secs(1);
+
+/// An alt-tab style walk through a workspace's focus history.
+///
+/// The order is captured when the walk starts, so repeated steps keep going
+/// back instead of bouncing between the two most recent windows.
+#[derive(Debug, Clone)]
+struct FocusMruWalk {
+    workspace_id: Uuid,
+    order: Vec<u32>,
+    index: usize,
+    last_step: Instant,
+}
+
+/// A keyboard move mode session, started with `stache tiling window --move-mode`.
+///
+/// Holds what the window looked like when the session started, so cancelling
+/// can put it back.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoveModeSession {
+    pub window_id: u32,
+    pub workspace_id: Uuid,
+    /// Frame of the window when the session started.
+    pub frame: Rect,
+    /// Window order of the workspace when the session started.
+    pub window_ids: Vec<u32>,
+}
+
+impl FocusMruWalk {
+    /// Returns the window currently selected by the walk.
+    fn selected(&self) -> u32 { self.order[self.index] }
+
+    /// Returns whether the walk is still going at `now`.
+    fn is_active(&self, now: Instant) -> bool {
+        now.duration_since(self.last_step) < FOCUS_MRU_COMMIT_DELAY
+    }
+}
 
 /// The root state container for the tiling window manager.
 ///
@@ -51,9 +91,37 @@ pub struct TilingState {
     /// Whether tiling is enabled.
     pub enabled: Observable<bool>,
 
-    /// Focus history: remembers the last focused window in each workspace.
-    /// Maps `workspace_id` -> `window_id`.
-    focus_history: HashMap<Uuid, u32>,
+    /// Whether window management is paused.
+    ///
+    /// While paused, windows are still tracked but layouts are not applied.
+    pub paused: Observable<bool>,
+
+    /// Focus history: the windows of each workspace, most recently focused first.
+    /// Maps `workspace_id` -> `window_id`s.
+    focus_history: HashMap<Uuid, Vec<u32>>,
+
+    /// Walk through the focus history in progress, if any.
+    focus_mru_walk: Option<FocusMruWalk>,
+
+    /// Focus history across all workspaces, most recently focused first.
+    global_focus_history: Vec<u32>,
+
+    /// Activated apps, most recently activated first.
+    app_history: Vec<RecentApp>,
+
+    /// Minimized windows of each workspace, most recently minimized last.
+    /// Maps `workspace_id` -> `window_id`s.
+    minimized_stacks: HashMap<Uuid, Vec<u32>>,
+
+    /// Number of times each window refused to resize to its layout frame.
+    resize_refusals: HashMap<u32, u8>,
+
+    /// Runtime gap adjustments layered over the config, in pixels.
+    /// Maps `screen_id` -> (inner, outer).
+    gap_adjustments: HashMap<u32, (f64, f64)>,
+
+    /// Keyboard move mode session in progress, if any.
+    move_mode: Option<MoveModeSession>,
 
     // ════════════════════════════════════════════════════════════════════════
     // Auxiliary Indices for O(1) Lookups
@@ -82,7 +150,15 @@ impl TilingState {
             windows: ObservableVector::new(),
             focus: Observable::new(FocusState::new()),
             enabled: Observable::new(true),
+            paused: Observable::new(false),
             focus_history: HashMap::new(),
+            focus_mru_walk: None,
+            global_focus_history: Vec::new(),
+            app_history: Vec::new(),
+            minimized_stacks: HashMap::new(),
+            resize_refusals: HashMap::new(),
+            gap_adjustments: HashMap::new(),
+            move_mode: None,
             screen_idx: HashMap::new(),
             workspace_idx: HashMap::new(),
             window_idx: HashMap::new(),
@@ -111,6 +187,12 @@ impl TilingState {
         self.screens.iter().find(|s| s.is_main).cloned()
     }
 
+    /// Get the screen assigned a role in `tiling.screenRoles`.
+    #[must_use]
+    pub fn get_screen_with_role(&self, role: &str) -> Option<&Screen> {
+        self.screens.iter().find(|s| s.has_role(role))
+    }
+
     /// Get the index of a screen by ID. O(1) via index.
     #[must_use]
     pub fn screen_index(&self, id: u32) -> Option<usize> { self.screen_idx.get(&id).copied() }
@@ -176,6 +258,12 @@ impl TilingState {
         self.workspaces.iter().filter(|w| w.screen_id == screen_id).cloned().collect()
     }
 
+    /// Get the Nth workspace of a screen (0-based), in config order. O(n).
+    #[must_use]
+    pub fn get_workspace_on_screen_at(&self, screen_id: u32, index: usize) -> Option<Workspace> {
+        self.workspaces.iter().filter(|w| w.screen_id == screen_id).nth(index).cloned()
+    }
+
     /// Get all visible workspaces. O(n).
     #[must_use]
     pub fn get_visible_workspaces(&self) -> Vec<Workspace> {
@@ -251,11 +339,7 @@ impl TilingState {
     /// Get all windows for a workspace. O(n).
     #[must_use]
     pub fn get_windows_for_workspace(&self, workspace_id: Uuid) -> Vec<Window> {
-        self.windows
-            .iter()
-            .filter(|w| w.workspace_id == workspace_id)
-            .cloned()
-            .collect()
+        self.windows.iter().filter(|w| w.belongs_to(workspace_id)).cloned().collect()
     }
 
     /// Get all windows for an application (by PID). O(n).
@@ -286,11 +370,37 @@ impl TilingState {
     pub fn get_layoutable_windows(&self, workspace_id: Uuid) -> Vec<Window> {
         self.windows
             .iter()
-            .filter(|w| w.workspace_id == workspace_id && w.is_layoutable())
+            .filter(|w| w.is_layoutable() && self.layout_workspace_of(w) == workspace_id)
             .cloned()
             .collect()
     }
 
+    /// Get the workspace a window is laid out in.
+    ///
+    /// That is its assigned workspace, unless the window is also shown in
+    /// other workspaces and only those are visible. Then it is the first
+    /// visible one, so a window is never laid out twice.
+    #[must_use]
+    pub fn layout_workspace_of(&self, window: &Window) -> Uuid {
+        let is_visible = |id: &Uuid| {
+            self.workspace_idx
+                .get(id)
+                .and_then(|&idx| self.workspaces.get(idx))
+                .is_some_and(|ws| ws.is_visible)
+        };
+
+        if window.extra_workspace_ids.is_empty() || is_visible(&window.workspace_id) {
+            return window.workspace_id;
+        }
+
+        window
+            .extra_workspace_ids
+            .iter()
+            .copied()
+            .find(is_visible)
+            .unwrap_or(window.workspace_id)
+    }
+
     /// Get the index of a window by ID. O(1) via index.
     #[must_use]
     pub fn window_index(&self, id: u32) -> Option<usize> { self.window_idx.get(&id).copied() }
@@ -311,6 +421,7 @@ impl TilingState {
     pub fn remove_window(&mut self, id: u32) -> Option<Window> {
         let idx = self.window_idx.remove(&id)?;
         let window = self.windows.remove(idx);
+        self.resize_refusals.remove(&id);
 
         // Update indices for all windows that shifted down
         for (&win_id, stored_idx) in &mut self.window_idx {
@@ -350,6 +461,11 @@ impl TilingState {
         workspace_id: Option<Uuid>,
         screen_id: Option<u32>,
     ) {
+        if let Some(window_id) = window_id {
+            self.global_focus_history.retain(|&id| id != window_id);
+            self.global_focus_history.insert(0, window_id);
+        }
+
         Observable::set(&mut self.focus, FocusState {
             focused_window_id: window_id,
             focused_workspace_id: workspace_id,
@@ -404,16 +520,27 @@ impl TilingState {
     /// Set whether tiling is enabled.
     pub fn set_enabled(&mut self, enabled: bool) { Observable::set(&mut self.enabled, enabled); }
 
+    /// Check if window management is paused.
+    #[must_use]
+    pub fn is_paused(&self) -> bool { *Observable::get(&self.paused) }
+
+    /// Set whether window management is paused.
+    pub fn set_paused(&mut self, paused: bool) { Observable::set(&mut self.paused, paused); }
+
     // ========================================================================
     // Focus History
     // ========================================================================
 
-    /// Record the last focused window for a workspace.
+    /// Record a window as the most recently focused one of a workspace.
     ///
     /// Call this before switching away from a workspace to remember which
-    /// window was focused there.
+    /// window was focused there. A window is only kept in the history of the
+    /// workspace it was last recorded for.
     pub fn record_focus_history(&mut self, workspace_id: Uuid, window_id: u32) {
-        self.focus_history.insert(workspace_id, window_id);
+        for history in self.focus_history.values_mut() {
+            history.retain(|&id| id != window_id);
+        }
+        self.focus_history.entry(workspace_id).or_default().insert(0, window_id);
     }
 
     /// Get the last focused window for a workspace.
@@ -422,16 +549,209 @@ impl TilingState {
     /// if one was recorded and the window still exists.
     #[must_use]
     pub fn get_focus_history(&self, workspace_id: Uuid) -> Option<u32> {
-        self.focus_history.get(&workspace_id).copied()
+        self.get_focus_mru(workspace_id).first().copied()
     }
 
+    /// Get the focus history of a workspace, most recently focused first.
+    #[must_use]
+    pub fn get_focus_mru(&self, workspace_id: Uuid) -> &[u32] {
+        self.focus_history.get(&workspace_id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Get the focus history across all workspaces, most recently focused first.
+    ///
+    /// Updated on every [`set_focus`](Self::set_focus) with a window.
+    #[must_use]
+    pub fn get_global_focus_mru(&self) -> &[u32] { &self.global_focus_history }
+
     /// Remove a window from all focus history entries.
     ///
     /// Call this when a window is destroyed to clean up stale references.
     pub fn remove_window_from_focus_history(&mut self, window_id: u32) {
-        self.focus_history.retain(|_, &mut id| id != window_id);
+        for history in self.focus_history.values_mut() {
+            history.retain(|&id| id != window_id);
+        }
+        self.focus_history.retain(|_, history| !history.is_empty());
+        self.global_focus_history.retain(|&id| id != window_id);
+
+        if self.focus_mru_walk.as_ref().is_some_and(|walk| walk.order.contains(&window_id)) {
+            self.focus_mru_walk = None;
+        }
     }
 
+    /// Record an app as the most recently activated one.
+    pub fn record_app_activation(&mut self, app: RecentApp) {
+        self.app_history.retain(|recent| recent.pid != app.pid);
+        self.app_history.insert(0, app);
+    }
+
+    /// Get the activated apps, most recently activated first.
+    #[must_use]
+    pub fn get_app_mru(&self) -> &[RecentApp] { &self.app_history }
+
+    /// Remove an app from the activation history.
+    ///
+    /// Call this when an app terminates.
+    pub fn remove_app_from_history(&mut self, pid: i32) {
+        self.app_history.retain(|recent| recent.pid != pid);
+    }
+
+    /// Record that a window received focus.
+    ///
+    /// Focus landing on the window selected by an ongoing MRU walk leaves the
+    /// history untouched, so the walk can keep going back. Any other focus
+    /// change ends the walk first.
+    pub fn record_window_focus(&mut self, workspace_id: Uuid, window_id: u32, now: Instant) {
+        if self.focus_mru_walk.as_ref().is_some_and(|walk| {
+            walk.workspace_id == workspace_id && walk.selected() == window_id && walk.is_active(now)
+        }) {
+            return;
+        }
+
+        self.commit_focus_mru();
+        self.record_focus_history(workspace_id, window_id);
+    }
+
+    /// Step `steps` windows back through a workspace's focus history.
+    ///
+    /// A step within [`FOCUS_MRU_COMMIT_DELAY`] of the previous one continues
+    /// the same walk; otherwise a new walk starts from `current`. Only
+    /// `candidates` can be selected; candidates missing from the history come
+    /// last, in the given order.
+    ///
+    /// Returns the selected window, or `None` if there is nothing to switch to.
+    pub fn step_focus_mru(
+        &mut self,
+        workspace_id: Uuid,
+        current: Option<u32>,
+        candidates: &[u32],
+        steps: usize,
+        now: Instant,
+    ) -> Option<u32> {
+        let continues = self
+            .focus_mru_walk
+            .as_ref()
+            .is_some_and(|walk| walk.workspace_id == workspace_id && walk.is_active(now));
+
+        if !continues {
+            self.commit_focus_mru();
+
+            let mut order: Vec<u32> =
+                current.filter(|id| candidates.contains(id)).into_iter().collect();
+            for &id in self.get_focus_mru(workspace_id).iter().chain(candidates) {
+                if candidates.contains(&id) && !order.contains(&id) {
+                    order.push(id);
+                }
+            }
+
+            if order.len() < 2 {
+                return None;
+            }
+
+            self.focus_mru_walk = Some(FocusMruWalk {
+                workspace_id,
+                order,
+                index: 0,
+                last_step: now,
+            });
+        }
+
+        let walk = self.focus_mru_walk.as_mut()?;
+        walk.index = (walk.index + steps) % walk.order.len();
+        walk.last_step = now;
+        Some(walk.selected())
+    }
+
+    /// End the MRU walk in progress, moving the selected window to the front
+    /// of its workspace's focus history.
+    pub fn commit_focus_mru(&mut self) {
+        if let Some(walk) = self.focus_mru_walk.take() {
+            self.record_focus_history(walk.workspace_id, walk.selected());
+        }
+    }
+
+    // ========================================================================
+    // Minimized Windows
+    // ========================================================================
+
+    /// Record a window as the most recently minimized one of a workspace.
+    pub fn push_minimized(&mut self, workspace_id: Uuid, window_id: u32) {
+        self.remove_minimized(window_id);
+        self.minimized_stacks.entry(workspace_id).or_default().push(window_id);
+    }
+
+    /// Remove a window from the minimized stacks.
+    ///
+    /// Call this when a window is restored or destroyed.
+    pub fn remove_minimized(&mut self, window_id: u32) {
+        for stack in self.minimized_stacks.values_mut() {
+            stack.retain(|&id| id != window_id);
+        }
+        self.minimized_stacks.retain(|_, stack| !stack.is_empty());
+    }
+
+    /// Get the minimized windows of a workspace, most recently minimized last.
+    #[must_use]
+    pub fn get_minimized_stack(&self, workspace_id: Uuid) -> &[u32] {
+        self.minimized_stacks.get(&workspace_id).map_or(&[], Vec::as_slice)
+    }
+
+    // ========================================================================
+    // Resize Refusals
+    // ========================================================================
+
+    /// Record that a window refused to resize, returning how often it did so.
+    pub fn record_resize_refusal(&mut self, window_id: u32) -> u8 {
+        let count = self.resize_refusals.entry(window_id).or_default();
+        *count = count.saturating_add(1);
+        *count
+    }
+
+    /// Forget the refusals recorded for a window.
+    pub fn clear_resize_refusals(&mut self, window_id: u32) {
+        self.resize_refusals.remove(&window_id);
+    }
+
+    // ========================================================================
+    // Gap Adjustments
+    // ========================================================================
+
+    /// Get the (inner, outer) gap adjustment of a screen, in pixels.
+    #[must_use]
+    pub fn get_gap_adjustment(&self, screen_id: u32) -> (f64, f64) {
+        self.gap_adjustments.get(&screen_id).copied().unwrap_or_default()
+    }
+
+    /// Set the (inner, outer) gap adjustment of a screen, in pixels.
+    ///
+    /// An adjustment of zero on both drops the entry.
+    pub fn set_gap_adjustment(&mut self, screen_id: u32, inner: f64, outer: f64) {
+        if inner == 0.0 && outer == 0.0 {
+            self.gap_adjustments.remove(&screen_id);
+        } else {
+            self.gap_adjustments.insert(screen_id, (inner, outer));
+        }
+    }
+
+    /// Drop all gap adjustments, returning the screens that had one.
+    pub fn clear_gap_adjustments(&mut self) -> Vec<u32> {
+        self.gap_adjustments.drain().map(|(screen_id, _)| screen_id).collect()
+    }
+
+    // ========================================================================
+    // Move Mode
+    // ========================================================================
+
+    /// Get the keyboard move mode session in progress, if any.
+    #[must_use]
+    pub const fn move_mode(&self) -> Option<&MoveModeSession> { self.move_mode.as_ref() }
+
+    /// Start a keyboard move mode session, replacing any previous one.
+    pub fn begin_move_mode(&mut self, session: MoveModeSession) { self.move_mode = Some(session); }
+
+    /// End the keyboard move mode session, returning it if one was in progress.
+    pub const fn end_move_mode(&mut self) -> Option<MoveModeSession> { self.move_mode.take() }
+
     // ========================================================================
     // ID-Only Queries (Zero-Clone)
     // ========================================================================
@@ -457,7 +777,7 @@ impl TilingState {
     pub fn get_window_ids_for_workspace(&self, workspace_id: Uuid) -> Vec<u32> {
         self.windows
             .iter()
-            .filter(|w| w.workspace_id == workspace_id)
+            .filter(|w| w.belongs_to(workspace_id))
             .map(|w| w.id)
             .collect()
     }
@@ -467,7 +787,7 @@ impl TilingState {
     pub fn get_layoutable_window_ids(&self, workspace_id: Uuid) -> Vec<u32> {
         self.windows
             .iter()
-            .filter(|w| w.workspace_id == workspace_id && w.is_layoutable())
+            .filter(|w| w.is_layoutable() && self.layout_workspace_of(w) == workspace_id)
             .map(|w| w.id)
             .collect()
     }
@@ -527,6 +847,8 @@ mod tests {
             is_main,
             is_builtin: false,
             refresh_rate: 60.0,
+            uuid: String::new(),
+            roles: Vec::new(),
         }
     }
 
@@ -543,6 +865,11 @@ mod tests {
             split_ratios: Vec::new(),
             master_ratio: None,
             configured_screen: None,
+            home_screen_uuid: None,
+            toggled_from: None,
+            layout_pinned: false,
+            master_position: None,
+            gaps_disabled: false,
         }
     }
 
@@ -553,15 +880,22 @@ mod tests {
             app_id: "com.test.app".to_string(),
             app_name: "Test App".to_string(),
             title: format!("Window {id}"),
+            subrole: None,
             frame: Rect::new(0.0, 0.0, 800.0, 600.0),
             minimum_size: None,
             inferred_minimum_size: None,
             expected_frame: None,
             workspace_id,
+            extra_workspace_ids: Vec::new(),
             is_minimized: false,
             is_fullscreen: false,
             is_hidden: false,
             is_floating: false,
+            float_frame: None,
+            always_on_top: false,
+            opacity: None,
+            non_resizable: false,
+            awaiting_frame: false,
             tab_group_id: None,
             is_active_tab: true,
             matched_rule: None,
@@ -621,6 +955,9 @@ mod tests {
         assert!(state.get_workspace(ws1_id).is_some());
         assert_eq!(state.get_workspace_by_name("dev").unwrap().id, ws1_id);
         assert_eq!(state.get_workspaces_for_screen(1).len(), 2);
+        assert_eq!(state.get_workspace_on_screen_at(1, 1).unwrap().name, "web");
+        assert!(state.get_workspace_on_screen_at(1, 2).is_none());
+        assert!(state.get_workspace_on_screen_at(2, 0).is_none());
 
         // Update in place
         state.update_workspace(ws1_id, |ws| {
@@ -660,6 +997,37 @@ mod tests {
         assert_eq!(state.get_layoutable_windows(ws_id).len(), 1);
     }
 
+    #[test]
+    fn test_window_in_extra_workspace_is_laid_out_once() {
+        let mut state = TilingState::new();
+        let code = make_workspace("code", 1);
+        let comms = make_workspace("comms", 2);
+        let (code_id, comms_id) = (code.id, comms.id);
+        state.upsert_workspace(code);
+        state.upsert_workspace(comms);
+
+        let mut window = make_window(100, code_id);
+        window.extra_workspace_ids.push(comms_id);
+        state.upsert_window(window);
+
+        assert_eq!(state.get_windows_for_workspace(comms_id).len(), 1);
+        assert_eq!(state.get_window_ids_for_workspace(comms_id), vec![100]);
+
+        // Neither is visible: laid out in its assigned workspace
+        assert_eq!(state.get_layoutable_window_ids(code_id), vec![100]);
+        assert!(state.get_layoutable_window_ids(comms_id).is_empty());
+
+        // Only the extra workspace is visible
+        state.update_workspace(comms_id, |ws| ws.is_visible = true);
+        assert!(state.get_layoutable_windows(code_id).is_empty());
+        assert_eq!(state.get_layoutable_windows(comms_id).len(), 1);
+
+        // Both are visible: the assigned workspace wins
+        state.update_workspace(code_id, |ws| ws.is_visible = true);
+        assert_eq!(state.get_layoutable_window_ids(code_id), vec![100]);
+        assert!(state.get_layoutable_window_ids(comms_id).is_empty());
+    }
+
     #[test]
     fn test_focus_operations() {
         let mut state = TilingState::new();
@@ -721,4 +1089,148 @@ mod tests {
         state.set_enabled(true);
         assert!(state.is_enabled());
     }
+
+    #[test]
+    fn test_paused_state() {
+        let mut state = TilingState::new();
+        assert!(!state.is_paused());
+
+        state.set_paused(true);
+        assert!(state.is_paused());
+        assert!(state.is_enabled());
+    }
+
+    #[test]
+    fn test_focus_history_is_mru_ordered() {
+        let mut state = TilingState::new();
+        let ws_a = Uuid::now_v7();
+        let ws_b = Uuid::now_v7();
+
+        state.record_focus_history(ws_a, 1);
+        state.record_focus_history(ws_a, 2);
+        state.record_focus_history(ws_a, 1);
+        assert_eq!(state.get_focus_mru(ws_a), &[1, 2]);
+        assert_eq!(state.get_focus_history(ws_a), Some(1));
+
+        // A window only stays in the history of its latest workspace
+        state.record_focus_history(ws_b, 2);
+        assert_eq!(state.get_focus_mru(ws_a), &[1]);
+        assert_eq!(state.get_focus_mru(ws_b), &[2]);
+
+        state.remove_window_from_focus_history(2);
+        assert!(state.get_focus_mru(ws_b).is_empty());
+    }
+
+    #[test]
+    fn test_focus_mru_walk_goes_back_and_commits() {
+        let mut state = TilingState::new();
+        let ws = Uuid::now_v7();
+        let candidates = [1, 2, 3];
+        for id in [3, 2, 1] {
+            state.record_focus_history(ws, id);
+        }
+
+        let now = Instant::now();
+        assert_eq!(state.step_focus_mru(ws, Some(1), &candidates, 1, now), Some(2));
+
+        // Focus landing on the selection doesn't reorder the history mid-walk
+        state.record_window_focus(ws, 2, now);
+        assert_eq!(state.get_focus_mru(ws), &[1, 2, 3]);
+
+        assert_eq!(state.step_focus_mru(ws, Some(2), &candidates, 1, now), Some(3));
+        assert_eq!(state.step_focus_mru(ws, Some(3), &candidates, 1, now), Some(1));
+
+        // After the delay the walk ends: the selection moves to the front
+        let later = now + FOCUS_MRU_COMMIT_DELAY;
+        assert_eq!(state.step_focus_mru(ws, Some(1), &candidates, 1, later), Some(2));
+        state.commit_focus_mru();
+        assert_eq!(state.get_focus_mru(ws), &[2, 1, 3]);
+    }
+
+    #[test]
+    fn test_global_focus_mru_tracks_set_focus_and_prunes() {
+        let mut state = TilingState::new();
+        let ws_a = Uuid::now_v7();
+        let ws_b = Uuid::now_v7();
+
+        state.set_focus(Some(1), Some(ws_a), Some(1));
+        state.set_focus(Some(2), Some(ws_b), Some(2));
+        state.set_focus(Some(1), Some(ws_a), Some(1));
+        state.set_focus(None, Some(ws_a), Some(1));
+        assert_eq!(state.get_global_focus_mru(), &[1, 2]);
+
+        state.remove_window_from_focus_history(2);
+        assert_eq!(state.get_global_focus_mru(), &[1]);
+    }
+
+    #[test]
+    fn test_app_mru_tracks_activations_and_prunes() {
+        let app = |pid: i32, name: &str| RecentApp {
+            pid,
+            bundle_id: format!("com.example.{}", name.to_lowercase()),
+            name: name.to_string(),
+        };
+        let mut state = TilingState::new();
+
+        state.record_app_activation(app(10, "Safari"));
+        state.record_app_activation(app(20, "Terminal"));
+        state.record_app_activation(app(30, "Notes"));
+        state.record_app_activation(app(10, "Safari"));
+        let pids: Vec<i32> = state.get_app_mru().iter().map(|app| app.pid).collect();
+        assert_eq!(pids, vec![10, 30, 20]);
+
+        state.remove_app_from_history(30);
+        let pids: Vec<i32> = state.get_app_mru().iter().map(|app| app.pid).collect();
+        assert_eq!(pids, vec![10, 20]);
+    }
+
+    #[test]
+    fn test_minimized_stack_order() {
+        let mut state = TilingState::new();
+        let ws = Uuid::now_v7();
+
+        state.push_minimized(ws, 1);
+        state.push_minimized(ws, 2);
+        state.push_minimized(ws, 1);
+        assert_eq!(state.get_minimized_stack(ws), &[2, 1]);
+
+        state.remove_minimized(1);
+        state.remove_minimized(2);
+        assert!(state.get_minimized_stack(ws).is_empty());
+    }
+
+    #[test]
+    fn test_resize_refusals_are_counted_until_removed() {
+        let mut state = TilingState::new();
+        let ws = Uuid::now_v7();
+        state.upsert_window(make_window(1, ws));
+
+        assert_eq!(state.record_resize_refusal(1), 1);
+        assert_eq!(state.record_resize_refusal(1), 2);
+
+        state.remove_window(1);
+        assert_eq!(state.record_resize_refusal(1), 1);
+    }
+
+    #[test]
+    fn test_gap_adjustments() {
+        let mut state = TilingState::new();
+        assert_eq!(state.get_gap_adjustment(1), (0.0, 0.0));
+
+        state.set_gap_adjustment(1, 4.0, -2.0);
+        assert_eq!(state.get_gap_adjustment(1), (4.0, -2.0));
+        assert_eq!(state.get_gap_adjustment(2), (0.0, 0.0));
+
+        assert_eq!(state.clear_gap_adjustments(), vec![1]);
+        assert_eq!(state.get_gap_adjustment(1), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_focus_mru_walk_needs_two_candidates() {
+        let mut state = TilingState::new();
+        let ws = Uuid::now_v7();
+        state.record_focus_history(ws, 1);
+
+        assert_eq!(state.step_focus_mru(ws, Some(1), &[1], 1, Instant::now()), None);
+    }
 }
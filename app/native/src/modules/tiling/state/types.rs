@@ -14,6 +14,8 @@ use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 use uuid::Uuid;
 
+use crate::config::MasterPosition;
+
 // ============================================================================
 // Geometry Types
 // ============================================================================
@@ -42,6 +44,17 @@ impl Rect {
     #[must_use]
     pub fn is_valid(&self) -> bool { self.width > 0.0 && self.height > 0.0 }
 
+    /// Check if this rectangle is taller than it is wide.
+    ///
+    /// Layouts use this to pick their orientation, so a rotated display
+    /// stacks windows vertically. Square rectangles count as landscape.
+    #[must_use]
+    pub fn is_portrait(&self) -> bool { self.height > self.width }
+
+    /// Check if this rectangle is at least as wide as it is tall.
+    #[must_use]
+    pub fn is_landscape(&self) -> bool { !self.is_portrait() }
+
     /// Check if this rectangle contains a point.
     #[must_use]
     pub fn contains_point(&self, x: f64, y: f64) -> bool {
@@ -57,6 +70,16 @@ impl Rect {
             && self.y + self.height > other.y
     }
 
+    /// Get the smallest rectangle containing both this rectangle and another.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        let right = (self.x + self.width).max(other.x + other.width);
+        let bottom = (self.y + self.height).max(other.y + other.height);
+        Self::new(x, y, right - x, bottom - y)
+    }
+
     /// Calculate the area of this rectangle.
     #[must_use]
     pub fn area(&self) -> f64 { self.width * self.height }
@@ -105,6 +128,14 @@ pub struct Screen {
 
     /// Display refresh rate in Hz (for batch timing).
     pub refresh_rate: f64,
+
+    /// Persistent display UUID, stable across reboots and reconnects.
+    #[serde(default)]
+    pub uuid: String,
+
+    /// Roles assigned to the display in `tiling.screenRoles`.
+    #[serde(default)]
+    pub roles: Vec<String>,
 }
 
 impl Default for Screen {
@@ -118,6 +149,8 @@ impl Default for Screen {
             is_main: false,
             is_builtin: false,
             refresh_rate: 60.0,
+            uuid: String::new(),
+            roles: Vec::new(),
         }
     }
 }
@@ -126,6 +159,12 @@ impl Screen {
     /// Get the batch interval for geometry updates based on refresh rate.
     #[must_use]
     pub fn batch_interval_ms(&self) -> f64 { 1000.0 / self.refresh_rate }
+
+    /// Returns whether the display was assigned `role` (case-insensitive).
+    #[must_use]
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r.eq_ignore_ascii_case(role))
+    }
 }
 
 // ============================================================================
@@ -202,6 +241,19 @@ impl LayoutType {
 /// avoiding heap allocation for the common case of workspaces with few windows.
 pub type WindowIdList = SmallVec<[u32; 8]>;
 
+/// A workspace layout with its runtime ratios, kept to be restored later.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SavedLayout {
+    /// Layout algorithm.
+    pub layout: LayoutType,
+
+    /// Custom split ratios in use.
+    pub split_ratios: Vec<f64>,
+
+    /// Runtime master ratio override in use.
+    pub master_ratio: Option<f64>,
+}
+
 /// A virtual desktop that contains windows.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Workspace {
@@ -243,6 +295,33 @@ pub struct Workspace {
 
     /// Configured screen name (for reconnection after screen hotplug).
     pub configured_screen: Option<String>,
+
+    /// UUID of the display the workspace was on when it was unplugged, so it
+    /// moves back to that display when it reconnects, whatever its new ID.
+    #[serde(default)]
+    pub home_screen_uuid: Option<String>,
+
+    /// Layout the workspace had before `--layout-toggle` switched it to the
+    /// alternate layout, restored by toggling again.
+    #[serde(default)]
+    pub toggled_from: Option<SavedLayout>,
+
+    /// Whether the layout was set by hand, keeping `tiling.autoLayout` from
+    /// switching it as windows come and go.
+    #[serde(default)]
+    pub layout_pinned: bool,
+
+    /// Runtime master position override for the Master layout.
+    ///
+    /// `None` means "use the global config default (`tiling.master.position`)".
+    /// Set by `stache tiling master --position`, and kept across layout changes.
+    #[serde(default)]
+    pub master_position: Option<MasterPosition>,
+
+    /// Whether gaps are toggled off, laying windows out edge to edge.
+    ///
+    /// A runtime toggle, not persisted. The bar offset still applies.
+    pub gaps_disabled: bool,
 }
 
 impl Default for Workspace {
@@ -259,6 +338,11 @@ impl Default for Workspace {
             split_ratios: Vec::new(),
             master_ratio: None,
             configured_screen: None,
+            home_screen_uuid: None,
+            toggled_from: None,
+            layout_pinned: false,
+            master_position: None,
+            gaps_disabled: false,
         }
     }
 }
@@ -321,6 +405,11 @@ pub struct Window {
     /// Window title.
     pub title: String,
 
+    /// Accessibility subrole (e.g., "AXStandardWindow", "AXDialog").
+    /// `None` if the window doesn't report one.
+    #[serde(default)]
+    pub subrole: Option<String>,
+
     /// Current frame (position and size).
     pub frame: Rect,
 
@@ -341,6 +430,12 @@ pub struct Window {
     /// Assigned workspace ID.
     pub workspace_id: Uuid,
 
+    /// Other workspaces the window is also shown in.
+    /// It is laid out in its assigned workspace while that one is visible, and
+    /// in the first visible of these otherwise.
+    #[serde(default)]
+    pub extra_workspace_ids: Vec<Uuid>,
+
     /// Is the window minimized?
     pub is_minimized: bool,
 
@@ -353,6 +448,26 @@ pub struct Window {
     /// Is the window floating (excluded from tiling)?
     pub is_floating: bool,
 
+    /// Frame the window last had while floating.
+    /// Restored when the window is toggled back to floating.
+    pub float_frame: Option<Rect>,
+
+    /// Is the window kept above normal windows?
+    pub always_on_top: bool,
+
+    /// Opacity set explicitly for this window (0.0-1.0).
+    /// `None` if the window is fully opaque. Kept across layout and focus changes.
+    pub opacity: Option<f64>,
+
+    /// Does the window ignore resize requests?
+    /// Set after it refused to resize several times; layouts then keep its current size.
+    pub non_resizable: bool,
+
+    /// Was the window zero-sized or off every screen when tracked?
+    /// Such windows are left out of layouts until they get a real frame.
+    #[serde(default)]
+    pub awaiting_frame: bool,
+
     /// Tab group ID if this window is part of a tab group.
     pub tab_group_id: Option<Uuid>,
 
@@ -371,15 +486,22 @@ impl Default for Window {
             app_id: String::new(),
             app_name: String::new(),
             title: String::new(),
+            subrole: None,
             frame: Rect::zero(),
             minimum_size: None,
             inferred_minimum_size: None,
             expected_frame: None,
             workspace_id: Uuid::nil(),
+            extra_workspace_ids: Vec::new(),
             is_minimized: false,
             is_fullscreen: false,
             is_hidden: false,
             is_floating: false,
+            float_frame: None,
+            always_on_top: false,
+            opacity: None,
+            non_resizable: false,
+            awaiting_frame: false,
             tab_group_id: None,
             is_active_tab: true,
             matched_rule: None,
@@ -395,9 +517,16 @@ impl Window {
             && !self.is_hidden
             && !self.is_fullscreen
             && !self.is_floating
+            && !self.awaiting_frame
             && (self.tab_group_id.is_none() || self.is_active_tab)
     }
 
+    /// Check if the window is assigned to a workspace or also shown in it.
+    #[must_use]
+    pub fn belongs_to(&self, workspace_id: Uuid) -> bool {
+        self.workspace_id == workspace_id || self.extra_workspace_ids.contains(&workspace_id)
+    }
+
     /// Check if this window is in a tab group.
     #[must_use]
     pub const fn is_tabbed(&self) -> bool { self.tab_group_id.is_some() }
@@ -405,9 +534,13 @@ impl Window {
     /// Returns the effective minimum size, preferring reported over inferred.
     ///
     /// Uses `minimum_size` (from `AXMinimumSize`) if available, otherwise falls back
-    /// to `inferred_minimum_size` (detected from position mismatch).
+    /// to `inferred_minimum_size` (detected from position mismatch). A non-resizable
+    /// window needs at least its current size.
     #[must_use]
     pub const fn effective_minimum_size(&self) -> Option<(f64, f64)> {
+        if self.non_resizable {
+            return Some((self.frame.width, self.frame.height));
+        }
         // Prefer reported minimum_size if available
         if let Some(reported) = self.minimum_size {
             return Some(reported);
@@ -479,6 +612,23 @@ impl FocusState {
     }
 }
 
+// ============================================================================
+// Recent App
+// ============================================================================
+
+/// An application in the history of activated apps.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecentApp {
+    /// Process ID.
+    pub pid: i32,
+
+    /// Bundle identifier (e.g., "com.apple.Safari").
+    pub bundle_id: String,
+
+    /// Application name (e.g., "Safari").
+    pub name: String,
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -517,6 +667,15 @@ mod tests {
             assert!(!Rect::new(0.0, 0.0, -1.0, 100.0).is_valid());
         }
 
+        #[test]
+        fn test_rect_orientation() {
+            assert!(Rect::new(0.0, 0.0, 1080.0, 1920.0).is_portrait());
+            assert!(Rect::new(0.0, 0.0, 1920.0, 1080.0).is_landscape());
+
+            // Square counts as landscape
+            assert!(Rect::new(0.0, 0.0, 1000.0, 1000.0).is_landscape());
+        }
+
         #[test]
         fn test_rect_contains_point() {
             let rect = Rect::new(10.0, 10.0, 100.0, 100.0);
@@ -538,6 +697,15 @@ mod tests {
             assert!(!c.intersects(&a));
         }
 
+        #[test]
+        fn test_rect_union() {
+            let a = Rect::new(0.0, 10.0, 100.0, 100.0);
+            let b = Rect::new(50.0, 0.0, 100.0, 50.0);
+
+            assert_eq!(a.union(&b), Rect::new(0.0, 0.0, 150.0, 110.0));
+            assert_eq!(a.union(&a), a);
+        }
+
         #[test]
         fn test_rect_area() {
             let rect = Rect::new(0.0, 0.0, 100.0, 50.0);
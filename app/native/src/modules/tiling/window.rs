@@ -18,7 +18,7 @@ use objc::{msg_send, sel, sel_impl};
 
 use super::ffi::accessibility::AXElement;
 use super::rules::is_pip_window;
-use super::state::Rect;
+use super::state::{RecentApp, Rect};
 
 // ============================================================================
 // FFI for NSString
@@ -128,6 +128,33 @@ pub fn get_running_apps() -> Vec<AppInfo> {
     }
 }
 
+/// Gets the bundle identifier and name of a running app by PID.
+///
+/// Like [`get_running_apps`], only includes "regular" apps, so background-only
+/// apps and agents such as Stache itself return `None`.
+#[must_use]
+pub fn running_app_for_pid(pid: i32) -> Option<RecentApp> {
+    unsafe {
+        let app_class = Class::get("NSRunningApplication")?;
+        let app: *mut Object = msg_send![app_class, runningApplicationWithProcessIdentifier: pid];
+        if app.is_null() {
+            return None;
+        }
+
+        // 0 = NSApplicationActivationPolicyRegular
+        let activation_policy: i64 = msg_send![app, activationPolicy];
+        if activation_policy != 0 {
+            return None;
+        }
+
+        Some(RecentApp {
+            pid,
+            bundle_id: ns_string_to_rust(msg_send![app, bundleIdentifier]),
+            name: ns_string_to_rust(msg_send![app, localizedName]),
+        })
+    }
+}
+
 // ============================================================================
 // WindowInfo
 // ============================================================================
@@ -146,6 +173,8 @@ pub struct WindowInfo {
     pub app_name: String,
     /// Window title.
     pub title: String,
+    /// Accessibility subrole, if the window reports one.
+    pub subrole: Option<String>,
     /// Window frame (position and size).
     pub frame: Rect,
     /// Minimum size constraints (width, height) if the window reports them.
@@ -185,6 +214,7 @@ pub fn get_all_windows_including_hidden() -> Vec<WindowInfo> {
     const MIN_DIALOG_HEIGHT: f64 = 300.0;
 
     let apps = get_running_apps();
+    let config = crate::config::get_config();
 
     // Build a map of PID -> (bundle_id, app_name, is_hidden)
     let app_info_map: HashMap<i32, (&str, &str, bool)> = apps
@@ -243,7 +273,13 @@ pub fn get_all_windows_including_hidden() -> Vec<WindowInfo> {
                 }
             };
 
-            if !should_manage {
+            // Get app info
+            let (bundle_id, app_name, is_hidden) =
+                app_info_map.get(&app.pid).copied().unwrap_or(("", "", false));
+
+            // Apps listed in `tiling.forceTile` are managed regardless of subrole and size
+            let force_tile = config.tiling.is_force_tiled(bundle_id);
+            if !should_manage && !force_tile {
                 continue;
             }
 
@@ -254,13 +290,9 @@ pub fn get_all_windows_including_hidden() -> Vec<WindowInfo> {
             let is_fullscreen = ax_window.is_fullscreen().unwrap_or(false);
             let minimum_size = ax_window.minimum_size();
 
-            // Get app info
-            let (bundle_id, app_name, is_hidden) =
-                app_info_map.get(&app.pid).copied().unwrap_or(("", "", false));
-
             // Skip Finder "Get Info" windows (e.g., "filename.txt Info")
             // These are popup-like windows that shouldn't be tiled
-            if bundle_id == "com.apple.finder" && title.ends_with(" Info") {
+            if !force_tile && bundle_id == "com.apple.finder" && title.ends_with(" Info") {
                 continue;
             }
 
@@ -270,6 +302,7 @@ pub fn get_all_windows_including_hidden() -> Vec<WindowInfo> {
                 bundle_id: bundle_id.to_string(),
                 app_name: app_name.to_string(),
                 title,
+                subrole,
                 frame,
                 minimum_size,
                 is_minimized,
@@ -3,11 +3,23 @@
 //! Uses native macOS APIs to set the desktop wallpaper for each screen.
 
 use std::path::Path;
+use std::process::Command;
 
-use objc::runtime::{Class, Object};
+use objc::runtime::{BOOL, Class, NO, Object, YES};
 use objc::{msg_send, sel, sel_impl};
 
 use super::processing;
+use crate::config::WallpaperScaling;
+
+#[link(name = "AppKit", kind = "framework")]
+unsafe extern "C" {
+    static NSWorkspaceDesktopImageScalingKey: *mut Object;
+    static NSWorkspaceDesktopImageAllowClippingKey: *mut Object;
+}
+
+/// `NSImageScaling` values accepted by `NSWorkspaceDesktopImageScalingKey`.
+const NS_IMAGE_SCALE_AXES_INDEPENDENTLY: usize = 1;
+const NS_IMAGE_SCALE_PROPORTIONALLY_UP_OR_DOWN: usize = 3;
 
 /// Errors that can occur when setting the wallpaper.
 #[derive(Debug)]
@@ -39,24 +51,68 @@ impl std::error::Error for WallpaperError {}
 #[inline]
 pub fn screen_count() -> usize { processing::get_screen_count() }
 
+/// Returns the `NSImageScaling` value and clipping flag for a scaling mode.
+///
+/// Processed images are composed at the screen's pixel size, so they are
+/// always scaled proportionally into the screen's point size. Showing them
+/// unscaled would map pixels to points and crop them on Retina displays.
+const fn desktop_image_options(scaling: WallpaperScaling) -> (usize, bool) {
+    match scaling {
+        WallpaperScaling::Fill => (NS_IMAGE_SCALE_PROPORTIONALLY_UP_OR_DOWN, true),
+        WallpaperScaling::Fit | WallpaperScaling::Center | WallpaperScaling::Tile => {
+            (NS_IMAGE_SCALE_PROPORTIONALLY_UP_OR_DOWN, false)
+        }
+        WallpaperScaling::Stretch => (NS_IMAGE_SCALE_AXES_INDEPENDENTLY, false),
+    }
+}
+
 /// Sets the desktop wallpaper for all screens.
 ///
+/// The default `fill` scaling sets every desktop, on every Space, through
+/// System Events; other modes set the wallpaper screen by screen with explicit
+/// scaling options, which only affects the current Space.
+///
 /// # Arguments
 ///
 /// * `path` - Path to the image file to set as wallpaper
+/// * `scaling` - How the image is scaled to each screen
 ///
 /// # Errors
 ///
 /// Returns an error if the file doesn't exist or the wallpaper setting fails.
-pub fn set_wallpaper(path: &Path) -> Result<(), WallpaperError> {
+pub fn set_wallpaper(path: &Path, scaling: WallpaperScaling) -> Result<(), WallpaperError> {
     if !path.exists() {
         return Err(WallpaperError::FileNotFound(path.display().to_string()));
     }
 
-    let path_str = path.display().to_string();
+    if scaling != WallpaperScaling::Fill {
+        for screen_index in 0..screen_count() {
+            set_wallpaper_for_screen(path, screen_index, scaling)?;
+        }
+        return Ok(());
+    }
+
+    let script = format!(
+        "tell application \"System Events\" to tell every desktop to set picture to {}",
+        applescript_string(&path.display().to_string())
+    );
+    let output = Command::new("osascript")
+        .args(["-e", &script])
+        .output()
+        .map_err(|e| WallpaperError::SetWallpaperFailed(e.to_string()))?;
+
+    if !output.status.success() {
+        return Err(WallpaperError::SetWallpaperFailed(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    Ok(())
+}
 
-    wallpaper::set_from_path(&path_str)
-        .map_err(|e| WallpaperError::SetWallpaperFailed(e.to_string()))
+/// Quotes a string as an AppleScript string literal.
+fn applescript_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
 }
 
 /// Sets the desktop wallpaper for a specific screen.
@@ -65,13 +121,18 @@ pub fn set_wallpaper(path: &Path) -> Result<(), WallpaperError> {
 ///
 /// * `path` - Path to the image file to set as wallpaper
 /// * `screen_index` - The 0-based index of the screen
+/// * `scaling` - How the image is scaled to the screen
 ///
 /// # Errors
 ///
 /// Returns an error if the file doesn't exist, the screen index is invalid,
 /// or the wallpaper setting fails.
 #[allow(clippy::cast_possible_truncation)]
-pub fn set_wallpaper_for_screen(path: &Path, screen_index: usize) -> Result<(), WallpaperError> {
+pub fn set_wallpaper_for_screen(
+    path: &Path,
+    screen_index: usize,
+    scaling: WallpaperScaling,
+) -> Result<(), WallpaperError> {
     if !path.exists() {
         return Err(WallpaperError::FileNotFound(path.display().to_string()));
     }
@@ -142,7 +203,7 @@ pub fn set_wallpaper_for_screen(path: &Path, screen_index: usize) -> Result<(),
         }
 
         // Set wallpaper for screen: setDesktopImageURL:forScreen:options:error:
-        let options: *mut Object = msg_send![Class::get("NSDictionary").unwrap(), dictionary];
+        let options = build_desktop_image_options(scaling)?;
         let mut error: *mut Object = std::ptr::null_mut();
 
         let success: bool = msg_send![workspace, setDesktopImageURL:url forScreen:screen options:options error:&mut error];
@@ -170,6 +231,46 @@ pub fn set_wallpaper_for_screen(path: &Path, screen_index: usize) -> Result<(),
     }
 }
 
+/// Builds the `NSWorkspace` desktop image options dictionary for a scaling mode.
+fn build_desktop_image_options(scaling: WallpaperScaling) -> Result<*mut Object, WallpaperError> {
+    let (Some(dictionary_class), Some(number_class)) =
+        (Class::get("NSDictionary"), Class::get("NSNumber"))
+    else {
+        return Err(WallpaperError::SetWallpaperFailed(
+            "Failed to get NSDictionary or NSNumber class".to_string(),
+        ));
+    };
+
+    let (image_scaling, allow_clipping) = desktop_image_options(scaling);
+    let clipping: BOOL = if allow_clipping { YES } else { NO };
+
+    unsafe {
+        let scaling_value: *mut Object =
+            msg_send![number_class, numberWithUnsignedInteger: image_scaling];
+        let clipping_value: *mut Object = msg_send![number_class, numberWithBool: clipping];
+
+        let keys = [
+            NSWorkspaceDesktopImageScalingKey,
+            NSWorkspaceDesktopImageAllowClippingKey,
+        ];
+        let values = [scaling_value, clipping_value];
+
+        let options: *mut Object = msg_send![
+            dictionary_class,
+            dictionaryWithObjects: values.as_ptr()
+            forKeys: keys.as_ptr()
+            count: keys.len()
+        ];
+        if options.is_null() {
+            return Err(WallpaperError::SetWallpaperFailed(
+                "Failed to create desktop image options".to_string(),
+            ));
+        }
+
+        Ok(options)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -213,16 +314,55 @@ mod tests {
 
     #[test]
     fn test_set_wallpaper_returns_error_for_nonexistent_file() {
-        let result = set_wallpaper(std::path::Path::new("/nonexistent/path/to/wallpaper.jpg"));
+        let result = set_wallpaper(
+            std::path::Path::new("/nonexistent/path/to/wallpaper.jpg"),
+            WallpaperScaling::Fill,
+        );
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), WallpaperError::FileNotFound(_)));
     }
 
     #[test]
     fn test_set_wallpaper_for_screen_returns_error_for_nonexistent_file() {
-        let result =
-            set_wallpaper_for_screen(std::path::Path::new("/nonexistent/path/to/wallpaper.jpg"), 0);
+        let result = set_wallpaper_for_screen(
+            std::path::Path::new("/nonexistent/path/to/wallpaper.jpg"),
+            0,
+            WallpaperScaling::Fit,
+        );
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), WallpaperError::FileNotFound(_)));
     }
+
+    #[test]
+    fn test_desktop_image_options_mapping() {
+        assert_eq!(
+            desktop_image_options(WallpaperScaling::Fill),
+            (NS_IMAGE_SCALE_PROPORTIONALLY_UP_OR_DOWN, true)
+        );
+        assert_eq!(
+            desktop_image_options(WallpaperScaling::Fit),
+            (NS_IMAGE_SCALE_PROPORTIONALLY_UP_OR_DOWN, false)
+        );
+        assert_eq!(
+            desktop_image_options(WallpaperScaling::Stretch),
+            (NS_IMAGE_SCALE_AXES_INDEPENDENTLY, false)
+        );
+        assert_eq!(
+            desktop_image_options(WallpaperScaling::Center),
+            (NS_IMAGE_SCALE_PROPORTIONALLY_UP_OR_DOWN, false)
+        );
+        assert_eq!(
+            desktop_image_options(WallpaperScaling::Tile),
+            (NS_IMAGE_SCALE_PROPORTIONALLY_UP_OR_DOWN, false)
+        );
+    }
+
+    #[test]
+    fn test_applescript_string_escapes_quotes_and_backslashes() {
+        assert_eq!(applescript_string("/tmp/a.png"), "\"/tmp/a.png\"");
+        assert_eq!(
+            applescript_string(r#"/tmp/a "b"\c.png"#),
+            r#""/tmp/a \"b\"\\c.png""#
+        );
+    }
 }
@@ -6,17 +6,23 @@ use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
 use std::time::{Duration, Instant};
 
-use rand::RngExt;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{RngExt, SeedableRng};
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use super::macos;
 use super::processing::{self, ProcessingError};
-use crate::config::{WallpaperConfig, WallpaperMode};
+use crate::config::{WallpaperConfig, WallpaperMode, WallpaperScaling};
 use crate::platform::path::expand;
 
 /// Global wallpaper manager instance.
 static MANAGER: OnceLock<Arc<WallpaperManager>> = OnceLock::new();
 
+/// File in the wallpaper cache directory holding the current wallpaper.
+const SLIDESHOW_STATE_FILE: &str = "slideshow.json";
+
 /// Actions that can be performed on the wallpaper.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WallpaperAction {
@@ -28,6 +34,23 @@ pub enum WallpaperAction {
     File(String),
     /// Set a specific wallpaper for a specific screen.
     FileForScreen(usize, String),
+    /// Advance to the next wallpaper (same for all screens).
+    Next,
+    /// Go back to the previous wallpaper (same for all screens).
+    Previous,
+}
+
+/// Wallpaper position persisted across restarts and CLI invocations.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct SlideshowState {
+    /// Index of the current wallpaper in the sorted wallpaper list.
+    index: usize,
+    /// Path of the current wallpaper, used to resume when the folder changes.
+    path: Option<String>,
+    /// Seed of the shuffled order, so every process visits the wallpapers in
+    /// the same order.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
 }
 
 /// Errors that can occur in wallpaper management.
@@ -85,12 +108,29 @@ pub struct WallpaperManager {
     config: WallpaperConfig,
     /// Current wallpaper index (for sequential mode).
     current_index: AtomicUsize,
+    /// Order in which `Next`/`Previous` visit the wallpapers (shuffled for
+    /// shuffled slideshows, identity otherwise).
+    order: Vec<usize>,
+    /// Seed `order` was shuffled with, persisted with the slideshow state.
+    seed: Option<u64>,
+    /// Current position in `order`.
+    position: AtomicUsize,
     /// Whether the cycling timer is running.
     timer_running: AtomicBool,
     /// Mutex for thread-safe wallpaper changes.
     change_lock: Mutex<()>,
 }
 
+/// Returns the order a slideshow of `len` wallpapers visits them in, shuffled
+/// with `seed` when there is one.
+fn slideshow_order(len: usize, seed: Option<u64>) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..len).collect();
+    if let Some(seed) = seed {
+        order.shuffle(&mut StdRng::seed_from_u64(seed));
+    }
+    order
+}
+
 impl WallpaperManager {
     /// Creates a new wallpaper manager from configuration.
     ///
@@ -104,27 +144,48 @@ impl WallpaperManager {
             return Err(WallpaperManagerError::NoWallpapers);
         }
 
-        Ok(Self {
+        // Reuse the persisted seed so restarts and the CLI keep the same order
+        let seed = config.is_shuffled().then(|| {
+            Self::read_slideshow_state()
+                .and_then(|state| state.seed)
+                .unwrap_or_else(|| rand::rng().random())
+        });
+
+        let manager = Self {
+            order: slideshow_order(wallpapers.len(), seed),
+            seed,
             wallpapers,
             config: config.clone(),
             current_index: AtomicUsize::new(0),
+            position: AtomicUsize::new(0),
             timer_running: AtomicBool::new(false),
             change_lock: Mutex::new(()),
-        })
+        };
+
+        if manager.is_slideshow() {
+            manager.restore_slideshow_state();
+        }
+
+        Ok(manager)
+    }
+
+    /// Returns whether the manager runs in slideshow mode.
+    const fn is_slideshow(&self) -> bool {
+        matches!(self.config.mode, WallpaperMode::Slideshow { .. })
     }
 
     /// Loads wallpaper paths from the configuration.
     fn load_wallpapers(config: &WallpaperConfig) -> Result<Vec<PathBuf>, WallpaperManagerError> {
-        // If path is specified, read all images from that directory
-        if !config.path.is_empty() {
-            let path = expand(&config.path);
+        // If a directory is specified (slideshow folder or path), read all images from it
+        let directory = config.directory();
+        if !directory.is_empty() {
+            let path = expand(directory);
             if !path.exists() {
-                return Err(WallpaperManagerError::InvalidPath(config.path.clone()));
+                return Err(WallpaperManagerError::InvalidPath(directory.to_string()));
             }
             if !path.is_dir() {
                 return Err(WallpaperManagerError::InvalidPath(format!(
-                    "{} is not a directory",
-                    config.path
+                    "{directory} is not a directory"
                 )));
             }
 
@@ -153,6 +214,7 @@ impl WallpaperManager {
                 rng.random_range(0..self.wallpapers.len())
             }
             WallpaperMode::Sequential => 0,
+            WallpaperMode::Slideshow { .. } => self.order[self.position.load(Ordering::SeqCst)],
         }
     }
 
@@ -167,6 +229,105 @@ impl WallpaperManager {
                 let current = self.current_index.load(Ordering::SeqCst);
                 (current + 1) % self.wallpapers.len()
             }
+            WallpaperMode::Slideshow { .. } => self.index_at_offset(1),
+        }
+    }
+
+    /// Returns the wallpaper index `offset` steps away from the current position.
+    fn index_at_offset(&self, offset: isize) -> usize {
+        self.order[self.position_at_offset(offset)]
+    }
+
+    /// Returns the position in `order` that is `offset` steps away from the current one.
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    fn position_at_offset(&self, offset: isize) -> usize {
+        let len = self.order.len() as isize;
+        let current = self.position.load(Ordering::SeqCst) as isize;
+        (current + offset).rem_euclid(len) as usize
+    }
+
+    /// Steps through the wallpapers in `order` and applies the result.
+    ///
+    /// Wallpapers that fail to process (e.g. unreadable or corrupt files) are
+    /// skipped in the direction of travel until one succeeds. An `offset` of 0
+    /// re-applies the current wallpaper, skipping forward on failure.
+    fn step(&self, offset: isize) -> Result<(), WallpaperManagerError> {
+        // Another process (e.g. the CLI or the running app) may have moved on
+        self.restore_slideshow_state();
+
+        let direction = if offset < 0 { -1 } else { 1 };
+        let mut last_error = WallpaperManagerError::NoWallpapers;
+
+        for attempt in 0..self.order.len() {
+            #[allow(clippy::cast_possible_wrap)]
+            let index = self.index_at_offset(offset + direction * attempt as isize);
+            match self.set_wallpaper_at_index(index) {
+                Ok(()) => return Ok(()),
+                Err(err) => {
+                    tracing::warn!(
+                        error = %err,
+                        path = %self.wallpapers[index].display(),
+                        "skipping wallpaper that could not be applied"
+                    );
+                    last_error = err;
+                }
+            }
+        }
+
+        Err(last_error)
+    }
+
+    /// Returns the path of the persisted slideshow state.
+    fn slideshow_state_path() -> PathBuf { processing::cache_dir().join(SLIDESHOW_STATE_FILE) }
+
+    /// Reads the persisted slideshow state, if a valid one exists.
+    fn read_slideshow_state() -> Option<SlideshowState> {
+        std::fs::read_to_string(Self::slideshow_state_path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+    }
+
+    /// Restores the slideshow position from disk, if a valid state exists.
+    fn restore_slideshow_state(&self) {
+        let Some(state) = Self::read_slideshow_state() else {
+            return;
+        };
+
+        if let Some(index) = self.resolve_slideshow_state(&state) {
+            self.current_index.store(index, Ordering::SeqCst);
+            if let Some(position) = self.order.iter().position(|&i| i == index) {
+                self.position.store(position, Ordering::SeqCst);
+            }
+        }
+    }
+
+    /// Resolves a persisted state to a wallpaper index.
+    ///
+    /// Prefers the stored path so added or removed images don't shift the
+    /// slideshow, falling back to the stored index when the file is gone.
+    fn resolve_slideshow_state(&self, state: &SlideshowState) -> Option<usize> {
+        state
+            .path
+            .as_deref()
+            .and_then(|path| self.wallpapers.iter().position(|p| p.to_string_lossy() == path))
+            .or_else(|| (state.index < self.wallpapers.len()).then_some(state.index))
+    }
+
+    /// Persists the current slideshow position to disk.
+    fn save_slideshow_state(&self, index: usize) {
+        let state = SlideshowState {
+            index,
+            path: Some(self.wallpapers[index].display().to_string()),
+            seed: self.seed,
+        };
+
+        let result = processing::ensure_cache_dir().map_err(|err| err.to_string()).and_then(|()| {
+            let json = serde_json::to_string(&state).map_err(|err| err.to_string())?;
+            std::fs::write(Self::slideshow_state_path(), json).map_err(|err| err.to_string())
+        });
+
+        if let Err(err) = result {
+            tracing::warn!(error = %err, "failed to persist slideshow state");
         }
     }
 
@@ -176,14 +337,32 @@ impl WallpaperManager {
 
         let source = &self.wallpapers[index];
 
-        // Process the image (applies blur and rounded corners, uses cache if available)
-        let processed_path = processing::process_image(source, &self.config)?;
+        if self.config.scaling == WallpaperScaling::Fill {
+            // Process the image (applies blur and rounded corners, uses cache if available)
+            let processed_path = processing::process_image(source, &self.config)?;
 
-        // Set the wallpaper using macOS APIs
-        macos::set_wallpaper(&processed_path)?;
+            // Set the wallpaper on every desktop using macOS APIs
+            macos::set_wallpaper(&processed_path, self.config.scaling)?;
+        } else {
+            // Process the image for each screen so it matches that screen's resolution
+            for screen_index in 0..macos::screen_count() {
+                let processed_path =
+                    processing::process_image_for_screen(source, &self.config, screen_index)?;
+                macos::set_wallpaper_for_screen(
+                    &processed_path,
+                    screen_index,
+                    self.config.scaling,
+                )?;
+            }
+        }
 
         // Update the current index
         self.current_index.store(index, Ordering::SeqCst);
+        if let Some(position) = self.order.iter().position(|&i| i == index) {
+            self.position.store(position, Ordering::SeqCst);
+        }
+
+        self.save_slideshow_state(index);
 
         Ok(())
     }
@@ -203,7 +382,7 @@ impl WallpaperManager {
             processing::process_image_for_screen(source, &self.config, screen_index)?;
 
         // Set the wallpaper for the specific screen
-        macos::set_wallpaper_for_screen(&processed_path, screen_index)?;
+        macos::set_wallpaper_for_screen(&processed_path, screen_index, self.config.scaling)?;
 
         Ok(())
     }
@@ -228,6 +407,10 @@ impl WallpaperManager {
     /// Sets the initial wallpaper on startup.
     #[cfg_attr(debug_assertions, allow(dead_code))]
     pub fn set_initial_wallpaper(&self) -> Result<(), WallpaperManagerError> {
+        if self.is_slideshow() {
+            return self.step(0);
+        }
+
         let index = self.select_initial_index();
         self.set_wallpaper_at_index(index)
     }
@@ -269,6 +452,8 @@ impl WallpaperManager {
                 let index = self.find_wallpaper_index(filename)?;
                 self.set_wallpaper_at_index_for_screen(index, *screen_index)
             }
+            WallpaperAction::Next => self.step(1),
+            WallpaperAction::Previous => self.step(-1),
         }
     }
 
@@ -301,7 +486,8 @@ impl WallpaperManager {
     ///
     /// Does nothing if the interval is 0.
     pub fn start_timer(self: &Arc<Self>) {
-        if self.config.interval == 0 {
+        let interval = self.config.effective_interval();
+        if interval == 0 {
             return;
         }
 
@@ -311,7 +497,7 @@ impl WallpaperManager {
         }
 
         let manager = Arc::clone(self);
-        let interval = Duration::from_secs(self.config.interval);
+        let interval = Duration::from_secs(interval);
 
         std::thread::spawn(move || {
             loop {
@@ -321,8 +507,12 @@ impl WallpaperManager {
                     break;
                 }
 
-                let next_index = manager.select_next_index();
-                if let Err(err) = manager.set_wallpaper_at_index(next_index) {
+                let result = if manager.is_slideshow() {
+                    manager.step(1)
+                } else {
+                    manager.set_wallpaper_at_index(manager.select_next_index())
+                };
+                if let Err(err) = result {
                     tracing::warn!(error = %err, "wallpaper timer failed to set wallpaper");
                 }
             }
@@ -412,7 +602,7 @@ pub fn perform_action(action: &WallpaperAction) -> Result<(), WallpaperManagerEr
     manager.perform_action(action)?;
 
     // Reset timer if interval is set (to restart from current moment)
-    if manager.config.interval > 0 {
+    if manager.config.effective_interval() > 0 {
         manager.reset_timer();
     }
 
@@ -785,6 +975,8 @@ mod tests {
         let _ = WallpaperAction::RandomForScreen(0);
         let _file = WallpaperAction::File("test.jpg".to_string());
         let _file_screen = WallpaperAction::FileForScreen(0, "test.jpg".to_string());
+        let _ = WallpaperAction::Next;
+        let _ = WallpaperAction::Previous;
     }
 
     #[test]
@@ -1102,4 +1294,120 @@ mod tests {
         // Similar to above - depends on global state
         let _result = list_wallpapers();
     }
+
+    // ========================================================================
+    // Slideshow tests
+    // ========================================================================
+
+    fn make_manager(count: usize, order: Vec<usize>) -> WallpaperManager {
+        WallpaperManager {
+            wallpapers: (0..count).map(|i| PathBuf::from(format!("/slides/{i}.jpg"))).collect(),
+            config: WallpaperConfig {
+                mode: WallpaperMode::Slideshow {
+                    folder: "/slides".to_string(),
+                    interval: 60,
+                    shuffle: false,
+                },
+                ..Default::default()
+            },
+            current_index: AtomicUsize::new(0),
+            order,
+            seed: None,
+            position: AtomicUsize::new(0),
+            timer_running: AtomicBool::new(false),
+            change_lock: Mutex::new(()),
+        }
+    }
+
+    #[test]
+    fn test_index_at_offset_wraps_in_both_directions() {
+        let manager = make_manager(3, vec![0, 1, 2]);
+        assert_eq!(manager.index_at_offset(1), 1);
+        assert_eq!(manager.index_at_offset(-1), 2);
+        assert_eq!(manager.index_at_offset(3), 0);
+
+        manager.position.store(2, Ordering::SeqCst);
+        assert_eq!(manager.index_at_offset(1), 0);
+        assert_eq!(manager.index_at_offset(-2), 0);
+    }
+
+    #[test]
+    fn test_index_at_offset_follows_shuffled_order() {
+        let manager = make_manager(3, vec![2, 0, 1]);
+        assert_eq!(manager.index_at_offset(0), 2);
+        assert_eq!(manager.index_at_offset(1), 0);
+        assert_eq!(manager.index_at_offset(-1), 1);
+        assert_eq!(manager.select_initial_index(), 2);
+        assert_eq!(manager.select_next_index(), 0);
+    }
+
+    #[test]
+    fn test_resolve_slideshow_state_prefers_path() {
+        let manager = make_manager(3, vec![0, 1, 2]);
+        let state = SlideshowState {
+            index: 0,
+            path: Some("/slides/2.jpg".to_string()),
+            seed: None,
+        };
+        assert_eq!(manager.resolve_slideshow_state(&state), Some(2));
+    }
+
+    #[test]
+    fn test_resolve_slideshow_state_falls_back_to_index() {
+        let manager = make_manager(3, vec![0, 1, 2]);
+        let state = SlideshowState {
+            index: 1,
+            path: Some("/slides/removed.jpg".to_string()),
+            seed: None,
+        };
+        assert_eq!(manager.resolve_slideshow_state(&state), Some(1));
+
+        let out_of_range = SlideshowState { index: 5, ..Default::default() };
+        assert_eq!(manager.resolve_slideshow_state(&out_of_range), None);
+    }
+
+    #[test]
+    fn test_slideshow_state_roundtrip() {
+        let state = SlideshowState {
+            index: 4,
+            path: Some("/slides/4.jpg".to_string()),
+            seed: Some(42),
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        let parsed: SlideshowState = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, state);
+    }
+
+    #[test]
+    fn test_slideshow_state_without_seed_parses() {
+        let parsed: SlideshowState =
+            serde_json::from_str(r#"{"index":1,"path":"/slides/1.jpg"}"#).unwrap();
+        assert_eq!(parsed.seed, None);
+    }
+
+    #[test]
+    fn test_slideshow_order_is_stable_for_a_seed() {
+        assert_eq!(slideshow_order(4, None), vec![0, 1, 2, 3]);
+
+        let order = slideshow_order(20, Some(7));
+        assert_eq!(order, slideshow_order(20, Some(7)));
+
+        let mut sorted = order.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_load_wallpapers_uses_slideshow_folder() {
+        let config = WallpaperConfig {
+            mode: WallpaperMode::Slideshow {
+                folder: "/nonexistent/slideshow/folder".to_string(),
+                interval: 60,
+                shuffle: false,
+            },
+            ..Default::default()
+        };
+        let result = WallpaperManager::load_wallpapers(&config);
+        assert!(matches!(result, Err(WallpaperManagerError::InvalidPath(_))));
+    }
 }
@@ -5,6 +5,7 @@
 //! - Processing images with rounded corners and Gaussian blur
 //! - Caching processed images to avoid redundant processing
 //! - Automatic wallpaper cycling based on interval settings
+//! - Folder slideshows with optional shuffle and persisted position
 //! - Manual wallpaper control via CLI commands
 //! - Multi-screen support with per-screen wallpapers
 
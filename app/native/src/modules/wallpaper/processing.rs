@@ -15,7 +15,7 @@ use objc::runtime::{Class, Object};
 use objc::{msg_send, sel, sel_impl};
 
 use crate::cache::get_cache_subdir;
-use crate::config::WallpaperConfig;
+use crate::config::{WallpaperConfig, WallpaperScaling};
 
 /// Supported image file extensions.
 const SUPPORTED_EXTENSIONS: &[&str] = &["jpg", "jpeg", "png", "webp"];
@@ -190,8 +190,12 @@ fn cache_filename(source: &Path, config: &WallpaperConfig, screen: ScreenSize) -
     let stem = source.file_stem().and_then(|s| s.to_str()).unwrap_or("wallpaper");
     let source_key = source_cache_key(source);
     format!(
-        "{stem}_{source_key}_{}x{}_r{}_b{}.png",
-        screen.width, screen.height, config.radius, config.blur
+        "{stem}_{source_key}_{}x{}_r{}_b{}{}.png",
+        screen.width,
+        screen.height,
+        config.radius,
+        config.blur,
+        config.scaling.cache_suffix()
     )
 }
 
@@ -206,8 +210,12 @@ fn cache_filename_for_screen(
     let stem = source.file_stem().and_then(|s| s.to_str()).unwrap_or("wallpaper");
     let source_key = source_cache_key(source);
     format!(
-        "{stem}_{source_key}_s{screen_index}_{}x{}_r{}_b{}.png",
-        screen.width, screen.height, config.radius, config.blur
+        "{stem}_{source_key}_s{screen_index}_{}x{}_r{}_b{}{}.png",
+        screen.width,
+        screen.height,
+        config.radius,
+        config.blur,
+        config.scaling.cache_suffix()
     )
 }
 
@@ -321,8 +329,8 @@ fn process_image_internal(
         .decode()
         .map_err(|_| ProcessingError::ImageRead(source.display().to_string()))?;
 
-    // Resize to screen dimensions
-    let resized = resize_to_screen(&img, screen);
+    // Scale to screen dimensions
+    let resized = scale_to_screen(&img, screen, config.scaling);
 
     // Apply processing (blur, rounded corners)
     let processed = apply_effects(resized, config.radius, config.blur);
@@ -393,6 +401,60 @@ pub fn process_image_for_screen(
     process_image_internal(source, config, cache_path, screen)
 }
 
+/// Composes an image onto a canvas matching the screen dimensions.
+///
+/// The processed image always matches the screen size exactly, so the
+/// scaling mode is applied here rather than left to the system.
+fn scale_to_screen(
+    img: &DynamicImage,
+    screen: ScreenSize,
+    scaling: WallpaperScaling,
+) -> DynamicImage {
+    match scaling {
+        WallpaperScaling::Fill => resize_to_screen(img, screen),
+        WallpaperScaling::Fit => fit_to_screen(img, screen),
+        WallpaperScaling::Stretch => img.resize_exact(
+            screen.width,
+            screen.height,
+            image::imageops::FilterType::Lanczos3,
+        ),
+        WallpaperScaling::Center => center_on_screen(img, screen),
+        WallpaperScaling::Tile => tile_on_screen(img, screen),
+    }
+}
+
+/// Resizes an image to fit inside the screen, letterboxing with black.
+fn fit_to_screen(img: &DynamicImage, screen: ScreenSize) -> DynamicImage {
+    // `resize` preserves the aspect ratio and fits within the given bounds
+    let resized = img.resize(
+        screen.width,
+        screen.height,
+        image::imageops::FilterType::Lanczos3,
+    );
+    center_on_screen(&resized, screen)
+}
+
+/// Places an image at its original size in the center of a black canvas,
+/// cropping it if it is larger than the screen.
+fn center_on_screen(img: &DynamicImage, screen: ScreenSize) -> DynamicImage {
+    let (img_width, img_height) = img.dimensions();
+    let mut canvas = RgbImage::new(screen.width, screen.height);
+
+    let x = (i64::from(screen.width) - i64::from(img_width)) / 2;
+    let y = (i64::from(screen.height) - i64::from(img_height)) / 2;
+    imageops::replace(&mut canvas, &img.to_rgb8(), x, y);
+
+    DynamicImage::ImageRgb8(canvas)
+}
+
+/// Repeats an image at its original size across the screen, starting at the top-left.
+fn tile_on_screen(img: &DynamicImage, screen: ScreenSize) -> DynamicImage {
+    let tile = img.to_rgb8();
+    let mut canvas = RgbImage::new(screen.width, screen.height);
+    imageops::tile(&mut canvas, &tile);
+    DynamicImage::ImageRgb8(canvas)
+}
+
 /// Resizes an image to cover the screen dimensions while maintaining aspect ratio.
 ///
 /// Uses "cover" scaling: the image is scaled to fill the entire screen,
@@ -687,6 +749,100 @@ mod tests {
         assert_eq!(h, 100);
     }
 
+    #[test]
+    fn test_scale_to_screen_all_modes_match_screen_size() {
+        let img =
+            DynamicImage::ImageRgb8(RgbImage::from_fn(200, 100, |_, _| Rgb([128u8, 128, 128])));
+        let screen = ScreenSize { width: 120, height: 90 };
+
+        for scaling in [
+            WallpaperScaling::Fill,
+            WallpaperScaling::Fit,
+            WallpaperScaling::Stretch,
+            WallpaperScaling::Center,
+            WallpaperScaling::Tile,
+        ] {
+            let scaled = scale_to_screen(&img, screen, scaling);
+            assert_eq!(scaled.dimensions(), (120, 90), "{scaling:?}");
+        }
+    }
+
+    #[test]
+    fn test_fit_to_screen_letterboxes_with_black() {
+        // 2:1 image on a 1:1 screen leaves bars above and below
+        let img =
+            DynamicImage::ImageRgb8(RgbImage::from_fn(200, 100, |_, _| Rgb([255u8, 255, 255])));
+        let screen = ScreenSize { width: 100, height: 100 };
+
+        let fitted = fit_to_screen(&img, screen).to_rgb8();
+        assert_eq!(fitted.get_pixel(50, 0), &Rgb([0, 0, 0]));
+        assert_eq!(fitted.get_pixel(50, 50), &Rgb([255, 255, 255]));
+        assert_eq!(fitted.get_pixel(50, 99), &Rgb([0, 0, 0]));
+    }
+
+    #[test]
+    fn test_center_on_screen_keeps_original_size() {
+        let img = DynamicImage::ImageRgb8(RgbImage::from_fn(10, 10, |_, _| Rgb([255u8, 0, 0])));
+        let screen = ScreenSize { width: 30, height: 30 };
+
+        let centered = center_on_screen(&img, screen).to_rgb8();
+        assert_eq!(centered.get_pixel(0, 0), &Rgb([0, 0, 0]));
+        assert_eq!(centered.get_pixel(10, 10), &Rgb([255, 0, 0]));
+        assert_eq!(centered.get_pixel(19, 19), &Rgb([255, 0, 0]));
+        assert_eq!(centered.get_pixel(20, 20), &Rgb([0, 0, 0]));
+    }
+
+    #[test]
+    fn test_center_on_screen_crops_larger_image() {
+        let img = DynamicImage::ImageRgb8(RgbImage::from_fn(50, 50, |x, _| {
+            if x < 25 {
+                Rgb([255u8, 0, 0])
+            } else {
+                Rgb([0u8, 0, 255])
+            }
+        }));
+        let screen = ScreenSize { width: 10, height: 10 };
+
+        let centered = center_on_screen(&img, screen).to_rgb8();
+        assert_eq!(centered.dimensions(), (10, 10));
+        assert_eq!(centered.get_pixel(0, 0), &Rgb([255, 0, 0]));
+        assert_eq!(centered.get_pixel(9, 0), &Rgb([0, 0, 255]));
+    }
+
+    #[test]
+    fn test_tile_on_screen_repeats_image() {
+        let img = DynamicImage::ImageRgb8(RgbImage::from_fn(2, 2, |x, y| {
+            if (x, y) == (0, 0) {
+                Rgb([255u8, 255, 255])
+            } else {
+                Rgb([0u8, 0, 0])
+            }
+        }));
+        let screen = ScreenSize { width: 6, height: 4 };
+
+        let tiled = tile_on_screen(&img, screen).to_rgb8();
+        assert_eq!(tiled.get_pixel(0, 0), &Rgb([255, 255, 255]));
+        assert_eq!(tiled.get_pixel(2, 0), &Rgb([255, 255, 255]));
+        assert_eq!(tiled.get_pixel(4, 2), &Rgb([255, 255, 255]));
+        assert_eq!(tiled.get_pixel(1, 0), &Rgb([0, 0, 0]));
+    }
+
+    #[test]
+    fn test_cache_filename_includes_scaling_suffix() {
+        let screen = ScreenSize { width: 1920, height: 1080 };
+        let path = Path::new("/path/to/wallpaper.jpg");
+        let fill = WallpaperConfig::default();
+        let fit = WallpaperConfig {
+            scaling: WallpaperScaling::Fit,
+            ..Default::default()
+        };
+
+        let fill_name = cache_filename(path, &fill, screen);
+        let fit_name = cache_filename(path, &fit, screen);
+        assert!(fill_name.ends_with("_b0.png"));
+        assert!(fit_name.ends_with("_b0_fit.png"));
+    }
+
     // ========================================================================
     // ScreenSize tests
     // ========================================================================
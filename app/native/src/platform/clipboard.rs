@@ -0,0 +1,38 @@
+//! Clipboard access via `NSPasteboard`.
+
+use std::ffi::c_void;
+
+use objc::runtime::Object;
+use objc::{class, msg_send, sel, sel_impl};
+
+use super::objc::nsstring;
+
+/// Uniform type identifier of PNG data (`NSPasteboardTypePNG`).
+const PASTEBOARD_TYPE_PNG: &str = "public.png";
+
+/// Replaces the contents of the general pasteboard with PNG image data.
+///
+/// Returns whether the data was written.
+#[must_use]
+pub fn copy_png(data: &[u8]) -> bool {
+    // SAFETY: The pasteboard is obtained via the standard generalPasteboard
+    // method, and NSData copies the bytes before this function returns.
+    unsafe {
+        let pasteboard: *mut Object = msg_send![class!(NSPasteboard), generalPasteboard];
+        if pasteboard.is_null() {
+            return false;
+        }
+
+        let ns_data: *mut Object = msg_send![
+            class!(NSData),
+            dataWithBytes: data.as_ptr().cast::<c_void>()
+            length: data.len()
+        ];
+        if ns_data.is_null() {
+            return false;
+        }
+
+        let _: isize = msg_send![pasteboard, clearContents];
+        msg_send![pasteboard, setData: ns_data forType: nsstring(PASTEBOARD_TYPE_PNG)]
+    }
+}
@@ -43,10 +43,20 @@ pub enum StacheNotification {
     WorkspaceChanged(String),
     /// Reload configuration request.
     Reload,
+    /// Show the bar window.
+    BarShow,
+    /// Hide the bar window.
+    BarHide,
+    /// Show the bar window if hidden, hide it otherwise.
+    BarToggle,
+    /// Change the background material of the bar windows.
+    BarMaterial(String),
 
     // Tiling window manager notifications
     /// Focus a workspace by name.
     TilingFocusWorkspace(String),
+    /// Focus the Nth workspace (1-based) of the focused screen.
+    TilingFocusWorkspaceIndex(usize),
     /// Change layout of focused workspace.
     TilingSetLayout(String),
     /// Focus window in direction or by ID.
@@ -57,14 +67,58 @@ pub enum StacheNotification {
     TilingWindowResize { dimension: String, amount: i32 },
     /// Apply floating preset to focused window.
     TilingWindowPreset(String),
-    /// Send focused window to workspace.
-    TilingWindowSendToWorkspace(String),
+    /// Send focused window to workspace, optionally following it there.
+    TilingWindowSendToWorkspace { workspace: String, follow: bool },
+    /// Send focused window to the Nth workspace (1-based) of the focused screen.
+    TilingWindowSendToWorkspaceIndex { index: usize, follow: bool },
+    /// Toggle whether the focused window is also shown in a workspace.
+    TilingWindowAlsoWorkspace(String),
     /// Send focused window to screen.
     TilingWindowSendToScreen(String),
+    /// Minimize the focused window.
+    TilingWindowMinimize,
+    /// Restore the most recently minimized window of the focused workspace.
+    TilingWindowRestore,
+    /// Move the focused window one position earlier in the stack.
+    TilingWindowPromote,
+    /// Move the focused window one position later in the stack.
+    TilingWindowDemote,
+    /// Start moving the focused window with the arrow keys.
+    TilingWindowMoveMode,
     /// Balance focused workspace.
     TilingWorkspaceBalance,
+    /// Toggle the focused workspace to the alternate layout and back.
+    TilingWorkspaceToggleLayout,
+    /// Let `tiling.autoLayout` pick the focused workspace's layout again.
+    TilingWorkspaceAutoLayout,
+    /// Set where the master window sits in the focused workspace.
+    TilingSetMasterPosition(String),
+    /// Toggle gaps off or back on for the focused workspace, or all of them.
+    TilingToggleGaps { all: bool },
+    /// Grow or shrink the gaps of the focused screen, in pixels.
+    TilingAdjustGaps { inner: i32, outer: i32 },
+    /// Drop runtime gap adjustments.
+    TilingResetGaps,
     /// Send focused workspace to screen.
     TilingWorkspaceSendToScreen(String),
+    /// Swap the visible workspaces of two screens.
+    TilingWorkspaceSwapScreens { screen_a: String, screen_b: String },
+    /// Focus the visible workspace on another screen.
+    TilingFocusScreen(String),
+    /// Move the mouse cursor to the center of the focused screen.
+    TilingCenterCursor,
+    /// Gather windows into the focused workspace, optionally only one app's.
+    TilingWorkspaceGather(Option<String>),
+    /// Make the windows of an app adjacent in the focused workspace.
+    TilingAppGather(String),
+    /// Pause window management.
+    TilingPause,
+    /// Resume window management.
+    TilingResume,
+    /// Resync tracked windows with the windows that exist.
+    TilingReconcile,
+    /// Tear down and re-initialize tiling.
+    TilingRestart,
 }
 
 impl StacheNotification {
@@ -74,17 +128,46 @@ impl StacheNotification {
             Self::WindowFocusChanged => "window-focus-changed",
             Self::WorkspaceChanged(_) => "workspace-changed",
             Self::Reload => "reload",
+            Self::BarShow => "bar-show",
+            Self::BarHide => "bar-hide",
+            Self::BarToggle => "bar-toggle",
+            Self::BarMaterial(_) => "bar-material",
             // Tiling notifications
             Self::TilingFocusWorkspace(_) => "tiling-focus-workspace",
+            Self::TilingFocusWorkspaceIndex(_) => "tiling-focus-workspace-index",
             Self::TilingSetLayout(_) => "tiling-set-layout",
             Self::TilingWindowFocus(_) => "tiling-window-focus",
             Self::TilingWindowSwap(_) => "tiling-window-swap",
             Self::TilingWindowResize { .. } => "tiling-window-resize",
             Self::TilingWindowPreset(_) => "tiling-window-preset",
-            Self::TilingWindowSendToWorkspace(_) => "tiling-window-send-to-workspace",
+            Self::TilingWindowSendToWorkspace { .. } => "tiling-window-send-to-workspace",
+            Self::TilingWindowSendToWorkspaceIndex { .. } => {
+                "tiling-window-send-to-workspace-index"
+            }
+            Self::TilingWindowAlsoWorkspace(_) => "tiling-window-also-workspace",
             Self::TilingWindowSendToScreen(_) => "tiling-window-send-to-screen",
+            Self::TilingWindowMinimize => "tiling-window-minimize",
+            Self::TilingWindowRestore => "tiling-window-restore",
+            Self::TilingWindowPromote => "tiling-window-promote",
+            Self::TilingWindowDemote => "tiling-window-demote",
+            Self::TilingWindowMoveMode => "tiling-window-move-mode",
             Self::TilingWorkspaceBalance => "tiling-workspace-balance",
+            Self::TilingWorkspaceToggleLayout => "tiling-workspace-toggle-layout",
+            Self::TilingWorkspaceAutoLayout => "tiling-workspace-auto-layout",
+            Self::TilingSetMasterPosition(_) => "tiling-set-master-position",
+            Self::TilingToggleGaps { .. } => "tiling-toggle-gaps",
+            Self::TilingAdjustGaps { .. } => "tiling-adjust-gaps",
+            Self::TilingResetGaps => "tiling-reset-gaps",
             Self::TilingWorkspaceSendToScreen(_) => "tiling-workspace-send-to-screen",
+            Self::TilingWorkspaceSwapScreens { .. } => "tiling-workspace-swap-screens",
+            Self::TilingFocusScreen(_) => "tiling-focus-screen",
+            Self::TilingCenterCursor => "tiling-center-cursor",
+            Self::TilingWorkspaceGather(_) => "tiling-workspace-gather",
+            Self::TilingAppGather(_) => "tiling-app-gather",
+            Self::TilingPause => "tiling-pause",
+            Self::TilingResume => "tiling-resume",
+            Self::TilingReconcile => "tiling-reconcile",
+            Self::TilingRestart => "tiling-restart",
         };
         format!("{NOTIFICATION_PREFIX}{suffix}")
     }
@@ -93,9 +176,14 @@ impl StacheNotification {
     fn user_info(&self) -> Option<Vec<(&str, String)>> {
         match self {
             Self::WorkspaceChanged(name) => Some(vec![("workspace", name.clone())]),
+            Self::BarMaterial(material) => Some(vec![("material", material.clone())]),
             // Tiling notifications with parameters
-            Self::TilingFocusWorkspace(workspace) => Some(vec![("workspace", workspace.clone())]),
+            Self::TilingFocusWorkspace(workspace) | Self::TilingWindowAlsoWorkspace(workspace) => {
+                Some(vec![("workspace", workspace.clone())])
+            }
+            Self::TilingFocusWorkspaceIndex(index) => Some(vec![("index", index.to_string())]),
             Self::TilingSetLayout(layout) => Some(vec![("layout", layout.clone())]),
+            Self::TilingSetMasterPosition(position) => Some(vec![("position", position.clone())]),
             Self::TilingWindowFocus(target) => Some(vec![("target", target.clone())]),
             Self::TilingWindowSwap(direction) => Some(vec![("direction", direction.clone())]),
             Self::TilingWindowResize { dimension, amount } => Some(vec![
@@ -103,12 +191,28 @@ impl StacheNotification {
                 ("amount", amount.to_string()),
             ]),
             Self::TilingWindowPreset(preset) => Some(vec![("preset", preset.clone())]),
-            Self::TilingWindowSendToWorkspace(workspace) => {
-                Some(vec![("workspace", workspace.clone())])
+            Self::TilingWindowSendToWorkspace { workspace, follow } => Some(vec![
+                ("workspace", workspace.clone()),
+                ("follow", follow.to_string()),
+            ]),
+            Self::TilingWindowSendToWorkspaceIndex { index, follow } => Some(vec![
+                ("index", index.to_string()),
+                ("follow", follow.to_string()),
+            ]),
+            Self::TilingToggleGaps { all } => Some(vec![("all", all.to_string())]),
+            Self::TilingAdjustGaps { inner, outer } => {
+                Some(vec![("inner", inner.to_string()), ("outer", outer.to_string())])
             }
-            Self::TilingWindowSendToScreen(screen) | Self::TilingWorkspaceSendToScreen(screen) => {
-                Some(vec![("screen", screen.clone())])
+            Self::TilingWindowSendToScreen(screen)
+            | Self::TilingWorkspaceSendToScreen(screen)
+            | Self::TilingFocusScreen(screen) => Some(vec![("screen", screen.clone())]),
+            Self::TilingWorkspaceGather(Some(app)) | Self::TilingAppGather(app) => {
+                Some(vec![("app", app.clone())])
             }
+            Self::TilingWorkspaceSwapScreens { screen_a, screen_b } => Some(vec![
+                ("screenA", screen_a.clone()),
+                ("screenB", screen_b.clone()),
+            ]),
             _ => None,
         }
     }
@@ -128,12 +232,27 @@ impl StacheNotification {
                 Some(Self::WorkspaceChanged(workspace))
             }
             "reload" => Some(Self::Reload),
+            "bar-show" => Some(Self::BarShow),
+            "bar-hide" => Some(Self::BarHide),
+            "bar-toggle" => Some(Self::BarToggle),
+            "bar-material" => {
+                let material =
+                    user_info.and_then(|info| info.get("material")).cloned().unwrap_or_default();
+                Some(Self::BarMaterial(material))
+            }
             // Tiling notifications
             "tiling-focus-workspace" => {
                 let workspace =
                     user_info.and_then(|info| info.get("workspace")).cloned().unwrap_or_default();
                 Some(Self::TilingFocusWorkspace(workspace))
             }
+            "tiling-focus-workspace-index" => {
+                let index = user_info
+                    .and_then(|info| info.get("index"))
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(0);
+                Some(Self::TilingFocusWorkspaceIndex(index))
+            }
             "tiling-set-layout" => {
                 let layout =
                     user_info.and_then(|info| info.get("layout")).cloned().unwrap_or_default();
@@ -166,7 +285,26 @@ impl StacheNotification {
             "tiling-window-send-to-workspace" => {
                 let workspace =
                     user_info.and_then(|info| info.get("workspace")).cloned().unwrap_or_default();
-                Some(Self::TilingWindowSendToWorkspace(workspace))
+                // Missing `follow` keeps the default of following the window
+                let follow = user_info
+                    .and_then(|info| info.get("follow"))
+                    .is_none_or(|follow| follow != "false");
+                Some(Self::TilingWindowSendToWorkspace { workspace, follow })
+            }
+            "tiling-window-send-to-workspace-index" => {
+                let index = user_info
+                    .and_then(|info| info.get("index"))
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(0);
+                let follow = user_info
+                    .and_then(|info| info.get("follow"))
+                    .is_none_or(|follow| follow != "false");
+                Some(Self::TilingWindowSendToWorkspaceIndex { index, follow })
+            }
+            "tiling-window-also-workspace" => {
+                let workspace =
+                    user_info.and_then(|info| info.get("workspace")).cloned().unwrap_or_default();
+                Some(Self::TilingWindowAlsoWorkspace(workspace))
             }
             "tiling-window-send-to-screen" => {
                 let screen =
@@ -174,11 +312,66 @@ impl StacheNotification {
                 Some(Self::TilingWindowSendToScreen(screen))
             }
             "tiling-workspace-balance" => Some(Self::TilingWorkspaceBalance),
+            "tiling-workspace-toggle-layout" => Some(Self::TilingWorkspaceToggleLayout),
+            "tiling-workspace-auto-layout" => Some(Self::TilingWorkspaceAutoLayout),
+            "tiling-set-master-position" => {
+                let position =
+                    user_info.and_then(|info| info.get("position")).cloned().unwrap_or_default();
+                Some(Self::TilingSetMasterPosition(position))
+            }
+            "tiling-toggle-gaps" => {
+                let all =
+                    user_info.and_then(|info| info.get("all")).is_some_and(|all| all == "true");
+                Some(Self::TilingToggleGaps { all })
+            }
+            "tiling-adjust-gaps" => {
+                let parse = |key| {
+                    user_info
+                        .and_then(|info| info.get(key))
+                        .and_then(|s| s.parse().ok())
+                        .unwrap_or(0)
+                };
+                Some(Self::TilingAdjustGaps {
+                    inner: parse("inner"),
+                    outer: parse("outer"),
+                })
+            }
+            "tiling-reset-gaps" => Some(Self::TilingResetGaps),
             "tiling-workspace-send-to-screen" => {
                 let screen =
                     user_info.and_then(|info| info.get("screen")).cloned().unwrap_or_default();
                 Some(Self::TilingWorkspaceSendToScreen(screen))
             }
+            "tiling-workspace-swap-screens" => {
+                let screen_a =
+                    user_info.and_then(|info| info.get("screenA")).cloned().unwrap_or_default();
+                let screen_b =
+                    user_info.and_then(|info| info.get("screenB")).cloned().unwrap_or_default();
+                Some(Self::TilingWorkspaceSwapScreens { screen_a, screen_b })
+            }
+            "tiling-focus-screen" => {
+                let screen =
+                    user_info.and_then(|info| info.get("screen")).cloned().unwrap_or_default();
+                Some(Self::TilingFocusScreen(screen))
+            }
+            "tiling-center-cursor" => Some(Self::TilingCenterCursor),
+            "tiling-workspace-gather" => {
+                let app = user_info.and_then(|info| info.get("app")).cloned();
+                Some(Self::TilingWorkspaceGather(app))
+            }
+            "tiling-app-gather" => {
+                let app = user_info.and_then(|info| info.get("app")).cloned().unwrap_or_default();
+                Some(Self::TilingAppGather(app))
+            }
+            "tiling-window-minimize" => Some(Self::TilingWindowMinimize),
+            "tiling-window-restore" => Some(Self::TilingWindowRestore),
+            "tiling-window-promote" => Some(Self::TilingWindowPromote),
+            "tiling-window-demote" => Some(Self::TilingWindowDemote),
+            "tiling-window-move-mode" => Some(Self::TilingWindowMoveMode),
+            "tiling-pause" => Some(Self::TilingPause),
+            "tiling-resume" => Some(Self::TilingResume),
+            "tiling-reconcile" => Some(Self::TilingReconcile),
+            "tiling-restart" => Some(Self::TilingRestart),
             _ => None,
         }
     }
@@ -303,17 +496,44 @@ pub fn start_notification_listener() {
             format!("{NOTIFICATION_PREFIX}window-focus-changed"),
             format!("{NOTIFICATION_PREFIX}workspace-changed"),
             format!("{NOTIFICATION_PREFIX}reload"),
+            format!("{NOTIFICATION_PREFIX}bar-show"),
+            format!("{NOTIFICATION_PREFIX}bar-hide"),
+            format!("{NOTIFICATION_PREFIX}bar-toggle"),
+            format!("{NOTIFICATION_PREFIX}bar-material"),
             // Tiling notifications
             format!("{NOTIFICATION_PREFIX}tiling-focus-workspace"),
+            format!("{NOTIFICATION_PREFIX}tiling-focus-workspace-index"),
             format!("{NOTIFICATION_PREFIX}tiling-set-layout"),
+            format!("{NOTIFICATION_PREFIX}tiling-set-master-position"),
             format!("{NOTIFICATION_PREFIX}tiling-window-focus"),
             format!("{NOTIFICATION_PREFIX}tiling-window-swap"),
             format!("{NOTIFICATION_PREFIX}tiling-window-resize"),
             format!("{NOTIFICATION_PREFIX}tiling-window-preset"),
             format!("{NOTIFICATION_PREFIX}tiling-window-send-to-workspace"),
+            format!("{NOTIFICATION_PREFIX}tiling-window-send-to-workspace-index"),
+            format!("{NOTIFICATION_PREFIX}tiling-window-also-workspace"),
             format!("{NOTIFICATION_PREFIX}tiling-window-send-to-screen"),
+            format!("{NOTIFICATION_PREFIX}tiling-window-minimize"),
+            format!("{NOTIFICATION_PREFIX}tiling-window-restore"),
+            format!("{NOTIFICATION_PREFIX}tiling-window-promote"),
+            format!("{NOTIFICATION_PREFIX}tiling-window-demote"),
+            format!("{NOTIFICATION_PREFIX}tiling-window-move-mode"),
             format!("{NOTIFICATION_PREFIX}tiling-workspace-balance"),
+            format!("{NOTIFICATION_PREFIX}tiling-workspace-toggle-layout"),
+            format!("{NOTIFICATION_PREFIX}tiling-workspace-auto-layout"),
+            format!("{NOTIFICATION_PREFIX}tiling-toggle-gaps"),
+            format!("{NOTIFICATION_PREFIX}tiling-adjust-gaps"),
+            format!("{NOTIFICATION_PREFIX}tiling-reset-gaps"),
             format!("{NOTIFICATION_PREFIX}tiling-workspace-send-to-screen"),
+            format!("{NOTIFICATION_PREFIX}tiling-workspace-swap-screens"),
+            format!("{NOTIFICATION_PREFIX}tiling-focus-screen"),
+            format!("{NOTIFICATION_PREFIX}tiling-center-cursor"),
+            format!("{NOTIFICATION_PREFIX}tiling-workspace-gather"),
+            format!("{NOTIFICATION_PREFIX}tiling-app-gather"),
+            format!("{NOTIFICATION_PREFIX}tiling-pause"),
+            format!("{NOTIFICATION_PREFIX}tiling-resume"),
+            format!("{NOTIFICATION_PREFIX}tiling-reconcile"),
+            format!("{NOTIFICATION_PREFIX}tiling-restart"),
         ];
 
         for notification_name in &notifications {
@@ -510,6 +730,156 @@ mod tests {
         assert_eq!(notification, Some(StacheNotification::Reload));
     }
 
+    #[test]
+    fn test_send_to_workspace_follow_round_trip() {
+        let notification = StacheNotification::TilingWindowSendToWorkspace {
+            workspace: "web".to_string(),
+            follow: false,
+        };
+        let user_info: std::collections::HashMap<String, String> = notification
+            .user_info()
+            .unwrap()
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect();
+
+        let parsed = StacheNotification::from_notification(
+            &notification.notification_name(),
+            Some(&user_info),
+        );
+        assert_eq!(parsed, Some(notification));
+    }
+
+    #[test]
+    fn test_workspace_target_round_trip() {
+        for notification in [
+            StacheNotification::TilingWindowAlsoWorkspace("comms".to_string()),
+            StacheNotification::TilingFocusWorkspaceIndex(2),
+            StacheNotification::TilingWindowSendToWorkspaceIndex { index: 3, follow: false },
+        ] {
+            let user_info: std::collections::HashMap<String, String> = notification
+                .user_info()
+                .unwrap()
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect();
+
+            let parsed = StacheNotification::from_notification(
+                &notification.notification_name(),
+                Some(&user_info),
+            );
+            assert_eq!(parsed, Some(notification));
+        }
+    }
+
+    #[test]
+    fn test_bar_visibility_round_trip() {
+        for notification in [
+            StacheNotification::BarShow,
+            StacheNotification::BarHide,
+            StacheNotification::BarToggle,
+        ] {
+            assert!(notification.user_info().is_none());
+            let parsed =
+                StacheNotification::from_notification(&notification.notification_name(), None);
+            assert_eq!(parsed, Some(notification));
+        }
+    }
+
+    #[test]
+    fn test_center_cursor_round_trip() {
+        let notification = StacheNotification::TilingCenterCursor;
+        assert!(notification.user_info().is_none());
+        let parsed = StacheNotification::from_notification(&notification.notification_name(), None);
+        assert_eq!(parsed, Some(notification));
+    }
+
+    #[test]
+    fn test_bar_material_round_trip() {
+        let notification = StacheNotification::BarMaterial("menu".to_string());
+        let user_info: std::collections::HashMap<String, String> = notification
+            .user_info()
+            .unwrap()
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect();
+
+        let parsed = StacheNotification::from_notification(
+            &notification.notification_name(),
+            Some(&user_info),
+        );
+        assert_eq!(parsed, Some(notification));
+    }
+
+    #[test]
+    fn test_set_master_position_round_trip() {
+        let notification = StacheNotification::TilingSetMasterPosition("top".to_string());
+        let user_info: std::collections::HashMap<String, String> = notification
+            .user_info()
+            .unwrap()
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect();
+
+        let parsed = StacheNotification::from_notification(
+            &notification.notification_name(),
+            Some(&user_info),
+        );
+        assert_eq!(parsed, Some(notification));
+    }
+
+    #[test]
+    fn test_toggle_gaps_round_trip() {
+        let notification = StacheNotification::TilingToggleGaps { all: true };
+        let user_info: std::collections::HashMap<String, String> = notification
+            .user_info()
+            .unwrap()
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect();
+
+        let parsed = StacheNotification::from_notification(
+            &notification.notification_name(),
+            Some(&user_info),
+        );
+        assert_eq!(parsed, Some(notification));
+    }
+
+    #[test]
+    fn test_adjust_gaps_round_trip() {
+        let notification = StacheNotification::TilingAdjustGaps { inner: 4, outer: -4 };
+        let user_info: std::collections::HashMap<String, String> = notification
+            .user_info()
+            .unwrap()
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect();
+
+        let parsed = StacheNotification::from_notification(
+            &notification.notification_name(),
+            Some(&user_info),
+        );
+        assert_eq!(parsed, Some(notification));
+    }
+
+    #[test]
+    fn test_send_to_workspace_follows_by_default() {
+        let mut user_info = std::collections::HashMap::new();
+        user_info.insert("workspace".to_string(), "web".to_string());
+
+        let notification = StacheNotification::from_notification(
+            "com.marcosmoura.stache.tiling-window-send-to-workspace",
+            Some(&user_info),
+        );
+        assert_eq!(
+            notification,
+            Some(StacheNotification::TilingWindowSendToWorkspace {
+                workspace: "web".to_string(),
+                follow: true,
+            })
+        );
+    }
+
     #[test]
     fn test_from_notification_unknown() {
         let notification =
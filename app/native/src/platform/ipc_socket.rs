@@ -9,6 +9,22 @@
 //! - CLI commands connect to the socket, send a JSON query, and receive a JSON response
 //! - If the socket doesn't exist or connection fails, the app is not running
 //!
+//! # Socket Path
+//!
+//! The socket lives under the user's runtime directory when the platform has
+//! one, and at `~/Library/Caches/{APP_BUNDLE_ID}/stache.sock` otherwise. Set
+//! `STACHE_SOCKET_PATH` to override it, e.g. to run two builds side by side
+//! without them fighting over one socket, or when the default location is
+//! not writable. The CLI reads the same variable to find the app, so both
+//! must agree. Keep the path short: macOS limits socket paths to 104 bytes.
+//!
+//! On startup, a socket left behind by a crashed instance is detected (nothing
+//! accepts connections on it) and removed before binding, which avoids
+//! "address already in use" errors. A socket another instance is still
+//! serving is left alone, and the server is not started. On shutdown, the
+//! socket is only removed if it is still the one this process bound, so an
+//! instance that took over the path keeps its socket.
+//!
 //! # Query Format
 //!
 //! Queries are JSON objects with a `type` field and optional parameters:
@@ -28,20 +44,26 @@
 //! {"error": "Tiling not initialized"}
 //! ```
 
+use std::ffi::OsString;
 use std::io::{BufRead, BufReader, Write};
+use std::os::unix::fs::{FileTypeExt, MetadataExt};
 use std::os::unix::net::{UnixListener, UnixStream};
-use std::path::PathBuf;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
 use std::thread;
 
 use serde::{Deserialize, Serialize};
 
 use crate::cache::get_cache_dir;
+use crate::config::DimensionValue;
 
-/// Socket filename within the cache directory.
+/// Socket filename within the runtime or cache directory.
 const SOCKET_FILENAME: &str = "stache.sock";
 
+/// Environment variable that overrides the socket path for both app and CLI.
+pub const SOCKET_PATH_ENV: &str = "STACHE_SOCKET_PATH";
+
 /// Default timeout for socket operations in milliseconds.
 const DEFAULT_TIMEOUT_MS: u64 = 5000;
 
@@ -54,6 +76,9 @@ const RETRY_DELAY_MS: u64 = 100;
 /// Whether the server is running.
 static SERVER_RUNNING: AtomicBool = AtomicBool::new(false);
 
+/// Path and inode of the socket this process bound, if any.
+static BOUND_SOCKET: Mutex<Option<(PathBuf, u64)>> = Mutex::new(None);
+
 // ============================================================================
 // Query Types
 // ============================================================================
@@ -88,9 +113,109 @@ pub enum IpcQuery {
         detailed: bool,
     },
 
+    /// Query the computed layout of a workspace by name.
+    ///
+    /// Returns the layout type, split ratios and the frame the engine would
+    /// apply to each tiled window, in layout order.
+    Layout { workspace: String },
+
+    /// Query layout diagnostics of a workspace by name.
+    ///
+    /// Returns the layout type, split ratios, whether the applied layout is
+    /// still up to date, and each window's tracked frame next to its current
+    /// on-screen frame. Read-only.
+    Diagnostics { workspace: String },
+
+    /// Query the window tree of a workspace, for `stache tiling tree`.
+    ///
+    /// Returns the visible frame of the workspace's screen and each of its
+    /// windows with title and frame, tiled windows at their computed layout
+    /// frame. Defaults to the focused workspace.
+    Tree {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        workspace: Option<String>,
+    },
+
+    /// Query tracked windows that no longer have an accessibility element.
+    ///
+    /// With `prune`, the phantom windows are also untracked and the layout of
+    /// their workspaces is re-applied.
+    PhantomWindows {
+        #[serde(default)]
+        prune: bool,
+    },
+
+    /// Query which rules match a tracked window, for `stache tiling explain`.
+    ///
+    /// Returns the ignore rules evaluated, the display and workspace rules
+    /// matching the window, the fallback used when no rule put the window on
+    /// its workspace, and the resulting workspace and screen.
+    Explain {
+        #[serde(rename = "windowId")]
+        window_id: u32,
+    },
+
+    /// Set the focused window's frame, for `stache tiling window --place`.
+    ///
+    /// Positions are relative to the screen's visible frame, or to the area
+    /// inside the outer gaps with `gaps`. Returns the frame applied.
+    PlaceWindow {
+        x: DimensionValue,
+        y: DimensionValue,
+        width: DimensionValue,
+        height: DimensionValue,
+        #[serde(default)]
+        gaps: bool,
+    },
+
+    /// Toggle whether the focused window is kept above normal windows, for
+    /// `stache tiling window --always-on-top`.
+    ///
+    /// Returns the window ID and its new state, or an error if the window
+    /// server refused the new level.
+    ToggleAlwaysOnTop,
+
+    /// Set the opacity of the focused window, in percent, for
+    /// `stache tiling window --opacity`.
+    ///
+    /// Returns the window ID and its new opacity, or an error if the window
+    /// server refused it.
+    SetWindowOpacity { opacity: u8 },
+
+    /// Query the internal tiling performance counters.
+    ///
+    /// With `reset`, the counters are set back to zero after being read.
+    Metrics {
+        #[serde(default)]
+        reset: bool,
+    },
+
+    /// Take a screenshot, for `stache screenshot`.
+    ///
+    /// Captures the focused window, or with `workspace` the bounding box of
+    /// the visible windows of the focused workspace. With `onlyWindows`, the
+    /// bar, borders and anything else on top are left out. Writes a PNG to
+    /// `output`, an absolute path, or to the clipboard without one. Returns
+    /// the path, size and captured window IDs.
+    Screenshot {
+        #[serde(default)]
+        workspace: bool,
+        #[serde(default, rename = "onlyWindows")]
+        only_windows: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        output: Option<String>,
+    },
+
     /// Query all running applications (excluding ignored apps).
     Apps,
 
+    /// Query recently activated applications, most recent first.
+    RecentApps,
+
+    /// Activate the second most recently activated application, for
+    /// `stache app switch`. Returns the activated app.
+    SwitchApp,
+
     /// Ping to check if app is running.
     Ping,
 
@@ -148,19 +273,75 @@ impl IpcResponse {
 // ============================================================================
 
 /// Gets the path to the IPC socket.
+///
+/// Uses `STACHE_SOCKET_PATH` when set, otherwise a path under the user's
+/// runtime directory, falling back to the cache directory.
 #[must_use]
-pub fn get_socket_path() -> PathBuf { get_cache_dir().join(SOCKET_FILENAME) }
+pub fn get_socket_path() -> PathBuf { socket_path_from(std::env::var_os(SOCKET_PATH_ENV)) }
+
+/// Resolves the socket path from the value of `STACHE_SOCKET_PATH`.
+fn socket_path_from(override_path: Option<OsString>) -> PathBuf {
+    override_path.filter(|path| !path.is_empty()).map_or_else(
+        || {
+            dirs::runtime_dir()
+                .map(|dir| dir.join(crate::constants::APP_BUNDLE_ID))
+                .unwrap_or_else(get_cache_dir)
+                .join(SOCKET_FILENAME)
+        },
+        PathBuf::from,
+    )
+}
 
 /// Checks if the socket file exists.
 #[must_use]
 #[allow(dead_code)]
 pub fn socket_exists() -> bool { get_socket_path().exists() }
 
-/// Removes the socket file if it exists.
+/// Removes the socket this process bound, unless another instance has
+/// replaced it since.
 fn remove_socket() {
-    let path = get_socket_path();
-    if path.exists() {
-        let _ = std::fs::remove_file(&path);
+    let bound = BOUND_SOCKET.lock().unwrap_or_else(PoisonError::into_inner).take();
+    if let Some((path, inode)) = bound
+        && !remove_owned_socket(&path, inode)
+    {
+        tracing::debug!(path = %path.display(), "ipc socket was replaced, leaving it in place");
+    }
+}
+
+/// Removes the file at `path` if it is still the one with `inode`.
+///
+/// Returns `false` if the file is gone or is another one.
+fn remove_owned_socket(path: &Path, inode: u64) -> bool {
+    let owned = std::fs::symlink_metadata(path).is_ok_and(|metadata| metadata.ino() == inode);
+    owned && std::fs::remove_file(path).is_ok()
+}
+
+/// State of an existing file at the socket path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExistingSocket {
+    /// Nothing exists at the path.
+    None,
+    /// A socket nobody is listening on, left behind by a crashed instance.
+    Stale,
+    /// A socket another running instance is serving.
+    Live,
+    /// Something that is not a socket; never removed.
+    NotASocket,
+}
+
+/// Inspects the file at `path` with a connect test.
+fn inspect_existing_socket(path: &Path) -> ExistingSocket {
+    let Ok(metadata) = std::fs::symlink_metadata(path) else {
+        return ExistingSocket::None;
+    };
+
+    if !metadata.file_type().is_socket() {
+        return ExistingSocket::NotASocket;
+    }
+
+    match UnixStream::connect(path) {
+        Ok(_) => ExistingSocket::Live,
+        Err(_) => ExistingSocket::Stale,
     }
 }
 
@@ -183,14 +364,36 @@ where F: Fn(IpcQuery) -> IpcResponse + Send + Sync + 'static {
         return;
     }
 
-    // Remove any stale socket file
-    remove_socket();
-
     let socket_path = get_socket_path();
 
+    // Remove a socket left behind by a crashed instance, but never take over
+    // one that is still served or delete a file that is not a socket
+    match inspect_existing_socket(&socket_path) {
+        ExistingSocket::None => {}
+        ExistingSocket::Stale => {
+            tracing::debug!(path = %socket_path.display(), "removing stale ipc socket");
+            let _ = std::fs::remove_file(&socket_path);
+        }
+        ExistingSocket::Live => {
+            tracing::error!(
+                path = %socket_path.display(),
+                "ipc socket is in use by another instance; set {SOCKET_PATH_ENV} to use a different path"
+            );
+            SERVER_RUNNING.store(false, Ordering::SeqCst);
+            return;
+        }
+        ExistingSocket::NotASocket => {
+            tracing::error!(path = %socket_path.display(), "ipc socket path exists and is not a socket");
+            SERVER_RUNNING.store(false, Ordering::SeqCst);
+            return;
+        }
+    }
+
     // Ensure parent directory exists
-    if let Some(parent) = socket_path.parent() {
-        let _ = std::fs::create_dir_all(parent);
+    if let Some(parent) = socket_path.parent()
+        && let Err(e) = std::fs::create_dir_all(parent)
+    {
+        tracing::warn!(error = %e, path = %parent.display(), "failed to create ipc socket directory");
     }
 
     // Bind the socket
@@ -203,6 +406,12 @@ where F: Fn(IpcQuery) -> IpcResponse + Send + Sync + 'static {
         }
     };
 
+    // Remember which file we bound, so shutdown never removes another instance's socket
+    if let Ok(metadata) = std::fs::symlink_metadata(&socket_path) {
+        *BOUND_SOCKET.lock().unwrap_or_else(PoisonError::into_inner) =
+            Some((socket_path.clone(), metadata.ino()));
+    }
+
     // Restrict socket permissions to owner-only (0o600) to prevent other
     // local users from querying or injecting commands into the tiling WM.
     #[cfg(unix)]
@@ -433,6 +642,60 @@ mod tests {
         assert!(path.to_string_lossy().contains("stache.sock"));
     }
 
+    #[test]
+    fn test_socket_path_env_override() {
+        let path = socket_path_from(Some(OsString::from("/tmp/stache-dev.sock")));
+        assert_eq!(path, PathBuf::from("/tmp/stache-dev.sock"));
+    }
+
+    #[test]
+    fn test_socket_path_ignores_empty_override() {
+        let path = socket_path_from(Some(OsString::new()));
+        assert!(path.ends_with(SOCKET_FILENAME));
+        assert_eq!(path, socket_path_from(None));
+    }
+
+    #[test]
+    fn test_inspect_existing_socket() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.sock");
+
+        assert_eq!(inspect_existing_socket(&path), ExistingSocket::None);
+
+        let listener = UnixListener::bind(&path).unwrap();
+        assert_eq!(inspect_existing_socket(&path), ExistingSocket::Live);
+
+        // Dropping the listener leaves the socket file behind, like a crash
+        drop(listener);
+        assert_eq!(inspect_existing_socket(&path), ExistingSocket::Stale);
+
+        let file = dir.path().join("file.txt");
+        std::fs::write(&file, "not a socket").unwrap();
+        assert_eq!(inspect_existing_socket(&file), ExistingSocket::NotASocket);
+    }
+
+    #[test]
+    fn test_remove_owned_socket_leaves_replaced_sockets() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.sock");
+
+        let listener = UnixListener::bind(&path).unwrap();
+        let inode = std::fs::symlink_metadata(&path).unwrap().ino();
+
+        // Another instance moves the socket away and binds its own at the same path
+        std::fs::rename(&path, dir.path().join("old.sock")).unwrap();
+        let replacement = UnixListener::bind(&path).unwrap();
+        assert!(!remove_owned_socket(&path, inode));
+        assert!(path.exists());
+
+        let inode = std::fs::symlink_metadata(&path).unwrap().ino();
+        assert!(remove_owned_socket(&path, inode));
+        assert!(!path.exists());
+        assert!(!remove_owned_socket(&path, inode));
+
+        drop((listener, replacement));
+    }
+
     #[test]
     fn test_ipc_query_serialization() {
         let query = IpcQuery::Screens;
@@ -450,6 +713,88 @@ mod tests {
         assert!(json.contains(r#""type":"windows""#));
         assert!(json.contains(r#""screen":"main""#));
         assert!(json.contains(r#""focusedWorkspace":true"#));
+
+        let query = IpcQuery::Layout {
+            workspace: "coding".to_string(),
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"layout","workspace":"coding"}"#);
+
+        let query = IpcQuery::Diagnostics {
+            workspace: "coding".to_string(),
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"diagnostics","workspace":"coding"}"#);
+
+        let query = IpcQuery::Tree { workspace: None };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"tree"}"#);
+
+        let query: IpcQuery =
+            serde_json::from_str(r#"{"type":"tree","workspace":"coding"}"#).unwrap();
+        assert!(matches!(query, IpcQuery::Tree { workspace: Some(ref w) } if w == "coding"));
+
+        let query = IpcQuery::PhantomWindows { prune: true };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"phantomWindows","prune":true}"#);
+
+        let query = IpcQuery::Explain { window_id: 42 };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"explain","windowId":42}"#);
+
+        let query = IpcQuery::ToggleAlwaysOnTop;
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"toggleAlwaysOnTop"}"#);
+
+        let query = IpcQuery::SetWindowOpacity { opacity: 70 };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(json, r#"{"type":"setWindowOpacity","opacity":70}"#);
+
+        let query: IpcQuery = serde_json::from_str(r#"{"type":"metrics"}"#).unwrap();
+        assert!(matches!(query, IpcQuery::Metrics { reset: false }));
+
+        let query = IpcQuery::PlaceWindow {
+            x: DimensionValue::Pixels(10),
+            y: DimensionValue::Pixels(20),
+            width: DimensionValue::Percentage("50%".to_string()),
+            height: DimensionValue::Pixels(600),
+            gaps: false,
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"placeWindow","x":10,"y":20,"width":"50%","height":600,"gaps":false}"#
+        );
+    }
+
+    #[test]
+    fn test_screenshot_query_serialization() {
+        let query = IpcQuery::Screenshot {
+            workspace: true,
+            only_windows: false,
+            output: Some("/tmp/shot.png".to_string()),
+        };
+        let json = serde_json::to_string(&query).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"screenshot","workspace":true,"onlyWindows":false,"output":"/tmp/shot.png"}"#
+        );
+
+        let query: IpcQuery = serde_json::from_str(r#"{"type":"screenshot"}"#).unwrap();
+        assert!(matches!(query, IpcQuery::Screenshot {
+            workspace: false,
+            only_windows: false,
+            output: None
+        }));
+    }
+
+    #[test]
+    fn test_recent_apps_query_serialization() {
+        let json = serde_json::to_string(&IpcQuery::RecentApps).unwrap();
+        assert_eq!(json, r#"{"type":"recentApps"}"#);
+
+        let query: IpcQuery = serde_json::from_str(r#"{"type":"switchApp"}"#).unwrap();
+        assert!(matches!(query, IpcQuery::SwitchApp));
     }
 
     #[test]
@@ -1,4 +1,5 @@
 pub mod accessibility;
+pub mod clipboard;
 pub mod command;
 pub mod display;
 pub mod ipc;
@@ -7,6 +7,8 @@ use objc::runtime::{Class, Object, Sel};
 use objc::{class, msg_send, sel, sel_impl};
 use tauri::{LogicalPosition, LogicalSize, Position, Size, WebviewWindow};
 
+use super::objc::{nsstring, nsstring_to_string};
+
 // Cache created Space so we only create/show once.
 static G_SPACE: OnceLock<u64> = OnceLock::new();
 static NON_ACTIVATING_PANEL_CLASS: OnceLock<&'static Class> = OnceLock::new();
@@ -21,6 +23,11 @@ const NSTRACKING_MOUSE_MOVED: u64 = 0x2;
 const NSTRACKING_ACTIVE_ALWAYS: u64 = 0x80;
 const NSTRACKING_IN_VISIBLE_RECT: u64 = 0x200;
 const NS_EVENT_TYPE_LEFT_MOUSE_DOWN: u64 = 1;
+const NS_VISUAL_EFFECT_BLENDING_MODE_BEHIND_WINDOW: i64 = 0;
+const NS_VISUAL_EFFECT_STATE_ACTIVE: i64 = 1;
+const NS_VIEW_WIDTH_SIZABLE: u64 = 1 << 1;
+const NS_VIEW_HEIGHT_SIZABLE: u64 = 1 << 4;
+const NS_WINDOW_BELOW: i64 = -1;
 
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -108,6 +115,7 @@ extern "C" fn forward_send_event(this: &Object, _: Sel, event: ObjcId) {
 
 const OBJC_ASSOCIATION_RETAIN_NONATOMIC: usize = 0x301;
 static TRACKING_AREA_ASSOC_KEY: u8 = 0;
+static VISUAL_EFFECT_VIEW_ASSOC_KEY: u8 = 0;
 
 pub fn set_position(window: &WebviewWindow, x: f64, y: f64, width: f64, height: f64) {
     let _ = window.set_size(Size::Logical(LogicalSize { width, height }));
@@ -187,6 +195,87 @@ pub fn get_screen_size(window: &WebviewWindow) -> Result<(f64, f64), Box<dyn std
     Ok((logical_width, logical_height))
 }
 
+/// Returns whether the system appearance is dark.
+#[must_use]
+pub fn is_dark_appearance() -> bool {
+    unsafe {
+        let defaults: ObjcId = msg_send![class!(NSUserDefaults), standardUserDefaults];
+        let style: ObjcId = msg_send![defaults, stringForKey: nsstring("AppleInterfaceStyle")];
+        nsstring_to_string(style).eq_ignore_ascii_case("dark")
+    }
+}
+
+/// Puts an `NSVisualEffectView` with the given material behind the window content.
+///
+/// `material` is an `NSVisualEffectMaterial` value; `None` removes the view.
+/// The view is created once per window and reused on later calls, so this can
+/// run again whenever the system appearance changes. Must be called on the
+/// main thread.
+pub fn set_window_material(window: &WebviewWindow, material: Option<i64>, dark: bool) {
+    let Ok(ns_win_ptr) = window.ns_window() else {
+        return;
+    };
+
+    unsafe {
+        let ns_win: ObjcId = ns_win_ptr as ObjcId;
+        let content_view: ObjcId = msg_send![ns_win, contentView];
+        if content_view.is_null() {
+            return;
+        }
+
+        let key_ptr = (&raw const VISUAL_EFFECT_VIEW_ASSOC_KEY).cast::<c_void>();
+        let mut effect_view: ObjcId = objc_getAssociatedObject(content_view, key_ptr);
+
+        let Some(material) = material else {
+            if !effect_view.is_null() {
+                let _: () = msg_send![effect_view, removeFromSuperview];
+                objc_setAssociatedObject(
+                    content_view,
+                    key_ptr,
+                    ptr::null_mut(),
+                    OBJC_ASSOCIATION_RETAIN_NONATOMIC,
+                );
+            }
+            return;
+        };
+
+        if effect_view.is_null() {
+            let bounds: NSRect = msg_send![content_view, bounds];
+            effect_view = msg_send![class!(NSVisualEffectView), alloc];
+            effect_view = msg_send![effect_view, initWithFrame: bounds];
+            let _: () = msg_send![effect_view,
+                setAutoresizingMask: NS_VIEW_WIDTH_SIZABLE | NS_VIEW_HEIGHT_SIZABLE
+            ];
+            let _: () = msg_send![effect_view, setBlendingMode: NS_VISUAL_EFFECT_BLENDING_MODE_BEHIND_WINDOW];
+            let _: () = msg_send![effect_view, setState: NS_VISUAL_EFFECT_STATE_ACTIVE];
+            let _: () = msg_send![content_view,
+                addSubview: effect_view
+                positioned: NS_WINDOW_BELOW
+                relativeTo: ptr::null_mut::<Object>()
+            ];
+            objc_setAssociatedObject(
+                content_view,
+                key_ptr,
+                effect_view,
+                OBJC_ASSOCIATION_RETAIN_NONATOMIC,
+            );
+            let _: () = msg_send![effect_view, release];
+        }
+
+        // The bar window has a fixed theme, so follow the system appearance on
+        // the view itself.
+        let appearance_name = if dark {
+            "NSAppearanceNameDarkAqua"
+        } else {
+            "NSAppearanceNameAqua"
+        };
+        let appearance: ObjcId =
+            msg_send![class!(NSAppearance), appearanceNamed: nsstring(appearance_name)];
+        let _: () = msg_send![effect_view, setAppearance: appearance];
+        let _: () = msg_send![effect_view, setMaterial: material];
+    }
+}
+
 fn enforce_non_activating_click_behavior(ns_win: ObjcId) {
     unsafe {
         let panel_class = non_activating_panel_class();
@@ -325,6 +414,14 @@ mod tests {
         assert_eq!(NSTRACKING_IN_VISIBLE_RECT, 0x200);
     }
 
+    #[test]
+    fn visual_effect_constants_are_valid() {
+        assert_eq!(NS_VISUAL_EFFECT_BLENDING_MODE_BEHIND_WINDOW, 0);
+        assert_eq!(NS_VISUAL_EFFECT_STATE_ACTIVE, 1);
+        assert_eq!(NS_VIEW_WIDTH_SIZABLE | NS_VIEW_HEIGHT_SIZABLE, 18);
+        assert_eq!(NS_WINDOW_BELOW, -1);
+    }
+
     #[test]
     fn event_type_constant_is_valid() {
         assert_eq!(NS_EVENT_TYPE_LEFT_MOUSE_DOWN, 1);
@@ -3,24 +3,37 @@
 //! This module generates a JSON Schema that describes the configuration file
 //! format, which can be used for editor validation and autocompletion.
 
-use crate::config::StacheConfig;
+use crate::config::{CONFIG_VERSION, StacheConfig};
+
+/// Public URL of the published schema, used as its `$id`.
+pub const SCHEMA_ID: &str =
+    "https://raw.githubusercontent.com/marcosmoura/stache/main/stache.schema.json";
+
+/// Title editors display for the schema.
+pub const SCHEMA_TITLE: &str = "Stache configuration";
 
 /// Generates a JSON Schema for the Stache configuration.
 ///
 /// The schema includes all configuration options with their types,
-/// descriptions, and default values.
+/// descriptions, and default values. The `version` property defaults to, and
+/// is capped at, the schema version this build supports.
 #[must_use]
 pub fn generate_schema() -> schemars::Schema {
     let mut schema = schemars::schema_for!(StacheConfig);
 
-    // Add $id for proper schema identification
+    // Add $id and a readable title for proper schema identification
     if let Some(obj) = schema.as_object_mut() {
-        obj.insert(
-            "$id".to_string(),
-            serde_json::json!(
-                "https://raw.githubusercontent.com/marcosmoura/stache/main/stache.schema.json"
-            ),
-        );
+        obj.insert("$id".to_string(), serde_json::json!(SCHEMA_ID));
+        obj.insert("title".to_string(), serde_json::json!(SCHEMA_TITLE));
+
+        if let Some(version) = obj
+            .get_mut("properties")
+            .and_then(|properties| properties.get_mut("version"))
+            .and_then(serde_json::Value::as_object_mut)
+        {
+            version.insert("default".to_string(), serde_json::json!(CONFIG_VERSION));
+            version.insert("maximum".to_string(), serde_json::json!(CONFIG_VERSION));
+        }
     }
 
     schema
@@ -49,11 +62,21 @@ mod tests {
 
         assert!(parsed["$id"].as_str().unwrap().contains("stache.schema.json"));
         assert_eq!(parsed["$schema"], "https://json-schema.org/draft/2020-12/schema");
-        assert_eq!(parsed["title"], "StacheConfig");
+        assert_eq!(parsed["title"], SCHEMA_TITLE);
         assert!(parsed["properties"]["keybindings"].is_object());
         assert!(parsed["properties"]["bar"].is_object());
     }
 
+    #[test]
+    fn test_schema_emits_config_version() {
+        let schema_json = print_schema();
+        let parsed: serde_json::Value = serde_json::from_str(&schema_json).unwrap();
+        let version = &parsed["properties"]["version"];
+
+        assert_eq!(version["default"], CONFIG_VERSION);
+        assert_eq!(version["maximum"], CONFIG_VERSION);
+    }
+
     #[test]
     fn test_generate_schema_returns_schema_object() {
         let schema = generate_schema();
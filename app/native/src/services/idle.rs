@@ -0,0 +1,208 @@
+//! Idle detection service.
+//!
+//! Polls the time since the last keyboard, mouse or trackpad event and
+//! dispatches the configured `idle.action` once it crosses `idle.timeout`.
+//! On the next input the action is undone when `idle.restoreOnActivity` is
+//! set. The config is read on every poll, so changes apply without a restart.
+
+use std::thread;
+use std::time::Duration;
+
+use tauri::{AppHandle, Emitter};
+
+use super::thread::spawn_named_thread;
+use crate::config::{self, IdleAction, IdleConfig};
+use crate::events;
+use crate::modules::bar::components::keepawake;
+use crate::modules::tiling;
+
+/// Delay between idle time checks.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// `kCGEventSourceStateHIDSystemState`: input from hardware devices.
+const HID_SYSTEM_STATE: i32 = 1;
+
+/// `kCGAnyInputEventType`: any keyboard, mouse or trackpad event.
+const ANY_INPUT_EVENT_TYPE: u32 = u32::MAX;
+
+#[link(name = "ApplicationServices", kind = "framework")]
+unsafe extern "C" {
+    fn CGEventSourceSecondsSinceLastEventType(state_id: i32, event_type: u32) -> f64;
+}
+
+/// Returns the seconds elapsed since the last user input.
+fn seconds_since_last_input() -> f64 {
+    // SAFETY: Pure query on the HID event source, no pointers involved.
+    unsafe { CGEventSourceSecondsSinceLastEventType(HID_SYSTEM_STATE, ANY_INPUT_EVENT_TYPE) }
+}
+
+/// Change of the user's idle state between two polls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Transition {
+    /// No input for at least the timeout.
+    BecameIdle,
+    /// Input received after being idle.
+    BecameActive,
+}
+
+/// Tracks whether the user is idle across polls.
+#[derive(Debug, Default)]
+struct IdleTracker {
+    idle: bool,
+}
+
+impl IdleTracker {
+    /// Records the latest idle time, returning the transition it caused, if any.
+    ///
+    /// A `timeout` of 0 never counts as idle.
+    #[allow(clippy::cast_precision_loss)] // Timeouts are far below f64 precision limits
+    fn update(&mut self, idle_seconds: f64, timeout: u64) -> Option<Transition> {
+        let idle = timeout > 0 && idle_seconds >= timeout as f64;
+        if idle == self.idle {
+            return None;
+        }
+
+        self.idle = idle;
+        Some(if idle {
+            Transition::BecameIdle
+        } else {
+            Transition::BecameActive
+        })
+    }
+}
+
+/// What to undo on the next input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Undo {
+    /// Re-acquire the released wake lock.
+    KeepAwake,
+    /// Switch back to the workspace focused before going idle.
+    Workspace(String),
+}
+
+/// Returns the name of the focused tiling workspace.
+fn focused_workspace_name(handle: &tiling::StateActorHandle) -> Option<String> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .ok()?
+        .block_on(handle.get_focused_workspace())
+        .ok()
+        .and_then(tiling::actor::QueryResult::into_workspace)
+        .flatten()
+        .map(|ws| ws.name)
+}
+
+/// Switches to a tiling workspace, returning the previously focused one.
+fn switch_workspace(name: &str) -> Option<String> {
+    if !tiling::init::is_initialized() {
+        tracing::debug!("idle: tiling not initialized, not switching to '{name}'");
+        return None;
+    }
+
+    let handle = tiling::init::get_handle()?;
+    let previous = focused_workspace_name(handle);
+
+    if let Err(e) = handle.switch_workspace(name) {
+        tracing::warn!("idle: failed to switch to workspace '{name}': {e}");
+        return None;
+    }
+
+    previous.filter(|previous| previous != name)
+}
+
+/// Dispatches the idle action, returning what to undo on the next input.
+fn dispatch(app_handle: &AppHandle, config: &IdleConfig) -> Option<Undo> {
+    let restore = config.restore_on_activity;
+
+    match &config.action {
+        IdleAction::None => None,
+        IdleAction::ReleaseKeepAwake => {
+            let released = keepawake::release_for_idle(app_handle, restore);
+            (released && restore).then_some(Undo::KeepAwake)
+        }
+        IdleAction::SwitchWorkspace(name) => {
+            let previous = switch_workspace(name);
+            previous.filter(|_| restore).map(Undo::Workspace)
+        }
+    }
+}
+
+/// Undoes an idle action after the user becomes active again.
+fn undo(app_handle: &AppHandle, undo: Undo) {
+    match undo {
+        Undo::KeepAwake => keepawake::resume_after_idle(app_handle),
+        Undo::Workspace(name) => {
+            switch_workspace(&name);
+        }
+    }
+}
+
+/// Polls the idle time forever, dispatching and undoing the idle action.
+fn run(app_handle: &AppHandle) {
+    let mut tracker = IdleTracker::default();
+    let mut pending_undo = None;
+
+    loop {
+        let config = &config::get_config().idle;
+        let timeout = if config.is_enabled() {
+            config.timeout
+        } else {
+            0
+        };
+
+        match tracker.update(seconds_since_last_input(), timeout) {
+            Some(Transition::BecameIdle) => {
+                tracing::info!("idle: no input for {timeout}s");
+                pending_undo = dispatch(app_handle, config);
+                app_handle.emit(events::idle::STATE_CHANGED, true).ok();
+            }
+            Some(Transition::BecameActive) => {
+                tracing::debug!("idle: input received");
+                if let Some(action) = pending_undo.take() {
+                    undo(app_handle, action);
+                }
+                app_handle.emit(events::idle::STATE_CHANGED, false).ok();
+            }
+            None => {}
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Starts the idle monitor.
+///
+/// The monitor always runs and is a no-op while `idle` is disabled, so the
+/// section is registered for live reload.
+pub fn init(app_handle: AppHandle) {
+    config::register_live_reload("idle", |_| true);
+
+    spawn_named_thread("idle-monitor", move || run(&app_handle));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracker_reports_transitions_once() {
+        let mut tracker = IdleTracker::default();
+
+        assert_eq!(tracker.update(10.0, 60), None);
+        assert_eq!(tracker.update(60.0, 60), Some(Transition::BecameIdle));
+        assert_eq!(tracker.update(120.0, 60), None);
+        assert_eq!(tracker.update(0.5, 60), Some(Transition::BecameActive));
+        assert_eq!(tracker.update(1.0, 60), None);
+    }
+
+    #[test]
+    fn test_tracker_disabled_timeout_is_never_idle() {
+        let mut tracker = IdleTracker::default();
+        assert_eq!(tracker.update(10_000.0, 0), None);
+
+        // Disabling while idle counts as becoming active, so the action is undone
+        tracker.update(120.0, 60);
+        assert_eq!(tracker.update(120.0, 0), Some(Transition::BecameActive));
+    }
+}
@@ -5,7 +5,9 @@
 //!
 //! - [`traits`] - Module and service trait definitions
 //! - [`thread`] - Thread utilities for spawning named threads and GCD dispatch
+//! - [`idle`] - Idle detection that dispatches an action after a period without input
 
+pub mod idle;
 pub mod thread;
 pub mod traits;
 
@@ -37,8 +37,13 @@ const getSortedWorkspaces = (workspaces: TilingWorkspace[] | undefined) => {
 
 const fetchWorkspacesData = async () => {
   try {
-    const workspaces = await invoke<TilingWorkspace[]>('get_tiling_workspaces');
-    const focusedWorkspace = await invoke<string | null>('get_tiling_focused_workspace');
+    // With a bar on every screen, each one only shows its own screen's workspaces
+    const screenId = await invoke<number | null>('get_bar_screen');
+    const workspaces = await invoke<TilingWorkspace[]>('get_tiling_workspaces', { screenId });
+    const focusedWorkspace =
+      screenId !== null
+        ? (workspaces.find(({ isVisible }) => isVisible)?.name ?? null)
+        : await invoke<string | null>('get_tiling_focused_workspace');
 
     return {
       workspacesData: getSortedWorkspaces(workspaces)?.map(({ name }) => name),
@@ -6,7 +6,14 @@ import { invoke } from '@tauri-apps/api/core';
 import { useTauriEvent } from '@/hooks';
 import { KeepAwakeEvents } from '@/types';
 
-const fetchKeepAwake = async (): Promise<boolean> => invoke<boolean>('is_system_awake');
+type KeepAwakeStatus = {
+  awake: boolean;
+  mode: 'display' | 'system';
+  remainingSeconds: number | null;
+};
+
+const fetchKeepAwake = async (): Promise<boolean> =>
+  (await invoke<KeepAwakeStatus>('is_system_awake')).awake;
 
 export const useKeepAwake = () => {
   const queryClient = useQueryClient();
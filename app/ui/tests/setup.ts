@@ -21,8 +21,9 @@ const defaultInvokeMocks: Record<string, unknown> = {
   get_current_media_info: {},
   get_battery_info: { percentage: 100, state: 'Full' },
   get_cpu_info: { usage: 25, temperature: 50 },
-  is_system_awake: false,
+  is_system_awake: { awake: false, mode: 'display', remainingSeconds: null },
   get_weather_config: {},
+  get_bar_screen: null,
   get_tiling_workspaces: [
     {
       name: 'terminal',
@@ -16,6 +16,14 @@ export const MenubarEvents = {
   VISIBILITY_CHANGED: 'stache://menubar/visibility-changed',
 } as const;
 
+/**
+ * Status bar events
+ */
+export const BarEvents = {
+  /** Emitted when the bar window is shown or hidden on demand. Payload: boolean */
+  VISIBILITY_CHANGED: 'stache://bar/visibility-changed',
+} as const;
+
 /**
  * Keep-awake (caffeinate) related events
  */
@@ -24,6 +32,14 @@ export const KeepAwakeEvents = {
   STATE_CHANGED: 'stache://keepawake/state-changed',
 } as const;
 
+/**
+ * Idle detection events
+ */
+export const IdleEvents = {
+  /** Emitted when the user becomes idle or active again. Payload: boolean */
+  STATE_CHANGED: 'stache://idle/state-changed',
+} as const;
+
 /**
  * Media playback related events
  */
@@ -32,6 +48,14 @@ export const MediaEvents = {
   PLAYBACK_CHANGED: 'stache://media/playback-changed',
 } as const;
 
+/**
+ * Audio device related events
+ */
+export const AudioEvents = {
+  /** Emitted when the default output is switched automatically. Payload: { id: number, name: string } */
+  OUTPUT_DEVICE_SWITCHED: 'stache://audio/output-device-switched',
+} as const;
+
 /**
  * Spaces/workspace related events
  *
@@ -82,6 +106,8 @@ export const TilingEvents = {
   WORKSPACE_CHANGED: 'stache://tiling/workspace-changed',
   /** Emitted when windows in a workspace change (added/removed). Payload: { workspace: string, windows: number[] } */
   WORKSPACE_WINDOWS_CHANGED: 'stache://tiling/workspace-windows-changed',
+  /** Emitted when the window count of any workspace changes. Payload: { name: string, screen: string, windowCount: number, isVisible: boolean, isFocused: boolean }[] */
+  WORKSPACES_CHANGED: 'stache://tiling/workspaces-changed',
   /** Emitted when a workspace's layout changes. Payload: { workspace: string, layout: string } */
   LAYOUT_CHANGED: 'stache://tiling/layout-changed',
   /** Emitted when a new window is tracked. Payload: { windowId: number, workspace: string } */
@@ -96,4 +122,6 @@ export const TilingEvents = {
   WINDOW_FOCUS_CHANGED: 'stache://tiling/window-focus-changed',
   /** Emitted when a window's title changes. Payload: { windowId: number, title: string } */
   WINDOW_TITLE_CHANGED: 'stache://tiling/window-title-changed',
+  /** Emitted while a window is dragged or resized, at most ~30fps. Payload: { windowId: number, frame: { x, y, width, height } } */
+  GEOMETRY_UPDATED: 'stache://tiling/geometry-updated',
 } as const;